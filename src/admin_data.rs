@@ -0,0 +1,253 @@
+use crate::{AbstractionElement, AutosarAbstractionError, abstraction_element};
+use autosar_data::{AttributeName, Element, ElementName};
+
+//##################################################################
+
+/// `AdminData` contains administrative information about an identifiable element,
+/// e.g. the revision history, the used languages and special data groups (SDGs)
+///
+/// Use [`IdentifiableAbstractionElement::get_or_create_admin_data`](crate::IdentifiableAbstractionElement::get_or_create_admin_data)
+/// to create the `AdminData` of an element.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AdminData(Element);
+abstraction_element!(AdminData, AdminData);
+
+impl AdminData {
+    pub(crate) fn get_or_create(parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let admin_data = parent.get_or_create_sub_element(ElementName::AdminData)?;
+        Ok(Self(admin_data))
+    }
+
+    /// create a new entry in the revision history
+    pub fn create_doc_revision(&self, revision_label: &str) -> Result<DocRevision, AutosarAbstractionError> {
+        let doc_revisions = self.element().get_or_create_sub_element(ElementName::DocRevisions)?;
+        DocRevision::new(&doc_revisions, revision_label)
+    }
+
+    /// iterate over all entries of the revision history
+    pub fn doc_revisions(&self) -> impl Iterator<Item = DocRevision> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::DocRevisions)
+            .into_iter()
+            .flat_map(|doc_revisions| doc_revisions.sub_elements())
+            .filter_map(|elem| DocRevision::try_from(elem).ok())
+    }
+
+    /// add a language to the list of used languages
+    ///
+    /// The language is given as a two-letter code, e.g. "EN" or "DE"
+    pub fn add_used_language(&self, language: &str, description: &str) -> Result<(), AutosarAbstractionError> {
+        let l10 = self
+            .element()
+            .get_or_create_sub_element(ElementName::UsedLanguages)?
+            .create_sub_element(ElementName::L10)?;
+        if let Err(error) = l10.set_attribute_string(AttributeName::L, language) {
+            // an invalid language code must not leave an incomplete entry behind
+            if let Ok(Some(used_languages)) = l10.parent() {
+                let _ = used_languages.remove_sub_element(l10);
+            }
+            return Err(error.into());
+        }
+        l10.set_character_data(description)?;
+        Ok(())
+    }
+
+    /// get the list of used languages
+    #[must_use]
+    pub fn used_languages(&self) -> Vec<String> {
+        self.element()
+            .get_sub_element(ElementName::UsedLanguages)
+            .into_iter()
+            .flat_map(|used_languages| used_languages.sub_elements())
+            .filter_map(|l10| l10.attribute_value(AttributeName::L).map(|lang| lang.to_string()))
+            .collect()
+    }
+
+    /// add a special data group (SDG)
+    ///
+    /// Each value of the SDG is stored as an SD element with its own gid.
+    pub fn add_special_data_group(&self, sdg: &SpecialDataGroup) -> Result<(), AutosarAbstractionError> {
+        let sdg_elem = self
+            .element()
+            .get_or_create_sub_element(ElementName::Sdgs)?
+            .create_sub_element(ElementName::Sdg)?;
+        sdg_elem.set_attribute_string(AttributeName::Gid, &sdg.gid)?;
+        for (gid, value) in &sdg.values {
+            let sd_elem = sdg_elem.create_sub_element(ElementName::Sd)?;
+            sd_elem.set_attribute_string(AttributeName::Gid, gid)?;
+            sd_elem.set_character_data(value.as_str())?;
+        }
+        Ok(())
+    }
+
+    /// iterate over all special data groups (SDGs)
+    pub fn special_data_groups(&self) -> impl Iterator<Item = SpecialDataGroup> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::Sdgs)
+            .into_iter()
+            .flat_map(|sdgs| sdgs.sub_elements())
+            .map(|sdg_elem| {
+                let values = sdg_elem
+                    .sub_elements()
+                    .filter(|elem| elem.element_name() == ElementName::Sd)
+                    .map(|sd_elem| {
+                        let value = sd_elem
+                            .character_data()
+                            .and_then(|cdata| cdata.string_value())
+                            .unwrap_or_default();
+                        (gid_of(&sd_elem), value)
+                    })
+                    .collect();
+                SpecialDataGroup {
+                    gid: gid_of(&sdg_elem),
+                    values,
+                }
+            })
+    }
+}
+
+fn gid_of(element: &Element) -> String {
+    element
+        .attribute_value(AttributeName::Gid)
+        .and_then(|gid| gid.string_value())
+        .unwrap_or_default()
+}
+
+//##################################################################
+
+/// An entry in the revision history of an [`AdminData`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DocRevision(Element);
+abstraction_element!(DocRevision, DocRevision);
+
+impl DocRevision {
+    pub(crate) fn new(parent: &Element, revision_label: &str) -> Result<Self, AutosarAbstractionError> {
+        let doc_revision = Self(parent.create_sub_element(ElementName::DocRevision)?);
+        doc_revision.set_revision_label(revision_label)?;
+        Ok(doc_revision)
+    }
+
+    /// set the revision label, e.g. a version number
+    pub fn set_revision_label(&self, revision_label: &str) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::RevisionLabel)?
+            .set_character_data(revision_label)?;
+        Ok(())
+    }
+
+    /// get the revision label
+    #[must_use]
+    pub fn revision_label(&self) -> Option<String> {
+        self.text_of(ElementName::RevisionLabel)
+    }
+
+    /// set or remove the state of the revision, e.g. "released"
+    pub fn set_state(&self, state: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        self.set_text_of(ElementName::State, state)
+    }
+
+    /// get the state of the revision
+    #[must_use]
+    pub fn state(&self) -> Option<String> {
+        self.text_of(ElementName::State)
+    }
+
+    /// set or remove the name of the person or organization that issued the revision
+    pub fn set_issued_by(&self, issued_by: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        self.set_text_of(ElementName::IssuedBy, issued_by)
+    }
+
+    /// get the name of the person or organization that issued the revision
+    #[must_use]
+    pub fn issued_by(&self) -> Option<String> {
+        self.text_of(ElementName::IssuedBy)
+    }
+
+    /// set or remove the date of the revision
+    ///
+    /// The date must be given in the ISO 8601 format, e.g. "2024-01-31" or "2024-01-31T12:00:00Z"
+    pub fn set_date(&self, date: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        self.set_text_of(ElementName::Date, date)
+    }
+
+    /// get the date of the revision
+    #[must_use]
+    pub fn date(&self) -> Option<String> {
+        self.text_of(ElementName::Date)
+    }
+
+    fn set_text_of(&self, element_name: ElementName, value: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        if let Some(value) = value {
+            self.element()
+                .get_or_create_sub_element(element_name)?
+                .set_character_data(value)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(element_name);
+        }
+        Ok(())
+    }
+
+    fn text_of(&self, element_name: ElementName) -> Option<String> {
+        self.element()
+            .get_sub_element(element_name)?
+            .character_data()?
+            .string_value()
+    }
+}
+
+//##################################################################
+
+/// A special data group (SDG) in the [`AdminData`] of an element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecialDataGroup {
+    /// the gid of the SDG
+    pub gid: String,
+    /// the values in the SDG as pairs of (gid, value)
+    pub values: Vec<(String, String)>,
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutosarModelAbstraction, IdentifiableAbstractionElement, SystemCategory};
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn admin_data() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        assert!(system.admin_data().is_none());
+
+        let admin_data = system.get_or_create_admin_data().unwrap();
+        assert_eq!(system.admin_data(), Some(admin_data.clone()));
+
+        let revision = admin_data.create_doc_revision("1.0.0").unwrap();
+        revision.set_state(Some("released")).unwrap();
+        revision.set_issued_by(Some("someone")).unwrap();
+        revision.set_date(Some("2024-01-31")).unwrap();
+        assert_eq!(revision.revision_label().as_deref(), Some("1.0.0"));
+        assert_eq!(revision.state().as_deref(), Some("released"));
+        assert_eq!(revision.issued_by().as_deref(), Some("someone"));
+        assert_eq!(revision.date().as_deref(), Some("2024-01-31"));
+        revision.set_state(None).unwrap();
+        assert_eq!(revision.state(), None);
+        admin_data.create_doc_revision("1.1.0").unwrap();
+        assert_eq!(admin_data.doc_revisions().count(), 2);
+
+        admin_data.add_used_language("EN", "English").unwrap();
+        assert_eq!(admin_data.used_languages(), vec!["EN".to_string()]);
+
+        let sdg = SpecialDataGroup {
+            gid: "Generator".to_string(),
+            values: vec![("Name".to_string(), "MyGenerator".to_string())],
+        };
+        admin_data.add_special_data_group(&sdg).unwrap();
+        assert_eq!(admin_data.special_data_groups().collect::<Vec<_>>(), vec![sdg]);
+
+        system.remove_admin_data();
+        assert!(system.admin_data().is_none());
+    }
+}
@@ -0,0 +1,344 @@
+//! Analysis of the model
+//!
+//! This module contains functions that evaluate the model as a whole, e.g. to render the network topology of a system.
+//!
+//! # Example
+//!
+//! ```
+//! use autosar_data::*;
+//! use autosar_data_abstraction::*;
+//! use autosar_data_abstraction::analysis::*;
+//! use autosar_data_abstraction::communication::*;
+//!
+//! # fn main() -> Result<(), AutosarAbstractionError> {
+//! let model = AutosarModelAbstraction::create("topology.arxml", AutosarVersion::LATEST);
+//! let package = model.get_or_create_package("/System")?;
+//! let system = package.create_system("System", SystemCategory::SystemExtract)?;
+//! let cluster = system.create_can_cluster("CanCluster", &package, None)?;
+//! let channel = cluster.create_physical_channel("CanChannel")?;
+//! let ecu = system.create_ecu_instance("Ecu", &package)?;
+//! let controller = ecu.create_can_communication_controller("CanCtrl")?;
+//! controller.connect_physical_channel("Ecu_CanConnector", &channel)?;
+//!
+//! let dot = topology_graph(&system, GraphFormat::Dot);
+//! assert!(dot.starts_with("graph Topology {"));
+//! let mermaid = topology_graph(&system, GraphFormat::Mermaid);
+//! assert!(mermaid.starts_with("graph LR"));
+//! # Ok(())}
+//! ```
+
+use crate::communication::{AbstractPhysicalChannel, Cluster, PhysicalChannel};
+use crate::{AbstractionElement, IdentifiableAbstractionElement, System};
+use autosar_data::Element;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+//##################################################################
+
+/// The output format of a graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GraphFormat {
+    /// `GraphViz` DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+/// render the network topology of a `System` as a graph
+///
+/// The graph contains all clusters of the system with their physical channels, all ECUs of the system,
+/// and their communication controllers. Each ECU is connected to its controllers, and each controller is
+/// connected to the channels it is attached to. The channels of a cluster are grouped in a subgraph.
+///
+/// The result is a string in the requested [`GraphFormat`], which can be rendered by `GraphViz` or Mermaid.
+#[must_use]
+pub fn topology_graph(system: &System, format: GraphFormat) -> String {
+    let topology = Topology::new(system);
+    match format {
+        GraphFormat::Dot => topology.to_dot(),
+        GraphFormat::Mermaid => topology.to_mermaid(),
+    }
+}
+
+//##################################################################
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    Ecu,
+    Controller,
+    Channel,
+}
+
+#[derive(Debug)]
+struct Node {
+    label: String,
+    kind: NodeKind,
+}
+
+// intermediate representation of the topology, which is shared by all output formats
+#[derive(Debug, Default)]
+struct Topology {
+    nodes: Vec<Node>,
+    // each cluster has a label and contains a list of channel nodes
+    clusters: Vec<(String, Vec<usize>)>,
+    edges: Vec<(usize, usize)>,
+    node_ids: HashMap<Element, usize>,
+}
+
+impl Topology {
+    fn new(system: &System) -> Self {
+        let mut topology = Self::default();
+
+        for cluster in system.clusters() {
+            let (kind, channels) = cluster_channels(&cluster);
+            let cluster_name = cluster.name().unwrap_or_default();
+            let mut channel_nodes = Vec::new();
+            for channel in &channels {
+                let label = channel_label(channel);
+                channel_nodes.push(topology.node(channel.element(), label, NodeKind::Channel));
+            }
+            topology
+                .clusters
+                .push((format!("{cluster_name} ({kind})"), channel_nodes));
+
+            for channel in channels {
+                let channel_node = topology.node(channel.element(), channel_label(&channel), NodeKind::Channel);
+                for (ecu, controller) in channel.ecus() {
+                    let ecu_node = topology.node(ecu.element(), ecu.name().unwrap_or_default(), NodeKind::Ecu);
+                    let controller_node = topology.node(
+                        controller.element(),
+                        controller.name().unwrap_or_default(),
+                        NodeKind::Controller,
+                    );
+                    topology.edge(ecu_node, controller_node);
+                    topology.edge(controller_node, channel_node);
+                }
+            }
+        }
+
+        // ECUs and controllers that are not connected to any channel are also part of the topology
+        for ecu in system.ecu_instances() {
+            let ecu_node = topology.node(ecu.element(), ecu.name().unwrap_or_default(), NodeKind::Ecu);
+            for controller in ecu.communication_controllers() {
+                let controller_node = topology.node(
+                    controller.element(),
+                    controller.name().unwrap_or_default(),
+                    NodeKind::Controller,
+                );
+                topology.edge(ecu_node, controller_node);
+            }
+        }
+
+        topology
+    }
+
+    // get the index of the node for the element, creating the node if it does not exist yet
+    fn node(&mut self, element: &Element, label: String, kind: NodeKind) -> usize {
+        if let Some(index) = self.node_ids.get(element) {
+            return *index;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(Node { label, kind });
+        self.node_ids.insert(element.clone(), index);
+        index
+    }
+
+    fn edge(&mut self, from: usize, to: usize) {
+        if !self.edges.contains(&(from, to)) {
+            self.edges.push((from, to));
+        }
+    }
+
+    // nodes that are not part of any cluster subgraph
+    fn free_nodes(&self) -> impl Iterator<Item = usize> {
+        (0..self.nodes.len()).filter(|index| self.nodes[*index].kind != NodeKind::Channel)
+    }
+
+    fn to_dot(&self) -> String {
+        let mut output = String::new();
+        let _ = writeln!(output, "graph Topology {{");
+        for (cluster_idx, (label, channel_nodes)) in self.clusters.iter().enumerate() {
+            let _ = writeln!(output, "    subgraph cluster_{cluster_idx} {{");
+            let _ = writeln!(output, "        label=\"{}\";", escape_dot(label));
+            for node in channel_nodes {
+                let _ = writeln!(output, "        {};", self.dot_node(*node));
+            }
+            let _ = writeln!(output, "    }}");
+        }
+        for node in self.free_nodes() {
+            let _ = writeln!(output, "    {};", self.dot_node(node));
+        }
+        for (from, to) in &self.edges {
+            let _ = writeln!(output, "    n{from} -- n{to};");
+        }
+        let _ = writeln!(output, "}}");
+        output
+    }
+
+    fn dot_node(&self, index: usize) -> String {
+        let node = &self.nodes[index];
+        let shape = match node.kind {
+            NodeKind::Ecu => "box3d",
+            NodeKind::Controller => "ellipse",
+            NodeKind::Channel => "box",
+        };
+        format!("n{index} [label=\"{}\", shape={shape}]", escape_dot(&node.label))
+    }
+
+    fn to_mermaid(&self) -> String {
+        let mut output = String::new();
+        let _ = writeln!(output, "graph LR");
+        for (cluster_idx, (label, channel_nodes)) in self.clusters.iter().enumerate() {
+            let _ = writeln!(
+                output,
+                "    subgraph cluster_{cluster_idx}[\"{}\"]",
+                escape_mermaid(label)
+            );
+            for node in channel_nodes {
+                let _ = writeln!(output, "        {}", self.mermaid_node(*node));
+            }
+            let _ = writeln!(output, "    end");
+        }
+        for node in self.free_nodes() {
+            let _ = writeln!(output, "    {}", self.mermaid_node(node));
+        }
+        for (from, to) in &self.edges {
+            let _ = writeln!(output, "    n{from} --- n{to}");
+        }
+        output
+    }
+
+    fn mermaid_node(&self, index: usize) -> String {
+        let node = &self.nodes[index];
+        let label = escape_mermaid(&node.label);
+        match node.kind {
+            NodeKind::Ecu => format!("n{index}[[\"{label}\"]]"),
+            NodeKind::Controller => format!("n{index}([\"{label}\"])"),
+            NodeKind::Channel => format!("n{index}[\"{label}\"]"),
+        }
+    }
+}
+
+// get the bus type and the physical channels of a cluster
+fn cluster_channels(cluster: &Cluster) -> (&'static str, Vec<PhysicalChannel>) {
+    match cluster {
+        Cluster::Can(can_cluster) => (
+            "CAN",
+            can_cluster
+                .physical_channel()
+                .map(PhysicalChannel::Can)
+                .into_iter()
+                .collect(),
+        ),
+        Cluster::Ethernet(eth_cluster) => (
+            "Ethernet",
+            eth_cluster.physical_channels().map(PhysicalChannel::Ethernet).collect(),
+        ),
+        Cluster::FlexRay(flx_cluster) => {
+            let channels_info = flx_cluster.physical_channels();
+            (
+                "FlexRay",
+                [channels_info.channel_a, channels_info.channel_b]
+                    .into_iter()
+                    .flatten()
+                    .map(PhysicalChannel::Flexray)
+                    .collect(),
+            )
+        }
+        Cluster::Lin(lin_cluster) => (
+            "LIN",
+            lin_cluster
+                .physical_channel()
+                .map(PhysicalChannel::Lin)
+                .into_iter()
+                .collect(),
+        ),
+    }
+}
+
+fn channel_label(channel: &PhysicalChannel) -> String {
+    let name = channel.name().unwrap_or_default();
+    match channel {
+        PhysicalChannel::Ethernet(eth_channel) => match eth_channel.vlan_info() {
+            Some(vlan_info) => format!("{name} (VLAN {})", vlan_info.vlan_id),
+            None => name,
+        },
+        _ => name,
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::communication::EthernetVlanInfo;
+    use crate::{AutosarModelAbstraction, SystemCategory};
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn topology() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+
+        let can_cluster = system.create_can_cluster("CanCluster", &package, None).unwrap();
+        let can_channel = can_cluster.create_physical_channel("CanChannel").unwrap();
+        let eth_cluster = system.create_ethernet_cluster("EthCluster", &package).unwrap();
+        let vlan_info = EthernetVlanInfo {
+            vlan_id: 10,
+            vlan_name: "VLAN_10".to_string(),
+        };
+        let eth_channel = eth_cluster
+            .create_physical_channel("EthChannel", Some(&vlan_info))
+            .unwrap();
+
+        let ecu_a = system.create_ecu_instance("Ecu_A", &package).unwrap();
+        let can_ctrl = ecu_a.create_can_communication_controller("CanCtrl").unwrap();
+        can_ctrl.connect_physical_channel("Ecu_A_Can", &can_channel).unwrap();
+        let eth_ctrl = ecu_a.create_ethernet_communication_controller("EthCtrl", None).unwrap();
+        eth_ctrl.connect_physical_channel("Ecu_A_Eth", &eth_channel).unwrap();
+        let ecu_b = system.create_ecu_instance("Ecu_B", &package).unwrap();
+        let can_ctrl_b = ecu_b.create_can_communication_controller("CanCtrl").unwrap();
+        can_ctrl_b.connect_physical_channel("Ecu_B_Can", &can_channel).unwrap();
+        // an ECU without any connection
+        system.create_ecu_instance("Ecu_C", &package).unwrap();
+
+        let topology = Topology::new(&system);
+        // 2 channels, 3 ECUs, 3 controllers
+        assert_eq!(topology.nodes.len(), 8);
+        assert_eq!(topology.clusters.len(), 2);
+        assert_eq!(topology.clusters[0].0, "CanCluster (CAN)");
+        // each of the 3 controllers is connected to its ECU and to a channel
+        assert_eq!(topology.edges.len(), 6);
+
+        let dot = topology_graph(&system, GraphFormat::Dot);
+        assert!(dot.starts_with("graph Topology {\n"));
+        assert!(dot.contains("label=\"EthCluster (Ethernet)\";"));
+        assert!(dot.contains("[label=\"EthChannel (VLAN 10)\", shape=box]"));
+        assert!(dot.contains("[label=\"Ecu_C\", shape=box3d]"));
+        assert_eq!(dot.matches(" -- ").count(), 6);
+        assert!(dot.ends_with("}\n"));
+
+        let mermaid = topology_graph(&system, GraphFormat::Mermaid);
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("subgraph cluster_0[\"CanCluster (CAN)\"]"));
+        assert!(mermaid.contains("[[\"Ecu_A\"]]"));
+        assert!(mermaid.contains("([\"EthCtrl\"])"));
+        assert_eq!(mermaid.matches(" --- ").count(), 6);
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape_dot("a\"b\\c"), "a\\\"b\\\\c");
+        assert_eq!(escape_mermaid("a\"b"), "a#quot;b");
+    }
+}
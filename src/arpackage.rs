@@ -1,20 +1,24 @@
-use autosar_data::{AutosarModel, Element, ElementName};
+use autosar_data::{ArxmlFile, AutosarModel, Element, ElementName, WeakArxmlFile};
 
 use crate::{
-    AbstractionElement, AutosarAbstractionError, ByteOrder, EcuInstance, IdentifiableAbstractionElement, System,
-    SystemCategory, abstraction_element,
+    AbstractionElement, AutosarAbstractionError, ByteOrder, EcuInstance, FlatMap, IdentifiableAbstractionElement,
+    PostBuildVariantCriterion, PostBuildVariantCriterionValueSet, PredefinedVariant, RapidPrototypingScenario,
+    SwSystemconst, SwSystemconstantValueSet, System, SystemCategory, abstraction_element,
+    bsw::{BswCallType, BswModuleDescription, BswModuleEntry},
     communication::{
-        CanCluster, CanFrame, CanTpConfig, ContainerIPdu, DataTransformationSet, DcmIPdu, DoIpTpConfig,
-        EthernetCluster, FlexrayArTpConfig, FlexrayCluster, FlexrayFrame, FlexrayTpConfig, GeneralPurposeIPdu,
-        GeneralPurposePdu, ISignal, ISignalGroup, ISignalIPdu, LinCluster, MultiplexedIPdu, NPdu, NmConfig, NmPdu,
-        RequestResponseDelay, SecuredIPdu, SomeipSdClientEventGroupTimingConfig, SomeipSdClientServiceInstanceConfig,
+        CanCluster, CanFrame, CanTpConfig, ContainerIPdu, CryptoServiceKey, CryptoServicePrimitive, CryptoServiceQueue,
+        DataTransformationSet, DcmIPdu, DoIpTpConfig, EthernetCluster, FlexrayArTpConfig, FlexrayCluster, FlexrayFrame,
+        FlexrayTpConfig, GeneralPurposeIPdu, GeneralPurposePdu, ISignal, ISignalGroup, ISignalIPdu, LinCluster,
+        MultiplexedIPdu, NPdu, NmConfig, NmPdu, RequestResponseDelay, SecOcCryptoServiceMapping, SecuredIPdu,
+        SomeipSdClientEventGroupTimingConfig, SomeipSdClientServiceInstanceConfig,
         SomeipSdServerEventGroupTimingConfig, SomeipSdServerServiceInstanceConfig, SystemSignal, SystemSignalGroup,
+        TlsCryptoServiceMapping,
     },
     datatype::{
         ApplicationArrayDataType, ApplicationArraySize, ApplicationDataType, ApplicationPrimitiveCategory,
         ApplicationPrimitiveDataType, ApplicationRecordDataType, BaseTypeEncoding, CompuMethod, CompuMethodContent,
         ConstantSpecification, DataConstr, DataTypeMappingSet, ImplementationDataType, ImplementationDataTypeSettings,
-        SwBaseType, Unit, ValueSpecification,
+        PhysicalDimension, PhysicalDimensionExponents, SectionType, SwAddrMethod, SwBaseType, Unit, ValueSpecification,
     },
     ecu_configuration::{
         EcucDefinitionCollection, EcucDestinationUriDefSet, EcucModuleConfigurationValues, EcucModuleDef,
@@ -23,9 +27,10 @@ use crate::{
     software_component::{
         ApplicationSwComponentType, ClientServerInterface, ComplexDeviceDriverSwComponentType,
         CompositionSwComponentType, EcuAbstractionSwComponentType, ModeDeclarationGroup, ModeDeclarationGroupCategory,
-        ModeSwitchInterface, NvDataInterface, ParameterInterface, SenderReceiverInterface,
+        ModeSwitchInterface, NvBlockSwComponentType, NvDataInterface, ParameterInterface, SenderReceiverInterface,
         SensorActuatorSwComponentType, ServiceSwComponentType, TriggerInterface,
     },
+    timing::SystemTiming,
 };
 
 /// An `ArPackage` is an Autosar package, which can contain other packages or elements
@@ -252,6 +257,10 @@ impl ArPackage {
                     let n_pdu = NPdu::try_from(element)?;
                     n_pdu.remove(deep)?;
                 }
+                ElementName::NvBlockSwComponentType => {
+                    let nv_block_sw_component_type = NvBlockSwComponentType::try_from(element)?;
+                    nv_block_sw_component_type.remove(deep)?;
+                }
                 ElementName::NvDataInterface => {
                     let nv_data_interface = NvDataInterface::try_from(element)?;
                     nv_data_interface.remove(deep)?;
@@ -434,6 +443,54 @@ impl ArPackage {
         ApplicationSwComponentType::new(name, self)
     }
 
+    /// create a new `BswModuleDescription` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let module = package.create_bsw_module_description("BswModuleDescription")?;
+    /// assert!(model.get_element_by_path("/some/package/BswModuleDescription").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the BSW-MODULE-DESCRIPTION element
+    pub fn create_bsw_module_description(&self, name: &str) -> Result<BswModuleDescription, AutosarAbstractionError> {
+        BswModuleDescription::new(name, self)
+    }
+
+    /// create a new `BswModuleEntry` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, bsw::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let entry = package.create_bsw_module_entry("BswModuleEntry", BswCallType::Regular)?;
+    /// assert!(model.get_element_by_path("/some/package/BswModuleEntry").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the BSW-MODULE-ENTRY element
+    pub fn create_bsw_module_entry(
+        &self,
+        name: &str,
+        call_type: BswCallType,
+    ) -> Result<BswModuleEntry, AutosarAbstractionError> {
+        BswModuleEntry::new(name, self, call_type)
+    }
+
     /// create a new `ClientServerInterface` in the package
     ///
     /// # Example
@@ -570,6 +627,81 @@ impl ArPackage {
         ConstantSpecification::new(name, self, value.into())
     }
 
+    /// create a new `CryptoServiceKey` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let key = package.create_crypto_service_key("CryptoServiceKey")?;
+    /// assert!(model.get_element_by_path("/some/package/CryptoServiceKey").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the CRYPTO-SERVICE-KEY element
+    pub fn create_crypto_service_key(&self, name: &str) -> Result<CryptoServiceKey, AutosarAbstractionError> {
+        CryptoServiceKey::new(name, self)
+    }
+
+    /// create a new `CryptoServicePrimitive` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let primitive = package.create_crypto_service_primitive("CryptoServicePrimitive", "AES", "CMAC")?;
+    /// assert!(model.get_element_by_path("/some/package/CryptoServicePrimitive").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the CRYPTO-SERVICE-PRIMITIVE element
+    pub fn create_crypto_service_primitive(
+        &self,
+        name: &str,
+        algorithm_family: &str,
+        algorithm_mode: &str,
+    ) -> Result<CryptoServicePrimitive, AutosarAbstractionError> {
+        CryptoServicePrimitive::new(name, self, algorithm_family, algorithm_mode)
+    }
+
+    /// create a new `CryptoServiceQueue` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let queue = package.create_crypto_service_queue("CryptoServiceQueue", 4)?;
+    /// assert!(model.get_element_by_path("/some/package/CryptoServiceQueue").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the CRYPTO-SERVICE-QUEUE element
+    pub fn create_crypto_service_queue(
+        &self,
+        name: &str,
+        queue_size: u32,
+    ) -> Result<CryptoServiceQueue, AutosarAbstractionError> {
+        CryptoServiceQueue::new(name, self, queue_size)
+    }
+
     /// create a new `DataConstr` in the package
     ///
     /// # Example
@@ -782,6 +914,28 @@ impl ArPackage {
         EcucValueCollection::new(name, self)
     }
 
+    /// create a new `FlatMap` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let flat_map = package.create_flat_map("FlatMap")?;
+    /// assert!(model.get_element_by_path("/some/package/FlatMap").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the FLAT-MAP element
+    pub fn create_flat_map(&self, name: &str) -> Result<FlatMap, AutosarAbstractionError> {
+        FlatMap::new(name, self)
+    }
+
     /// create a new `ImplementationDataType` in the package
     ///
     /// # Example
@@ -862,6 +1016,31 @@ impl ArPackage {
         ModeSwitchInterface::new(name, self)
     }
 
+    /// create a new `NvBlockSwComponentType` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let component = package.create_nv_block_sw_component_type("NvBlockSwComponentType")?;
+    /// assert!(model.get_element_by_path("/some/package/NvBlockSwComponentType").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the NV-BLOCK-SW-COMPONENT-TYPE element
+    pub fn create_nv_block_sw_component_type(
+        &self,
+        name: &str,
+    ) -> Result<NvBlockSwComponentType, AutosarAbstractionError> {
+        NvBlockSwComponentType::new(name, self)
+    }
+
     /// create a new `NvDataInterface` in the package
     ///
     /// # Example
@@ -906,6 +1085,155 @@ impl ArPackage {
         ParameterInterface::new(name, self)
     }
 
+    /// create a new `PhysicalDimension` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, datatype::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let exponents = PhysicalDimensionExponents { length: 1, time: -1, ..Default::default() };
+    /// let physical_dimension = package.create_physical_dimension("Velocity", &exponents)?;
+    /// assert!(model.get_element_by_path("/some/package/Velocity").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the PHYSICAL-DIMENSION element
+    pub fn create_physical_dimension(
+        &self,
+        name: &str,
+        exponents: &PhysicalDimensionExponents,
+    ) -> Result<PhysicalDimension, AutosarAbstractionError> {
+        PhysicalDimension::new(name, self, exponents)
+    }
+
+    /// create a new `PostBuildVariantCriterion` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let criterion = package.create_post_build_variant_criterion("Criterion")?;
+    /// assert!(model.get_element_by_path("/some/package/Criterion").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the POST-BUILD-VARIANT-CRITERION element
+    pub fn create_post_build_variant_criterion(
+        &self,
+        name: &str,
+    ) -> Result<PostBuildVariantCriterion, AutosarAbstractionError> {
+        PostBuildVariantCriterion::new(name, self)
+    }
+
+    /// create a new `PostBuildVariantCriterionValueSet` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let value_set = package.create_post_build_variant_criterion_value_set("CriterionValues")?;
+    /// assert!(model.get_element_by_path("/some/package/CriterionValues").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the POST-BUILD-VARIANT-CRITERION-VALUE-SET element
+    pub fn create_post_build_variant_criterion_value_set(
+        &self,
+        name: &str,
+    ) -> Result<PostBuildVariantCriterionValueSet, AutosarAbstractionError> {
+        PostBuildVariantCriterionValueSet::new(name, self)
+    }
+
+    /// create a new `PredefinedVariant` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let variant = package.create_predefined_variant("Variant")?;
+    /// assert!(model.get_element_by_path("/some/package/Variant").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the PREDEFINED-VARIANT element
+    pub fn create_predefined_variant(&self, name: &str) -> Result<PredefinedVariant, AutosarAbstractionError> {
+        PredefinedVariant::new(name, self)
+    }
+
+    /// create a new `RapidPrototypingScenario` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let scenario = package.create_rapid_prototyping_scenario("Scenario")?;
+    /// assert!(model.get_element_by_path("/some/package/Scenario").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the RAPID-PROTOTYPING-SCENARIO element
+    pub fn create_rapid_prototyping_scenario(
+        &self,
+        name: &str,
+    ) -> Result<RapidPrototypingScenario, AutosarAbstractionError> {
+        RapidPrototypingScenario::new(name, self)
+    }
+
+    /// create a new `SecOcCryptoServiceMapping` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let mapping = package.create_secoc_crypto_service_mapping("SecOcCryptoServiceMapping")?;
+    /// assert!(model.get_element_by_path("/some/package/SecOcCryptoServiceMapping").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the SEC-OC-CRYPTO-SERVICE-MAPPING element
+    pub fn create_secoc_crypto_service_mapping(
+        &self,
+        name: &str,
+    ) -> Result<SecOcCryptoServiceMapping, AutosarAbstractionError> {
+        SecOcCryptoServiceMapping::new(name, self)
+    }
+
     /// create a new `SenderReceiverInterface` in the package
     ///
     /// # Example
@@ -1088,6 +1416,32 @@ impl ArPackage {
         SomeipSdServerServiceInstanceConfig::new(name, self, ttl)
     }
 
+    /// create a new `SwAddrMethod` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, datatype::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let sw_addr_method = package.create_sw_addr_method("CALIB", Some(SectionType::CalibrationVariables))?;
+    /// assert!(model.get_element_by_path("/some/package/CALIB").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the SW-ADDR-METHOD element
+    pub fn create_sw_addr_method(
+        &self,
+        name: &str,
+        section_type: Option<SectionType>,
+    ) -> Result<SwAddrMethod, AutosarAbstractionError> {
+        SwAddrMethod::new(name, self, section_type)
+    }
+
     /// create a new `SwBaseType` in the package
     ///
     /// # Example
@@ -1126,6 +1480,53 @@ impl ArPackage {
         )
     }
 
+    /// create a new `SwSystemconst` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let systemconst = package.create_sw_systemconst("SYSC_VARIANT")?;
+    /// assert!(model.get_element_by_path("/some/package/SYSC_VARIANT").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the SW-SYSTEMCONST element
+    pub fn create_sw_systemconst(&self, name: &str) -> Result<SwSystemconst, AutosarAbstractionError> {
+        SwSystemconst::new(name, self)
+    }
+
+    /// create a new `SwSystemconstantValueSet` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let value_set = package.create_sw_systemconstant_value_set("SystemconstValues")?;
+    /// assert!(model.get_element_by_path("/some/package/SystemconstValues").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the SW-SYSTEMCONSTANT-VALUE-SET element
+    pub fn create_sw_systemconstant_value_set(
+        &self,
+        name: &str,
+    ) -> Result<SwSystemconstantValueSet, AutosarAbstractionError> {
+        SwSystemconstantValueSet::new(name, self)
+    }
+
     /// create a new System in the package
     ///
     /// Note that an Autosar model should ony contain one SYSTEM. This is not checked here.
@@ -1194,6 +1595,54 @@ impl ArPackage {
         SystemSignalGroup::new(name, self)
     }
 
+    /// create a new `SystemTiming` in the package, which contains the timing descriptions and constraints of the system
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let timing = package.create_system_timing("SystemTiming", &system)?;
+    /// assert!(model.get_element_by_path("/some/package/SystemTiming").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the SYSTEM-TIMING element
+    pub fn create_system_timing(&self, name: &str, system: &System) -> Result<SystemTiming, AutosarAbstractionError> {
+        SystemTiming::new(name, self, system)
+    }
+
+    /// create a new `TlsCryptoServiceMapping` in the package
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let mapping = package.create_tls_crypto_service_mapping("TlsCryptoServiceMapping")?;
+    /// assert!(model.get_element_by_path("/some/package/TlsCryptoServiceMapping").is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the TLS-CRYPTO-SERVICE-MAPPING element
+    pub fn create_tls_crypto_service_mapping(
+        &self,
+        name: &str,
+    ) -> Result<TlsCryptoServiceMapping, AutosarAbstractionError> {
+        TlsCryptoServiceMapping::new(name, self)
+    }
+
     /// create a new `TriggerInterface` in the package
     ///
     /// # Example
@@ -1238,6 +1687,39 @@ impl ArPackage {
         Unit::new(name, self, display_name)
     }
 
+    /// get an existing `Unit` in the package by name, or create a new one if it does not exist
+    ///
+    /// The display name is only used if a new `Unit` is created.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// let unit = package.find_or_create_unit("KmPerHour", Some("km/h"))?;
+    /// let unit2 = package.find_or_create_unit("KmPerHour", Some("km/h"))?;
+    /// assert_eq!(unit, unit2);
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the UNIT element
+    pub fn find_or_create_unit(&self, name: &str, display_name: Option<&str>) -> Result<Unit, AutosarAbstractionError> {
+        let existing_unit = self
+            .elements()
+            .filter(|elem| elem.element_name() == ElementName::Unit)
+            .find(|elem| elem.item_name().as_deref() == Some(name));
+        if let Some(unit_elem) = existing_unit {
+            Unit::try_from(unit_elem)
+        } else {
+            Unit::new(name, self, display_name)
+        }
+    }
+
     /// iterate over all elements in the package
     ///
     /// # Example
@@ -1305,6 +1787,60 @@ impl ArPackage {
             .filter_map(|element| ArPackage::try_from(element).ok())
     }
 
+    /// iterate over all sub-packages in the package, including the sub-packages of the sub-packages
+    ///
+    /// The packages are returned in depth-first order, i.e. each package is followed by its own sub-packages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// model.get_or_create_package("/some/package/sub/sub_sub")?;
+    /// assert_eq!(package.sub_packages_recursive().count(), 2);
+    /// # Ok(())}
+    /// ```
+    pub fn sub_packages_recursive(&self) -> impl Iterator<Item = ArPackage> + Send + use<> {
+        let mut packages = Vec::new();
+        self.collect_sub_packages(&mut packages);
+        packages.into_iter()
+    }
+
+    fn collect_sub_packages(&self, packages: &mut Vec<ArPackage>) {
+        for sub_package in self.sub_packages() {
+            packages.push(sub_package.clone());
+            sub_package.collect_sub_packages(packages);
+        }
+    }
+
+    /// iterate over all elements of the package that have the type `T`
+    ///
+    /// Elements of other types are skipped. Sub-packages are not searched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let package = model.get_or_create_package("/some/package")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # system.create_isignal_ipdu("Pdu", &package, 8)?;
+    /// for pdu in package.elements_of_type::<ISignalIPdu>() {
+    ///    println!("{:?}", pdu.name());
+    /// }
+    /// # assert_eq!(package.elements_of_type::<ISignalIPdu>().count(), 1);
+    /// # Ok(())}
+    /// ```
+    pub fn elements_of_type<T: AbstractionElement>(&self) -> impl Iterator<Item = T> + Send + use<T> {
+        self.elements().filter_map(|element| T::try_from(element).ok())
+    }
+
     /// create a new `ReferenceBase` in the package
     ///
     /// A `ReferenceBase` is the base of a relative reference to elements in the model.
@@ -1352,6 +1888,77 @@ impl ArPackage {
             .flat_map(|element| element.sub_elements())
             .filter_map(|element| ReferenceBase::try_from(element).ok())
     }
+
+    /// set or remove the category of the package
+    pub fn set_category(&self, category: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        if let Some(category) = category {
+            self.0
+                .get_or_create_sub_element(ElementName::Category)?
+                .set_character_data(category)?;
+        } else {
+            let _ = self.0.remove_sub_element_kind(ElementName::Category);
+        }
+        Ok(())
+    }
+
+    /// get the category of the package
+    #[must_use]
+    pub fn category(&self) -> Option<String> {
+        self.0
+            .get_sub_element(ElementName::Category)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// set the files that the package is serialized into
+    ///
+    /// Elements that are created in the package later on are serialized into the same files.
+    /// Sub-packages and elements that already exist keep their own file membership if they have one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("file1.arxml", AutosarVersion::Autosar_00048);
+    /// let file_2 = model.create_file("file2.arxml", AutosarVersion::Autosar_00048)?;
+    /// let package = model.get_or_create_package("/pkg")?;
+    /// package.set_files(&[file_2.clone()])?;
+    /// assert_eq!(package.files(), vec![file_2]);
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the list of files is empty
+    /// - [`AutosarAbstractionError::ModelError`] the file membership could not be set
+    pub fn set_files(&self, files: &[ArxmlFile]) -> Result<(), AutosarAbstractionError> {
+        if files.is_empty() {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "A package must be part of at least one file".to_string(),
+            ));
+        }
+        self.0
+            .set_file_membership(files.iter().map(ArxmlFile::downgrade).collect())?;
+        Ok(())
+    }
+
+    /// get the files that the package is serialized into
+    #[must_use]
+    pub fn files(&self) -> Vec<ArxmlFile> {
+        element_files(&self.0)
+    }
+}
+
+// get the files an element is serialized into, sorted by file name
+fn element_files(element: &Element) -> Vec<ArxmlFile> {
+    let Ok((_, files)) = element.file_membership() else {
+        return Vec::new();
+    };
+    let mut files: Vec<ArxmlFile> = files.iter().filter_map(WeakArxmlFile::upgrade).collect();
+    files.sort_by_key(ArxmlFile::filename);
+    files
 }
 
 //##################################################################
@@ -1383,7 +1990,11 @@ impl ReferenceBase {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{AutosarModelAbstraction, datatype::*};
+    use crate::{
+        AutosarModelAbstraction, SpecialDataGroup,
+        communication::{ISignalIPdu, SystemSignal},
+        datatype::*,
+    };
     use crate::{System, SystemCategory};
     use autosar_data::AutosarVersion;
 
@@ -1559,6 +2170,12 @@ mod test {
         let interface = package.create_mode_switch_interface("ModeSwitchInterface").unwrap();
         assert_eq!(interface.name().unwrap(), "ModeSwitchInterface");
 
+        // create a new nv block sw component type
+        let component = package
+            .create_nv_block_sw_component_type("NvBlockSwComponentType")
+            .unwrap();
+        assert_eq!(component.name().unwrap(), "NvBlockSwComponentType");
+
         // create a new nv data interface
         let interface = package.create_nv_data_interface("NvDataInterface").unwrap();
         assert_eq!(interface.name().unwrap(), "NvDataInterface");
@@ -1828,6 +2445,31 @@ mod test {
 
         // iterate over sub-packages
         assert_eq!(package.sub_packages().count(), 2);
+
+        // iterate recursively over sub-packages
+        let sub_sub = model.get_or_create_package("/package/sub1/subsub").unwrap();
+        let recursive: Vec<_> = package.sub_packages_recursive().collect();
+        assert_eq!(recursive.len(), 3);
+        assert_eq!(recursive[1], sub_sub);
+    }
+
+    #[test]
+    fn elements_of_type() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+        let package = model.get_or_create_package("/package").unwrap();
+        let sub_package = model.get_or_create_package("/package/sub").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let pdu_1 = system.create_isignal_ipdu("Pdu1", &package, 8).unwrap();
+        let pdu_2 = system.create_isignal_ipdu("Pdu2", &sub_package, 8).unwrap();
+        package.create_system_signal("Signal").unwrap();
+
+        let pdus: Vec<ISignalIPdu> = package.elements_of_type().collect();
+        assert_eq!(pdus, vec![pdu_1.clone()]);
+        assert_eq!(package.elements_of_type::<System>().next(), Some(system));
+
+        let all_pdus: Vec<ISignalIPdu> = model.find_elements_by_type().collect();
+        assert_eq!(all_pdus, vec![pdu_1, pdu_2]);
+        assert_eq!(model.find_elements_by_type::<SystemSignal>().count(), 1);
     }
 
     #[test]
@@ -1858,4 +2500,59 @@ mod test {
                 .is_some()
         );
     }
+
+    #[test]
+    fn category_and_admin_data() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+        let package = model.get_or_create_package("/package").unwrap();
+
+        package.set_category(Some("STANDARD")).unwrap();
+        assert_eq!(package.category().as_deref(), Some("STANDARD"));
+        package.set_category(None).unwrap();
+        assert_eq!(package.category(), None);
+
+        let admin_data = package.get_or_create_admin_data().unwrap();
+        admin_data.create_doc_revision("1.0.0").unwrap();
+        admin_data.create_doc_revision("1.1.0").unwrap();
+        let revision_labels: Vec<_> = package
+            .admin_data()
+            .unwrap()
+            .doc_revisions()
+            .filter_map(|doc_revision| doc_revision.revision_label())
+            .collect();
+        assert_eq!(revision_labels, vec!["1.0.0", "1.1.0"]);
+
+        let sdg = SpecialDataGroup {
+            gid: "Generator".to_string(),
+            values: vec![
+                ("Name".to_string(), "MyGenerator".to_string()),
+                ("Version".to_string(), "2.0".to_string()),
+            ],
+        };
+        admin_data.add_special_data_group(&sdg).unwrap();
+        assert_eq!(
+            package.admin_data().unwrap().special_data_groups().collect::<Vec<_>>(),
+            vec![sdg]
+        );
+    }
+
+    #[test]
+    fn file_membership() {
+        let model = AutosarModelAbstraction::create("file1.arxml", AutosarVersion::Autosar_00048);
+        let file_1 = model.files().next().unwrap();
+        let file_2 = model.create_file("file2.arxml", AutosarVersion::Autosar_00048).unwrap();
+        let package = model.get_or_create_package("/package").unwrap();
+        assert!(package.set_files(&[]).is_err());
+
+        package.set_files(&[file_2.clone()]).unwrap();
+        assert_eq!(package.files(), vec![file_2.clone()]);
+        package.set_files(&[file_1.clone(), file_2.clone()]).unwrap();
+        assert_eq!(package.files(), vec![file_1.clone(), file_2.clone()]);
+
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        model.move_element_to_file(&system, &file_1).unwrap();
+        let (_, system_files) = system.element().file_membership().unwrap();
+        assert!(system_files.contains(&file_1.downgrade()));
+        assert!(!system_files.contains(&file_2.downgrade()));
+    }
 }
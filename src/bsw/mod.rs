@@ -0,0 +1,1085 @@
+//! Basic software module descriptions
+//!
+//! This module contains the description of basic software (BSW) modules: the entry points that a module
+//! provides or requires, and the internal behavior with the schedulable entities and the events that start them.
+//!
+//! # Example
+//!
+//! ```
+//! use autosar_data::*;
+//! use autosar_data_abstraction::*;
+//! use autosar_data_abstraction::bsw::*;
+//!
+//! # fn main() -> Result<(), AutosarAbstractionError> {
+//! let model = AutosarModelAbstraction::create("bsw.arxml", AutosarVersion::LATEST);
+//! let package = model.get_or_create_package("/Bsw")?;
+//!
+//! let main_function = package.create_bsw_module_entry("MyModule_MainFunction", BswCallType::Scheduled)?;
+//! let module = package.create_bsw_module_description("MyModule")?;
+//! module.add_provided_entry(&main_function)?;
+//!
+//! let behavior = module.create_internal_behavior("MyModule_Behavior")?;
+//! let entity = behavior.create_schedulable_entity("MainFunction", &main_function)?;
+//! behavior.create_timing_event("MainFunction_10ms", &entity, 0.01)?;
+//! assert_eq!(behavior.events().count(), 1);
+//! # Ok(())}
+//! ```
+//!
+//! Modes that are exchanged between application software components and a BSW module, e.g. mode requests
+//! which are handled by the BswM, are described by [`BswModeGroup`]s of the module.
+//! Mode switches of a required mode group can start a schedulable entity using a [`BswModeSwitchEvent`].
+//!
+//! ```
+//! use autosar_data::*;
+//! use autosar_data_abstraction::*;
+//! use autosar_data_abstraction::bsw::*;
+//! use autosar_data_abstraction::software_component::*;
+//!
+//! # fn main() -> Result<(), AutosarAbstractionError> {
+//! let model = AutosarModelAbstraction::create("bsw.arxml", AutosarVersion::LATEST);
+//! let package = model.get_or_create_package("/Bsw")?;
+//!
+//! let request_modes = package.create_mode_declaration_group("EcuRunRequest", None)?;
+//! let run = request_modes.create_mode_declaration("RUN")?;
+//! let post_run = request_modes.create_mode_declaration("POST_RUN")?;
+//!
+//! let main_function = package.create_bsw_module_entry("BswM_MainFunction", BswCallType::Scheduled)?;
+//! let bswm = package.create_bsw_module_description("BswM")?;
+//! let mode_request = bswm.create_required_mode_group("EcuRunRequest", &request_modes)?;
+//!
+//! let behavior = bswm.create_internal_behavior("BswM_Behavior")?;
+//! let entity = behavior.create_schedulable_entity("MainFunction", &main_function)?;
+//! let event = behavior.create_mode_switch_event(
+//!     "OnRunToPostRun",
+//!     &entity,
+//!     ModeActivationKind::OnTransition,
+//!     &mode_request,
+//!     &run,
+//!     Some(&post_run),
+//! )?;
+//! assert_eq!(event.mode_declarations(), Some((vec![run, post_run], mode_request)));
+//! # Ok(())}
+//! ```
+
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, Element, IdentifiableAbstractionElement,
+    abstraction_element,
+    software_component::{ModeActivationKind, ModeDeclaration, ModeDeclarationGroup},
+};
+use autosar_data::{ElementName, EnumItem};
+
+//##################################################################
+
+/// A `BswModuleDescription` describes a basic software module
+///
+/// Use [`ArPackage::create_bsw_module_description`] to create a new `BswModuleDescription`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BswModuleDescription(Element);
+abstraction_element!(BswModuleDescription, BswModuleDescription);
+impl IdentifiableAbstractionElement for BswModuleDescription {}
+
+impl BswModuleDescription {
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let module_description = elements.create_named_sub_element(ElementName::BswModuleDescription, name)?;
+
+        Ok(Self(module_description))
+    }
+
+    /// set or remove the module id, which is assigned to each standardized module by AUTOSAR
+    pub fn set_module_id(&self, module_id: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(module_id) = module_id {
+            self.element()
+                .get_or_create_sub_element(ElementName::ModuleId)?
+                .set_character_data(u64::from(module_id))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::ModuleId);
+        }
+        Ok(())
+    }
+
+    /// get the module id
+    #[must_use]
+    pub fn module_id(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::ModuleId)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// add a `BswModuleEntry` that is provided by the module
+    pub fn add_provided_entry(&self, entry: &BswModuleEntry) -> Result<(), AutosarAbstractionError> {
+        add_entry_ref(self.element(), ElementName::ProvidedEntrys, entry)
+    }
+
+    /// iterate over all `BswModuleEntry`s that are provided by the module
+    pub fn provided_entries(&self) -> impl Iterator<Item = BswModuleEntry> + Send + use<> {
+        entry_refs(self.element(), ElementName::ProvidedEntrys)
+    }
+
+    /// add a `BswModuleEntry` that is required by the module, i.e. which is called by the module
+    pub fn add_required_entry(&self, entry: &BswModuleEntry) -> Result<(), AutosarAbstractionError> {
+        add_entry_ref(self.element(), ElementName::RequiredEntrys, entry)
+    }
+
+    /// iterate over all `BswModuleEntry`s that are required by the module
+    pub fn required_entries(&self) -> impl Iterator<Item = BswModuleEntry> + Send + use<> {
+        entry_refs(self.element(), ElementName::RequiredEntrys)
+    }
+
+    /// create a new `BswModeGroup` for a mode that is switched by the module
+    pub fn create_provided_mode_group(
+        &self,
+        name: &str,
+        mode_declaration_group: &ModeDeclarationGroup,
+    ) -> Result<BswModeGroup, AutosarAbstractionError> {
+        let mode_groups = self
+            .element()
+            .get_or_create_sub_element(ElementName::ProvidedModeGroups)?;
+        BswModeGroup::new(name, &mode_groups, mode_declaration_group)
+    }
+
+    /// iterate over all `BswModeGroup`s for modes that are switched by the module
+    pub fn provided_mode_groups(&self) -> impl Iterator<Item = BswModeGroup> + Send + use<> {
+        mode_groups(self.element(), ElementName::ProvidedModeGroups)
+    }
+
+    /// create a new `BswModeGroup` for a mode that is used by the module, e.g. a mode request that is handled by the BswM
+    pub fn create_required_mode_group(
+        &self,
+        name: &str,
+        mode_declaration_group: &ModeDeclarationGroup,
+    ) -> Result<BswModeGroup, AutosarAbstractionError> {
+        let mode_groups = self
+            .element()
+            .get_or_create_sub_element(ElementName::RequiredModeGroups)?;
+        BswModeGroup::new(name, &mode_groups, mode_declaration_group)
+    }
+
+    /// iterate over all `BswModeGroup`s for modes that are used by the module
+    pub fn required_mode_groups(&self) -> impl Iterator<Item = BswModeGroup> + Send + use<> {
+        mode_groups(self.element(), ElementName::RequiredModeGroups)
+    }
+
+    /// create a new `BswInternalBehavior` in the module
+    pub fn create_internal_behavior(&self, name: &str) -> Result<BswInternalBehavior, AutosarAbstractionError> {
+        let behaviors = self
+            .element()
+            .get_or_create_sub_element(ElementName::InternalBehaviors)?;
+        BswInternalBehavior::new(name, &behaviors)
+    }
+
+    /// iterate over all `BswInternalBehavior`s of the module
+    pub fn internal_behaviors(&self) -> impl Iterator<Item = BswInternalBehavior> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::InternalBehaviors)
+            .into_iter()
+            .flat_map(|behaviors| behaviors.sub_elements())
+            .filter_map(|elem| BswInternalBehavior::try_from(elem).ok())
+    }
+}
+
+fn add_entry_ref(
+    element: &Element,
+    container_name: ElementName,
+    entry: &BswModuleEntry,
+) -> Result<(), AutosarAbstractionError> {
+    element
+        .get_or_create_sub_element(container_name)?
+        .create_sub_element(ElementName::BswModuleEntryRefConditional)?
+        .create_sub_element(ElementName::BswModuleEntryRef)?
+        .set_reference_target(entry.element())?;
+    Ok(())
+}
+
+fn mode_groups(element: &Element, container_name: ElementName) -> impl Iterator<Item = BswModeGroup> + Send + use<> {
+    element
+        .get_sub_element(container_name)
+        .into_iter()
+        .flat_map(|container| container.sub_elements())
+        .filter_map(|elem| BswModeGroup::try_from(elem).ok())
+}
+
+fn entry_refs(element: &Element, container_name: ElementName) -> impl Iterator<Item = BswModuleEntry> + Send + use<> {
+    element
+        .get_sub_element(container_name)
+        .into_iter()
+        .flat_map(|container| container.sub_elements())
+        .filter_map(|conditional| conditional.get_sub_element(ElementName::BswModuleEntryRef))
+        .filter_map(|entry_ref| entry_ref.get_reference_target().ok())
+        .filter_map(|elem| BswModuleEntry::try_from(elem).ok())
+}
+
+//##################################################################
+
+/// A `BswModuleEntry` is a function that is provided or called by a basic software module
+///
+/// Use [`ArPackage::create_bsw_module_entry`] to create a new `BswModuleEntry`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BswModuleEntry(Element);
+abstraction_element!(BswModuleEntry, BswModuleEntry);
+impl IdentifiableAbstractionElement for BswModuleEntry {}
+
+impl BswModuleEntry {
+    pub(crate) fn new(
+        name: &str,
+        package: &ArPackage,
+        call_type: BswCallType,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let entry_elem = elements.create_named_sub_element(ElementName::BswModuleEntry, name)?;
+
+        let entry = Self(entry_elem);
+        entry.set_call_type(call_type)?;
+
+        Ok(entry)
+    }
+
+    /// set the call type of the entry
+    pub fn set_call_type(&self, call_type: BswCallType) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::CallType)?
+            .set_character_data::<EnumItem>(call_type.into())?;
+        Ok(())
+    }
+
+    /// get the call type of the entry
+    #[must_use]
+    pub fn call_type(&self) -> Option<BswCallType> {
+        self.element()
+            .get_sub_element(ElementName::CallType)?
+            .character_data()?
+            .enum_value()
+            .and_then(|enumitem| BswCallType::try_from(enumitem).ok())
+    }
+
+    /// set or remove the service id of the entry
+    pub fn set_service_id(&self, service_id: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(service_id) = service_id {
+            self.element()
+                .get_or_create_sub_element(ElementName::ServiceId)?
+                .set_character_data(u64::from(service_id))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::ServiceId);
+        }
+        Ok(())
+    }
+
+    /// get the service id of the entry
+    #[must_use]
+    pub fn service_id(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::ServiceId)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// set or remove the flag that indicates if the entry is reentrant
+    pub fn set_is_reentrant(&self, is_reentrant: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        if let Some(is_reentrant) = is_reentrant {
+            self.element()
+                .get_or_create_sub_element(ElementName::IsReentrant)?
+                .set_character_data(is_reentrant)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::IsReentrant);
+        }
+        Ok(())
+    }
+
+    /// check if the entry is reentrant
+    #[must_use]
+    pub fn is_reentrant(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::IsReentrant)?
+            .character_data()?
+            .parse_bool()
+    }
+
+    /// set or remove the flag that indicates if the entry is synchronous
+    pub fn set_is_synchronous(&self, is_synchronous: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        if let Some(is_synchronous) = is_synchronous {
+            self.element()
+                .get_or_create_sub_element(ElementName::IsSynchronous)?
+                .set_character_data(is_synchronous)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::IsSynchronous);
+        }
+        Ok(())
+    }
+
+    /// check if the entry is synchronous
+    #[must_use]
+    pub fn is_synchronous(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::IsSynchronous)?
+            .character_data()?
+            .parse_bool()
+    }
+}
+
+//##################################################################
+
+/// A `BswModeGroup` is a mode that is provided or required by a [`BswModuleDescription`]
+///
+/// aka ModeDeclarationGroupPrototype in the AUTOSAR standard
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BswModeGroup(Element);
+abstraction_element!(BswModeGroup, ModeDeclarationGroupPrototype);
+impl IdentifiableAbstractionElement for BswModeGroup {}
+
+impl BswModeGroup {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        mode_declaration_group: &ModeDeclarationGroup,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let mode_group_elem = parent.create_named_sub_element(ElementName::ModeDeclarationGroupPrototype, name)?;
+        let mode_group = Self(mode_group_elem);
+        mode_group.set_mode_declaration_group(mode_declaration_group)?;
+
+        Ok(mode_group)
+    }
+
+    /// set the `ModeDeclarationGroup` of the mode group
+    pub fn set_mode_declaration_group(
+        &self,
+        mode_declaration_group: &ModeDeclarationGroup,
+    ) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::TypeTref)?
+            .set_reference_target(mode_declaration_group.element())?;
+        Ok(())
+    }
+
+    /// get the `ModeDeclarationGroup` of the mode group
+    #[must_use]
+    pub fn mode_declaration_group(&self) -> Option<ModeDeclarationGroup> {
+        let mode_declaration_group_elem = self
+            .element()
+            .get_sub_element(ElementName::TypeTref)?
+            .get_reference_target()
+            .ok()?;
+        ModeDeclarationGroup::try_from(mode_declaration_group_elem).ok()
+    }
+
+    /// get the `BswModuleDescription` that contains the mode group
+    #[must_use]
+    pub fn bsw_module_description(&self) -> Option<BswModuleDescription> {
+        let parent = self.element().named_parent().ok()??;
+        BswModuleDescription::try_from(parent).ok()
+    }
+}
+
+//##################################################################
+
+/// The call type of a [`BswModuleEntry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BswCallType {
+    /// the entry is a callback, which is called by another module to notify the module
+    Callback,
+    /// the entry is an interrupt service routine
+    Interrupt,
+    /// the entry is a regular API function
+    Regular,
+    /// the entry is a scheduled function, e.g. a main function
+    Scheduled,
+}
+
+impl From<BswCallType> for EnumItem {
+    fn from(value: BswCallType) -> Self {
+        match value {
+            BswCallType::Callback => EnumItem::Callback,
+            BswCallType::Interrupt => EnumItem::Interrupt,
+            BswCallType::Regular => EnumItem::Regular,
+            BswCallType::Scheduled => EnumItem::Scheduled,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for BswCallType {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::Callback => Ok(BswCallType::Callback),
+            EnumItem::Interrupt => Ok(BswCallType::Interrupt),
+            EnumItem::Regular => Ok(BswCallType::Regular),
+            EnumItem::Scheduled => Ok(BswCallType::Scheduled),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "BswCallType".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
+/// The `BswInternalBehavior` of a [`BswModuleDescription`] contains the entities of the module and the events that start them
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BswInternalBehavior(Element);
+abstraction_element!(BswInternalBehavior, BswInternalBehavior);
+impl IdentifiableAbstractionElement for BswInternalBehavior {}
+
+impl BswInternalBehavior {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let behavior = parent.create_named_sub_element(ElementName::BswInternalBehavior, name)?;
+
+        Ok(Self(behavior))
+    }
+
+    /// get the `BswModuleDescription` that contains the behavior
+    #[must_use]
+    pub fn bsw_module_description(&self) -> Option<BswModuleDescription> {
+        let parent = self.element().named_parent().ok()??;
+        BswModuleDescription::try_from(parent).ok()
+    }
+
+    /// create a new `BswSchedulableEntity`, which implements the given `BswModuleEntry`
+    pub fn create_schedulable_entity(
+        &self,
+        name: &str,
+        implemented_entry: &BswModuleEntry,
+    ) -> Result<BswSchedulableEntity, AutosarAbstractionError> {
+        let entities = self.element().get_or_create_sub_element(ElementName::Entitys)?;
+        BswSchedulableEntity::new(name, &entities, implemented_entry)
+    }
+
+    /// create a new `BswCalledEntity`, which implements the given `BswModuleEntry`
+    pub fn create_called_entity(
+        &self,
+        name: &str,
+        implemented_entry: &BswModuleEntry,
+    ) -> Result<BswCalledEntity, AutosarAbstractionError> {
+        let entities = self.element().get_or_create_sub_element(ElementName::Entitys)?;
+        BswCalledEntity::new(name, &entities, implemented_entry)
+    }
+
+    /// iterate over all entities of the behavior
+    pub fn entities(&self) -> impl Iterator<Item = BswModuleEntity> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::Entitys)
+            .into_iter()
+            .flat_map(|entities| entities.sub_elements())
+            .filter_map(|elem| BswModuleEntity::try_from(elem).ok())
+    }
+
+    /// create a timing event that periodically starts a `BswSchedulableEntity`
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the period is not a positive number
+    pub fn create_timing_event(
+        &self,
+        name: &str,
+        entity: &BswSchedulableEntity,
+        period: f64,
+    ) -> Result<BswTimingEvent, AutosarAbstractionError> {
+        let events = self.element().get_or_create_sub_element(ElementName::Events)?;
+        BswTimingEvent::new(name, &events, entity, period)
+    }
+
+    /// create a background event that starts a `BswSchedulableEntity` for background processing
+    pub fn create_background_event(
+        &self,
+        name: &str,
+        entity: &BswSchedulableEntity,
+    ) -> Result<BswBackgroundEvent, AutosarAbstractionError> {
+        let events = self.element().get_or_create_sub_element(ElementName::Events)?;
+        BswBackgroundEvent::new(name, &events, entity)
+    }
+
+    /// create a mode switch event that starts a `BswSchedulableEntity` when the mode of a `BswModeGroup` is switched
+    ///
+    /// The second mode declaration must be provided if the activation kind is `OnTransition`.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] a mode declaration is not part of the `ModeDeclarationGroup`
+    ///   of the mode group, or the second mode declaration is missing for `OnTransition`
+    pub fn create_mode_switch_event(
+        &self,
+        name: &str,
+        entity: &BswSchedulableEntity,
+        activation: ModeActivationKind,
+        mode_group: &BswModeGroup,
+        mode_declaration: &ModeDeclaration,
+        second_mode_declaration: Option<&ModeDeclaration>,
+    ) -> Result<BswModeSwitchEvent, AutosarAbstractionError> {
+        let events = self.element().get_or_create_sub_element(ElementName::Events)?;
+        BswModeSwitchEvent::new(
+            name,
+            &events,
+            entity,
+            activation,
+            mode_group,
+            mode_declaration,
+            second_mode_declaration,
+        )
+    }
+
+    /// iterate over all events of the behavior
+    pub fn events(&self) -> impl Iterator<Item = BswEvent> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::Events)
+            .into_iter()
+            .flat_map(|events| events.sub_elements())
+            .filter_map(|elem| BswEvent::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// The common functionality of all BSW module entities
+pub trait AbstractBswModuleEntity: AbstractionElement {
+    /// set the `BswModuleEntry` that is implemented by the entity
+    fn set_implemented_entry(&self, entry: &BswModuleEntry) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::ImplementedEntryRef)?
+            .set_reference_target(entry.element())?;
+        Ok(())
+    }
+
+    /// get the `BswModuleEntry` that is implemented by the entity
+    fn implemented_entry(&self) -> Option<BswModuleEntry> {
+        let entry_elem = self
+            .element()
+            .get_sub_element(ElementName::ImplementedEntryRef)?
+            .get_reference_target()
+            .ok()?;
+        BswModuleEntry::try_from(entry_elem).ok()
+    }
+
+    /// get the `BswInternalBehavior` that contains the entity
+    fn bsw_internal_behavior(&self) -> Option<BswInternalBehavior> {
+        let parent = self.element().named_parent().ok()??;
+        BswInternalBehavior::try_from(parent).ok()
+    }
+}
+
+//##################################################################
+
+/// A `BswSchedulableEntity` is a main function of a BSW module, which is started by a [`BswEvent`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BswSchedulableEntity(Element);
+abstraction_element!(BswSchedulableEntity, BswSchedulableEntity);
+impl IdentifiableAbstractionElement for BswSchedulableEntity {}
+impl AbstractBswModuleEntity for BswSchedulableEntity {}
+
+impl BswSchedulableEntity {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        implemented_entry: &BswModuleEntry,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let entity_elem = parent.create_named_sub_element(ElementName::BswSchedulableEntity, name)?;
+        let entity = Self(entity_elem);
+
+        let result = entity.set_implemented_entry(implemented_entry);
+        if let Err(err) = result {
+            parent.remove_sub_element(entity.0)?;
+            return Err(err);
+        }
+
+        Ok(entity)
+    }
+}
+
+//##################################################################
+
+/// A `BswCalledEntity` is a function of a BSW module, which is called directly by other modules
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BswCalledEntity(Element);
+abstraction_element!(BswCalledEntity, BswCalledEntity);
+impl IdentifiableAbstractionElement for BswCalledEntity {}
+impl AbstractBswModuleEntity for BswCalledEntity {}
+
+impl BswCalledEntity {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        implemented_entry: &BswModuleEntry,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let entity_elem = parent.create_named_sub_element(ElementName::BswCalledEntity, name)?;
+        let entity = Self(entity_elem);
+
+        let result = entity.set_implemented_entry(implemented_entry);
+        if let Err(err) = result {
+            parent.remove_sub_element(entity.0)?;
+            return Err(err);
+        }
+
+        Ok(entity)
+    }
+}
+
+//##################################################################
+
+/// All entities of a [`BswInternalBehavior`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BswModuleEntity {
+    /// a function that is called directly
+    Called(BswCalledEntity),
+    /// a main function that is started by an event
+    Schedulable(BswSchedulableEntity),
+}
+
+impl AbstractionElement for BswModuleEntity {
+    fn element(&self) -> &Element {
+        match self {
+            BswModuleEntity::Called(entity) => entity.element(),
+            BswModuleEntity::Schedulable(entity) => entity.element(),
+        }
+    }
+}
+
+impl TryFrom<Element> for BswModuleEntity {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        match element.element_name() {
+            ElementName::BswCalledEntity => Ok(BswModuleEntity::Called(BswCalledEntity(element))),
+            ElementName::BswSchedulableEntity => Ok(BswModuleEntity::Schedulable(BswSchedulableEntity(element))),
+            _ => Err(AutosarAbstractionError::ConversionError {
+                element,
+                dest: "BswModuleEntity".to_string(),
+            }),
+        }
+    }
+}
+
+impl IdentifiableAbstractionElement for BswModuleEntity {}
+impl AbstractBswModuleEntity for BswModuleEntity {}
+
+//##################################################################
+
+/// The common functionality of all BSW events
+pub trait AbstractBswEvent: AbstractionElement {
+    /// set the `BswSchedulableEntity` that is started by the event
+    fn set_starts_on_event(&self, entity: &BswSchedulableEntity) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::StartsOnEventRef)?
+            .set_reference_target(entity.element())?;
+        Ok(())
+    }
+
+    /// get the `BswSchedulableEntity` that is started by the event
+    fn starts_on_event(&self) -> Option<BswSchedulableEntity> {
+        let entity_elem = self
+            .element()
+            .get_sub_element(ElementName::StartsOnEventRef)?
+            .get_reference_target()
+            .ok()?;
+        BswSchedulableEntity::try_from(entity_elem).ok()
+    }
+
+    /// get the `BswInternalBehavior` that contains the event
+    fn bsw_internal_behavior(&self) -> Option<BswInternalBehavior> {
+        let parent = self.element().named_parent().ok()??;
+        BswInternalBehavior::try_from(parent).ok()
+    }
+}
+
+//##################################################################
+
+/// A `BswTimingEvent` periodically starts a [`BswSchedulableEntity`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BswTimingEvent(Element);
+abstraction_element!(BswTimingEvent, BswTimingEvent);
+impl IdentifiableAbstractionElement for BswTimingEvent {}
+impl AbstractBswEvent for BswTimingEvent {}
+
+impl BswTimingEvent {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        entity: &BswSchedulableEntity,
+        period: f64,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let event_elem = parent.create_named_sub_element(ElementName::BswTimingEvent, name)?;
+        let event = Self(event_elem);
+
+        let result = event
+            .set_starts_on_event(entity)
+            .and_then(|()| event.set_period(period));
+        if let Err(err) = result {
+            parent.remove_sub_element(event.0)?;
+            return Err(err);
+        }
+
+        Ok(event)
+    }
+
+    /// set the period of the event in seconds
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the period is not a positive number
+    pub fn set_period(&self, period: f64) -> Result<(), AutosarAbstractionError> {
+        if !period.is_finite() || period <= 0.0 {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "The period of a BswTimingEvent must be positive, got {period}"
+            )));
+        }
+        self.element()
+            .get_or_create_sub_element(ElementName::Period)?
+            .set_character_data(period)?;
+        Ok(())
+    }
+
+    /// get the period of the event in seconds
+    #[must_use]
+    pub fn period(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::Period)?
+            .character_data()?
+            .parse_float()
+    }
+}
+
+//##################################################################
+
+/// A `BswBackgroundEvent` starts a [`BswSchedulableEntity`] for background processing at low priority
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BswBackgroundEvent(Element);
+abstraction_element!(BswBackgroundEvent, BswBackgroundEvent);
+impl IdentifiableAbstractionElement for BswBackgroundEvent {}
+impl AbstractBswEvent for BswBackgroundEvent {}
+
+impl BswBackgroundEvent {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        entity: &BswSchedulableEntity,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let event_elem = parent.create_named_sub_element(ElementName::BswBackgroundEvent, name)?;
+        let event = Self(event_elem);
+
+        let result = event.set_starts_on_event(entity);
+        if let Err(err) = result {
+            parent.remove_sub_element(event.0)?;
+            return Err(err);
+        }
+
+        Ok(event)
+    }
+}
+
+//##################################################################
+
+/// A `BswModeSwitchEvent` starts a [`BswSchedulableEntity`] when the mode of a [`BswModeGroup`] is switched
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BswModeSwitchEvent(Element);
+abstraction_element!(BswModeSwitchEvent, BswModeSwitchEvent);
+impl IdentifiableAbstractionElement for BswModeSwitchEvent {}
+impl AbstractBswEvent for BswModeSwitchEvent {}
+
+impl BswModeSwitchEvent {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        entity: &BswSchedulableEntity,
+        activation: ModeActivationKind,
+        mode_group: &BswModeGroup,
+        mode_declaration: &ModeDeclaration,
+        second_mode_declaration: Option<&ModeDeclaration>,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let event_elem = parent.create_named_sub_element(ElementName::BswModeSwitchEvent, name)?;
+        let event = Self(event_elem);
+
+        let result = event
+            .set_starts_on_event(entity)
+            .and_then(|()| event.set_mode_activation_kind(activation))
+            .and_then(|()| event.set_mode_declaration(mode_group, mode_declaration, second_mode_declaration));
+        if let Err(err) = result {
+            // this operation could fail if bad parameters are provided; in this case we remove the event
+            parent.remove_sub_element(event.0)?;
+            return Err(err);
+        }
+
+        Ok(event)
+    }
+
+    /// set the `ModeActivationKind` that controls when the event is triggered
+    pub fn set_mode_activation_kind(&self, activation: ModeActivationKind) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::Activation)?
+            .set_character_data::<EnumItem>(activation.into())?;
+        Ok(())
+    }
+
+    /// get the `ModeActivationKind` that controls when the event is triggered
+    #[must_use]
+    pub fn mode_activation_kind(&self) -> Option<ModeActivationKind> {
+        let value = self
+            .element()
+            .get_sub_element(ElementName::Activation)?
+            .character_data()?
+            .enum_value()?;
+        ModeActivationKind::try_from(value).ok()
+    }
+
+    /// set the `ModeDeclaration` that triggers the event
+    ///
+    /// The mode declarations must be part of the `ModeDeclarationGroup` of the `BswModeGroup`.
+    /// The second mode must be provided if the activation kind `OnTransition` is configured.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] a mode declaration is not part of the `ModeDeclarationGroup`,
+    ///   or the second mode is missing for the activation kind `OnTransition`
+    pub fn set_mode_declaration(
+        &self,
+        mode_group: &BswModeGroup,
+        mode_declaration: &ModeDeclaration,
+        second_mode_declaration: Option<&ModeDeclaration>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if self.mode_activation_kind() == Some(ModeActivationKind::OnTransition) && second_mode_declaration.is_none() {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The activation kind OnTransition requires a second mode declaration".to_string(),
+            ));
+        }
+        let Some(mode_declaration_group) = mode_group.mode_declaration_group() else {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "BswModeGroup {} is invalid: the reference a ModeDeclarationGroup is missing",
+                mode_group.name().as_deref().unwrap_or("(invalid)")
+            )));
+        };
+        for mode in std::iter::once(mode_declaration).chain(second_mode_declaration) {
+            if mode.mode_declaration_group()? != mode_declaration_group {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "ModeDeclaration {} is not part of ModeDeclarationGroup {}",
+                    mode.name().as_deref().unwrap_or("(invalid)"),
+                    mode_declaration_group.name().as_deref().unwrap_or("(invalid)")
+                )));
+            }
+        }
+
+        let _ = self.element().remove_sub_element_kind(ElementName::ModeIrefs);
+        let mode_irefs_elem = self.element().create_sub_element(ElementName::ModeIrefs)?;
+        for mode in std::iter::once(mode_declaration).chain(second_mode_declaration) {
+            let mode_iref = mode_irefs_elem.create_sub_element(ElementName::ModeIref)?;
+            mode_iref
+                .create_sub_element(ElementName::ContextModeDeclarationGroupRef)?
+                .set_reference_target(mode_group.element())?;
+            mode_iref
+                .create_sub_element(ElementName::TargetModeRef)?
+                .set_reference_target(mode.element())?;
+        }
+
+        Ok(())
+    }
+
+    /// get the `ModeDeclaration`s that trigger the event, together with the `BswModeGroup` they belong to
+    ///
+    /// The list contains either one or two `ModeDeclaration`s depending on the `ModeActivationKind`.
+    #[must_use]
+    pub fn mode_declarations(&self) -> Option<(Vec<ModeDeclaration>, BswModeGroup)> {
+        let mode_irefs_elem = self.element().get_sub_element(ElementName::ModeIrefs)?;
+        let mode_declarations = mode_irefs_elem
+            .sub_elements()
+            .filter_map(|mode_iref_elem| {
+                mode_iref_elem
+                    .get_sub_element(ElementName::TargetModeRef)
+                    .and_then(|tref_elem| tref_elem.get_reference_target().ok())
+                    .and_then(|elem| ModeDeclaration::try_from(elem).ok())
+            })
+            .collect();
+        let mode_group_elem = mode_irefs_elem
+            .get_sub_element(ElementName::ModeIref)?
+            .get_sub_element(ElementName::ContextModeDeclarationGroupRef)?
+            .get_reference_target()
+            .ok()?;
+        let mode_group = BswModeGroup::try_from(mode_group_elem).ok()?;
+        Some((mode_declarations, mode_group))
+    }
+}
+
+//##################################################################
+
+/// All events of a [`BswInternalBehavior`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BswEvent {
+    /// starts an entity for background processing
+    Background(BswBackgroundEvent),
+    /// starts an entity when a mode is switched
+    ModeSwitch(BswModeSwitchEvent),
+    /// starts an entity periodically
+    Timing(BswTimingEvent),
+}
+
+impl AbstractionElement for BswEvent {
+    fn element(&self) -> &Element {
+        match self {
+            BswEvent::Background(event) => event.element(),
+            BswEvent::ModeSwitch(event) => event.element(),
+            BswEvent::Timing(event) => event.element(),
+        }
+    }
+}
+
+impl TryFrom<Element> for BswEvent {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        match element.element_name() {
+            ElementName::BswBackgroundEvent => Ok(BswEvent::Background(BswBackgroundEvent(element))),
+            ElementName::BswModeSwitchEvent => Ok(BswEvent::ModeSwitch(BswModeSwitchEvent(element))),
+            ElementName::BswTimingEvent => Ok(BswEvent::Timing(BswTimingEvent(element))),
+            _ => Err(AutosarAbstractionError::ConversionError {
+                element,
+                dest: "BswEvent".to_string(),
+            }),
+        }
+    }
+}
+
+impl IdentifiableAbstractionElement for BswEvent {}
+impl AbstractBswEvent for BswEvent {}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::AutosarModelAbstraction;
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn bsw_module_description() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/Bsw").unwrap();
+
+        let main_function = package
+            .create_bsw_module_entry("Mod_MainFunction", BswCallType::Scheduled)
+            .unwrap();
+        main_function.set_service_id(Some(0x10)).unwrap();
+        main_function.set_is_reentrant(Some(false)).unwrap();
+        main_function.set_is_synchronous(Some(true)).unwrap();
+        assert_eq!(main_function.call_type(), Some(BswCallType::Scheduled));
+        assert_eq!(main_function.service_id(), Some(0x10));
+        assert_eq!(main_function.is_reentrant(), Some(false));
+        assert_eq!(main_function.is_synchronous(), Some(true));
+        main_function.set_service_id(None).unwrap();
+        assert_eq!(main_function.service_id(), None);
+
+        let api_function = package
+            .create_bsw_module_entry("Mod_Transmit", BswCallType::Regular)
+            .unwrap();
+        let det_function = package
+            .create_bsw_module_entry("Det_ReportError", BswCallType::Regular)
+            .unwrap();
+
+        let module = package.create_bsw_module_description("Mod").unwrap();
+        module.set_module_id(Some(255)).unwrap();
+        assert_eq!(module.module_id(), Some(255));
+        module.add_provided_entry(&main_function).unwrap();
+        module.add_provided_entry(&api_function).unwrap();
+        module.add_required_entry(&det_function).unwrap();
+        assert_eq!(
+            module.provided_entries().collect::<Vec<_>>(),
+            vec![main_function.clone(), api_function.clone()]
+        );
+        assert_eq!(module.required_entries().collect::<Vec<_>>(), vec![det_function]);
+
+        let behavior = module.create_internal_behavior("Mod_Behavior").unwrap();
+        assert_eq!(module.internal_behaviors().next(), Some(behavior.clone()));
+        assert_eq!(behavior.bsw_module_description(), Some(module));
+
+        let schedulable = behavior
+            .create_schedulable_entity("MainFunction", &main_function)
+            .unwrap();
+        let called = behavior.create_called_entity("Transmit", &api_function).unwrap();
+        assert_eq!(schedulable.implemented_entry(), Some(main_function));
+        assert_eq!(called.bsw_internal_behavior(), Some(behavior.clone()));
+        assert_eq!(
+            behavior.entities().collect::<Vec<_>>(),
+            vec![
+                BswModuleEntity::Schedulable(schedulable.clone()),
+                BswModuleEntity::Called(called)
+            ]
+        );
+
+        let timing_event = behavior
+            .create_timing_event("MainFunction_10ms", &schedulable, 0.01)
+            .unwrap();
+        assert_eq!(timing_event.period(), Some(0.01));
+        assert!(timing_event.set_period(0.0).is_err());
+        assert_eq!(timing_event.period(), Some(0.01));
+        // an invalid period is rejected without leaving an incomplete event behind
+        assert!(behavior.create_timing_event("Invalid", &schedulable, -0.01).is_err());
+        assert_eq!(behavior.events().count(), 1);
+        assert_eq!(timing_event.starts_on_event(), Some(schedulable.clone()));
+        let background_event = behavior.create_background_event("Background", &schedulable).unwrap();
+        assert_eq!(background_event.bsw_internal_behavior(), Some(behavior.clone()));
+        assert_eq!(
+            behavior.events().collect::<Vec<_>>(),
+            vec![BswEvent::Timing(timing_event), BswEvent::Background(background_event)]
+        );
+    }
+
+    #[test]
+    fn bsw_mode_groups() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/Bsw").unwrap();
+
+        let request_modes = package.create_mode_declaration_group("RunRequest", None).unwrap();
+        let run = request_modes.create_mode_declaration("RUN").unwrap();
+        let post_run = request_modes.create_mode_declaration("POST_RUN").unwrap();
+        let other_modes = package.create_mode_declaration_group("Other", None).unwrap();
+        let other = other_modes.create_mode_declaration("OTHER").unwrap();
+
+        let main_function = package
+            .create_bsw_module_entry("BswM_MainFunction", BswCallType::Scheduled)
+            .unwrap();
+        let module = package.create_bsw_module_description("BswM").unwrap();
+        let required = module.create_required_mode_group("RunRequest", &request_modes).unwrap();
+        let provided = module.create_provided_mode_group("Other", &other_modes).unwrap();
+        assert_eq!(
+            module.required_mode_groups().collect::<Vec<_>>(),
+            vec![required.clone()]
+        );
+        assert_eq!(
+            module.provided_mode_groups().collect::<Vec<_>>(),
+            vec![provided.clone()]
+        );
+        assert_eq!(required.mode_declaration_group(), Some(request_modes));
+        assert_eq!(provided.bsw_module_description(), Some(module.clone()));
+
+        let behavior = module.create_internal_behavior("BswM_Behavior").unwrap();
+        let entity = behavior
+            .create_schedulable_entity("MainFunction", &main_function)
+            .unwrap();
+
+        // the mode declaration must be part of the mode declaration group of the mode group
+        let result =
+            behavior.create_mode_switch_event("Invalid", &entity, ModeActivationKind::OnEntry, &required, &other, None);
+        assert!(result.is_err());
+        assert_eq!(behavior.events().count(), 0);
+        // a transition needs a second mode
+        let result = behavior.create_mode_switch_event(
+            "Invalid",
+            &entity,
+            ModeActivationKind::OnTransition,
+            &required,
+            &run,
+            None,
+        );
+        assert!(result.is_err());
+        assert_eq!(behavior.events().count(), 0);
+
+        let event = behavior
+            .create_mode_switch_event("OnRun", &entity, ModeActivationKind::OnEntry, &required, &run, None)
+            .unwrap();
+        assert_eq!(event.mode_activation_kind(), Some(ModeActivationKind::OnEntry));
+        assert_eq!(event.starts_on_event(), Some(entity));
+        assert_eq!(event.mode_declarations(), Some((vec![run.clone()], required.clone())));
+
+        event
+            .set_mode_activation_kind(ModeActivationKind::OnTransition)
+            .unwrap();
+        event.set_mode_declaration(&required, &run, Some(&post_run)).unwrap();
+        assert_eq!(event.mode_declarations(), Some((vec![run, post_run], required)));
+        assert_eq!(behavior.events().collect::<Vec<_>>(), vec![BswEvent::ModeSwitch(event)]);
+    }
+}
@@ -0,0 +1,265 @@
+use autosar_data::{AutosarModel, Element};
+use std::{cell::RefCell, collections::HashMap, marker::PhantomData};
+
+//##################################################################
+
+thread_local! {
+    static SESSION_CACHES: RefCell<Vec<SessionCache>> = const { RefCell::new(Vec::new()) };
+}
+
+// caches that are used while a BulkEditSession is active for a model
+struct SessionCache {
+    model: AutosarModel,
+    sessions: usize,
+    // full path of the initial name -> last counter that was used to make the name unique
+    counters: HashMap<String, usize>,
+    // reverse reference index: path of the referenced element -> referring elements and their parents
+    references: HashMap<String, ReferenceParents>,
+}
+
+// the referring elements of a target, together with the (named parent, parent) of each reference
+struct ReferenceParents {
+    referrers: Vec<Element>,
+    parents: Vec<(Element, Element)>,
+}
+
+/// A `BulkEditSession` speeds up the creation of large numbers of elements
+///
+/// Many elements are created with automatically generated names, e.g. `PduTriggering`s or mappings.
+/// If a generated name already exists, a numeric suffix is appended, and the suffixes are tried one by one.
+/// When thousands of elements with the same base name are created, this becomes very slow.
+///
+/// While a `BulkEditSession` is active, the last used suffix of each generated name is cached, so that
+/// the search for a free name can continue where it stopped the last time.
+/// The generated names are still checked for uniqueness, so the cache can never cause a name collision.
+/// If elements are removed during a session, then their names may not be reused until the session ends.
+///
+/// The session also maintains a reverse reference index, which maps each referenced element to the parents of
+/// the references to it. Lookups such as [`AbstractPdu::pdu_triggerings`](crate::communication::AbstractPdu::pdu_triggerings)
+/// use the index instead of walking up the element tree from each reference.
+/// An entry is recomputed if the references to its element have changed, and the whole index is discarded
+/// whenever an element is renamed with `set_name` or removed with `remove`.
+///
+/// The session is active until it is dropped. It only affects the current thread and the model it was created for.
+///
+/// Use [`AutosarModelAbstraction::bulk_edit_session`](crate::AutosarModelAbstraction::bulk_edit_session)
+/// to start a `BulkEditSession`.
+#[derive(Debug)]
+pub struct BulkEditSession {
+    model: AutosarModel,
+    // the session uses thread local storage, so it must not be sent to a different thread
+    _not_send: PhantomData<*const ()>,
+}
+
+impl BulkEditSession {
+    pub(crate) fn new(model: &AutosarModel) -> Self {
+        SESSION_CACHES.with_borrow_mut(|caches| {
+            if let Some(cache) = caches.iter_mut().find(|cache| cache.model == *model) {
+                cache.sessions += 1;
+            } else {
+                caches.push(SessionCache {
+                    model: model.clone(),
+                    sessions: 1,
+                    counters: HashMap::new(),
+                    references: HashMap::new(),
+                });
+            }
+        });
+        Self {
+            model: model.clone(),
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for BulkEditSession {
+    fn drop(&mut self) {
+        SESSION_CACHES.with_borrow_mut(|caches| {
+            if let Some(pos) = caches.iter().position(|cache| cache.model == self.model) {
+                caches[pos].sessions -= 1;
+                if caches[pos].sessions == 0 {
+                    caches.swap_remove(pos);
+                }
+            }
+        });
+    }
+}
+
+// get the cached counter for the given initial path, if a session is active for the model
+pub(crate) fn cached_name_counter(model: &AutosarModel, initial_path: &str) -> usize {
+    SESSION_CACHES.with_borrow(|caches| {
+        caches
+            .iter()
+            .find(|cache| cache.model == *model)
+            .and_then(|cache| cache.counters.get(initial_path).copied())
+            .unwrap_or(0)
+    })
+}
+
+// store the counter that was used for the given initial path, if a session is active for the model
+pub(crate) fn update_name_counter(model: &AutosarModel, initial_path: String, counter: usize) {
+    SESSION_CACHES.with_borrow_mut(|caches| {
+        if let Some(cache) = caches.iter_mut().find(|cache| cache.model == *model) {
+            cache.counters.insert(initial_path, counter);
+        }
+    });
+}
+
+// get the indexed parents of the references to the given path, if a session is active for the model
+// and the referring elements have not changed since the entry was created
+pub(crate) fn cached_reference_parents(
+    model: &AutosarModel,
+    target_path: &str,
+    referrers: &[Element],
+) -> Option<Vec<(Element, Element)>> {
+    SESSION_CACHES.with_borrow(|caches| {
+        let cache = caches.iter().find(|cache| cache.model == *model)?;
+        let entry = cache.references.get(target_path)?;
+        (entry.referrers == referrers).then(|| entry.parents.clone())
+    })
+}
+
+// store the parents of the references to the given path in the index, if a session is active for the model
+pub(crate) fn update_reference_parents(
+    model: &AutosarModel,
+    target_path: String,
+    referrers: Vec<Element>,
+    parents: Vec<(Element, Element)>,
+) {
+    SESSION_CACHES.with_borrow_mut(|caches| {
+        if let Some(cache) = caches.iter_mut().find(|cache| cache.model == *model) {
+            cache
+                .references
+                .insert(target_path, ReferenceParents { referrers, parents });
+        }
+    });
+}
+
+// discard the reverse reference index of the model, because paths or references are changing
+pub(crate) fn invalidate_reference_index(model: &AutosarModel) {
+    SESSION_CACHES.with_borrow_mut(|caches| {
+        if let Some(cache) = caches.iter_mut().find(|cache| cache.model == *model) {
+            cache.references.clear();
+        }
+    });
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AbstractionElement, AutosarModelAbstraction, ByteOrder, IdentifiableAbstractionElement, SystemCategory,
+        communication::{AbstractPdu, CanAddressingMode, CanFrameType},
+        make_unique_name,
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn bulk_edit_session() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        package.create_system_signal("Sig").unwrap();
+
+        let session = model.bulk_edit_session();
+        // nested sessions are allowed
+        let inner_session = model.bulk_edit_session();
+        let mut signals = Vec::new();
+        for idx in 1..=10 {
+            let name = make_unique_name(model.model(), "/pkg", "Sig").unwrap();
+            assert_eq!(name, format!("Sig_{idx}"));
+            signals.push(package.create_system_signal(&name).unwrap());
+        }
+        drop(inner_session);
+
+        // the cache does not prevent the detection of existing names
+        package.create_system_signal("Sig_11").unwrap();
+        assert_eq!(make_unique_name(model.model(), "/pkg", "Sig").unwrap(), "Sig_12");
+
+        // removed names are not reused while the session is active
+        signals.remove(2).remove(false).unwrap();
+        assert_eq!(make_unique_name(model.model(), "/pkg", "Sig").unwrap(), "Sig_12");
+
+        // the session does not affect other models
+        let other_model = AutosarModelAbstraction::create("other", AutosarVersion::LATEST);
+        let other_package = other_model.get_or_create_package("/pkg").unwrap();
+        other_package.create_system_signal("Sig").unwrap();
+        assert_eq!(make_unique_name(other_model.model(), "/pkg", "Sig").unwrap(), "Sig_1");
+        let other_session = other_model.bulk_edit_session();
+        drop(other_session);
+        assert_eq!(make_unique_name(model.model(), "/pkg", "Sig").unwrap(), "Sig_12");
+
+        drop(session);
+        assert_eq!(make_unique_name(model.model(), "/pkg", "Sig").unwrap(), "Sig_3");
+        assert_eq!(package.elements().count(), 11);
+    }
+
+    // number of entries in the reverse reference index of the model, or None if no session is active
+    fn reference_index_len(model: &AutosarModel) -> Option<usize> {
+        SESSION_CACHES.with_borrow(|caches| {
+            caches
+                .iter()
+                .find(|cache| cache.model == *model)
+                .map(|cache| cache.references.len())
+        })
+    }
+
+    #[test]
+    fn reference_index() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel_1 = cluster.create_physical_channel("Channel1").unwrap();
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let pdu_path = pdu.element().path().unwrap();
+
+        let session = model.bulk_edit_session();
+        assert_eq!(reference_index_len(model.model()), Some(0));
+        channel_1
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let pdu_triggerings = pdu.pdu_triggerings();
+        assert_eq!(pdu_triggerings.len(), 1);
+        let pt_1 = pdu_triggerings[0].clone();
+        // the lookup was stored in the index, and the next lookup uses it
+        assert!(reference_index_len(model.model()).is_some_and(|len| len > 0));
+        let referrers: Vec<Element> = model
+            .model()
+            .get_references_to(&pdu_path)
+            .iter()
+            .filter_map(autosar_data::WeakElement::upgrade)
+            .collect();
+        let parents = cached_reference_parents(model.model(), &pdu_path, &referrers).unwrap();
+        assert_eq!(parents.len(), 1);
+        assert_eq!(parents[0].0, *pt_1.element());
+        assert_eq!(pdu.pdu_triggerings(), vec![pt_1.clone()]);
+
+        // a new reference makes the entry stale, so it is recomputed
+        let channel_2 = cluster.create_physical_channel("Channel2").unwrap();
+        channel_2
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let pdu_triggerings = pdu.pdu_triggerings();
+        assert_eq!(pdu_triggerings.len(), 2);
+        let pt_2 = pdu_triggerings.into_iter().find(|pt| *pt != pt_1).unwrap();
+
+        // renaming and removing elements discards the index
+        pt_2.set_name("Renamed").unwrap();
+        assert_eq!(reference_index_len(model.model()), Some(0));
+        assert_eq!(pdu.pdu_triggerings().len(), 2);
+        assert_eq!(reference_index_len(model.model()), Some(1));
+        pt_2.remove(false).unwrap();
+        assert_eq!(reference_index_len(model.model()), Some(0));
+        assert_eq!(pdu.pdu_triggerings(), vec![pt_1]);
+
+        // the index is dropped together with the session
+        drop(session);
+        assert_eq!(reference_index_len(model.model()), None);
+    }
+}
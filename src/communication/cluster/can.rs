@@ -1,7 +1,9 @@
-use crate::communication::{AbstractCluster, CanNmCluster, CanPhysicalChannel};
+use crate::communication::{
+    AbstractCluster, CanNmCluster, CanPhysicalChannel, CommunicationController, collect_cluster_ecus,
+};
 use crate::{
-    AbstractionElement, ArPackage, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
-    get_reference_parents,
+    AbstractionElement, ArPackage, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
+    abstraction_element, get_reference_parents,
 };
 use autosar_data::{Element, ElementName};
 
@@ -217,6 +219,13 @@ impl CanCluster {
             .get_sub_element(ElementName::CanPhysicalChannel)?;
         CanPhysicalChannel::try_from(channel).ok()
     }
+
+    /// iterate over all ECUs that are connected to this `CanCluster`
+    ///
+    /// Each `EcuInstance` is returned together with the `CommunicationController` that connects it to the cluster.
+    pub fn ecus(&self) -> impl Iterator<Item = (EcuInstance, CommunicationController)> + Send + use<> {
+        collect_cluster_ecus(self.physical_channel()).into_iter()
+    }
 }
 
 impl AbstractCluster for CanCluster {}
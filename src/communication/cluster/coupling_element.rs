@@ -0,0 +1,476 @@
+use crate::communication::{EthernetCluster, EthernetPhysicalChannel};
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
+    abstraction_element,
+};
+use autosar_data::{Element, ElementName};
+
+//##################################################################
+
+/// A `CouplingElement` describes a switch (or another coupling device) in an ethernet network.
+///
+/// The ports of the coupling element are linked to the VLANs they carry. Since every VLAN
+/// is represented by an [`EthernetPhysicalChannel`], one channel can be present on many
+/// ports of several coupling elements.
+///
+/// Use [`crate::System::create_coupling_element`] to create a new coupling element.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CouplingElement(Element);
+abstraction_element!(CouplingElement, CouplingElement);
+impl IdentifiableAbstractionElement for CouplingElement {}
+
+impl CouplingElement {
+    // create a new CouplingElement - for internal use. User code should call System::create_coupling_element
+    pub(crate) fn new(
+        name: &str,
+        package: &ArPackage,
+        cluster: &EthernetCluster,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let pkg_elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let elem = pkg_elements.create_named_sub_element(ElementName::CouplingElement, name)?;
+        let coupling_element = Self(elem);
+
+        coupling_element.set_cluster(cluster)?;
+
+        Ok(coupling_element)
+    }
+
+    /// set the [`EthernetCluster`] that this coupling element is part of
+    pub fn set_cluster(&self, cluster: &EthernetCluster) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::CommunicationClusterRef)?
+            .set_reference_target(cluster.element())?;
+        Ok(())
+    }
+
+    /// get the [`EthernetCluster`] that this coupling element is part of
+    #[must_use]
+    pub fn cluster(&self) -> Option<EthernetCluster> {
+        self.element()
+            .get_sub_element(ElementName::CommunicationClusterRef)
+            .and_then(|elem| elem.get_reference_target().ok())
+            .and_then(|elem| EthernetCluster::try_from(elem).ok())
+    }
+
+    /// set or remove the [`EcuInstance`] that contains this coupling element
+    ///
+    /// A switch may be integrated into an ECU; a stand-alone switch has no ECU instance.
+    pub fn set_ecu_instance(&self, ecu_instance: Option<&EcuInstance>) -> Result<(), AutosarAbstractionError> {
+        if let Some(ecu_instance) = ecu_instance {
+            self.element()
+                .get_or_create_sub_element(ElementName::EcuInstanceRef)?
+                .set_reference_target(ecu_instance.element())?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::EcuInstanceRef);
+        }
+        Ok(())
+    }
+
+    /// get the [`EcuInstance`] that contains this coupling element, if any
+    #[must_use]
+    pub fn ecu_instance(&self) -> Option<EcuInstance> {
+        self.element()
+            .get_sub_element(ElementName::EcuInstanceRef)
+            .and_then(|eir| eir.get_reference_target().ok())
+            .and_then(|elem| EcuInstance::try_from(elem).ok())
+    }
+
+    /// create a new [`CouplingPort`] in this coupling element
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let cluster = system.create_ethernet_cluster("Cluster", &package)?;
+    /// let switch = system.create_coupling_element("Switch", &package, &cluster)?;
+    /// let port = switch.create_coupling_port("Port1")?;
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the port
+    pub fn create_coupling_port(&self, name: &str) -> Result<CouplingPort, AutosarAbstractionError> {
+        let ports = self.element().get_or_create_sub_element(ElementName::CouplingPorts)?;
+        let port = ports.create_named_sub_element(ElementName::CouplingPort, name)?;
+        Ok(CouplingPort(port))
+    }
+
+    /// iterate over all [`CouplingPort`]s of this coupling element
+    pub fn coupling_ports(&self) -> impl Iterator<Item = CouplingPort> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::CouplingPorts)
+            .into_iter()
+            .flat_map(|ports| ports.sub_elements())
+            .filter_map(|elem| CouplingPort::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// A `CouplingPort` is a port of a [`CouplingElement`] or of an
+/// [`EthernetCommunicationController`](crate::communication::EthernetCommunicationController).
+///
+/// The VLAN memberships of the port define which [`EthernetPhysicalChannel`]s it carries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CouplingPort(Element);
+abstraction_element!(CouplingPort, CouplingPort);
+impl IdentifiableAbstractionElement for CouplingPort {}
+
+impl CouplingPort {
+    /// add a VLAN membership to the port
+    ///
+    /// Adding a VLAN that is already a member of the port has no effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_ethernet_cluster("Cluster", &package)?;
+    /// let vlan_info = EthernetVlanInfo {
+    ///     vlan_name: "VLAN_1".to_string(),
+    ///     vlan_id: 1,
+    /// };
+    /// let channel = cluster.create_physical_channel("Channel", Some(&vlan_info))?;
+    /// let switch = system.create_coupling_element("Switch", &package, &cluster)?;
+    /// let port = switch.create_coupling_port("Port1")?;
+    /// port.add_vlan_membership(&channel)?;
+    /// assert_eq!(port.vlan_memberships().next(), Some(channel));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the channel does not have any VLAN information
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to add the membership
+    pub fn add_vlan_membership(&self, channel: &EthernetPhysicalChannel) -> Result<(), AutosarAbstractionError> {
+        if channel.vlan_info().is_none() {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "only channels with VLAN information can be added to a coupling port".to_string(),
+            ));
+        }
+        if self.vlan_memberships().any(|vlan| &vlan == channel) {
+            return Ok(());
+        }
+
+        self.element()
+            .get_or_create_sub_element(ElementName::VlanMemberships)?
+            .create_sub_element(ElementName::VlanMembership)?
+            .create_sub_element(ElementName::VlanRef)?
+            .set_reference_target(channel.element())?;
+        Ok(())
+    }
+
+    /// remove a VLAN membership from the port
+    ///
+    /// If the VLAN is also the default VLAN of the port, then the default VLAN is removed as well.
+    pub fn remove_vlan_membership(&self, channel: &EthernetPhysicalChannel) -> Result<(), AutosarAbstractionError> {
+        if let Some(vlan_memberships) = self.element().get_sub_element(ElementName::VlanMemberships) {
+            let memberships: Vec<_> = vlan_memberships.sub_elements().collect();
+            for membership in memberships {
+                if membership
+                    .get_sub_element(ElementName::VlanRef)
+                    .and_then(|vlan_ref| vlan_ref.get_reference_target().ok())
+                    .is_some_and(|vlan| &vlan == channel.element())
+                {
+                    vlan_memberships.remove_sub_element(membership)?;
+                }
+            }
+            if vlan_memberships.sub_elements().next().is_none() {
+                self.element().remove_sub_element(vlan_memberships)?;
+            }
+        }
+        if self.default_vlan().as_ref() == Some(channel) {
+            self.set_default_vlan(None)?;
+        }
+        Ok(())
+    }
+
+    /// iterate over all VLANs (i.e. [`EthernetPhysicalChannel`]s) that this port is a member of
+    pub fn vlan_memberships(&self) -> impl Iterator<Item = EthernetPhysicalChannel> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::VlanMemberships)
+            .into_iter()
+            .flat_map(|vms| vms.sub_elements())
+            .filter_map(|vm| vm.get_sub_element(ElementName::VlanRef))
+            .filter_map(|vlan_ref| vlan_ref.get_reference_target().ok())
+            .filter_map(|elem| EthernetPhysicalChannel::try_from(elem).ok())
+    }
+
+    /// set or remove the default VLAN of the port
+    ///
+    /// Untagged frames received on the port are assigned to the default VLAN.
+    /// The port becomes a member of the VLAN if it is not a member yet.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the channel does not have any VLAN information
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to set the default VLAN
+    pub fn set_default_vlan(&self, channel: Option<&EthernetPhysicalChannel>) -> Result<(), AutosarAbstractionError> {
+        if let Some(channel) = channel {
+            self.add_vlan_membership(channel)?;
+            self.element()
+                .get_or_create_sub_element(ElementName::DefaultVlanRef)?
+                .set_reference_target(channel.element())?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::DefaultVlanRef);
+        }
+        Ok(())
+    }
+
+    /// get the default VLAN of the port
+    #[must_use]
+    pub fn default_vlan(&self) -> Option<EthernetPhysicalChannel> {
+        self.element()
+            .get_sub_element(ElementName::DefaultVlanRef)
+            .and_then(|dvr| dvr.get_reference_target().ok())
+            .and_then(|elem| EthernetPhysicalChannel::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// A `CouplingPortConnection` is a link between two [`CouplingPort`]s in an [`EthernetCluster`]
+///
+/// Use [`EthernetCluster::create_coupling_port_connection`] to create a new connection.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CouplingPortConnection(Element);
+abstraction_element!(CouplingPortConnection, CouplingPortConnection);
+
+impl CouplingPortConnection {
+    pub(crate) fn new(
+        parent: &Element,
+        first_port: &CouplingPort,
+        second_port: &CouplingPort,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let connection = parent.create_sub_element(ElementName::CouplingPortConnection)?;
+        let result = connection
+            .create_sub_element(ElementName::FirstPortRef)
+            .and_then(|fpr| fpr.set_reference_target(first_port.element()))
+            .and_then(|()| connection.create_sub_element(ElementName::SecondPortRef))
+            .and_then(|spr| spr.set_reference_target(second_port.element()));
+        if let Err(error) = result {
+            let _ = parent.remove_sub_element(connection);
+            return Err(error.into());
+        }
+
+        Ok(Self(connection))
+    }
+
+    /// get the first port of the connection
+    #[must_use]
+    pub fn first_port(&self) -> Option<CouplingPort> {
+        self.element()
+            .get_sub_element(ElementName::FirstPortRef)
+            .and_then(|fpr| fpr.get_reference_target().ok())
+            .and_then(|elem| CouplingPort::try_from(elem).ok())
+    }
+
+    /// get the second port of the connection
+    #[must_use]
+    pub fn second_port(&self) -> Option<CouplingPort> {
+        self.element()
+            .get_sub_element(ElementName::SecondPortRef)
+            .and_then(|spr| spr.get_reference_target().ok())
+            .and_then(|elem| CouplingPort::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// An issue found by [`EthernetCluster::check_vlan_consistency`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VlanConsistencyIssue {
+    /// two channels of the cluster use the same VLAN identifier
+    DuplicateVlanId(EthernetPhysicalChannel, EthernetPhysicalChannel),
+    /// two channels of the cluster use the same VLAN name, but different VLAN identifiers
+    DuplicateVlanName(EthernetPhysicalChannel, EthernetPhysicalChannel),
+    /// a port in the cluster is a member of a VLAN that belongs to a different cluster
+    ForeignVlan(CouplingPort, EthernetPhysicalChannel),
+    /// the default VLAN of a port is not one of its VLAN memberships
+    DefaultVlanNotMember(CouplingPort, EthernetPhysicalChannel),
+    /// only one of two connected ports is a member of the VLAN, so its traffic can't pass the connection
+    VlanMembershipMismatch(CouplingPortConnection, EthernetPhysicalChannel),
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::communication::EthernetVlanInfo;
+    use crate::{AutosarModelAbstraction, SystemCategory};
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn coupling_element() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_ethernet_cluster("Cluster", &package).unwrap();
+        let vlan_info = EthernetVlanInfo {
+            vlan_name: "VLAN_1".to_string(),
+            vlan_id: 1,
+        };
+        let channel = cluster.create_physical_channel("Channel1", Some(&vlan_info)).unwrap();
+        let untagged = cluster.create_physical_channel("Untagged", None).unwrap();
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+
+        let switch = system.create_coupling_element("Switch", &package, &cluster).unwrap();
+        assert_eq!(switch.cluster().unwrap(), cluster);
+        assert_eq!(cluster.coupling_elements().next(), Some(switch.clone()));
+        assert!(switch.ecu_instance().is_none());
+        switch.set_ecu_instance(Some(&ecu)).unwrap();
+        assert_eq!(switch.ecu_instance(), Some(ecu));
+        switch.set_ecu_instance(None).unwrap();
+        assert!(switch.ecu_instance().is_none());
+
+        let port1 = switch.create_coupling_port("Port1").unwrap();
+        let port2 = switch.create_coupling_port("Port2").unwrap();
+        assert_eq!(switch.coupling_ports().count(), 2);
+
+        // untagged channels can't be added as VLAN memberships
+        assert!(port1.add_vlan_membership(&untagged).is_err());
+        port1.add_vlan_membership(&channel).unwrap();
+        // adding the same VLAN again has no effect
+        port1.add_vlan_membership(&channel).unwrap();
+        assert_eq!(port1.vlan_memberships().count(), 1);
+
+        // setting the default VLAN also adds the membership
+        port2.set_default_vlan(Some(&channel)).unwrap();
+        assert_eq!(port2.default_vlan(), Some(channel.clone()));
+        assert_eq!(port2.vlan_memberships().next(), Some(channel.clone()));
+        assert_eq!(channel.coupling_ports().count(), 2);
+
+        port2.remove_vlan_membership(&channel).unwrap();
+        assert_eq!(port2.vlan_memberships().count(), 0);
+        assert!(port2.default_vlan().is_none());
+
+        let connection = cluster.create_coupling_port_connection(&port1, &port2).unwrap();
+        assert_eq!(connection.first_port(), Some(port1.clone()));
+        assert_eq!(connection.second_port(), Some(port2.clone()));
+        assert_eq!(cluster.coupling_port_connections().next(), Some(connection));
+        // a port can't be connected to itself
+        assert!(cluster.create_coupling_port_connection(&port1, &port1).is_err());
+    }
+
+    #[test]
+    fn vlan_consistency() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_ethernet_cluster("Cluster", &package).unwrap();
+        let other_cluster = system.create_ethernet_cluster("OtherCluster", &package).unwrap();
+
+        let vlan_info = EthernetVlanInfo {
+            vlan_name: "VLAN_1".to_string(),
+            vlan_id: 1,
+        };
+        let channel1 = cluster.create_physical_channel("Channel1", Some(&vlan_info)).unwrap();
+        let vlan_info = EthernetVlanInfo {
+            vlan_name: "VLAN_2".to_string(),
+            vlan_id: 2,
+        };
+        let channel2 = cluster.create_physical_channel("Channel2", Some(&vlan_info)).unwrap();
+        let foreign_channel = other_cluster
+            .create_physical_channel("Channel1", Some(&vlan_info))
+            .unwrap();
+
+        // an ECU is connected to both VLANs; the coupling port of its controller is linked to a switch
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let controller = ecu
+            .create_ethernet_communication_controller("Controller", None)
+            .unwrap();
+        controller.connect_physical_channel("Connection1", &channel1).unwrap();
+        controller.connect_physical_channel("Connection2", &channel2).unwrap();
+        let ecu_port = controller.coupling_port().unwrap();
+        assert_eq!(ecu_port.vlan_memberships().count(), 2);
+
+        let switch1 = system.create_coupling_element("Switch1", &package, &cluster).unwrap();
+        let switch2 = system.create_coupling_element("Switch2", &package, &cluster).unwrap();
+        let port0 = switch1.create_coupling_port("Port0").unwrap();
+        let port1 = switch1.create_coupling_port("Port1").unwrap();
+        let port2 = switch2.create_coupling_port("Port2").unwrap();
+        for port in [&port0, &port1, &port2] {
+            port.add_vlan_membership(&channel1).unwrap();
+            port.add_vlan_membership(&channel2).unwrap();
+        }
+        cluster.create_coupling_port_connection(&ecu_port, &port0).unwrap();
+        cluster.create_coupling_port_connection(&port1, &port2).unwrap();
+        assert!(cluster.check_vlan_consistency().is_empty());
+
+        // VLAN 2 is only carried by one side of the connection
+        port2.remove_vlan_membership(&channel2).unwrap();
+        let issues = cluster.check_vlan_consistency();
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(&issues[0], VlanConsistencyIssue::VlanMembershipMismatch(_, vlan) if vlan == &channel2));
+        port2.add_vlan_membership(&channel2).unwrap();
+
+        // a port of the cluster is a member of a VLAN of another cluster
+        port1.add_vlan_membership(&foreign_channel).unwrap();
+        let issues = cluster.check_vlan_consistency();
+        assert!(issues.contains(&VlanConsistencyIssue::ForeignVlan(
+            port1.clone(),
+            foreign_channel.clone()
+        )));
+        port1.remove_vlan_membership(&foreign_channel).unwrap();
+
+        // the default VLAN is not a VLAN membership of the port
+        port1.set_default_vlan(Some(&channel1)).unwrap();
+        port1
+            .element()
+            .remove_sub_element_kind(ElementName::VlanMemberships)
+            .unwrap();
+        let issues = cluster.check_vlan_consistency();
+        assert!(issues.contains(&VlanConsistencyIssue::DefaultVlanNotMember(
+            port1.clone(),
+            channel1.clone()
+        )));
+        port1.add_vlan_membership(&channel1).unwrap();
+        port1.add_vlan_membership(&channel2).unwrap();
+        assert!(cluster.check_vlan_consistency().is_empty());
+
+        // duplicate VLAN names and ids can only be created by modifying the model directly
+        channel2
+            .element()
+            .get_sub_element(ElementName::Vlan)
+            .unwrap()
+            .set_item_name("VLAN_1")
+            .unwrap();
+        let issues = cluster.check_vlan_consistency();
+        assert_eq!(
+            issues,
+            vec![VlanConsistencyIssue::DuplicateVlanName(
+                channel1.clone(),
+                channel2.clone()
+            )]
+        );
+        channel2
+            .element()
+            .get_sub_element(ElementName::Vlan)
+            .and_then(|vlan| vlan.get_sub_element(ElementName::VlanIdentifier))
+            .unwrap()
+            .set_character_data("1")
+            .unwrap();
+        let issues = cluster.check_vlan_consistency();
+        assert_eq!(
+            issues,
+            vec![VlanConsistencyIssue::DuplicateVlanId(
+                channel1.clone(),
+                channel2.clone()
+            )]
+        );
+    }
+}
@@ -1,11 +1,13 @@
 use crate::communication::{
-    AbstractCluster, DoIpTpConfig, EthernetPhysicalChannel, EthernetVlanInfo, SomeipTpConfig, UdpNmCluster,
+    AbstractCluster, CommunicationController, CouplingElement, CouplingPort, CouplingPortConnection, DoIpTpConfig,
+    EthernetPhysicalChannel, EthernetVlanInfo, SomeipTpConfig, UdpNmCluster, VlanConsistencyIssue,
+    collect_cluster_ecus,
 };
 use crate::{
-    AbstractionElement, ArPackage, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
-    get_reference_parents,
+    AbstractionElement, ArPackage, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
+    abstraction_element, get_reference_parents,
 };
-use autosar_data::{Element, ElementName};
+use autosar_data::{Element, ElementName, WeakElement};
 
 /// An `EthernetCluster` contains all configuration items associated with an ethernet network.
 /// The cluster connects multiple ECUs.
@@ -40,7 +42,7 @@ impl EthernetCluster {
         // delegate to the trait implementation to clean up all other references to the element and the element itself
         AbstractionElement::remove(self, deep)?;
 
-        // check if any DoipTpConfig, or UdpNmCluster or SomeIpTpConfig uses this EthernetCluster
+        // check if any DoipTpConfig, UdpNmCluster, SomeIpTpConfig or CouplingElement uses this EthernetCluster
         // The cluster reference is mandatory these elements, so we remove them together with the cluster
         for (named_parent, _parent) in ref_parents {
             match named_parent.element_name() {
@@ -59,6 +61,11 @@ impl EthernetCluster {
                         someip_tp_config.remove(deep)?;
                     }
                 }
+                ElementName::CouplingElement => {
+                    if let Ok(coupling_element) = CouplingElement::try_from(named_parent) {
+                        coupling_element.remove(deep)?;
+                    }
+                }
                 _ => {}
             }
         }
@@ -135,6 +142,175 @@ impl EthernetCluster {
             .flat_map(|phys_channel| phys_channel.sub_elements())
             .filter_map(|elem| EthernetPhysicalChannel::try_from(elem).ok())
     }
+
+    /// iterate over all ECUs that are connected to this `EthernetCluster`
+    ///
+    /// Each `EcuInstance` is returned together with the `CommunicationController` that connects it to the cluster.
+    ///
+    /// An ECU that is connected to several channels (VLANs) of the cluster through the same controller is only returned once.
+    pub fn ecus(&self) -> impl Iterator<Item = (EcuInstance, CommunicationController)> + Send + use<> {
+        collect_cluster_ecus(self.physical_channels()).into_iter()
+    }
+
+    /// iterate over all [`CouplingElement`]s (switches) that are part of this `EthernetCluster`
+    pub fn coupling_elements(&self) -> impl Iterator<Item = CouplingElement> + Send + use<> {
+        let model = self.element().model().ok();
+        let path = self.element().path().ok();
+        model
+            .zip(path)
+            .map(|(model, path)| model.get_references_to(&path))
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(WeakElement::upgrade)
+            .filter(|elem| elem.element_name() == ElementName::CommunicationClusterRef)
+            .filter_map(|elem| elem.named_parent().ok().flatten())
+            .filter_map(|parent| CouplingElement::try_from(parent).ok())
+    }
+
+    /// connect two [`CouplingPort`]s, e.g. the port of an ECU and the port of a switch
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let cluster = system.create_ethernet_cluster("Cluster", &package)?;
+    /// let switch1 = system.create_coupling_element("Switch1", &package, &cluster)?;
+    /// let switch2 = system.create_coupling_element("Switch2", &package, &cluster)?;
+    /// let port1 = switch1.create_coupling_port("Port1")?;
+    /// let port2 = switch2.create_coupling_port("Port2")?;
+    /// let connection = cluster.create_coupling_port_connection(&port1, &port2)?;
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] a port can't be connected to itself
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the connection
+    pub fn create_coupling_port_connection(
+        &self,
+        first_port: &CouplingPort,
+        second_port: &CouplingPort,
+    ) -> Result<CouplingPortConnection, AutosarAbstractionError> {
+        if first_port == second_port {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "a coupling port can't be connected to itself".to_string(),
+            ));
+        }
+        let connections = self
+            .element()
+            .get_or_create_sub_element(ElementName::EthernetClusterVariants)?
+            .get_or_create_sub_element(ElementName::EthernetClusterConditional)?
+            .get_or_create_sub_element(ElementName::CouplingPortConnections)?;
+        CouplingPortConnection::new(&connections, first_port, second_port)
+    }
+
+    /// iterate over all [`CouplingPortConnection`]s of this `EthernetCluster`
+    pub fn coupling_port_connections(&self) -> impl Iterator<Item = CouplingPortConnection> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::EthernetClusterVariants)
+            .and_then(|ecv| ecv.get_sub_element(ElementName::EthernetClusterConditional))
+            .and_then(|ecc| ecc.get_sub_element(ElementName::CouplingPortConnections))
+            .into_iter()
+            .flat_map(|connections| connections.sub_elements())
+            .filter_map(|elem| CouplingPortConnection::try_from(elem).ok())
+    }
+
+    /// check that the VLANs of the cluster are consistent across all coupling ports
+    ///
+    /// The same VLAN may appear on many ports of different switches and ECUs. This function checks that
+    ///  - the VLAN identifiers and VLAN names of all channels in the cluster are unique
+    ///  - the ports of the cluster are only members of VLANs of this cluster
+    ///  - the default VLAN of each port is also one of its VLAN memberships
+    ///  - both ports of a coupling port connection are members of the same VLANs
+    ///
+    /// An empty result means that no issues were found.
+    #[must_use]
+    pub fn check_vlan_consistency(&self) -> Vec<VlanConsistencyIssue> {
+        let mut issues = Vec::new();
+
+        let vlan_channels: Vec<_> = self
+            .physical_channels()
+            .filter_map(|channel| channel.vlan_info().map(|vlan_info| (channel, vlan_info)))
+            .collect();
+        for (idx, (channel, vlan_info)) in vlan_channels.iter().enumerate() {
+            for (other_channel, other_vlan_info) in &vlan_channels[idx + 1..] {
+                if vlan_info.vlan_id == other_vlan_info.vlan_id {
+                    issues.push(VlanConsistencyIssue::DuplicateVlanId(
+                        channel.clone(),
+                        other_channel.clone(),
+                    ));
+                } else if vlan_info.vlan_name == other_vlan_info.vlan_name {
+                    issues.push(VlanConsistencyIssue::DuplicateVlanName(
+                        channel.clone(),
+                        other_channel.clone(),
+                    ));
+                }
+            }
+        }
+
+        // collect the ports of the switches, of the ECUs and of all port connections in the cluster
+        let connections: Vec<_> = self.coupling_port_connections().collect();
+        let mut ports: Vec<CouplingPort> = Vec::new();
+        let candidate_ports = self
+            .coupling_elements()
+            .flat_map(|coupling_element| coupling_element.coupling_ports())
+            .chain(self.ecus().filter_map(|(_, controller)| match controller {
+                CommunicationController::Ethernet(eth_controller) => eth_controller.coupling_port(),
+                _ => None,
+            }))
+            .chain(
+                connections
+                    .iter()
+                    .flat_map(|connection| [connection.first_port(), connection.second_port()])
+                    .flatten(),
+            );
+        for port in candidate_ports {
+            if !ports.contains(&port) {
+                ports.push(port);
+            }
+        }
+
+        for port in &ports {
+            let memberships: Vec<_> = port.vlan_memberships().collect();
+            for vlan in &memberships {
+                if vlan.cluster().ok().as_ref() != Some(self) {
+                    issues.push(VlanConsistencyIssue::ForeignVlan(port.clone(), vlan.clone()));
+                }
+            }
+            if let Some(default_vlan) = port.default_vlan()
+                && !memberships.contains(&default_vlan)
+            {
+                issues.push(VlanConsistencyIssue::DefaultVlanNotMember(port.clone(), default_vlan));
+            }
+        }
+
+        for connection in &connections {
+            let (Some(first_port), Some(second_port)) = (connection.first_port(), connection.second_port()) else {
+                continue;
+            };
+            let first_vlans: Vec<_> = first_port.vlan_memberships().collect();
+            let second_vlans: Vec<_> = second_port.vlan_memberships().collect();
+            for vlan in first_vlans.iter().filter(|vlan| !second_vlans.contains(vlan)) {
+                issues.push(VlanConsistencyIssue::VlanMembershipMismatch(
+                    connection.clone(),
+                    vlan.clone(),
+                ));
+            }
+            for vlan in second_vlans.iter().filter(|vlan| !first_vlans.contains(vlan)) {
+                issues.push(VlanConsistencyIssue::VlanMembershipMismatch(
+                    connection.clone(),
+                    vlan.clone(),
+                ));
+            }
+        }
+
+        issues
+    }
 }
 
 impl AbstractCluster for EthernetCluster {}
@@ -1,12 +1,14 @@
 use crate::{
-    AbstractionElement, ArPackage, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
+    AbstractionElement, ArPackage, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
+    abstraction_element,
     communication::{
-        AbstractCluster, FlexrayArTpConfig, FlexrayChannelName, FlexrayNmCluster, FlexrayPhysicalChannel,
-        FlexrayTpConfig,
+        AbstractCluster, CommunicationController, FlexrayArTpConfig, FlexrayChannelName, FlexrayNmCluster,
+        FlexrayPhysicalChannel, FlexrayTpConfig, collect_cluster_ecus,
     },
     get_reference_parents,
 };
 use autosar_data::{Element, ElementName, EnumItem};
+use std::ops::RangeInclusive;
 
 /// A `FlexrayCluster` contains all configuration items associated with a Flexray network.
 /// The cluster connects multiple ECUs.
@@ -658,6 +660,16 @@ impl FlexrayCluster {
         }
         channel_info
     }
+
+    /// iterate over all ECUs that are connected to this `FlexrayCluster`
+    ///
+    /// Each `EcuInstance` is returned together with the `CommunicationController` that connects it to the cluster.
+    ///
+    /// An ECU that is connected to both channels of the cluster through the same controller is only returned once.
+    pub fn ecus(&self) -> impl Iterator<Item = (EcuInstance, CommunicationController)> + Send + use<> {
+        let channels = self.physical_channels();
+        collect_cluster_ecus([channels.channel_a, channels.channel_b].into_iter().flatten()).into_iter()
+    }
 }
 
 impl AbstractCluster for FlexrayCluster {}
@@ -988,6 +1000,33 @@ impl FlexrayClusterSettings {
 
         true
     }
+
+    /// get the range of slot ids in the dynamic segment
+    ///
+    /// The dynamic slot ids follow the static slots. Each dynamic slot uses at least one minislot, so the number
+    /// of dynamic slots is limited by the number of minislots, as well as by the highest slot id 2047 (cSlotIDMax).
+    /// Returns None if the cluster does not have a dynamic segment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data_abstraction::communication::*;
+    /// let settings = FlexrayClusterSettings::default();
+    /// assert_eq!(settings.dynamic_slot_range(), Some(51..=235));
+    /// ```
+    #[must_use]
+    pub fn dynamic_slot_range(&self) -> Option<RangeInclusive<u16>> {
+        let first_slot = self.number_of_static_slots.checked_add(1)?;
+        let last_slot = self
+            .number_of_static_slots
+            .saturating_add(self.number_of_minislots)
+            .min(2047);
+        if self.number_of_minislots == 0 || first_slot > last_slot {
+            None
+        } else {
+            Some(first_slot..=last_slot)
+        }
+    }
 }
 
 impl Default for FlexrayClusterSettings {
@@ -1,6 +1,7 @@
-use crate::communication::{AbstractCluster, LinPhysicalChannel};
+use crate::communication::{AbstractCluster, CommunicationController, LinPhysicalChannel, collect_cluster_ecus};
 use crate::{
-    AbstractionElement, ArPackage, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
+    AbstractionElement, ArPackage, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
+    abstraction_element,
 };
 use autosar_data::{Element, ElementName};
 
@@ -115,6 +116,13 @@ impl LinCluster {
             .get_sub_element(ElementName::LinPhysicalChannel)?;
         LinPhysicalChannel::try_from(channel).ok()
     }
+
+    /// iterate over all ECUs that are connected to this `LinCluster`
+    ///
+    /// Each `EcuInstance` is returned together with the `CommunicationController` that connects it to the cluster.
+    pub fn ecus(&self) -> impl Iterator<Item = (EcuInstance, CommunicationController)> + Send + use<> {
+        collect_cluster_ecus(self.physical_channel()).into_iter()
+    }
 }
 
 impl AbstractCluster for LinCluster {}
@@ -1,12 +1,15 @@
-use crate::{AbstractionElement, AutosarAbstractionError, IdentifiableAbstractionElement, System};
+use crate::communication::{AbstractPhysicalChannel, CommunicationController};
+use crate::{AbstractionElement, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement, System};
 use autosar_data::{Element, ElementName};
 
 mod can;
+mod coupling_element;
 mod ethernet;
 mod flexray;
 mod lin;
 
 pub use can::*;
+pub use coupling_element::*;
 pub use ethernet::*;
 pub use flexray::*;
 pub use lin::*;
@@ -118,6 +121,35 @@ impl Cluster {
             Cluster::Lin(lin_cluster) => lin_cluster.remove(deep),
         }
     }
+
+    /// iterate over all ECUs that are connected to any physical channel of this `Cluster`
+    ///
+    /// Each `EcuInstance` is returned together with the `CommunicationController` that connects it to the cluster.
+    /// An ECU that is connected to several channels of the cluster through the same controller is only returned once.
+    pub fn ecus(&self) -> impl Iterator<Item = (EcuInstance, CommunicationController)> + Send + use<> {
+        match self {
+            Cluster::Can(can_cluster) => can_cluster.ecus().collect::<Vec<_>>(),
+            Cluster::Ethernet(eth_cluster) => eth_cluster.ecus().collect(),
+            Cluster::FlexRay(flx_cluster) => flx_cluster.ecus().collect(),
+            Cluster::Lin(lin_cluster) => lin_cluster.ecus().collect(),
+        }
+        .into_iter()
+    }
+}
+
+// collect the ECUs of all channels of a cluster, removing duplicates
+pub(crate) fn collect_cluster_ecus<T: AbstractPhysicalChannel>(
+    channels: impl IntoIterator<Item = T>,
+) -> Vec<(EcuInstance, CommunicationController)> {
+    let mut ecus = Vec::new();
+    for channel in channels {
+        for ecu_info in channel.ecus() {
+            if !ecus.contains(&ecu_info) {
+                ecus.push(ecu_info);
+            }
+        }
+    }
+    ecus
 }
 
 //##################################################################
@@ -125,7 +157,7 @@ impl Cluster {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::AutosarModelAbstraction;
+    use crate::{AutosarModelAbstraction, communication::EthernetVlanInfo};
     use autosar_data::AutosarVersion;
 
     #[test]
@@ -185,4 +217,52 @@ mod tests {
         cluster.remove(true).unwrap();
         assert_eq!(system.clusters().count(), 0);
     }
+
+    #[test]
+    fn cluster_ecus() {
+        let model = AutosarModelAbstraction::create("test.arxml", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/Test").unwrap();
+        let system = package
+            .create_system("System", crate::SystemCategory::EcuExtract)
+            .unwrap();
+        let ecu_1 = system.create_ecu_instance("Ecu1", &package).unwrap();
+        let ecu_2 = system.create_ecu_instance("Ecu2", &package).unwrap();
+
+        let can_cluster = system.create_can_cluster("CanCluster", &package, None).unwrap();
+        let can_channel = can_cluster.create_physical_channel("CanChannel").unwrap();
+        let can_ctrl_1 = ecu_1.create_can_communication_controller("CanCtrl").unwrap();
+        let can_ctrl_2 = ecu_2.create_can_communication_controller("CanCtrl").unwrap();
+        can_ctrl_1.connect_physical_channel("Connector", &can_channel).unwrap();
+        can_ctrl_2.connect_physical_channel("Connector", &can_channel).unwrap();
+        let channel_ecus: Vec<_> = can_channel.ecus().collect();
+        assert_eq!(
+            channel_ecus,
+            vec![
+                (ecu_1.clone(), CommunicationController::Can(can_ctrl_1.clone())),
+                (ecu_2.clone(), CommunicationController::Can(can_ctrl_2.clone()))
+            ]
+        );
+        assert_eq!(can_cluster.ecus().count(), 2);
+
+        // an ECU that is connected to two VLANs with the same controller is only listed once
+        let eth_cluster = system.create_ethernet_cluster("EthCluster", &package).unwrap();
+        let vlan_1 = EthernetVlanInfo {
+            vlan_name: "VLAN_1".to_string(),
+            vlan_id: 1,
+        };
+        let vlan_2 = EthernetVlanInfo {
+            vlan_name: "VLAN_2".to_string(),
+            vlan_id: 2,
+        };
+        let eth_channel_1 = eth_cluster.create_physical_channel("Channel1", Some(&vlan_1)).unwrap();
+        let eth_channel_2 = eth_cluster.create_physical_channel("Channel2", Some(&vlan_2)).unwrap();
+        let eth_ctrl = ecu_1.create_ethernet_communication_controller("EthCtrl", None).unwrap();
+        eth_ctrl.connect_physical_channel("Connector1", &eth_channel_1).unwrap();
+        eth_ctrl.connect_physical_channel("Connector2", &eth_channel_2).unwrap();
+        assert_eq!(eth_channel_1.ecus().count(), 1);
+        assert_eq!(eth_channel_2.ecus().count(), 1);
+        let cluster: Cluster = eth_cluster.into();
+        let cluster_ecus: Vec<_> = cluster.ecus().collect();
+        assert_eq!(cluster_ecus, vec![(ecu_1, CommunicationController::Ethernet(eth_ctrl))]);
+    }
 }
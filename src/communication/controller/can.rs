@@ -1,6 +1,10 @@
 use crate::{
     AbstractionElement, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement, abstraction_element,
-    communication::{AbstractCommunicationConnector, AbstractCommunicationController, CanPhysicalChannel},
+    communication::{
+        AbstractCommunicationConnector, AbstractCommunicationController, CanPhysicalChannel,
+        channel_connector_of_controller, controller_conditional, existing_controller_conditional,
+        remove_connector_and_ports,
+    },
 };
 use autosar_data::{AutosarDataError, AutosarModel, Element, ElementName, ElementsIterator, WeakElement};
 
@@ -144,12 +148,122 @@ impl CanCommunicationController {
 
         Ok(connector)
     }
+
+    /// Disconnect this [`CanCommunicationController`] from a [`CanPhysicalChannel`]
+    ///
+    /// This is the inverse of [`Self::connect_physical_channel`]. The connector between the controller and the
+    /// channel is removed from the [`EcuInstance`], together with all its frame, pdu and signal ports.
+    /// The references to these ports in the triggerings of the channel are removed as well.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the controller is not connected to the channel
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while removing the connector
+    pub fn disconnect_physical_channel(&self, can_channel: &CanPhysicalChannel) -> Result<(), AutosarAbstractionError> {
+        let connector = channel_connector_of_controller(self, can_channel)?;
+        remove_connector_and_ports(connector)
+    }
+
+    /// set or remove the CAN FD configuration of the controller
+    ///
+    /// The settings are stored in the `CanControllerConfiguration` of the controller.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model, e.g. because the controller
+    ///   uses a `CanControllerConfigurationRequirements` instead of a `CanControllerConfiguration`
+    pub fn set_fd_configuration(
+        &self,
+        fd_configuration: Option<&CanControllerFdConfiguration>,
+    ) -> Result<(), AutosarAbstractionError> {
+        let conditional = controller_conditional(&self.0)?;
+        if let Some(fd_configuration) = fd_configuration {
+            let configuration = conditional
+                .get_or_create_sub_element(ElementName::CanControllerAttributes)?
+                .get_or_create_sub_element(ElementName::CanControllerConfiguration)?;
+            let _ = configuration.remove_sub_element_kind(ElementName::CanControllerFdAttributes);
+            let fd_attributes = configuration.create_sub_element(ElementName::CanControllerFdAttributes)?;
+            let optional_values = [
+                (ElementName::PaddingValue, fd_configuration.padding_value),
+                (ElementName::PropSeg, fd_configuration.prop_seg),
+                (ElementName::SspOffset, fd_configuration.ssp_offset),
+                (ElementName::SyncJumpWidth, fd_configuration.sync_jump_width),
+                (ElementName::TimeSeg1, fd_configuration.time_seg1),
+                (ElementName::TimeSeg2, fd_configuration.time_seg2),
+            ];
+            for (element_name, value) in optional_values {
+                if let Some(value) = value {
+                    fd_attributes
+                        .create_sub_element(element_name)?
+                        .set_character_data(u64::from(value))?;
+                }
+            }
+            fd_attributes
+                .create_sub_element(ElementName::TxBitRateSwitch)?
+                .set_character_data(fd_configuration.tx_bit_rate_switch)?;
+        } else if let Some(configuration) = conditional
+            .get_sub_element(ElementName::CanControllerAttributes)
+            .and_then(|attributes| attributes.get_sub_element(ElementName::CanControllerConfiguration))
+        {
+            let _ = configuration.remove_sub_element_kind(ElementName::CanControllerFdAttributes);
+        }
+        Ok(())
+    }
+
+    /// get the CAN FD configuration of the controller
+    #[must_use]
+    pub fn fd_configuration(&self) -> Option<CanControllerFdConfiguration> {
+        let fd_attributes = existing_controller_conditional(&self.0)?
+            .get_sub_element(ElementName::CanControllerAttributes)?
+            .get_sub_element(ElementName::CanControllerConfiguration)?
+            .get_sub_element(ElementName::CanControllerFdAttributes)?;
+        let get_value = |element_name| {
+            fd_attributes
+                .get_sub_element(element_name)?
+                .character_data()?
+                .parse_integer()
+        };
+        Some(CanControllerFdConfiguration {
+            tx_bit_rate_switch: fd_attributes
+                .get_sub_element(ElementName::TxBitRateSwitch)
+                .and_then(|elem| elem.character_data())
+                .and_then(|cdata| cdata.parse_bool())
+                .unwrap_or(false),
+            padding_value: get_value(ElementName::PaddingValue),
+            prop_seg: get_value(ElementName::PropSeg),
+            ssp_offset: get_value(ElementName::SspOffset),
+            sync_jump_width: get_value(ElementName::SyncJumpWidth),
+            time_seg1: get_value(ElementName::TimeSeg1),
+            time_seg2: get_value(ElementName::TimeSeg2),
+        })
+    }
 }
 
 impl AbstractCommunicationController for CanCommunicationController {}
 
 //##################################################################
 
+/// The CAN FD settings of a [`CanCommunicationController`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CanControllerFdConfiguration {
+    /// true if the bit rate is switched to the data bit rate during the data phase of a CAN FD frame
+    pub tx_bit_rate_switch: bool,
+    /// value that is used to fill unused bytes of CAN FD frames
+    pub padding_value: Option<u32>,
+    /// propagation segment in the data phase, in time quanta
+    pub prop_seg: Option<u32>,
+    /// secondary sample point offset in the data phase, in time quanta
+    pub ssp_offset: Option<u32>,
+    /// synchronization jump width in the data phase, in time quanta
+    pub sync_jump_width: Option<u32>,
+    /// phase segment 1 in the data phase, in time quanta
+    pub time_seg1: Option<u32>,
+    /// phase segment 2 in the data phase, in time quanta
+    pub time_seg2: Option<u32>,
+}
+
+//##################################################################
+
 /// A connector between a [`CanCommunicationController`] in an ECU and a [`CanPhysicalChannel`]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CanCommunicationConnector(Element);
@@ -168,6 +282,107 @@ impl CanCommunicationConnector {
             .set_reference_target(controller.element())?;
         Ok(Self(connector))
     }
+
+    /// set the partial network wakeup frame of the connector
+    ///
+    /// The transceiver wakes up the ECU when it receives a frame that matches the settings.
+    pub fn set_pnc_wakeup(&self, pnc_wakeup: Option<&CanPncWakeup>) -> Result<(), AutosarAbstractionError> {
+        if let Some(pnc_wakeup) = pnc_wakeup {
+            if pnc_wakeup.dlc > 8 {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "Invalid PNC wakeup DLC {}: the DLC must be in the range 0..=8",
+                    pnc_wakeup.dlc
+                )));
+            }
+            let max_id = if pnc_wakeup.extended { 0x1FFF_FFFF } else { 0x7FF };
+            if pnc_wakeup.can_id > max_id {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "Invalid PNC wakeup CAN id {:#x}",
+                    pnc_wakeup.can_id
+                )));
+            }
+        }
+
+        let element_names = [
+            ElementName::PncWakeupCanId,
+            ElementName::PncWakeupCanIdExtended,
+            ElementName::PncWakeupCanIdMask,
+            ElementName::PncWakeupData,
+            ElementName::PncWakeupDataMask,
+            ElementName::PncWakeupDlc,
+        ];
+        for name in element_names {
+            let _ = self.element().remove_sub_element_kind(name);
+        }
+
+        if let Some(pnc_wakeup) = pnc_wakeup {
+            self.element()
+                .create_sub_element(ElementName::PncWakeupCanId)?
+                .set_character_data(u64::from(pnc_wakeup.can_id))?;
+            self.element()
+                .create_sub_element(ElementName::PncWakeupCanIdExtended)?
+                .set_character_data(pnc_wakeup.extended)?;
+            self.element()
+                .create_sub_element(ElementName::PncWakeupCanIdMask)?
+                .set_character_data(u64::from(pnc_wakeup.can_id_mask))?;
+            self.element()
+                .create_sub_element(ElementName::PncWakeupData)?
+                .set_character_data(pnc_wakeup.data)?;
+            self.element()
+                .create_sub_element(ElementName::PncWakeupDataMask)?
+                .set_character_data(pnc_wakeup.data_mask)?;
+            self.element()
+                .create_sub_element(ElementName::PncWakeupDlc)?
+                .set_character_data(u64::from(pnc_wakeup.dlc))?;
+        }
+        Ok(())
+    }
+
+    /// get the partial network wakeup frame of the connector
+    #[must_use]
+    pub fn pnc_wakeup(&self) -> Option<CanPncWakeup> {
+        let get_integer = |name| {
+            self.element()
+                .get_sub_element(name)
+                .and_then(|elem| elem.character_data())
+                .and_then(|cdata| cdata.parse_integer::<u64>())
+        };
+        let can_id = get_integer(ElementName::PncWakeupCanId)?;
+        let extended = self
+            .element()
+            .get_sub_element(ElementName::PncWakeupCanIdExtended)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_bool())
+            .unwrap_or(false);
+
+        Some(CanPncWakeup {
+            can_id: u32::try_from(can_id).ok()?,
+            extended,
+            can_id_mask: get_integer(ElementName::PncWakeupCanIdMask).and_then(|mask| u32::try_from(mask).ok())?,
+            data: get_integer(ElementName::PncWakeupData)?,
+            data_mask: get_integer(ElementName::PncWakeupDataMask)?,
+            dlc: get_integer(ElementName::PncWakeupDlc).and_then(|dlc| u8::try_from(dlc).ok())?,
+        })
+    }
+}
+
+//##################################################################
+
+/// Settings of the CAN frame that wakes up an ECU with partial networking
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CanPncWakeup {
+    /// CAN id of the wakeup frame
+    pub can_id: u32,
+    /// true if the CAN id is an extended (29-bit) id
+    pub extended: bool,
+    /// mask that is applied to the CAN id before comparing it
+    pub can_id_mask: u32,
+    /// payload of the wakeup frame
+    pub data: u64,
+    /// mask that is applied to the payload before comparing it
+    pub data_mask: u64,
+    /// data length code of the wakeup frame
+    pub dlc: u8,
 }
 
 impl AbstractCommunicationConnector for CanCommunicationConnector {
@@ -246,7 +461,13 @@ impl Iterator for CanCtrlChannelsIterator {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{AutosarModelAbstraction, SystemCategory};
+    use crate::{
+        AutosarModelAbstraction, ByteOrder, SystemCategory,
+        communication::{
+            AbstractFrame, AbstractFrameTriggering, AbstractPhysicalChannel, CanAddressingMode, CanFrameType,
+            CommunicationController, CommunicationDirection, PncGatewayType,
+        },
+    };
     use autosar_data::AutosarVersion;
 
     #[test]
@@ -283,6 +504,39 @@ mod test {
         assert_eq!(count, 0);
     }
 
+    #[test]
+    fn controller_settings() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let ecu = system.create_ecu_instance("ECU", &pkg).unwrap();
+        let controller = ecu.create_can_communication_controller("Controller").unwrap();
+
+        assert_eq!(controller.wake_up_by_controller_supported(), None);
+        controller.set_wake_up_by_controller_supported(Some(true)).unwrap();
+        assert_eq!(controller.wake_up_by_controller_supported(), Some(true));
+        controller.set_wake_up_by_controller_supported(None).unwrap();
+        assert_eq!(controller.wake_up_by_controller_supported(), None);
+
+        assert_eq!(controller.fd_configuration(), None);
+        let fd_configuration = CanControllerFdConfiguration {
+            tx_bit_rate_switch: true,
+            padding_value: Some(0xCC),
+            prop_seg: Some(1),
+            ssp_offset: Some(5),
+            sync_jump_width: Some(2),
+            time_seg1: Some(6),
+            time_seg2: Some(2),
+        };
+        controller.set_fd_configuration(Some(&fd_configuration)).unwrap();
+        assert_eq!(controller.fd_configuration(), Some(fd_configuration));
+        let minimal_configuration = CanControllerFdConfiguration::default();
+        controller.set_fd_configuration(Some(&minimal_configuration)).unwrap();
+        assert_eq!(controller.fd_configuration(), Some(minimal_configuration));
+        controller.set_fd_configuration(None).unwrap();
+        assert_eq!(controller.fd_configuration(), None);
+    }
+
     #[test]
     fn connector() {
         let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
@@ -305,6 +559,34 @@ mod test {
         assert_eq!(connector.controller().unwrap(), controller);
         assert_eq!(connector.ecu_instance().unwrap(), ecu);
 
+        // partial networking settings
+        connector.set_pnc_gateway_type(Some(PncGatewayType::Active)).unwrap();
+        assert_eq!(connector.pnc_gateway_type(), Some(PncGatewayType::Active));
+        connector.set_pnc_gateway_type(None).unwrap();
+        assert_eq!(connector.pnc_gateway_type(), None);
+        connector.set_pnc_filter_array_mask(Some(&[0xff, 0x0f])).unwrap();
+        assert_eq!(connector.pnc_filter_array_mask(), Some(vec![0xff, 0x0f]));
+        connector.set_pnc_filter_array_mask(None).unwrap();
+        assert_eq!(connector.pnc_filter_array_mask(), None);
+
+        let pnc_wakeup = CanPncWakeup {
+            can_id: 0x500,
+            extended: false,
+            can_id_mask: 0x7C0,
+            data: 0x01,
+            data_mask: 0xff,
+            dlc: 8,
+        };
+        connector.set_pnc_wakeup(Some(&pnc_wakeup)).unwrap();
+        assert_eq!(connector.pnc_wakeup(), Some(pnc_wakeup));
+        let invalid_wakeup = CanPncWakeup {
+            can_id: 0x800,
+            ..pnc_wakeup
+        };
+        assert!(connector.set_pnc_wakeup(Some(&invalid_wakeup)).is_err());
+        connector.set_pnc_wakeup(None).unwrap();
+        assert_eq!(connector.pnc_wakeup(), None);
+
         // remove the CommControllerRef from the connector and try to get the controller
         connector
             .element()
@@ -336,4 +618,54 @@ mod test {
         assert_eq!(ecu.communication_controllers().count(), 0);
         assert!(connector.element().path().is_err());
     }
+
+    #[test]
+    fn disconnect_physical_channel() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let ecu = system.create_ecu_instance("ECU", &pkg).unwrap();
+        let controller = ecu.create_can_communication_controller("Controller").unwrap();
+        let cluster = system.create_can_cluster("CanCluster", &pkg, None).unwrap();
+        let channel = cluster.create_physical_channel("C1").unwrap();
+        let connector = controller.connect_physical_channel("connection", &channel).unwrap();
+
+        let frame = system.create_can_frame("Frame", &pkg, 8).unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &pkg, 8).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        frame_triggering
+            .connect_to_ecu(&ecu, CommunicationDirection::Out)
+            .unwrap();
+        let pdu_triggering = frame_triggering.pdu_triggerings().next().unwrap();
+        assert_eq!(frame_triggering.frame_ports().count(), 1);
+        assert_eq!(pdu_triggering.pdu_ports().count(), 1);
+
+        controller.disconnect_physical_channel(&channel).unwrap();
+        assert!(connector.element().path().is_err());
+        assert_eq!(channel.connectors().count(), 0);
+        assert_eq!(controller.connected_channels().count(), 0);
+        assert_eq!(frame_triggering.frame_ports().count(), 0);
+        assert!(
+            frame_triggering
+                .element()
+                .get_sub_element(ElementName::FramePortRefs)
+                .is_none()
+        );
+        assert_eq!(pdu_triggering.pdu_ports().count(), 0);
+
+        // the controller is no longer connected
+        assert!(controller.disconnect_physical_channel(&channel).is_err());
+
+        // the controller can be connected again
+        controller.connect_physical_channel("connection", &channel).unwrap();
+        CommunicationController::Can(controller.clone())
+            .disconnect_physical_channel(&channel.clone().into())
+            .unwrap();
+        assert_eq!(channel.connectors().count(), 0);
+    }
 }
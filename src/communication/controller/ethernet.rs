@@ -1,5 +1,7 @@
 use crate::communication::{
-    AbstractCommunicationConnector, AbstractCommunicationController, EthernetPhysicalChannel, EthernetVlanInfo,
+    AbstractCommunicationConnector, AbstractCommunicationController, CouplingPort, EthernetPhysicalChannel,
+    EthernetVlanInfo, SocketAddressType, channel_connector_of_controller, controller_conditional,
+    existing_controller_conditional, remove_connector_and_ports,
 };
 use crate::{
     AbstractionElement, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement, abstraction_element,
@@ -100,6 +102,20 @@ impl EthernetCommunicationController {
         }
     }
 
+    /// get the [`CouplingPort`] of this controller
+    ///
+    /// The coupling port is created together with the controller. When the controller is connected to
+    /// a VLAN, the coupling port becomes a member of that VLAN.
+    #[must_use]
+    pub fn coupling_port(&self) -> Option<CouplingPort> {
+        self.0
+            .get_sub_element(ElementName::EthernetCommunicationControllerVariants)
+            .and_then(|eccv| eccv.get_sub_element(ElementName::EthernetCommunicationControllerConditional))
+            .and_then(|eccc| eccc.get_sub_element(ElementName::CouplingPorts))
+            .and_then(|cps| cps.get_sub_element(ElementName::CouplingPort))
+            .and_then(|cp| CouplingPort::try_from(cp).ok())
+    }
+
     /// Connect this [`EthernetCommunicationController`] inside an [`EcuInstance`] to an [`EthernetPhysicalChannel`] in the [`crate::System`]
     ///
     /// Creates an `EthernetCommunicationConnector` in the [`EcuInstance`] that contains this [`EthernetCommunicationController`].
@@ -198,6 +214,107 @@ impl EthernetCommunicationController {
 
         Ok(connector)
     }
+
+    /// Disconnect this [`EthernetCommunicationController`] from an [`EthernetPhysicalChannel`]
+    ///
+    /// This is the inverse of [`Self::connect_physical_channel`]. The connector between the controller and the
+    /// channel is removed from the [`EcuInstance`], together with all its pdu ports.
+    /// The references to these ports in the triggerings of the channel are removed as well.
+    ///
+    /// Unicast socket addresses of the `EcuInstance` in the channel are removed, and the `EcuInstance` is
+    /// removed from multicast socket addresses. The VLAN membership of the coupling port is also removed.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the controller is not connected to the channel
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while removing the connector
+    pub fn disconnect_physical_channel(
+        &self,
+        eth_channel: &EthernetPhysicalChannel,
+    ) -> Result<(), AutosarAbstractionError> {
+        let connector = channel_connector_of_controller(self, eth_channel)?;
+        let ecu = self.ecu_instance()?;
+
+        for socket_address in eth_channel.socket_addresses() {
+            match socket_address.socket_address_type() {
+                Some(SocketAddressType::Unicast(Some(sa_ecu))) if sa_ecu == ecu => socket_address.remove(false)?,
+                Some(SocketAddressType::Multicast(_)) => {
+                    socket_address.remove_multicast_ecu(&ecu)?;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(vlan_memberships) = self
+            .0
+            .get_sub_element(ElementName::EthernetCommunicationControllerVariants)
+            .and_then(|eccv| eccv.get_sub_element(ElementName::EthernetCommunicationControllerConditional))
+            .and_then(|eccc| eccc.get_sub_element(ElementName::CouplingPorts))
+            .and_then(|cps| cps.get_sub_element(ElementName::CouplingPort))
+            .and_then(|cp| cp.get_sub_element(ElementName::VlanMemberships))
+        {
+            let memberships: Vec<_> = vlan_memberships.sub_elements().collect();
+            for vlan_membership in memberships {
+                if vlan_membership
+                    .get_sub_element(ElementName::VlanRef)
+                    .and_then(|vlan_ref| vlan_ref.get_reference_target().ok())
+                    .is_some_and(|vlan| &vlan == eth_channel.element())
+                {
+                    vlan_memberships.remove_sub_element(vlan_membership)?;
+                }
+            }
+        }
+
+        remove_connector_and_ports(connector)
+    }
+
+    /// set or remove the MAC unicast address of the controller
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] the MAC address has an invalid format, or an error occurred in the Autosar model
+    pub fn set_mac_unicast_address(&self, mac_address: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        let conditional = controller_conditional(&self.0)?;
+        if let Some(mac_address) = mac_address {
+            conditional
+                .get_or_create_sub_element(ElementName::MacUnicastAddress)?
+                .set_character_data(mac_address)?;
+        } else {
+            let _ = conditional.remove_sub_element_kind(ElementName::MacUnicastAddress);
+        }
+        Ok(())
+    }
+
+    /// get the MAC unicast address of the controller
+    #[must_use]
+    pub fn mac_unicast_address(&self) -> Option<String> {
+        existing_controller_conditional(&self.0)?
+            .get_sub_element(ElementName::MacUnicastAddress)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// set or remove the maximum transmission unit (MTU) of the controller, in bytes
+    pub fn set_maximum_transmission_unit(&self, mtu: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        let conditional = controller_conditional(&self.0)?;
+        if let Some(mtu) = mtu {
+            conditional
+                .get_or_create_sub_element(ElementName::MaximumTransmissionUnit)?
+                .set_character_data(u64::from(mtu))?;
+        } else {
+            let _ = conditional.remove_sub_element_kind(ElementName::MaximumTransmissionUnit);
+        }
+        Ok(())
+    }
+
+    /// get the maximum transmission unit (MTU) of the controller, in bytes
+    #[must_use]
+    pub fn maximum_transmission_unit(&self) -> Option<u32> {
+        existing_controller_conditional(&self.0)?
+            .get_sub_element(ElementName::MaximumTransmissionUnit)?
+            .character_data()?
+            .parse_integer()
+    }
 }
 
 impl AbstractCommunicationController for EthernetCommunicationController {}
@@ -349,6 +466,15 @@ mod test {
         let count = controller.connected_channels().count();
         assert_eq!(count, 2);
 
+        assert_eq!(controller.mac_unicast_address().as_deref(), Some("01:02:03:04:05:06"));
+        controller.set_mac_unicast_address(Some("01:02:03:04:05:07")).unwrap();
+        assert_eq!(controller.mac_unicast_address().as_deref(), Some("01:02:03:04:05:07"));
+        assert!(controller.set_mac_unicast_address(Some("abcdef")).is_err());
+        controller.set_maximum_transmission_unit(Some(1500)).unwrap();
+        assert_eq!(controller.maximum_transmission_unit(), Some(1500));
+        controller.set_maximum_transmission_unit(None).unwrap();
+        assert_eq!(controller.maximum_transmission_unit(), None);
+
         // remove the controller and try to list its connected channels again
         let ctrl_parent = controller.element().parent().unwrap().unwrap();
         ctrl_parent.remove_sub_element(controller.element().clone()).unwrap();
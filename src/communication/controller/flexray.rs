@@ -1,4 +1,7 @@
-use crate::communication::{AbstractCommunicationConnector, AbstractCommunicationController, FlexrayPhysicalChannel};
+use crate::communication::{
+    AbstractCommunicationConnector, AbstractCommunicationController, FlexrayPhysicalChannel,
+    channel_connector_of_controller, remove_connector_and_ports,
+};
 use crate::{
     AbstractionElement, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement, abstraction_element,
 };
@@ -137,6 +140,24 @@ impl FlexrayCommunicationController {
 
         Ok(connector)
     }
+
+    /// Disconnect this [`FlexrayCommunicationController`] from a [`FlexrayPhysicalChannel`]
+    ///
+    /// This is the inverse of [`Self::connect_physical_channel`]. The connector between the controller and the
+    /// channel is removed from the [`EcuInstance`], together with all its frame, pdu and signal ports.
+    /// The references to these ports in the triggerings of the channel are removed as well.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the controller is not connected to the channel
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while removing the connector
+    pub fn disconnect_physical_channel(
+        &self,
+        flx_channel: &FlexrayPhysicalChannel,
+    ) -> Result<(), AutosarAbstractionError> {
+        let connector = channel_connector_of_controller(self, flx_channel)?;
+        remove_connector_and_ports(connector)
+    }
 }
 
 impl AbstractCommunicationController for FlexrayCommunicationController {}
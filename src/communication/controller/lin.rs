@@ -1,6 +1,9 @@
 use crate::{
     AbstractionElement, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement, abstraction_element,
-    communication::{AbstractCommunicationConnector, AbstractCommunicationController, LinPhysicalChannel},
+    communication::{
+        AbstractCommunicationConnector, AbstractCommunicationController, LinPhysicalChannel,
+        channel_connector_of_controller, remove_connector_and_ports,
+    },
 };
 use autosar_data::{AutosarDataError, AutosarModel, Element, ElementName, ElementsIterator, WeakElement};
 
@@ -111,6 +114,21 @@ pub trait AbstractLinCommunicationController:
 
         Ok(connector)
     }
+
+    /// Disconnect this LIN controller from a [`LinPhysicalChannel`]
+    ///
+    /// This is the inverse of `connect_physical_channel`. The connector between the controller and the
+    /// channel is removed from the [`EcuInstance`], together with all its frame, pdu and signal ports.
+    /// The references to these ports in the triggerings of the channel are removed as well.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the controller is not connected to the channel
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while removing the connector
+    fn disconnect_physical_channel(&self, lin_channel: &LinPhysicalChannel) -> Result<(), AutosarAbstractionError> {
+        let connector = channel_connector_of_controller(self, lin_channel)?;
+        remove_connector_and_ports(connector)
+    }
 }
 
 //##################################################################
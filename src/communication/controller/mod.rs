@@ -1,5 +1,6 @@
+use crate::communication::{AbstractPhysicalChannel, PhysicalChannel};
 use crate::{AbstractionElement, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement};
-use autosar_data::{AutosarDataError, Element, ElementName};
+use autosar_data::{AutosarDataError, Element, ElementName, EnumItem, WeakElement};
 
 mod can;
 mod ethernet;
@@ -107,6 +108,104 @@ impl CommunicationController {
             CommunicationController::LinSlave(lin_slave) => lin_slave.remove(deep),
         }
     }
+
+    /// disconnect this `CommunicationController` from a physical channel
+    ///
+    /// The channel must have the same bus type as the controller.
+    /// See the `disconnect_physical_channel` functions of the individual controllers for details.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the controller is not connected to the channel
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while removing the connector
+    pub fn disconnect_physical_channel(&self, channel: &PhysicalChannel) -> Result<(), AutosarAbstractionError> {
+        match (self, channel) {
+            (CommunicationController::Can(can_ctrl), PhysicalChannel::Can(can_channel)) => {
+                can_ctrl.disconnect_physical_channel(can_channel)
+            }
+            (CommunicationController::Ethernet(eth_ctrl), PhysicalChannel::Ethernet(eth_channel)) => {
+                eth_ctrl.disconnect_physical_channel(eth_channel)
+            }
+            (CommunicationController::Flexray(flx_ctrl), PhysicalChannel::Flexray(flx_channel)) => {
+                flx_ctrl.disconnect_physical_channel(flx_channel)
+            }
+            (CommunicationController::LinMaster(lin_master), PhysicalChannel::Lin(lin_channel)) => {
+                lin_master.disconnect_physical_channel(lin_channel)
+            }
+            (CommunicationController::LinSlave(lin_slave), PhysicalChannel::Lin(lin_channel)) => {
+                lin_slave.disconnect_physical_channel(lin_channel)
+            }
+            _ => Err(AutosarAbstractionError::InvalidParameter(
+                "The controller is not connected to the channel".to_string(),
+            )),
+        }
+    }
+}
+
+// find the connector between a controller and a physical channel
+pub(crate) fn channel_connector_of_controller<C: AbstractCommunicationController, P: AbstractPhysicalChannel>(
+    controller: &C,
+    channel: &P,
+) -> Result<P::CommunicationConnectorType, AutosarAbstractionError> {
+    channel
+        .connectors()
+        .find(|connector| {
+            connector
+                .controller()
+                .is_ok_and(|ctrl| ctrl.element() == controller.element())
+        })
+        .ok_or_else(|| {
+            AutosarAbstractionError::InvalidParameter("The controller is not connected to the channel".to_string())
+        })
+}
+
+// remove a connector together with its ports, as well as all references to the connector and to the ports.
+// The ports are referenced by the frame, pdu and signal triggerings of the channel; reference lists
+// that become empty are removed too.
+pub(crate) fn remove_connector_and_ports<T: AbstractCommunicationConnector>(
+    connector: T,
+) -> Result<(), AutosarAbstractionError> {
+    let model = connector.element().model()?;
+    let mut ref_elements = Vec::new();
+    if let Some(port_instances) = connector.element().get_sub_element(ElementName::EcuCommPortInstances) {
+        for port in port_instances.sub_elements() {
+            ref_elements.extend(
+                model
+                    .get_references_to(&port.path()?)
+                    .iter()
+                    .filter_map(WeakElement::upgrade),
+            );
+        }
+    }
+    ref_elements.extend(
+        model
+            .get_references_to(&connector.element().path()?)
+            .iter()
+            .filter_map(WeakElement::upgrade)
+            .filter(|ref_elem| ref_elem.element_name() == ElementName::CommunicationConnectorRef),
+    );
+
+    for ref_elem in ref_elements {
+        let Some(parent) = ref_elem.parent()? else {
+            continue;
+        };
+        if ref_elem.element_name() == ElementName::CommunicationConnectorRef {
+            // CommConnectors -> CommunicationConnectorRefConditional -> CommunicationConnectorRef
+            if let Some(grandparent) = parent.parent()? {
+                grandparent.remove_sub_element(parent)?;
+            }
+        } else {
+            // e.g. FramePortRefs -> FramePortRef
+            parent.remove_sub_element(ref_elem)?;
+            if parent.sub_elements().next().is_none()
+                && let Some(grandparent) = parent.parent()?
+            {
+                grandparent.remove_sub_element(parent)?;
+            }
+        }
+    }
+
+    connector.remove(false)
 }
 
 //##################################################################
@@ -139,6 +238,70 @@ pub trait AbstractCommunicationController: AbstractionElement {
         // named_parent() can only return Ok(None) for an ArPackage
         self.element().named_parent()?.unwrap().try_into()
     }
+
+    /// set or remove the information whether the controller supports the wake-up over the bus
+    fn set_wake_up_by_controller_supported(&self, supported: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        let conditional = controller_conditional(self.element())?;
+        if let Some(supported) = supported {
+            conditional
+                .get_or_create_sub_element(ElementName::WakeUpByControllerSupported)?
+                .set_character_data(supported)?;
+        } else {
+            let _ = conditional.remove_sub_element_kind(ElementName::WakeUpByControllerSupported);
+        }
+        Ok(())
+    }
+
+    /// get the information whether the controller supports the wake-up over the bus
+    #[must_use]
+    fn wake_up_by_controller_supported(&self) -> Option<bool> {
+        existing_controller_conditional(self.element())?
+            .get_sub_element(ElementName::WakeUpByControllerSupported)?
+            .character_data()?
+            .parse_bool()
+    }
+}
+
+// get or create the <controller>-CONDITIONAL element that contains the settings of a communication controller
+pub(crate) fn controller_conditional(controller: &Element) -> Result<Element, AutosarAbstractionError> {
+    let (variants_name, conditional_name) = controller_conditional_names(controller)?;
+    Ok(controller
+        .get_or_create_sub_element(variants_name)?
+        .get_or_create_sub_element(conditional_name)?)
+}
+
+// get the <controller>-CONDITIONAL element of a communication controller, without creating it
+pub(crate) fn existing_controller_conditional(controller: &Element) -> Option<Element> {
+    let (variants_name, conditional_name) = controller_conditional_names(controller).ok()?;
+    controller
+        .get_sub_element(variants_name)?
+        .get_sub_element(conditional_name)
+}
+
+fn controller_conditional_names(controller: &Element) -> Result<(ElementName, ElementName), AutosarAbstractionError> {
+    let names = match controller.element_name() {
+        ElementName::CanCommunicationController => (
+            ElementName::CanCommunicationControllerVariants,
+            ElementName::CanCommunicationControllerConditional,
+        ),
+        ElementName::EthernetCommunicationController => (
+            ElementName::EthernetCommunicationControllerVariants,
+            ElementName::EthernetCommunicationControllerConditional,
+        ),
+        ElementName::FlexrayCommunicationController => (
+            ElementName::FlexrayCommunicationControllerVariants,
+            ElementName::FlexrayCommunicationControllerConditional,
+        ),
+        ElementName::LinMaster => (ElementName::LinMasterVariants, ElementName::LinMasterConditional),
+        ElementName::LinSlave => (ElementName::LinSlaveVariants, ElementName::LinSlaveConditional),
+        _ => {
+            return Err(AutosarAbstractionError::ConversionError {
+                element: controller.clone(),
+                dest: "CommunicationController".to_string(),
+            });
+        }
+    };
+    Ok(names)
 }
 
 //##################################################################
@@ -158,6 +321,54 @@ pub trait AbstractCommunicationConnector: AbstractionElement {
 
     /// Get the controller of the `CommunicationConnector`
     fn controller(&self) -> Result<Self::CommunicationControllerType, AutosarAbstractionError>;
+
+    /// set the partial network gateway type of the `CommunicationConnector`
+    fn set_pnc_gateway_type(&self, gateway_type: Option<PncGatewayType>) -> Result<(), AutosarAbstractionError> {
+        if let Some(gateway_type) = gateway_type {
+            self.element()
+                .get_or_create_sub_element(ElementName::PncGatewayType)?
+                .set_character_data::<EnumItem>(gateway_type.into())?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::PncGatewayType);
+        }
+        Ok(())
+    }
+
+    /// get the partial network gateway type of the `CommunicationConnector`
+    fn pnc_gateway_type(&self) -> Option<PncGatewayType> {
+        self.element()
+            .get_sub_element(ElementName::PncGatewayType)?
+            .character_data()?
+            .enum_value()?
+            .try_into()
+            .ok()
+    }
+
+    /// set the partial network filter array mask of the `CommunicationConnector`
+    ///
+    /// Each byte of the mask selects the PNCs in the corresponding byte of the PNC vector
+    /// that are relevant for the ECU.
+    fn set_pnc_filter_array_mask(&self, mask: Option<&[u8]>) -> Result<(), AutosarAbstractionError> {
+        let _ = self.element().remove_sub_element_kind(ElementName::PncFilterArrayMasks);
+        if let Some(mask) = mask {
+            let masks = self.element().create_sub_element(ElementName::PncFilterArrayMasks)?;
+            for value in mask {
+                masks
+                    .create_sub_element(ElementName::PncFilterArrayMask)?
+                    .set_character_data(u64::from(*value))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// get the partial network filter array mask of the `CommunicationConnector`
+    fn pnc_filter_array_mask(&self) -> Option<Vec<u8>> {
+        let masks = self.element().get_sub_element(ElementName::PncFilterArrayMasks)?;
+        masks
+            .sub_elements()
+            .map(|mask| mask.character_data()?.parse_integer())
+            .collect()
+    }
 }
 
 //##################################################################
@@ -226,6 +437,45 @@ impl AbstractCommunicationConnector for CommunicationConnector {
 
 //##################################################################
 
+/// The partial network gateway type of a `CommunicationConnector`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PncGatewayType {
+    /// the connector actively forwards PNC requests to the channel
+    Active,
+    /// the connector is not part of a PNC gateway
+    None,
+    /// the connector passively forwards PNC requests received on the channel
+    Passive,
+}
+
+impl TryFrom<EnumItem> for PncGatewayType {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(item: EnumItem) -> Result<Self, Self::Error> {
+        match item {
+            EnumItem::Active => Ok(PncGatewayType::Active),
+            EnumItem::None => Ok(PncGatewayType::None),
+            EnumItem::Passive => Ok(PncGatewayType::Passive),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: item.to_string(),
+                dest: "PncGatewayType".to_string(),
+            }),
+        }
+    }
+}
+
+impl From<PncGatewayType> for EnumItem {
+    fn from(gateway_type: PncGatewayType) -> Self {
+        match gateway_type {
+            PncGatewayType::Active => EnumItem::Active,
+            PncGatewayType::None => EnumItem::None,
+            PncGatewayType::Passive => EnumItem::Passive,
+        }
+    }
+}
+
+//##################################################################
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,402 @@
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
+};
+use autosar_data::{Element, ElementName};
+
+//##################################################################
+
+/// A `CryptoServiceKey` describes a key slot that is used by the crypto stack, e.g. for `SecOC` or TLS
+///
+/// Use [`ArPackage::create_crypto_service_key`] to create a new `CryptoServiceKey`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CryptoServiceKey(Element);
+abstraction_element!(CryptoServiceKey, CryptoServiceKey);
+impl IdentifiableAbstractionElement for CryptoServiceKey {}
+
+impl CryptoServiceKey {
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let key_elem = elements.create_named_sub_element(ElementName::CryptoServiceKey, name)?;
+
+        Ok(Self(key_elem))
+    }
+
+    /// set or remove the key storage type, which describes where the key is stored, e.g. "CUSTOM" or "HSM"
+    pub fn set_key_storage_type(&self, key_storage_type: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        if let Some(key_storage_type) = key_storage_type {
+            self.element()
+                .get_or_create_sub_element(ElementName::KeyStorageType)?
+                .set_character_data(key_storage_type)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::KeyStorageType);
+        }
+        Ok(())
+    }
+
+    /// get the key storage type
+    #[must_use]
+    pub fn key_storage_type(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::KeyStorageType)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// set or remove the length of the key in bits
+    pub fn set_length(&self, length: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(length) = length {
+            self.element()
+                .get_or_create_sub_element(ElementName::Length)?
+                .set_character_data(u64::from(length))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::Length);
+        }
+        Ok(())
+    }
+
+    /// get the length of the key in bits
+    #[must_use]
+    pub fn length(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::Length)?
+            .character_data()?
+            .parse_integer()
+    }
+}
+
+//##################################################################
+
+/// A `CryptoServiceQueue` is a job queue of the crypto stack
+///
+/// Use [`ArPackage::create_crypto_service_queue`] to create a new `CryptoServiceQueue`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CryptoServiceQueue(Element);
+abstraction_element!(CryptoServiceQueue, CryptoServiceQueue);
+impl IdentifiableAbstractionElement for CryptoServiceQueue {}
+
+impl CryptoServiceQueue {
+    pub(crate) fn new(name: &str, package: &ArPackage, queue_size: u32) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let queue_elem = elements.create_named_sub_element(ElementName::CryptoServiceQueue, name)?;
+
+        let queue = Self(queue_elem);
+        queue.set_queue_size(queue_size)?;
+
+        Ok(queue)
+    }
+
+    /// set the size of the queue
+    pub fn set_queue_size(&self, queue_size: u32) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::QueueSize)?
+            .set_character_data(u64::from(queue_size))?;
+        Ok(())
+    }
+
+    /// get the size of the queue
+    #[must_use]
+    pub fn queue_size(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::QueueSize)?
+            .character_data()?
+            .parse_integer()
+    }
+}
+
+//##################################################################
+
+/// A `CryptoServicePrimitive` describes a cryptographic algorithm, e.g. a MAC or a cipher
+///
+/// Use [`ArPackage::create_crypto_service_primitive`] to create a new `CryptoServicePrimitive`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CryptoServicePrimitive(Element);
+abstraction_element!(CryptoServicePrimitive, CryptoServicePrimitive);
+impl IdentifiableAbstractionElement for CryptoServicePrimitive {}
+
+impl CryptoServicePrimitive {
+    pub(crate) fn new(
+        name: &str,
+        package: &ArPackage,
+        algorithm_family: &str,
+        algorithm_mode: &str,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let primitive_elem = elements.create_named_sub_element(ElementName::CryptoServicePrimitive, name)?;
+
+        let primitive = Self(primitive_elem);
+        primitive.set_algorithm_family(algorithm_family)?;
+        primitive.set_algorithm_mode(algorithm_mode)?;
+
+        Ok(primitive)
+    }
+
+    /// set the algorithm family, e.g. "AES"
+    pub fn set_algorithm_family(&self, algorithm_family: &str) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::AlgorithmFamily)?
+            .set_character_data(algorithm_family)?;
+        Ok(())
+    }
+
+    /// get the algorithm family
+    #[must_use]
+    pub fn algorithm_family(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::AlgorithmFamily)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// set the algorithm mode, e.g. "CMAC"
+    pub fn set_algorithm_mode(&self, algorithm_mode: &str) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::AlgorithmMode)?
+            .set_character_data(algorithm_mode)?;
+        Ok(())
+    }
+
+    /// get the algorithm mode
+    #[must_use]
+    pub fn algorithm_mode(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::AlgorithmMode)?
+            .character_data()?
+            .string_value()
+    }
+}
+
+//##################################################################
+
+/// A `SecOcCryptoServiceMapping` assigns the crypto services, i.e. the algorithm, key and queue, that
+/// are used by `SecOC` to authenticate the `SecuredIPdu`s
+///
+/// The mapping is the only place where a `CryptoServiceKey` is referenced for `SecOC`; the `SecuredIPdu`s
+/// themselves only carry the `SecureCommunicationProps`.
+///
+/// Use [`ArPackage::create_secoc_crypto_service_mapping`] to create a new `SecOcCryptoServiceMapping`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SecOcCryptoServiceMapping(Element);
+abstraction_element!(SecOcCryptoServiceMapping, SecOcCryptoServiceMapping);
+impl IdentifiableAbstractionElement for SecOcCryptoServiceMapping {}
+
+impl SecOcCryptoServiceMapping {
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let mapping_elem = elements.create_named_sub_element(ElementName::SecOcCryptoServiceMapping, name)?;
+
+        Ok(Self(mapping_elem))
+    }
+
+    /// set or remove the `CryptoServicePrimitive` that is used for the authentication
+    pub fn set_authentication(
+        &self,
+        primitive: Option<&CryptoServicePrimitive>,
+    ) -> Result<(), AutosarAbstractionError> {
+        set_optional_reference(self.element(), ElementName::AuthenticationRef, primitive)
+    }
+
+    /// get the `CryptoServicePrimitive` that is used for the authentication
+    #[must_use]
+    pub fn authentication(&self) -> Option<CryptoServicePrimitive> {
+        get_reference(self.element(), ElementName::AuthenticationRef)
+    }
+
+    /// set or remove the `CryptoServiceKey` that is used for the authentication
+    pub fn set_crypto_service_key(&self, key: Option<&CryptoServiceKey>) -> Result<(), AutosarAbstractionError> {
+        set_optional_reference(self.element(), ElementName::CryptoServiceKeyRef, key)
+    }
+
+    /// get the `CryptoServiceKey` that is used for the authentication
+    #[must_use]
+    pub fn crypto_service_key(&self) -> Option<CryptoServiceKey> {
+        get_reference(self.element(), ElementName::CryptoServiceKeyRef)
+    }
+
+    /// set or remove the `CryptoServiceQueue` that processes the crypto jobs
+    pub fn set_crypto_service_queue(&self, queue: Option<&CryptoServiceQueue>) -> Result<(), AutosarAbstractionError> {
+        set_optional_reference(self.element(), ElementName::CryptoServiceQueueRef, queue)
+    }
+
+    /// get the `CryptoServiceQueue` that processes the crypto jobs
+    #[must_use]
+    pub fn crypto_service_queue(&self) -> Option<CryptoServiceQueue> {
+        get_reference(self.element(), ElementName::CryptoServiceQueueRef)
+    }
+}
+
+//##################################################################
+
+/// A `TlsCryptoServiceMapping` contains the cipher suites that can be used by TLS connections
+///
+/// A socket uses the mapping once it is assigned with [`SocketAddress::set_tls_crypto_mapping`].
+///
+/// [`SocketAddress::set_tls_crypto_mapping`]: crate::communication::SocketAddress::set_tls_crypto_mapping
+///
+/// Use [`ArPackage::create_tls_crypto_service_mapping`] to create a new `TlsCryptoServiceMapping`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TlsCryptoServiceMapping(Element);
+abstraction_element!(TlsCryptoServiceMapping, TlsCryptoServiceMapping);
+impl IdentifiableAbstractionElement for TlsCryptoServiceMapping {}
+
+impl TlsCryptoServiceMapping {
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let mapping_elem = elements.create_named_sub_element(ElementName::TlsCryptoServiceMapping, name)?;
+
+        Ok(Self(mapping_elem))
+    }
+
+    /// create a new `TlsCryptoCipherSuite` in the mapping
+    ///
+    /// The `cipher_suite_id` is the identifier of the cipher suite that is assigned by IANA, e.g. 0x1301 for `TLS_AES_128_GCM_SHA256`
+    pub fn create_tls_cipher_suite(
+        &self,
+        name: &str,
+        cipher_suite_id: u16,
+    ) -> Result<TlsCryptoCipherSuite, AutosarAbstractionError> {
+        let cipher_suites = self.element().get_or_create_sub_element(ElementName::TlsCipherSuites)?;
+        TlsCryptoCipherSuite::new(name, &cipher_suites, cipher_suite_id)
+    }
+
+    /// iterate over all `TlsCryptoCipherSuite`s in the mapping
+    pub fn tls_cipher_suites(&self) -> impl Iterator<Item = TlsCryptoCipherSuite> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::TlsCipherSuites)
+            .into_iter()
+            .flat_map(|cipher_suites| cipher_suites.sub_elements())
+            .filter_map(|elem| TlsCryptoCipherSuite::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// A `TlsCryptoCipherSuite` describes a cipher suite of a [`TlsCryptoServiceMapping`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TlsCryptoCipherSuite(Element);
+abstraction_element!(TlsCryptoCipherSuite, TlsCryptoCipherSuite);
+impl IdentifiableAbstractionElement for TlsCryptoCipherSuite {}
+
+impl TlsCryptoCipherSuite {
+    pub(crate) fn new(name: &str, parent: &Element, cipher_suite_id: u16) -> Result<Self, AutosarAbstractionError> {
+        let cipher_suite_elem = parent.create_named_sub_element(ElementName::TlsCryptoCipherSuite, name)?;
+
+        let cipher_suite = Self(cipher_suite_elem);
+        cipher_suite.set_cipher_suite_id(cipher_suite_id)?;
+
+        Ok(cipher_suite)
+    }
+
+    /// set the IANA identifier of the cipher suite
+    pub fn set_cipher_suite_id(&self, cipher_suite_id: u16) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::CipherSuiteId)?
+            .set_character_data(u64::from(cipher_suite_id))?;
+        Ok(())
+    }
+
+    /// get the IANA identifier of the cipher suite
+    #[must_use]
+    pub fn cipher_suite_id(&self) -> Option<u16> {
+        self.element()
+            .get_sub_element(ElementName::CipherSuiteId)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// set or remove the `CryptoServicePrimitive` that is used for the authentication
+    pub fn set_authentication(
+        &self,
+        primitive: Option<&CryptoServicePrimitive>,
+    ) -> Result<(), AutosarAbstractionError> {
+        set_optional_reference(self.element(), ElementName::AuthenticationRef, primitive)
+    }
+
+    /// get the `CryptoServicePrimitive` that is used for the authentication
+    #[must_use]
+    pub fn authentication(&self) -> Option<CryptoServicePrimitive> {
+        get_reference(self.element(), ElementName::AuthenticationRef)
+    }
+
+    /// set or remove the `CryptoServicePrimitive` that is used for the encryption
+    pub fn set_encryption(&self, primitive: Option<&CryptoServicePrimitive>) -> Result<(), AutosarAbstractionError> {
+        set_optional_reference(self.element(), ElementName::EncryptionRef, primitive)
+    }
+
+    /// get the `CryptoServicePrimitive` that is used for the encryption
+    #[must_use]
+    pub fn encryption(&self) -> Option<CryptoServicePrimitive> {
+        get_reference(self.element(), ElementName::EncryptionRef)
+    }
+}
+
+//##################################################################
+
+fn set_optional_reference<T: AbstractionElement>(
+    element: &Element,
+    reference_name: ElementName,
+    target: Option<&T>,
+) -> Result<(), AutosarAbstractionError> {
+    if let Some(target) = target {
+        element
+            .get_or_create_sub_element(reference_name)?
+            .set_reference_target(target.element())?;
+    } else {
+        let _ = element.remove_sub_element_kind(reference_name);
+    }
+    Ok(())
+}
+
+fn get_reference<T: TryFrom<Element>>(element: &Element, reference_name: ElementName) -> Option<T> {
+    let target = element.get_sub_element(reference_name)?.get_reference_target().ok()?;
+    T::try_from(target).ok()
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::AutosarModelAbstraction;
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn crypto_services() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+
+        let key = package.create_crypto_service_key("Key").unwrap();
+        key.set_key_storage_type(Some("HSM")).unwrap();
+        key.set_length(Some(128)).unwrap();
+        assert_eq!(key.key_storage_type().as_deref(), Some("HSM"));
+        assert_eq!(key.length(), Some(128));
+        key.set_length(None).unwrap();
+        assert_eq!(key.length(), None);
+
+        let queue = package.create_crypto_service_queue("Queue", 4).unwrap();
+        assert_eq!(queue.queue_size(), Some(4));
+
+        let cmac = package.create_crypto_service_primitive("Cmac", "AES", "CMAC").unwrap();
+        assert_eq!(cmac.algorithm_family().as_deref(), Some("AES"));
+        assert_eq!(cmac.algorithm_mode().as_deref(), Some("CMAC"));
+
+        let secoc_mapping = package.create_secoc_crypto_service_mapping("SecOcMapping").unwrap();
+        secoc_mapping.set_authentication(Some(&cmac)).unwrap();
+        secoc_mapping.set_crypto_service_key(Some(&key)).unwrap();
+        secoc_mapping.set_crypto_service_queue(Some(&queue)).unwrap();
+        assert_eq!(secoc_mapping.authentication(), Some(cmac.clone()));
+        assert_eq!(secoc_mapping.crypto_service_key(), Some(key));
+        assert_eq!(secoc_mapping.crypto_service_queue(), Some(queue));
+        secoc_mapping.set_crypto_service_queue(None).unwrap();
+        assert_eq!(secoc_mapping.crypto_service_queue(), None);
+
+        let gcm = package.create_crypto_service_primitive("Gcm", "AES", "GCM").unwrap();
+        let tls_mapping = package.create_tls_crypto_service_mapping("TlsMapping").unwrap();
+        let cipher_suite = tls_mapping.create_tls_cipher_suite("CipherSuite", 0x1301).unwrap();
+        cipher_suite.set_authentication(Some(&cmac)).unwrap();
+        cipher_suite.set_encryption(Some(&gcm)).unwrap();
+        assert_eq!(cipher_suite.cipher_suite_id(), Some(0x1301));
+        assert_eq!(cipher_suite.authentication(), Some(cmac));
+        assert_eq!(cipher_suite.encryption(), Some(gcm));
+        assert_eq!(tls_mapping.tls_cipher_suites().next(), Some(cipher_suite));
+    }
+}
@@ -191,6 +191,15 @@ impl DataTransformation {
             .and_then(|dts| DataTransformationSet::try_from(dts).ok())
     }
 
+    /// get the executeDespiteDataUnavailability flag of the `DataTransformation`
+    #[must_use]
+    pub fn execute_despite_data_unavailability(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::ExecuteDespiteDataUnavailability)?
+            .character_data()?
+            .parse_bool()
+    }
+
     /// Create an iterator over the `TransformationTechnologies` in the `DataTransformation`
     ///
     /// # Example
@@ -1174,7 +1183,9 @@ impl EndToEndTransformationISignalProps {
                 .inner_element()
                 .and_then(|inner| inner.remove_sub_element_kind(ElementName::DataIds).ok());
         } else {
-            let data_ids_elem = self.create_inner_element()?.create_sub_element(ElementName::DataIds)?;
+            let inner_elem = self.create_inner_element()?;
+            let _ = inner_elem.remove_sub_element_kind(ElementName::DataIds);
+            let data_ids_elem = inner_elem.create_sub_element(ElementName::DataIds)?;
             for data_id in data_ids {
                 data_ids_elem
                     .create_sub_element(ElementName::DataId)?
@@ -1288,6 +1299,94 @@ impl EndToEndTransformationISignalProps {
             .character_data()?
             .parse_integer()
     }
+
+    /// get the E2E profile of the referenced E2E transformer
+    #[must_use]
+    pub fn profile(&self) -> Option<E2EProfile> {
+        match self.transformer()?.config()? {
+            TransformationTechnologyConfig::E2E(e2e_config) => Some(e2e_config.profile),
+            _ => None,
+        }
+    }
+
+    /// check if the settings of the E2E transformation properties are consistent with the E2E profile
+    ///
+    /// The requirements depend on the profile:
+    /// - profiles 02 and 22 use a list of 16 data IDs, all other profiles use a single data ID
+    /// - profiles 01, 02, 05, 11 and 22 have a fixed data length, all other profiles use a minimum and maximum data length
+    /// - only the profiles 04m, 07m, 08m and 44m use a source ID
+    pub fn check_profile_settings(&self) -> Result<(), AutosarAbstractionError> {
+        let Some(profile) = self.profile() else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "EndToEndTransformationISignalProps does not reference a valid E2E transformer".to_string(),
+            ));
+        };
+
+        let data_ids = self.data_ids();
+        let (id_count, max_id) = match profile {
+            E2EProfile::P02 | E2EProfile::P22 => (16, 0xFF),
+            E2EProfile::P01 | E2EProfile::P05 | E2EProfile::P06 | E2EProfile::P11 => (1, 0xFFFF),
+            _ => (1, u32::MAX),
+        };
+        if data_ids.len() != id_count {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "E2E profile {profile:?} requires {id_count} data ID(s), but {} are configured",
+                data_ids.len()
+            )));
+        }
+        if let Some(data_id) = data_ids.iter().find(|data_id| **data_id > max_id) {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "Data ID {data_id} is too large for E2E profile {profile:?}"
+            )));
+        }
+
+        let fixed_length = matches!(
+            profile,
+            E2EProfile::P01 | E2EProfile::P02 | E2EProfile::P05 | E2EProfile::P11 | E2EProfile::P22
+        );
+        if fixed_length {
+            if self.data_length().is_none() {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "E2E profile {profile:?} requires a data length"
+                )));
+            }
+            if self.min_data_length().is_some() || self.max_data_length().is_some() {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "E2E profile {profile:?} does not use a minimum or maximum data length"
+                )));
+            }
+        } else {
+            let (Some(min_data_length), Some(max_data_length)) = (self.min_data_length(), self.max_data_length())
+            else {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "E2E profile {profile:?} requires a minimum and maximum data length"
+                )));
+            };
+            if min_data_length > max_data_length {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "The minimum data length {min_data_length} is larger than the maximum data length {max_data_length}"
+                )));
+            }
+            if self.data_length().is_some() {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "E2E profile {profile:?} does not use a fixed data length"
+                )));
+            }
+        }
+
+        let uses_source_id = matches!(
+            profile,
+            E2EProfile::P04m | E2EProfile::P07m | E2EProfile::P08m | E2EProfile::P44m
+        );
+        if uses_source_id != self.source_id().is_some() {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "E2E profile {profile:?} {} a source ID",
+                if uses_source_id { "requires" } else { "does not use" }
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 //#########################################################
@@ -1852,6 +1951,7 @@ mod test {
         assert!(result.is_ok());
         let dt = result.unwrap();
         assert_eq!(dt.data_transformation_set().unwrap(), dts);
+        assert_eq!(dt.execute_despite_data_unavailability(), Some(true));
 
         let dts2 = package.create_data_transformation_set("test_dts2").unwrap();
 
@@ -2066,6 +2166,7 @@ mod test {
             .create_e2e_transformation_isignal_props(&e2e_transformation)
             .unwrap();
         assert_eq!(e2e_props.transformer().unwrap(), e2e_transformation);
+        assert_eq!(e2e_props.profile(), Some(E2EProfile::P01));
         e2e_props.set_data_ids(&[1, 2, 3]).unwrap();
         e2e_props.set_data_length(Some(8)).unwrap();
         e2e_props.set_max_data_length(Some(16)).unwrap();
@@ -2076,6 +2177,18 @@ mod test {
         assert_eq!(e2e_props.max_data_length().unwrap(), 16);
         assert_eq!(e2e_props.min_data_length().unwrap(), 4);
         assert_eq!(e2e_props.source_id().unwrap(), 0);
+        // too many data IDs, min/max length and source ID are not valid for profile 01
+        assert!(e2e_props.check_profile_settings().is_err());
+        e2e_props.set_data_ids(&[0x1234]).unwrap();
+        assert_eq!(e2e_props.data_ids(), vec![0x1234]);
+        assert!(e2e_props.check_profile_settings().is_err());
+        e2e_props.set_max_data_length(None).unwrap();
+        e2e_props.set_min_data_length(None).unwrap();
+        assert!(e2e_props.check_profile_settings().is_err());
+        e2e_props.set_source_id(None).unwrap();
+        assert!(e2e_props.check_profile_settings().is_ok());
+        e2e_props.set_data_ids(&[0x12345]).unwrap();
+        assert!(e2e_props.check_profile_settings().is_err());
         e2e_props.set_data_ids(&[]).unwrap();
         e2e_props.set_data_length(None).unwrap();
         e2e_props.set_max_data_length(None).unwrap();
@@ -2089,6 +2202,32 @@ mod test {
 
         assert!(EndToEndTransformationISignalProps::try_from(e2e_props.element().clone()).is_ok());
 
+        // profile 05 uses a fixed data length
+        let Some(TransformationTechnologyConfig::E2E(e2e_config)) = e2e_transformation.config() else {
+            panic!("expected an E2E config");
+        };
+        let p05_transformation = dts
+            .create_transformation_technology(
+                "e2e_p05",
+                &TransformationTechnologyConfig::E2E(E2ETransformationTechnologyConfig {
+                    profile: E2EProfile::P05,
+                    ..e2e_config
+                }),
+            )
+            .unwrap();
+        let p05_props = signal
+            .create_e2e_transformation_isignal_props(&p05_transformation)
+            .unwrap();
+        assert_eq!(p05_props.profile(), Some(E2EProfile::P05));
+        p05_props.set_data_ids(&[0x1234]).unwrap();
+        p05_props.set_min_data_length(Some(4)).unwrap();
+        p05_props.set_max_data_length(Some(16)).unwrap();
+        assert!(p05_props.check_profile_settings().is_err());
+        p05_props.set_min_data_length(None).unwrap();
+        p05_props.set_max_data_length(None).unwrap();
+        p05_props.set_data_length(Some(8)).unwrap();
+        assert!(p05_props.check_profile_settings().is_ok());
+
         let someip_props = signal
             .create_someip_transformation_isignal_props(&someip_transformation)
             .unwrap();
@@ -1,12 +1,13 @@
 use crate::communication::{
     AbstractFrame, AbstractFrameTriggering, AbstractPdu, CanPhysicalChannel, CommunicationDirection, Frame, FramePort,
-    FrameTriggering, Pdu, PduToFrameMapping, PduTriggering,
+    FrameTriggering, Pdu, PduToFrameMapping, PduTriggering, frame_triggering_ecu_connections,
 };
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, ByteOrder, EcuInstance, IdentifiableAbstractionElement,
     abstraction_element, is_used_system_element, make_unique_name,
 };
 use autosar_data::{Element, ElementName, EnumItem};
+use std::ops::RangeInclusive;
 
 //##################################################################
 
@@ -105,7 +106,7 @@ impl CanFrameTriggering {
             .name()
             .ok_or(AutosarAbstractionError::InvalidParameter("invalid frame".to_string()))?;
         let ft_name = format!("FT_{frame_name}");
-        let ft_name = make_unique_name(&model, &base_path, &ft_name);
+        let ft_name = make_unique_name(&model, &base_path, &ft_name)?;
 
         let frame_triggerings = channel
             .element()
@@ -117,9 +118,10 @@ impl CanFrameTriggering {
             .set_reference_target(frame.element())?;
 
         let ft = Self(can_triggering);
-        ft.set_addressing_mode(addressing_mode)?;
+        ft.write_addressing_mode(addressing_mode)?;
         ft.set_frame_type(frame_type)?;
-        if let Err(error) = ft.set_identifier(identifier) {
+        let result = check_can_id(identifier, addressing_mode).and_then(|()| ft.write_identifier(identifier));
+        if let Err(error) = result {
             let _ = frame_triggerings.remove_sub_element(ft.0);
             return Err(error);
         }
@@ -160,18 +162,101 @@ impl CanFrameTriggering {
         Ok(())
     }
 
-    /// set the can id associated with this frame
-    pub fn set_identifier(&self, identifier: u32) -> Result<(), AutosarAbstractionError> {
-        let amode = self.addressing_mode().unwrap_or(CanAddressingMode::Standard);
-        if amode == CanAddressingMode::Standard && identifier > 0x7ff {
-            return Err(AutosarAbstractionError::InvalidParameter(format!(
-                "CAN-ID {identifier} is outside the 11-bit range allowed by standard addressing"
-            )));
-        } else if identifier > 0x1fff_ffff {
-            return Err(AutosarAbstractionError::InvalidParameter(format!(
-                "CAN-ID {identifier} is outside the 29-bit range allowed by extended addressing"
-            )));
+    /// move this `CanFrameTriggering` to another CAN physical channel
+    ///
+    /// A new frame triggering with the same identifier and settings is created in the target channel,
+    /// and it is connected to the same ECUs as the old one. The pdu triggerings, signal triggerings and ports
+    /// are recreated automatically. Finally the old frame triggering is removed.
+    ///
+    /// References from other elements to the old pdu triggerings are not updated.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the frame triggering has no frame or identifier, or one of the
+    ///   connected ECUs is not connected to the target channel. This is checked before the model is modified.
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while moving the frame triggering
+    pub fn move_to_channel(self, channel: &CanPhysicalChannel) -> Result<CanFrameTriggering, AutosarAbstractionError> {
+        if self.physical_channel()? == *channel {
+            return Ok(self);
+        }
+        let (Some(frame), Some(identifier)) = (self.frame(), self.identifier()) else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The frame triggering has no frame or no identifier".to_string(),
+            ));
+        };
+        let ecu_connections = frame_triggering_ecu_connections(&self, channel)?;
+
+        let addressing_mode = self.addressing_mode().unwrap_or(CanAddressingMode::Standard);
+        let frame_type = self.frame_type().unwrap_or(CanFrameType::Any);
+        let new_ft = channel.trigger_frame(&frame, identifier, addressing_mode, frame_type)?;
+        if let Some(rx_behavior) = self.rx_behavior() {
+            new_ft.set_rx_behavior(rx_behavior)?;
+        }
+        if let Some(tx_behavior) = self.tx_behavior() {
+            new_ft.set_tx_behavior(tx_behavior)?;
         }
+        new_ft.set_rx_identifier_range(self.rx_identifier_range())?;
+        new_ft.set_rx_mask(self.rx_mask())?;
+        for (ecu, direction) in ecu_connections {
+            new_ft.connect_to_ecu(&ecu, direction)?;
+        }
+
+        self.remove(false)?;
+        Ok(new_ft)
+    }
+
+    /// set the CAN-ID and the addressing mode of this frame triggering
+    ///
+    /// The identifier must fit into the range allowed by the addressing mode, and it must not be used by any
+    /// other frame triggering with the same addressing mode on the same channel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, communication::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_can_cluster("Cluster", &package, None)?;
+    /// # let channel = cluster.create_physical_channel("Channel")?;
+    /// # let frame1 = system.create_can_frame("Frame1", &package, 8)?;
+    /// # let frame2 = system.create_can_frame("Frame2", &package, 8)?;
+    /// let ft1 = channel.trigger_frame(&frame1, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)?;
+    /// let ft2 = channel.trigger_frame(&frame2, 0x200, CanAddressingMode::Standard, CanFrameType::Can20)?;
+    /// ft2.set_identifier(0x101, CanAddressingMode::Standard)?;
+    /// // 0x100 is already used by ft1
+    /// let result = ft2.set_identifier(0x100, CanAddressingMode::Standard);
+    /// assert!(matches!(result, Err(AutosarAbstractionError::ItemAlreadyExists)));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The identifier is outside the range allowed by the addressing mode
+    /// - [`AutosarAbstractionError::ItemAlreadyExists`] Another frame triggering on the channel already uses the identifier
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn set_identifier(
+        &self,
+        identifier: u32,
+        addressing_mode: CanAddressingMode,
+    ) -> Result<(), AutosarAbstractionError> {
+        check_can_id(identifier, addressing_mode)?;
+        let channel = self.physical_channel()?;
+        if channel.frame_triggerings().any(|ft| {
+            &ft != self
+                && ft.identifier() == Some(identifier)
+                && ft.addressing_mode().unwrap_or(CanAddressingMode::Standard) == addressing_mode
+        }) {
+            return Err(AutosarAbstractionError::ItemAlreadyExists);
+        }
+
+        self.write_addressing_mode(addressing_mode)?;
+        self.write_identifier(identifier)
+    }
+
+    fn write_identifier(&self, identifier: u32) -> Result<(), AutosarAbstractionError> {
         self.element()
             .get_or_create_sub_element(ElementName::Identifier)?
             .set_character_data(identifier.to_string())?;
@@ -188,8 +273,128 @@ impl CanFrameTriggering {
             .parse_integer()
     }
 
+    // check that a CAN-ID fits into the range allowed by the addressing mode of this frame triggering
+    fn check_identifier(&self, identifier: u32) -> Result<(), AutosarAbstractionError> {
+        check_can_id(
+            identifier,
+            self.addressing_mode().unwrap_or(CanAddressingMode::Standard),
+        )
+    }
+
+    /// set or remove the range of CAN-IDs that are received by this frame triggering
+    ///
+    /// This allows a single frame triggering to receive all frames with an identifier in the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The range is empty, or the identifiers are outside the range allowed by the addressing mode
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn set_rx_identifier_range(&self, range: Option<RangeInclusive<u32>>) -> Result<(), AutosarAbstractionError> {
+        if let Some(range) = range {
+            if range.is_empty() {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "The CAN-ID range {range:?} is empty"
+                )));
+            }
+            self.check_identifier(*range.start())?;
+            self.check_identifier(*range.end())?;
+            let range_elem = self
+                .element()
+                .get_or_create_sub_element(ElementName::RxIdentifierRange)?;
+            range_elem
+                .get_or_create_sub_element(ElementName::LowerCanId)?
+                .set_character_data(u64::from(*range.start()))?;
+            range_elem
+                .get_or_create_sub_element(ElementName::UpperCanId)?
+                .set_character_data(u64::from(*range.end()))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::RxIdentifierRange);
+        }
+
+        Ok(())
+    }
+
+    /// get the range of CAN-IDs that are received by this frame triggering
+    #[must_use]
+    pub fn rx_identifier_range(&self) -> Option<RangeInclusive<u32>> {
+        let range_elem = self.element().get_sub_element(ElementName::RxIdentifierRange)?;
+        let lower = range_elem
+            .get_sub_element(ElementName::LowerCanId)?
+            .character_data()?
+            .parse_integer()?;
+        let upper = range_elem
+            .get_sub_element(ElementName::UpperCanId)?
+            .character_data()?
+            .parse_integer()?;
+        Some(lower..=upper)
+    }
+
+    /// set or remove the reception mask of this frame triggering
+    ///
+    /// A received frame matches the frame triggering if `(received_id & rx_mask) == (identifier & rx_mask)`
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The mask is outside the range allowed by the addressing mode
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn set_rx_mask(&self, rx_mask: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(rx_mask) = rx_mask {
+            self.check_identifier(rx_mask)?;
+            self.element()
+                .get_or_create_sub_element(ElementName::RxMask)?
+                .set_character_data(u64::from(rx_mask))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::RxMask);
+        }
+
+        Ok(())
+    }
+
+    /// get the reception mask of this frame triggering
+    #[must_use]
+    pub fn rx_mask(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::RxMask)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// check if a received CAN-ID is accepted by this frame triggering
+    ///
+    /// The CAN-ID is accepted if it is inside the rx identifier range, or if it matches the identifier
+    /// after applying the rx mask. If neither is configured, then only the identifier itself is accepted.
+    #[must_use]
+    pub fn accepts_identifier(&self, can_id: u32) -> bool {
+        if let Some(range) = self.rx_identifier_range() {
+            return range.contains(&can_id);
+        }
+        let Some(identifier) = self.identifier() else {
+            return false;
+        };
+        let rx_mask = self.rx_mask().unwrap_or(u32::MAX);
+        (can_id & rx_mask) == (identifier & rx_mask)
+    }
+
     /// set the addressing mode for this frame triggering
+    ///
+    /// If an identifier is already set, then it is checked in the same way as in [`CanFrameTriggering::set_identifier`]:
+    /// it must fit into the range of the new addressing mode, and it must not be used by any other frame triggering
+    /// with the new addressing mode on the same channel.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The identifier is outside the range allowed by the addressing mode
+    /// - [`AutosarAbstractionError::ItemAlreadyExists`] Another frame triggering on the channel already uses the identifier
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
     pub fn set_addressing_mode(&self, addressing_mode: CanAddressingMode) -> Result<(), AutosarAbstractionError> {
+        if let Some(identifier) = self.identifier() {
+            self.set_identifier(identifier, addressing_mode)
+        } else {
+            self.write_addressing_mode(addressing_mode)
+        }
+    }
+
+    fn write_addressing_mode(&self, addressing_mode: CanAddressingMode) -> Result<(), AutosarAbstractionError> {
         self.element()
             .get_or_create_sub_element(ElementName::CanAddressingMode)?
             .set_character_data::<EnumItem>(addressing_mode.into())?;
@@ -231,6 +436,46 @@ impl CanFrameTriggering {
             .ok()
     }
 
+    /// set the frame type that is accepted when receiving this frame triggering
+    pub fn set_rx_behavior(&self, rx_behavior: CanFrameType) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::CanFrameRxBehavior)?
+            .set_character_data::<EnumItem>(rx_behavior.into())?;
+
+        Ok(())
+    }
+
+    /// get the frame type that is accepted when receiving this frame triggering
+    #[must_use]
+    pub fn rx_behavior(&self) -> Option<CanFrameType> {
+        self.element()
+            .get_sub_element(ElementName::CanFrameRxBehavior)?
+            .character_data()?
+            .enum_value()?
+            .try_into()
+            .ok()
+    }
+
+    /// set the frame type that is used when sending this frame triggering
+    pub fn set_tx_behavior(&self, tx_behavior: CanFrameType) -> Result<(), AutosarAbstractionError> {
+        if tx_behavior == CanFrameType::Any {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The tx behavior of a CAN frame triggering must be either Can20 or CanFd".to_string(),
+            ));
+        }
+        self.element()
+            .get_or_create_sub_element(ElementName::CanFrameTxBehavior)?
+            .set_character_data::<EnumItem>(tx_behavior.into())?;
+
+        Ok(())
+    }
+
+    /// get the frame type that is used when sending this frame triggering
+    #[must_use]
+    pub fn tx_behavior(&self) -> Option<CanFrameType> {
+        self.frame_type()
+    }
+
     pub(crate) fn add_pdu_triggering(&self, pdu: &Pdu) -> Result<PduTriggering, AutosarAbstractionError> {
         FrameTriggering::Can(self.clone()).add_pdu_triggering(pdu)
     }
@@ -337,12 +582,29 @@ impl From<CanFrameType> for EnumItem {
     }
 }
 
+// check that a CAN-ID fits into the range allowed by the addressing mode
+fn check_can_id(identifier: u32, addressing_mode: CanAddressingMode) -> Result<(), AutosarAbstractionError> {
+    if addressing_mode == CanAddressingMode::Standard && identifier > 0x7ff {
+        return Err(AutosarAbstractionError::InvalidParameter(format!(
+            "CAN-ID {identifier} is outside the 11-bit range allowed by standard addressing"
+        )));
+    } else if identifier > 0x1fff_ffff {
+        return Err(AutosarAbstractionError::InvalidParameter(format!(
+            "CAN-ID {identifier} is outside the 29-bit range allowed by extended addressing"
+        )));
+    }
+    Ok(())
+}
+
 //##################################################################
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{AutosarModelAbstraction, ByteOrder, SystemCategory, communication::AbstractPhysicalChannel};
+    use crate::{
+        AutosarModelAbstraction, ByteOrder, SystemCategory,
+        communication::{AbstractPhysicalChannel, FlexrayChannelName, FlexrayClusterSettings},
+    };
     use autosar_data::AutosarVersion;
 
     #[test]
@@ -389,7 +651,7 @@ mod test {
         assert_eq!(channel.frame_triggerings().count(), 2);
 
         // try to set an invalid identifier
-        let result = frame_triggering1.set_identifier(0xffff_ffff);
+        let result = frame_triggering1.set_identifier(0xffff_ffff, CanAddressingMode::Extended);
         assert!(result.is_err());
 
         // frame 1 already had a PDU mapped to it before it was connected to the channel, so a pdu triggering should have been created
@@ -437,6 +699,51 @@ mod test {
         assert_eq!(port2.communication_direction().unwrap(), CommunicationDirection::Out);
     }
 
+    #[test]
+    fn rx_identifier_range_and_behavior() {
+        let model = AutosarModelAbstraction::create("test", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+        let can_cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = can_cluster.create_physical_channel("Channel").unwrap();
+        let frame = system.create_can_frame("frame", &package, 8).unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x120, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+
+        // without range or mask only the identifier itself is accepted
+        assert!(frame_triggering.accepts_identifier(0x120));
+        assert!(!frame_triggering.accepts_identifier(0x121));
+
+        // rx mask
+        assert_eq!(frame_triggering.rx_mask(), None);
+        frame_triggering.set_rx_mask(Some(0x7f0)).unwrap();
+        assert_eq!(frame_triggering.rx_mask(), Some(0x7f0));
+        assert!(frame_triggering.accepts_identifier(0x12f));
+        assert!(!frame_triggering.accepts_identifier(0x130));
+        assert!(frame_triggering.set_rx_mask(Some(0x1fff_ffff)).is_err());
+        frame_triggering.set_rx_mask(None).unwrap();
+        assert_eq!(frame_triggering.rx_mask(), None);
+
+        // rx identifier range
+        assert_eq!(frame_triggering.rx_identifier_range(), None);
+        frame_triggering.set_rx_identifier_range(Some(0x100..=0x1ff)).unwrap();
+        assert_eq!(frame_triggering.rx_identifier_range(), Some(0x100..=0x1ff));
+        assert!(frame_triggering.accepts_identifier(0x1ab));
+        assert!(!frame_triggering.accepts_identifier(0x200));
+        assert!(frame_triggering.set_rx_identifier_range(Some(0x200..=0x100)).is_err());
+        assert!(frame_triggering.set_rx_identifier_range(Some(0x100..=0x800)).is_err());
+        frame_triggering.set_rx_identifier_range(None).unwrap();
+        assert_eq!(frame_triggering.rx_identifier_range(), None);
+
+        // rx and tx behavior can be configured independently
+        frame_triggering.set_rx_behavior(CanFrameType::Any).unwrap();
+        frame_triggering.set_tx_behavior(CanFrameType::CanFd).unwrap();
+        assert_eq!(frame_triggering.rx_behavior(), Some(CanFrameType::Any));
+        assert_eq!(frame_triggering.tx_behavior(), Some(CanFrameType::CanFd));
+        assert!(frame_triggering.set_tx_behavior(CanFrameType::Any).is_err());
+    }
+
     #[test]
     fn remove_frame_triggering() {
         let model = AutosarModelAbstraction::create("test", AutosarVersion::LATEST);
@@ -485,6 +792,72 @@ mod test {
         assert_eq!(channel.pdu_triggerings().count(), 0);
     }
 
+    #[test]
+    fn move_frame_triggering() {
+        let model = AutosarModelAbstraction::create("test", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+        let can_cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel_1 = can_cluster.create_physical_channel("Channel1").unwrap();
+        let channel_2 = can_cluster.create_physical_channel("Channel2").unwrap();
+
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let controller = ecu.create_can_communication_controller("Controller").unwrap();
+        controller.connect_physical_channel("Connector1", &channel_1).unwrap();
+
+        let frame = system.create_can_frame("frame", &package, 8).unwrap();
+        let pdu = system.create_isignal_ipdu("pdu", &package, 8).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let frame_triggering = channel_1
+            .trigger_frame(&frame, 0x123, CanAddressingMode::Extended, CanFrameType::CanFd)
+            .unwrap();
+        frame_triggering.set_rx_mask(Some(0x7F0)).unwrap();
+        frame_triggering
+            .connect_to_ecu(&ecu, CommunicationDirection::Out)
+            .unwrap();
+
+        // the ECU is not connected to the target channel
+        let result = frame_triggering.clone().move_to_channel(&channel_2);
+        assert!(matches!(result, Err(AutosarAbstractionError::InvalidParameter(_))));
+        assert_eq!(channel_1.frame_triggerings().count(), 1);
+
+        let other_controller = ecu.create_can_communication_controller("Controller2").unwrap();
+        other_controller
+            .connect_physical_channel("Connector2", &channel_2)
+            .unwrap();
+        let moved_ft = frame_triggering.move_to_channel(&channel_2).unwrap();
+        assert_eq!(channel_1.frame_triggerings().count(), 0);
+        assert_eq!(channel_1.pdu_triggerings().count(), 0);
+        assert_eq!(channel_2.frame_triggerings().count(), 1);
+        assert_eq!(channel_2.pdu_triggerings().count(), 1);
+        assert_eq!(moved_ft.physical_channel().unwrap(), channel_2);
+        assert_eq!(moved_ft.frame(), Some(frame.clone()));
+        assert_eq!(moved_ft.identifier(), Some(0x123));
+        assert_eq!(moved_ft.addressing_mode(), Some(CanAddressingMode::Extended));
+        assert_eq!(moved_ft.frame_type(), Some(CanFrameType::CanFd));
+        assert_eq!(moved_ft.rx_mask(), Some(0x7F0));
+        let frame_port = moved_ft.frame_ports().next().unwrap();
+        assert_eq!(frame_port.ecu().unwrap(), ecu);
+        assert_eq!(frame_port.communication_direction(), Some(CommunicationDirection::Out));
+        assert_eq!(frame.frame_triggerings().len(), 1);
+
+        // moving to the same channel does nothing
+        let same_ft = moved_ft.clone().move_to_channel(&channel_2).unwrap();
+        assert_eq!(same_ft, moved_ft);
+
+        // the generic FrameTriggering can't be moved to a channel of a different bus type
+        let flexray_cluster = system
+            .create_flexray_cluster("FlxCluster", &package, &FlexrayClusterSettings::default())
+            .unwrap();
+        let flexray_channel = flexray_cluster
+            .create_physical_channel("FlxChannel", FlexrayChannelName::A)
+            .unwrap();
+        let result = FrameTriggering::Can(moved_ft).move_to_channel(&flexray_channel.into());
+        assert!(result.is_err());
+    }
+
     #[test]
     fn remove_frame() {
         let model = AutosarModelAbstraction::create("test", AutosarVersion::LATEST);
@@ -516,4 +889,107 @@ mod test {
         assert_eq!(channel.frame_triggerings().count(), 0);
         assert_eq!(channel.pdu_triggerings().count(), 0);
     }
+
+    #[test]
+    fn remove_frame_deep() {
+        let model = AutosarModelAbstraction::create("test", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+        let can_cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = can_cluster.create_physical_channel("Channel").unwrap();
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let controller = ecu.create_can_communication_controller("Controller").unwrap();
+        controller.connect_physical_channel("Connection", &channel).unwrap();
+
+        let frame = system.create_can_frame("frame", &package, 8).unwrap();
+        let frame2 = system.create_can_frame("frame2", &package, 8).unwrap();
+        let pdu = system.create_isignal_ipdu("pdu", &package, 4).unwrap();
+        let shared_pdu = system.create_isignal_ipdu("shared_pdu", &package, 4).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        frame
+            .map_pdu(&shared_pdu, 32, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        frame2
+            .map_pdu(&shared_pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x123, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let frame_port = frame_triggering
+            .connect_to_ecu(&ecu, CommunicationDirection::Out)
+            .unwrap();
+        let pdu_ports: Vec<_> = frame_triggering
+            .pdu_triggerings()
+            .flat_map(|pt| pt.pdu_ports())
+            .collect();
+        assert_eq!(pdu_ports.len(), 2);
+
+        // remove the frame with deep=true
+        frame.remove(true).unwrap();
+        // the frame triggering, its pdu triggerings and all ports are removed
+        assert!(frame_triggering.element().path().is_err());
+        assert!(frame_port.element().path().is_err());
+        assert!(pdu_ports.iter().all(|port| port.element().path().is_err()));
+        assert_eq!(channel.frame_triggerings().count(), 0);
+        assert_eq!(channel.pdu_triggerings().count(), 0);
+        // the unused pdu is removed, while the pdu that is still mapped into frame2 remains
+        assert!(pdu.element().path().is_err());
+        assert_eq!(system.pdus().count(), 1);
+        assert_eq!(frame2.mapped_pdus().count(), 1);
+        assert_eq!(system.frames().count(), 1);
+    }
+
+    #[test]
+    fn set_identifier() {
+        let model = AutosarModelAbstraction::create("test", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+        let can_cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = can_cluster.create_physical_channel("Channel").unwrap();
+        let channel2 = can_cluster.create_physical_channel("Channel2").unwrap();
+        let frame1 = system.create_can_frame("frame1", &package, 8).unwrap();
+        let frame2 = system.create_can_frame("frame2", &package, 8).unwrap();
+        let frame3 = system.create_can_frame("frame3", &package, 8).unwrap();
+
+        let ft1 = channel
+            .trigger_frame(&frame1, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let ft2 = channel
+            .trigger_frame(&frame2, 0x200, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let ft3 = channel2
+            .trigger_frame(&frame3, 0x300, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+
+        // the identifier is already used by ft1 on the same channel
+        let result = ft2.set_identifier(0x100, CanAddressingMode::Standard);
+        assert!(matches!(result, Err(AutosarAbstractionError::ItemAlreadyExists)));
+        assert_eq!(ft2.identifier(), Some(0x200));
+        // the same identifier with extended addressing is a different CAN-ID
+        ft2.set_identifier(0x100, CanAddressingMode::Extended).unwrap();
+        assert_eq!(ft2.identifier(), Some(0x100));
+        assert_eq!(ft2.addressing_mode(), Some(CanAddressingMode::Extended));
+        // an identifier outside the range of standard addressing is rejected
+        let result = ft2.set_identifier(0x12345, CanAddressingMode::Standard);
+        assert!(matches!(result, Err(AutosarAbstractionError::InvalidParameter(_))));
+        assert_eq!(ft2.addressing_mode(), Some(CanAddressingMode::Extended));
+        // setting the current identifier again is allowed
+        ft1.set_identifier(0x100, CanAddressingMode::Standard).unwrap();
+        // triggerings on other channels are not checked
+        ft3.set_identifier(0x100, CanAddressingMode::Standard).unwrap();
+        assert_eq!(ft3.identifier(), Some(0x100));
+
+        // changing only the addressing mode applies the same checks
+        ft1.set_identifier(0x7ff, CanAddressingMode::Extended).unwrap();
+        ft2.set_identifier(0x7ff, CanAddressingMode::Standard).unwrap();
+        let result = ft1.set_addressing_mode(CanAddressingMode::Standard);
+        assert!(matches!(result, Err(AutosarAbstractionError::ItemAlreadyExists)));
+        assert_eq!(ft1.addressing_mode(), Some(CanAddressingMode::Extended));
+        ft1.set_identifier(0x12345, CanAddressingMode::Extended).unwrap();
+        let result = ft1.set_addressing_mode(CanAddressingMode::Standard);
+        assert!(matches!(result, Err(AutosarAbstractionError::InvalidParameter(_))));
+        assert_eq!(ft1.addressing_mode(), Some(CanAddressingMode::Extended));
+    }
 }
@@ -1,6 +1,6 @@
 use crate::communication::{
     AbstractFrame, AbstractFrameTriggering, AbstractPdu, CommunicationDirection, FlexrayPhysicalChannel, Frame,
-    FramePort, FrameTriggering, Pdu, PduToFrameMapping, PduTriggering,
+    FramePort, FrameTriggering, Pdu, PduToFrameMapping, PduTriggering, frame_triggering_ecu_connections,
 };
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, ByteOrder, EcuInstance, IdentifiableAbstractionElement,
@@ -104,7 +104,7 @@ impl FlexrayFrameTriggering {
             .name()
             .ok_or(AutosarAbstractionError::InvalidParameter("invalid frame".to_string()))?;
         let ft_name = format!("FT_{frame_name}");
-        let ft_name = make_unique_name(&model, &base_path, &ft_name);
+        let ft_name = make_unique_name(&model, &base_path, &ft_name)?;
 
         let frame_triggerings = channel
             .element()
@@ -249,6 +249,104 @@ impl FlexrayFrameTriggering {
         }
     }
 
+    /// set the base cycle of the flexray frame triggering
+    ///
+    /// The cycle repetition of the existing timing is kept.
+    /// If the frame triggering currently uses a cycle counter, then it is changed to a
+    /// repetition based timing with the cycle repetition `C1`.
+    pub fn set_base_cycle(&self, base_cycle: u8) -> Result<(), AutosarAbstractionError> {
+        let cycle_repetition = match self.timing() {
+            Some(FlexrayCommunicationCycle::Repetition { cycle_repetition, .. }) => cycle_repetition,
+            _ => CycleRepetition::C1,
+        };
+        self.set_timing(&FlexrayCommunicationCycle::Repetition {
+            base_cycle,
+            cycle_repetition,
+        })
+    }
+
+    /// set the cycle repetition of the flexray frame triggering
+    ///
+    /// The base cycle of the existing timing is kept.
+    /// If the frame triggering currently uses a cycle counter, then it is changed to a
+    /// repetition based timing with the base cycle 0.
+    pub fn set_cycle_repetition(&self, cycle_repetition: CycleRepetition) -> Result<(), AutosarAbstractionError> {
+        let base_cycle = match self.timing() {
+            Some(FlexrayCommunicationCycle::Repetition { base_cycle, .. }) => base_cycle,
+            _ => 0,
+        };
+        self.set_timing(&FlexrayCommunicationCycle::Repetition {
+            base_cycle,
+            cycle_repetition,
+        })
+    }
+
+    /// set or remove the `allow_dynamic_lsdu_length` flag of the flexray frame triggering
+    ///
+    /// If the flag is set, then a frame in the dynamic segment may be sent with a shorter length
+    /// than the configured frame length, depending on the data of the contained PDUs.
+    pub fn set_allow_dynamic_lsdu_length(
+        &self,
+        allow_dynamic_lsdu_length: Option<bool>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(allow_dynamic_lsdu_length) = allow_dynamic_lsdu_length {
+            self.element()
+                .get_or_create_sub_element(ElementName::AllowDynamicLSduLength)?
+                .set_character_data(allow_dynamic_lsdu_length)?;
+        } else {
+            let _ = self
+                .element()
+                .remove_sub_element_kind(ElementName::AllowDynamicLSduLength);
+        }
+        Ok(())
+    }
+
+    /// get the `allow_dynamic_lsdu_length` flag of the flexray frame triggering
+    #[must_use]
+    pub fn allow_dynamic_lsdu_length(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::AllowDynamicLSduLength)?
+            .character_data()?
+            .parse_bool()
+    }
+
+    /// move this `FlexrayFrameTriggering` to another flexray physical channel
+    ///
+    /// A new frame triggering with the same slot and timing is created in the target channel,
+    /// and it is connected to the same ECUs as the old one. The pdu triggerings, signal triggerings and ports
+    /// are recreated automatically. Finally the old frame triggering is removed.
+    ///
+    /// References from other elements to the old pdu triggerings are not updated.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the frame triggering has no frame, slot or timing, or one of the
+    ///   connected ECUs is not connected to the target channel. This is checked before the model is modified.
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while moving the frame triggering
+    pub fn move_to_channel(
+        self,
+        channel: &FlexrayPhysicalChannel,
+    ) -> Result<FlexrayFrameTriggering, AutosarAbstractionError> {
+        if self.physical_channel()? == *channel {
+            return Ok(self);
+        }
+        let (Some(frame), Some(slot), Some(timing)) = (self.frame(), self.slot(), self.timing()) else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The frame triggering has no frame, slot or timing".to_string(),
+            ));
+        };
+        let ecu_connections = frame_triggering_ecu_connections(&self, channel)?;
+
+        let new_ft = channel.trigger_frame(&frame, slot, &timing)?;
+        new_ft.set_allow_dynamic_lsdu_length(self.allow_dynamic_lsdu_length())?;
+        for (ecu, direction) in ecu_connections {
+            new_ft.connect_to_ecu(&ecu, direction)?;
+        }
+
+        self.remove(false)?;
+        Ok(new_ft)
+    }
+
     pub(crate) fn add_pdu_triggering(&self, pdu: &Pdu) -> Result<PduTriggering, AutosarAbstractionError> {
         FrameTriggering::Flexray(self.clone()).add_pdu_triggering(pdu)
     }
@@ -485,6 +583,27 @@ mod test {
         assert_eq!(mapping.byte_order().unwrap(), ByteOrder::MostSignificantByteFirst);
         assert_eq!(mapping.start_position().unwrap(), 7);
         assert_eq!(mapping.update_bit(), Some(8));
+
+        // re-time the frames
+        frame_triggering1.set_slot(5).unwrap();
+        assert_eq!(frame_triggering1.slot().unwrap(), 5);
+        frame_triggering1.set_cycle_repetition(CycleRepetition::C4).unwrap();
+        frame_triggering1.set_base_cycle(2).unwrap();
+        assert_eq!(
+            frame_triggering1.timing().unwrap(),
+            FlexrayCommunicationCycle::Repetition {
+                base_cycle: 2,
+                cycle_repetition: CycleRepetition::C4
+            }
+        );
+        frame_triggering2.set_base_cycle(3).unwrap();
+        assert_eq!(
+            frame_triggering2.timing().unwrap(),
+            FlexrayCommunicationCycle::Repetition {
+                base_cycle: 3,
+                cycle_repetition: CycleRepetition::C1
+            }
+        );
     }
 
     #[test]
@@ -606,4 +725,59 @@ mod test {
         assert_eq!(channel.frame_triggerings().count(), 0);
         assert_eq!(channel.pdu_triggerings().count(), 0);
     }
+
+    #[test]
+    fn remove_frame_deep() {
+        let model = AutosarModelAbstraction::create("test", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+        let flexray_cluster = system
+            .create_flexray_cluster("Cluster", &package, &FlexrayClusterSettings::default())
+            .unwrap();
+        let channel = flexray_cluster
+            .create_physical_channel("Channel", FlexrayChannelName::A)
+            .unwrap();
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let controller = ecu.create_flexray_communication_controller("Controller").unwrap();
+        controller.connect_physical_channel("Connection", &channel).unwrap();
+
+        let frame = system.create_flexray_frame("frame", &package, 8).unwrap();
+        let frame2 = system.create_flexray_frame("frame2", &package, 8).unwrap();
+        let pdu = system.create_isignal_ipdu("pdu", &package, 4).unwrap();
+        let shared_pdu = system.create_isignal_ipdu("shared_pdu", &package, 4).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        frame
+            .map_pdu(&shared_pdu, 32, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        frame2
+            .map_pdu(&shared_pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x123, &FlexrayCommunicationCycle::Counter { cycle_counter: 1 })
+            .unwrap();
+        let frame_port = frame_triggering
+            .connect_to_ecu(&ecu, CommunicationDirection::Out)
+            .unwrap();
+        let pdu_ports: Vec<_> = frame_triggering
+            .pdu_triggerings()
+            .flat_map(|pt| pt.pdu_ports())
+            .collect();
+        assert_eq!(pdu_ports.len(), 2);
+
+        // remove the frame with deep=true
+        frame.remove(true).unwrap();
+        // the frame triggering, its pdu triggerings and all ports are removed
+        assert!(frame_triggering.element().path().is_err());
+        assert!(frame_port.element().path().is_err());
+        assert!(pdu_ports.iter().all(|port| port.element().path().is_err()));
+        assert_eq!(channel.frame_triggerings().count(), 0);
+        assert_eq!(channel.pdu_triggerings().count(), 0);
+        // the unused pdu is removed, while the pdu that is still mapped into frame2 remains
+        assert!(pdu.element().path().is_err());
+        assert_eq!(system.pdus().count(), 1);
+        assert_eq!(frame2.mapped_pdus().count(), 1);
+        assert_eq!(system.frames().count(), 1);
+    }
 }
@@ -1,6 +1,6 @@
 use crate::communication::{
     AbstractFrame, AbstractFrameTriggering, AbstractPdu, CommunicationDirection, Frame, FramePort, FrameTriggering,
-    LinPhysicalChannel, Pdu, PduToFrameMapping, PduTriggering,
+    LinPhysicalChannel, Pdu, PduToFrameMapping, PduTriggering, frame_triggering_ecu_connections,
 };
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, ByteOrder, EcuInstance, IdentifiableAbstractionElement,
@@ -354,7 +354,7 @@ impl LinFrameTriggering {
             .name()
             .ok_or(AutosarAbstractionError::InvalidParameter("invalid frame".to_string()))?;
         let ft_name = format!("FT_{frame_name}");
-        let ft_name = make_unique_name(&model, &base_path, &ft_name);
+        let ft_name = make_unique_name(&model, &base_path, &ft_name)?;
 
         let frame_triggerings = channel
             .element()
@@ -422,6 +422,39 @@ impl LinFrameTriggering {
             .parse_integer()
     }
 
+    /// move this `LinFrameTriggering` to another LIN physical channel
+    ///
+    /// A new frame triggering with the same identifier is created in the target channel,
+    /// and it is connected to the same ECUs as the old one. The pdu triggerings, signal triggerings and ports
+    /// are recreated automatically. Finally the old frame triggering is removed.
+    ///
+    /// References from other elements to the old pdu triggerings are not updated.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the frame triggering has no frame or identifier, or one of the
+    ///   connected ECUs is not connected to the target channel. This is checked before the model is modified.
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while moving the frame triggering
+    pub fn move_to_channel(self, channel: &LinPhysicalChannel) -> Result<LinFrameTriggering, AutosarAbstractionError> {
+        if self.physical_channel()? == *channel {
+            return Ok(self);
+        }
+        let (Some(frame), Some(identifier)) = (self.frame(), self.identifier()) else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The frame triggering has no frame or no identifier".to_string(),
+            ));
+        };
+        let ecu_connections = frame_triggering_ecu_connections(&self, channel)?;
+
+        let new_ft = channel.trigger_frame(&frame, identifier)?;
+        for (ecu, direction) in ecu_connections {
+            new_ft.connect_to_ecu(&ecu, direction)?;
+        }
+
+        self.remove(false)?;
+        Ok(new_ft)
+    }
+
     pub(crate) fn add_pdu_triggering(&self, pdu: &Pdu) -> Result<PduTriggering, AutosarAbstractionError> {
         FrameTriggering::Lin(self.clone()).add_pdu_triggering(pdu)
     }
@@ -1,5 +1,5 @@
 use crate::communication::{
-    AbstractPdu, AbstractPhysicalChannel, CommunicationDirection, Pdu, PduTriggering, PhysicalChannel,
+    AbstractPdu, AbstractPhysicalChannel, CommunicationDirection, Pdu, PduTriggering, PhysicalChannel, signal_bits,
 };
 use crate::{
     AbstractionElement, AutosarAbstractionError, ByteOrder, EcuInstance, IdentifiableAbstractionElement,
@@ -15,6 +15,7 @@ use autosar_data::{AutosarDataError, Element, ElementName, EnumItem};
 pub use can::*;
 pub use flexray::*;
 pub use lin::*;
+use std::collections::HashSet;
 
 //##################################################################
 
@@ -59,6 +60,85 @@ pub trait AbstractFrame: AbstractionElement {
             .and_then(|elem| elem.character_data())
             .and_then(|cdata| cdata.parse_integer())
     }
+
+    /// check the layout of the PDUs in the frame
+    ///
+    /// The check verifies that all mapped PDUs fit into the frame, that they do not overlap each other,
+    /// and that the update bits neither collide with any PDU nor with other update bits.
+    /// An empty list is returned if no problems were found.
+    fn check_layout(&self) -> Vec<FrameLayoutIssue> {
+        let frame_bits = self.length().unwrap_or(0) * 8;
+        let mut issues = Vec::new();
+
+        let mut entries = Vec::new();
+        for mapping in self.mapped_pdus() {
+            let (Some(pdu_length), Some(start_position), Some(byte_order)) = (
+                mapping.pdu().and_then(|pdu| pdu.length()),
+                mapping.start_position(),
+                mapping.byte_order(),
+            ) else {
+                issues.push(FrameLayoutIssue::IncompleteMapping(mapping));
+                continue;
+            };
+            let pdu_bits: HashSet<u32> = signal_bits(start_position, u64::from(pdu_length) * 8, byte_order)
+                .into_iter()
+                .collect();
+            if pdu_bits.iter().any(|bit| *bit >= frame_bits) {
+                issues.push(FrameLayoutIssue::PduOutOfRange(mapping.clone()));
+            }
+            let update_bit = mapping.update_bit();
+            if let Some(update_bit) = update_bit {
+                if update_bit >= frame_bits {
+                    issues.push(FrameLayoutIssue::UpdateBitOutOfRange(mapping.clone()));
+                } else if pdu_bits.contains(&update_bit) {
+                    issues.push(FrameLayoutIssue::UpdateBitCollision(mapping.clone(), mapping.clone()));
+                }
+            }
+            entries.push((mapping, pdu_bits, update_bit));
+        }
+
+        for (idx, (mapping_a, bits_a, update_bit_a)) in entries.iter().enumerate() {
+            for (mapping_b, bits_b, update_bit_b) in &entries[idx + 1..] {
+                if !bits_a.is_disjoint(bits_b) {
+                    issues.push(FrameLayoutIssue::PduOverlap(mapping_a.clone(), mapping_b.clone()));
+                }
+                if update_bit_a.is_some_and(|bit| bits_b.contains(&bit))
+                    || (update_bit_a.is_some() && update_bit_a == update_bit_b)
+                {
+                    issues.push(FrameLayoutIssue::UpdateBitCollision(
+                        mapping_a.clone(),
+                        mapping_b.clone(),
+                    ));
+                }
+                if update_bit_b.is_some_and(|bit| bits_a.contains(&bit)) {
+                    issues.push(FrameLayoutIssue::UpdateBitCollision(
+                        mapping_b.clone(),
+                        mapping_a.clone(),
+                    ));
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+//##################################################################
+
+/// A problem in the layout of a frame, as found by [`AbstractFrame::check_layout`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameLayoutIssue {
+    /// The mapping is incomplete: the PDU, its length, the start position or the byte order is missing
+    IncompleteMapping(PduToFrameMapping),
+    /// The PDU extends beyond the end of the frame
+    PduOutOfRange(PduToFrameMapping),
+    /// The PDUs of the two mappings overlap
+    PduOverlap(PduToFrameMapping, PduToFrameMapping),
+    /// The update bit of the mapping is outside of the frame
+    UpdateBitOutOfRange(PduToFrameMapping),
+    /// The update bit of the first mapping collides with the PDU or the update bit of the second mapping.
+    /// Both mappings are the same if the update bit is located inside its own PDU.
+    UpdateBitCollision(PduToFrameMapping, PduToFrameMapping),
 }
 
 //##################################################################
@@ -140,6 +220,15 @@ impl TryFrom<Element> for Frame {
 }
 
 impl Frame {
+    /// remove this `Frame` from the model
+    pub fn remove(self, deep: bool) -> Result<(), AutosarAbstractionError> {
+        match self {
+            Self::Can(cf) => cf.remove(deep),
+            Self::Flexray(ff) => ff.remove(deep),
+            Self::Lin(lf) => lf.remove(deep),
+        }
+    }
+
     fn map_pdu_internal(
         &self,
         pdu: &Pdu,
@@ -176,7 +265,7 @@ impl Frame {
         // create and return the new mapping
         let model = self.element().model()?;
         let base_path = self.element().path()?;
-        let name = make_unique_name(&model, &base_path, &pdu_name);
+        let name = make_unique_name(&model, &base_path, &pdu_name)?;
 
         let mappings = self
             .element()
@@ -401,6 +490,50 @@ impl FrameTriggering {
             Self::Lin(lft) => lft.remove(deep),
         }
     }
+
+    /// move this `FrameTriggering` to another physical channel of the same bus type
+    ///
+    /// See [`CanFrameTriggering::move_to_channel`], [`FlexrayFrameTriggering::move_to_channel`]
+    /// and [`LinFrameTriggering::move_to_channel`] for details.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the channel uses a different bus type than the frame triggering,
+    ///   or the move is not possible for one of the reasons given in the bus specific functions
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while moving the frame triggering
+    pub fn move_to_channel(self, channel: &PhysicalChannel) -> Result<FrameTriggering, AutosarAbstractionError> {
+        match (self, channel) {
+            (Self::Can(cft), PhysicalChannel::Can(can_channel)) => Ok(cft.move_to_channel(can_channel)?.into()),
+            (Self::Flexray(fft), PhysicalChannel::Flexray(flexray_channel)) => {
+                Ok(fft.move_to_channel(flexray_channel)?.into())
+            }
+            (Self::Lin(lft), PhysicalChannel::Lin(lin_channel)) => Ok(lft.move_to_channel(lin_channel)?.into()),
+            _ => Err(AutosarAbstractionError::InvalidParameter(
+                "The frame triggering can only be moved to a channel of the same bus type".to_string(),
+            )),
+        }
+    }
+}
+
+// get the ECUs and communication directions of all frame ports of a frame triggering, in preparation for moving it
+// to a different channel. All ECUs must also be connected to the target channel.
+pub(crate) fn frame_triggering_ecu_connections<T: AbstractFrameTriggering, C: AbstractPhysicalChannel>(
+    frame_triggering: &T,
+    target_channel: &C,
+) -> Result<Vec<(EcuInstance, CommunicationDirection)>, AutosarAbstractionError> {
+    let mut connections = Vec::new();
+    for frame_port in frame_triggering.frame_ports() {
+        if let (Ok(ecu), Some(direction)) = (frame_port.ecu(), frame_port.communication_direction()) {
+            if target_channel.ecu_connector(&ecu).is_none() {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "The ECU {} is not connected to the target channel",
+                    ecu.name().unwrap_or_default()
+                )));
+            }
+            connections.push((ecu, direction));
+        }
+    }
+    Ok(connections)
 }
 
 //##################################################################
@@ -615,4 +748,45 @@ mod test {
         let err = Frame::try_from(model.root_element().clone());
         assert!(err.is_err());
     }
+
+    #[test]
+    fn check_layout() {
+        let model = AutosarModelAbstraction::create("filename", autosar_data::AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+
+        let frame = system.create_can_frame("CanFrame", &package, 8).unwrap();
+        let pdu_1 = system.create_isignal_ipdu("Pdu1", &package, 3).unwrap();
+        let pdu_2 = system.create_isignal_ipdu("Pdu2", &package, 4).unwrap();
+        let mapping_1 = frame
+            .map_pdu(&pdu_1, 0, ByteOrder::MostSignificantByteLast, Some(63))
+            .unwrap();
+        let mapping_2 = frame
+            .map_pdu(&pdu_2, 24, ByteOrder::MostSignificantByteLast, Some(62))
+            .unwrap();
+        assert!(frame.check_layout().is_empty());
+
+        // pdu_3 overlaps pdu_2 and uses the same update bit as pdu_1
+        let pdu_3 = system.create_isignal_ipdu("Pdu3", &package, 1).unwrap();
+        let mapping_3 = frame
+            .map_pdu(&pdu_3, 40, ByteOrder::MostSignificantByteLast, Some(63))
+            .unwrap();
+        let issues = frame.check_layout();
+        assert_eq!(issues.len(), 2);
+        assert!(issues.contains(&FrameLayoutIssue::PduOverlap(mapping_2.clone(), mapping_3.clone())));
+        assert!(issues.contains(&FrameLayoutIssue::UpdateBitCollision(
+            mapping_1.clone(),
+            mapping_3.clone()
+        )));
+        mapping_3.remove(false).unwrap();
+
+        // pdu_4 extends beyond the end of the frame, and its update bit is outside of the frame
+        let pdu_4 = system.create_isignal_ipdu("Pdu4", &package, 2).unwrap();
+        let mapping_4 = frame
+            .map_pdu(&pdu_4, 56, ByteOrder::MostSignificantByteLast, Some(64))
+            .unwrap();
+        let issues = frame.check_layout();
+        assert!(issues.contains(&FrameLayoutIssue::PduOutOfRange(mapping_4.clone())));
+        assert!(issues.contains(&FrameLayoutIssue::UpdateBitOutOfRange(mapping_4.clone())));
+    }
 }
@@ -0,0 +1,76 @@
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, EcuInstance, Element, IdentifiableAbstractionElement,
+    abstraction_element,
+};
+use autosar_data::ElementName;
+
+//##################################################################
+
+/// A `Gateway` describes an `EcuInstance` that forwards frames, PDUs or signals between physical channels
+///
+/// A gateway ECU may send and receive the same frame, PDU or signal, which would be an error for any other ECU.
+///
+/// Use [`System::create_gateway`](crate::System::create_gateway) to create a new `Gateway`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Gateway(Element);
+abstraction_element!(Gateway, Gateway);
+impl IdentifiableAbstractionElement for Gateway {}
+
+impl Gateway {
+    pub(crate) fn new(name: &str, package: &ArPackage, ecu: &EcuInstance) -> Result<Self, AutosarAbstractionError> {
+        let pkg_elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let gateway_elem = pkg_elements.create_named_sub_element(ElementName::Gateway, name)?;
+        let gateway = Self(gateway_elem);
+
+        let result = gateway.set_ecu(ecu);
+        if let Err(err) = result {
+            pkg_elements.remove_sub_element(gateway.0)?;
+            return Err(err);
+        }
+
+        Ok(gateway)
+    }
+
+    /// set the `EcuInstance` that acts as the gateway
+    pub fn set_ecu(&self, ecu: &EcuInstance) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::EcuRef)?
+            .set_reference_target(ecu.element())?;
+        Ok(())
+    }
+
+    /// get the `EcuInstance` that acts as the gateway
+    #[must_use]
+    pub fn ecu(&self) -> Option<EcuInstance> {
+        let ecu_elem = self
+            .element()
+            .get_sub_element(ElementName::EcuRef)?
+            .get_reference_target()
+            .ok()?;
+        EcuInstance::try_from(ecu_elem).ok()
+    }
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use crate::{AutosarModelAbstraction, SystemCategory};
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn gateway() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let other_ecu = system.create_ecu_instance("OtherEcu", &package).unwrap();
+
+        let gateway = system.create_gateway("Gateway", &package, &ecu).unwrap();
+        assert_eq!(gateway.ecu(), Some(ecu));
+        assert_eq!(system.gateways().collect::<Vec<_>>(), vec![gateway.clone()]);
+
+        gateway.set_ecu(&other_ecu).unwrap();
+        assert_eq!(gateway.ecu(), Some(other_ecu));
+    }
+}
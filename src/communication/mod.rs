@@ -115,8 +115,10 @@ use autosar_data::EnumItem;
 
 mod cluster;
 mod controller;
+mod crypto;
 mod data_transformation;
 mod frame;
+mod gateway;
 mod network_management;
 mod pdu;
 mod physical_channel;
@@ -125,8 +127,10 @@ mod transport_layer;
 
 pub use cluster::*;
 pub use controller::*;
+pub use crypto::*;
 pub use data_transformation::*;
 pub use frame::*;
+pub use gateway::*;
 pub use network_management::*;
 pub use pdu::*;
 pub use physical_channel::*;
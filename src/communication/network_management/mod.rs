@@ -1,11 +1,12 @@
 use crate::communication::{
-    AbstractCluster, AbstractCommunicationController, CanCluster, EthernetCluster, FlexrayCluster, NmPdu,
+    AbstractCluster, AbstractCommunicationController, CanCluster, CommunicationController, EthernetCluster,
+    FlexrayCluster, NmPdu,
 };
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
     abstraction_element,
 };
-use autosar_data::{Element, ElementName};
+use autosar_data::{Element, ElementName, EnumItem};
 
 mod can_nm;
 mod flexray_nm;
@@ -564,6 +565,217 @@ impl NmEcu {
             .and_then(|elem| elem.character_data())
             .and_then(|cdata| cdata.parse_float())
     }
+
+    /// create the `NmCoordinator` of this `NmEcu`
+    ///
+    /// An `NmEcu` can have at most one `NmCoordinator`; an existing coordinator is replaced.
+    /// The coordinator is used by gateway ECUs to coordinate the shutdown of multiple NM clusters,
+    /// e.g. a CAN and an Ethernet cluster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let ecu = system.create_ecu_instance("Gateway", &package)?;
+    /// let nm_config = system.create_nm_config("NmConfig", &package)?;
+    /// let nm_ecu = nm_config.create_nm_ecu("NmGateway", &ecu)?;
+    /// let coordinator = nm_ecu.create_nm_coordinator(0)?;
+    /// coordinator.set_nm_active_coordinator(Some(true))?;
+    /// assert_eq!(nm_ecu.nm_coordinator(), Some(coordinator));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while creating the `NmCoordinator`
+    pub fn create_nm_coordinator(&self, index: u32) -> Result<NmCoordinator, AutosarAbstractionError> {
+        let _ = self.element().remove_sub_element_kind(ElementName::NmCoordinator);
+        NmCoordinator::new(self.element(), index)
+    }
+
+    /// get the `NmCoordinator` of this `NmEcu`
+    #[must_use]
+    pub fn nm_coordinator(&self) -> Option<NmCoordinator> {
+        self.element()
+            .get_sub_element(ElementName::NmCoordinator)
+            .and_then(|elem| elem.try_into().ok())
+    }
+}
+
+//##################################################################
+
+/// The `NmCoordinator` coordinates the shutdown of several NM clusters on a gateway `NmEcu`
+///
+/// All `NmNodes` of the `NmEcu` that are assigned to the coordinator go to sleep together.
+/// Use [`NmEcu::create_nm_coordinator`] to create a new `NmCoordinator`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NmCoordinator(Element);
+abstraction_element!(NmCoordinator, NmCoordinator);
+
+impl NmCoordinator {
+    pub(crate) fn new(parent: &Element, index: u32) -> Result<Self, AutosarAbstractionError> {
+        let nm_coordinator_elem = parent.create_sub_element(ElementName::NmCoordinator)?;
+        let nm_coordinator = Self(nm_coordinator_elem);
+        nm_coordinator.set_index(index)?;
+
+        Ok(nm_coordinator)
+    }
+
+    /// get the `NmEcu` that contains this `NmCoordinator`
+    pub fn nm_ecu(&self) -> Result<NmEcu, AutosarAbstractionError> {
+        let nm_ecu_elem = self.element().named_parent()?.unwrap();
+        NmEcu::try_from(nm_ecu_elem)
+    }
+
+    /// set the index of the coordinator
+    pub fn set_index(&self, index: u32) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::Index)?
+            .set_character_data(u64::from(index))?;
+        Ok(())
+    }
+
+    /// get the index of the coordinator
+    #[must_use]
+    pub fn index(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::Index)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_integer())
+    }
+
+    /// set or remove the nmActiveCoordinator flag
+    ///
+    /// An active coordinator actively keeps the coordinated clusters awake, while a passive coordinator
+    /// only forwards the shutdown of the clusters.
+    pub fn set_nm_active_coordinator(&self, value: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        if let Some(value) = value {
+            self.element()
+                .get_or_create_sub_element(ElementName::NmActiveCoordinator)?
+                .set_character_data(value)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::NmActiveCoordinator);
+        }
+        Ok(())
+    }
+
+    /// get the nmActiveCoordinator flag
+    #[must_use]
+    pub fn nm_active_coordinator(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::NmActiveCoordinator)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_bool())
+    }
+
+    /// set or remove the nmCoordSyncSupport flag
+    ///
+    /// If this flag is set, then the coordinator synchronizes the shutdown of the coordinated clusters.
+    pub fn set_nm_coord_sync_support(&self, value: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        if let Some(value) = value {
+            self.element()
+                .get_or_create_sub_element(ElementName::NmCoordSyncSupport)?
+                .set_character_data(value)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::NmCoordSyncSupport);
+        }
+        Ok(())
+    }
+
+    /// get the nmCoordSyncSupport flag
+    #[must_use]
+    pub fn nm_coord_sync_support(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::NmCoordSyncSupport)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_bool())
+    }
+
+    /// set or remove the nmGlobalCoordinatorTime value
+    ///
+    /// This is the maximum time in seconds that the coordinated shutdown of all clusters may take.
+    pub fn set_nm_global_coordinator_time(&self, value: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        if let Some(value) = value {
+            self.element()
+                .get_or_create_sub_element(ElementName::NmGlobalCoordinatorTime)?
+                .set_character_data(value)?;
+        } else {
+            let _ = self
+                .element()
+                .remove_sub_element_kind(ElementName::NmGlobalCoordinatorTime);
+        }
+        Ok(())
+    }
+
+    /// get the nmGlobalCoordinatorTime value
+    #[must_use]
+    pub fn nm_global_coordinator_time(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::NmGlobalCoordinatorTime)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_float())
+    }
+
+    /// add an `NmNode` to the coordinator
+    ///
+    /// The node must belong to the same `NmEcu` as the coordinator.
+    /// Adding a node that is already part of the coordinator has no effect.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The node does not belong to the `NmEcu` of the coordinator
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while adding the node
+    pub fn add_nm_node<T: AbstractNmNode>(&self, nm_node: &T) -> Result<(), AutosarAbstractionError> {
+        if nm_node.nm_ecu() != Some(self.nm_ecu()?) {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The NmNode does not belong to the NmEcu of the NmCoordinator".to_string(),
+            ));
+        }
+        if self.nm_nodes().any(|node| node.element() == nm_node.element()) {
+            return Ok(());
+        }
+        self.element()
+            .get_or_create_sub_element(ElementName::NmNodeRefs)?
+            .create_sub_element(ElementName::NmNodeRef)?
+            .set_reference_target(nm_node.element())?;
+        Ok(())
+    }
+
+    /// iterate over all `NmNodes` of the coordinator
+    pub fn nm_nodes(&self) -> impl Iterator<Item = NmNode> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::NmNodeRefs)
+            .into_iter()
+            .flat_map(|nodes| nodes.sub_elements())
+            .filter_map(|refelem| {
+                refelem
+                    .get_reference_target()
+                    .ok()
+                    .and_then(|elem| elem.try_into().ok())
+            })
+    }
+
+    /// get the `NmClusters` that are coordinated by this coordinator
+    ///
+    /// These are the clusters of all `NmNodes` of the coordinator. Each cluster is only listed once.
+    #[must_use]
+    pub fn coordinated_clusters(&self) -> Vec<NmCluster> {
+        let mut clusters = Vec::new();
+        for nm_node in self.nm_nodes() {
+            if let Some(cluster) = nm_node.nm_cluster()
+                && !clusters.contains(&cluster)
+            {
+                clusters.push(cluster);
+            }
+        }
+        clusters
+    }
 }
 
 //##################################################################
@@ -705,6 +917,138 @@ pub trait AbstractNmNode: AbstractionElement {
                     .and_then(|elem| elem.try_into().ok())
             })
     }
+
+    /// set or remove the nmCoordCluster value
+    ///
+    /// This is the index of the coordination cluster that the node belongs to.
+    fn set_coord_cluster(&self, value: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(value) = value {
+            self.element()
+                .get_or_create_sub_element(ElementName::NmCoordCluster)?
+                .set_character_data(u64::from(value))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::NmCoordCluster);
+        }
+        Ok(())
+    }
+
+    /// get the nmCoordCluster value
+    fn coord_cluster(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::NmCoordCluster)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_integer())
+    }
+
+    /// set or remove the nmCoordinatorRole of the node
+    fn set_coordinator_role(&self, value: Option<NmCoordinatorRole>) -> Result<(), AutosarAbstractionError> {
+        if let Some(value) = value {
+            self.element()
+                .get_or_create_sub_element(ElementName::NmCoordinatorRole)?
+                .set_character_data::<EnumItem>(value.into())?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::NmCoordinatorRole);
+        }
+        Ok(())
+    }
+
+    /// get the nmCoordinatorRole of the node
+    fn coordinator_role(&self) -> Option<NmCoordinatorRole> {
+        self.element()
+            .get_sub_element(ElementName::NmCoordinatorRole)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.enum_value())
+            .and_then(|enumitem| enumitem.try_into().ok())
+    }
+
+    /// get the `NmCluster` that contains this node
+    fn nm_cluster(&self) -> Option<NmCluster> {
+        self.element()
+            .named_parent()
+            .ok()
+            .flatten()
+            .and_then(|elem| elem.try_into().ok())
+    }
+}
+
+//##################################################################
+
+/// The `NmNode` encapsulates the bus specific NM nodes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NmNode {
+    /// the NM node is a `CanNmNode`
+    CanNm(CanNmNode),
+    /// the NM node is a `FlexrayNmNode`
+    FlexrayNm(FlexrayNmNode),
+    /// the NM node is a `UdpNmNode`
+    UdpNm(UdpNmNode),
+}
+
+impl TryFrom<Element> for NmNode {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        match element.element_name() {
+            ElementName::CanNmNode => CanNmNode::try_from(element).map(NmNode::CanNm),
+            ElementName::FlexrayNmNode => FlexrayNmNode::try_from(element).map(NmNode::FlexrayNm),
+            ElementName::UdpNmNode => UdpNmNode::try_from(element).map(NmNode::UdpNm),
+            _ => Err(AutosarAbstractionError::ConversionError {
+                element,
+                dest: "NmNode".to_string(),
+            }),
+        }
+    }
+}
+
+impl AbstractionElement for NmNode {
+    fn element(&self) -> &Element {
+        match self {
+            NmNode::CanNm(node) => node.element(),
+            NmNode::FlexrayNm(node) => node.element(),
+            NmNode::UdpNm(node) => node.element(),
+        }
+    }
+}
+
+impl IdentifiableAbstractionElement for NmNode {}
+
+impl AbstractNmNode for NmNode {
+    type CommunicationControllerType = CommunicationController;
+}
+
+//##################################################################
+
+/// The role of an `NmNode` in the coordination of several NM clusters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NmCoordinatorRole {
+    /// the node actively keeps the coordinated clusters awake
+    Active,
+    /// the node only forwards the shutdown to the other coordinated clusters
+    Passive,
+}
+
+impl From<NmCoordinatorRole> for EnumItem {
+    fn from(value: NmCoordinatorRole) -> Self {
+        match value {
+            NmCoordinatorRole::Active => EnumItem::Active,
+            NmCoordinatorRole::Passive => EnumItem::Passive,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for NmCoordinatorRole {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::Active => Ok(NmCoordinatorRole::Active),
+            EnumItem::Passive => Ok(NmCoordinatorRole::Passive),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "NmCoordinatorRole".to_string(),
+            }),
+        }
+    }
 }
 
 //##################################################################
@@ -1266,4 +1610,150 @@ mod test {
         let cluster_coupling2 = nm_config.nm_cluster_couplings().next().unwrap();
         assert_eq!(cluster_coupling.element(), cluster_coupling2.element());
     }
+
+    #[test]
+    fn test_nm_coordinator() {
+        let model = AutosarModelAbstraction::create("test", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+
+        // a gateway that coordinates a CAN and an Ethernet cluster
+        let can_cluster = system.create_can_cluster("can_cluster", &package, None).unwrap();
+        let can_physical_channel = can_cluster.create_physical_channel("can_channel").unwrap();
+        let ethernet_cluster = system.create_ethernet_cluster("ethernet_cluster", &package).unwrap();
+        let ethernet_physical_channel = ethernet_cluster
+            .create_physical_channel("ethernet_channel", None)
+            .unwrap();
+        let gateway = system.create_ecu_instance("gateway", &package).unwrap();
+        let can_controller = gateway.create_can_communication_controller("can_controller").unwrap();
+        can_controller
+            .connect_physical_channel("can_connection", &can_physical_channel)
+            .unwrap();
+        let eth_controller = gateway
+            .create_ethernet_communication_controller("eth_controller", None)
+            .unwrap();
+        eth_controller
+            .connect_physical_channel("eth_connection", &ethernet_physical_channel)
+            .unwrap();
+        let other_ecu = system.create_ecu_instance("other_ecu", &package).unwrap();
+        let other_controller = other_ecu.create_can_communication_controller("can_controller").unwrap();
+        other_controller
+            .connect_physical_channel("can_connection", &can_physical_channel)
+            .unwrap();
+
+        let nm_config = system.create_nm_config("NmConfig", &package).unwrap();
+        let can_nm_cluster = nm_config
+            .create_can_nm_cluster(
+                "can_nm_cluster",
+                &CanNmClusterSettings {
+                    nm_busload_reduction_active: false,
+                    nm_immediate_nm_transmissions: 0,
+                    nm_message_timeout_time: 1.0,
+                    nm_msg_cycle_time: 0.5,
+                    nm_network_timeout: 2.0,
+                    nm_remote_sleep_indication_time: 2.0,
+                    nm_repeat_message_time: 1.5,
+                    nm_wait_bus_sleep_time: 1.0,
+                },
+                &can_cluster,
+            )
+            .unwrap();
+        let udp_nm_cluster = nm_config
+            .create_udp_nm_cluster(
+                "udp_nm_cluster",
+                &UdpNmClusterSettings {
+                    nm_msg_cycle_time: 1.0,
+                    nm_msg_timeout_time: 2.0,
+                    nm_network_timeout: 3.0,
+                    nm_remote_sleep_indication_time: 4.0,
+                    nm_repeat_message_time: 5.0,
+                    nm_wait_bus_sleep_time: 6.0,
+                },
+                &ethernet_cluster,
+            )
+            .unwrap();
+        can_nm_cluster.set_synchronizing_network(Some(true)).unwrap();
+
+        let nm_gateway = nm_config.create_nm_ecu("nm_gateway", &gateway).unwrap();
+        let nm_other = nm_config.create_nm_ecu("nm_other", &other_ecu).unwrap();
+        let can_node = can_nm_cluster
+            .create_can_nm_node("gateway_can_node", &can_controller, &nm_gateway)
+            .unwrap();
+        let udp_node = udp_nm_cluster
+            .create_udp_nm_node("gateway_udp_node", &eth_controller, &nm_gateway, 0.0)
+            .unwrap();
+        let other_node = can_nm_cluster
+            .create_can_nm_node("other_can_node", &other_controller, &nm_other)
+            .unwrap();
+
+        // coordinator settings
+        assert_eq!(nm_gateway.nm_coordinator(), None);
+        let coordinator = nm_gateway.create_nm_coordinator(1).unwrap();
+        assert_eq!(nm_gateway.nm_coordinator(), Some(coordinator.clone()));
+        assert_eq!(coordinator.nm_ecu().unwrap(), nm_gateway);
+        assert_eq!(coordinator.index(), Some(1));
+        coordinator.set_index(0).unwrap();
+        assert_eq!(coordinator.index(), Some(0));
+        coordinator.set_nm_active_coordinator(Some(true)).unwrap();
+        assert_eq!(coordinator.nm_active_coordinator(), Some(true));
+        coordinator.set_nm_coord_sync_support(Some(true)).unwrap();
+        assert_eq!(coordinator.nm_coord_sync_support(), Some(true));
+        coordinator.set_nm_global_coordinator_time(Some(3.5)).unwrap();
+        assert_eq!(coordinator.nm_global_coordinator_time(), Some(3.5));
+
+        // coordinated nodes and clusters
+        coordinator.add_nm_node(&can_node).unwrap();
+        coordinator.add_nm_node(&udp_node).unwrap();
+        // adding a node twice doesn't create a second reference
+        coordinator.add_nm_node(&can_node).unwrap();
+        // the node of another ECU can't be coordinated by the gateway
+        assert!(coordinator.add_nm_node(&other_node).is_err());
+        let nodes: Vec<NmNode> = coordinator.nm_nodes().collect();
+        assert_eq!(
+            nodes,
+            vec![NmNode::CanNm(can_node.clone()), NmNode::UdpNm(udp_node.clone())]
+        );
+        assert_eq!(nodes[0].nm_ecu(), Some(nm_gateway.clone()));
+        assert_eq!(
+            coordinator.coordinated_clusters(),
+            vec![
+                NmCluster::CanNm(can_nm_cluster.clone()),
+                NmCluster::UdpNm(udp_nm_cluster.clone())
+            ]
+        );
+
+        // coordinator roles of the nodes
+        can_node.set_coord_cluster(Some(0)).unwrap();
+        assert_eq!(can_node.coord_cluster(), Some(0));
+        can_node.set_coordinator_role(Some(NmCoordinatorRole::Active)).unwrap();
+        assert_eq!(can_node.coordinator_role(), Some(NmCoordinatorRole::Active));
+        udp_node.set_coordinator_role(Some(NmCoordinatorRole::Passive)).unwrap();
+        assert_eq!(udp_node.coordinator_role(), Some(NmCoordinatorRole::Passive));
+        assert_eq!(udp_node.nm_cluster(), Some(NmCluster::UdpNm(udp_nm_cluster)));
+
+        // remove optional values
+        coordinator.set_nm_active_coordinator(None).unwrap();
+        assert_eq!(coordinator.nm_active_coordinator(), None);
+        coordinator.set_nm_coord_sync_support(None).unwrap();
+        assert_eq!(coordinator.nm_coord_sync_support(), None);
+        coordinator.set_nm_global_coordinator_time(None).unwrap();
+        assert_eq!(coordinator.nm_global_coordinator_time(), None);
+        can_node.set_coord_cluster(None).unwrap();
+        assert_eq!(can_node.coord_cluster(), None);
+        can_node.set_coordinator_role(None).unwrap();
+        assert_eq!(can_node.coordinator_role(), None);
+
+        // creating a new coordinator replaces the existing one
+        let coordinator2 = nm_gateway.create_nm_coordinator(2).unwrap();
+        assert_eq!(nm_gateway.nm_coordinator(), Some(coordinator2.clone()));
+        assert_eq!(coordinator2.nm_nodes().count(), 0);
+
+        // conversions
+        let nm_node = NmNode::try_from(can_node.element().clone()).unwrap();
+        assert_eq!(nm_node, NmNode::CanNm(can_node));
+        let result = NmNode::try_from(model.root_element());
+        assert!(result.is_err());
+        let coordinator3 = NmCoordinator::try_from(coordinator2.element().clone()).unwrap();
+        assert_eq!(coordinator3, coordinator2);
+    }
 }
@@ -0,0 +1,442 @@
+use crate::communication::{AbstractPdu, ISignalIPdu, ISignalToIPduMapping, SignalPdu, signal_bits};
+use crate::datatype::{BaseTypeEncoding, CompuMethodContent, CompuScaleDirection};
+use crate::{AutosarAbstractionError, ByteOrder, IdentifiableAbstractionElement};
+
+//##################################################################
+
+/// A description of the payload layout of an `ISignalIPdu`, which can be used to encode and decode payload bytes
+///
+/// It is created by [`ISignalIPdu::codec`]. The codec is a plain data structure without any connection to the model,
+/// so it can be used by downstream code, e.g. for the generation of communication stacks or in test tools.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PduCodec {
+    /// name of the PDU
+    pub name: String,
+    /// length of the PDU in bytes
+    pub length: u32,
+    /// all signals of the PDU
+    pub signals: Vec<SignalCodec>,
+}
+
+impl PduCodec {
+    /// get the codec of the signal with the given name
+    #[must_use]
+    pub fn signal(&self, name: &str) -> Option<&SignalCodec> {
+        self.signals.iter().find(|signal| signal.name == name)
+    }
+
+    /// decode the physical values of all signals in the payload
+    ///
+    /// Signals which are not completely contained in the payload are skipped.
+    #[must_use]
+    pub fn decode(&self, payload: &[u8]) -> Vec<(String, f64)> {
+        self.signals
+            .iter()
+            .filter_map(|signal| Some((signal.name.clone(), signal.decode(payload)?)))
+            .collect()
+    }
+}
+
+//##################################################################
+
+/// The packing and scaling information of a single signal in a PDU
+///
+/// Bits are numbered sequentially, so bit n is bit (n % 8) of byte (n / 8).
+/// For signals with byte order `MostSignificantByteFirst` the start position is the most significant bit,
+/// otherwise it is the least significant bit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalCodec {
+    /// name of the signal
+    pub name: String,
+    /// start position of the signal in the PDU, in bits
+    pub start_position: u32,
+    /// length of the signal in bits
+    pub bit_length: u32,
+    /// byte order of the signal
+    pub byte_order: ByteOrder,
+    /// the encoding of the raw value
+    pub value_type: SignalValueType,
+    /// scaling factor: `physical = raw * factor + offset`
+    pub factor: f64,
+    /// scaling offset: `physical = raw * factor + offset`
+    pub offset: f64,
+    /// position of the update bit, if the signal has one
+    pub update_bit: Option<u32>,
+}
+
+impl SignalCodec {
+    /// create the codec of a signal from its mapping in a PDU
+    ///
+    /// Returns `None` if the mapping does not contain a complete signal description.
+    fn from_mapping(mapping: &ISignalToIPduMapping) -> Option<Self> {
+        let signal = mapping.signal()?;
+        let name = signal.name()?;
+        let bit_length = u32::try_from(signal.length()?).ok()?;
+        let start_position = mapping.start_position()?;
+        let byte_order = mapping.byte_order()?;
+
+        let value_type = match signal.datatype().and_then(|datatype| datatype.base_type_encoding()) {
+            Some(BaseTypeEncoding::TwosComplement) => SignalValueType::Signed,
+            Some(BaseTypeEncoding::Ieee754) if bit_length == 32 || bit_length == 64 => SignalValueType::Float,
+            _ => SignalValueType::Unsigned,
+        };
+
+        // only linear scaling can be described by factor and offset; other compu methods are ignored
+        let (factor, offset) = match signal.compu_method().and_then(|compu_method| compu_method.content()) {
+            Some(CompuMethodContent::Linear(linear))
+                if linear.direction == CompuScaleDirection::IntToPhys && linear.divisor != 0.0 =>
+            {
+                (linear.factor / linear.divisor, linear.offset / linear.divisor)
+            }
+            _ => (1.0, 0.0),
+        };
+
+        Some(Self {
+            name,
+            start_position,
+            bit_length,
+            byte_order,
+            value_type,
+            factor,
+            offset,
+            update_bit: mapping.update_bit(),
+        })
+    }
+
+    /// extract the raw value of the signal from the payload
+    ///
+    /// Returns `None` if the signal is not completely contained in the payload, or if its length is not 1 to 64 bits.
+    #[must_use]
+    pub fn decode_raw(&self, payload: &[u8]) -> Option<u64> {
+        if self.bit_length == 0 || self.bit_length > 64 {
+            return None;
+        }
+        let mut raw = 0u64;
+        for (value_bit, position) in self.bit_positions() {
+            let byte = *payload.get((position / 8) as usize)?;
+            if byte & (1 << (position % 8)) != 0 {
+                raw |= 1 << value_bit;
+            }
+        }
+        Some(raw)
+    }
+
+    /// extract the physical value of the signal from the payload
+    ///
+    /// The raw value is interpreted according to the `value_type` and then scaled with `factor` and `offset`.
+    #[must_use]
+    pub fn decode(&self, payload: &[u8]) -> Option<f64> {
+        let raw = self.decode_raw(payload)?;
+        let value = match self.value_type {
+            SignalValueType::Unsigned => raw as f64,
+            SignalValueType::Signed => {
+                // sign extension of the raw value
+                let shift = 64 - self.bit_length;
+                (((raw << shift) as i64) >> shift) as f64
+            }
+            SignalValueType::Float if self.bit_length == 32 => f64::from(f32::from_bits(raw as u32)),
+            SignalValueType::Float => f64::from_bits(raw),
+        };
+        Some(value * self.factor + self.offset)
+    }
+
+    /// write a raw value of the signal into the payload
+    ///
+    /// Bits of the value beyond the bit length of the signal are ignored. Other signals in the payload are not modified.
+    /// The update bit is not changed; use [`SignalCodec::set_update_bit`] for that.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The signal is not completely contained in the payload,
+    ///   or it is longer than 64 bits
+    pub fn encode_raw(&self, raw: u64, payload: &mut [u8]) -> Result<(), AutosarAbstractionError> {
+        if self.bit_length > 64
+            || self
+                .bit_positions()
+                .any(|(_, position)| position / 8 >= payload.len() as u32)
+        {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "signal {} does not fit into a payload of {} bytes",
+                self.name,
+                payload.len()
+            )));
+        }
+        for (value_bit, position) in self.bit_positions() {
+            let byte = &mut payload[(position / 8) as usize];
+            if raw & (1 << value_bit) != 0 {
+                *byte |= 1 << (position % 8);
+            } else {
+                *byte &= !(1 << (position % 8));
+            }
+        }
+        Ok(())
+    }
+
+    /// write a physical value of the signal into the payload
+    ///
+    /// The value is converted to a raw value using `factor` and `offset`, rounded, and limited to the
+    /// range of values that can be represented by the signal.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The signal is not completely contained in the payload,
+    ///   or it is longer than 64 bits
+    pub fn encode(&self, value: f64, payload: &mut [u8]) -> Result<(), AutosarAbstractionError> {
+        let internal = if self.factor == 0.0 {
+            0.0
+        } else {
+            (value - self.offset) / self.factor
+        };
+        let raw = match self.value_type {
+            SignalValueType::Unsigned => {
+                let max = if self.bit_length >= 64 {
+                    u64::MAX
+                } else {
+                    (1u64 << self.bit_length) - 1
+                };
+                // float to int casts saturate, so the upper limit only needs to be applied for short signals
+                (internal.round().max(0.0) as u64).min(max)
+            }
+            SignalValueType::Signed => {
+                let bits = self.bit_length.clamp(1, 64);
+                let max = i64::MAX >> (64 - bits);
+                let min = i64::MIN >> (64 - bits);
+                (internal.round() as i64).clamp(min, max) as u64
+            }
+            SignalValueType::Float if self.bit_length == 32 => u64::from((internal as f32).to_bits()),
+            SignalValueType::Float => internal.to_bits(),
+        };
+        self.encode_raw(raw, payload)
+    }
+
+    /// set or clear the update bit of the signal in the payload
+    ///
+    /// Nothing is changed if the signal has no update bit.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The update bit is not contained in the payload
+    pub fn set_update_bit(&self, updated: bool, payload: &mut [u8]) -> Result<(), AutosarAbstractionError> {
+        let Some(update_bit) = self.update_bit else {
+            return Ok(());
+        };
+        let byte = payload
+            .get_mut((update_bit / 8) as usize)
+            .ok_or(AutosarAbstractionError::InvalidParameter(format!(
+                "the update bit of signal {} is not contained in the payload",
+                self.name
+            )))?;
+        if updated {
+            *byte |= 1 << (update_bit % 8);
+        } else {
+            *byte &= !(1 << (update_bit % 8));
+        }
+        Ok(())
+    }
+
+    // iterate over the bits of the signal: (bit in the value, bit position in the payload)
+    fn bit_positions(&self) -> impl Iterator<Item = (u32, u32)> + use<> {
+        let bits = signal_bits(self.start_position, u64::from(self.bit_length), self.byte_order);
+        let bit_length = self.bit_length;
+        let big_endian = self.byte_order == ByteOrder::MostSignificantByteFirst;
+        // big endian signals start with the most significant bit, little endian signals with the least significant bit
+        bits.into_iter().enumerate().map(move |(idx, position)| {
+            let idx = idx as u32;
+            let value_bit = if big_endian { bit_length - 1 - idx } else { idx };
+            (value_bit, position)
+        })
+    }
+}
+
+//##################################################################
+
+/// The encoding of the raw value of a signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignalValueType {
+    /// unsigned integer
+    Unsigned,
+    /// signed integer in two's complement
+    Signed,
+    /// IEEE 754 floating point number; only used for signals with 32 or 64 bits
+    Float,
+}
+
+//##################################################################
+
+impl ISignalIPdu {
+    /// generate a codec which describes how the signals are packed into the payload of this PDU
+    ///
+    /// For each mapped signal the codec contains the start position, length, byte order and update bit,
+    /// as well as the scaling from a linear compu method of the signal.
+    /// Signal group mappings and mappings with incomplete information are not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let pdu = system.create_isignal_ipdu("Pdu", &package, 8)?;
+    /// let syssignal = package.create_system_signal("Speed")?;
+    /// let signal = system.create_isignal("Speed", &package, 16, &syssignal, None)?;
+    /// pdu.map_signal(&signal, 0, ByteOrder::MostSignificantByteLast, None, TransferProperty::Triggered)?;
+    ///
+    /// let codec = pdu.codec();
+    /// let mut payload = [0u8; 8];
+    /// codec.signal("Speed").unwrap().encode(1234.0, &mut payload)?;
+    /// assert_eq!(payload[0..2], [0xd2, 0x04]);
+    /// assert_eq!(codec.decode(&payload), vec![("Speed".to_string(), 1234.0)]);
+    /// # Ok(())}
+    /// ```
+    #[must_use]
+    pub fn codec(&self) -> PduCodec {
+        PduCodec {
+            name: self.name().unwrap_or_default(),
+            length: self.length().unwrap_or(0),
+            signals: self
+                .mapped_signals()
+                .filter_map(|mapping| SignalCodec::from_mapping(&mapping))
+                .collect(),
+        }
+    }
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AutosarModelAbstraction, SystemCategory, communication::TransferProperty, datatype::CompuMethodLinearContent,
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn pdu_codec() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+
+        let create_signal = |name: &str, bit_length: u64| {
+            let syssignal = package.create_system_signal(name).unwrap();
+            system
+                .create_isignal(name, &package, bit_length, &syssignal, None)
+                .unwrap()
+        };
+
+        // little endian, unsigned, with linear scaling and update bit
+        let speed = create_signal("Speed", 12);
+        let compu_method = package
+            .create_compu_method(
+                "SpeedCompu",
+                CompuMethodContent::Linear(CompuMethodLinearContent {
+                    direction: CompuScaleDirection::IntToPhys,
+                    offset: -10.0,
+                    factor: 1.0,
+                    divisor: 2.0,
+                    lower_limit: None,
+                    upper_limit: None,
+                }),
+            )
+            .unwrap();
+        speed.set_compu_method(&compu_method).unwrap();
+        pdu.map_signal(
+            &speed,
+            4,
+            ByteOrder::MostSignificantByteLast,
+            Some(3),
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+
+        // big endian, signed
+        let temp = create_signal("Temp", 10);
+        let base_type = package
+            .create_sw_base_type("sint16", 16, BaseTypeEncoding::TwosComplement, None, None, None)
+            .unwrap();
+        temp.set_datatype(&base_type).unwrap();
+        pdu.map_signal(
+            &temp,
+            23,
+            ByteOrder::MostSignificantByteFirst,
+            None,
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+
+        // float
+        let ratio = create_signal("Ratio", 32);
+        let float_type = package
+            .create_sw_base_type("float32", 32, BaseTypeEncoding::Ieee754, None, None, None)
+            .unwrap();
+        ratio.set_datatype(&float_type).unwrap();
+        pdu.map_signal(
+            &ratio,
+            32,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+
+        let codec = pdu.codec();
+        assert_eq!(codec.name, "Pdu");
+        assert_eq!(codec.length, 8);
+        assert_eq!(codec.signals.len(), 3);
+        let speed_codec = codec.signal("Speed").unwrap();
+        assert_eq!(speed_codec.start_position, 4);
+        assert_eq!(speed_codec.bit_length, 12);
+        assert_eq!(speed_codec.value_type, SignalValueType::Unsigned);
+        assert_eq!(speed_codec.factor, 0.5);
+        assert_eq!(speed_codec.offset, -5.0);
+        assert_eq!(speed_codec.update_bit, Some(3));
+        let temp_codec = codec.signal("Temp").unwrap();
+        assert_eq!(temp_codec.value_type, SignalValueType::Signed);
+        assert_eq!(temp_codec.byte_order, ByteOrder::MostSignificantByteFirst);
+        let ratio_codec = codec.signal("Ratio").unwrap();
+        assert_eq!(ratio_codec.value_type, SignalValueType::Float);
+
+        let mut payload = [0u8; 8];
+        speed_codec.encode(100.0, &mut payload).unwrap();
+        speed_codec.set_update_bit(true, &mut payload).unwrap();
+        // raw = (100 + 5) / 0.5 = 210 = 0x0d2, stored in bits 4..16; the update bit is bit 3
+        assert_eq!(payload[0..2], [0x28, 0x0d]);
+        assert_eq!(speed_codec.decode_raw(&payload), Some(210));
+
+        let mut temp_payload = [0u8; 8];
+        temp_codec.encode(-2.0, &mut temp_payload).unwrap();
+        // raw = 0x3fe: the 8 most significant bits are in byte 2, the 2 least significant bits in bits 7 and 6 of byte 3
+        assert_eq!(temp_payload[2..4], [0xff, 0x80]);
+        assert_eq!(temp_codec.decode(&temp_payload), Some(-2.0));
+        // values are limited to the range of the signal
+        temp_codec.encode(-1000.0, &mut temp_payload).unwrap();
+        assert_eq!(temp_codec.decode(&temp_payload), Some(-512.0));
+
+        ratio_codec.encode(0.25, &mut payload).unwrap();
+        assert_eq!(payload[4..8], 0.25f32.to_le_bytes());
+        let decoded = codec.decode(&payload);
+        assert_eq!(
+            decoded,
+            vec![
+                ("Speed".to_string(), 100.0),
+                ("Temp".to_string(), 0.0),
+                ("Ratio".to_string(), 0.25)
+            ]
+        );
+
+        // encoding doesn't touch other bits
+        speed_codec.encode(-5.0, &mut payload).unwrap();
+        assert_eq!(payload[0..2], [0x08, 0x00]);
+        assert_eq!(payload[4..8], 0.25f32.to_le_bytes());
+
+        // payload too short
+        assert!(ratio_codec.encode(1.0, &mut [0u8; 4]).is_err());
+        assert_eq!(ratio_codec.decode(&[0u8; 4]), None);
+        assert!(speed_codec.set_update_bit(true, &mut []).is_err());
+    }
+}
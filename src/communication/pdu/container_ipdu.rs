@@ -4,6 +4,8 @@ use crate::{
     get_reference_parents,
 };
 use autosar_data::{Element, ElementName, EnumItem};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
 
 use super::{PduCollectionTrigger, PduTriggering};
 
@@ -173,6 +175,137 @@ impl ContainerIPdu {
             .filter_map(|triggering_ref| triggering_ref.get_reference_target().ok())
             .filter_map(|triggering| PduTriggering::try_from(triggering).ok())
     }
+
+    /// iterate over all `IPdus` that are contained in this `ContainerIPdu`
+    ///
+    /// An `IPdu` that is contained through several triggerings, e.g. on different channels, is only returned once.
+    pub fn contained_ipdus(&self) -> impl Iterator<Item = IPdu> + Send + use<> {
+        let mut ipdus: Vec<IPdu> = Vec::new();
+        for ipdu in self
+            .contained_ipdu_triggerings()
+            .filter_map(|pt| pt.pdu())
+            .filter_map(|pdu| IPdu::try_from(pdu.element().clone()).ok())
+        {
+            if !ipdus.contains(&ipdu) {
+                ipdus.push(ipdu);
+            }
+        }
+        ipdus.into_iter()
+    }
+
+    /// assign unique header ids to all contained `IPdus`
+    ///
+    /// Depending on the header type of the container, the long or the short header id of the contained `IPdus` is set.
+    /// Existing header ids inside the range are kept, unless another contained `IPdu` already uses the same id.
+    /// These collisions are listed in the returned report. All `IPdus` without a header id, with an id outside the range,
+    /// or with a colliding id are assigned the lowest free id in the range.
+    ///
+    /// The header id is a property of the contained `IPdu`, so it also changes in other containers that contain the same `IPdu`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg")?;
+    /// # let system = package.create_system("system", SystemCategory::EcuExtract)?;
+    /// # let cluster = system.create_can_cluster("Cluster", &package, None)?;
+    /// # let channel = cluster.create_physical_channel("Channel")?;
+    /// let container_ipdu = system.create_container_ipdu(
+    ///     "Container",
+    ///     &package,
+    ///     64,
+    ///     ContainerIPduHeaderType::ShortHeader,
+    ///     RxAcceptContainedIPdu::AcceptAll,
+    /// )?;
+    /// let pdu1 = system.create_isignal_ipdu("Pdu1", &package, 8)?;
+    /// let pdu2 = system.create_isignal_ipdu("Pdu2", &package, 8)?;
+    /// container_ipdu.map_ipdu(&pdu1, &channel)?;
+    /// container_ipdu.map_ipdu(&pdu2, &channel)?;
+    /// let report = container_ipdu.assign_header_ids(0x100..=0x1ff)?;
+    /// assert_eq!(report.assigned.len(), 2);
+    /// assert_eq!(pdu2.contained_ipdu_props().unwrap().header_id_short, Some(0x101));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the container has no header, the range exceeds the
+    ///   24 bits of a short header id, or the range does not contain enough free ids. The model is not modified in this case.
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while setting the header ids
+    pub fn assign_header_ids(
+        &self,
+        id_range: RangeInclusive<u32>,
+    ) -> Result<HeaderIdAssignmentReport, AutosarAbstractionError> {
+        let header_type = self.header_type().unwrap_or(ContainerIPduHeaderType::NoHeader);
+        match header_type {
+            ContainerIPduHeaderType::NoHeader => {
+                return Err(AutosarAbstractionError::InvalidParameter(
+                    "header ids can only be assigned in a ContainerIPdu with a long or short header".to_string(),
+                ));
+            }
+            ContainerIPduHeaderType::ShortHeader if *id_range.end() > 0xff_ffff => {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "the range {id_range:?} exceeds the 24 bits of a short header id"
+                )));
+            }
+            _ => {}
+        }
+        let get_header_id = |props: &ContainedIPduProps| match header_type {
+            ContainerIPduHeaderType::LongHeader => props.header_id_long,
+            _ => props.header_id_short,
+        };
+
+        // keep all unique ids in the range, and collect the IPdus that need a new id
+        let mut report = HeaderIdAssignmentReport::default();
+        let mut used_ids: HashMap<u32, IPdu> = HashMap::new();
+        let mut unassigned = Vec::new();
+        for ipdu in self.contained_ipdus() {
+            let header_id = ipdu.contained_ipdu_props().as_ref().and_then(get_header_id);
+            match header_id {
+                Some(header_id) if id_range.contains(&header_id) => {
+                    if let Some(other_ipdu) = used_ids.get(&header_id) {
+                        report.collisions.push(HeaderIdCollision {
+                            ipdu: ipdu.clone(),
+                            conflicting_ipdu: other_ipdu.clone(),
+                            header_id,
+                        });
+                        unassigned.push(ipdu);
+                    } else {
+                        used_ids.insert(header_id, ipdu);
+                    }
+                }
+                _ => unassigned.push(ipdu),
+            }
+        }
+
+        // find free ids for all unassigned IPdus before modifying the model
+        let mut free_ids = id_range.clone().filter(|id| !used_ids.contains_key(id));
+        let mut new_ids = Vec::with_capacity(unassigned.len());
+        for ipdu in unassigned {
+            let Some(header_id) = free_ids.next() else {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "the range {id_range:?} does not contain enough free header ids"
+                )));
+            };
+            new_ids.push((ipdu, header_id));
+        }
+
+        for (ipdu, header_id) in new_ids {
+            let mut props = ipdu.contained_ipdu_props().unwrap_or_default();
+            match header_type {
+                ContainerIPduHeaderType::LongHeader => props.header_id_long = Some(header_id),
+                _ => props.header_id_short = Some(header_id),
+            }
+            ipdu.set_contained_ipdu_props(Some(&props))?;
+            report.assigned.push((ipdu, header_id));
+        }
+
+        Ok(report)
+    }
 }
 
 impl AbstractPdu for ContainerIPdu {}
@@ -304,7 +437,7 @@ impl TryFrom<EnumItem> for ContainerIPduTrigger {
 //##################################################################
 
 /// Properties for an `IPdu` that is transmitted in a container `IPdu`
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct ContainedIPduProps {
     /// collection semantics: `LastIsBest` or `Queued`
     pub collection_semantics: Option<ContainedIPduCollectionSemantics>,
@@ -416,6 +549,28 @@ impl ContainedIPduProps {
 
 //##################################################################
 
+/// Result of [`ContainerIPdu::assign_header_ids`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HeaderIdAssignmentReport {
+    /// the `IPdus` that received a new header id, together with the id
+    pub assigned: Vec<(IPdu, u32)>,
+    /// the `IPdus` whose existing header id was already used by another contained `IPdu`
+    pub collisions: Vec<HeaderIdCollision>,
+}
+
+/// Two `IPdus` in a `ContainerIPdu` had the same header id
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeaderIdCollision {
+    /// the `IPdu` that was assigned a new header id
+    pub ipdu: IPdu,
+    /// the `IPdu` that kept the header id
+    pub conflicting_ipdu: IPdu,
+    /// the header id that was used by both `IPdus`
+    pub header_id: u32,
+}
+
+//##################################################################
+
 /// collection semantics for the `ContainedIPdu`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ContainedIPduCollectionSemantics {
@@ -548,4 +703,103 @@ mod test {
         assert_eq!(container_ipdu.contained_ipdu_triggerings().count(), 1);
         assert_eq!(container_ipdu.contained_ipdu_triggerings().next(), Some(pdu_triggering));
     }
+
+    #[test]
+    fn assign_header_ids() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("system", SystemCategory::EcuExtract).unwrap();
+        let flexray_cluster = system
+            .create_flexray_cluster("FlxCluster", &package, &FlexrayClusterSettings::new())
+            .unwrap();
+        let channel_a = flexray_cluster
+            .create_physical_channel("FlxChannelA", FlexrayChannelName::A)
+            .unwrap();
+        let channel_b = flexray_cluster
+            .create_physical_channel("FlxChannelB", FlexrayChannelName::B)
+            .unwrap();
+
+        let container_ipdu = system
+            .create_container_ipdu(
+                "container_ipdu",
+                &package,
+                64,
+                ContainerIPduHeaderType::LongHeader,
+                RxAcceptContainedIPdu::AcceptAll,
+            )
+            .unwrap();
+        let pdu1 = system.create_isignal_ipdu("Pdu1", &package, 8).unwrap();
+        let pdu2 = system.create_isignal_ipdu("Pdu2", &package, 8).unwrap();
+        let pdu3 = system.create_isignal_ipdu("Pdu3", &package, 8).unwrap();
+        let pdu4 = system.create_isignal_ipdu("Pdu4", &package, 8).unwrap();
+        container_ipdu.map_ipdu(&pdu1, &channel_a).unwrap();
+        container_ipdu.map_ipdu(&pdu1, &channel_b).unwrap();
+        container_ipdu.map_ipdu(&pdu2, &channel_a).unwrap();
+        container_ipdu.map_ipdu(&pdu3, &channel_a).unwrap();
+        container_ipdu.map_ipdu(&pdu4, &channel_a).unwrap();
+        assert_eq!(container_ipdu.contained_ipdus().count(), 4);
+
+        // pdu1 and pdu2 use the same id, pdu3 has an id outside the range, pdu4 has no id
+        let props = ContainedIPduProps {
+            header_id_long: Some(5),
+            priority: Some(3),
+            ..Default::default()
+        };
+        pdu1.set_contained_ipdu_props(Some(&props)).unwrap();
+        pdu2.set_contained_ipdu_props(Some(&props)).unwrap();
+        let props = ContainedIPduProps {
+            header_id_long: Some(500),
+            ..Default::default()
+        };
+        pdu3.set_contained_ipdu_props(Some(&props)).unwrap();
+
+        // not enough free ids in the range; the model is not modified
+        let result = container_ipdu.assign_header_ids(4..=6);
+        assert!(result.is_err());
+        assert_eq!(pdu4.contained_ipdu_props(), None);
+
+        let report = container_ipdu.assign_header_ids(1..=10).unwrap();
+        assert_eq!(
+            report.collisions,
+            vec![HeaderIdCollision {
+                ipdu: pdu2.clone().into(),
+                conflicting_ipdu: pdu1.clone().into(),
+                header_id: 5,
+            }]
+        );
+        assert_eq!(
+            report.assigned,
+            vec![
+                (pdu2.clone().into(), 1),
+                (pdu3.clone().into(), 2),
+                (pdu4.clone().into(), 3)
+            ]
+        );
+        assert_eq!(pdu1.contained_ipdu_props().unwrap().header_id_long, Some(5));
+        // other properties are preserved
+        let pdu2_props = pdu2.contained_ipdu_props().unwrap();
+        assert_eq!(pdu2_props.header_id_long, Some(1));
+        assert_eq!(pdu2_props.priority, Some(3));
+        assert_eq!(pdu3.contained_ipdu_props().unwrap().header_id_long, Some(2));
+        assert_eq!(pdu4.contained_ipdu_props().unwrap().header_id_long, Some(3));
+
+        // all ids are unique now, so nothing changes
+        let report = container_ipdu.assign_header_ids(1..=10).unwrap();
+        assert_eq!(report, HeaderIdAssignmentReport::default());
+
+        // short header ids are limited to 24 bits
+        container_ipdu
+            .set_header_type(ContainerIPduHeaderType::ShortHeader)
+            .unwrap();
+        assert!(container_ipdu.assign_header_ids(0..=0x100_0000).is_err());
+        let report = container_ipdu.assign_header_ids(0x10..=0x20).unwrap();
+        assert_eq!(report.assigned.len(), 4);
+        assert_eq!(pdu4.contained_ipdu_props().unwrap().header_id_short, Some(0x13));
+
+        // containers without a header don't have header ids
+        container_ipdu
+            .set_header_type(ContainerIPduHeaderType::NoHeader)
+            .unwrap();
+        assert!(container_ipdu.assign_header_ids(1..=10).is_err());
+    }
 }
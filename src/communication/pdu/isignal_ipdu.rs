@@ -1,12 +1,14 @@
 use crate::communication::{
     AbstractIpdu, AbstractPdu, CommunicationDirection, DynamicPartAlternative, IPdu, ISignal, ISignalGroup,
-    MultiplexedIPdu, Pdu, PduToFrameMapping, TransferProperty,
+    MultiplexedIPdu, Pdu, PduToFrameMapping, SomeipTpConnection, TransferProperty,
 };
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, ByteOrder, IdentifiableAbstractionElement,
     abstraction_element, get_reference_parents, is_used_system_element, make_unique_name,
 };
 use autosar_data::{Element, ElementName, EnumItem};
+use std::collections::HashSet;
+use std::ops::Range;
 
 //##################################################################
 
@@ -167,7 +169,7 @@ impl ISignalIPdu {
         // create and return the new mapping
         let model = self.element().model()?;
         let base_path = self.element().path()?;
-        let name = make_unique_name(&model, &base_path, &signal_name);
+        let name = make_unique_name(&model, &base_path, &signal_name)?;
 
         let mappings = self
             .element()
@@ -206,7 +208,7 @@ impl ISignalIPdu {
         // create and return the new mapping
         let model = self.element().model()?;
         let base_path = self.element().path()?;
-        let name = make_unique_name(&model, &base_path, &signal_group_name);
+        let name = make_unique_name(&model, &base_path, &signal_group_name)?;
 
         let mappings = self
             .element()
@@ -215,16 +217,189 @@ impl ISignalIPdu {
         ISignalToIPduMapping::new_with_group(&name, &mappings, signal_group)
     }
 
+    /// map several signals to the PDU, automatically placing them at the first free bit positions
+    ///
+    /// Each entry of `signals` contains the signal, its byte order and its transfer property.
+    /// The signals are placed in the given order, after any signals that are already mapped to the PDU.
+    /// If `with_update_bits` is true, then an update bit is allocated for each signal at the first free position after the signal.
+    /// If `byte_aligned` is true, then each signal starts at the beginning of a byte.
+    ///
+    /// The positions of all signals are determined before any mapping is created, so if the signals
+    /// do not fit into the PDU then the PDU is not modified.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] a signal has no length, or there is not enough free space in the PDU
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while creating the mappings
+    pub fn auto_map_signals(
+        &self,
+        signals: &[(&ISignal, ByteOrder, TransferProperty)],
+        with_update_bits: bool,
+        byte_aligned: bool,
+    ) -> Result<Vec<ISignalToIPduMapping>, AutosarAbstractionError> {
+        let pdu_name = self.name().unwrap_or_default();
+        let length = self.length().unwrap_or(0);
+        let bit_count = length * 8;
+
+        // mark all bits that are used by existing signals
+        let mut validator = SignalMappingValidator::new(length);
+        for mapping in self.mapped_signals() {
+            if let (Some(m_signal), Some(m_start_pos), Some(m_byte_order)) =
+                (mapping.signal(), mapping.start_position(), mapping.byte_order())
+            {
+                let len = m_signal.length().unwrap_or(0);
+                validator.add_signal(m_start_pos, len, m_byte_order, mapping.update_bit());
+            }
+        }
+
+        // the bits are filled in transmission order; for big endian signals the start position is the most significant bit
+        let bit_position = |sequence: u32, byte_order: ByteOrder| {
+            if byte_order == ByteOrder::MostSignificantByteFirst {
+                (sequence / 8) * 8 + 7 - sequence % 8
+            } else {
+                sequence
+            }
+        };
+
+        let mut placements = Vec::with_capacity(signals.len());
+        for (signal, byte_order, _) in signals {
+            let signal_name = signal.name().unwrap_or_default();
+            let signal_length =
+                signal
+                    .length()
+                    .filter(|len| *len > 0)
+                    .ok_or(AutosarAbstractionError::InvalidParameter(format!(
+                        "The length of signal {signal_name} is not set"
+                    )))?;
+
+            let mut placement = None;
+            for sequence in (0..bit_count).filter(|seq| !byte_aligned || seq % 8 == 0) {
+                let start_position = bit_position(sequence, *byte_order);
+                let mut candidate = validator.clone();
+                if !candidate.add_signal(start_position, signal_length, *byte_order, None) {
+                    continue;
+                }
+                if with_update_bits {
+                    // the update bit is placed at the first free bit after the start of the signal
+                    let update_bit = (sequence..bit_count).chain(0..sequence).find_map(|update_seq| {
+                        let update_bit = bit_position(update_seq, ByteOrder::MostSignificantByteLast);
+                        let mut update_candidate = candidate.clone();
+                        update_candidate
+                            .add_signal(update_bit, 1, ByteOrder::MostSignificantByteLast, None)
+                            .then_some((update_bit, update_candidate))
+                    });
+                    if let Some((update_bit, update_candidate)) = update_bit {
+                        placement = Some((start_position, Some(update_bit), update_candidate));
+                        break;
+                    }
+                } else {
+                    placement = Some((start_position, None, candidate));
+                    break;
+                }
+            }
+
+            let Some((start_position, update_bit, new_validator)) = placement else {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "Not enough free space in PDU {pdu_name} to map signal {signal_name}"
+                )));
+            };
+            validator = new_validator;
+            placements.push((start_position, update_bit));
+        }
+
+        signals
+            .iter()
+            .zip(placements)
+            .map(
+                |((signal, byte_order, transfer_property), (start_position, update_bit))| {
+                    self.map_signal(signal, start_position, *byte_order, update_bit, *transfer_property)
+                },
+            )
+            .collect()
+    }
+
+    /// get the layout of the PDU, i.e. the bits that are occupied by each signal mapping
+    ///
+    /// Signal group mappings do not occupy any bits themselves and are not included.
+    /// Bits are numbered sequentially, so bit n is bit (n % 8) of byte (n / 8).
+    #[must_use]
+    pub fn layout(&self) -> Vec<PduLayoutEntry> {
+        self.mapped_signals()
+            .filter_map(|mapping| {
+                let signal = mapping.signal()?;
+                let start_position = mapping.start_position()?;
+                let byte_order = mapping.byte_order()?;
+                let bits = signal_bits(start_position, signal.length().unwrap_or(0), byte_order);
+                let update_bit = mapping.update_bit();
+                Some(PduLayoutEntry {
+                    mapping,
+                    ranges: bit_ranges(bits),
+                    update_bit,
+                })
+            })
+            .collect()
+    }
+
+    /// find the first free range of `bit_length` consecutive bits in the PDU
+    ///
+    /// The start of the range is a multiple of `alignment` bits; an alignment of 0 or 1 allows any position.
+    /// The returned start position can be used directly for a signal with byte order `MostSignificantByteLast`.
+    #[must_use]
+    pub fn find_free_range(&self, bit_length: u32, alignment: u32) -> Option<u32> {
+        let bit_count = self.length().unwrap_or(0) * 8;
+        let alignment = alignment.max(1);
+        let occupied = self.occupied_bits();
+
+        (0..bit_count)
+            .step_by(alignment as usize)
+            .take_while(|start| start + bit_length <= bit_count)
+            .find(|start| (*start..start + bit_length).all(|bit| !occupied.contains(&bit)))
+    }
+
+    /// find all pairs of signal mappings which overlap each other
+    ///
+    /// Overlaps between a signal and the update bit of another signal are also reported.
+    #[must_use]
+    pub fn overlapping_mappings(&self) -> Vec<(ISignalToIPduMapping, ISignalToIPduMapping)> {
+        let entries: Vec<_> = self
+            .layout()
+            .into_iter()
+            .map(|entry| {
+                let bits: HashSet<u32> = entry.bits().collect();
+                (entry.mapping, bits)
+            })
+            .collect();
+
+        let mut overlaps = Vec::new();
+        for (idx, (mapping_a, bits_a)) in entries.iter().enumerate() {
+            for (mapping_b, bits_b) in &entries[idx + 1..] {
+                if !bits_a.is_disjoint(bits_b) {
+                    overlaps.push((mapping_a.clone(), mapping_b.clone()));
+                }
+            }
+        }
+        overlaps
+    }
+
+    // collect all bits that are occupied by signals or update bits
+    fn occupied_bits(&self) -> HashSet<u32> {
+        self.layout().iter().flat_map(PduLayoutEntry::bits).collect()
+    }
+
     /// set the transmission timing of the PDU
+    ///
+    /// Any transmission mode conditions that were previously set are kept.
     pub fn set_timing(&self, timing_spec: &IpduTiming) -> Result<(), AutosarAbstractionError> {
-        let _ = self
-            .element()
-            .remove_sub_element_kind(ElementName::IPduTimingSpecifications);
-
         let timing_elem = self
             .element()
-            .create_sub_element(ElementName::IPduTimingSpecifications)?
-            .create_sub_element(ElementName::IPduTiming)?;
+            .get_or_create_sub_element(ElementName::IPduTimingSpecifications)?
+            .get_or_create_sub_element(ElementName::IPduTiming)?;
+        let _ = timing_elem.remove_sub_element_kind(ElementName::MinimumDelay);
+        if let Some(tmd_elem) = timing_elem.get_sub_element(ElementName::TransmissionModeDeclaration) {
+            let _ = tmd_elem.remove_sub_element_kind(ElementName::TransmissionModeTrueTiming);
+            let _ = tmd_elem.remove_sub_element_kind(ElementName::TransmissionModeFalseTiming);
+        }
+
         if let Some(min_delay) = timing_spec.minimum_delay {
             timing_elem
                 .create_sub_element(ElementName::MinimumDelay)?
@@ -348,6 +523,117 @@ impl ISignalIPdu {
             event_controlled_timing,
         })
     }
+
+    /// set the transmission mode conditions of the PDU
+    ///
+    /// The transmission mode of the PDU is true if at least one of the conditions is fulfilled, and false otherwise.
+    /// Each condition applies a data filter to the value of a signal that is mapped to this PDU.
+    /// Any previously existing conditions are replaced; an empty list removes all conditions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let syssignal = package.create_system_signal("syssignal")?;
+    /// # let signal = system.create_isignal("signal", &package, 8, &syssignal, None)?;
+    /// let pdu = system.create_isignal_ipdu("pdu", &package, 8)?;
+    /// let mapping = pdu.map_signal(&signal, 0, ByteOrder::MostSignificantByteLast, None, TransferProperty::Triggered)?;
+    /// pdu.set_transmission_mode_conditions(&[TransmissionModeCondition {
+    ///     signal_mapping: mapping,
+    ///     data_filter: DataFilter::NewIsWithin { min: 10, max: 20 },
+    /// }])?;
+    /// assert_eq!(pdu.transmission_mode_conditions().len(), 1);
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] A signal mapping does not belong to this PDU or does not map a signal
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the conditions
+    pub fn set_transmission_mode_conditions(
+        &self,
+        conditions: &[TransmissionModeCondition],
+    ) -> Result<(), AutosarAbstractionError> {
+        for condition in conditions {
+            if condition.signal_mapping.element().named_parent()?.as_ref() != Some(self.element())
+                || condition.signal_mapping.signal().is_none()
+            {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "The transmission mode condition must refer to a signal that is mapped to the PDU {}",
+                    self.name().unwrap_or_default()
+                )));
+            }
+        }
+
+        let tmd_elem = self
+            .element()
+            .get_or_create_sub_element(ElementName::IPduTimingSpecifications)?
+            .get_or_create_sub_element(ElementName::IPduTiming)?
+            .get_or_create_sub_element(ElementName::TransmissionModeDeclaration)?;
+        let _ = tmd_elem.remove_sub_element_kind(ElementName::TransmissionModeConditions);
+        if conditions.is_empty() {
+            return Ok(());
+        }
+
+        let conditions_elem = tmd_elem.create_sub_element(ElementName::TransmissionModeConditions)?;
+        for condition in conditions {
+            let condition_elem = conditions_elem.create_sub_element(ElementName::TransmissionModeCondition)?;
+            condition.data_filter.store(&condition_elem)?;
+            condition_elem
+                .create_sub_element(ElementName::ISignalInIPduRef)?
+                .set_reference_target(condition.signal_mapping.element())?;
+        }
+
+        Ok(())
+    }
+
+    /// get the transmission mode conditions of the PDU
+    #[must_use]
+    pub fn transmission_mode_conditions(&self) -> Vec<TransmissionModeCondition> {
+        let Some(conditions_elem) = self
+            .element()
+            .get_sub_element(ElementName::IPduTimingSpecifications)
+            .and_then(|its| its.get_sub_element(ElementName::IPduTiming))
+            .and_then(|timing| timing.get_sub_element(ElementName::TransmissionModeDeclaration))
+            .and_then(|tmd| tmd.get_sub_element(ElementName::TransmissionModeConditions))
+        else {
+            return Vec::new();
+        };
+
+        conditions_elem
+            .sub_elements()
+            .filter_map(|condition_elem| {
+                let signal_mapping = condition_elem
+                    .get_sub_element(ElementName::ISignalInIPduRef)
+                    .and_then(|mapping_ref| mapping_ref.get_reference_target().ok())
+                    .and_then(|mapping_elem| ISignalToIPduMapping::try_from(mapping_elem).ok())?;
+                let data_filter = DataFilter::load(&condition_elem)?;
+                Some(TransmissionModeCondition {
+                    signal_mapping,
+                    data_filter,
+                })
+            })
+            .collect()
+    }
+
+    /// iterate over all `SomeipTpConnection`s that transport this PDU as their TP-SDU
+    ///
+    /// `ISignalIPdus` that are larger than the maximum UDP payload are segmented by `SomeIp` TP.
+    pub fn someip_tp_connections(&self) -> impl Iterator<Item = SomeipTpConnection> + Send + use<> {
+        self.pdu_triggerings()
+            .iter()
+            .filter_map(|pdu_triggering| get_reference_parents(pdu_triggering.element()).ok())
+            .flatten()
+            .filter_map(|(_named_parent, parent)| SomeipTpConnection::try_from(parent).ok())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
 }
 
 impl SignalPdu for ISignalIPdu {
@@ -550,6 +836,58 @@ impl ISignalToIPduMapping {
 
 //##################################################################
 
+/// An entry in the layout of an `ISignalIPdu`, as returned by [`ISignalIPdu::layout`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PduLayoutEntry {
+    /// the signal mapping which occupies the bits
+    pub mapping: ISignalToIPduMapping,
+    /// the ranges of bits that are occupied by the signal
+    pub ranges: Vec<Range<u32>>,
+    /// the update bit of the signal, if any
+    pub update_bit: Option<u32>,
+}
+
+impl PduLayoutEntry {
+    /// iterate over all bits occupied by the signal and its update bit
+    pub fn bits(&self) -> impl Iterator<Item = u32> + '_ {
+        self.ranges.iter().flat_map(Clone::clone).chain(self.update_bit)
+    }
+}
+
+// get the sequential bit numbers of all bits of a signal
+//
+// For big endian signals the start position is the most significant bit. The signal continues towards the
+// lower bits of the same byte and then with the highest bit of the following byte.
+pub(crate) fn signal_bits(start_position: u32, bit_length: u64, byte_order: ByteOrder) -> Vec<u32> {
+    let bit_length = u32::try_from(bit_length).unwrap_or(u32::MAX);
+    if byte_order == ByteOrder::MostSignificantByteFirst {
+        let mut bits = Vec::new();
+        let mut position = start_position;
+        for _ in 0..bit_length {
+            bits.push(position);
+            position = if position % 8 == 0 { position + 15 } else { position - 1 };
+        }
+        bits
+    } else {
+        (start_position..start_position.saturating_add(bit_length)).collect()
+    }
+}
+
+// merge a list of bit numbers into sorted ranges of consecutive bits
+fn bit_ranges(mut bits: Vec<u32>) -> Vec<Range<u32>> {
+    bits.sort_unstable();
+    let mut ranges: Vec<Range<u32>> = Vec::new();
+    for bit in bits {
+        match ranges.last_mut() {
+            Some(range) if range.end == bit => range.end += 1,
+            _ => ranges.push(bit..bit + 1),
+        }
+    }
+    ranges
+}
+
+//##################################################################
+
 /// Timing specification for an IPDU
 #[derive(Debug, Clone, PartialEq)]
 pub struct IpduTiming {
@@ -561,6 +899,61 @@ pub struct IpduTiming {
     pub transmission_mode_false_timing: Option<TransmissionModeTiming>,
 }
 
+impl IpduTiming {
+    /// create a timing specification for a PDU that is sent periodically
+    #[must_use]
+    pub fn cyclic(time_period: f64) -> Self {
+        Self {
+            minimum_delay: None,
+            transmission_mode_true_timing: Some(TransmissionModeTiming {
+                cyclic_timing: Some(CyclicTiming {
+                    time_period,
+                    time_offset: None,
+                }),
+                event_controlled_timing: None,
+            }),
+            transmission_mode_false_timing: None,
+        }
+    }
+
+    /// create a timing specification for a PDU that is sent whenever its data changes
+    ///
+    /// The PDU is sent (`number_of_repetitions` + 1) times, with `repetition_period` seconds between the transmissions.
+    #[must_use]
+    pub fn event_controlled(number_of_repetitions: u32, repetition_period: Option<f64>) -> Self {
+        Self {
+            minimum_delay: None,
+            transmission_mode_true_timing: Some(TransmissionModeTiming {
+                cyclic_timing: None,
+                event_controlled_timing: Some(EventControlledTiming {
+                    number_of_repetitions,
+                    repetition_period,
+                }),
+            }),
+            transmission_mode_false_timing: None,
+        }
+    }
+
+    /// create a timing specification for a PDU that is sent periodically and additionally whenever its data changes
+    #[must_use]
+    pub fn mixed(time_period: f64, number_of_repetitions: u32, repetition_period: Option<f64>) -> Self {
+        Self {
+            minimum_delay: None,
+            transmission_mode_true_timing: Some(TransmissionModeTiming {
+                cyclic_timing: Some(CyclicTiming {
+                    time_period,
+                    time_offset: None,
+                }),
+                event_controlled_timing: Some(EventControlledTiming {
+                    number_of_repetitions,
+                    repetition_period,
+                }),
+            }),
+            transmission_mode_false_timing: None,
+        }
+    }
+}
+
 /// Cyclic and event controlled timing parameters for an IPDU
 #[derive(Debug, Clone, PartialEq)]
 pub struct TransmissionModeTiming {
@@ -588,6 +981,158 @@ pub struct EventControlledTiming {
     pub repetition_period: Option<f64>,
 }
 
+/// A condition that determines the transmission mode of an IPDU, as used by [`ISignalIPdu::set_transmission_mode_conditions`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransmissionModeCondition {
+    /// the mapping of the signal whose value is checked
+    pub signal_mapping: ISignalToIPduMapping,
+    /// the filter that is applied to the signal value
+    pub data_filter: DataFilter,
+}
+
+/// A filter that is applied to the value of a signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFilter {
+    /// the filter always passes
+    Always,
+    /// the filter never passes
+    Never,
+    /// passes if `(new_value & mask) != (old_value & mask)`
+    MaskedNewDiffersMaskedOld {
+        /// the mask applied to the values
+        mask: u64,
+    },
+    /// passes if `(new_value & mask) != x`
+    MaskedNewDiffersX {
+        /// the mask applied to the value
+        mask: u64,
+        /// the value to compare with
+        x: u64,
+    },
+    /// passes if `(new_value & mask) == x`
+    MaskedNewEqualsX {
+        /// the mask applied to the value
+        mask: u64,
+        /// the value to compare with
+        x: u64,
+    },
+    /// passes if `new_value < min || new_value > max`
+    NewIsOutside {
+        /// lower limit
+        min: i64,
+        /// upper limit
+        max: i64,
+    },
+    /// passes if `min <= new_value <= max`
+    NewIsWithin {
+        /// lower limit
+        min: i64,
+        /// upper limit
+        max: i64,
+    },
+    /// passes once every `period` occurrences, starting at occurrence `offset`
+    OneEveryN {
+        /// the number of occurrences between two passes
+        period: u32,
+        /// the occurrence on which the filter passes first
+        offset: u32,
+    },
+}
+
+impl DataFilter {
+    // store the filter in a DATA-FILTER sub element of the given parent element
+    fn store(&self, parent: &Element) -> Result<(), AutosarAbstractionError> {
+        let _ = parent.remove_sub_element_kind(ElementName::DataFilter);
+        let filter_elem = parent.create_sub_element(ElementName::DataFilter)?;
+        let filter_type = match self {
+            DataFilter::Always => EnumItem::Always,
+            DataFilter::Never => EnumItem::Never,
+            DataFilter::MaskedNewDiffersMaskedOld { .. } => EnumItem::MaskedNewDiffersMaskedOld,
+            DataFilter::MaskedNewDiffersX { .. } => EnumItem::MaskedNewDiffersX,
+            DataFilter::MaskedNewEqualsX { .. } => EnumItem::MaskedNewEqualsX,
+            DataFilter::NewIsOutside { .. } => EnumItem::NewIsOutside,
+            DataFilter::NewIsWithin { .. } => EnumItem::NewIsWithin,
+            DataFilter::OneEveryN { .. } => EnumItem::OneEveryN,
+        };
+        filter_elem
+            .create_sub_element(ElementName::DataFilterType)?
+            .set_character_data(filter_type)?;
+
+        match self {
+            DataFilter::Always | DataFilter::Never => {}
+            DataFilter::MaskedNewDiffersMaskedOld { mask } => {
+                filter_elem
+                    .create_sub_element(ElementName::Mask)?
+                    .set_character_data(*mask)?;
+            }
+            DataFilter::MaskedNewDiffersX { mask, x } | DataFilter::MaskedNewEqualsX { mask, x } => {
+                filter_elem
+                    .create_sub_element(ElementName::Mask)?
+                    .set_character_data(*mask)?;
+                filter_elem.create_sub_element(ElementName::X)?.set_character_data(*x)?;
+            }
+            DataFilter::NewIsOutside { min, max } | DataFilter::NewIsWithin { min, max } => {
+                filter_elem
+                    .create_sub_element(ElementName::Max)?
+                    .set_character_data(max.to_string())?;
+                filter_elem
+                    .create_sub_element(ElementName::Min)?
+                    .set_character_data(min.to_string())?;
+            }
+            DataFilter::OneEveryN { period, offset } => {
+                filter_elem
+                    .create_sub_element(ElementName::Offset)?
+                    .set_character_data(u64::from(*offset))?;
+                filter_elem
+                    .create_sub_element(ElementName::Period)?
+                    .set_character_data(u64::from(*period))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // load the filter from the DATA-FILTER sub element of the given parent element
+    fn load(parent: &Element) -> Option<Self> {
+        let filter_elem = parent.get_sub_element(ElementName::DataFilter)?;
+        let get_value = |element_name| {
+            filter_elem
+                .get_sub_element(element_name)
+                .and_then(|elem| elem.character_data())
+        };
+        let filter_type = get_value(ElementName::DataFilterType)?.enum_value()?;
+
+        match filter_type {
+            EnumItem::Always => Some(DataFilter::Always),
+            EnumItem::Never => Some(DataFilter::Never),
+            EnumItem::MaskedNewDiffersMaskedOld => Some(DataFilter::MaskedNewDiffersMaskedOld {
+                mask: get_value(ElementName::Mask)?.parse_integer()?,
+            }),
+            EnumItem::MaskedNewDiffersX => Some(DataFilter::MaskedNewDiffersX {
+                mask: get_value(ElementName::Mask)?.parse_integer()?,
+                x: get_value(ElementName::X)?.parse_integer()?,
+            }),
+            EnumItem::MaskedNewEqualsX => Some(DataFilter::MaskedNewEqualsX {
+                mask: get_value(ElementName::Mask)?.parse_integer()?,
+                x: get_value(ElementName::X)?.parse_integer()?,
+            }),
+            EnumItem::NewIsOutside => Some(DataFilter::NewIsOutside {
+                min: get_value(ElementName::Min)?.parse_integer()?,
+                max: get_value(ElementName::Max)?.parse_integer()?,
+            }),
+            EnumItem::NewIsWithin => Some(DataFilter::NewIsWithin {
+                min: get_value(ElementName::Min)?.parse_integer()?,
+                max: get_value(ElementName::Max)?.parse_integer()?,
+            }),
+            EnumItem::OneEveryN => Some(DataFilter::OneEveryN {
+                period: get_value(ElementName::Period)?.parse_integer()?,
+                offset: get_value(ElementName::Offset)?.parse_integer()?,
+            }),
+            _ => None,
+        }
+    }
+}
+
 //##################################################################
 
 /// A group of ISignalIPdus that is handled together
@@ -659,6 +1204,7 @@ impl ISignalIPduGroup {
 //##################################################################
 
 /// Helper struct to validate signal mappings
+#[derive(Debug, Clone)]
 pub struct SignalMappingValidator {
     bitmap: Vec<u8>,
 }
@@ -943,6 +1489,158 @@ mod test {
         assert_eq!(validator.bitmap, [0xFF; 8]);
     }
 
+    #[test]
+    fn auto_map_signals() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("system", SystemCategory::EcuExtract).unwrap();
+        let pdu = system.create_isignal_ipdu("isignal_ipdu", &package, 2).unwrap();
+
+        let syssignal_1 = package.create_system_signal("syssignal_1").unwrap();
+        let signal_1 = system
+            .create_isignal("signal_1", &package, 4, &syssignal_1, None)
+            .unwrap();
+        let syssignal_2 = package.create_system_signal("syssignal_2").unwrap();
+        let signal_2 = system
+            .create_isignal("signal_2", &package, 8, &syssignal_2, None)
+            .unwrap();
+        let syssignal_3 = package.create_system_signal("syssignal_3").unwrap();
+        let signal_3 = system
+            .create_isignal("signal_3", &package, 4, &syssignal_3, None)
+            .unwrap();
+
+        let mappings = pdu
+            .auto_map_signals(
+                &[
+                    (
+                        &signal_1,
+                        ByteOrder::MostSignificantByteLast,
+                        TransferProperty::Triggered,
+                    ),
+                    (&signal_2, ByteOrder::MostSignificantByteLast, TransferProperty::Pending),
+                ],
+                true,
+                false,
+            )
+            .unwrap();
+        assert_eq!(mappings.len(), 2);
+        assert_eq!(mappings[0].start_position(), Some(0));
+        assert_eq!(mappings[0].update_bit(), Some(4));
+        assert_eq!(mappings[1].start_position(), Some(5));
+        assert_eq!(mappings[1].update_bit(), Some(13));
+        assert_eq!(mappings[1].transfer_property(), Some(TransferProperty::Pending));
+
+        // only two bits are left, so signal_3 does not fit, and the PDU remains unchanged
+        let result = pdu.auto_map_signals(
+            &[(
+                &signal_3,
+                ByteOrder::MostSignificantByteLast,
+                TransferProperty::Triggered,
+            )],
+            false,
+            false,
+        );
+        assert!(result.is_err());
+        assert_eq!(pdu.mapped_signals().count(), 2);
+
+        // byte aligned big endian placement in a new PDU
+        let pdu_2 = system.create_isignal_ipdu("isignal_ipdu_2", &package, 2).unwrap();
+        let mappings = pdu_2
+            .auto_map_signals(
+                &[
+                    (
+                        &signal_1,
+                        ByteOrder::MostSignificantByteFirst,
+                        TransferProperty::Triggered,
+                    ),
+                    (
+                        &signal_3,
+                        ByteOrder::MostSignificantByteFirst,
+                        TransferProperty::Triggered,
+                    ),
+                ],
+                false,
+                true,
+            )
+            .unwrap();
+        assert_eq!(mappings[0].start_position(), Some(7));
+        assert_eq!(mappings[1].start_position(), Some(15));
+    }
+
+    #[test]
+    fn layout() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("system", SystemCategory::EcuExtract).unwrap();
+        let pdu = system.create_isignal_ipdu("isignal_ipdu", &package, 4).unwrap();
+
+        let syssignal_1 = package.create_system_signal("syssignal_1").unwrap();
+        let signal_1 = system
+            .create_isignal("signal_1", &package, 4, &syssignal_1, None)
+            .unwrap();
+        let syssignal_2 = package.create_system_signal("syssignal_2").unwrap();
+        let signal_2 = system
+            .create_isignal("signal_2", &package, 10, &syssignal_2, None)
+            .unwrap();
+
+        let mapping_1 = pdu
+            .map_signal(
+                &signal_1,
+                0,
+                ByteOrder::MostSignificantByteLast,
+                Some(4),
+                TransferProperty::Triggered,
+            )
+            .unwrap();
+        // big endian signal starting at bit 5 of byte 1: bits 8-13 and 20-23
+        let mapping_2 = pdu
+            .map_signal(
+                &signal_2,
+                13,
+                ByteOrder::MostSignificantByteFirst,
+                None,
+                TransferProperty::Triggered,
+            )
+            .unwrap();
+
+        let layout = pdu.layout();
+        assert_eq!(layout.len(), 2);
+        assert_eq!(layout[0].mapping, mapping_1);
+        assert_eq!(layout[0].ranges, vec![0..4]);
+        assert_eq!(layout[0].update_bit, Some(4));
+        assert_eq!(layout[1].mapping, mapping_2);
+        assert_eq!(layout[1].ranges, vec![8..14, 20..24]);
+        assert!(pdu.overlapping_mappings().is_empty());
+
+        // the free bits are 5-7, 14-19 and 24-31
+        assert_eq!(pdu.find_free_range(3, 1), Some(5));
+        assert_eq!(pdu.find_free_range(4, 8), Some(16));
+        assert_eq!(pdu.find_free_range(6, 1), Some(14));
+        assert_eq!(pdu.find_free_range(8, 1), Some(24));
+        assert_eq!(pdu.find_free_range(9, 1), None);
+
+        // create an overlapping mapping directly in the model, bypassing the checks in map_signal
+        let syssignal_3 = package.create_system_signal("syssignal_3").unwrap();
+        let signal_3 = system
+            .create_isignal("signal_3", &package, 2, &syssignal_3, None)
+            .unwrap();
+        let mappings = pdu
+            .element()
+            .get_sub_element(ElementName::ISignalToPduMappings)
+            .unwrap();
+        let mapping_3 = ISignalToIPduMapping::new_with_signal(
+            "overlap",
+            &mappings,
+            &signal_3,
+            3,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+        assert_eq!(pdu.overlapping_mappings(), vec![(mapping_1, mapping_3)]);
+    }
+
     #[test]
     fn ipdu_timing() {
         let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
@@ -975,6 +1673,92 @@ mod test {
         pdu.set_timing(&timing_spec).unwrap();
         let timing_spec2 = pdu.timing().unwrap();
         assert_eq!(timing_spec, timing_spec2);
+
+        // convenience constructors
+        pdu.set_timing(&IpduTiming::cyclic(0.1)).unwrap();
+        assert_eq!(pdu.timing().unwrap(), IpduTiming::cyclic(0.1));
+        pdu.set_timing(&IpduTiming::event_controlled(2, Some(0.01))).unwrap();
+        assert_eq!(pdu.timing().unwrap(), IpduTiming::event_controlled(2, Some(0.01)));
+        let mixed_timing = IpduTiming {
+            transmission_mode_false_timing: Some(TransmissionModeTiming {
+                cyclic_timing: Some(CyclicTiming {
+                    time_period: 1.0,
+                    time_offset: None,
+                }),
+                event_controlled_timing: None,
+            }),
+            ..IpduTiming::mixed(0.1, 3, Some(0.02))
+        };
+        pdu.set_timing(&mixed_timing).unwrap();
+        assert_eq!(pdu.timing().unwrap(), mixed_timing);
+    }
+
+    #[test]
+    fn transmission_mode_conditions() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("system", SystemCategory::EcuExtract).unwrap();
+        let pdu = system.create_isignal_ipdu("pdu", &package, 8).unwrap();
+        let other_pdu = system.create_isignal_ipdu("other_pdu", &package, 8).unwrap();
+        let syssignal_1 = package.create_system_signal("syssignal_1").unwrap();
+        let signal_1 = system
+            .create_isignal("signal_1", &package, 8, &syssignal_1, None)
+            .unwrap();
+        let syssignal_2 = package.create_system_signal("syssignal_2").unwrap();
+        let signal_2 = system
+            .create_isignal("signal_2", &package, 8, &syssignal_2, None)
+            .unwrap();
+        let mapping_1 = pdu
+            .map_signal(
+                &signal_1,
+                0,
+                ByteOrder::MostSignificantByteLast,
+                None,
+                TransferProperty::Triggered,
+            )
+            .unwrap();
+        let mapping_2 = other_pdu
+            .map_signal(
+                &signal_2,
+                0,
+                ByteOrder::MostSignificantByteLast,
+                None,
+                TransferProperty::Triggered,
+            )
+            .unwrap();
+
+        assert!(pdu.transmission_mode_conditions().is_empty());
+        let conditions = vec![
+            TransmissionModeCondition {
+                signal_mapping: mapping_1.clone(),
+                data_filter: DataFilter::NewIsWithin { min: -5, max: 5 },
+            },
+            TransmissionModeCondition {
+                signal_mapping: mapping_1.clone(),
+                data_filter: DataFilter::MaskedNewEqualsX { mask: 0xf0, x: 0x10 },
+            },
+            TransmissionModeCondition {
+                signal_mapping: mapping_1.clone(),
+                data_filter: DataFilter::OneEveryN { period: 4, offset: 1 },
+            },
+        ];
+        pdu.set_transmission_mode_conditions(&conditions).unwrap();
+        assert_eq!(pdu.transmission_mode_conditions(), conditions);
+
+        // setting the timing does not remove the conditions
+        pdu.set_timing(&IpduTiming::mixed(0.1, 1, Some(0.01))).unwrap();
+        assert_eq!(pdu.transmission_mode_conditions(), conditions);
+        assert_eq!(pdu.timing().unwrap(), IpduTiming::mixed(0.1, 1, Some(0.01)));
+
+        // a condition may only refer to signals in the same PDU
+        let result = pdu.set_transmission_mode_conditions(&[TransmissionModeCondition {
+            signal_mapping: mapping_2,
+            data_filter: DataFilter::Always,
+        }]);
+        assert!(result.is_err());
+
+        pdu.set_transmission_mode_conditions(&[]).unwrap();
+        assert!(pdu.transmission_mode_conditions().is_empty());
     }
 
     #[test]
@@ -9,10 +9,12 @@ use crate::{
 use autosar_data::{AutosarDataError, Element, ElementName, EnumItem};
 use std::str::FromStr;
 
+mod codec;
 mod container_ipdu;
 mod isignal_ipdu;
 mod secured_ipdu;
 
+pub use codec::*;
 pub use container_ipdu::*;
 pub use isignal_ipdu::*;
 pub use secured_ipdu::*;
@@ -39,21 +41,12 @@ pub trait AbstractPdu: AbstractionElement + Into<Pdu> {
 
     /// list all `PduTriggerings` that trigger this PDU
     fn pdu_triggerings(&self) -> Vec<PduTriggering> {
-        let model_result = self.element().model();
-        let path_result = self.element().path();
-        if let (Ok(model), Ok(path)) = (model_result, path_result) {
-            model
-                .get_references_to(&path)
-                .iter()
-                .filter_map(|e| {
-                    e.upgrade()
-                        .and_then(|ref_elem| ref_elem.named_parent().ok().flatten())
-                        .and_then(|elem| PduTriggering::try_from(elem).ok())
-                })
-                .collect()
-        } else {
-            vec![]
-        }
+        // uses the reverse reference index while a BulkEditSession is active
+        get_reference_parents(self.element())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(named_parent, _)| PduTriggering::try_from(named_parent).ok())
+            .collect()
     }
 }
 
@@ -168,7 +161,7 @@ impl NmPdu {
         // create and return the new mapping
         let model = self.element().model()?;
         let base_path = self.element().path()?;
-        let name = make_unique_name(&model, &base_path, &signal_name);
+        let name = make_unique_name(&model, &base_path, &signal_name)?;
 
         // the crucial difference between NmPdu and ISignalIPdu is here
         // NmPdu uses ISignalToIPduMapping, while ISignalIPdu uses ISignalToPduMapping
@@ -209,7 +202,7 @@ impl NmPdu {
         // create and return the new mapping
         let model = self.element().model()?;
         let base_path = self.element().path()?;
-        let name = make_unique_name(&model, &base_path, &signal_group_name);
+        let name = make_unique_name(&model, &base_path, &signal_group_name)?;
 
         // the crucial difference between NmPdu and ISignalIPdu is here
         // NmPdu uses ISignalToIPduMapping, while ISignalIPdu uses ISignalToPduMapping
@@ -1246,7 +1239,7 @@ impl PduTriggering {
             .name()
             .ok_or(AutosarAbstractionError::InvalidParameter("invalid pdu".to_string()))?;
         let pt_name = format!("PT_{pdu_name}");
-        let pt_name = make_unique_name(&model, &base_path, &pt_name);
+        let pt_name = make_unique_name(&model, &base_path, &pt_name)?;
 
         let triggerings = channel
             .element()
@@ -1409,10 +1402,41 @@ impl PduTriggering {
     }
 
     /// create a signal triggering for a signal and connect it to this `PduTriggering`
-    pub(crate) fn create_signal_triggering(
-        &self,
-        signal: &ISignal,
-    ) -> Result<ISignalTriggering, AutosarAbstractionError> {
+    ///
+    /// Signal triggerings are created automatically when a PDU is triggered or when a signal is mapped to a
+    /// triggered PDU. This function can be used to repair models where the signal triggering is missing.
+    /// The new signal triggering is connected to the ECUs of all `IPduPorts` of the `PduTriggering`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, communication::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_can_cluster("Cluster", &package, None)?;
+    /// # let channel = cluster.create_physical_channel("Channel")?;
+    /// # let frame = system.create_can_frame("Frame", &package, 8)?;
+    /// # let pdu = system.create_isignal_ipdu("Pdu", &package, 8)?;
+    /// # let syssignal = package.create_system_signal("Signal")?;
+    /// # let signal = system.create_isignal("Signal", &package, 8, &syssignal, None)?;
+    /// # let frame_triggering = channel.trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)?;
+    /// # frame.map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)?;
+    /// # pdu.map_signal(&signal, 0, ByteOrder::MostSignificantByteLast, None, TransferProperty::Triggered)?;
+    /// let pdu_triggering = frame_triggering.pdu_triggerings().next().unwrap();
+    /// // in an imported model, the signal triggering might be missing
+    /// # pdu_triggering.signal_triggerings().next().unwrap().remove(false)?;
+    /// let signal_triggering = pdu_triggering.create_signal_triggering(&signal)?;
+    /// assert_eq!(signal_triggering.signal(), Some(signal));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while creating the signal triggering
+    pub fn create_signal_triggering(&self, signal: &ISignal) -> Result<ISignalTriggering, AutosarAbstractionError> {
         let channel = self.physical_channel()?;
         let st = ISignalTriggering::new(signal, &channel)?;
         let triggerings = self
@@ -1433,7 +1457,13 @@ impl PduTriggering {
     }
 
     /// create a signal triggering for a signal group and connect it to this `PduTriggering`
-    pub(crate) fn create_signal_group_triggering(
+    ///
+    /// The new signal triggering is connected to the ECUs of all `IPduPorts` of the `PduTriggering`.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while creating the signal triggering
+    pub fn create_signal_group_triggering(
         &self,
         signal_group: &ISignalGroup,
     ) -> Result<ISignalTriggering, AutosarAbstractionError> {
@@ -1455,6 +1485,68 @@ impl PduTriggering {
 
         Ok(st)
     }
+
+    /// connect an existing signal triggering to this `PduTriggering`
+    ///
+    /// This can be used to repair orphaned signal triggerings, which are not referenced by any `PduTriggering`.
+    /// The signal triggering is connected to the ECUs of all `IPduPorts` of the `PduTriggering`.
+    /// Nothing is changed if the signal triggering is already connected.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The signal triggering belongs to a different physical channel
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn add_signal_triggering(&self, signal_triggering: &ISignalTriggering) -> Result<(), AutosarAbstractionError> {
+        if signal_triggering.physical_channel()? != self.physical_channel()? {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The signal triggering belongs to a different physical channel".to_string(),
+            ));
+        }
+        if self.signal_triggerings().any(|st| st == *signal_triggering) {
+            return Ok(());
+        }
+
+        self.element()
+            .get_or_create_sub_element(ElementName::ISignalTriggerings)?
+            .create_sub_element(ElementName::ISignalTriggeringRefConditional)?
+            .create_sub_element(ElementName::ISignalTriggeringRef)?
+            .set_reference_target(signal_triggering.element())?;
+
+        for pdu_port in self.pdu_ports() {
+            if let (Ok(ecu), Some(direction)) = (pdu_port.ecu(), pdu_port.communication_direction()) {
+                signal_triggering.connect_to_ecu(&ecu, direction)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// disconnect a signal triggering from this `PduTriggering`
+    ///
+    /// The signal triggering itself is not removed; use [`ISignalTriggering::remove`] for that.
+    /// Nothing is changed if the signal triggering is not connected to this `PduTriggering`.
+    pub fn remove_signal_triggering(
+        &self,
+        signal_triggering: &ISignalTriggering,
+    ) -> Result<(), AutosarAbstractionError> {
+        let Some(triggerings) = self.element().get_sub_element(ElementName::ISignalTriggerings) else {
+            return Ok(());
+        };
+        for ref_conditional in triggerings.sub_elements() {
+            let is_target = ref_conditional
+                .get_sub_element(ElementName::ISignalTriggeringRef)
+                .and_then(|st_ref| st_ref.get_reference_target().ok())
+                .is_some_and(|target| target == *signal_triggering.element());
+            if is_target {
+                triggerings.remove_sub_element(ref_conditional)?;
+            }
+        }
+        if triggerings.sub_elements().next().is_none() {
+            self.element().remove_sub_element(triggerings)?;
+        }
+
+        Ok(())
+    }
 }
 
 //##################################################################
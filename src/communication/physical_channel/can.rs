@@ -1,8 +1,9 @@
 use crate::{
-    AbstractionElement, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
+    AbstractionElement, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement, abstraction_element,
     communication::{
-        AbstractPhysicalChannel, CanAddressingMode, CanCluster, CanCommunicationConnector, CanFrame,
-        CanFrameTriggering, CanFrameType, PhysicalChannel,
+        AbstractFrame, AbstractFrameTriggering, AbstractPhysicalChannel, BusLoad, CanAddressingMode, CanCluster,
+        CanCommunicationConnector, CanFrame, CanFrameTriggering, CanFrameType, CommunicationDirection,
+        EventControlledTiming, PhysicalChannel,
     },
 };
 use autosar_data::{Element, ElementName};
@@ -118,6 +119,213 @@ impl CanPhysicalChannel {
             .flat_map(|elem| elem.sub_elements())
             .filter_map(|elem| CanFrameTriggering::try_from(elem).ok())
     }
+
+    /// calculate the bus load of this CAN channel
+    ///
+    /// The transmission period of each frame is the shortest cyclic timing of the `ISignalIPdus` mapped to it.
+    /// Frames without any cyclic timing are not included in the load; they are listed in `frames_without_timing`.
+    ///
+    /// The average load is calculated without dynamic stuff bits, while the worst-case load assumes the
+    /// maximum number of stuff bits in each frame. The data phase of CAN FD frames uses the CAN FD baudrate, if it is set.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The baudrate of the CAN cluster is not set
+    pub fn bus_load(&self) -> Result<BusLoad, AutosarAbstractionError> {
+        let cluster = self.cluster()?;
+        let baudrate =
+            cluster
+                .baudrate()
+                .filter(|baudrate| *baudrate > 0)
+                .ok_or(AutosarAbstractionError::InvalidParameter(
+                    "The baudrate of the CAN cluster is not set".to_string(),
+                ))?;
+        let fd_baudrate = cluster
+            .can_fd_baudrate()
+            .filter(|fd_baudrate| *fd_baudrate > 0)
+            .unwrap_or(baudrate);
+
+        let mut bus_load = BusLoad {
+            average: 0.0,
+            worst_case: 0.0,
+            frames_without_timing: Vec::new(),
+        };
+        for frame_triggering in self.frame_triggerings() {
+            let Some((frame, cycle_time)) = frame_triggering
+                .frame()
+                .and_then(|frame| super::frame_cycle_time(&frame).map(|cycle_time| (frame, cycle_time)))
+            else {
+                bus_load.frames_without_timing.push(frame_triggering.into());
+                continue;
+            };
+
+            let length = frame.length().unwrap_or(0);
+            let extended = frame_triggering.addressing_mode() == Some(CanAddressingMode::Extended);
+            let is_fd = match frame_triggering.frame_type() {
+                Some(CanFrameType::CanFd) => true,
+                Some(CanFrameType::Can20) => false,
+                Some(CanFrameType::Any) | None => length > 8,
+            };
+            let (average_time, worst_case_time) = if is_fd {
+                can_fd_frame_time(length, extended, baudrate, fd_baudrate)
+            } else {
+                can_frame_time(length, extended, baudrate)
+            };
+            bus_load.average += average_time / cycle_time;
+            bus_load.worst_case += worst_case_time / cycle_time;
+        }
+
+        Ok(bus_load)
+    }
+
+    /// create a table of the transmission schedule of all frames on this CAN channel
+    ///
+    /// The timing of each frame is derived from the timing specifications of the `ISignalIPdus` mapped to it:
+    /// the cycle time and offset come from the cyclic timing with the shortest period, and the minimum delay
+    /// is the largest minimum delay of all mapped PDUs.
+    /// The table is sorted by CAN identifier and is intended for the configuration of a rest-bus simulation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_can_cluster("Cluster", &package, None)?;
+    /// # let channel = cluster.create_physical_channel("Channel")?;
+    /// # let frame = system.create_can_frame("Frame", &package, 8)?;
+    /// channel.trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)?;
+    /// for entry in channel.schedule_table() {
+    ///     println!("{}: {:?} {:?}", entry.frame_name, entry.cycle_time, entry.time_offset);
+    /// }
+    /// # assert_eq!(channel.schedule_table().len(), 1);
+    /// # Ok(())}
+    /// ```
+    #[must_use]
+    pub fn schedule_table(&self) -> Vec<CanScheduleEntry> {
+        let mut table: Vec<CanScheduleEntry> = self
+            .frame_triggerings()
+            .map(|frame_triggering| {
+                let frame = frame_triggering.frame();
+                let frame_name = frame.as_ref().and_then(|frame| frame.name()).unwrap_or_default();
+                let length = frame.as_ref().and_then(|frame| frame.length()).unwrap_or(0);
+                let cyclic_timing = frame.as_ref().and_then(super::frame_cyclic_timing);
+                let ipdu_timings: Vec<_> = frame
+                    .as_ref()
+                    .map(|frame| super::frame_ipdu_timings(frame).collect())
+                    .unwrap_or_default();
+                let minimum_delay = ipdu_timings
+                    .iter()
+                    .filter_map(|timing| timing.minimum_delay)
+                    .reduce(f64::max);
+                let event_controlled_timing = ipdu_timings
+                    .into_iter()
+                    .flat_map(|timing| {
+                        [
+                            timing.transmission_mode_true_timing,
+                            timing.transmission_mode_false_timing,
+                        ]
+                    })
+                    .flatten()
+                    .find_map(|tm_timing| tm_timing.event_controlled_timing);
+                let senders = frame_triggering
+                    .frame_ports()
+                    .filter(|port| port.communication_direction() == Some(CommunicationDirection::Out))
+                    .filter_map(|port| port.ecu().ok())
+                    .collect();
+
+                CanScheduleEntry {
+                    identifier: frame_triggering.identifier(),
+                    addressing_mode: frame_triggering.addressing_mode(),
+                    frame_type: frame_triggering.frame_type(),
+                    frame_name,
+                    length,
+                    cycle_time: cyclic_timing.as_ref().map(|cyclic_timing| cyclic_timing.time_period),
+                    time_offset: cyclic_timing.and_then(|cyclic_timing| cyclic_timing.time_offset),
+                    minimum_delay,
+                    event_controlled_timing,
+                    senders,
+                    frame_triggering,
+                }
+            })
+            .collect();
+        // frame triggerings without an identifier are sorted to the end
+        table.sort_by_key(|entry| (entry.identifier.is_none(), entry.identifier));
+        table
+    }
+}
+
+//##################################################################
+
+/// One row of the transmission schedule of a CAN channel
+///
+/// It is returned by [`CanPhysicalChannel::schedule_table`]. All times are given in seconds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CanScheduleEntry {
+    /// the frame triggering that transmits the frame
+    pub frame_triggering: CanFrameTriggering,
+    /// the name of the triggered frame
+    pub frame_name: String,
+    /// the CAN identifier of the frame
+    pub identifier: Option<u32>,
+    /// the addressing mode of the frame
+    pub addressing_mode: Option<CanAddressingMode>,
+    /// the frame type (CAN 2.0 or CAN FD)
+    pub frame_type: Option<CanFrameType>,
+    /// the length of the frame in bytes
+    pub length: u32,
+    /// the shortest cyclic transmission period of the PDUs in the frame
+    pub cycle_time: Option<f64>,
+    /// the offset of the cyclic transmission
+    pub time_offset: Option<f64>,
+    /// the minimum delay between two transmissions, used for debouncing event-triggered transmissions
+    pub minimum_delay: Option<f64>,
+    /// the event controlled timing of the PDUs in the frame, if any
+    pub event_controlled_timing: Option<EventControlledTiming>,
+    /// the ECUs that send the frame
+    pub senders: Vec<EcuInstance>,
+}
+
+// transmission time in seconds of a CAN 2.0 frame without and with the maximum number of stuff bits
+fn can_frame_time(length: u32, extended: bool, baudrate: u32) -> (f64, f64) {
+    let data_bits = 8 * length.min(8);
+    // bits between SOF and the end of the CRC are subject to bit stuffing
+    let (stuffable_bits, fixed_bits) = if extended { (54, 13) } else { (34, 13) };
+    let bits = stuffable_bits + data_bits + fixed_bits;
+    let stuff_bits = (stuffable_bits + data_bits - 1) / 4;
+
+    let baudrate = f64::from(baudrate);
+    (f64::from(bits) / baudrate, f64::from(bits + stuff_bits) / baudrate)
+}
+
+// transmission time in seconds of a CAN FD frame without and with the maximum number of dynamic stuff bits
+fn can_fd_frame_time(length: u32, extended: bool, baudrate: u32, fd_baudrate: u32) -> (f64, f64) {
+    // the payload is padded up to the next valid CAN FD length
+    let length = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64]
+        .into_iter()
+        .find(|valid_length| *valid_length >= length)
+        .unwrap_or(64);
+    let crc_bits = if length <= 16 { 17 } else { 21 };
+
+    // arbitration phase: SOF, identifier, control bits up to BRS; CRC delimiter, ACK, EOF and IFS
+    let arbitration_stuffable_bits = if extended { 36 } else { 17 };
+    let arbitration_bits = arbitration_stuffable_bits + 13;
+    let arbitration_stuff_bits = (arbitration_stuffable_bits - 1) / 4;
+    // data phase: ESI, DLC, data, stuff count and CRC; the stuff count and CRC always contain fixed stuff bits
+    let data_stuffable_bits = 5 + 8 * length;
+    let data_bits = data_stuffable_bits + 4 + crc_bits + (4 + crc_bits).div_ceil(4);
+    let data_stuff_bits = (data_stuffable_bits - 1) / 4;
+
+    let baudrate = f64::from(baudrate);
+    let fd_baudrate = f64::from(fd_baudrate);
+    let average_time = f64::from(arbitration_bits) / baudrate + f64::from(data_bits) / fd_baudrate;
+    let worst_case_time = f64::from(arbitration_bits + arbitration_stuff_bits) / baudrate
+        + f64::from(data_bits + data_stuff_bits) / fd_baudrate;
+    (average_time, worst_case_time)
 }
 
 impl From<CanPhysicalChannel> for PhysicalChannel {
@@ -136,7 +344,10 @@ impl AbstractPhysicalChannel for CanPhysicalChannel {
 mod test {
     use crate::{
         AbstractionElement, AutosarModelAbstraction, ByteOrder, SystemCategory,
-        communication::{AbstractFrame, AbstractPhysicalChannel, CanAddressingMode, CanFrameType, PhysicalChannel},
+        communication::{
+            AbstractFrame, AbstractPhysicalChannel, CanAddressingMode, CanFrameType, CommunicationDirection,
+            CyclicTiming, EventControlledTiming, FrameTriggering, IpduTiming, PhysicalChannel, TransmissionModeTiming,
+        },
     };
     use autosar_data::AutosarVersion;
 
@@ -181,4 +392,163 @@ mod test {
         // the PDU was removed, because it was unused and deep removal was requested
         assert!(isignal_ipdu.element().parent().is_err());
     }
+
+    #[test]
+    fn bus_load() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+
+        // the baudrate is required
+        let cluster = system.create_can_cluster("NoBaudrate", &pkg, None).unwrap();
+        let channel = cluster.create_physical_channel("channel").unwrap();
+        assert!(channel.bus_load().is_err());
+
+        let cluster = system.create_can_cluster("CanCluster", &pkg, Some(500_000)).unwrap();
+        let channel = cluster.create_physical_channel("channel_name").unwrap();
+
+        let frame = system.create_can_frame("CanFrame", &pkg, 8).unwrap();
+        channel
+            .trigger_frame(&frame, 0x123, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let isignal_ipdu = system.create_isignal_ipdu("ISignalIPdu", &pkg, 8).unwrap();
+        isignal_ipdu
+            .set_timing(&IpduTiming {
+                minimum_delay: None,
+                transmission_mode_true_timing: Some(TransmissionModeTiming {
+                    cyclic_timing: Some(CyclicTiming {
+                        time_period: 0.01,
+                        time_offset: None,
+                    }),
+                    event_controlled_timing: None,
+                }),
+                transmission_mode_false_timing: None,
+            })
+            .unwrap();
+        frame
+            .map_pdu(&isignal_ipdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+
+        // a frame without any cyclic PDU
+        let frame_2 = system.create_can_frame("CanFrame2", &pkg, 8).unwrap();
+        let ft_2 = channel
+            .trigger_frame(&frame_2, 0x124, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+
+        let bus_load = channel.bus_load().unwrap();
+        // 111 bits without stuff bits, 135 bits with the maximum number of stuff bits; every 10ms at 500kbit/s
+        assert!((bus_load.average - 0.0222).abs() < 1e-9);
+        assert!((bus_load.worst_case - 0.027).abs() < 1e-9);
+        assert_eq!(bus_load.frames_without_timing, vec![FrameTriggering::Can(ft_2)]);
+    }
+
+    #[test]
+    fn schedule_table() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let cluster = system.create_can_cluster("CanCluster", &pkg, Some(500_000)).unwrap();
+        let channel = cluster.create_physical_channel("channel_name").unwrap();
+        assert!(channel.schedule_table().is_empty());
+
+        let ecu = system.create_ecu_instance("Ecu", &pkg).unwrap();
+        let controller = ecu.create_can_communication_controller("Controller").unwrap();
+        controller.connect_physical_channel("Connector", &channel).unwrap();
+
+        // a frame with two PDUs: the faster cyclic timing and the larger minimum delay are used
+        let frame = system.create_can_frame("CyclicFrame", &pkg, 8).unwrap();
+        let ft = channel
+            .trigger_frame(&frame, 0x200, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        ft.connect_to_ecu(&ecu, CommunicationDirection::Out).unwrap();
+        let ipdu_1 = system.create_isignal_ipdu("ISignalIPdu1", &pkg, 4).unwrap();
+        ipdu_1
+            .set_timing(&IpduTiming {
+                minimum_delay: Some(0.005),
+                transmission_mode_true_timing: Some(TransmissionModeTiming {
+                    cyclic_timing: Some(CyclicTiming {
+                        time_period: 0.1,
+                        time_offset: Some(0.01),
+                    }),
+                    event_controlled_timing: None,
+                }),
+                transmission_mode_false_timing: None,
+            })
+            .unwrap();
+        let ipdu_2 = system.create_isignal_ipdu("ISignalIPdu2", &pkg, 4).unwrap();
+        ipdu_2
+            .set_timing(&IpduTiming {
+                minimum_delay: Some(0.002),
+                transmission_mode_true_timing: Some(TransmissionModeTiming {
+                    cyclic_timing: Some(CyclicTiming {
+                        time_period: 0.02,
+                        time_offset: Some(0.004),
+                    }),
+                    event_controlled_timing: None,
+                }),
+                transmission_mode_false_timing: None,
+            })
+            .unwrap();
+        frame
+            .map_pdu(&ipdu_1, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        frame
+            .map_pdu(&ipdu_2, 32, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+
+        // an event-triggered frame with a lower identifier
+        let frame_2 = system.create_can_frame("EventFrame", &pkg, 8).unwrap();
+        let ft_2 = channel
+            .trigger_frame(&frame_2, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        ft_2.connect_to_ecu(&ecu, CommunicationDirection::In).unwrap();
+        let ipdu_3 = system.create_isignal_ipdu("ISignalIPdu3", &pkg, 8).unwrap();
+        ipdu_3
+            .set_timing(&IpduTiming {
+                minimum_delay: None,
+                transmission_mode_true_timing: Some(TransmissionModeTiming {
+                    cyclic_timing: None,
+                    event_controlled_timing: Some(EventControlledTiming {
+                        number_of_repetitions: 2,
+                        repetition_period: Some(0.01),
+                    }),
+                }),
+                transmission_mode_false_timing: None,
+            })
+            .unwrap();
+        frame_2
+            .map_pdu(&ipdu_3, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+
+        let table = channel.schedule_table();
+        assert_eq!(table.len(), 2);
+
+        assert_eq!(table[0].frame_triggering, ft_2);
+        assert_eq!(table[0].frame_name, "EventFrame");
+        assert_eq!(table[0].identifier, Some(0x100));
+        assert_eq!(table[0].cycle_time, None);
+        assert_eq!(table[0].time_offset, None);
+        assert_eq!(table[0].minimum_delay, None);
+        assert_eq!(
+            table[0].event_controlled_timing,
+            Some(EventControlledTiming {
+                number_of_repetitions: 2,
+                repetition_period: Some(0.01),
+            })
+        );
+        // the ECU only receives this frame
+        assert!(table[0].senders.is_empty());
+
+        assert_eq!(table[1].frame_triggering, ft);
+        assert_eq!(table[1].frame_name, "CyclicFrame");
+        assert_eq!(table[1].identifier, Some(0x200));
+        assert_eq!(table[1].addressing_mode, Some(CanAddressingMode::Standard));
+        assert_eq!(table[1].frame_type, Some(CanFrameType::Can20));
+        assert_eq!(table[1].length, 8);
+        assert_eq!(table[1].cycle_time, Some(0.02));
+        assert_eq!(table[1].time_offset, Some(0.004));
+        assert_eq!(table[1].minimum_delay, Some(0.005));
+        assert_eq!(table[1].event_controlled_timing, None);
+        assert_eq!(table[1].senders, vec![ecu]);
+    }
 }
@@ -1,12 +1,12 @@
 use crate::communication::{
-    AbstractPdu, AbstractPhysicalChannel, CommunicationDirection, EthernetCluster, EthernetCommunicationConnector,
-    GeneralPurposePdu, Pdu, PduCollectionTrigger, PduTriggering,
+    AbstractPdu, AbstractPhysicalChannel, CommunicationDirection, CouplingPort, EthernetCluster,
+    EthernetCommunicationConnector, GeneralPurposePdu, Pdu, PduCollectionTrigger, PduTriggering,
 };
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
-    abstraction_element,
+    abstraction_element, get_reference_parents, make_unique_generated_name,
 };
-use autosar_data::{AutosarVersion, Element, ElementName, EnumItem};
+use autosar_data::{AutosarVersion, Element, ElementName, EnumItem, WeakElement};
 
 mod networkendpoint;
 mod soad_old;
@@ -229,6 +229,35 @@ impl EthernetPhysicalChannel {
         EthernetCluster::try_from(cluster_elem)
     }
 
+    /// iterate over all [`CouplingPort`]s that are members of the VLAN of this channel
+    ///
+    /// This includes the ports of [`crate::communication::CouplingElement`]s as well as the coupling ports
+    /// of connected [`crate::communication::EthernetCommunicationController`]s.
+    pub fn coupling_ports(&self) -> impl Iterator<Item = CouplingPort> + Send + use<> {
+        let mut ports = Vec::new();
+        if let Ok(model) = self.element().model()
+            && let Ok(path) = self.element().path()
+        {
+            for vlan_ref in model
+                .get_references_to(&path)
+                .iter()
+                .filter_map(WeakElement::upgrade)
+                .filter(|elem| elem.element_name() == ElementName::VlanRef)
+            {
+                if let Some(port) = vlan_ref
+                    .named_parent()
+                    .ok()
+                    .flatten()
+                    .and_then(|parent| CouplingPort::try_from(parent).ok())
+                    && !ports.contains(&port)
+                {
+                    ports.push(port);
+                }
+            }
+        }
+        ports.into_iter()
+    }
+
     /// create a network endpoint - IPv4 or IPv6 address - for this channel
     ///
     /// In older versions of the Autosar standard, up to version 4.4.0, the `NetworkEndpoint` could be linked to an Ecu.
@@ -252,6 +281,8 @@ impl EthernetPhysicalChannel {
     ///     address_source: Some(IPv4AddressSource::Fixed),
     ///     default_gateway: Some("192.168.0.2".to_string()),
     ///     network_mask: Some("255.255.255.0".to_string()),
+    ///     dns_servers: vec![],
+    ///     ttl: None,
     /// };
     /// let network_endpoint = channel.create_network_endpoint("Address1", endpoint_address, None)?;
     /// # Ok(())}
@@ -315,6 +346,8 @@ impl EthernetPhysicalChannel {
     /// #     address_source: Some(IPv4AddressSource::Fixed),
     /// #     default_gateway: Some("192.168.0.2".to_string()),
     /// #     network_mask: Some("255.255.255.0".to_string()),
+    /// #     dns_servers: vec![],
+    /// #     ttl: None,
     /// # };
     /// # channel.create_network_endpoint("Address1", endpoint_address, None)?;
     /// for network_endpoint in channel.network_endpoints() {
@@ -356,6 +389,8 @@ impl EthernetPhysicalChannel {
     /// #     address_source: Some(IPv4AddressSource::Fixed),
     /// #     default_gateway: Some("192.168.0.2".to_string()),
     /// #     network_mask: Some("255.255.255.0".to_string()),
+    /// #     dns_servers: vec![],
+    /// #     ttl: None,
     /// # };
     /// # let network_endpoint = channel.create_network_endpoint("Address", endpoint_address, None)?;
     /// let tcp_port = TpConfig::TcpTp {
@@ -403,6 +438,8 @@ impl EthernetPhysicalChannel {
     /// #     address_source: Some(IPv4AddressSource::Fixed),
     /// #     default_gateway: Some("192.168.0.2".to_string()),
     /// #     network_mask: Some("255.255.255.0".to_string()),
+    /// #     dns_servers: vec![],
+    /// #     ttl: None,
     /// # };
     /// # let network_endpoint = channel.create_network_endpoint("Address", endpoint_address, None)?;
     /// let tcp_port = TpConfig::TcpTp {
@@ -444,7 +481,9 @@ impl EthernetPhysicalChannel {
     /// #    address: Some("192.16.0.1".to_string()),
     /// #    address_source: Some(IPv4AddressSource::Fixed),
     /// #    default_gateway: None,
-    /// #    network_mask: None
+    /// #    network_mask: None,
+    /// #    dns_servers: vec![],
+    /// #    ttl: None
     /// # }, None)?;
     /// # let server_socket = channel.create_socket_address("ServerSocket", &server_endpoint, &TpConfig::TcpTp {
     /// #    port_number: Some(1234),
@@ -477,6 +516,216 @@ impl EthernetPhysicalChannel {
             .filter_map(|cb_elem| SocketConnectionBundle::try_from(cb_elem).ok())
     }
 
+    /// iterate over all socket connections in this channel that are not part of a `SocketConnectionBundle`
+    ///
+    /// These `SocketConnection`s are the "very old" way to establish a connection between two sockets.
+    /// They can only be read; use [`upgrade_to_connection_bundles`](Self::upgrade_to_connection_bundles)
+    /// to convert them into `SocketConnectionBundle`s.
+    pub fn socket_connections(&self) -> impl Iterator<Item = SocketConnection> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::SoAdConfig)
+            .and_then(|sc| sc.get_sub_element(ElementName::Connections))
+            .into_iter()
+            .flat_map(|connections| connections.sub_elements())
+            .filter_map(|conn_elem| SocketConnection::try_from(conn_elem).ok())
+    }
+
+    /// convert all "very old" `SocketConnection`s of this channel into `SocketConnectionBundle`s
+    ///
+    /// The connections are grouped by their server port, and each group is moved into the bundle for that
+    /// server port. Existing bundles are reused, otherwise a new bundle is created.
+    /// All settings of the connections, including their `SocketConnectionIpduIdentifier`s, are kept.
+    ///
+    /// Returns the bundles that received connections.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] one of the connections has no server port.
+    ///   This is checked before the model is modified.
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while moving the connections
+    pub fn upgrade_to_connection_bundles(&self) -> Result<Vec<SocketConnectionBundle>, AutosarAbstractionError> {
+        let connections = self
+            .socket_connections()
+            .map(|connection| {
+                let server_port = connection
+                    .element()
+                    .get_sub_element(ElementName::ServerPortRef)
+                    .and_then(|spr| spr.get_reference_target().ok())
+                    .and_then(|sp| SocketAddress::try_from(sp).ok());
+                server_port.map(|server_port| (connection, server_port)).ok_or_else(|| {
+                    AutosarAbstractionError::InvalidParameter("A SocketConnection has no server port".to_string())
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let model = self.element().model()?;
+        let path = self.element().path()?;
+        let mut bundles: Vec<SocketConnectionBundle> = Vec::new();
+        for (connection, server_port) in connections {
+            let existing_bundle = self
+                .socket_connection_bundles()
+                .find(|bundle| bundle.server_port().as_ref() == Some(&server_port));
+            let bundle = if let Some(bundle) = existing_bundle {
+                bundle
+            } else {
+                let initial_name = format!("{}_Bundle", server_port.name().unwrap_or_default());
+                let name = make_unique_generated_name(&model, &path, &initial_name);
+                self.create_socket_connection_bundle(&name, &server_port)?
+            };
+
+            // move the connection first, so that it is still complete if the move fails
+            let moved_connection = bundle
+                .element()
+                .get_or_create_sub_element(ElementName::BundledConnections)?
+                .move_element_here(connection.element())?;
+            // inside a bundle, the server port is provided by the bundle
+            let _ = moved_connection.remove_sub_element_kind(ElementName::ServerPortRef);
+
+            if !bundles.contains(&bundle) {
+                bundles.push(bundle);
+            }
+        }
+
+        if let Some(soad_config) = self.element().get_sub_element(ElementName::SoAdConfig) {
+            let _ = soad_config.remove_sub_element_kind(ElementName::Connections);
+        }
+
+        Ok(bundles)
+    }
+
+    /// migrate all `SocketConnectionBundle`s of this channel to `StaticSocketConnection`s
+    ///
+    /// Each bundled connection is replaced by a pair of `StaticSocketConnection`s, one on the server port and one
+    /// on the client port. For TCP the server port listens and the client port connects; UDP connections get no `TcpRole`.
+    /// A `SoConIPduIdentifier` is created in `ipdu_identifier_set` for each `SocketConnectionIpduIdentifier`;
+    /// it keeps the header id, timeout, collection trigger and the existing `PduTriggering`.
+    /// Any "very old" connections without a bundle are converted with
+    /// [`upgrade_to_connection_bundles`](Self::upgrade_to_connection_bundles) first.
+    ///
+    /// `StaticSocketConnection`s are only available since Autosar 4.5.0 (`AUTOSAR_00048`), so the model
+    /// should be switched to a newer version after the migration.
+    ///
+    /// Returns all created `StaticSocketConnection`s.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] a bundle or a connection without bundle has no server port,
+    ///   or a connection has no client port. This is checked before the model is modified.
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while creating the new elements
+    pub fn migrate_to_static_socket_connections(
+        &self,
+        ipdu_identifier_set: &SocketConnectionIpduIdentifierSet,
+    ) -> Result<Vec<StaticSocketConnection>, AutosarAbstractionError> {
+        // check all connections before the model is modified, including those that are upgraded to bundles first
+        for connection in self.socket_connections() {
+            if connection
+                .element()
+                .get_sub_element(ElementName::ServerPortRef)
+                .is_none()
+            {
+                return Err(AutosarAbstractionError::InvalidParameter(
+                    "A SocketConnection has no server port".to_string(),
+                ));
+            }
+            if connection.client_port().is_none() {
+                return Err(AutosarAbstractionError::InvalidParameter(
+                    "A SocketConnection has no client port".to_string(),
+                ));
+            }
+        }
+        for bundle in self.socket_connection_bundles() {
+            let bundle_name = bundle.name().unwrap_or_default();
+            if bundle.server_port().is_none() {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "SocketConnectionBundle {bundle_name} has no server port"
+                )));
+            }
+            if bundle
+                .bundled_connections()
+                .any(|connection| connection.client_port().is_none())
+            {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "A SocketConnection in SocketConnectionBundle {bundle_name} has no client port"
+                )));
+            }
+        }
+
+        self.upgrade_to_connection_bundles()?;
+
+        let mut bundles = Vec::new();
+        for bundle in self.socket_connection_bundles() {
+            let bundle_name = bundle.name().unwrap_or_default();
+            let Some(server_port) = bundle.server_port() else {
+                continue;
+            };
+            let connections: Vec<_> = bundle
+                .bundled_connections()
+                .filter_map(|connection| connection.client_port().map(|client_port| (connection, client_port)))
+                .collect();
+            bundles.push((bundle, bundle_name, server_port, connections));
+        }
+
+        let model = self.element().model()?;
+        let set_path = ipdu_identifier_set.element().path()?;
+        let mut static_connections = Vec::new();
+        for (bundle, bundle_name, server_port, connections) in bundles {
+            // the TcpRole is only meaningful for TCP connections
+            let is_tcp = matches!(server_port.tp_config(), Some(TpConfig::TcpTp { .. }));
+            for (connection, client_port) in connections {
+                let server_name = make_unique_generated_name(&model, &server_port.element().path()?, &bundle_name);
+                let server_ssc = server_port.create_static_socket_connection(
+                    &server_name,
+                    &client_port,
+                    is_tcp.then_some(TcpRole::Listen),
+                    None,
+                )?;
+                let client_name = make_unique_generated_name(&model, &client_port.element().path()?, &bundle_name);
+                let client_ssc = client_port.create_static_socket_connection(
+                    &client_name,
+                    &server_port,
+                    is_tcp.then_some(TcpRole::Connect),
+                    None,
+                )?;
+
+                for scii in connection.socket_connection_ipdu_identifiers() {
+                    let Some(pdu_triggering) = scii.pdu_triggering() else {
+                        continue;
+                    };
+                    let initial_name = pdu_triggering
+                        .pdu()
+                        .and_then(|pdu| pdu.name())
+                        .unwrap_or_else(|| bundle_name.clone());
+                    let name = make_unique_generated_name(&model, &set_path, &initial_name);
+                    let ipdu_identifiers = ipdu_identifier_set
+                        .element()
+                        .get_or_create_sub_element(ElementName::IPduIdentifiers)?;
+                    let socon_ipdu_identifier = SoConIPduIdentifier::new_with_pdu_triggering(
+                        &name,
+                        &ipdu_identifiers,
+                        &pdu_triggering,
+                        scii.header_id(),
+                        scii.timeout(),
+                        scii.collection_trigger(),
+                    )?;
+                    server_ssc.add_ipdu_identifier(&socon_ipdu_identifier)?;
+                    client_ssc.add_ipdu_identifier(&socon_ipdu_identifier)?;
+                }
+
+                static_connections.push(server_ssc);
+                static_connections.push(client_ssc);
+            }
+
+            // the PduTriggerings are now referenced by the SoConIPduIdentifiers, so they must not be removed
+            AbstractionElement::remove(bundle, false)?;
+        }
+
+        if let Some(soad_config) = self.element().get_sub_element(ElementName::SoAdConfig) {
+            let _ = soad_config.remove_sub_element_kind(ElementName::ConnectionBundles);
+        }
+
+        Ok(static_connections)
+    }
+
     /// create a pair of static socket connections
     ///
     /// Static socket connections are usually created as a pair, one on each socket involved on the connection.
@@ -506,7 +755,9 @@ impl EthernetPhysicalChannel {
     /// #    address: Some("192.168.0.1".to_string()),
     /// #    address_source: Some(IPv4AddressSource::Fixed),
     /// #    default_gateway: None,
-    /// #    network_mask: None
+    /// #    network_mask: None,
+    /// #    dns_servers: vec![],
+    /// #    ttl: None
     /// # }, None)?;
     /// # let server_socket = channel.create_socket_address("ServerSocket", &endpoint, &TpConfig::TcpTp {
     /// #    port_number: Some(1234),
@@ -572,7 +823,9 @@ impl EthernetPhysicalChannel {
     ///    address: Some("192.168.0.1".to_string()),
     ///    address_source: Some(IPv4AddressSource::Fixed),
     ///    default_gateway: None,
-    ///    network_mask: None
+    ///    network_mask: None,
+    ///    dns_servers: vec![],
+    ///    ttl: None
     /// }, None)?;
     /// let unicast_socket = channel.create_socket_address("UnicastSocket", &unicast_endpoint, &TpConfig::UdpTp {
     ///    port_number: Some(30490),
@@ -582,7 +835,9 @@ impl EthernetPhysicalChannel {
     ///    address: Some("239.0.0.1".to_string()),
     ///    address_source: Some(IPv4AddressSource::Fixed),
     ///    default_gateway: None,
-    ///    network_mask: None
+    ///    network_mask: None,
+    ///    dns_servers: vec![],
+    ///    ttl: None
     /// }, None)?;
     /// let multicast_rx_socket = channel.create_socket_address("MulticastSocket", &multicast_rx_endpoint, &TpConfig::UdpTp {
     ///    port_number: Some(30490),
@@ -592,7 +847,9 @@ impl EthernetPhysicalChannel {
     ///    address: Some("ANY".to_string()),
     ///    address_source: None,
     ///    default_gateway: None,
-    ///    network_mask: None
+    ///    network_mask: None,
+    ///    dns_servers: vec![],
+    ///    ttl: None
     /// }, None)?;
     /// let remote_socket = channel.create_socket_address("RemoteSocket", &remote_endpoint, &TpConfig::UdpTp {
     ///   port_number: Some(0),
@@ -949,6 +1206,275 @@ impl EthernetPhysicalChannel {
         Ok(())
     }
 
+    /// configure the per-service SOME/IP service discovery (SD) settings of an ECU connected to this channel
+    ///
+    /// This is the follow-up to [`EthernetPhysicalChannel::configure_service_discovery_for_ecu`], which only configures the
+    /// SD transport. This function handles all `ProvidedServiceInstance`s and `ConsumedServiceInstance`s whose local unicast
+    /// address is a socket of the ECU on this channel:
+    ///  - each `ProvidedServiceInstance` gets a `SomeipSdServerServiceInstanceConfig`, and each of its `EventHandler`s gets a
+    ///    `SomeipSdServerEventGroupTimingConfig`
+    ///  - each `ConsumedServiceInstance` gets a `SomeipSdClientServiceInstanceConfig`, and each of its `ConsumedEventGroup`s gets a
+    ///    `SomeipSdClientEventGroupTimingConfig`
+    ///  - each `EventHandler` and `ConsumedEventGroup` gets a `PduActivationRoutingGroup` that references the `SoConIPduIdentifier`s
+    ///    of the static socket connections of the service's local unicast sockets whose PDUs belong to its event group.
+    ///    The PDUs of each event group are taken from `service_config.event_group_pdus`. No routing group is created
+    ///    if none of the event group's PDUs is transmitted on the service's sockets.
+    ///
+    /// Settings that already exist are not modified, so the function can be called again after new services were added.
+    /// The configuration elements are created in the package given in `service_config`.
+    ///
+    /// # Example
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let ecu_instance = system.create_ecu_instance("Ecu", &package)?;
+    /// # let controller = ecu_instance.create_ethernet_communication_controller("EthCtrl", None)?;
+    /// # let cluster = system.create_ethernet_cluster("Cluster", &package)?;
+    /// # let channel = cluster.create_physical_channel("Channel", None)?;
+    /// # controller.connect_physical_channel("connection", &channel)?;
+    /// # let endpoint = channel.create_network_endpoint("Endpoint", NetworkEndpointAddress::IPv4 {
+    /// #    address: Some("192.168.0.1".to_string()),
+    /// #    address_source: Some(IPv4AddressSource::Fixed),
+    /// #    default_gateway: None,
+    /// #    network_mask: None,
+    /// #    dns_servers: vec![],
+    /// #    ttl: None
+    /// # }, None)?;
+    /// # let service_socket = channel.create_socket_address("ServiceSocket", &endpoint, &TpConfig::UdpTp {
+    /// #    port_number: Some(30500),
+    /// #    port_dynamically_assigned: None
+    /// # }, SocketAddressType::Unicast(Some(ecu_instance.clone())))?;
+    /// let si_set = system.create_service_instance_collection_set("ServiceInstances", &package)?;
+    /// let psi = si_set.create_provided_service_instance("Service", 1, 1, 1, 0)?;
+    /// psi.set_local_unicast_address(&service_socket)?;
+    /// psi.create_event_handler("EventHandler", 1)?;
+    ///
+    /// let service_config = ServiceDiscoveryServiceConfig {
+    ///     package: &package,
+    ///     name_prefix: None,
+    ///     service_offer_time_to_live: 3,
+    ///     request_response_delay: RequestResponseDelay { min_value: 0.01, max_value: 0.05 },
+    ///     subscribe_time_to_live: 3,
+    ///     event_group_pdus: &[],
+    /// };
+    /// channel.configure_service_discovery_services_for_ecu(&ecu_instance, &service_config)?;
+    /// assert!(psi.sd_server_instance_config().is_some());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The ECU is not connected to this channel
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn configure_service_discovery_services_for_ecu(
+        &self,
+        ecu: &EcuInstance,
+        service_config: &ServiceDiscoveryServiceConfig,
+    ) -> Result<(), AutosarAbstractionError> {
+        if self.ecu_connector(ecu).is_none() {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The ECU must be connected to the channel".to_string(),
+            ));
+        };
+
+        let model = self.element().model()?;
+        let package_path = service_config.package.element().path()?;
+        let name_prefix = service_config.name_prefix.unwrap_or("");
+
+        let (provided_services, consumed_services) = self.ecu_service_instances(ecu)?;
+
+        for psi in provided_services {
+            let psi_name = psi.name().unwrap_or("unnamed".to_string());
+            if psi.sd_server_instance_config().is_none() {
+                let name =
+                    make_unique_generated_name(&model, &package_path, &format!("{name_prefix}SdServer_{psi_name}"));
+                let config = service_config.package.create_someip_sd_server_service_instance_config(
+                    &name,
+                    service_config.service_offer_time_to_live,
+                )?;
+                config.set_request_response_delay(&service_config.request_response_delay)?;
+                psi.set_sd_server_instance_config(&config)?;
+            }
+
+            let ipdu_identifiers = self.service_ipdu_identifiers(psi.local_unicast_addresses());
+            let mut eg_timing_config = None;
+            for event_handler in psi.event_handlers() {
+                if event_handler.sd_server_event_group_timing_config().is_none() {
+                    // all event handlers of the service share one timing config
+                    if eg_timing_config.is_none() {
+                        let name = make_unique_generated_name(
+                            &model,
+                            &package_path,
+                            &format!("{name_prefix}SdServerEgTiming_{psi_name}"),
+                        );
+                        eg_timing_config = Some(
+                            service_config
+                                .package
+                                .create_someip_sd_server_event_group_timing_config(
+                                    &name,
+                                    &service_config.request_response_delay,
+                                )?,
+                        );
+                    }
+                    if let Some(config) = &eg_timing_config {
+                        event_handler.set_sd_server_event_group_timing_config(config)?;
+                    }
+                }
+
+                let eg_ipdu_identifiers = event_group_ipdu_identifiers(
+                    &ipdu_identifiers,
+                    event_handler.event_group_identifier(),
+                    service_config.event_group_pdus,
+                );
+                if event_handler.pdu_activation_routing_groups().next().is_none() && !eg_ipdu_identifiers.is_empty() {
+                    let eh_name = event_handler.name().unwrap_or("unnamed".to_string());
+                    let routing_group = event_handler.create_pdu_activation_routing_group(
+                        &format!("{name_prefix}{eh_name}_RoutingGroup"),
+                        EventGroupControlType::ActivationAndTriggerUnicast,
+                    )?;
+                    add_routing_group_identifiers(&routing_group, &eg_ipdu_identifiers)?;
+                }
+            }
+        }
+
+        for csi in consumed_services {
+            let csi_name = csi.name().unwrap_or("unnamed".to_string());
+            if csi.sd_client_instance_config().is_none() {
+                let name =
+                    make_unique_generated_name(&model, &package_path, &format!("{name_prefix}SdClient_{csi_name}"));
+                let config = service_config
+                    .package
+                    .create_someip_sd_client_service_instance_config(&name)?;
+                csi.set_sd_client_instance_config(&config)?;
+            }
+
+            let ipdu_identifiers = self.service_ipdu_identifiers(csi.local_unicast_addresses());
+            let mut eg_timing_config = None;
+            for consumed_event_group in csi.consumed_event_groups() {
+                if consumed_event_group.sd_client_timer_config().is_none() {
+                    // all consumed event groups of the service share one timing config
+                    if eg_timing_config.is_none() {
+                        let name = make_unique_generated_name(
+                            &model,
+                            &package_path,
+                            &format!("{name_prefix}SdClientEgTiming_{csi_name}"),
+                        );
+                        let config = service_config
+                            .package
+                            .create_someip_sd_client_event_group_timing_config(
+                                &name,
+                                service_config.subscribe_time_to_live,
+                            )?;
+                        config.set_request_response_delay(&service_config.request_response_delay)?;
+                        eg_timing_config = Some(config);
+                    }
+                    if let Some(config) = &eg_timing_config {
+                        consumed_event_group.set_sd_client_timer_config(config)?;
+                    }
+                }
+
+                let eg_ipdu_identifiers = event_group_ipdu_identifiers(
+                    &ipdu_identifiers,
+                    consumed_event_group.event_group_identifier(),
+                    service_config.event_group_pdus,
+                );
+                if consumed_event_group.pdu_activation_routing_groups().next().is_none()
+                    && !eg_ipdu_identifiers.is_empty()
+                {
+                    let ceg_name = consumed_event_group.name().unwrap_or("unnamed".to_string());
+                    let routing_group = consumed_event_group.create_pdu_activation_routing_group(
+                        &format!("{name_prefix}{ceg_name}_RoutingGroup"),
+                        EventGroupControlType::ActivationAndTriggerUnicast,
+                    )?;
+                    add_routing_group_identifiers(&routing_group, &eg_ipdu_identifiers)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // find all service instances whose local unicast address is a socket of the ECU on this channel
+    fn ecu_service_instances(
+        &self,
+        ecu: &EcuInstance,
+    ) -> Result<(Vec<ProvidedServiceInstance>, Vec<ConsumedServiceInstance>), AutosarAbstractionError> {
+        let model = self.element().model()?;
+        let mut provided_services = Vec::new();
+        let mut consumed_services = Vec::new();
+
+        for socket in self.socket_addresses() {
+            if socket.socket_address_type() != Some(SocketAddressType::Unicast(Some(ecu.clone()))) {
+                continue;
+            }
+            let Some(application_endpoint) = socket.element().get_sub_element(ElementName::ApplicationEndpoint) else {
+                continue;
+            };
+            let referrers = model
+                .get_references_to(&application_endpoint.path()?)
+                .iter()
+                .filter_map(WeakElement::upgrade)
+                .filter_map(|ref_elem| ref_elem.named_parent().ok().flatten());
+            for referrer in referrers {
+                match referrer.element_name() {
+                    ElementName::ProvidedServiceInstance => {
+                        if let Ok(psi) = ProvidedServiceInstance::try_from(referrer)
+                            && !provided_services.contains(&psi)
+                        {
+                            provided_services.push(psi);
+                        }
+                    }
+                    ElementName::ConsumedServiceInstance => {
+                        if let Ok(csi) = ConsumedServiceInstance::try_from(referrer)
+                            && !consumed_services.contains(&csi)
+                        {
+                            consumed_services.push(csi);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok((provided_services, consumed_services))
+    }
+
+    // get the non-SD SoConIPduIdentifiers of the static socket connections of the given local unicast addresses
+    // the flag is true if the socket uses TCP
+    fn service_ipdu_identifiers(
+        &self,
+        addresses: impl Iterator<Item = LocalUnicastAddress>,
+    ) -> Vec<(SoConIPduIdentifier, bool)> {
+        let mut ipdu_identifiers = Vec::new();
+        for address in addresses {
+            let (socket, is_tcp) = match address {
+                LocalUnicastAddress::Udp(socket) => (socket, false),
+                LocalUnicastAddress::Tcp(socket) => (socket, true),
+            };
+            if socket.physical_channel().ok().as_ref() != Some(self) {
+                continue;
+            }
+            for ipdu_identifier in socket
+                .static_socket_connections()
+                .flat_map(|ssc| ssc.ipdu_identifiers())
+                .filter(|ipdu_identifier| ipdu_identifier.header_id() != Some(SoConIPduIdentifier::SD_HEADER_ID))
+            {
+                if !ipdu_identifiers
+                    .iter()
+                    .any(|(existing, _)| existing == &ipdu_identifier)
+                {
+                    ipdu_identifiers.push((ipdu_identifier, is_tcp));
+                }
+            }
+        }
+        ipdu_identifiers
+    }
+
     /// check if the channel contains any `SocketConnectionBundles` (old) or `SocketConnections` (very old)
     #[must_use]
     pub fn has_socket_connections(&self) -> bool {
@@ -966,6 +1492,209 @@ impl EthernetPhysicalChannel {
         }
         false
     }
+
+    /// check the socket configuration of this channel for common errors
+    ///
+    /// The following problems are detected:
+    /// - a header id is used more than once in a socket connection, either in a `SocketConnection` (old)
+    ///   or in a `StaticSocketConnection` (new)
+    /// - two socket addresses on the same network endpoint use the same transport protocol and port number
+    /// - a socket address uses a network endpoint of a different channel
+    /// - a socket connection refers to a socket address of a different channel
+    ///
+    /// An empty list is returned if no problems were found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_ethernet_cluster("Cluster", &package)?;
+    /// # let channel = cluster.create_physical_channel("Channel", None)?;
+    /// # let endpoint = channel.create_network_endpoint("Address", NetworkEndpointAddress::IPv4 {
+    /// #    address: Some("192.168.0.1".to_string()),
+    /// #    address_source: Some(IPv4AddressSource::Fixed),
+    /// #    default_gateway: None,
+    /// #    network_mask: None,
+    /// #    dns_servers: vec![],
+    /// #    ttl: None
+    /// # }, None)?;
+    /// let tp_config = TpConfig::UdpTp { port_number: Some(1234), port_dynamically_assigned: None };
+    /// let socket_1 = channel.create_socket_address("Socket1", &endpoint, &tp_config, SocketAddressType::Unicast(None))?;
+    /// let socket_2 = channel.create_socket_address("Socket2", &endpoint, &tp_config, SocketAddressType::Unicast(None))?;
+    /// let issues = channel.check_socket_connections();
+    /// assert_eq!(issues, vec![SocketConnectionIssue::OverlappingPorts(socket_1, socket_2)]);
+    /// # Ok(())}
+    /// ```
+    #[must_use]
+    pub fn check_socket_connections(&self) -> Vec<SocketConnectionIssue> {
+        let mut issues = Vec::new();
+        let socket_addresses: Vec<SocketAddress> = self.socket_addresses().collect();
+
+        // socket addresses: network endpoints of other channels, and overlapping ports
+        for (idx, socket_address) in socket_addresses.iter().enumerate() {
+            let Some(network_endpoint) = socket_address.network_endpoint() else {
+                continue;
+            };
+            if !self.contains_element(network_endpoint.element()) {
+                issues.push(SocketConnectionIssue::ForeignNetworkEndpoint(
+                    socket_address.clone(),
+                    network_endpoint.clone(),
+                ));
+            }
+            let Some(port) = socket_port(socket_address) else {
+                continue;
+            };
+            for other in &socket_addresses[idx + 1..] {
+                if other.network_endpoint().as_ref() == Some(&network_endpoint) && socket_port(other) == Some(port) {
+                    issues.push(SocketConnectionIssue::OverlappingPorts(
+                        socket_address.clone(),
+                        other.clone(),
+                    ));
+                }
+            }
+        }
+
+        // old style: bundled connections and "very old" connections without a bundle
+        for bundle in self.socket_connection_bundles() {
+            if let Some(server_port) = bundle.server_port()
+                && !self.contains_element(server_port.element())
+            {
+                issues.push(SocketConnectionIssue::ForeignServerPort(bundle.clone(), server_port));
+            }
+        }
+        let connections = self
+            .socket_connection_bundles()
+            .flat_map(|bundle| bundle.bundled_connections())
+            .chain(self.socket_connections());
+        for connection in connections {
+            if let Some(client_port) = connection.client_port()
+                && !self.contains_element(client_port.element())
+            {
+                issues.push(SocketConnectionIssue::ForeignClientPort(
+                    connection.clone(),
+                    client_port,
+                ));
+            }
+            let header_ids = connection
+                .socket_connection_ipdu_identifiers()
+                .filter_map(|identifier| identifier.header_id());
+            for header_id in duplicate_values(header_ids) {
+                issues.push(SocketConnectionIssue::DuplicateHeaderId(connection.clone(), header_id));
+            }
+        }
+
+        // new style: static socket connections
+        for socket_address in &socket_addresses {
+            for connection in socket_address.static_socket_connections() {
+                if let Some(remote_socket) = connection.remote_socket()
+                    && !self.contains_element(remote_socket.element())
+                {
+                    issues.push(SocketConnectionIssue::ForeignRemoteSocket(
+                        connection.clone(),
+                        remote_socket,
+                    ));
+                }
+                let header_ids = connection
+                    .ipdu_identifiers()
+                    .filter_map(|identifier| identifier.header_id());
+                for header_id in duplicate_values(header_ids) {
+                    issues.push(SocketConnectionIssue::DuplicateStaticHeaderId(
+                        connection.clone(),
+                        header_id,
+                    ));
+                }
+            }
+        }
+
+        issues
+    }
+
+    // check if an element is located inside of this channel
+    fn contains_element(&self, element: &Element) -> bool {
+        element
+            .named_parent()
+            .ok()
+            .flatten()
+            .is_some_and(|parent| &parent == self.element())
+    }
+}
+
+// the transport protocol and port number of a socket address; dynamically assigned ports can't overlap
+fn socket_port(socket_address: &SocketAddress) -> Option<(bool, u16)> {
+    match socket_address.tp_config()? {
+        TpConfig::TcpTp {
+            port_number: Some(port_number),
+            port_dynamically_assigned: None | Some(false),
+        } => Some((true, port_number)),
+        TpConfig::UdpTp {
+            port_number: Some(port_number),
+            port_dynamically_assigned: None | Some(false),
+        } => Some((false, port_number)),
+        _ => None,
+    }
+}
+
+// find all values that occur more than once, in the order of their second occurrence
+fn duplicate_values(values: impl Iterator<Item = u64>) -> Vec<u64> {
+    let mut seen = Vec::new();
+    let mut duplicates = Vec::new();
+    for value in values {
+        if seen.contains(&value) {
+            if !duplicates.contains(&value) {
+                duplicates.push(value);
+            }
+        } else {
+            seen.push(value);
+        }
+    }
+    duplicates
+}
+
+// select the SoConIPduIdentifiers whose PDU belongs to the event group with the given identifier
+fn event_group_ipdu_identifiers(
+    ipdu_identifiers: &[(SoConIPduIdentifier, bool)],
+    event_group_identifier: Option<u32>,
+    event_group_pdus: &[(u32, Vec<Pdu>)],
+) -> Vec<(SoConIPduIdentifier, bool)> {
+    let Some(event_group_identifier) = event_group_identifier else {
+        return Vec::new();
+    };
+    let pdus: Vec<&Pdu> = event_group_pdus
+        .iter()
+        .filter(|(id, _)| *id == event_group_identifier)
+        .flat_map(|(_, pdus)| pdus)
+        .collect();
+    ipdu_identifiers
+        .iter()
+        .filter(|(ipdu_identifier, _)| {
+            ipdu_identifier
+                .pdu_triggering()
+                .and_then(|pt| pt.pdu())
+                .is_some_and(|pdu| pdus.contains(&&pdu))
+        })
+        .cloned()
+        .collect()
+}
+
+// add the given SoConIPduIdentifiers to a PduActivationRoutingGroup, as TCP or UDP references
+fn add_routing_group_identifiers(
+    routing_group: &PduActivationRoutingGroup,
+    ipdu_identifiers: &[(SoConIPduIdentifier, bool)],
+) -> Result<(), AutosarAbstractionError> {
+    for (ipdu_identifier, is_tcp) in ipdu_identifiers {
+        if *is_tcp {
+            routing_group.add_ipdu_identifier_tcp(ipdu_identifier)?;
+        } else {
+            routing_group.add_ipdu_identifier_udp(ipdu_identifier)?;
+        }
+    }
+    Ok(())
 }
 
 impl From<EthernetPhysicalChannel> for PhysicalChannel {
@@ -980,6 +1709,28 @@ impl AbstractPhysicalChannel for EthernetPhysicalChannel {
 
 //##################################################################
 
+/// A problem in the socket configuration of an [`EthernetPhysicalChannel`],
+/// as found by [`EthernetPhysicalChannel::check_socket_connections`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SocketConnectionIssue {
+    /// The header id is used more than once in the `SocketConnection`
+    DuplicateHeaderId(SocketConnection, u64),
+    /// The header id is used more than once in the `StaticSocketConnection`
+    DuplicateStaticHeaderId(StaticSocketConnection, u64),
+    /// The two socket addresses use the same transport protocol and port on the same network endpoint
+    OverlappingPorts(SocketAddress, SocketAddress),
+    /// The socket address uses a network endpoint of a different channel
+    ForeignNetworkEndpoint(SocketAddress, NetworkEndpoint),
+    /// The server port of the `SocketConnectionBundle` is a socket address of a different channel
+    ForeignServerPort(SocketConnectionBundle, SocketAddress),
+    /// The client port of the `SocketConnection` is a socket address of a different channel
+    ForeignClientPort(SocketConnection, SocketAddress),
+    /// The remote socket of the `StaticSocketConnection` is a socket address of a different channel
+    ForeignRemoteSocket(StaticSocketConnection, SocketAddress),
+}
+
+//##################################################################
+
 /// A `CommonServiceDiscoveryConfig` contains common configuration settings for `System::configure_service_discovery_for_ecu`.
 ///
 /// This struct contains ECU-independent settings that should be re-used for all ECUs that are configured for SD.
@@ -1001,6 +1752,29 @@ pub struct CommonServiceDiscoveryConfig<'a> {
 
 //##################################################################
 
+/// A `ServiceDiscoveryServiceConfig` contains the settings for `EthernetPhysicalChannel::configure_service_discovery_services_for_ecu`.
+///
+/// These settings are applied to all services of an ECU that don't have an SD configuration yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceDiscoveryServiceConfig<'a> {
+    /// the package in which the SD configuration elements are created
+    pub package: &'a ArPackage,
+    /// an optional prefix for the names of the created elements
+    pub name_prefix: Option<&'a str>,
+    /// the time to live of the service offers in seconds
+    pub service_offer_time_to_live: u32,
+    /// the request response delay used by the server and client configurations
+    pub request_response_delay: RequestResponseDelay,
+    /// the time to live of event group subscriptions in seconds
+    pub subscribe_time_to_live: u32,
+    /// the PDUs of each event group, identified by the event group identifier
+    ///
+    /// The `PduActivationRoutingGroup` of an event group only references the `SoConIPduIdentifier`s of these PDUs.
+    pub event_group_pdus: &'a [(u32, Vec<Pdu>)],
+}
+
+//##################################################################
+
 /// A static socket connection is a connection between two sockets.
 ///
 /// This is the new way to establish a connection. It was introduced in Autosar 4.5.0 (`AUTOSAR_00048`).
@@ -1029,6 +1803,26 @@ impl StaticSocketConnection {
         Ok(ssc)
     }
 
+    /// remove this `StaticSocketConnection` from the model
+    ///
+    /// If `deep` is true, the `SoConIPduIdentifier`s of the connection are removed as well,
+    /// unless they are still used by other connections
+    pub fn remove(self, deep: bool) -> Result<(), AutosarAbstractionError> {
+        let ipdu_identifiers: Vec<_> = self.ipdu_identifiers().collect();
+
+        AbstractionElement::remove(self, deep)?;
+
+        if deep {
+            for ipdu_identifier in ipdu_identifiers {
+                if get_reference_parents(ipdu_identifier.element())?.is_empty() {
+                    ipdu_identifier.remove(deep)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// get the socket address containing this static socket connection
     pub fn socket_address(&self) -> Result<SocketAddress, AutosarAbstractionError> {
         let sa = self.element().named_parent()?.unwrap();
@@ -1216,6 +2010,32 @@ impl SoConIPduIdentifier {
         Ok(scii)
     }
 
+    // create a new SoConIPduIdentifier that uses an existing PduTriggering (internal)
+    pub(crate) fn new_with_pdu_triggering(
+        name: &str,
+        parent: &Element,
+        pdu_triggering: &PduTriggering,
+        header_id: Option<u64>,
+        timeout: Option<f64>,
+        collection_trigger: Option<PduCollectionTrigger>,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let scii = Self(parent.create_named_sub_element(ElementName::SoConIPduIdentifier, name)?);
+        scii.element()
+            .create_sub_element(ElementName::PduTriggeringRef)?
+            .set_reference_target(pdu_triggering.element())?;
+
+        if let Some(header_id) = header_id {
+            scii.set_header_id(header_id)?;
+        }
+        if let Some(timeout) = timeout {
+            scii.set_timeout(timeout)?;
+        }
+        if let Some(collection_trigger) = collection_trigger {
+            scii.set_collection_trigger(collection_trigger)?;
+        }
+        Ok(scii)
+    }
+
     /// remove this `SoConIPduIdentifier`
     pub fn remove(self, deep: bool) -> Result<(), AutosarAbstractionError> {
         let opt_pdu_triggering = self.pdu_triggering();
@@ -1445,6 +2265,8 @@ mod test {
                     address_source: None,
                     default_gateway: None,
                     network_mask: None,
+                    dns_servers: vec![],
+                    ttl: None,
                 },
                 None,
             )
@@ -1462,6 +2284,8 @@ mod test {
                 address_source: None,
                 default_gateway: None,
                 network_mask: None,
+                dns_servers: vec![],
+                ttl: None,
             },
             Some(&ecu),
         );
@@ -1483,6 +2307,8 @@ mod test {
                     address_source: None,
                     default_gateway: None,
                     network_mask: None,
+                    dns_servers: vec![],
+                    ttl: None,
                 },
                 Some(&ecu),
             )
@@ -1683,6 +2509,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: Some("192.168.0.200".to_string()),
             network_mask: Some("255.255.255.0".to_string()),
+            dns_servers: vec![],
+            ttl: None,
         };
         let network_endpoint = channel
             .create_network_endpoint("local_endpoint", network_address, None)
@@ -1706,6 +2534,8 @@ mod test {
                     address_source: Some(IPv4AddressSource::Fixed),
                     default_gateway: None,
                     network_mask: None,
+                    dns_servers: vec![],
+                    ttl: None,
                 },
                 None,
             )
@@ -1729,6 +2559,8 @@ mod test {
                     address_source: None,
                     default_gateway: None,
                     network_mask: None,
+                    dns_servers: vec![],
+                    ttl: None,
                 },
                 None,
             )
@@ -1763,6 +2595,243 @@ mod test {
         )
     }
 
+    #[test]
+    fn sd_service_configuration() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let sd_pkg = model.get_or_create_package("/test/sd").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let cluster = system.create_ethernet_cluster("EthCluster", &pkg).unwrap();
+        let channel = cluster.create_physical_channel("Channel", None).unwrap();
+
+        let ecu = system.create_ecu_instance("ECU", &pkg).unwrap();
+        let controller = ecu
+            .create_ethernet_communication_controller("EthController", None)
+            .unwrap();
+        controller.connect_physical_channel("connection", &channel).unwrap();
+        let other_ecu = system.create_ecu_instance("OtherECU", &pkg).unwrap();
+
+        let network_address = NetworkEndpointAddress::IPv4 {
+            address: Some("192.168.0.1".to_string()),
+            address_source: Some(IPv4AddressSource::Fixed),
+            default_gateway: None,
+            network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
+        };
+        let network_endpoint = channel
+            .create_network_endpoint("local_endpoint", network_address, None)
+            .unwrap();
+        let udp_port = TpConfig::UdpTp {
+            port_number: Some(30500),
+            port_dynamically_assigned: None,
+        };
+        let service_socket = channel
+            .create_socket_address(
+                "ServiceSocket",
+                &network_endpoint,
+                &udp_port,
+                SocketAddressType::Unicast(Some(ecu.clone())),
+            )
+            .unwrap();
+        let remote_socket = channel
+            .create_socket_address(
+                "RemoteSocket",
+                &network_endpoint,
+                &udp_port,
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+
+        let ipdu_identifier_set = system
+            .create_socket_connection_ipdu_identifier_set("IpduIdentifierSet", &pkg)
+            .unwrap();
+        let pdu = system
+            .create_general_purpose_pdu("EventPdu", &pkg, 8, GeneralPurposePduCategory::Sd)
+            .unwrap();
+        let ipdu_identifier = ipdu_identifier_set
+            .create_socon_ipdu_identifier("EventPdu", &pdu, &channel, Some(0x1234), None, None)
+            .unwrap();
+        let ssc = service_socket
+            .create_static_socket_connection("ServiceConnection", &remote_socket, None, None)
+            .unwrap();
+        ssc.add_ipdu_identifier(&ipdu_identifier).unwrap();
+
+        let si_set = system
+            .create_service_instance_collection_set("ServiceInstances", &pkg)
+            .unwrap();
+        let psi = si_set
+            .create_provided_service_instance("ProvidedService", 1, 1, 1, 0)
+            .unwrap();
+        psi.set_local_unicast_address(&service_socket).unwrap();
+        let event_handler = psi.create_event_handler("EventHandler", 1).unwrap();
+        let csi = si_set
+            .create_consumed_service_instance("ConsumedService", 2, 1, 1, "0")
+            .unwrap();
+        csi.set_local_unicast_address(&service_socket).unwrap();
+        let consumed_event_group = csi.create_consumed_event_group("ConsumedEventGroup", 1).unwrap();
+
+        let event_group_pdus = [(1, vec![Pdu::from(pdu.clone())])];
+        let service_config = ServiceDiscoveryServiceConfig {
+            package: &sd_pkg,
+            name_prefix: Some("Test_"),
+            service_offer_time_to_live: 3,
+            request_response_delay: RequestResponseDelay {
+                min_value: 0.01,
+                max_value: 0.05,
+            },
+            subscribe_time_to_live: 5,
+            event_group_pdus: &event_group_pdus,
+        };
+
+        // the ECU must be connected to the channel
+        assert!(
+            channel
+                .configure_service_discovery_services_for_ecu(&other_ecu, &service_config)
+                .is_err()
+        );
+
+        channel
+            .configure_service_discovery_services_for_ecu(&ecu, &service_config)
+            .unwrap();
+
+        let server_config = psi.sd_server_instance_config().unwrap();
+        assert_eq!(server_config.name().unwrap(), "Test_SdServer_ProvidedService");
+        assert_eq!(server_config.service_offer_time_to_live(), Some(3));
+        assert_eq!(
+            server_config.request_response_delay(),
+            Some(service_config.request_response_delay.clone())
+        );
+        assert!(event_handler.sd_server_event_group_timing_config().is_some());
+        let routing_group = event_handler.pdu_activation_routing_groups().next().unwrap();
+        assert_eq!(
+            routing_group.event_group_control_type(),
+            Some(EventGroupControlType::ActivationAndTriggerUnicast)
+        );
+        assert_eq!(
+            routing_group.ipdu_identifiers_udp().next(),
+            Some(ipdu_identifier.clone())
+        );
+        assert_eq!(routing_group.ipdu_identifiers_tcp().count(), 0);
+
+        assert!(csi.sd_client_instance_config().is_some());
+        let client_timer_config = consumed_event_group.sd_client_timer_config().unwrap();
+        assert_eq!(client_timer_config.time_to_live(), Some(5));
+        assert_eq!(consumed_event_group.pdu_activation_routing_groups().count(), 1);
+
+        // calling the function again does not create any new elements
+        let element_count = sd_pkg.element().elements_dfs().count();
+        channel
+            .configure_service_discovery_services_for_ecu(&ecu, &service_config)
+            .unwrap();
+        assert_eq!(sd_pkg.element().elements_dfs().count(), element_count);
+        assert_eq!(event_handler.pdu_activation_routing_groups().count(), 1);
+    }
+
+    #[test]
+    fn sd_service_routing_groups() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let cluster = system.create_ethernet_cluster("EthCluster", &pkg).unwrap();
+        let channel = cluster.create_physical_channel("Channel", None).unwrap();
+        let ecu = system.create_ecu_instance("ECU", &pkg).unwrap();
+        let controller = ecu
+            .create_ethernet_communication_controller("EthController", None)
+            .unwrap();
+        controller.connect_physical_channel("connection", &channel).unwrap();
+
+        let network_address = NetworkEndpointAddress::IPv4 {
+            address: Some("192.168.0.1".to_string()),
+            address_source: Some(IPv4AddressSource::Fixed),
+            default_gateway: None,
+            network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
+        };
+        let network_endpoint = channel
+            .create_network_endpoint("local_endpoint", network_address, None)
+            .unwrap();
+        let udp_port = TpConfig::UdpTp {
+            port_number: Some(30500),
+            port_dynamically_assigned: None,
+        };
+        let service_socket = channel
+            .create_socket_address(
+                "ServiceSocket",
+                &network_endpoint,
+                &udp_port,
+                SocketAddressType::Unicast(Some(ecu.clone())),
+            )
+            .unwrap();
+        let remote_socket = channel
+            .create_socket_address(
+                "RemoteSocket",
+                &network_endpoint,
+                &udp_port,
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+
+        // two PDUs on the same socket connection, each belonging to a different event group
+        let ipdu_identifier_set = system
+            .create_socket_connection_ipdu_identifier_set("IpduIdentifierSet", &pkg)
+            .unwrap();
+        let pdu1 = system
+            .create_general_purpose_pdu("EventPdu1", &pkg, 8, GeneralPurposePduCategory::Sd)
+            .unwrap();
+        let pdu2 = system
+            .create_general_purpose_pdu("EventPdu2", &pkg, 8, GeneralPurposePduCategory::Sd)
+            .unwrap();
+        let ipdu_identifier1 = ipdu_identifier_set
+            .create_socon_ipdu_identifier("EventPdu1", &pdu1, &channel, Some(0x1001), None, None)
+            .unwrap();
+        let ipdu_identifier2 = ipdu_identifier_set
+            .create_socon_ipdu_identifier("EventPdu2", &pdu2, &channel, Some(0x1002), None, None)
+            .unwrap();
+        let ssc = service_socket
+            .create_static_socket_connection("ServiceConnection", &remote_socket, None, None)
+            .unwrap();
+        ssc.add_ipdu_identifier(&ipdu_identifier1).unwrap();
+        ssc.add_ipdu_identifier(&ipdu_identifier2).unwrap();
+
+        let si_set = system
+            .create_service_instance_collection_set("ServiceInstances", &pkg)
+            .unwrap();
+        let psi = si_set
+            .create_provided_service_instance("ProvidedService", 1, 1, 1, 0)
+            .unwrap();
+        psi.set_local_unicast_address(&service_socket).unwrap();
+        let event_handler1 = psi.create_event_handler("EventHandler1", 1).unwrap();
+        let event_handler2 = psi.create_event_handler("EventHandler2", 2).unwrap();
+        // event group 3 has no PDUs, so it doesn't get a routing group
+        let event_handler3 = psi.create_event_handler("EventHandler3", 3).unwrap();
+
+        let event_group_pdus = [(1, vec![Pdu::from(pdu1)]), (2, vec![Pdu::from(pdu2)])];
+        let service_config = ServiceDiscoveryServiceConfig {
+            package: &pkg,
+            name_prefix: None,
+            service_offer_time_to_live: 3,
+            request_response_delay: RequestResponseDelay {
+                min_value: 0.01,
+                max_value: 0.05,
+            },
+            subscribe_time_to_live: 5,
+            event_group_pdus: &event_group_pdus,
+        };
+        channel
+            .configure_service_discovery_services_for_ecu(&ecu, &service_config)
+            .unwrap();
+
+        let routing_group1 = event_handler1.pdu_activation_routing_groups().next().unwrap();
+        let identifiers1: Vec<_> = routing_group1.ipdu_identifiers_udp().collect();
+        assert_eq!(identifiers1, vec![ipdu_identifier1]);
+        let routing_group2 = event_handler2.pdu_activation_routing_groups().next().unwrap();
+        let identifiers2: Vec<_> = routing_group2.ipdu_identifiers_udp().collect();
+        assert_eq!(identifiers2, vec![ipdu_identifier2]);
+        assert_eq!(event_handler3.pdu_activation_routing_groups().count(), 0);
+    }
+
     #[test]
     fn socon_ipdu_identifier() {
         let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
@@ -1841,6 +2910,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: None,
             network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
         };
         let remote_endpoint = channel
             .create_network_endpoint("RemoteAddress", remote_address, None)
@@ -1861,6 +2932,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: None,
             network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
         };
         let local_endpoint = channel
             .create_network_endpoint("LocalAddress", local_address, None)
@@ -1908,6 +2981,148 @@ mod test {
         assert_eq!(ssc.socket_address().unwrap(), local_socket);
     }
 
+    #[test]
+    fn check_socket_connections() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let cluster = system.create_ethernet_cluster("EthCluster", &pkg).unwrap();
+        let channel = cluster.create_physical_channel("Channel", None).unwrap();
+        let other_channel = cluster.create_physical_channel("OtherChannel", None).unwrap();
+
+        let address = |ip: &str| NetworkEndpointAddress::IPv4 {
+            address: Some(ip.to_string()),
+            address_source: Some(IPv4AddressSource::Fixed),
+            default_gateway: None,
+            network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
+        };
+        let udp = |port_number| TpConfig::UdpTp {
+            port_number: Some(port_number),
+            port_dynamically_assigned: None,
+        };
+        let server_endpoint = channel
+            .create_network_endpoint("ServerAddress", address("192.168.0.1"), None)
+            .unwrap();
+        let client_endpoint = channel
+            .create_network_endpoint("ClientAddress", address("192.168.0.2"), None)
+            .unwrap();
+        let foreign_endpoint = other_channel
+            .create_network_endpoint("ForeignAddress", address("192.168.0.3"), None)
+            .unwrap();
+        let server_socket = channel
+            .create_socket_address(
+                "ServerSocket",
+                &server_endpoint,
+                &udp(1234),
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        let client_socket = channel
+            .create_socket_address(
+                "ClientSocket",
+                &client_endpoint,
+                &udp(1234),
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        assert!(channel.check_socket_connections().is_empty());
+
+        // same port on the same network endpoint; TCP and UDP ports don't overlap
+        let overlapping_socket = channel
+            .create_socket_address(
+                "OverlappingSocket",
+                &server_endpoint,
+                &udp(1234),
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        channel
+            .create_socket_address(
+                "TcpSocket",
+                &server_endpoint,
+                &TpConfig::TcpTp {
+                    port_number: Some(1234),
+                    port_dynamically_assigned: None,
+                },
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        // network endpoint of another channel
+        let foreign_socket = channel
+            .create_socket_address(
+                "ForeignSocket",
+                &foreign_endpoint,
+                &udp(5555),
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        assert_eq!(
+            channel.check_socket_connections(),
+            vec![
+                SocketConnectionIssue::OverlappingPorts(server_socket.clone(), overlapping_socket.clone()),
+                SocketConnectionIssue::ForeignNetworkEndpoint(foreign_socket.clone(), foreign_endpoint.clone()),
+            ]
+        );
+        overlapping_socket.remove(false).unwrap();
+        foreign_socket.remove(false).unwrap();
+        assert!(channel.check_socket_connections().is_empty());
+
+        // old style: duplicate header ids in a socket connection
+        let pdu_1 = GeneralPurposePdu::new("Pdu1", &pkg, 0, GeneralPurposePduCategory::Sd).unwrap();
+        let pdu_2 = GeneralPurposePdu::new("Pdu2", &pkg, 0, GeneralPurposePduCategory::Sd).unwrap();
+        let bundle = channel
+            .create_socket_connection_bundle("Bundle", &server_socket)
+            .unwrap();
+        let connection = bundle.create_bundled_connection(&client_socket).unwrap();
+        connection
+            .create_socket_connection_ipdu_identifier(&pdu_1, 0x10, None, None)
+            .unwrap();
+        connection
+            .create_socket_connection_ipdu_identifier(&pdu_2, 0x10, None, None)
+            .unwrap();
+        assert_eq!(
+            channel.check_socket_connections(),
+            vec![SocketConnectionIssue::DuplicateHeaderId(connection.clone(), 0x10)]
+        );
+        bundle.remove(false).unwrap();
+
+        // new style: duplicate header ids in a static socket connection, and a remote socket of another channel
+        let ipdu_identifier_set = system
+            .create_socket_connection_ipdu_identifier_set("IpduIdentifierSet", &pkg)
+            .unwrap();
+        let identifier_1 = ipdu_identifier_set
+            .create_socon_ipdu_identifier("Identifier1", &pdu_1, &channel, Some(0x20), None, None)
+            .unwrap();
+        let identifier_2 = ipdu_identifier_set
+            .create_socon_ipdu_identifier("Identifier2", &pdu_2, &channel, Some(0x20), None, None)
+            .unwrap();
+        let ssc = client_socket
+            .create_static_socket_connection("ssc", &server_socket, None, None)
+            .unwrap();
+        ssc.add_ipdu_identifier(&identifier_1).unwrap();
+        ssc.add_ipdu_identifier(&identifier_2).unwrap();
+        let other_endpoint = other_channel
+            .create_network_endpoint("OtherAddress", address("192.168.0.4"), None)
+            .unwrap();
+        let other_socket = other_channel
+            .create_socket_address(
+                "OtherSocket",
+                &other_endpoint,
+                &udp(1234),
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        let foreign_ssc = server_socket
+            .create_static_socket_connection("foreign_ssc", &other_socket, None, None)
+            .unwrap();
+        let issues = channel.check_socket_connections();
+        assert_eq!(issues.len(), 2);
+        assert!(issues.contains(&SocketConnectionIssue::DuplicateStaticHeaderId(ssc, 0x20)));
+        assert!(issues.contains(&SocketConnectionIssue::ForeignRemoteSocket(foreign_ssc, other_socket)));
+    }
+
     #[test]
     fn remove_channel() {
         let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
@@ -1921,6 +3136,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: None,
             network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
         };
         let remote_endpoint = channel
             .create_network_endpoint("RemoteAddress", remote_address, None)
@@ -1941,6 +3158,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: None,
             network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
         };
         let local_endpoint = channel
             .create_network_endpoint("LocalAddress", local_address, None)
@@ -1978,4 +3197,128 @@ mod test {
         // the isignal ipdu was unused and got removed because deep=true
         assert!(isignal_ipdu.element().parent().is_err());
     }
+
+    #[test]
+    fn migrate_to_static_socket_connections() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+        let package = model.get_or_create_package("/pkg1").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_ethernet_cluster("Cluster", &package).unwrap();
+        let channel = cluster.create_physical_channel("Channel", None).unwrap();
+        let endpoint = channel
+            .create_network_endpoint(
+                "Address",
+                NetworkEndpointAddress::IPv4 {
+                    address: Some("192.168.0.1".to_string()),
+                    address_source: Some(IPv4AddressSource::Fixed),
+                    default_gateway: None,
+                    network_mask: None,
+                    dns_servers: vec![],
+                    ttl: None,
+                },
+                None,
+            )
+            .unwrap();
+        let tp_config = TpConfig::TcpTp {
+            port_number: Some(1234),
+            port_dynamically_assigned: None,
+        };
+        let server_socket = channel
+            .create_socket_address("ServerSocket", &endpoint, &tp_config, SocketAddressType::Unicast(None))
+            .unwrap();
+        let client_socket = channel
+            .create_socket_address("ClientSocket", &endpoint, &tp_config, SocketAddressType::Unicast(None))
+            .unwrap();
+        let bundle = channel
+            .create_socket_connection_bundle("Bundle", &server_socket)
+            .unwrap();
+        let connection = bundle.create_bundled_connection(&client_socket).unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        let (_, pdu_triggering) = connection
+            .create_socket_connection_ipdu_identifier(&pdu, 0x1234, Some(0.5), Some(PduCollectionTrigger::Always))
+            .unwrap();
+
+        let ipdu_identifier_set = system
+            .create_socket_connection_ipdu_identifier_set("IpduIdentifierSet", &package)
+            .unwrap();
+        let static_connections = channel
+            .migrate_to_static_socket_connections(&ipdu_identifier_set)
+            .unwrap();
+        assert_eq!(static_connections.len(), 2);
+        assert_eq!(channel.socket_connection_bundles().count(), 0);
+        assert!(!channel.has_socket_connections());
+
+        let server_ssc = server_socket.static_socket_connections().next().unwrap();
+        assert_eq!(server_ssc.remote_socket(), Some(client_socket.clone()));
+        assert_eq!(server_ssc.tcp_role(), Some(TcpRole::Listen));
+        let client_ssc = client_socket.static_socket_connections().next().unwrap();
+        assert_eq!(client_ssc.remote_socket(), Some(server_socket));
+        assert_eq!(client_ssc.tcp_role(), Some(TcpRole::Connect));
+
+        let socon_ipdu_identifier = ipdu_identifier_set.socon_ipdu_identifiers().next().unwrap();
+        assert_eq!(socon_ipdu_identifier.pdu_triggering(), Some(pdu_triggering));
+        assert_eq!(socon_ipdu_identifier.header_id(), Some(0x1234));
+        assert_eq!(socon_ipdu_identifier.timeout(), Some(0.5));
+        assert_eq!(
+            socon_ipdu_identifier.collection_trigger(),
+            Some(PduCollectionTrigger::Always)
+        );
+        assert_eq!(
+            server_ssc.ipdu_identifiers().next(),
+            Some(socon_ipdu_identifier.clone())
+        );
+        assert_eq!(client_ssc.ipdu_identifiers().next(), Some(socon_ipdu_identifier));
+
+        // UDP connections don't get a TcpRole
+        let udp_config = TpConfig::UdpTp {
+            port_number: Some(1235),
+            port_dynamically_assigned: None,
+        };
+        let udp_server_socket = channel
+            .create_socket_address(
+                "UdpServerSocket",
+                &endpoint,
+                &udp_config,
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        let udp_client_socket = channel
+            .create_socket_address(
+                "UdpClientSocket",
+                &endpoint,
+                &udp_config,
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        let udp_bundle = channel
+            .create_socket_connection_bundle("UdpBundle", &udp_server_socket)
+            .unwrap();
+        udp_bundle.create_bundled_connection(&udp_client_socket).unwrap();
+
+        // a connection without client port is rejected before anything is modified
+        let broken_bundle = channel
+            .create_socket_connection_bundle("BrokenBundle", &server_socket)
+            .unwrap();
+        let broken_connection = broken_bundle.create_bundled_connection(&client_socket).unwrap();
+        broken_connection
+            .element()
+            .remove_sub_element_kind(ElementName::ClientPortRef)
+            .unwrap();
+        assert!(
+            channel
+                .migrate_to_static_socket_connections(&ipdu_identifier_set)
+                .is_err()
+        );
+        assert_eq!(channel.socket_connection_bundles().count(), 2);
+        assert_eq!(udp_server_socket.static_socket_connections().count(), 0);
+
+        AbstractionElement::remove(broken_bundle, false).unwrap();
+        channel
+            .migrate_to_static_socket_connections(&ipdu_identifier_set)
+            .unwrap();
+        let udp_server_ssc = udp_server_socket.static_socket_connections().next().unwrap();
+        assert_eq!(udp_server_ssc.tcp_role(), None);
+        let udp_client_ssc = udp_client_socket.static_socket_connections().next().unwrap();
+        assert_eq!(udp_client_ssc.tcp_role(), None);
+    }
 }
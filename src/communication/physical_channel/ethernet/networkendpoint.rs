@@ -1,4 +1,4 @@
-use crate::communication::EthernetPhysicalChannel;
+use crate::communication::{CommunicationDirection, EthernetPhysicalChannel};
 use crate::{AbstractionElement, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element};
 use autosar_data::{CharacterData, Element, ElementName, EnumItem};
 
@@ -69,6 +69,8 @@ impl NetworkEndpoint {
                 address_source,
                 default_gateway,
                 network_mask,
+                dns_servers,
+                ttl,
             } => {
                 let cfg = addresses.create_sub_element(ElementName::Ipv4Configuration)?;
                 if let Some(addr) = address {
@@ -87,11 +89,19 @@ impl NetworkEndpoint {
                     cfg.create_sub_element(ElementName::NetworkMask)?
                         .set_character_data(netmask)?;
                 }
+                set_dns_servers(&cfg, &dns_servers)?;
+                if let Some(ttl) = ttl {
+                    cfg.create_sub_element(ElementName::Ttl)?
+                        .set_character_data(u64::from(ttl))?;
+                }
             }
             NetworkEndpointAddress::IPv6 {
                 address,
                 address_source,
                 default_router,
+                dns_servers,
+                hop_count,
+                prefix_length,
             } => {
                 let cfg = addresses.create_sub_element(ElementName::Ipv6Configuration)?;
                 if let Some(addr) = address {
@@ -106,6 +116,15 @@ impl NetworkEndpoint {
                     cfg.create_sub_element(ElementName::DefaultRouter)?
                         .set_character_data(dr)?;
                 }
+                set_dns_servers(&cfg, &dns_servers)?;
+                if let Some(hop_count) = hop_count {
+                    cfg.create_sub_element(ElementName::HopCount)?
+                        .set_character_data(u64::from(hop_count))?;
+                }
+                if let Some(prefix_length) = prefix_length {
+                    cfg.create_sub_element(ElementName::IpAddressPrefixLength)?
+                        .set_character_data(u64::from(prefix_length))?;
+                }
             }
         }
         Ok(())
@@ -119,11 +138,372 @@ impl NetworkEndpoint {
             .flat_map(|addresses| addresses.sub_elements())
             .filter_map(|elem| NetworkEndpointAddress::try_from(elem).ok())
     }
+
+    /// create a new `IpSecRule` in the IPsec configuration of this `NetworkEndpoint`
+    ///
+    /// The rules describe which traffic to and from the `NetworkEndpoint` is protected by IPsec
+    pub fn create_ip_sec_rule(&self, name: &str) -> Result<IpSecRule, AutosarAbstractionError> {
+        let ip_sec_rules = self
+            .element()
+            .get_or_create_sub_element(ElementName::IpSecConfig)?
+            .get_or_create_sub_element(ElementName::IpSecRules)?;
+        IpSecRule::new(name, &ip_sec_rules)
+    }
+
+    /// iterate over all `IpSecRule`s of this `NetworkEndpoint`
+    pub fn ip_sec_rules(&self) -> impl Iterator<Item = IpSecRule> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::IpSecConfig)
+            .and_then(|ip_sec_config| ip_sec_config.get_sub_element(ElementName::IpSecRules))
+            .into_iter()
+            .flat_map(|ip_sec_rules| ip_sec_rules.sub_elements())
+            .filter_map(|elem| IpSecRule::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// An `IpSecRule` describes how the traffic between a `NetworkEndpoint` and remote addresses is secured with IPsec
+///
+/// Use [`NetworkEndpoint::create_ip_sec_rule`] to create a new `IpSecRule`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IpSecRule(Element);
+abstraction_element!(IpSecRule, IpSecRule);
+impl IdentifiableAbstractionElement for IpSecRule {}
+
+impl IpSecRule {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let ip_sec_rule = parent.create_named_sub_element(ElementName::IpSecRule, name)?;
+        Ok(Self(ip_sec_rule))
+    }
+
+    /// get the `NetworkEndpoint` that contains this `IpSecRule`
+    pub fn network_endpoint(&self) -> Result<NetworkEndpoint, AutosarAbstractionError> {
+        let network_endpoint = self.element().named_parent()?.unwrap();
+        NetworkEndpoint::try_from(network_endpoint)
+    }
+
+    /// set the direction of the traffic that is covered by this rule
+    ///
+    /// If no direction is set, the rule applies to both directions
+    pub fn set_direction(&self, direction: Option<CommunicationDirection>) -> Result<(), AutosarAbstractionError> {
+        self.set_enum_value(ElementName::Direction, direction.map(EnumItem::from))
+    }
+
+    /// get the direction of the traffic that is covered by this rule
+    #[must_use]
+    pub fn direction(&self) -> Option<CommunicationDirection> {
+        self.enum_value(ElementName::Direction)?.try_into().ok()
+    }
+
+    /// set the IPsec header type: authentication header (AH) or encapsulating security payload (ESP)
+    pub fn set_header_type(&self, header_type: Option<IpSecHeaderType>) -> Result<(), AutosarAbstractionError> {
+        self.set_enum_value(ElementName::HeaderType, header_type.map(EnumItem::from))
+    }
+
+    /// get the IPsec header type
+    #[must_use]
+    pub fn header_type(&self) -> Option<IpSecHeaderType> {
+        self.enum_value(ElementName::HeaderType)?.try_into().ok()
+    }
+
+    /// set the IP protocol that is covered by this rule
+    pub fn set_ip_protocol(&self, ip_protocol: Option<IpSecIpProtocol>) -> Result<(), AutosarAbstractionError> {
+        self.set_enum_value(ElementName::IpProtocol, ip_protocol.map(EnumItem::from))
+    }
+
+    /// get the IP protocol that is covered by this rule
+    #[must_use]
+    pub fn ip_protocol(&self) -> Option<IpSecIpProtocol> {
+        self.enum_value(ElementName::IpProtocol)?.try_into().ok()
+    }
+
+    /// set the policy that is applied to the traffic covered by this rule
+    pub fn set_policy(&self, policy: Option<IpSecPolicy>) -> Result<(), AutosarAbstractionError> {
+        self.set_enum_value(ElementName::Policy, policy.map(EnumItem::from))
+    }
+
+    /// get the policy that is applied to the traffic covered by this rule
+    #[must_use]
+    pub fn policy(&self) -> Option<IpSecPolicy> {
+        self.enum_value(ElementName::Policy)?.try_into().ok()
+    }
+
+    /// set the priority of this rule; rules with a lower value are evaluated first
+    pub fn set_priority(&self, priority: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(priority) = priority {
+            self.element()
+                .get_or_create_sub_element(ElementName::Priority)?
+                .set_character_data(u64::from(priority))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::Priority);
+        }
+        Ok(())
+    }
+
+    /// get the priority of this rule
+    #[must_use]
+    pub fn priority(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::Priority)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// set the range of local ports that is covered by this rule
+    ///
+    /// The range is given as (start, end), both inclusive
+    pub fn set_local_port_range(&self, range: Option<(u16, u16)>) -> Result<(), AutosarAbstractionError> {
+        self.set_port_range(ElementName::LocalPortRangeStart, ElementName::LocalPortRangeEnd, range)
+    }
+
+    /// get the range of local ports that is covered by this rule
+    #[must_use]
+    pub fn local_port_range(&self) -> Option<(u16, u16)> {
+        self.port_range(ElementName::LocalPortRangeStart, ElementName::LocalPortRangeEnd)
+    }
+
+    /// set the range of remote ports that is covered by this rule
+    ///
+    /// The range is given as (start, end), both inclusive
+    pub fn set_remote_port_range(&self, range: Option<(u16, u16)>) -> Result<(), AutosarAbstractionError> {
+        self.set_port_range(
+            ElementName::RemotePortRangeStart,
+            ElementName::RemotePortRangeEnd,
+            range,
+        )
+    }
+
+    /// get the range of remote ports that is covered by this rule
+    #[must_use]
+    pub fn remote_port_range(&self) -> Option<(u16, u16)> {
+        self.port_range(ElementName::RemotePortRangeStart, ElementName::RemotePortRangeEnd)
+    }
+
+    /// add a remote `NetworkEndpoint` whose traffic is covered by this rule
+    pub fn add_remote_ip_address(&self, remote: &NetworkEndpoint) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::RemoteIpAddressRefs)?
+            .create_sub_element(ElementName::RemoteIpAddressRef)?
+            .set_reference_target(remote.element())?;
+        Ok(())
+    }
+
+    /// iterate over all remote `NetworkEndpoint`s whose traffic is covered by this rule
+    pub fn remote_ip_addresses(&self) -> impl Iterator<Item = NetworkEndpoint> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::RemoteIpAddressRefs)
+            .into_iter()
+            .flat_map(|refs| refs.sub_elements())
+            .filter_map(|remote_ref| NetworkEndpoint::try_from(remote_ref.get_reference_target().ok()?).ok())
+    }
+
+    fn set_enum_value(
+        &self,
+        element_name: ElementName,
+        value: Option<EnumItem>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(value) = value {
+            self.element()
+                .get_or_create_sub_element(element_name)?
+                .set_character_data(value)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(element_name);
+        }
+        Ok(())
+    }
+
+    fn enum_value(&self, element_name: ElementName) -> Option<EnumItem> {
+        self.element()
+            .get_sub_element(element_name)?
+            .character_data()?
+            .enum_value()
+    }
+
+    fn set_port_range(
+        &self,
+        start_name: ElementName,
+        end_name: ElementName,
+        range: Option<(u16, u16)>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some((start, end)) = range {
+            if start > end {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "Invalid port range: {start} > {end}"
+                )));
+            }
+            self.element()
+                .get_or_create_sub_element(start_name)?
+                .set_character_data(u64::from(start))?;
+            self.element()
+                .get_or_create_sub_element(end_name)?
+                .set_character_data(u64::from(end))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(start_name);
+            let _ = self.element().remove_sub_element_kind(end_name);
+        }
+        Ok(())
+    }
+
+    fn port_range(&self, start_name: ElementName, end_name: ElementName) -> Option<(u16, u16)> {
+        let start = self
+            .element()
+            .get_sub_element(start_name)?
+            .character_data()?
+            .parse_integer()?;
+        let end = self
+            .element()
+            .get_sub_element(end_name)?
+            .character_data()?
+            .parse_integer()?;
+        Some((start, end))
+    }
+}
+
+//##################################################################
+
+/// The IPsec header type of an [`IpSecRule`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpSecHeaderType {
+    /// authentication header: the packets are authenticated, but not encrypted
+    Ah,
+    /// encapsulating security payload: the packets are authenticated and encrypted
+    Esp,
+    /// no IPsec header
+    None,
+}
+
+impl From<IpSecHeaderType> for EnumItem {
+    fn from(value: IpSecHeaderType) -> Self {
+        match value {
+            IpSecHeaderType::Ah => EnumItem::Ah,
+            IpSecHeaderType::Esp => EnumItem::Esp,
+            IpSecHeaderType::None => EnumItem::None,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for IpSecHeaderType {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::Ah => Ok(IpSecHeaderType::Ah),
+            EnumItem::Esp => Ok(IpSecHeaderType::Esp),
+            EnumItem::None => Ok(IpSecHeaderType::None),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "IpSecHeaderType".to_string(),
+            }),
+        }
+    }
+}
+
+/// The IP protocol that is covered by an [`IpSecRule`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpSecIpProtocol {
+    /// any IP protocol
+    Any,
+    /// TCP only
+    Tcp,
+    /// UDP only
+    Udp,
+}
+
+impl From<IpSecIpProtocol> for EnumItem {
+    fn from(value: IpSecIpProtocol) -> Self {
+        match value {
+            IpSecIpProtocol::Any => EnumItem::Any,
+            IpSecIpProtocol::Tcp => EnumItem::Tcp,
+            IpSecIpProtocol::Udp => EnumItem::Udp,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for IpSecIpProtocol {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::Any => Ok(IpSecIpProtocol::Any),
+            EnumItem::Tcp => Ok(IpSecIpProtocol::Tcp),
+            EnumItem::Udp => Ok(IpSecIpProtocol::Udp),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "IpSecIpProtocol".to_string(),
+            }),
+        }
+    }
+}
+
+/// The policy that an [`IpSecRule`] applies to the covered traffic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IpSecPolicy {
+    /// the traffic is passed without IPsec processing
+    Bypass,
+    /// the traffic is discarded
+    Drop,
+    /// the traffic is protected by IPsec
+    Protect,
+}
+
+impl From<IpSecPolicy> for EnumItem {
+    fn from(value: IpSecPolicy) -> Self {
+        match value {
+            IpSecPolicy::Bypass => EnumItem::Bypass,
+            IpSecPolicy::Drop => EnumItem::Drop,
+            IpSecPolicy::Protect => EnumItem::Protect,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for IpSecPolicy {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::Bypass => Ok(IpSecPolicy::Bypass),
+            EnumItem::Drop => Ok(IpSecPolicy::Drop),
+            EnumItem::Protect => Ok(IpSecPolicy::Protect),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "IpSecPolicy".to_string(),
+            }),
+        }
+    }
+}
+
+// write the DNS server addresses of an IPv4 or IPv6 configuration
+fn set_dns_servers(cfg: &Element, dns_servers: &[String]) -> Result<(), AutosarAbstractionError> {
+    if !dns_servers.is_empty() {
+        let dns_server_addresses = cfg.create_sub_element(ElementName::DnsServerAddresses)?;
+        for dns_server in dns_servers {
+            dns_server_addresses
+                .create_sub_element(ElementName::DnsServerAddress)?
+                .set_character_data(dns_server.as_str())?;
+        }
+    }
+    Ok(())
+}
+
+// read the DNS server addresses of an IPv4 or IPv6 configuration
+fn dns_servers(cfg: &Element) -> Vec<String> {
+    cfg.get_sub_element(ElementName::DnsServerAddresses)
+        .into_iter()
+        .flat_map(|dns_server_addresses| dns_server_addresses.sub_elements())
+        .filter_map(|dns_server| dns_server.character_data()?.string_value())
+        .collect()
 }
 
 //##################################################################
 
 /// address information for a network endpoint
+///
+/// # Migration
+///
+/// The fields `dns_servers` and `ttl` (IPv4), as well as `dns_servers`, `hop_count` and `prefix_length` (IPv6)
+/// were added in this release. Code that constructs a `NetworkEndpointAddress` must now provide them;
+/// `dns_servers: vec![]` and `None` for the other fields keep the previous behavior.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NetworkEndpointAddress {
     /// IPv4 addressing information
@@ -136,6 +516,10 @@ pub enum NetworkEndpointAddress {
         default_gateway: Option<String>,
         /// Network mask in the form "a.b.c.d"
         network_mask: Option<String>,
+        /// IP addresses of the DNS servers
+        dns_servers: Vec<String>,
+        /// time to live of the IP packets sent from this address
+        ttl: Option<u8>,
     },
     /// IPv6 addressing information
     IPv6 {
@@ -145,6 +529,12 @@ pub enum NetworkEndpointAddress {
         address_source: Option<IPv6AddressSource>,
         /// IP address of the default router
         default_router: Option<String>,
+        /// IP addresses of the DNS servers
+        dns_servers: Vec<String>,
+        /// maximum number of hops of the IP packets sent from this address
+        hop_count: Option<u8>,
+        /// length of the network prefix of the address in bits
+        prefix_length: Option<u32>,
     },
 }
 
@@ -170,12 +560,18 @@ impl TryFrom<Element> for NetworkEndpointAddress {
                     .get_sub_element(ElementName::NetworkMask)
                     .and_then(|nm| nm.character_data())
                     .and_then(|cdata| cdata.string_value());
+                let ttl = element
+                    .get_sub_element(ElementName::Ttl)
+                    .and_then(|ttl| ttl.character_data())
+                    .and_then(|cdata| cdata.parse_integer());
 
                 Ok(NetworkEndpointAddress::IPv4 {
                     address,
                     address_source,
                     default_gateway,
                     network_mask,
+                    dns_servers: dns_servers(&element),
+                    ttl,
                 })
             }
             ElementName::Ipv6Configuration => {
@@ -191,11 +587,22 @@ impl TryFrom<Element> for NetworkEndpointAddress {
                     .get_sub_element(ElementName::DefaultRouter)
                     .and_then(|dr| dr.character_data())
                     .and_then(|cdata| cdata.string_value());
+                let hop_count = element
+                    .get_sub_element(ElementName::HopCount)
+                    .and_then(|hc| hc.character_data())
+                    .and_then(|cdata| cdata.parse_integer());
+                let prefix_length = element
+                    .get_sub_element(ElementName::IpAddressPrefixLength)
+                    .and_then(|pl| pl.character_data())
+                    .and_then(|cdata| cdata.parse_integer());
 
                 Ok(NetworkEndpointAddress::IPv6 {
                     address,
                     address_source,
                     default_router,
+                    dns_servers: dns_servers(&element),
+                    hop_count,
+                    prefix_length,
                 })
             }
             _ => Err(AutosarAbstractionError::ConversionError {
@@ -302,6 +709,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: Some("192.168.0.2".to_string()),
             network_mask: Some("255.255.0.0".to_string()),
+            dns_servers: vec!["192.168.0.3".to_string(), "192.168.0.4".to_string()],
+            ttl: Some(64),
         };
         let network_endpoint = channel
             .create_network_endpoint("RemoteAddress", address1.clone(), None)
@@ -314,6 +723,8 @@ mod test {
             address_source: Some(IPv4AddressSource::AutoIp),
             default_gateway: None,
             network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
         };
         network_endpoint.add_network_endpoint_address(address2).unwrap();
         assert_eq!(network_endpoint.addresses().count(), 2);
@@ -332,6 +743,9 @@ mod test {
             address: Some("2001:0db8:0000:0000:0000:0000:0000:0001".to_string()),
             address_source: Some(IPv6AddressSource::Fixed),
             default_router: Some("2001:0db8:0000:0000:0000:0000:0000:0002".to_string()),
+            dns_servers: vec!["2001:0db8:0000:0000:0000:0000:0000:0003".to_string()],
+            hop_count: Some(32),
+            prefix_length: Some(64),
         };
         let network_endpoint = channel
             .create_network_endpoint("RemoteAddress", address1.clone(), None)
@@ -343,8 +757,63 @@ mod test {
             address: None,
             address_source: Some(IPv6AddressSource::LinkLocal),
             default_router: None,
+            dns_servers: vec![],
+            hop_count: None,
+            prefix_length: None,
         };
         network_endpoint.add_network_endpoint_address(address2).unwrap();
         assert_eq!(network_endpoint.addresses().count(), 2);
     }
+
+    #[test]
+    fn ip_sec_rules() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let cluster = system.create_ethernet_cluster("EthCluster", &pkg).unwrap();
+        let channel = cluster.create_physical_channel("Channel", None).unwrap();
+        let address = |addr: &str| NetworkEndpointAddress::IPv4 {
+            address: Some(addr.to_string()),
+            address_source: Some(IPv4AddressSource::Fixed),
+            default_gateway: None,
+            network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
+        };
+        let local_endpoint = channel
+            .create_network_endpoint("LocalAddress", address("192.168.0.1"), None)
+            .unwrap();
+        let remote_endpoint = channel
+            .create_network_endpoint("RemoteAddress", address("192.168.0.2"), None)
+            .unwrap();
+
+        let rule = local_endpoint.create_ip_sec_rule("Rule").unwrap();
+        assert_eq!(local_endpoint.ip_sec_rules().next(), Some(rule.clone()));
+        assert_eq!(rule.network_endpoint().unwrap(), local_endpoint);
+
+        rule.set_direction(Some(CommunicationDirection::Out)).unwrap();
+        assert_eq!(rule.direction(), Some(CommunicationDirection::Out));
+        rule.set_header_type(Some(IpSecHeaderType::Esp)).unwrap();
+        assert_eq!(rule.header_type(), Some(IpSecHeaderType::Esp));
+        rule.set_ip_protocol(Some(IpSecIpProtocol::Udp)).unwrap();
+        assert_eq!(rule.ip_protocol(), Some(IpSecIpProtocol::Udp));
+        rule.set_policy(Some(IpSecPolicy::Protect)).unwrap();
+        assert_eq!(rule.policy(), Some(IpSecPolicy::Protect));
+        rule.set_priority(Some(10)).unwrap();
+        assert_eq!(rule.priority(), Some(10));
+
+        rule.set_local_port_range(Some((30000, 30010))).unwrap();
+        assert_eq!(rule.local_port_range(), Some((30000, 30010)));
+        assert!(rule.set_remote_port_range(Some((2, 1))).is_err());
+        rule.set_remote_port_range(Some((40000, 40000))).unwrap();
+        assert_eq!(rule.remote_port_range(), Some((40000, 40000)));
+        rule.set_remote_port_range(None).unwrap();
+        assert_eq!(rule.remote_port_range(), None);
+
+        rule.add_remote_ip_address(&remote_endpoint).unwrap();
+        assert_eq!(rule.remote_ip_addresses().next(), Some(remote_endpoint));
+
+        rule.set_direction(None).unwrap();
+        assert_eq!(rule.direction(), None);
+    }
 }
@@ -48,14 +48,18 @@ impl SocketConnectionBundle {
     /// #    address: Some("192.168.0.1".to_string()),
     /// #    address_source: Some(IPv4AddressSource::Fixed),
     /// #    default_gateway: None,
-    /// #    network_mask: None
+    /// #    network_mask: None,
+    /// #    dns_servers: vec![],
+    /// #    ttl: None
     /// # }, None)?;
     /// # let server_socket = channel.create_socket_address("ServerSocket", &server_endpoint, &TpConfig::TcpTp { port_number: Some(1234), port_dynamically_assigned: None }, SocketAddressType::Unicast(None))?;
     /// # let client_endpoint = channel.create_network_endpoint("ClientAddress", NetworkEndpointAddress::IPv4 {
     /// #    address: Some("192.168.0.2".to_string()),
     /// #    address_source: Some(IPv4AddressSource::Fixed),
     /// #    default_gateway: None,
-    /// #    network_mask: None
+    /// #    network_mask: None,
+    /// #    dns_servers: vec![],
+    /// #    ttl: None
     /// # }, None)?;
     /// # let client_socket = channel.create_socket_address("ClientSocket", &client_endpoint, &TpConfig::TcpTp { port_number: Some(1235), port_dynamically_assigned: None }, SocketAddressType::Unicast(None))?;
     /// let bundle = channel.create_socket_connection_bundle("Bundle", &server_socket)?;
@@ -127,6 +131,9 @@ impl SocketConnectionBundle {
 //##################################################################
 
 /// A socketConnection inside a `SocketConnectionBundle` describes a single connection to a specific client port.
+///
+/// "Very old" files can also contain `SocketConnection`s directly in the physical channel, without a bundle.
+/// These can be read using [`EthernetPhysicalChannel::socket_connections`].
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SocketConnection(Element);
 abstraction_element!(SocketConnection, SocketConnection);
@@ -161,14 +168,18 @@ impl SocketConnection {
     /// #    address: Some("192.168.0.1".to_string()),
     /// #    address_source: Some(IPv4AddressSource::Fixed),
     /// #    default_gateway: None,
-    /// #    network_mask: None
+    /// #    network_mask: None,
+    /// #    dns_servers: vec![],
+    /// #    ttl: None
     /// # }, None)?;
     /// # let server_socket = channel.create_socket_address("ServerSocket", &server_endpoint, &TpConfig::TcpTp { port_number: Some(1234), port_dynamically_assigned: None }, SocketAddressType::Unicast(None))?;
     /// # let client_endpoint = channel.create_network_endpoint("ClientAddress", NetworkEndpointAddress::IPv4 {
     /// #    address: Some("192.168.0.2".to_string()),
     /// #    address_source: Some(IPv4AddressSource::Fixed),
     /// #    default_gateway: None,
-    /// #    network_mask: None
+    /// #    network_mask: None,
+    /// #    dns_servers: vec![],
+    /// #    ttl: None
     /// # }, None)?;
     /// # let client_socket = channel.create_socket_address("ClientSocket", &client_endpoint, &TpConfig::TcpTp { port_number: Some(1235), port_dynamically_assigned: None }, SocketAddressType::Unicast(None))?;
     /// let bundle = channel.create_socket_connection_bundle("Bundle", &server_socket)?;
@@ -181,6 +192,18 @@ impl SocketConnection {
         SocketConnectionBundle::try_from(bundle)
     }
 
+    /// get the physical channel containing this socket connection
+    ///
+    /// This works both for bundled connections and for the "very old" connections that are not part of a bundle
+    pub fn physical_channel(&self) -> Result<EthernetPhysicalChannel, AutosarAbstractionError> {
+        let parent = self.element().named_parent()?.unwrap();
+        if let Ok(bundle) = SocketConnectionBundle::try_from(parent.clone()) {
+            bundle.physical_channel()
+        } else {
+            EthernetPhysicalChannel::try_from(parent)
+        }
+    }
+
     /// set the client port of this socket connection
     pub fn set_client_port(&self, client_port: &SocketAddress) -> Result<(), AutosarAbstractionError> {
         self.element()
@@ -602,6 +625,8 @@ mod test {
                     address_source: Some(IPv4AddressSource::Fixed),
                     default_gateway: None,
                     network_mask: None,
+                    dns_servers: vec![],
+                    ttl: None,
                 },
                 None,
             )
@@ -633,6 +658,8 @@ mod test {
                     address_source: Some(IPv4AddressSource::Fixed),
                     default_gateway: None,
                     network_mask: None,
+                    dns_servers: vec![],
+                    ttl: None,
                 },
                 None,
             )
@@ -702,4 +729,64 @@ mod test {
             Some(EventGroupControlType::TriggerUnicast)
         );
     }
+
+    #[test]
+    fn upgrade_socket_connections() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_4_2_1);
+        let package = model.get_or_create_package("/pkg1").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_ethernet_cluster("Cluster", &package).unwrap();
+        let channel = cluster.create_physical_channel("Channel", None).unwrap();
+        let endpoint = channel
+            .create_network_endpoint(
+                "Address",
+                NetworkEndpointAddress::IPv4 {
+                    address: Some("192.168.0.1".to_string()),
+                    address_source: Some(IPv4AddressSource::Fixed),
+                    default_gateway: None,
+                    network_mask: None,
+                    dns_servers: vec![],
+                    ttl: None,
+                },
+                None,
+            )
+            .unwrap();
+        let tp_config = TpConfig::UdpTp {
+            port_number: Some(1234),
+            port_dynamically_assigned: None,
+        };
+        let server_socket = channel
+            .create_socket_address("ServerSocket", &endpoint, &tp_config, SocketAddressType::Unicast(None))
+            .unwrap();
+        let client_socket = channel
+            .create_socket_address("ClientSocket", &endpoint, &tp_config, SocketAddressType::Unicast(None))
+            .unwrap();
+
+        // create a "very old" socket connection directly in the channel
+        let conn_elem = channel
+            .element()
+            .get_or_create_sub_element(ElementName::SoAdConfig)
+            .and_then(|soad_config| soad_config.get_or_create_sub_element(ElementName::Connections))
+            .and_then(|connections| connections.create_sub_element(ElementName::SocketConnection))
+            .unwrap();
+        conn_elem
+            .create_sub_element(ElementName::ServerPortRef)
+            .and_then(|spr| spr.set_reference_target(server_socket.element()))
+            .unwrap();
+        let connection = SocketConnection::new(conn_elem, &client_socket).unwrap();
+        assert!(channel.has_socket_connections());
+        assert_eq!(channel.socket_connections().next(), Some(connection.clone()));
+        assert_eq!(connection.physical_channel().unwrap(), channel);
+        assert!(connection.socket_connection_bundle().is_err());
+
+        let bundles = channel.upgrade_to_connection_bundles().unwrap();
+        assert_eq!(bundles.len(), 1);
+        assert_eq!(bundles[0].server_port(), Some(server_socket));
+        assert_eq!(channel.socket_connections().count(), 0);
+        let bundled_connection = bundles[0].bundled_connections().next().unwrap();
+        assert_eq!(bundled_connection.client_port(), Some(client_socket));
+        assert_eq!(bundled_connection.socket_connection_bundle().unwrap(), bundles[0]);
+        assert_eq!(bundled_connection.physical_channel().unwrap(), channel);
+        assert!(channel.has_socket_connections());
+    }
 }
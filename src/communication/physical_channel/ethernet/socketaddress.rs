@@ -1,12 +1,13 @@
 use crate::communication::{
-    AbstractPhysicalChannel, ConsumedServiceInstanceV1, EthernetPhysicalChannel, NetworkEndpoint,
-    ProvidedServiceInstanceV1, SocketConnection, SocketConnectionBundle, StaticSocketConnection, TcpRole,
+    AbstractPhysicalChannel, ConsumedServiceInstanceV1, EthernetCommunicationConnector, EthernetPhysicalChannel,
+    NetworkEndpoint, ProvidedServiceInstanceV1, SocketConnection, SocketConnectionBundle, StaticSocketConnection,
+    TcpRole, TlsCryptoServiceMapping,
 };
 use crate::{
     AbstractionElement, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement, abstraction_element,
-    get_reference_parents,
+    get_reference_parents, make_unique_generated_name,
 };
-use autosar_data::{Element, ElementName};
+use autosar_data::{Element, ElementName, EnumItem, WeakElement};
 
 //##################################################################
 
@@ -124,6 +125,13 @@ impl SocketAddress {
     }
 
     /// remove this `SocketAddress` from the model
+    ///
+    /// All connections that use this socket address are removed as well: its own static socket connections,
+    /// the static socket connections of other sockets that use it as their remote socket, and the
+    /// socket connections and socket connection bundles (old). Service discovery references to this
+    /// socket address, e.g. local unicast addresses and event multicast addresses, are removed.
+    ///
+    /// If `deep` is true, `SoConIPduIdentifier`s that are no longer used by any static socket connection are also removed.
     pub fn remove(self, deep: bool) -> Result<(), AutosarAbstractionError> {
         for static_socket_connection in self.static_socket_connections() {
             static_socket_connection.remove(deep)?;
@@ -138,9 +146,14 @@ impl SocketAddress {
         }
 
         let ref_parents = get_reference_parents(self.element())?;
+        let application_endpoint_refs = self.application_endpoint_references()?;
 
         AbstractionElement::remove(self, deep)?;
 
+        for ae_ref in application_endpoint_refs {
+            remove_application_endpoint_ref(ae_ref)?;
+        }
+
         for (named_parent, _parent) in ref_parents {
             match named_parent.element_name() {
                 ElementName::SocketConnectionBundle => {
@@ -153,6 +166,11 @@ impl SocketAddress {
                         socket_connection.remove(deep)?;
                     }
                 }
+                ElementName::StaticSocketConnection => {
+                    if let Ok(static_socket_connection) = StaticSocketConnection::try_from(named_parent) {
+                        static_socket_connection.remove(deep)?;
+                    }
+                }
                 _ => {}
             }
         }
@@ -160,6 +178,136 @@ impl SocketAddress {
         Ok(())
     }
 
+    /// merge this `SocketAddress` into another `SocketAddress`
+    ///
+    /// All references to this socket address are re-pointed to `other`. This includes socket connections (old),
+    /// static socket connections of other sockets, and service discovery references to the application endpoint.
+    /// The static socket connections and the service instances (old) of this socket address are moved to `other`.
+    /// Static socket connections that would connect `other` to itself are removed. Finally, this socket address is removed.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the socket addresses are identical, are part of different channels,
+    ///   or use different transport protocols. This is checked before the model is modified.
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn merge_into(self, other: &SocketAddress) -> Result<(), AutosarAbstractionError> {
+        if &self == other {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "A SocketAddress can not be merged into itself".to_string(),
+            ));
+        }
+        if self.physical_channel()? != other.physical_channel()? {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "Both SocketAddresses must be part of the same channel".to_string(),
+            ));
+        }
+        if !matches!(
+            (self.tp_config(), other.tp_config()),
+            (Some(TpConfig::TcpTp { .. }), Some(TpConfig::TcpTp { .. }))
+                | (Some(TpConfig::UdpTp { .. }), Some(TpConfig::UdpTp { .. }))
+                | (None, None)
+        ) {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "Both SocketAddresses must use the same transport protocol".to_string(),
+            ));
+        }
+        let application_endpoint_refs = self.application_endpoint_references()?;
+        let other_application_endpoint = other.element().get_sub_element(ElementName::ApplicationEndpoint);
+        if !application_endpoint_refs.is_empty() && other_application_endpoint.is_none() {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The target SocketAddress does not have an ApplicationEndpoint".to_string(),
+            ));
+        }
+
+        let model = self.element().model()?;
+        let other_path = other.element().path()?;
+
+        // move the static socket connections of this socket address to the other one
+        for static_socket_connection in self.static_socket_connections() {
+            if static_socket_connection.remote_socket().as_ref() == Some(other) {
+                static_socket_connection.remove(false)?;
+                continue;
+            }
+            let name = static_socket_connection.name().unwrap_or_default();
+            let new_name = make_unique_generated_name(&model, &other_path, &name);
+            if new_name != name {
+                static_socket_connection.set_name(&new_name)?;
+            }
+            other
+                .element()
+                .get_or_create_sub_element(ElementName::StaticSocketConnections)?
+                .move_element_here(static_socket_connection.element())?;
+        }
+
+        // move the service instances (old) of this socket address to the other one
+        if let Some(application_endpoint) = self.element().get_sub_element(ElementName::ApplicationEndpoint) {
+            for container_name in [
+                ElementName::ProvidedServiceInstances,
+                ElementName::ConsumedServiceInstances,
+            ] {
+                let Some(container) = application_endpoint.get_sub_element(container_name) else {
+                    continue;
+                };
+                let target_ae = match &other_application_endpoint {
+                    Some(target_ae) => target_ae.clone(),
+                    None => other.element().create_named_sub_element(
+                        ElementName::ApplicationEndpoint,
+                        &format!("{}_AE", other.name().unwrap_or_default()),
+                    )?,
+                };
+                let target_path = target_ae.path()?;
+                let target_container = target_ae.get_or_create_sub_element(container_name)?;
+                for service_instance in container.sub_elements() {
+                    let name = service_instance.item_name().unwrap_or_default();
+                    let new_name = make_unique_generated_name(&model, &target_path, &name);
+                    if new_name != name {
+                        service_instance.set_item_name(&new_name)?;
+                    }
+                    target_container.move_element_here(&service_instance)?;
+                }
+            }
+        }
+
+        // re-point all references to this socket address
+        for reference in references_to(self.element())? {
+            let referrer = reference.named_parent()?;
+            if let Some(static_socket_connection) =
+                referrer.and_then(|referrer| StaticSocketConnection::try_from(referrer).ok())
+                && static_socket_connection.socket_address().ok().as_ref() == Some(other)
+            {
+                static_socket_connection.remove(false)?;
+            } else {
+                reference.set_reference_target(other.element())?;
+            }
+        }
+        if let Some(other_application_endpoint) = &other_application_endpoint {
+            for ae_ref in application_endpoint_refs {
+                ae_ref.set_reference_target(other_application_endpoint)?;
+            }
+        }
+
+        AbstractionElement::remove(self, false)
+    }
+
+    // get all references to the application endpoint of this socket address from outside of the socket address
+    fn application_endpoint_references(&self) -> Result<Vec<Element>, AutosarAbstractionError> {
+        let Some(application_endpoint) = self.element().get_sub_element(ElementName::ApplicationEndpoint) else {
+            return Ok(Vec::new());
+        };
+        let own_path = format!("{}/", self.element().path()?);
+        Ok(references_to(&application_endpoint)?
+            .into_iter()
+            .filter(|reference| {
+                reference
+                    .named_parent()
+                    .ok()
+                    .flatten()
+                    .and_then(|named_parent| named_parent.path().ok())
+                    .is_some_and(|path| !path.starts_with(&own_path))
+            })
+            .collect())
+    }
+
     /// get the network endpoint of this `SocketAddress`
     #[must_use]
     pub fn network_endpoint(&self) -> Option<NetworkEndpoint> {
@@ -232,6 +380,41 @@ impl SocketAddress {
         Ok(())
     }
 
+    /// iterate over the connectors of all `EcuInstance`s of this multicast `SocketAddress`
+    pub fn multicast_connectors(&self) -> impl Iterator<Item = EthernetCommunicationConnector> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::MulticastConnectorRefs)
+            .into_iter()
+            .flat_map(|mcr| mcr.sub_elements())
+            .filter_map(|mc_ref| EthernetCommunicationConnector::try_from(mc_ref.get_reference_target().ok()?).ok())
+    }
+
+    /// remove an `EcuInstance` from this multicast `SocketAddress`
+    ///
+    /// Returns `true` if the `EcuInstance` was part of the multicast group.
+    /// When the last `EcuInstance` is removed, the empty MULTICAST-CONNECTOR-REFS element is removed as well.
+    pub fn remove_multicast_ecu(&self, ecu: &EcuInstance) -> Result<bool, AutosarAbstractionError> {
+        let Some(mcr) = self.element().get_sub_element(ElementName::MulticastConnectorRefs) else {
+            return Ok(false);
+        };
+        let mc_ref = mcr.sub_elements().find(|mc_ref| {
+            mc_ref
+                .get_reference_target()
+                .ok()
+                .and_then(|conn| conn.named_parent().ok().flatten())
+                .is_some_and(|ecu_elem| ecu_elem == *ecu.element())
+        });
+        if let Some(mc_ref) = mc_ref {
+            mcr.remove_sub_element(mc_ref)?;
+            if mcr.sub_elements().next().is_none() {
+                self.element().remove_sub_element(mcr)?;
+            }
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
     /// set the `EcuInstance` for this unicast `SocketAddress`
     pub fn set_unicast_ecu(&self, ecu: &EcuInstance) -> Result<(), AutosarAbstractionError> {
         let socket_type = self.socket_address_type();
@@ -298,6 +481,157 @@ impl SocketAddress {
         (port_number, port_dynamically_assigned)
     }
 
+    /// set or remove the UDP checksum handling of this `SocketAddress`
+    pub fn set_udp_checksum_handling(
+        &self,
+        handling: Option<UdpChecksumHandling>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(handling) = handling {
+            self.element()
+                .get_or_create_sub_element(ElementName::UdpChecksumHandling)?
+                .set_character_data::<EnumItem>(handling.into())?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::UdpChecksumHandling);
+        }
+        Ok(())
+    }
+
+    /// get the UDP checksum handling of this `SocketAddress`
+    #[must_use]
+    pub fn udp_checksum_handling(&self) -> Option<UdpChecksumHandling> {
+        self.element()
+            .get_sub_element(ElementName::UdpChecksumHandling)?
+            .character_data()?
+            .enum_value()?
+            .try_into()
+            .ok()
+    }
+
+    /// set or remove the differentiated services field (DSCP) of the IP packets sent from this `SocketAddress`
+    ///
+    /// The value must be in the range 0..=63
+    pub fn set_differentiated_service_field(&self, dscp: Option<u8>) -> Result<(), AutosarAbstractionError> {
+        if let Some(dscp) = dscp {
+            if dscp > 63 {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "The differentiated service field must be in the range 0..=63, got {dscp}"
+                )));
+            }
+            self.element()
+                .get_or_create_sub_element(ElementName::DifferentiatedServiceField)?
+                .set_character_data(u64::from(dscp))?;
+        } else {
+            let _ = self
+                .element()
+                .remove_sub_element_kind(ElementName::DifferentiatedServiceField);
+        }
+        Ok(())
+    }
+
+    /// get the differentiated services field (DSCP) of the IP packets sent from this `SocketAddress`
+    #[must_use]
+    pub fn differentiated_service_field(&self) -> Option<u8> {
+        self.element()
+            .get_sub_element(ElementName::DifferentiatedServiceField)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// set or remove the priority of the application endpoint of this `SocketAddress`
+    ///
+    /// The value must be in the range 0..=7
+    pub fn set_priority(&self, priority: Option<u8>) -> Result<(), AutosarAbstractionError> {
+        let Some(application_endpoint) = self.element().get_sub_element(ElementName::ApplicationEndpoint) else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The SocketAddress has no ApplicationEndpoint".to_string(),
+            ));
+        };
+        if let Some(priority) = priority {
+            if priority > 7 {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "The priority must be in the range 0..=7, got {priority}"
+                )));
+            }
+            application_endpoint
+                .get_or_create_sub_element(ElementName::Priority)?
+                .set_character_data(u64::from(priority))?;
+        } else {
+            let _ = application_endpoint.remove_sub_element_kind(ElementName::Priority);
+        }
+        Ok(())
+    }
+
+    /// get the priority of the application endpoint of this `SocketAddress`
+    #[must_use]
+    pub fn priority(&self) -> Option<u8> {
+        self.element()
+            .get_sub_element(ElementName::ApplicationEndpoint)?
+            .get_sub_element(ElementName::Priority)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// set or remove the `TlsCryptoServiceMapping` of the application endpoint of this `SocketAddress`
+    ///
+    /// The mapping provides the cipher suites of the (D)TLS connections that use this socket.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The `SocketAddress` has no `ApplicationEndpoint`
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn set_tls_crypto_mapping(
+        &self,
+        mapping: Option<&TlsCryptoServiceMapping>,
+    ) -> Result<(), AutosarAbstractionError> {
+        let Some(application_endpoint) = self.element().get_sub_element(ElementName::ApplicationEndpoint) else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The SocketAddress has no ApplicationEndpoint".to_string(),
+            ));
+        };
+        if let Some(mapping) = mapping {
+            application_endpoint
+                .get_or_create_sub_element(ElementName::TlsCryptoMappingRef)?
+                .set_reference_target(mapping.element())?;
+        } else {
+            let _ = application_endpoint.remove_sub_element_kind(ElementName::TlsCryptoMappingRef);
+        }
+        Ok(())
+    }
+
+    /// get the `TlsCryptoServiceMapping` of the application endpoint of this `SocketAddress`
+    #[must_use]
+    pub fn tls_crypto_mapping(&self) -> Option<TlsCryptoServiceMapping> {
+        self.element()
+            .get_sub_element(ElementName::ApplicationEndpoint)?
+            .get_sub_element(ElementName::TlsCryptoMappingRef)?
+            .get_reference_target()
+            .ok()
+            .and_then(|elem| TlsCryptoServiceMapping::try_from(elem).ok())
+    }
+
+    /// set or remove the port address of this `SocketAddress`
+    ///
+    /// The port address is the MAC multicast address that is used for this socket
+    pub fn set_port_address(&self, port_address: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        if let Some(port_address) = port_address {
+            self.element()
+                .get_or_create_sub_element(ElementName::PortAddress)?
+                .set_character_data(port_address)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::PortAddress);
+        }
+        Ok(())
+    }
+
+    /// get the port address of this `SocketAddress`
+    #[must_use]
+    pub fn port_address(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::PortAddress)?
+            .character_data()?
+            .string_value()
+    }
+
     /// create a new `StaticSocketConnection` from this `SocketAddress` to a remote `SocketAddress`
     pub fn create_static_socket_connection(
         &self,
@@ -399,6 +733,38 @@ impl SocketAddress {
     }
 }
 
+// get all reference elements that point to the given element
+fn references_to(element: &Element) -> Result<Vec<Element>, AutosarAbstractionError> {
+    let model = element.model()?;
+    let path = element.path()?;
+    Ok(model
+        .get_references_to(&path)
+        .iter()
+        .filter_map(WeakElement::upgrade)
+        .collect())
+}
+
+// remove a reference to an application endpoint, together with its conditional wrapper
+// the container of the conditional wrapper, e.g. LOCAL-UNICAST-ADDRESSS, is removed if it becomes empty
+fn remove_application_endpoint_ref(ae_ref: Element) -> Result<(), AutosarAbstractionError> {
+    let Some(parent) = ae_ref.parent()? else {
+        return Ok(());
+    };
+    if parent.element_name() == ElementName::ApplicationEndpointRefConditional {
+        if let Some(container) = parent.parent()? {
+            container.remove_sub_element(parent)?;
+            if container.sub_elements().next().is_none()
+                && let Some(container_parent) = container.parent()?
+            {
+                container_parent.remove_sub_element(container)?;
+            }
+        }
+    } else {
+        parent.remove_sub_element(ae_ref)?;
+    }
+    Ok(())
+}
+
 //##################################################################
 
 /// transport protocol settings of a [`SocketAddress`]
@@ -424,6 +790,41 @@ pub enum TpConfig {
 
 //##################################################################
 
+/// UDP checksum handling of a [`SocketAddress`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UdpChecksumHandling {
+    /// the UDP checksum is not calculated
+    Disabled,
+    /// the UDP checksum is calculated
+    Enabled,
+}
+
+impl From<UdpChecksumHandling> for EnumItem {
+    fn from(value: UdpChecksumHandling) -> Self {
+        match value {
+            UdpChecksumHandling::Disabled => EnumItem::UdpChecksumDisabled,
+            UdpChecksumHandling::Enabled => EnumItem::UdpChecksumEnabled,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for UdpChecksumHandling {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::UdpChecksumDisabled => Ok(UdpChecksumHandling::Disabled),
+            EnumItem::UdpChecksumEnabled => Ok(UdpChecksumHandling::Enabled),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "UdpChecksumHandling".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
 /// Describes if a [`SocketAddress`] is used for unicast or multicast
 #[derive(Debug, Clone, PartialEq)]
 pub enum SocketAddressType {
@@ -438,7 +839,9 @@ pub enum SocketAddressType {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::communication::{IPv4AddressSource, NetworkEndpointAddress};
+    use crate::communication::{
+        GeneralPurposePduCategory, IPv4AddressSource, LocalUnicastAddress, NetworkEndpointAddress,
+    };
     use crate::{AutosarModelAbstraction, SystemCategory};
     use autosar_data::AutosarVersion;
 
@@ -473,6 +876,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: Some("192.168.0.2".to_string()),
             network_mask: Some("255.255.255.0".to_string()),
+            dns_servers: vec![],
+            ttl: None,
         };
         let network_endpoint = channel
             .create_network_endpoint("Address", endpoint_address, Some(&ecu_instance))
@@ -522,6 +927,80 @@ mod test {
             multicast_socket_address.socket_address_type().unwrap(),
             SocketAddressType::Multicast(vec![ecu_instance.clone(), ecu_instance2.clone(), ecu_instance3.clone()])
         );
+        assert_eq!(multicast_socket_address.multicast_connectors().count(), 3);
+        assert!(multicast_socket_address.remove_multicast_ecu(&ecu_instance2).unwrap());
+        assert!(!multicast_socket_address.remove_multicast_ecu(&ecu_instance2).unwrap());
+        assert_eq!(
+            multicast_socket_address.socket_address_type().unwrap(),
+            SocketAddressType::Multicast(vec![ecu_instance.clone(), ecu_instance3.clone()])
+        );
+        // removing the last EcuInstance also removes the empty container
+        assert!(multicast_socket_address.remove_multicast_ecu(&ecu_instance).unwrap());
+        assert!(multicast_socket_address.remove_multicast_ecu(&ecu_instance3).unwrap());
+        assert!(
+            multicast_socket_address
+                .element()
+                .get_sub_element(ElementName::MulticastConnectorRefs)
+                .is_none()
+        );
+        assert_eq!(multicast_socket_address.socket_address_type(), None);
+    }
+
+    #[test]
+    fn socket_address_attributes() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg1").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_ethernet_cluster("Cluster", &package).unwrap();
+        let channel = cluster.create_physical_channel("Channel", None).unwrap();
+        let endpoint_address = NetworkEndpointAddress::IPv4 {
+            address: Some("192.168.0.1".to_string()),
+            address_source: Some(IPv4AddressSource::Fixed),
+            default_gateway: None,
+            network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
+        };
+        let network_endpoint = channel
+            .create_network_endpoint("Address", endpoint_address, None)
+            .unwrap();
+        let udp_port = TpConfig::UdpTp {
+            port_number: Some(1234),
+            port_dynamically_assigned: None,
+        };
+        let socket = channel
+            .create_socket_address("Socket", &network_endpoint, &udp_port, SocketAddressType::Unicast(None))
+            .unwrap();
+
+        socket
+            .set_udp_checksum_handling(Some(UdpChecksumHandling::Disabled))
+            .unwrap();
+        assert_eq!(socket.udp_checksum_handling(), Some(UdpChecksumHandling::Disabled));
+        socket.set_udp_checksum_handling(None).unwrap();
+        assert_eq!(socket.udp_checksum_handling(), None);
+
+        socket.set_differentiated_service_field(Some(46)).unwrap();
+        assert_eq!(socket.differentiated_service_field(), Some(46));
+        assert!(socket.set_differentiated_service_field(Some(64)).is_err());
+        socket.set_differentiated_service_field(None).unwrap();
+        assert_eq!(socket.differentiated_service_field(), None);
+
+        socket.set_priority(Some(5)).unwrap();
+        assert_eq!(socket.priority(), Some(5));
+        assert!(socket.set_priority(Some(8)).is_err());
+        socket.set_priority(None).unwrap();
+        assert_eq!(socket.priority(), None);
+
+        socket.set_port_address(Some("01:00:5E:00:00:01")).unwrap();
+        assert_eq!(socket.port_address(), Some("01:00:5E:00:00:01".to_string()));
+        socket.set_port_address(None).unwrap();
+        assert_eq!(socket.port_address(), None);
+
+        let tls_mapping = package.create_tls_crypto_service_mapping("TlsMapping").unwrap();
+        socket.set_tls_crypto_mapping(Some(&tls_mapping)).unwrap();
+        assert_eq!(socket.tls_crypto_mapping(), Some(tls_mapping));
+        socket.set_tls_crypto_mapping(None).unwrap();
+        assert_eq!(socket.tls_crypto_mapping(), None);
     }
 
     #[test]
@@ -543,6 +1022,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: None,
             network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
         };
         let network_endpoint = channel
             .create_network_endpoint("Address", endpoint_address, None)
@@ -572,4 +1053,127 @@ mod test {
             consumed_service_instance
         );
     }
+
+    #[test]
+    fn remove_and_merge_socket_address() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg1").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_ethernet_cluster("Cluster", &package).unwrap();
+        let channel = cluster.create_physical_channel("Channel", None).unwrap();
+        let endpoint_address = NetworkEndpointAddress::IPv4 {
+            address: Some("192.168.0.1".to_string()),
+            address_source: Some(IPv4AddressSource::Fixed),
+            default_gateway: None,
+            network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
+        };
+        let network_endpoint = channel
+            .create_network_endpoint("Address", endpoint_address, None)
+            .unwrap();
+        let udp_port = TpConfig::UdpTp {
+            port_number: Some(1234),
+            port_dynamically_assigned: None,
+        };
+        let tcp_port = TpConfig::TcpTp {
+            port_number: Some(1234),
+            port_dynamically_assigned: None,
+        };
+        let socket_a = channel
+            .create_socket_address(
+                "SocketA",
+                &network_endpoint,
+                &udp_port,
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        let socket_b = channel
+            .create_socket_address(
+                "SocketB",
+                &network_endpoint,
+                &udp_port,
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        let socket_c = channel
+            .create_socket_address(
+                "SocketC",
+                &network_endpoint,
+                &udp_port,
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+        let socket_tcp = channel
+            .create_socket_address(
+                "SocketTcp",
+                &network_endpoint,
+                &tcp_port,
+                SocketAddressType::Unicast(None),
+            )
+            .unwrap();
+
+        let ipdu_identifier_set = system
+            .create_socket_connection_ipdu_identifier_set("IpduIdentifierSet", &package)
+            .unwrap();
+        let pdu = system
+            .create_general_purpose_pdu("Pdu", &package, 1, GeneralPurposePduCategory::Sd)
+            .unwrap();
+        let ipdu_identifier = ipdu_identifier_set
+            .create_socon_ipdu_identifier("IpduIdentifier", &pdu, &channel, Some(1), None, None)
+            .unwrap();
+
+        let ssc_ab = socket_a
+            .create_static_socket_connection("ssc_ab", &socket_b, None, None)
+            .unwrap();
+        let ssc_ac = socket_a
+            .create_static_socket_connection("ssc_ac", &socket_c, None, None)
+            .unwrap();
+        ssc_ac.add_ipdu_identifier(&ipdu_identifier).unwrap();
+        let ssc_ca = socket_c
+            .create_static_socket_connection("ssc_ca", &socket_a, None, None)
+            .unwrap();
+
+        let si_set = system
+            .create_service_instance_collection_set("ServiceInstances", &package)
+            .unwrap();
+        let psi = si_set
+            .create_provided_service_instance("ProvidedInstance", 1, 1, 1, 0)
+            .unwrap();
+        psi.set_local_unicast_address(&socket_a).unwrap();
+
+        // invalid merges
+        assert!(socket_a.clone().merge_into(&socket_a).is_err());
+        assert!(socket_a.clone().merge_into(&socket_tcp).is_err());
+
+        // merge socket A into socket B
+        socket_a.merge_into(&socket_b).unwrap();
+        assert_eq!(channel.socket_addresses().count(), 3);
+        // ssc_ab would connect socket B to itself, so it is removed
+        assert!(ssc_ab.element().path().is_err());
+        // ssc_ac is moved to socket B
+        assert_eq!(socket_b.static_socket_connections().count(), 1);
+        let ssc_bc = socket_b.static_socket_connections().next().unwrap();
+        assert_eq!(ssc_bc.remote_socket(), Some(socket_c.clone()));
+        assert_eq!(ssc_bc.ipdu_identifiers().next(), Some(ipdu_identifier.clone()));
+        // ssc_ca now points to socket B
+        assert_eq!(ssc_ca.remote_socket(), Some(socket_b.clone()));
+        // the service instance uses socket B
+        assert_eq!(
+            psi.local_unicast_addresses().next(),
+            Some(LocalUnicastAddress::Udp(socket_b.clone()))
+        );
+
+        // remove socket B: all connections using it are removed, as well as the unused ipdu identifier
+        socket_b.remove(true).unwrap();
+        assert_eq!(channel.socket_addresses().count(), 2);
+        assert_eq!(socket_c.static_socket_connections().count(), 0);
+        assert_eq!(ipdu_identifier_set.socon_ipdu_identifiers().count(), 0);
+        assert_eq!(psi.local_unicast_addresses().count(), 0);
+        assert!(
+            psi.element()
+                .get_sub_element(ElementName::LocalUnicastAddresss)
+                .is_none()
+        );
+    }
 }
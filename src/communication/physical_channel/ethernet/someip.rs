@@ -4,6 +4,7 @@ use crate::communication::{
 };
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
+    is_used,
 };
 use autosar_data::{Element, ElementName, EnumItem};
 
@@ -1470,6 +1471,27 @@ impl SomeipTpConnection {
         Ok(conn)
     }
 
+    /// remove this `SomeipTpConnection` from the model
+    ///
+    /// The `PduTriggering` of the TP-SDU is created together with the connection, so it is also removed if it is not used elsewhere.
+    pub fn remove(self, deep: bool) -> Result<(), AutosarAbstractionError> {
+        let opt_tp_sdu_triggering = self
+            .element()
+            .get_sub_element(ElementName::TpSduRef)
+            .and_then(|ref_elem| ref_elem.get_reference_target().ok())
+            .and_then(|target| PduTriggering::try_from(target).ok());
+
+        AbstractionElement::remove(self, deep)?;
+
+        if let Some(tp_sdu_triggering) = opt_tp_sdu_triggering
+            && !is_used(tp_sdu_triggering.element())
+        {
+            tp_sdu_triggering.remove(deep)?;
+        }
+
+        Ok(())
+    }
+
     /// get the `SomeipTpConfig` that contains this `SomeipTpConnection`
     pub fn someip_tp_config(&self) -> Result<SomeipTpConfig, AutosarAbstractionError> {
         let parent = self.element().named_parent()?.unwrap();
@@ -1639,6 +1661,19 @@ impl SomeipTpChannel {
             .and_then(|st| st.character_data())
             .and_then(|cdata| cdata.parse_float())
     }
+
+    /// iterate over all `SomeipTpConnection`s that use this `SomeipTpChannel`
+    pub fn someip_tp_connections(&self) -> impl Iterator<Item = SomeipTpConnection> + Send + use<> {
+        let channel = self.clone();
+        self.element()
+            .named_parent()
+            .ok()
+            .flatten()
+            .and_then(|parent| SomeipTpConfig::try_from(parent).ok())
+            .into_iter()
+            .flat_map(|config| config.someip_tp_connections())
+            .filter(move |connection| connection.tp_channel().as_ref() == Some(&channel))
+    }
 }
 
 //##################################################################
@@ -1648,7 +1683,9 @@ mod test {
     use super::*;
     use crate::*;
     use autosar_data::AutosarVersion;
-    use communication::{EthernetVlanInfo, NetworkEndpointAddress, PduCollectionTrigger, SocketAddressType};
+    use communication::{
+        AbstractPdu, EthernetVlanInfo, NetworkEndpointAddress, PduCollectionTrigger, SocketAddressType,
+    };
 
     /// helper function to create a test setup with:
     /// - a system
@@ -1674,6 +1711,8 @@ mod test {
             address_source: None,
             default_gateway: None,
             network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
         };
         let network_endpoint = channel
             .create_network_endpoint("endpoint", network_endpoint_address, None)
@@ -2274,9 +2313,20 @@ mod test {
             .unwrap();
         assert_eq!(tp_config.someip_tp_connections().count(), 1);
         assert_eq!(tp_config.someip_tp_connections().next().unwrap(), tp_conn);
-        assert_eq!(tp_conn.tp_sdu(), Some(isignal_ipdu));
-        assert_eq!(tp_conn.tp_channel(), Some(tp_channel));
+        assert_eq!(tp_conn.tp_sdu(), Some(isignal_ipdu.clone()));
+        assert_eq!(tp_conn.tp_channel(), Some(tp_channel.clone()));
         assert_eq!(tp_conn.transport_pdu_triggering(), Some(transport_pdu_triggering));
         assert_eq!(tp_conn.someip_tp_config().unwrap(), tp_config);
+
+        // navigate from the tp channel and from the large pdu back to the connection
+        assert_eq!(tp_channel.someip_tp_connections().next(), Some(tp_conn.clone()));
+        assert_eq!(isignal_ipdu.someip_tp_connections().next(), Some(tp_conn.clone()));
+
+        // removing the connection also removes the pdu triggering of the tp sdu
+        assert_eq!(isignal_ipdu.pdu_triggerings().len(), 1);
+        tp_conn.remove(false).unwrap();
+        assert_eq!(tp_config.someip_tp_connections().count(), 0);
+        assert_eq!(tp_channel.someip_tp_connections().count(), 0);
+        assert_eq!(isignal_ipdu.pdu_triggerings().len(), 0);
     }
 }
@@ -282,6 +282,46 @@ impl EventHandlerV1 {
         Ok(())
     }
 
+    /// set the event group identifier of this `EventHandler`
+    pub fn set_event_group_identifier(&self, event_group_identifier: u32) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::EventGroupIdentifier)?
+            .set_character_data(u64::from(event_group_identifier))?;
+        Ok(())
+    }
+
+    /// get the event group identifier of this `EventHandler`
+    #[must_use]
+    pub fn event_group_identifier(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::EventGroupIdentifier)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// set or remove the multicast threshold of this `EventHandler`
+    ///
+    /// When the number of subscribers reaches the threshold, the events are sent via multicast instead of unicast
+    pub fn set_multicast_threshold(&self, threshold: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(threshold) = threshold {
+            self.element()
+                .get_or_create_sub_element(ElementName::MulticastThreshold)?
+                .set_character_data(u64::from(threshold))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::MulticastThreshold);
+        }
+        Ok(())
+    }
+
+    /// get the multicast threshold of this `EventHandler`
+    #[must_use]
+    pub fn multicast_threshold(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::MulticastThreshold)?
+            .character_data()?
+            .parse_integer()
+    }
+
     /// add a reference to a `SoAdRoutingGroup` to this `EventHandler`
     pub fn add_routing_group(&self, routing_group: &SoAdRoutingGroup) -> Result<(), AutosarAbstractionError> {
         let elem = self
@@ -445,6 +485,28 @@ impl ConsumedServiceInstanceV1 {
             .filter_map(|ceg| ConsumedEventGroupV1::try_from(ceg).ok())
     }
 
+    /// add a reference to a `SoAdRoutingGroup` to this `ConsumedServiceInstanceV1`
+    ///
+    /// The routing group controls the method communication of the consumed service instance
+    pub fn add_routing_group(&self, routing_group: &SoAdRoutingGroup) -> Result<(), AutosarAbstractionError> {
+        let elem = self
+            .element()
+            .get_or_create_sub_element(ElementName::RoutingGroupRefs)?;
+        elem.create_sub_element(ElementName::RoutingGroupRef)?
+            .set_reference_target(routing_group.element())?;
+        Ok(())
+    }
+
+    /// get the routing groups referenced by this `ConsumedServiceInstanceV1`
+    pub fn routing_groups(&self) -> impl Iterator<Item = SoAdRoutingGroup> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::RoutingGroupRefs)
+            .into_iter()
+            .flat_map(|rgs| rgs.sub_elements())
+            .filter_map(|rgref| rgref.get_reference_target().ok())
+            .filter_map(|rg| SoAdRoutingGroup::try_from(rg).ok())
+    }
+
     /// set the SD client configuration for this `ConsumedServiceInstanceV1`
     pub fn set_sd_client_config(&self, sd_client_config: &SdConfig) -> Result<(), AutosarAbstractionError> {
         // remove any existing SdClientConfig, so that we can start fresh
@@ -662,6 +724,35 @@ impl ConsumedEventGroupV1 {
             .and_then(|cdata| cdata.parse_integer())
     }
 
+    /// add an event multicast address to this `ConsumedEventGroup`
+    ///
+    /// Events are received on this address once the event handler switches to multicast
+    pub fn add_event_multicast_address(&self, address: &SocketAddress) -> Result<(), AutosarAbstractionError> {
+        let Some(application_endpoint) = address.element().get_sub_element(ElementName::ApplicationEndpoint) else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "SocketAddress does not have an ApplicationEndpoint".to_string(),
+            ));
+        };
+        self.element()
+            .get_or_create_sub_element(ElementName::EventMulticastAddresss)?
+            .create_sub_element(ElementName::ApplicationEndpointRefConditional)?
+            .create_sub_element(ElementName::ApplicationEndpointRef)?
+            .set_reference_target(&application_endpoint)?;
+        Ok(())
+    }
+
+    /// get the event multicast addresses of this `ConsumedEventGroup`
+    pub fn event_multicast_addresses(&self) -> impl Iterator<Item = SocketAddress> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::EventMulticastAddresss)
+            .into_iter()
+            .flat_map(|addresses| addresses.sub_elements())
+            .filter_map(|ae_ref_cond| ae_ref_cond.get_sub_element(ElementName::ApplicationEndpointRef))
+            .filter_map(|ae_ref| ae_ref.get_reference_target().ok())
+            .filter_map(|ae| ae.named_parent().ok().flatten())
+            .filter_map(|sa| SocketAddress::try_from(sa).ok())
+    }
+
     /// add a reference to a `SoAdRoutingGroup` to this `ConsumedEventGroup`
     pub fn add_routing_group(&self, routing_group: &SoAdRoutingGroup) -> Result<(), AutosarAbstractionError> {
         let elem = self
@@ -808,6 +899,8 @@ mod test {
             address_source: None,
             default_gateway: None,
             network_mask: None,
+            dns_servers: vec![],
+            ttl: None,
         };
         let network_endpoint = channel
             .create_network_endpoint("endpoint", network_endpoint_address, None)
@@ -892,6 +985,13 @@ mod test {
         assert_eq!(eh.routing_groups().next().unwrap(), rg);
         assert_eq!(eh.consumed_event_groups().count(), 0);
 
+        eh.set_event_group_identifier(0x1234).unwrap();
+        assert_eq!(eh.event_group_identifier(), Some(0x1234));
+        eh.set_multicast_threshold(Some(3)).unwrap();
+        assert_eq!(eh.multicast_threshold(), Some(3));
+        eh.set_multicast_threshold(None).unwrap();
+        assert_eq!(eh.multicast_threshold(), None);
+
         let csi = socket_address
             .create_consumed_service_instance("consumed_service", &psi)
             .unwrap();
@@ -950,6 +1050,12 @@ mod test {
         assert_eq!(ceg.routing_groups().next().unwrap(), rg);
         assert_eq!(ceg.event_handlers().len(), 1);
         assert_eq!(ceg.event_handlers()[0], eh);
+
+        ceg.add_event_multicast_address(&socket_address).unwrap();
+        assert_eq!(ceg.event_multicast_addresses().next(), Some(socket_address));
+
+        csi.add_routing_group(&rg).unwrap();
+        assert_eq!(csi.routing_groups().next(), Some(rg));
     }
 
     #[test]
@@ -1,8 +1,9 @@
 use crate::{
     AbstractionElement, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
     communication::{
-        AbstractPhysicalChannel, FlexrayCluster, FlexrayCommunicationConnector, FlexrayCommunicationCycle,
-        FlexrayFrame, FlexrayFrameTriggering, PhysicalChannel,
+        AbstractFrame, AbstractFrameTriggering, AbstractPhysicalChannel, BusLoad, CycleRepetition, FlexrayCluster,
+        FlexrayCommunicationConnector, FlexrayCommunicationCycle, FlexrayFrame, FlexrayFrameTriggering,
+        PhysicalChannel,
     },
 };
 use autosar_data::{Element, ElementName, EnumItem};
@@ -110,6 +111,59 @@ impl FlexrayPhysicalChannel {
         FlexrayFrameTriggering::new(self, frame, slot_id, timing)
     }
 
+    /// add a trigger for a flexray frame in the dynamic segment of this physical channel
+    ///
+    /// The frame is assigned to the lowest slot id of the dynamic segment that is not yet used by any other
+    /// frame triggering of the channel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_flexray_cluster("Cluster", &package, &FlexrayClusterSettings::default())?;
+    /// let channel = cluster.create_physical_channel("Channel", FlexrayChannelName::A)?;
+    /// let frame = system.create_flexray_frame("Frame", &package, 64)?;
+    /// let timing = FlexrayCommunicationCycle::Repetition {base_cycle: 0, cycle_repetition: CycleRepetition::C1};
+    /// let frame_triggering = channel.trigger_dynamic_frame(&frame, &timing)?;
+    /// assert_eq!(frame_triggering.slot(), Some(51));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The cluster has no dynamic segment, or all dynamic slots are in use
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the frame triggering
+    pub fn trigger_dynamic_frame(
+        &self,
+        frame: &FlexrayFrame,
+        timing: &FlexrayCommunicationCycle,
+    ) -> Result<FlexrayFrameTriggering, AutosarAbstractionError> {
+        let dynamic_slots =
+            self.cluster()?
+                .settings()
+                .dynamic_slot_range()
+                .ok_or(AutosarAbstractionError::InvalidParameter(
+                    "The Flexray cluster does not have a dynamic segment".to_string(),
+                ))?;
+        let used_slots: Vec<u16> = self
+            .frame_triggerings()
+            .filter_map(|frame_triggering| frame_triggering.slot())
+            .collect();
+        let slot_id = dynamic_slots
+            .into_iter()
+            .find(|slot| !used_slots.contains(slot))
+            .ok_or(AutosarAbstractionError::InvalidParameter(
+                "All slots in the dynamic segment are in use".to_string(),
+            ))?;
+        FlexrayFrameTriggering::new(self, frame, slot_id, timing)
+    }
+
     /// iterate over all frame triggerings of this physical channel
     ///
     /// # Example
@@ -138,6 +192,153 @@ impl FlexrayPhysicalChannel {
             .flat_map(|elem| elem.sub_elements())
             .filter_map(|elem| FlexrayFrameTriggering::try_from(elem).ok())
     }
+
+    /// calculate the bus load of the static segment of this Flexray channel
+    ///
+    /// The load is the fraction of the static slots in all communication cycles that are used by frames.
+    /// A frame with an absolute cycle counter uses its slot in one of the cycles, while a frame with a cycle
+    /// repetition uses its slot in every n-th cycle.
+    /// Since the static segment is scheduled statically, the average and worst-case loads are identical.
+    /// Frames in the dynamic segment are not included in the load.
+    /// Frame triggerings without slot or timing information are listed in `frames_without_timing`.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The Flexray cluster does not define any static slots
+    pub fn bus_load(&self) -> Result<BusLoad, AutosarAbstractionError> {
+        let settings = self.cluster()?.settings();
+        if settings.number_of_static_slots == 0 {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The Flexray cluster does not define any static slots".to_string(),
+            ));
+        }
+        let cycle_count = f64::from(settings.cycle_count_max) + 1.0;
+
+        let mut used_slots = 0.0;
+        let mut frames_without_timing = Vec::new();
+        for frame_triggering in self.frame_triggerings() {
+            let (Some(slot), Some(timing)) = (frame_triggering.slot(), frame_triggering.timing()) else {
+                frames_without_timing.push(frame_triggering.into());
+                continue;
+            };
+            if slot == 0 || slot > settings.number_of_static_slots {
+                // the frame is sent in the dynamic segment
+                continue;
+            }
+            used_slots += match timing {
+                FlexrayCommunicationCycle::Counter { .. } => 1.0 / cycle_count,
+                FlexrayCommunicationCycle::Repetition { cycle_repetition, .. } => {
+                    1.0 / f64::from(cycle_repetition_count(cycle_repetition))
+                }
+            };
+        }
+
+        let load = used_slots / f64::from(settings.number_of_static_slots);
+        Ok(BusLoad {
+            average: load,
+            worst_case: load,
+            frames_without_timing,
+        })
+    }
+
+    /// check the slot usage of this Flexray channel
+    ///
+    /// Two frame triggerings conflict if they use the same slot in at least one communication cycle.
+    /// The slot of each frame must be part of either the static or the dynamic segment of the cluster.
+    /// Frames in the static segment must fit into the static payload length of the cluster, and frames in the
+    /// dynamic segment may not be longer than the maximum Flexray payload of 254 bytes.
+    /// Frame triggerings without slot or timing information are not checked.
+    /// An empty list is returned if no problems were found.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] The Flexray cluster of the channel could not be found
+    pub fn check_slot_usage(&self) -> Result<Vec<FlexraySlotIssue>, AutosarAbstractionError> {
+        let settings = self.cluster()?.settings();
+        let static_payload_bytes = u32::from(settings.payload_length_static) * 2;
+        let dynamic_slots = settings.dynamic_slot_range();
+        let mut issues = Vec::new();
+
+        let mut scheduled = Vec::new();
+        for frame_triggering in self.frame_triggerings() {
+            let (Some(slot), Some(timing)) = (frame_triggering.slot(), frame_triggering.timing()) else {
+                continue;
+            };
+            let max_payload_bytes = if slot > 0 && slot <= settings.number_of_static_slots {
+                Some(static_payload_bytes)
+            } else if dynamic_slots.as_ref().is_some_and(|range| range.contains(&slot)) {
+                Some(MAX_FLEXRAY_PAYLOAD_BYTES)
+            } else {
+                None
+            };
+            if let Some(max_payload_bytes) = max_payload_bytes {
+                if frame_triggering
+                    .frame()
+                    .and_then(|frame| frame.length())
+                    .is_some_and(|length| length > max_payload_bytes)
+                {
+                    issues.push(FlexraySlotIssue::FrameTooLong(frame_triggering.clone()));
+                }
+            } else {
+                issues.push(FlexraySlotIssue::InvalidSlot(frame_triggering.clone()));
+            }
+            scheduled.push((frame_triggering, slot, used_cycles(timing, settings.cycle_count_max)));
+        }
+
+        for (idx, (frame_triggering_a, slot_a, cycles_a)) in scheduled.iter().enumerate() {
+            for (frame_triggering_b, slot_b, cycles_b) in &scheduled[idx + 1..] {
+                if slot_a == slot_b && cycles_a & cycles_b != 0 {
+                    issues.push(FlexraySlotIssue::SlotConflict(
+                        frame_triggering_a.clone(),
+                        frame_triggering_b.clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+}
+
+// the maximum payload of a Flexray frame in bytes
+const MAX_FLEXRAY_PAYLOAD_BYTES: u32 = 254;
+
+// get a bit mask of the communication cycles in which a frame with the given timing is sent
+fn used_cycles(timing: FlexrayCommunicationCycle, cycle_count_max: u8) -> u64 {
+    let cycle_count = (u32::from(cycle_count_max) + 1).min(64);
+    match timing {
+        FlexrayCommunicationCycle::Counter { cycle_counter } => {
+            if u32::from(cycle_counter) < cycle_count {
+                1 << cycle_counter
+            } else {
+                0
+            }
+        }
+        FlexrayCommunicationCycle::Repetition {
+            base_cycle,
+            cycle_repetition,
+        } => (u32::from(base_cycle)..cycle_count)
+            .step_by(usize::from(cycle_repetition_count(cycle_repetition)))
+            .fold(0, |mask, cycle| mask | (1 << cycle)),
+    }
+}
+
+// the number of cycles between two transmissions of a frame with the given cycle repetition
+fn cycle_repetition_count(cycle_repetition: CycleRepetition) -> u8 {
+    match cycle_repetition {
+        CycleRepetition::C1 => 1,
+        CycleRepetition::C2 => 2,
+        CycleRepetition::C4 => 4,
+        CycleRepetition::C5 => 5,
+        CycleRepetition::C8 => 8,
+        CycleRepetition::C10 => 10,
+        CycleRepetition::C16 => 16,
+        CycleRepetition::C20 => 20,
+        CycleRepetition::C32 => 32,
+        CycleRepetition::C40 => 40,
+        CycleRepetition::C50 => 50,
+        CycleRepetition::C64 => 64,
+    }
 }
 
 impl From<FlexrayPhysicalChannel> for PhysicalChannel {
@@ -152,6 +353,19 @@ impl AbstractPhysicalChannel for FlexrayPhysicalChannel {
 
 //##################################################################
 
+/// A problem in the slot usage of a Flexray channel, as found by [`FlexrayPhysicalChannel::check_slot_usage`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlexraySlotIssue {
+    /// The two frame triggerings use the same slot in at least one communication cycle
+    SlotConflict(FlexrayFrameTriggering, FlexrayFrameTriggering),
+    /// The frame is longer than the maximum payload length of its segment
+    FrameTooLong(FlexrayFrameTriggering),
+    /// The slot of the frame is neither part of the static segment nor of the dynamic segment
+    InvalidSlot(FlexrayFrameTriggering),
+}
+
+//##################################################################
+
 /// A flexray cluster may contain the channels A and/or B.
 ///
 /// This enum is an abstraction over the \<CHANNEL-NAME\> element.
@@ -169,7 +383,10 @@ pub enum FlexrayChannelName {
 mod test {
     use crate::{
         AbstractionElement, AutosarModelAbstraction, ByteOrder, SystemCategory,
-        communication::{AbstractFrame, FlexrayChannelName, FlexrayClusterSettings},
+        communication::{
+            AbstractFrame, CycleRepetition, FlexrayChannelName, FlexrayClusterSettings, FlexrayCommunicationCycle,
+            FlexraySlotIssue,
+        },
     };
     use autosar_data::{AutosarVersion, ElementName};
 
@@ -231,4 +448,137 @@ mod test {
         // the PDU was removed, because it was unused and deep removal was requested
         assert!(isignal_ipdu.element().parent().is_err());
     }
+
+    #[test]
+    fn bus_load() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        // the default settings contain 50 static slots and 64 cycles
+        let settings = FlexrayClusterSettings::default();
+        let cluster = system.create_flexray_cluster("FlxCluster", &pkg, &settings).unwrap();
+        let channel = cluster
+            .create_physical_channel("channel_name", FlexrayChannelName::A)
+            .unwrap();
+
+        let frame_1 = system.create_flexray_frame("Frame1", &pkg, 8).unwrap();
+        let timing = FlexrayCommunicationCycle::Repetition {
+            base_cycle: 0,
+            cycle_repetition: CycleRepetition::C1,
+        };
+        channel.trigger_frame(&frame_1, 1, &timing).unwrap();
+        let frame_2 = system.create_flexray_frame("Frame2", &pkg, 8).unwrap();
+        let timing = FlexrayCommunicationCycle::Counter { cycle_counter: 3 };
+        channel.trigger_frame(&frame_2, 2, &timing).unwrap();
+        // a frame in the dynamic segment is not included in the load
+        let frame_3 = system.create_flexray_frame("Frame3", &pkg, 8).unwrap();
+        channel.trigger_frame(&frame_3, 100, &timing).unwrap();
+
+        let bus_load = channel.bus_load().unwrap();
+        let expected = (1.0 + 1.0 / 64.0) / 50.0;
+        assert!((bus_load.average - expected).abs() < 1e-9);
+        assert_eq!(bus_load.average, bus_load.worst_case);
+        assert!(bus_load.frames_without_timing.is_empty());
+    }
+
+    #[test]
+    fn check_slot_usage() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        // the default settings contain 50 static slots with a payload of 32 words (64 bytes) and 64 cycles
+        let settings = FlexrayClusterSettings::default();
+        let cluster = system.create_flexray_cluster("FlxCluster", &pkg, &settings).unwrap();
+        let channel = cluster
+            .create_physical_channel("channel_name", FlexrayChannelName::A)
+            .unwrap();
+
+        // frame 1 is sent in cycles 0, 4, 8, ...; frame 2 in cycles 2, 6, 10, ...
+        let frame_1 = system.create_flexray_frame("Frame1", &pkg, 8).unwrap();
+        let timing_1 = FlexrayCommunicationCycle::Repetition {
+            base_cycle: 0,
+            cycle_repetition: CycleRepetition::C4,
+        };
+        channel.trigger_frame(&frame_1, 1, &timing_1).unwrap();
+        let frame_2 = system.create_flexray_frame("Frame2", &pkg, 8).unwrap();
+        let timing_2 = FlexrayCommunicationCycle::Repetition {
+            base_cycle: 2,
+            cycle_repetition: CycleRepetition::C4,
+        };
+        let ft_2 = channel.trigger_frame(&frame_2, 1, &timing_2).unwrap();
+        assert!(channel.check_slot_usage().unwrap().is_empty());
+
+        // frame 3 is sent in cycle 6, which is also used by frame 2
+        let frame_3 = system.create_flexray_frame("Frame3", &pkg, 8).unwrap();
+        let timing_3 = FlexrayCommunicationCycle::Counter { cycle_counter: 6 };
+        let ft_3 = channel.trigger_frame(&frame_3, 1, &timing_3).unwrap();
+        assert_eq!(
+            channel.check_slot_usage().unwrap(),
+            vec![FlexraySlotIssue::SlotConflict(ft_2.clone(), ft_3.clone())]
+        );
+        ft_3.set_slot(2).unwrap();
+        assert!(channel.check_slot_usage().unwrap().is_empty());
+
+        // frame 4 is too long for the static segment, but it is allowed in the dynamic segment
+        let frame_4 = system.create_flexray_frame("Frame4", &pkg, 100).unwrap();
+        let ft_4 = channel.trigger_frame(&frame_4, 3, &timing_1).unwrap();
+        assert_eq!(
+            channel.check_slot_usage().unwrap(),
+            vec![FlexraySlotIssue::FrameTooLong(ft_4.clone())]
+        );
+        ft_4.set_slot(60).unwrap();
+        assert!(channel.check_slot_usage().unwrap().is_empty());
+
+        // slot 300 is beyond the end of the dynamic segment (slots 51 - 235)
+        ft_4.set_slot(300).unwrap();
+        assert_eq!(
+            channel.check_slot_usage().unwrap(),
+            vec![FlexraySlotIssue::InvalidSlot(ft_4.clone())]
+        );
+    }
+
+    #[test]
+    fn dynamic_segment() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let settings = FlexrayClusterSettings::default();
+        let cluster = system.create_flexray_cluster("FlxCluster", &pkg, &settings).unwrap();
+        let channel = cluster
+            .create_physical_channel("channel_name", FlexrayChannelName::A)
+            .unwrap();
+        let timing = FlexrayCommunicationCycle::Repetition {
+            base_cycle: 0,
+            cycle_repetition: CycleRepetition::C1,
+        };
+
+        let frame_1 = system.create_flexray_frame("Frame1", &pkg, 200).unwrap();
+        let ft_1 = channel.trigger_dynamic_frame(&frame_1, &timing).unwrap();
+        assert_eq!(ft_1.slot(), Some(51));
+        let frame_2 = system.create_flexray_frame("Frame2", &pkg, 255).unwrap();
+        let ft_2 = channel.trigger_dynamic_frame(&frame_2, &timing).unwrap();
+        assert_eq!(ft_2.slot(), Some(52));
+        assert_eq!(
+            channel.check_slot_usage().unwrap(),
+            vec![FlexraySlotIssue::FrameTooLong(ft_2.clone())]
+        );
+
+        assert_eq!(ft_1.allow_dynamic_lsdu_length(), None);
+        ft_1.set_allow_dynamic_lsdu_length(Some(true)).unwrap();
+        assert_eq!(ft_1.allow_dynamic_lsdu_length(), Some(true));
+        ft_1.set_allow_dynamic_lsdu_length(None).unwrap();
+        assert_eq!(ft_1.allow_dynamic_lsdu_length(), None);
+
+        // a cluster without minislots has no dynamic segment
+        let settings = FlexrayClusterSettings {
+            number_of_minislots: 0,
+            ..Default::default()
+        };
+        let cluster_2 = system.create_flexray_cluster("FlxCluster2", &pkg, &settings).unwrap();
+        let channel_2 = cluster_2
+            .create_physical_channel("channel_name", FlexrayChannelName::A)
+            .unwrap();
+        let result = channel_2.trigger_dynamic_frame(&frame_1, &timing);
+        assert!(result.is_err());
+    }
 }
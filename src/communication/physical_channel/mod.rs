@@ -1,6 +1,11 @@
-use crate::communication::{AbstractCommunicationConnector, CommunicationConnector, ISignalTriggering, PduTriggering};
+use crate::communication::{
+    AbstractCommunicationConnector, AbstractFrame, AbstractFrameTriggering, CanFrameTriggering, CommunicationConnector,
+    CommunicationController, CommunicationDirection, CyclicTiming, FlexrayFrameTriggering, FrameTriggering, Gateway,
+    ISignalToIPduMapping, ISignalTriggering, IpduTiming, LinFrameTriggering, Pdu, PduTriggering, SignalPdu,
+};
 use crate::{AbstractionElement, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement};
-use autosar_data::{Element, ElementName};
+use autosar_data::{Element, ElementName, WeakElement};
+use std::collections::HashSet;
 
 mod can;
 mod ethernet;
@@ -19,6 +24,39 @@ pub trait AbstractPhysicalChannel: AbstractionElement + Into<PhysicalChannel> {
     /// the type of communication connector used by this physical channel
     type CommunicationConnectorType: AbstractCommunicationConnector;
 
+    /// iterate over all `FrameTriggerings` of this physical channel
+    ///
+    /// The concrete channel types also provide an inherent `frame_triggerings` method, which returns the
+    /// specific frame triggering type of the bus, e.g. `CanFrameTriggering`. This trait method can be used
+    /// in generic code. Ethernet channels do not contain any frame triggerings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, communication::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_can_cluster("Cluster", &package, None)?;
+    /// # let can_channel = cluster.create_physical_channel("Channel")?;
+    /// # let frame = system.create_can_frame("Frame", &package, 8)?;
+    /// can_channel.trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)?;
+    /// fn count_frames<T: AbstractPhysicalChannel>(channel: &T) -> usize {
+    ///     channel.frame_triggerings().count()
+    /// }
+    /// assert_eq!(count_frames(&can_channel), 1);
+    /// # Ok(())}
+    /// ```
+    fn frame_triggerings(&self) -> impl Iterator<Item = FrameTriggering> + Send + use<Self> {
+        self.element()
+            .get_sub_element(ElementName::FrameTriggerings)
+            .into_iter()
+            .flat_map(|triggerings| triggerings.sub_elements())
+            .filter_map(|triggering| FrameTriggering::try_from(triggering).ok())
+    }
+
     /// iterate over all `PduTriggerings` of this physical channel
     fn pdu_triggerings(&self) -> impl Iterator<Item = PduTriggering> + Send + use<Self> {
         self.element()
@@ -70,6 +108,38 @@ pub trait AbstractPhysicalChannel: AbstractionElement + Into<PhysicalChannel> {
             })
     }
 
+    /// iterate over all ECUs that are connected to this physical channel
+    ///
+    /// Each `EcuInstance` is returned together with the `CommunicationController` that connects it to the channel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, communication::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_can_cluster("Cluster", &package, None)?;
+    /// # let can_channel = cluster.create_physical_channel("Channel")?;
+    /// # let ecu = system.create_ecu_instance("ECU", &package)?;
+    /// # let can_controller = ecu.create_can_communication_controller("Controller")?;
+    /// can_controller.connect_physical_channel("Connector", &can_channel)?;
+    /// for (ecu, controller) in can_channel.ecus() {
+    ///    println!("ECU: {:?}, controller: {:?}", ecu.name(), controller.name());
+    /// }
+    /// # assert_eq!(can_channel.ecus().count(), 1);
+    /// # Ok(())}
+    /// ```
+    fn ecus(&self) -> impl Iterator<Item = (EcuInstance, CommunicationController)> + Send + use<Self> {
+        self.connectors().filter_map(|connector| {
+            let ecu = connector.ecu_instance().ok()?;
+            let controller = CommunicationController::try_from(connector.controller().ok()?.element().clone()).ok()?;
+            Some((ecu, controller))
+        })
+    }
+
     /// get the connector element between this channel and an ecu
     #[must_use]
     fn ecu_connector(&self, ecu_instance: &EcuInstance) -> Option<Self::CommunicationConnectorType> {
@@ -85,6 +155,290 @@ pub trait AbstractPhysicalChannel: AbstractionElement + Into<PhysicalChannel> {
 
         None
     }
+
+    /// check the communication directions of the ports of all triggerings in this physical channel
+    ///
+    /// The frame triggerings, pdu triggerings and signal triggerings are checked. Each of them must have exactly
+    /// one sending ECU and at least one receiving ECU, and an ECU may not both send and receive the same triggering,
+    /// unless it is a gateway. Triggerings that are not connected to any ECU are not checked.
+    /// An empty list is returned if no problems were found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, communication::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_can_cluster("Cluster", &package, None)?;
+    /// # let can_channel = cluster.create_physical_channel("Channel")?;
+    /// # let ecu = system.create_ecu_instance("ECU", &package)?;
+    /// # ecu.create_can_communication_controller("Controller")?.connect_physical_channel("Connector", &can_channel)?;
+    /// let frame = system.create_can_frame("Frame", &package, 8)?;
+    /// let frame_triggering = can_channel.trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)?;
+    /// frame_triggering.connect_to_ecu(&ecu, CommunicationDirection::Out)?;
+    /// let issues = can_channel.check_port_directions();
+    /// assert_eq!(issues, vec![PortDirectionIssue::NoReceiver(frame_triggering.into())]);
+    /// # Ok(())}
+    /// ```
+    #[must_use]
+    fn check_port_directions(&self) -> Vec<PortDirectionIssue> {
+        let frame_triggerings = self.frame_triggerings().map(|frame_triggering| {
+            let ports = frame_triggering
+                .frame_ports()
+                .filter_map(|port| Some((port.ecu().ok()?, port.communication_direction())))
+                .collect();
+            (CommunicationTriggering::Frame(frame_triggering), ports)
+        });
+        let pdu_triggerings = self.pdu_triggerings().map(|pdu_triggering| {
+            let ports = pdu_triggering
+                .pdu_ports()
+                .filter_map(|port| Some((port.ecu().ok()?, port.communication_direction())))
+                .collect();
+            (CommunicationTriggering::Pdu(pdu_triggering), ports)
+        });
+        let signal_triggerings = self.signal_triggerings().map(|signal_triggering| {
+            let ports = signal_triggering
+                .signal_ports()
+                .filter_map(|port| Some((port.ecu().ok()?, port.communication_direction())))
+                .collect();
+            (CommunicationTriggering::Signal(signal_triggering), ports)
+        });
+
+        let mut issues = Vec::new();
+        for (triggering, ports) in frame_triggerings.chain(pdu_triggerings).chain(signal_triggerings) {
+            check_triggering_ports(triggering, ports, &mut issues);
+        }
+        issues
+    }
+
+    /// check the signal triggerings of this physical channel for incomplete triggering chains
+    ///
+    /// Every signal triggering must reference a signal or signal group and must be contained in a `PduTriggering`,
+    /// whose PDU maps the triggered signal. In the other direction, every signal that is mapped to a triggered PDU
+    /// must have a signal triggering in the `PduTriggering`.
+    /// An empty list is returned if no problems were found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, communication::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_can_cluster("Cluster", &package, None)?;
+    /// # let can_channel = cluster.create_physical_channel("Channel")?;
+    /// # let frame = system.create_can_frame("Frame", &package, 8)?;
+    /// # let pdu = system.create_isignal_ipdu("Pdu", &package, 8)?;
+    /// # let syssignal = package.create_system_signal("Signal")?;
+    /// # let signal = system.create_isignal("Signal", &package, 8, &syssignal, None)?;
+    /// # frame.map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)?;
+    /// # pdu.map_signal(&signal, 0, ByteOrder::MostSignificantByteLast, None, TransferProperty::Triggered)?;
+    /// let frame_triggering = can_channel.trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)?;
+    /// let pdu_triggering = frame_triggering.pdu_triggerings().next().unwrap();
+    /// let signal_triggering = pdu_triggering.signal_triggerings().next().unwrap();
+    /// pdu_triggering.remove_signal_triggering(&signal_triggering)?;
+    /// let issues = can_channel.check_signal_triggerings();
+    /// assert!(issues.contains(&SignalTriggeringIssue::Orphaned(signal_triggering)));
+    /// # Ok(())}
+    /// ```
+    #[must_use]
+    fn check_signal_triggerings(&self) -> Vec<SignalTriggeringIssue> {
+        let mut issues = Vec::new();
+        let mut contained_triggerings = HashSet::new();
+
+        for pdu_triggering in self.pdu_triggerings() {
+            let signal_triggerings: Vec<_> = pdu_triggering.signal_triggerings().collect();
+            let mappings: Vec<_> = match pdu_triggering.pdu() {
+                Some(Pdu::ISignalIPdu(pdu)) => pdu.mapped_signals().collect(),
+                Some(Pdu::NmPdu(pdu)) => pdu.mapped_signals().collect(),
+                _ => Vec::new(),
+            };
+
+            for signal_triggering in &signal_triggerings {
+                contained_triggerings.insert(signal_triggering.clone());
+                let is_mapped = if let Some(signal) = signal_triggering.signal() {
+                    mappings
+                        .iter()
+                        .any(|mapping| mapping.signal().as_ref() == Some(&signal))
+                } else if let Some(signal_group) = signal_triggering.signal_group() {
+                    mappings
+                        .iter()
+                        .any(|mapping| mapping.signal_group().as_ref() == Some(&signal_group))
+                } else {
+                    // reported as MissingSignal
+                    true
+                };
+                if !is_mapped {
+                    issues.push(SignalTriggeringIssue::NotMappedInPdu(
+                        signal_triggering.clone(),
+                        pdu_triggering.clone(),
+                    ));
+                }
+            }
+
+            for mapping in mappings {
+                let is_triggered = if let Some(signal) = mapping.signal() {
+                    signal_triggerings
+                        .iter()
+                        .any(|st| st.signal().as_ref() == Some(&signal))
+                } else if let Some(signal_group) = mapping.signal_group() {
+                    signal_triggerings
+                        .iter()
+                        .any(|st| st.signal_group().as_ref() == Some(&signal_group))
+                } else {
+                    true
+                };
+                if !is_triggered {
+                    issues.push(SignalTriggeringIssue::MissingSignalTriggering(
+                        pdu_triggering.clone(),
+                        mapping,
+                    ));
+                }
+            }
+        }
+
+        for signal_triggering in self.signal_triggerings() {
+            if signal_triggering.signal().is_none() && signal_triggering.signal_group().is_none() {
+                issues.push(SignalTriggeringIssue::MissingSignal(signal_triggering.clone()));
+            }
+            if !contained_triggerings.contains(&signal_triggering) {
+                issues.push(SignalTriggeringIssue::Orphaned(signal_triggering));
+            }
+        }
+
+        issues
+    }
+}
+
+// check the communication directions of the ports of a single triggering
+fn check_triggering_ports(
+    triggering: CommunicationTriggering,
+    ports: Vec<(EcuInstance, Option<CommunicationDirection>)>,
+    issues: &mut Vec<PortDirectionIssue>,
+) {
+    if ports.is_empty() {
+        return;
+    }
+
+    let mut senders = Vec::new();
+    let mut receivers = Vec::new();
+    for (ecu, direction) in ports {
+        match direction {
+            Some(CommunicationDirection::Out) => senders.push(ecu),
+            Some(CommunicationDirection::In) => receivers.push(ecu),
+            None => issues.push(PortDirectionIssue::MissingDirection(triggering.clone(), ecu)),
+        }
+    }
+
+    if senders.is_empty() {
+        issues.push(PortDirectionIssue::NoSender(triggering.clone()));
+    } else if senders.len() > 1 {
+        issues.push(PortDirectionIssue::MultipleSenders(triggering.clone(), senders.clone()));
+    }
+    if receivers.is_empty() {
+        issues.push(PortDirectionIssue::NoReceiver(triggering.clone()));
+    }
+    for ecu in senders {
+        if receivers.contains(&ecu) && !is_gateway_ecu(&ecu) {
+            issues.push(PortDirectionIssue::SendAndReceive(triggering.clone(), ecu));
+        }
+    }
+}
+
+// an ECU is a gateway if a Gateway refers to it
+fn is_gateway_ecu(ecu: &EcuInstance) -> bool {
+    let (Ok(model), Ok(path)) = (ecu.element().model(), ecu.element().path()) else {
+        return false;
+    };
+    model
+        .get_references_to(&path)
+        .iter()
+        .filter_map(WeakElement::upgrade)
+        .filter_map(|reference| Gateway::try_from(reference.named_parent().ok()??).ok())
+        .any(|gateway| gateway.ecu().as_ref() == Some(ecu))
+}
+
+//##################################################################
+
+/// A triggering of a frame, pdu or signal in a physical channel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommunicationTriggering {
+    /// a [`FrameTriggering`]
+    Frame(FrameTriggering),
+    /// a [`PduTriggering`]
+    Pdu(PduTriggering),
+    /// an [`ISignalTriggering`]
+    Signal(ISignalTriggering),
+}
+
+impl From<FrameTriggering> for CommunicationTriggering {
+    fn from(frame_triggering: FrameTriggering) -> Self {
+        CommunicationTriggering::Frame(frame_triggering)
+    }
+}
+
+impl From<CanFrameTriggering> for CommunicationTriggering {
+    fn from(frame_triggering: CanFrameTriggering) -> Self {
+        CommunicationTriggering::Frame(FrameTriggering::Can(frame_triggering))
+    }
+}
+
+impl From<FlexrayFrameTriggering> for CommunicationTriggering {
+    fn from(frame_triggering: FlexrayFrameTriggering) -> Self {
+        CommunicationTriggering::Frame(FrameTriggering::Flexray(frame_triggering))
+    }
+}
+
+impl From<LinFrameTriggering> for CommunicationTriggering {
+    fn from(frame_triggering: LinFrameTriggering) -> Self {
+        CommunicationTriggering::Frame(FrameTriggering::Lin(frame_triggering))
+    }
+}
+
+impl From<PduTriggering> for CommunicationTriggering {
+    fn from(pdu_triggering: PduTriggering) -> Self {
+        CommunicationTriggering::Pdu(pdu_triggering)
+    }
+}
+
+impl From<ISignalTriggering> for CommunicationTriggering {
+    fn from(signal_triggering: ISignalTriggering) -> Self {
+        CommunicationTriggering::Signal(signal_triggering)
+    }
+}
+
+/// A problem in the communication directions of the ports of a triggering,
+/// as found by [`AbstractPhysicalChannel::check_port_directions`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PortDirectionIssue {
+    /// No ECU sends the triggering
+    NoSender(CommunicationTriggering),
+    /// More than one ECU sends the triggering
+    MultipleSenders(CommunicationTriggering, Vec<EcuInstance>),
+    /// No ECU receives the triggering
+    NoReceiver(CommunicationTriggering),
+    /// The ECU both sends and receives the triggering, but it is not a gateway
+    SendAndReceive(CommunicationTriggering, EcuInstance),
+    /// The port of the ECU has no communication direction
+    MissingDirection(CommunicationTriggering, EcuInstance),
+}
+
+/// A problem in the triggering chain of a signal, as found by [`AbstractPhysicalChannel::check_signal_triggerings`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignalTriggeringIssue {
+    /// The signal triggering is not contained in any `PduTriggering`
+    Orphaned(ISignalTriggering),
+    /// The signal triggering does not reference a signal or signal group
+    MissingSignal(ISignalTriggering),
+    /// The signal triggering is contained in the `PduTriggering`, but its signal is not mapped to the PDU
+    NotMappedInPdu(ISignalTriggering, PduTriggering),
+    /// The signal of the mapping is not triggered by any signal triggering of the `PduTriggering`
+    MissingSignalTriggering(PduTriggering, ISignalToIPduMapping),
 }
 
 //##################################################################
@@ -152,6 +506,56 @@ impl PhysicalChannel {
 
 //##################################################################
 
+/// The result of a bus load calculation
+///
+/// It is returned by [`CanPhysicalChannel::bus_load`] and [`FlexrayPhysicalChannel::bus_load`].
+/// The load values are fractions of the available bandwidth, i.e. 1.0 means that the bus is fully loaded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusLoad {
+    /// the average bus load
+    pub average: f64,
+    /// the worst-case bus load
+    pub worst_case: f64,
+    /// frame triggerings whose frames have no usable timing; they are not included in the load
+    pub frames_without_timing: Vec<FrameTriggering>,
+}
+
+// get the shortest cyclic transmission period of all ISignalIPdus that are mapped to the frame
+fn frame_cycle_time<T: AbstractFrame>(frame: &T) -> Option<f64> {
+    frame_cyclic_timing(frame).map(|cyclic_timing| cyclic_timing.time_period)
+}
+
+// get the cyclic timing with the shortest period of all ISignalIPdus that are mapped to the frame
+fn frame_cyclic_timing<T: AbstractFrame>(frame: &T) -> Option<CyclicTiming> {
+    frame_ipdu_timings(frame)
+        .flat_map(|timing| {
+            [
+                timing.transmission_mode_true_timing,
+                timing.transmission_mode_false_timing,
+            ]
+        })
+        .flatten()
+        .filter_map(|tm_timing| tm_timing.cyclic_timing)
+        .filter(|cyclic_timing| cyclic_timing.time_period > 0.0)
+        .reduce(|shortest, cyclic_timing| {
+            if cyclic_timing.time_period < shortest.time_period {
+                cyclic_timing
+            } else {
+                shortest
+            }
+        })
+}
+
+// get the timing specifications of all ISignalIPdus that are mapped to the frame
+fn frame_ipdu_timings<T: AbstractFrame>(frame: &T) -> impl Iterator<Item = IpduTiming> + use<T> {
+    frame.mapped_pdus().filter_map(|mapping| match mapping.pdu() {
+        Some(Pdu::ISignalIPdu(ipdu)) => ipdu.timing(),
+        _ => None,
+    })
+}
+
+//##################################################################
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -196,7 +600,7 @@ mod test {
             .unwrap();
 
         assert_eq!(channel.frame_triggerings().count(), 1);
-        assert_eq!(channel.frame_triggerings().next(), Some(frame_triggering));
+        assert_eq!(channel.frame_triggerings().next(), Some(frame_triggering.clone()));
         assert_eq!(channel.pdu_triggerings().count(), 1);
         assert_eq!(
             channel.pdu_triggerings().next().unwrap().pdu().unwrap(),
@@ -204,7 +608,191 @@ mod test {
         );
         assert_eq!(channel.signal_triggerings().count(), 1);
 
+        // the trait method returns all frame triggerings, without matching on the channel type
+        let generic_channel = PhysicalChannel::Can(channel.clone());
+        assert_eq!(
+            generic_channel.frame_triggerings().next(),
+            Some(FrameTriggering::Can(frame_triggering))
+        );
+        assert_eq!(generic_channel.pdu_triggerings().count(), 1);
+        assert_eq!(generic_channel.signal_triggerings().count(), 1);
+
         assert_eq!(channel.connectors().count(), 1);
         assert_eq!(channel.ecu_connector(&ecu).unwrap(), connector);
     }
+
+    #[test]
+    fn check_port_directions() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let cluster = system.create_can_cluster("CanCluster", &pkg, None).unwrap();
+        let channel = cluster.create_physical_channel("channel_name").unwrap();
+        let ecus: Vec<_> = ["EcuA", "EcuB", "EcuC"]
+            .iter()
+            .map(|name| {
+                let ecu = system.create_ecu_instance(name, &pkg).unwrap();
+                ecu.create_can_communication_controller("Controller")
+                    .unwrap()
+                    .connect_physical_channel("Connector", &channel)
+                    .unwrap();
+                ecu
+            })
+            .collect();
+
+        // one sender and one receiver: no issues
+        let frame_1 = system.create_can_frame("Frame1", &pkg, 8).unwrap();
+        let ft_1 = channel
+            .trigger_frame(&frame_1, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        ft_1.connect_to_ecu(&ecus[0], CommunicationDirection::Out).unwrap();
+        ft_1.connect_to_ecu(&ecus[1], CommunicationDirection::In).unwrap();
+        // triggerings without ports are not checked
+        let frame_2 = system.create_can_frame("Frame2", &pkg, 8).unwrap();
+        let ft_2 = channel
+            .trigger_frame(&frame_2, 0x200, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        assert!(channel.check_port_directions().is_empty());
+
+        // two senders, and one of them also receives the frame
+        ft_2.connect_to_ecu(&ecus[0], CommunicationDirection::Out).unwrap();
+        ft_2.connect_to_ecu(&ecus[1], CommunicationDirection::Out).unwrap();
+        ft_2.connect_to_ecu(&ecus[1], CommunicationDirection::In).unwrap();
+        let triggering: CommunicationTriggering = ft_2.clone().into();
+        assert_eq!(
+            channel.check_port_directions(),
+            vec![
+                PortDirectionIssue::MultipleSenders(triggering.clone(), vec![ecus[0].clone(), ecus[1].clone()]),
+                PortDirectionIssue::SendAndReceive(triggering.clone(), ecus[1].clone()),
+            ]
+        );
+
+        // a gateway may send and receive the same frame
+        system.create_gateway("Gateway", &pkg, &ecus[1]).unwrap();
+        assert_eq!(
+            channel.check_port_directions(),
+            vec![PortDirectionIssue::MultipleSenders(
+                triggering,
+                vec![ecus[0].clone(), ecus[1].clone()]
+            )]
+        );
+
+        // a frame without receivers
+        let frame_3 = system.create_can_frame("Frame3", &pkg, 8).unwrap();
+        let ft_3 = channel
+            .trigger_frame(&frame_3, 0x300, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        ft_3.connect_to_ecu(&ecus[2], CommunicationDirection::Out).unwrap();
+        assert!(
+            channel
+                .check_port_directions()
+                .contains(&PortDirectionIssue::NoReceiver(ft_3.into()))
+        );
+    }
+
+    #[test]
+    fn check_signal_triggerings() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let cluster = system.create_can_cluster("CanCluster", &pkg, None).unwrap();
+        let channel = cluster.create_physical_channel("channel_name").unwrap();
+        let ecu = system.create_ecu_instance("Ecu", &pkg).unwrap();
+        ecu.create_can_communication_controller("Controller")
+            .unwrap()
+            .connect_physical_channel("Connector", &channel)
+            .unwrap();
+
+        let frame = system.create_can_frame("Frame", &pkg, 8).unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &pkg, 8).unwrap();
+        let signals: Vec<_> = ["Sig1", "Sig2", "Sig3"]
+            .iter()
+            .map(|name| {
+                let syssignal = pkg.create_system_signal(name).unwrap();
+                system.create_isignal(name, &pkg, 8, &syssignal, None).unwrap()
+            })
+            .collect();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        for (idx, signal) in signals.iter().take(2).enumerate() {
+            pdu.map_signal(
+                signal,
+                idx as u32 * 8,
+                ByteOrder::MostSignificantByteLast,
+                None,
+                TransferProperty::Triggered,
+            )
+            .unwrap();
+        }
+        let ft = channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        ft.connect_to_ecu(&ecu, CommunicationDirection::Out).unwrap();
+        assert!(channel.check_signal_triggerings().is_empty());
+
+        let pt = ft.pdu_triggerings().next().unwrap();
+        let st_1 = pt
+            .signal_triggerings()
+            .find(|st| st.signal().as_ref() == Some(&signals[0]))
+            .unwrap();
+        assert_eq!(st_1.pdu_triggerings(), vec![pt.clone()]);
+        assert!(st_1.signal_group().is_none());
+        let signal_port = st_1.signal_ports().next().unwrap();
+        assert_eq!(signal_port.signal_triggering(), Some(st_1.clone()));
+        signal_port
+            .set_communication_direction(CommunicationDirection::In)
+            .unwrap();
+        assert_eq!(signal_port.communication_direction(), Some(CommunicationDirection::In));
+
+        // disconnect the signal triggering from the pdu triggering
+        pt.remove_signal_triggering(&st_1).unwrap();
+        assert!(st_1.pdu_triggerings().is_empty());
+        let mapping_1 = pdu
+            .mapped_signals()
+            .find(|mapping| mapping.signal().as_ref() == Some(&signals[0]))
+            .unwrap();
+        assert_eq!(
+            channel.check_signal_triggerings(),
+            vec![
+                SignalTriggeringIssue::MissingSignalTriggering(pt.clone(), mapping_1),
+                SignalTriggeringIssue::Orphaned(st_1.clone()),
+            ]
+        );
+
+        // reconnect it; connecting it twice has no effect
+        pt.add_signal_triggering(&st_1).unwrap();
+        pt.add_signal_triggering(&st_1).unwrap();
+        assert_eq!(pt.signal_triggerings().count(), 2);
+        assert!(channel.check_signal_triggerings().is_empty());
+
+        // a signal triggering for a signal that is not mapped to the pdu
+        let st_3 = pt.create_signal_triggering(&signals[2]).unwrap();
+        assert_eq!(st_3.signal_ports().count(), 1);
+        assert_eq!(
+            channel.check_signal_triggerings(),
+            vec![SignalTriggeringIssue::NotMappedInPdu(st_3.clone(), pt.clone())]
+        );
+
+        // removing the signal triggering also removes its reference from the pdu triggering
+        st_3.remove(false).unwrap();
+        assert!(channel.check_signal_triggerings().is_empty());
+        let triggering_refs = pt.element().get_sub_element(ElementName::ISignalTriggerings).unwrap();
+        assert_eq!(triggering_refs.sub_elements().count(), 2);
+
+        // signal triggerings of other channels can't be added
+        let cluster_2 = system.create_can_cluster("CanCluster2", &pkg, None).unwrap();
+        let channel_2 = cluster_2.create_physical_channel("channel_2").unwrap();
+        let ft_2 = channel_2
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let st_other = ft_2
+            .pdu_triggerings()
+            .next()
+            .unwrap()
+            .signal_triggerings()
+            .next()
+            .unwrap();
+        assert!(pt.add_signal_triggering(&st_other).is_err());
+    }
 }
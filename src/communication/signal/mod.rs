@@ -1,6 +1,7 @@
 use crate::communication::{
     AbstractPhysicalChannel, CommunicationDirection, DataTransformation, EndToEndTransformationISignalProps,
-    PhysicalChannel, SomeIpTransformationISignalProps, TransformationTechnology,
+    FrameTriggering, ISignalIPdu, PduTriggering, PhysicalChannel, SomeIpTransformationISignalProps,
+    TransformationTechnology,
 };
 use crate::datatype::{CompuMethod, DataConstr, SwBaseType, Unit, ValueSpecification};
 use crate::{
@@ -12,6 +13,7 @@ use crate::{
     is_used_system_element,
 };
 use autosar_data::{AutosarDataError, Element, ElementName, EnumItem, WeakElement};
+use std::collections::HashSet;
 
 use super::TransformationISignalProps;
 
@@ -115,10 +117,7 @@ impl ISignal {
 
     /// set the data type for this signal
     pub fn set_datatype(&self, datatype: &SwBaseType) -> Result<(), AutosarAbstractionError> {
-        self.element()
-            .get_or_create_sub_element(ElementName::NetworkRepresentationProps)?
-            .get_or_create_sub_element(ElementName::SwDataDefPropsVariants)?
-            .get_or_create_sub_element(ElementName::SwDataDefPropsConditional)?
+        self.get_or_create_network_representation_props()?
             .get_or_create_sub_element(ElementName::BaseTypeRef)?
             .set_reference_target(datatype.element())?;
         Ok(())
@@ -127,10 +126,7 @@ impl ISignal {
     /// get the data type of this signal
     #[must_use]
     pub fn datatype(&self) -> Option<SwBaseType> {
-        self.element()
-            .get_sub_element(ElementName::NetworkRepresentationProps)?
-            .get_sub_element(ElementName::SwDataDefPropsVariants)?
-            .get_sub_element(ElementName::SwDataDefPropsConditional)?
+        self.network_representation_props()?
             .get_sub_element(ElementName::BaseTypeRef)?
             .get_reference_target()
             .ok()?
@@ -138,6 +134,133 @@ impl ISignal {
             .ok()
     }
 
+    /// set the compu method of the network representation of this signal
+    ///
+    /// The compu method of the network representation describes the conversion between the physical values
+    /// and the values that are transmitted on the bus.
+    pub fn set_compu_method(&self, compu_method: &CompuMethod) -> Result<(), AutosarAbstractionError> {
+        self.get_or_create_network_representation_props()?
+            .get_or_create_sub_element(ElementName::CompuMethodRef)?
+            .set_reference_target(compu_method.element())?;
+        Ok(())
+    }
+
+    /// get the compu method of the network representation of this signal
+    #[must_use]
+    pub fn compu_method(&self) -> Option<CompuMethod> {
+        self.network_representation_props()?
+            .get_sub_element(ElementName::CompuMethodRef)?
+            .get_reference_target()
+            .ok()?
+            .try_into()
+            .ok()
+    }
+
+    /// set the data constraint of the network representation of this signal
+    pub fn set_data_constr(&self, data_constr: &DataConstr) -> Result<(), AutosarAbstractionError> {
+        self.get_or_create_network_representation_props()?
+            .get_or_create_sub_element(ElementName::DataConstrRef)?
+            .set_reference_target(data_constr.element())?;
+        Ok(())
+    }
+
+    /// get the data constraint of the network representation of this signal
+    #[must_use]
+    pub fn data_constr(&self) -> Option<DataConstr> {
+        self.network_representation_props()?
+            .get_sub_element(ElementName::DataConstrRef)?
+            .get_reference_target()
+            .ok()?
+            .try_into()
+            .ok()
+    }
+
+    /// set the invalid value of this signal
+    ///
+    /// The invalid value is sent when the signal is invalidated by the sender.
+    /// Only `NumericalValueSpecification`, `TextValueSpecification` or `ArrayValueSpecification` are permitted here
+    pub fn set_invalid_value<T: Into<ValueSpecification>>(
+        &self,
+        value_spec: Option<T>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(value_spec) = value_spec {
+            let value_spec: ValueSpecification = value_spec.into();
+            if !matches!(
+                value_spec,
+                ValueSpecification::Numerical(_) | ValueSpecification::Text(_) | ValueSpecification::Array(_)
+            ) {
+                return Err(AutosarAbstractionError::InvalidParameter(
+                "The invalid value must be a NumericalValueSpecification, TextValueSpecification or ArrayValueSpecification".to_string(),
+            ));
+            }
+            let _ = self
+                .network_representation_props()
+                .map(|props| props.remove_sub_element_kind(ElementName::InvalidValue));
+            let invalid_value_elem = self
+                .get_or_create_network_representation_props()?
+                .create_sub_element(ElementName::InvalidValue)?;
+            value_spec.store(&invalid_value_elem)?;
+        } else if let Some(props) = self.network_representation_props() {
+            let _ = props.remove_sub_element_kind(ElementName::InvalidValue);
+        }
+        Ok(())
+    }
+
+    /// get the invalid value of this signal
+    #[must_use]
+    pub fn invalid_value(&self) -> Option<ValueSpecification> {
+        let invalid_value_elem = self
+            .network_representation_props()?
+            .get_sub_element(ElementName::InvalidValue)?
+            .get_sub_element_at(0)?;
+        ValueSpecification::load(&invalid_value_elem)
+    }
+
+    /// set the handling of received values that are outside of the valid range of this signal
+    pub fn set_handle_out_of_range(
+        &self,
+        handle_out_of_range: Option<HandleOutOfRange>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(handle_out_of_range) = handle_out_of_range {
+            self.element()
+                .get_or_create_sub_element(ElementName::ISignalProps)?
+                .get_or_create_sub_element(ElementName::HandleOutOfRange)?
+                .set_character_data::<EnumItem>(handle_out_of_range.into())?;
+        } else if let Some(isignal_props) = self.element().get_sub_element(ElementName::ISignalProps) {
+            let _ = isignal_props.remove_sub_element_kind(ElementName::HandleOutOfRange);
+        }
+        Ok(())
+    }
+
+    /// get the handling of received values that are outside of the valid range of this signal
+    #[must_use]
+    pub fn handle_out_of_range(&self) -> Option<HandleOutOfRange> {
+        self.element()
+            .get_sub_element(ElementName::ISignalProps)?
+            .get_sub_element(ElementName::HandleOutOfRange)?
+            .character_data()?
+            .enum_value()?
+            .try_into()
+            .ok()
+    }
+
+    // get the SwDataDefPropsConditional inside the NetworkRepresentationProps of the signal
+    fn network_representation_props(&self) -> Option<Element> {
+        self.element()
+            .get_sub_element(ElementName::NetworkRepresentationProps)?
+            .get_sub_element(ElementName::SwDataDefPropsVariants)?
+            .get_sub_element(ElementName::SwDataDefPropsConditional)
+    }
+
+    // get or create the SwDataDefPropsConditional inside the NetworkRepresentationProps of the signal
+    fn get_or_create_network_representation_props(&self) -> Result<Element, AutosarAbstractionError> {
+        Ok(self
+            .element()
+            .get_or_create_sub_element(ElementName::NetworkRepresentationProps)?
+            .get_or_create_sub_element(ElementName::SwDataDefPropsVariants)?
+            .get_or_create_sub_element(ElementName::SwDataDefPropsConditional)?)
+    }
+
     /// set the length of this signal in bits
     pub fn set_length(&self, bit_length: u64) -> Result<(), AutosarAbstractionError> {
         self.element()
@@ -270,6 +393,67 @@ impl ISignal {
         }
     }
 
+    /// list all `FrameTriggering`s of frames that transport this signal
+    ///
+    /// The frame triggerings are found by following the signal triggerings to their PDU triggerings,
+    /// and from there to the frame triggerings that reference the PDU triggerings.
+    #[must_use]
+    pub fn frame_triggerings(&self) -> Vec<FrameTriggering> {
+        let mut frame_triggerings = Vec::new();
+        for signal_triggering in self.signal_triggerings() {
+            let pdu_triggerings = get_reference_parents(signal_triggering.element())
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(named_parent, _)| PduTriggering::try_from(named_parent).ok());
+            for pdu_triggering in pdu_triggerings {
+                let referring_frame_triggerings = get_reference_parents(pdu_triggering.element())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|(named_parent, _)| FrameTriggering::try_from(named_parent).ok());
+                for frame_triggering in referring_frame_triggerings {
+                    if !frame_triggerings.contains(&frame_triggering) {
+                        frame_triggerings.push(frame_triggering);
+                    }
+                }
+            }
+        }
+        frame_triggerings
+    }
+
+    /// list all ECUs that send this signal
+    ///
+    /// An ECU is a sender if it is connected to one of the signal triggerings with an outgoing `ISignalPort`
+    #[must_use]
+    pub fn sender_ecus(&self) -> Vec<EcuInstance> {
+        self.connected_ecus(CommunicationDirection::Out)
+    }
+
+    /// list all ECUs that receive this signal
+    ///
+    /// An ECU is a receiver if it is connected to one of the signal triggerings with an incoming `ISignalPort`
+    #[must_use]
+    pub fn receiver_ecus(&self) -> Vec<EcuInstance> {
+        self.connected_ecus(CommunicationDirection::In)
+    }
+
+    // collect the ECUs that are connected to the signal triggerings of this signal in the given direction
+    fn connected_ecus(&self, direction: CommunicationDirection) -> Vec<EcuInstance> {
+        let mut ecus = Vec::new();
+        for signal_port in self
+            .signal_triggerings()
+            .iter()
+            .flat_map(ISignalTriggering::signal_ports)
+        {
+            if signal_port.communication_direction() == Some(direction)
+                && let Ok(ecu) = signal_port.ecu()
+                && !ecus.contains(&ecu)
+            {
+                ecus.push(ecu);
+            }
+        }
+        ecus
+    }
+
     /// add a data transformation to this signal
     pub fn add_data_transformation(
         &self,
@@ -286,6 +470,21 @@ impl ISignal {
         Ok(())
     }
 
+    /// remove a data transformation from this signal
+    pub fn remove_data_transformation(
+        &self,
+        data_transformation: &DataTransformation,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(transformations) = self.element().get_sub_element(ElementName::DataTransformations) {
+            remove_data_transformation_ref(&transformations, data_transformation)?;
+            if transformations.sub_elements().count() == 0 {
+                self.element().remove_sub_element(transformations)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// get all data transformations that are applied to this signal
     pub fn data_transformations(&self) -> impl Iterator<Item = DataTransformation> + Send + use<> {
         self.element()
@@ -396,6 +595,39 @@ impl SystemSignal {
         Ok(())
     }
 
+    /// iterate over all `ISignal`s that refer to this system signal
+    ///
+    /// Usually a system signal corresponds to exactly one `ISignal`, but a system signal
+    /// may also be transmitted in several representations, each with its own `ISignal`.
+    pub fn isignals(&self) -> impl Iterator<Item = ISignal> + Send + use<> {
+        let referrers = match (self.element().model(), self.element().path()) {
+            (Ok(model), Ok(path)) => model.get_references_to(&path),
+            _ => Vec::new(),
+        };
+        referrers
+            .into_iter()
+            .filter_map(WeakElement::upgrade)
+            .filter_map(|refelem| refelem.named_parent().ok().flatten())
+            .filter_map(|elem| ISignal::try_from(elem).ok())
+    }
+
+    /// re-link all `ISignal`s that refer to this system signal to a different system signal
+    ///
+    /// This is useful when redundant system signals are consolidated. The re-linked `ISignal`s are returned.
+    /// This system signal is not removed; it can be removed afterwards if it is no longer needed.
+    pub fn relink_isignals(&self, target: &SystemSignal) -> Result<Vec<ISignal>, AutosarAbstractionError> {
+        if self == target {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The target system signal must be different from the current one".to_string(),
+            ));
+        }
+        let isignals: Vec<ISignal> = self.isignals().collect();
+        for isignal in &isignals {
+            isignal.set_system_signal(target)?;
+        }
+        Ok(isignals)
+    }
+
     /// get the signal group that contains this signal
     pub fn signal_group(&self) -> Option<SystemSignalGroup> {
         let path = self.element().path().ok()?;
@@ -602,6 +834,117 @@ impl ISignalGroup {
             })
     }
 
+    /// remove a signal from the signal group
+    ///
+    /// Nothing happens if the signal is not part of the group.
+    pub fn remove_signal(&self, signal: &ISignal) -> Result<(), AutosarAbstractionError> {
+        if let Some(isrefs) = self.element().get_sub_element(ElementName::ISignalRefs) {
+            let signal_refs: Vec<_> = isrefs
+                .sub_elements()
+                .filter(|isref| {
+                    isref
+                        .get_reference_target()
+                        .is_ok_and(|target| &target == signal.element())
+                })
+                .collect();
+            for isref in signal_refs {
+                isrefs.remove_sub_element(isref)?;
+            }
+            if isrefs.sub_elements().count() == 0 {
+                self.element().remove_sub_element(isrefs)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// change the order of the signals in the signal group
+    ///
+    /// The given signals must be exactly the signals that are already part of the group.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the given signals are not the same as the signals of the group
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while updating the signal references
+    pub fn set_signal_order(&self, signals: &[ISignal]) -> Result<(), AutosarAbstractionError> {
+        let current: HashSet<ISignal> = self.signals().collect();
+        let requested: HashSet<ISignal> = signals.iter().cloned().collect();
+        if current.len() != signals.len() || current != requested {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The signal order must contain each signal of the signal group exactly once".to_string(),
+            ));
+        }
+
+        if let Some(isrefs) = self.element().get_sub_element(ElementName::ISignalRefs) {
+            let signal_refs: Vec<_> = isrefs.sub_elements().collect();
+            for isref in signal_refs {
+                isrefs.remove_sub_element(isref)?;
+            }
+            for signal in signals {
+                isrefs
+                    .create_sub_element(ElementName::ISignalRef)?
+                    .set_reference_target(signal.element())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// list all `ISignalIPdu`s that contain this signal group
+    #[must_use]
+    pub fn isignal_ipdus(&self) -> Vec<ISignalIPdu> {
+        let Ok(model) = self.element().model() else {
+            return vec![];
+        };
+        let Ok(path) = self.element().path() else {
+            return vec![];
+        };
+        model
+            .get_references_to(&path)
+            .iter()
+            .filter_map(|weak| weak.upgrade())
+            .filter_map(|ref_elem| ref_elem.named_parent().ok().flatten())
+            .filter(|elem| elem.element_name() == ElementName::ISignalToIPduMapping)
+            .filter_map(|mapping| mapping.named_parent().ok().flatten())
+            .filter_map(|elem| ISignalIPdu::try_from(elem).ok())
+            .collect()
+    }
+
+    /// check that all signals of the group are mapped contiguously in each `ISignalIPdu` that contains the group
+    ///
+    /// E2E protection requires that the signals of a group form one continuous block of bits.
+    /// The update bits of the group signals are considered to be part of the block.
+    #[must_use]
+    pub fn check_contiguous_mapping(&self) -> Vec<SignalGroupMappingIssue> {
+        let group_signals: Vec<ISignal> = self.signals().collect();
+        let mut issues = Vec::new();
+
+        for ipdu in self.isignal_ipdus() {
+            let layout = ipdu.layout();
+            let mut bits = Vec::new();
+            for signal in &group_signals {
+                let mut entries = layout
+                    .iter()
+                    .filter(|entry| entry.mapping.signal().as_ref() == Some(signal))
+                    .peekable();
+                if entries.peek().is_none() {
+                    issues.push(SignalGroupMappingIssue::UnmappedSignal(ipdu.clone(), signal.clone()));
+                }
+                bits.extend(entries.flat_map(|entry| entry.bits()));
+            }
+
+            bits.sort_unstable();
+            bits.dedup();
+            if let (Some(first), Some(last)) = (bits.first(), bits.last())
+                && (last - first) as usize + 1 != bits.len()
+            {
+                issues.push(SignalGroupMappingIssue::NotContiguous(ipdu));
+            }
+        }
+
+        issues
+    }
+
     /// add a data transformation to this signal group
     pub fn add_data_transformation(
         &self,
@@ -616,6 +959,24 @@ impl ISignalGroup {
         Ok(())
     }
 
+    /// remove a data transformation from this signal group
+    pub fn remove_data_transformation(
+        &self,
+        data_transformation: &DataTransformation,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(cbst) = self
+            .element()
+            .get_sub_element(ElementName::ComBasedSignalGroupTransformations)
+        {
+            remove_data_transformation_ref(&cbst, data_transformation)?;
+            if cbst.sub_elements().count() == 0 {
+                self.element().remove_sub_element(cbst)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// get all data transformations that are applied to this signal group
     pub fn data_transformations(&self) -> impl Iterator<Item = DataTransformation> + Send + use<> {
         self.element()
@@ -661,6 +1022,43 @@ impl ISignalGroup {
 
 //##################################################################
 
+/// A problem in the mapping of a signal group, as found by [`ISignalGroup::check_contiguous_mapping`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignalGroupMappingIssue {
+    /// The signal of the group is not mapped to the `ISignalIPdu` that contains the group
+    UnmappedSignal(ISignalIPdu, ISignal),
+    /// The signals of the group do not form one continuous block of bits in the `ISignalIPdu`
+    NotContiguous(ISignalIPdu),
+}
+
+//##################################################################
+
+/// A problem in the relation of `ISignal`s and `SystemSignal`s, as found by [`System::check_signal_consistency`]
+///
+/// [`System::check_signal_consistency`]: crate::System::check_signal_consistency
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignalConsistencyIssue {
+    /// The `ISignal` does not refer to a `SystemSignal`
+    MissingSystemSignal(ISignal),
+    /// No `ISignal` refers to the `SystemSignal`
+    MissingISignal(SystemSignal),
+    /// The `ISignal` is part of the `ISignalGroup`, but its `SystemSignal` is not part of the corresponding `SystemSignalGroup`
+    GroupMismatch(ISignal, ISignalGroup),
+}
+
+/// The signals that were created by [`System::create_missing_signal_counterparts`]
+///
+/// [`System::create_missing_signal_counterparts`]: crate::System::create_missing_signal_counterparts
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignalCounterpartReport {
+    /// the `SystemSignal`s that were created for `ISignal`s without a system signal
+    pub created_system_signals: Vec<SystemSignal>,
+    /// the `ISignal`s that were created for `SystemSignal`s without an isignal
+    pub created_isignals: Vec<ISignal>,
+}
+
+//##################################################################
+
 /// A signal group refers to a set of signals that shall always be kept together. A signal group is used to
 /// guarantee the atomic transfer of AUTOSAR composite data types.
 ///
@@ -727,7 +1125,7 @@ impl ISignalTriggering {
             .name()
             .ok_or(AutosarAbstractionError::InvalidParameter("invalid signal".to_string()))?;
         let pt_name = format!("ST_{signal_name}");
-        let pt_name = make_unique_name(&model, &base_path, &pt_name);
+        let pt_name = make_unique_name(&model, &base_path, &pt_name)?;
 
         let triggerings = channel
             .element()
@@ -749,8 +1147,19 @@ impl ISignalTriggering {
             sp.remove(deep)?;
         }
 
+        let ref_parents = get_reference_parents(self.element())?;
+
         AbstractionElement::remove(self, deep)?;
 
+        // remove the ISignalTriggeringRefConditionals in PduTriggerings, which would otherwise be left empty
+        for (_, parent) in ref_parents {
+            if parent.element_name() == ElementName::ISignalTriggeringRefConditional
+                && let Ok(Some(parent_parent)) = parent.parent()
+            {
+                parent_parent.remove_sub_element(parent)?;
+            }
+        }
+
         if deep
             && let Some(signal) = opt_signal
             && !is_used_system_element(signal.element())
@@ -772,7 +1181,7 @@ impl ISignalTriggering {
             "invalid signal group".to_string(),
         ))?;
         let pt_name = format!("ST_{signal_name}");
-        let pt_name = make_unique_name(&model, &base_path, &pt_name);
+        let pt_name = make_unique_name(&model, &base_path, &pt_name)?;
 
         let triggerings = channel
             .element()
@@ -848,6 +1257,40 @@ impl ISignalTriggering {
         ISignal::try_from(signal_elem).ok()
     }
 
+    /// get the signal group that is triggered by this triggering
+    #[must_use]
+    pub fn signal_group(&self) -> Option<ISignalGroup> {
+        let signal_group_elem = self
+            .element()
+            .get_sub_element(ElementName::ISignalGroupRef)?
+            .get_reference_target()
+            .ok()?;
+        ISignalGroup::try_from(signal_group_elem).ok()
+    }
+
+    /// list all `PduTriggering`s that contain this signal triggering
+    ///
+    /// A signal triggering that is not contained in any `PduTriggering` is orphaned; see
+    /// [`AbstractPhysicalChannel::check_signal_triggerings`].
+    #[must_use]
+    pub fn pdu_triggerings(&self) -> Vec<PduTriggering> {
+        let model_result = self.element().model();
+        let path_result = self.element().path();
+        if let (Ok(model), Ok(path)) = (model_result, path_result) {
+            model
+                .get_references_to(&path)
+                .iter()
+                .filter_map(|e| {
+                    e.upgrade()
+                        .and_then(|ref_elem| ref_elem.named_parent().ok().flatten())
+                        .and_then(|elem| PduTriggering::try_from(elem).ok())
+                })
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
     /// create an iterator over all signal ports that are connected to this signal triggering
     pub fn signal_ports(&self) -> impl Iterator<Item = ISignalPort> + Send + use<> {
         self.element()
@@ -899,6 +1342,18 @@ impl ISignalPort {
             .try_into()
             .ok()
     }
+
+    /// get the signal triggering that uses this port
+    #[must_use]
+    pub fn signal_triggering(&self) -> Option<ISignalTriggering> {
+        let path = self.element().path().ok()?;
+        let model = self.element().model().ok()?;
+        model
+            .get_references_to(&path)
+            .iter()
+            .filter_map(|weak| weak.upgrade()?.named_parent().ok().flatten())
+            .find_map(|elem| ISignalTriggering::try_from(elem).ok())
+    }
 }
 
 //##################################################################
@@ -950,6 +1405,76 @@ impl TryFrom<EnumItem> for TransferProperty {
 
 //##################################################################
 
+/// `HandleOutOfRange` defines how a receiver handles signal values that are outside of the valid range
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HandleOutOfRange {
+    /// The value is replaced by the init value of the signal
+    Default,
+    /// The value is replaced by an externally provided replacement value
+    ExternalReplacement,
+    /// The value is ignored, and the last valid value is kept
+    Ignore,
+    /// The value is replaced by the invalid value of the signal
+    Invalid,
+    /// No special handling of out of range values
+    None,
+    /// The value is saturated to the limits of the valid range
+    Saturate,
+}
+
+impl From<HandleOutOfRange> for EnumItem {
+    fn from(value: HandleOutOfRange) -> Self {
+        match value {
+            HandleOutOfRange::Default => EnumItem::Default,
+            HandleOutOfRange::ExternalReplacement => EnumItem::ExternalReplacement,
+            HandleOutOfRange::Ignore => EnumItem::Ignore,
+            HandleOutOfRange::Invalid => EnumItem::Invalid,
+            HandleOutOfRange::None => EnumItem::None,
+            HandleOutOfRange::Saturate => EnumItem::Saturate,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for HandleOutOfRange {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::Default => Ok(HandleOutOfRange::Default),
+            EnumItem::ExternalReplacement => Ok(HandleOutOfRange::ExternalReplacement),
+            EnumItem::Ignore => Ok(HandleOutOfRange::Ignore),
+            EnumItem::Invalid => Ok(HandleOutOfRange::Invalid),
+            EnumItem::None => Ok(HandleOutOfRange::None),
+            EnumItem::Saturate => Ok(HandleOutOfRange::Saturate),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "HandleOutOfRange".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
+// remove the DataTransformationRefConditional that refers to the data transformation from the container
+fn remove_data_transformation_ref(
+    container: &Element,
+    data_transformation: &DataTransformation,
+) -> Result<(), AutosarAbstractionError> {
+    let ref_conditional = container.sub_elements().find(|elem| {
+        elem.get_sub_element(ElementName::DataTransformationRef)
+            .and_then(|dt_ref| dt_ref.get_reference_target().ok())
+            .is_some_and(|target| &target == data_transformation.element())
+    });
+    if let Some(ref_conditional) = ref_conditional {
+        container.remove_sub_element(ref_conditional)?;
+    }
+
+    Ok(())
+}
+
+//##################################################################
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1016,6 +1541,35 @@ mod tests {
 
         signal.set_init_value::<ValueSpecification>(None).unwrap();
         assert_eq!(signal.init_value(), None);
+
+        // invalid value
+        let invalid_value = NumericalValueSpecification {
+            label: None,
+            value: 255.0,
+        };
+        signal.set_invalid_value(Some(invalid_value.clone())).unwrap();
+        assert_eq!(signal.invalid_value(), Some(invalid_value.into()));
+        signal.set_invalid_value::<ValueSpecification>(None).unwrap();
+        assert_eq!(signal.invalid_value(), None);
+
+        // out of range handling
+        assert_eq!(signal.handle_out_of_range(), None);
+        signal
+            .set_handle_out_of_range(Some(HandleOutOfRange::Saturate))
+            .unwrap();
+        assert_eq!(signal.handle_out_of_range(), Some(HandleOutOfRange::Saturate));
+        signal.set_handle_out_of_range(None).unwrap();
+        assert_eq!(signal.handle_out_of_range(), None);
+
+        // network representation
+        let network_compu_method =
+            CompuMethod::new("network_compu_method", &package, CompuMethodContent::Identical).unwrap();
+        let network_data_constr = DataConstr::new("network_data_constr", &package).unwrap();
+        signal.set_compu_method(&network_compu_method).unwrap();
+        signal.set_data_constr(&network_data_constr).unwrap();
+        assert_eq!(signal.compu_method(), Some(network_compu_method));
+        assert_eq!(signal.data_constr(), Some(network_data_constr));
+        assert!(signal.datatype().is_some());
     }
 
     #[test]
@@ -1051,7 +1605,7 @@ mod tests {
         signal.add_data_transformation(&data_transformation).unwrap();
 
         assert_eq!(signal.data_transformations().count(), 1);
-        assert_eq!(signal.data_transformations().next(), Some(data_transformation));
+        assert_eq!(signal.data_transformations().next(), Some(data_transformation.clone()));
 
         let someip_props = signal.create_someip_transformation_isignal_props(&transformer).unwrap();
         someip_props.set_legacy_strings(Some(true)).unwrap();
@@ -1064,6 +1618,9 @@ mod tests {
         someip_props.set_size_of_union_length(Some(64)).unwrap();
 
         assert_eq!(signal.transformation_isignal_props().count(), 1);
+
+        signal.remove_data_transformation(&data_transformation).unwrap();
+        assert_eq!(signal.data_transformations().count(), 0);
     }
 
     #[test]
@@ -1095,7 +1652,10 @@ mod tests {
 
         signal_group.add_data_transformation(&data_transformation).unwrap();
         assert_eq!(signal_group.data_transformations().count(), 1);
-        assert_eq!(signal_group.data_transformations().next(), Some(data_transformation));
+        assert_eq!(
+            signal_group.data_transformations().next(),
+            Some(data_transformation.clone())
+        );
 
         let _someipxf_props = signal_group
             .create_someip_transformation_isignal_props(&transformer)
@@ -1105,6 +1665,9 @@ mod tests {
         assert!(result.is_err());
 
         assert_eq!(signal_group.transformation_isignal_props().count(), 1);
+
+        signal_group.remove_data_transformation(&data_transformation).unwrap();
+        assert_eq!(signal_group.data_transformations().count(), 0);
     }
 
     #[test]
@@ -1125,6 +1688,103 @@ mod tests {
 
         signal_group.add_signal(&signal).unwrap();
         assert_eq!(signal_group.signals().count(), 1);
+
+        signal_group.remove_signal(&signal).unwrap();
+        assert_eq!(signal_group.signals().count(), 0);
+    }
+
+    #[test]
+    fn test_system_signal_isignals() {
+        let model = AutosarModelAbstraction::create("test.arxml", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/test").unwrap();
+        let sys_signal_group = SystemSignalGroup::new("sys_signal_group", &package).unwrap();
+        let sys_signal = SystemSignal::new("sys_signal", &package).unwrap();
+        // the reference from the system signal group must not be mistaken for an isignal
+        sys_signal_group.add_signal(&sys_signal).unwrap();
+        let sys_signal_2 = SystemSignal::new("sys_signal_2", &package).unwrap();
+        let signal_1 = ISignal::new("signal_1", &package, 8, &sys_signal, None).unwrap();
+        let signal_2 = ISignal::new("signal_2", &package, 16, &sys_signal, None).unwrap();
+
+        let isignals: Vec<ISignal> = sys_signal.isignals().collect();
+        assert_eq!(isignals.len(), 2);
+        assert!(isignals.contains(&signal_1));
+        assert!(isignals.contains(&signal_2));
+        assert_eq!(sys_signal_2.isignals().count(), 0);
+
+        assert!(sys_signal.relink_isignals(&sys_signal).is_err());
+        let relinked = sys_signal.relink_isignals(&sys_signal_2).unwrap();
+        assert_eq!(relinked.len(), 2);
+        assert_eq!(sys_signal.isignals().count(), 0);
+        assert_eq!(sys_signal_2.isignals().count(), 2);
+        assert_eq!(signal_1.system_signal(), Some(sys_signal_2));
+    }
+
+    #[test]
+    fn test_signal_group_mapping() {
+        let model = AutosarModelAbstraction::create("test.arxml", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/test").unwrap();
+        let system = package.create_system("system", SystemCategory::EcuExtract).unwrap();
+        let sys_signal_group = SystemSignalGroup::new("sys_signal_group", &package).unwrap();
+        let signal_group = ISignalGroup::new("signal_group", &package, &sys_signal_group).unwrap();
+
+        let mut signals = Vec::new();
+        for name in ["sig_a", "sig_b", "sig_c"] {
+            let sys_signal = SystemSignal::new(name, &package).unwrap();
+            sys_signal_group.add_signal(&sys_signal).unwrap();
+            let signal = ISignal::new(name, &package, 8, &sys_signal, None).unwrap();
+            signal_group.add_signal(&signal).unwrap();
+            signals.push(signal);
+        }
+
+        // reorder the signals
+        let reordered = vec![signals[2].clone(), signals[0].clone(), signals[1].clone()];
+        signal_group.set_signal_order(&reordered).unwrap();
+        assert_eq!(signal_group.signals().collect::<Vec<_>>(), reordered);
+        // the order must contain every signal of the group exactly once
+        assert!(signal_group.set_signal_order(&signals[0..2]).is_err());
+        assert!(
+            signal_group
+                .set_signal_order(&[signals[0].clone(), signals[0].clone(), signals[1].clone()])
+                .is_err()
+        );
+
+        let ipdu = system.create_isignal_ipdu("ipdu", &package, 8).unwrap();
+        ipdu.map_signal_group(&signal_group).unwrap();
+        assert_eq!(signal_group.isignal_ipdus(), vec![ipdu.clone()]);
+
+        ipdu.map_signal(
+            &signals[0],
+            0,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Pending,
+        )
+        .unwrap();
+        ipdu.map_signal(
+            &signals[1],
+            16,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Pending,
+        )
+        .unwrap();
+        assert_eq!(
+            signal_group.check_contiguous_mapping(),
+            vec![
+                SignalGroupMappingIssue::UnmappedSignal(ipdu.clone(), signals[2].clone()),
+                SignalGroupMappingIssue::NotContiguous(ipdu.clone()),
+            ]
+        );
+
+        ipdu.map_signal(
+            &signals[2],
+            8,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Pending,
+        )
+        .unwrap();
+        assert!(signal_group.check_contiguous_mapping().is_empty());
     }
 
     #[test]
@@ -1143,6 +1803,7 @@ mod tests {
         can_frame
             .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
             .unwrap();
+        let frame_triggering = can_frame.frame_triggerings()[0].clone();
 
         let sw_base_type =
             SwBaseType::new("sw_base_type", &package, 8, BaseTypeEncoding::None, None, None, None).unwrap();
@@ -1167,20 +1828,27 @@ mod tests {
         assert_eq!(st, pt.signal_triggerings().next().unwrap());
 
         assert_eq!(st.physical_channel().unwrap(), PhysicalChannel::Can(channel.clone()));
+        assert_eq!(signal.frame_triggerings(), vec![FrameTriggering::Can(frame_triggering)]);
 
         let ecuinstance = system.create_ecu_instance("ecu", &package).unwrap();
         let controller = ecuinstance.create_can_communication_controller("controller").unwrap();
         controller.connect_physical_channel("connection", &channel).unwrap();
+        assert!(signal.receiver_ecus().is_empty());
+        assert!(signal.sender_ecus().is_empty());
 
         assert_eq!(st.signal_ports().count(), 0);
         let signal_port = st.connect_to_ecu(&ecuinstance, CommunicationDirection::In).unwrap();
         assert_eq!(st.signal_ports().count(), 1);
         assert_eq!(signal_port.ecu().unwrap(), ecuinstance);
         assert_eq!(signal_port.communication_direction(), Some(CommunicationDirection::In));
+        assert_eq!(signal.receiver_ecus(), vec![ecuinstance.clone()]);
+        assert!(signal.sender_ecus().is_empty());
         signal_port
             .set_communication_direction(CommunicationDirection::Out)
             .unwrap();
         assert_eq!(signal_port.communication_direction(), Some(CommunicationDirection::Out));
+        assert_eq!(signal.sender_ecus(), vec![ecuinstance.clone()]);
+        assert!(signal.receiver_ecus().is_empty());
         signal_port.set_name("new_name").unwrap();
         assert_eq!(signal_port.name().unwrap(), "new_name");
     }
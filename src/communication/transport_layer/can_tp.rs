@@ -1,4 +1,7 @@
-use crate::communication::{AbstractIpdu, CanCluster, CanCommunicationConnector, IPdu, NPdu};
+use crate::communication::{
+    AbstractCommunicationConnector, AbstractIpdu, AbstractPhysicalChannel, CanCluster, CanCommunicationConnector,
+    CanPhysicalChannel, CommunicationDirection, IPdu, NPdu, Pdu, PduTriggering, PhysicalChannel,
+};
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
     abstraction_element,
@@ -104,6 +107,8 @@ impl CanTpConfig {
     }
 
     /// create a new `CanTpConnection` in the configuration
+    ///
+    /// The data `NPdu` is triggered on the physical channel of the CAN cluster, if it is not triggered there yet.
     pub fn create_can_tp_connection<T: AbstractIpdu>(
         &self,
         name: Option<&str>,
@@ -424,6 +429,8 @@ impl CanTpConnection {
     }
 
     /// set the `NPdu` associated with this connection
+    ///
+    /// Use [`CanTpConnection::trigger_pdus`] to trigger the `NPdu` on the CAN channel once the connection is complete.
     pub fn set_data_pdu(&self, data_pdu: &NPdu) -> Result<(), AutosarAbstractionError> {
         self.element()
             .get_or_create_sub_element(ElementName::DataPduRef)?
@@ -501,7 +508,7 @@ impl CanTpConnection {
 
     /// set the transmitter of the connection
     ///
-    /// This is a `CanTpNode` representing an ECU that will send the data
+    /// This is a `CanTpNode` representing an ECU that will send the data.
     pub fn set_transmitter(&self, transmitter: &CanTpNode) -> Result<(), AutosarAbstractionError> {
         self.element()
             .get_or_create_sub_element(ElementName::TransmitterRef)?
@@ -520,7 +527,7 @@ impl CanTpConnection {
 
     /// add a receiver to the connection
     ///
-    /// This is a `CanTpNode` representing an ECU that will receive the data
+    /// This is a `CanTpNode` representing an ECU that will receive the data.
     pub fn add_receiver(&self, receiver: &CanTpNode) -> Result<(), AutosarAbstractionError> {
         let receivers = self.element().get_or_create_sub_element(ElementName::ReceiverRefs)?;
         let receiver_ref_elem = receivers.create_sub_element(ElementName::ReceiverRef)?;
@@ -542,6 +549,231 @@ impl CanTpConnection {
                 })
             })
     }
+
+    /// set the flow control `NPdu` of the connection
+    ///
+    /// The receiver of the connection sends flow control frames in this Pdu, while the data is sent in the data Pdu.
+    pub fn set_flow_control_pdu(&self, flow_control_pdu: Option<&NPdu>) -> Result<(), AutosarAbstractionError> {
+        if let Some(flow_control_pdu) = flow_control_pdu {
+            self.element()
+                .get_or_create_sub_element(ElementName::FlowControlPduRef)?
+                .set_reference_target(flow_control_pdu.element())?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::FlowControlPduRef);
+        }
+        Ok(())
+    }
+
+    /// get the flow control `NPdu` of the connection
+    #[must_use]
+    pub fn flow_control_pdu(&self) -> Option<NPdu> {
+        self.element()
+            .get_sub_element(ElementName::FlowControlPduRef)
+            .and_then(|elem| elem.get_reference_target().ok())
+            .and_then(|target| NPdu::try_from(target).ok())
+    }
+
+    /// set the maximum block size (BS) that the receiver sends in flow control frames
+    ///
+    /// A value of 0 means that the sender may send all consecutive frames without waiting for further flow control frames
+    pub fn set_max_block_size(&self, max_block_size: Option<u8>) -> Result<(), AutosarAbstractionError> {
+        if let Some(max_block_size) = max_block_size {
+            self.element()
+                .get_or_create_sub_element(ElementName::MaxBlockSize)?
+                .set_character_data(u64::from(max_block_size))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::MaxBlockSize);
+        }
+        Ok(())
+    }
+
+    /// get the maximum block size (BS) that the receiver sends in flow control frames
+    #[must_use]
+    pub fn max_block_size(&self) -> Option<u8> {
+        self.element()
+            .get_sub_element(ElementName::MaxBlockSize)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_integer())
+    }
+
+    /// set the timeout Br (in seconds) until the receiver sends the next flow control frame
+    pub fn set_timeout_br(&self, timeout_br: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        if let Some(timeout_br) = timeout_br {
+            self.element()
+                .get_or_create_sub_element(ElementName::TimeoutBr)?
+                .set_character_data(timeout_br)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::TimeoutBr);
+        }
+        Ok(())
+    }
+
+    /// get the timeout Br (in seconds) until the receiver sends the next flow control frame
+    #[must_use]
+    pub fn timeout_br(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::TimeoutBr)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_float())
+    }
+
+    /// set the timeout Bs (in seconds) until the sender receives the next flow control frame
+    pub fn set_timeout_bs(&self, timeout_bs: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        if let Some(timeout_bs) = timeout_bs {
+            self.element()
+                .get_or_create_sub_element(ElementName::TimeoutBs)?
+                .set_character_data(timeout_bs)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::TimeoutBs);
+        }
+        Ok(())
+    }
+
+    /// get the timeout Bs (in seconds) until the sender receives the next flow control frame
+    #[must_use]
+    pub fn timeout_bs(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::TimeoutBs)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_float())
+    }
+
+    /// set the timeout Cr (in seconds) until the receiver receives the next consecutive frame
+    pub fn set_timeout_cr(&self, timeout_cr: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        if let Some(timeout_cr) = timeout_cr {
+            self.element()
+                .get_or_create_sub_element(ElementName::TimeoutCr)?
+                .set_character_data(timeout_cr)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::TimeoutCr);
+        }
+        Ok(())
+    }
+
+    /// get the timeout Cr (in seconds) until the receiver receives the next consecutive frame
+    #[must_use]
+    pub fn timeout_cr(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::TimeoutCr)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_float())
+    }
+
+    /// set the timeout Cs (in seconds) until the sender sends the next consecutive frame
+    pub fn set_timeout_cs(&self, timeout_cs: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        if let Some(timeout_cs) = timeout_cs {
+            self.element()
+                .get_or_create_sub_element(ElementName::TimeoutCs)?
+                .set_character_data(timeout_cs)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::TimeoutCs);
+        }
+        Ok(())
+    }
+
+    /// get the timeout Cs (in seconds) until the sender sends the next consecutive frame
+    #[must_use]
+    pub fn timeout_cs(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::TimeoutCs)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_float())
+    }
+
+    /// trigger the data `NPdu` and the flow control `NPdu` of the connection on the CAN channel
+    ///
+    /// The `NPdu`s are triggered on the physical channel of the CAN cluster of the `CanTpConfig`, and `PduPort`s are
+    /// created for the ECUs of the transmitter and the receivers: the transmitter sends the data `NPdu` and receives
+    /// the flow control `NPdu`, while the receivers do the opposite. Existing `PduTriggering`s and ports are reused.
+    ///
+    /// Returns the `PduTriggering`s of the data `NPdu` and of the flow control `NPdu`, if there is one.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the connection has no data `NPdu` or no transmitter,
+    ///   the cluster has no physical channel, or a node is not connected to the channel.
+    ///   This is checked before the model is modified.
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn trigger_pdus(&self) -> Result<(PduTriggering, Option<PduTriggering>), AutosarAbstractionError> {
+        let channel = self
+            .element()
+            .named_parent()?
+            .and_then(|parent| CanTpConfig::try_from(parent).ok())
+            .and_then(|tp_config| tp_config.cluster())
+            .and_then(|cluster| cluster.physical_channel())
+            .ok_or(AutosarAbstractionError::InvalidParameter(
+                "The CAN cluster of the CanTpConfig has no physical channel".to_string(),
+            ))?;
+        let data_pdu = self.data_pdu().ok_or(AutosarAbstractionError::InvalidParameter(
+            "The CanTpConnection has no data NPdu".to_string(),
+        ))?;
+        let transmitter = self.transmitter().ok_or(AutosarAbstractionError::InvalidParameter(
+            "The CanTpConnection has no transmitter".to_string(),
+        ))?;
+
+        // the direction of each node refers to the data pdu; the flow control pdu is sent in the opposite direction
+        let mut nodes = vec![(transmitter, CommunicationDirection::Out)];
+        nodes.extend(self.receivers().map(|receiver| (receiver, CommunicationDirection::In)));
+        let mut ecus = Vec::new();
+        for (node, direction) in nodes {
+            let ecu = node
+                .connector()
+                .filter(|connector| {
+                    channel
+                        .connectors()
+                        .any(|channel_connector| channel_connector == *connector)
+                })
+                .and_then(|connector| connector.ecu_instance().ok())
+                .ok_or_else(|| {
+                    AutosarAbstractionError::InvalidParameter(format!(
+                        "The CanTpNode {} is not connected to the CAN channel",
+                        node.name().unwrap_or_default()
+                    ))
+                })?;
+            ecus.push((ecu, direction));
+        }
+
+        let data_triggering = trigger_npdu(&channel, &data_pdu, &ecus)?;
+        let flow_control_triggering = if let Some(flow_control_pdu) = self.flow_control_pdu() {
+            let fc_ecus: Vec<_> = ecus
+                .iter()
+                .map(|(ecu, direction)| {
+                    let fc_direction = match direction {
+                        CommunicationDirection::In => CommunicationDirection::Out,
+                        CommunicationDirection::Out => CommunicationDirection::In,
+                    };
+                    (ecu.clone(), fc_direction)
+                })
+                .collect();
+            Some(trigger_npdu(&channel, &flow_control_pdu, &fc_ecus)?)
+        } else {
+            None
+        };
+
+        Ok((data_triggering, flow_control_triggering))
+    }
+}
+
+// trigger an NPdu on the CAN channel, reusing an existing PduTriggering, and create pdu ports for the ECUs
+fn trigger_npdu(
+    channel: &CanPhysicalChannel,
+    npdu: &NPdu,
+    ecus: &[(EcuInstance, CommunicationDirection)],
+) -> Result<PduTriggering, AutosarAbstractionError> {
+    let pdu = Pdu::from(npdu.clone());
+    let pdu_triggering = if let Some(pdu_triggering) = channel
+        .pdu_triggerings()
+        .find(|pdu_triggering| pdu_triggering.pdu().as_ref() == Some(&pdu))
+    {
+        pdu_triggering
+    } else {
+        PduTriggering::new(&pdu, &PhysicalChannel::Can(channel.clone()))?
+    };
+
+    for (ecu, direction) in ecus {
+        pdu_triggering.create_pdu_port(ecu, *direction)?;
+    }
+    Ok(pdu_triggering)
 }
 
 //#########################################################
@@ -642,6 +874,90 @@ impl CanTpNode {
             .and_then(|elem| elem.get_reference_target().ok())
             .and_then(|target| CanCommunicationConnector::try_from(target).ok())
     }
+
+    /// set the minimum separation time (STmin, in seconds) between consecutive frames that this node requests as a receiver
+    pub fn set_st_min(&self, st_min: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        if let Some(st_min) = st_min {
+            self.element()
+                .get_or_create_sub_element(ElementName::StMin)?
+                .set_character_data(st_min)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::StMin);
+        }
+        Ok(())
+    }
+
+    /// get the minimum separation time (STmin, in seconds) between consecutive frames that this node requests as a receiver
+    #[must_use]
+    pub fn st_min(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::StMin)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_float())
+    }
+
+    /// set the maximum number of flow control wait frames (WFTmax) that this node sends as a receiver
+    pub fn set_max_fc_wait(&self, max_fc_wait: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(max_fc_wait) = max_fc_wait {
+            self.element()
+                .get_or_create_sub_element(ElementName::MaxFcWait)?
+                .set_character_data(u64::from(max_fc_wait))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::MaxFcWait);
+        }
+        Ok(())
+    }
+
+    /// get the maximum number of flow control wait frames (WFTmax) that this node sends as a receiver
+    #[must_use]
+    pub fn max_fc_wait(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::MaxFcWait)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_integer())
+    }
+
+    /// set the timeout Ar (in seconds) for the transmission of a frame by this node as a receiver
+    pub fn set_timeout_ar(&self, timeout_ar: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        if let Some(timeout_ar) = timeout_ar {
+            self.element()
+                .get_or_create_sub_element(ElementName::TimeoutAr)?
+                .set_character_data(timeout_ar)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::TimeoutAr);
+        }
+        Ok(())
+    }
+
+    /// get the timeout Ar (in seconds) for the transmission of a frame by this node as a receiver
+    #[must_use]
+    pub fn timeout_ar(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::TimeoutAr)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_float())
+    }
+
+    /// set the timeout As (in seconds) for the transmission of a frame by this node as a sender
+    pub fn set_timeout_as(&self, timeout_as: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        if let Some(timeout_as) = timeout_as {
+            self.element()
+                .get_or_create_sub_element(ElementName::TimeoutAs)?
+                .set_character_data(timeout_as)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::TimeoutAs);
+        }
+        Ok(())
+    }
+
+    /// get the timeout As (in seconds) for the transmission of a frame by this node as a sender
+    #[must_use]
+    pub fn timeout_as(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::TimeoutAs)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_float())
+    }
 }
 
 //#########################################################
@@ -752,12 +1068,61 @@ mod test {
 
         node.set_address(&address).unwrap();
         assert_eq!(node.address().unwrap(), address);
-        node.set_connector(&connector).unwrap();
-        assert_eq!(node.connector().unwrap(), connector);
+        // the pdus can't be triggered without a transmitter
+        assert!(connection.trigger_pdus().is_err());
         connection.set_transmitter(&node).unwrap();
         assert_eq!(connection.transmitter().unwrap(), node);
+        // the node is not connected to the channel yet
+        assert!(connection.trigger_pdus().is_err());
+        assert_eq!(can_channel.pdu_triggerings().count(), 0);
+        node.set_connector(&connector).unwrap();
+        assert_eq!(node.connector().unwrap(), connector);
 
         connection.add_receiver(&node).unwrap();
         assert_eq!(connection.receivers().count(), 1);
+        // flow control parameters of the connection
+        let fc_pdu = system.create_n_pdu("fc_pdu", &package, 8).unwrap();
+        connection.set_flow_control_pdu(Some(&fc_pdu)).unwrap();
+        assert_eq!(connection.flow_control_pdu().unwrap(), fc_pdu);
+        // setting the references doesn't trigger anything
+        assert_eq!(can_channel.pdu_triggerings().count(), 0);
+
+        // the data pdu and the flow control pdu are connected to the ECU of the node in both directions
+        let (data_pt, fc_pt) = connection.trigger_pdus().unwrap();
+        assert_eq!(data_pt.pdu(), Some(data_pdu2.clone().into()));
+        assert_eq!(data_pt.pdu_ports().count(), 2);
+        let fc_pt = fc_pt.unwrap();
+        assert_eq!(fc_pt.pdu(), Some(fc_pdu.clone().into()));
+        assert_eq!(fc_pt.pdu_ports().count(), 2);
+        // each pdu is only triggered once
+        connection.trigger_pdus().unwrap();
+        assert_eq!(can_channel.pdu_triggerings().count(), 2);
+        assert_eq!(data_pt.pdu_ports().count(), 2);
+        connection.set_flow_control_pdu(None).unwrap();
+        assert_eq!(connection.flow_control_pdu(), None);
+        connection.set_max_block_size(Some(8)).unwrap();
+        assert_eq!(connection.max_block_size(), Some(8));
+        connection.set_timeout_br(Some(0.07)).unwrap();
+        assert_eq!(connection.timeout_br(), Some(0.07));
+        connection.set_timeout_bs(Some(0.15)).unwrap();
+        assert_eq!(connection.timeout_bs(), Some(0.15));
+        connection.set_timeout_cr(Some(0.15)).unwrap();
+        assert_eq!(connection.timeout_cr(), Some(0.15));
+        connection.set_timeout_cs(Some(0.07)).unwrap();
+        assert_eq!(connection.timeout_cs(), Some(0.07));
+        connection.set_max_block_size(None).unwrap();
+        assert_eq!(connection.max_block_size(), None);
+
+        // flow control parameters of the node
+        node.set_st_min(Some(0.001)).unwrap();
+        assert_eq!(node.st_min(), Some(0.001));
+        node.set_max_fc_wait(Some(5)).unwrap();
+        assert_eq!(node.max_fc_wait(), Some(5));
+        node.set_timeout_ar(Some(0.07)).unwrap();
+        assert_eq!(node.timeout_ar(), Some(0.07));
+        node.set_timeout_as(Some(0.07)).unwrap();
+        assert_eq!(node.timeout_as(), Some(0.07));
+        node.set_st_min(None).unwrap();
+        assert_eq!(node.st_min(), None);
     }
 }
@@ -267,6 +267,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: Some("192.168.0.200".to_string()),
             network_mask: Some("255.255.255.0".to_string()),
+            dns_servers: vec![],
+            ttl: None,
         };
         let network_endpoint_1 = eth_channel
             .create_network_endpoint("local_endpoint", network_address_1, None)
@@ -286,6 +288,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: Some("192.168.0.200".to_string()),
             network_mask: Some("255.255.255.0".to_string()),
+            dns_servers: vec![],
+            ttl: None,
         };
         let network_endpoint_2 = eth_channel
             .create_network_endpoint("remote_endpoint", network_address_2, None)
@@ -5,8 +5,8 @@ use crate::{
     get_reference_parents, is_used,
     software_component::{ArgumentDataPrototype, ParameterDataPrototype, VariableDataPrototype},
 };
-use autosar_data::{ElementName, EnumItem};
-use datatype::{AbstractAutosarDataType, CompuMethod, DataConstr, Unit};
+use autosar_data::{AutosarDataError, ElementName, EnumItem};
+use datatype::{AbstractAutosarDataType, AbstractSwDataDefProps, CompuMethod, DataConstr, Unit};
 
 //#########################################################
 
@@ -18,6 +18,7 @@ pub struct ApplicationArrayDataType(Element);
 abstraction_element!(ApplicationArrayDataType, ApplicationArrayDataType);
 impl IdentifiableAbstractionElement for ApplicationArrayDataType {}
 impl AbstractAutosarDataType for ApplicationArrayDataType {}
+impl AbstractSwDataDefProps for ApplicationArrayDataType {}
 
 impl ApplicationArrayDataType {
     /// create a new application array data type in the given package
@@ -308,6 +309,7 @@ pub struct ApplicationRecordDataType(Element);
 abstraction_element!(ApplicationRecordDataType, ApplicationRecordDataType);
 impl IdentifiableAbstractionElement for ApplicationRecordDataType {}
 impl AbstractAutosarDataType for ApplicationRecordDataType {}
+impl AbstractSwDataDefProps for ApplicationRecordDataType {}
 
 impl ApplicationRecordDataType {
     /// create a new application record data type in the given package
@@ -352,6 +354,42 @@ impl ApplicationRecordDataType {
             .flat_map(|elements| elements.sub_elements())
             .filter_map(|element| ApplicationRecordElement::try_from(element).ok())
     }
+
+    /// move a record element of the record data type to a new position
+    ///
+    /// The position is the index of the record element in the list of record elements after the move
+    pub fn move_record_element(
+        &self,
+        record_element: &ApplicationRecordElement,
+        position: usize,
+    ) -> Result<(), AutosarAbstractionError> {
+        if record_element.record_data_type().as_ref() != Some(self) {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The record element is not part of this record data type".to_string(),
+            ));
+        }
+        let elements = self
+            .element()
+            .get_sub_element(ElementName::Elements)
+            .ok_or(AutosarDataError::ItemDeleted)?;
+        let mut new_order: Vec<Element> = elements
+            .sub_elements()
+            .filter(|elem| elem != record_element.element())
+            .collect();
+        if position > new_order.len() {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "Position {position} is out of range"
+            )));
+        }
+        new_order.insert(position, record_element.element().clone());
+
+        // moving each element to the end of the list in turn results in the new order
+        for elem in &new_order {
+            elements.move_element_here(elem)?;
+        }
+
+        Ok(())
+    }
 }
 
 //#########################################################
@@ -416,6 +454,13 @@ impl ApplicationRecordElement {
         Ok(())
     }
 
+    /// get the record data type that contains the record element
+    #[must_use]
+    pub fn record_data_type(&self) -> Option<ApplicationRecordDataType> {
+        let named_parent = self.element().named_parent().ok()??;
+        ApplicationRecordDataType::try_from(named_parent).ok()
+    }
+
     /// get the data type of the record element
     #[must_use]
     pub fn data_type(&self) -> Option<ApplicationDataType> {
@@ -438,6 +483,7 @@ pub struct ApplicationPrimitiveDataType(Element);
 abstraction_element!(ApplicationPrimitiveDataType, ApplicationPrimitiveDataType);
 impl IdentifiableAbstractionElement for ApplicationPrimitiveDataType {}
 impl AbstractAutosarDataType for ApplicationPrimitiveDataType {}
+impl AbstractSwDataDefProps for ApplicationPrimitiveDataType {}
 
 impl ApplicationPrimitiveDataType {
     /// create a new application primitive data type in the given package
@@ -929,6 +975,39 @@ mod tests {
             ApplicationDataType::Primitive(element_type)
         );
         assert_eq!(record_data_type.record_elements().next().unwrap(), record_element);
+        assert_eq!(record_element.record_data_type().unwrap(), record_data_type);
+
+        // reorder the record elements
+        let record_element2 = record_data_type
+            .create_record_element("Element2", &element_type)
+            .unwrap();
+        let record_element3 = record_data_type
+            .create_record_element("Element3", &element_type)
+            .unwrap();
+        record_data_type.move_record_element(&record_element3, 0).unwrap();
+        let elements: Vec<_> = record_data_type.record_elements().collect();
+        assert_eq!(
+            elements,
+            vec![record_element3.clone(), record_element.clone(), record_element2.clone()]
+        );
+        record_data_type.move_record_element(&record_element3, 2).unwrap();
+        let elements: Vec<_> = record_data_type.record_elements().collect();
+        assert_eq!(
+            elements,
+            vec![record_element.clone(), record_element2.clone(), record_element3.clone()]
+        );
+        assert!(record_data_type.move_record_element(&record_element3, 3).is_err());
+
+        // remove a record element
+        record_element2.remove(false).unwrap();
+        assert_eq!(record_data_type.record_elements().count(), 2);
+
+        // elements of other record data types cannot be moved
+        let record_data_type2 = ApplicationRecordDataType::new("Record2", &package).unwrap();
+        let other_element = record_data_type2
+            .create_record_element("Element", &element_type)
+            .unwrap();
+        assert!(record_data_type.move_record_element(&other_element, 0).is_err());
     }
 
     #[test]
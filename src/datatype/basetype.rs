@@ -162,6 +162,118 @@ impl SwBaseType {
 
 //#########################################################
 
+// name, bit length and encoding of the standard platform base types
+const STANDARD_BASE_TYPES: [(&str, u32, BaseTypeEncoding); 11] = [
+    ("boolean", 8, BaseTypeEncoding::Boolean),
+    ("float32", 32, BaseTypeEncoding::Ieee754),
+    ("float64", 64, BaseTypeEncoding::Ieee754),
+    ("sint8", 8, BaseTypeEncoding::TwosComplement),
+    ("sint16", 16, BaseTypeEncoding::TwosComplement),
+    ("sint32", 32, BaseTypeEncoding::TwosComplement),
+    ("sint64", 64, BaseTypeEncoding::TwosComplement),
+    ("uint8", 8, BaseTypeEncoding::None),
+    ("uint16", 16, BaseTypeEncoding::None),
+    ("uint32", 32, BaseTypeEncoding::None),
+    ("uint64", 64, BaseTypeEncoding::None),
+];
+
+/// A `BaseTypeLibrary` is a collection of `SwBaseType`s that can be looked up by name
+///
+/// # Example
+///
+/// ```
+/// # use autosar_data::*;
+/// # use autosar_data_abstraction::{*, datatype::*};
+/// # fn main() -> Result<(), AutosarAbstractionError> {
+/// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+/// let package = model.get_or_create_package("/BaseTypes")?;
+/// let library = BaseTypeLibrary::create_standard_types(&package)?;
+/// let uint8 = library.base_type("uint8").unwrap();
+/// assert_eq!(uint8.bit_length(), Some(8));
+/// # Ok(())}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseTypeLibrary {
+    base_types: Vec<SwBaseType>,
+}
+
+impl BaseTypeLibrary {
+    /// create the standard platform base types in the given package
+    ///
+    /// The base types are uint8, uint16, uint32, uint64, sint8, sint16, sint32, sint64, float32, float64 and boolean.
+    /// Each base type uses its name as its native declaration.
+    /// Base types that already exist in the package are reused, so this function can be called repeatedly.
+    /// If the function fails, the base types that were created by this call are removed again.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] a base type with a standard name already exists in the package,
+    ///   but its bit length or encoding differs from the standard definition
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create a SW-BASE-TYPE element,
+    ///   e.g. because a different element with the same name already exists in the package
+    pub fn create_standard_types(package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let existing = Self::from_package(package);
+        // check all existing base types first, so that a mismatch is reported before anything is created
+        for (name, bit_length, base_type_encoding) in STANDARD_BASE_TYPES {
+            if let Some(base_type) = existing.base_type(name)
+                && (base_type.bit_length() != Some(bit_length)
+                    || base_type.base_type_encoding() != Some(base_type_encoding))
+            {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "The existing base type {name} does not match the standard definition: \
+                    expected {bit_length} bits with encoding {base_type_encoding}"
+                )));
+            }
+        }
+
+        let mut base_types = Vec::with_capacity(STANDARD_BASE_TYPES.len());
+        let mut created = Vec::new();
+        for (name, bit_length, base_type_encoding) in STANDARD_BASE_TYPES {
+            if let Some(base_type) = existing.base_type(name) {
+                base_types.push(base_type.clone());
+                continue;
+            }
+            match SwBaseType::new(name, package, bit_length, base_type_encoding, None, None, Some(name)) {
+                Ok(base_type) => {
+                    created.push(base_type.clone());
+                    base_types.push(base_type);
+                }
+                Err(err) => {
+                    for base_type in created {
+                        base_type.remove(false)?;
+                    }
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(Self { base_types })
+    }
+
+    /// create a `BaseTypeLibrary` containing all `SwBaseType`s of the given package
+    #[must_use]
+    pub fn from_package(package: &ArPackage) -> Self {
+        Self {
+            base_types: package.elements_of_type::<SwBaseType>().collect(),
+        }
+    }
+
+    /// get a base type by its name
+    #[must_use]
+    pub fn base_type(&self, name: &str) -> Option<&SwBaseType> {
+        self.base_types
+            .iter()
+            .find(|base_type| base_type.name().as_deref() == Some(name))
+    }
+
+    /// iterate over all base types in the library
+    pub fn base_types(&self) -> impl Iterator<Item = &SwBaseType> {
+        self.base_types.iter()
+    }
+}
+
+//#########################################################
+
 /// `BaseTypeEncoding` describes the encoding of a basic data type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BaseTypeEncoding {
@@ -288,4 +400,40 @@ mod tests {
         assert_eq!(sw_base_type.mem_alignment(), Some(8));
         assert_eq!(sw_base_type.native_declaration(), Some("uint32".to_string()));
     }
+
+    #[test]
+    fn test_base_type_library() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/BaseTypes").unwrap();
+
+        let library = BaseTypeLibrary::create_standard_types(&package).unwrap();
+        assert_eq!(library.base_types().count(), 11);
+        let sint16 = library.base_type("sint16").unwrap();
+        assert_eq!(sint16.bit_length(), Some(16));
+        assert_eq!(sint16.base_type_encoding(), Some(BaseTypeEncoding::TwosComplement));
+        assert_eq!(sint16.native_declaration().as_deref(), Some("sint16"));
+        let float64 = library.base_type("float64").unwrap();
+        assert_eq!(float64.base_type_encoding(), Some(BaseTypeEncoding::Ieee754));
+        assert!(library.base_type("char").is_none());
+
+        // calling the function again reuses the existing base types
+        let library2 = BaseTypeLibrary::create_standard_types(&package).unwrap();
+        assert_eq!(library, library2);
+        assert_eq!(package.elements().count(), 11);
+        assert_eq!(BaseTypeLibrary::from_package(&package).base_types().count(), 11);
+
+        // an existing base type that doesn't match the standard definition is an error
+        let package2 = model.get_or_create_package("/BaseTypes2").unwrap();
+        package2
+            .create_sw_base_type("uint16", 8, BaseTypeEncoding::None, None, None, None)
+            .unwrap();
+        assert!(BaseTypeLibrary::create_standard_types(&package2).is_err());
+        assert_eq!(package2.elements().count(), 1);
+
+        // base types created before a failure are removed again
+        let package3 = model.get_or_create_package("/BaseTypes3").unwrap();
+        package3.create_system_signal("uint32").unwrap();
+        assert!(BaseTypeLibrary::create_standard_types(&package3).is_err());
+        assert_eq!(package3.elements().count(), 1);
+    }
 }
@@ -299,9 +299,7 @@ impl CompuMethod {
                 let compu_scale = CompuScale::try_from(compu_scale_elem).ok()?;
                 let content = compu_scale.content()?;
                 if let CompuScaleContent::RationalCoeffs { numerator, denominator } = content {
-                    let offset = numerator[0];
-                    let factor = numerator[1];
-                    let divisor = denominator[0];
+                    let (offset, factor, divisor) = linear_coefficients(&numerator, &denominator)?;
                     let lower_limit = compu_scale.lower_limit();
                     let upper_limit = compu_scale.upper_limit();
 
@@ -332,9 +330,7 @@ impl CompuMethod {
                     let upper_limit = compu_scale.upper_limit()?;
                     let content = compu_scale.content()?;
                     if let CompuScaleContent::RationalCoeffs { numerator, denominator } = content {
-                        let offset = numerator[0];
-                        let factor = numerator[1];
-                        let divisor = denominator[0];
+                        let (offset, factor, divisor) = linear_coefficients(&numerator, &denominator)?;
 
                         scale_linear_content.push(CompuMethodScaleLinearContent {
                             direction,
@@ -430,9 +426,7 @@ impl CompuMethod {
                     let upper_limit = compu_scale.upper_limit()?;
                     let content = compu_scale.content()?;
                     if let CompuScaleContent::RationalCoeffs { numerator, denominator } = content {
-                        let offset = numerator[0];
-                        let factor = numerator[1];
-                        let divisor = denominator[0];
+                        let (offset, factor, divisor) = linear_coefficients(&numerator, &denominator)?;
 
                         scale_linear_content.push(CompuMethodScaleLinearContent {
                             direction,
@@ -633,6 +627,70 @@ impl CompuMethod {
             .flat_map(|elem: Element| elem.sub_elements())
             .filter_map(|cs| CompuScale::try_from(cs).ok())
     }
+
+    /// Set the default value of the internal-to-physical conversion
+    ///
+    /// The default value is used for all internal values that are not covered by any `CompuScale`,
+    /// e.g. a text table entry for undefined values.
+    /// Calling `set_content` removes the default value, so it should be set afterwards.
+    pub fn set_int_to_phys_default_value(
+        &self,
+        default_value: Option<CompuDefaultValue>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(default_value) = default_value {
+            let int_to_phys = self
+                .element()
+                .get_or_create_sub_element(ElementName::CompuInternalToPhys)?;
+            let _ = int_to_phys.remove_sub_element_kind(ElementName::CompuDefaultValue);
+            let default_value_elem = int_to_phys.create_sub_element(ElementName::CompuDefaultValue)?;
+            match default_value {
+                CompuDefaultValue::TextConstant(text) => {
+                    default_value_elem
+                        .create_sub_element(ElementName::Vt)?
+                        .set_character_data(text)?;
+                }
+                CompuDefaultValue::NumericConstant(value) => {
+                    default_value_elem
+                        .create_sub_element(ElementName::V)?
+                        .set_character_data(value)?;
+                }
+            }
+        } else if let Some(int_to_phys) = self.element().get_sub_element(ElementName::CompuInternalToPhys) {
+            let _ = int_to_phys.remove_sub_element_kind(ElementName::CompuDefaultValue);
+        }
+
+        Ok(())
+    }
+
+    /// Get the default value of the internal-to-physical conversion
+    #[must_use]
+    pub fn int_to_phys_default_value(&self) -> Option<CompuDefaultValue> {
+        let default_value_elem = self
+            .element()
+            .get_sub_element(ElementName::CompuInternalToPhys)?
+            .get_sub_element(ElementName::CompuDefaultValue)?;
+        if let Some(text) = default_value_elem
+            .get_sub_element(ElementName::Vt)
+            .and_then(|vt| vt.character_data())
+            .and_then(|cdata| cdata.string_value())
+        {
+            Some(CompuDefaultValue::TextConstant(text))
+        } else {
+            default_value_elem
+                .get_sub_element(ElementName::V)?
+                .character_data()?
+                .parse_float()
+                .map(CompuDefaultValue::NumericConstant)
+        }
+    }
+}
+
+// extract (offset, factor, divisor) from the coefficients of a linear rational function
+fn linear_coefficients(numerator: &[f64], denominator: &[f64]) -> Option<(f64, f64, f64)> {
+    let offset = *numerator.first()?;
+    let factor = *numerator.get(1)?;
+    let divisor = denominator.first().copied().unwrap_or(1.0);
+    Some((offset, factor, divisor))
 }
 
 //#########################################################
@@ -828,11 +886,13 @@ impl CompuScale {
                     numerator.push(value);
                 }
             }
+            // the denominator is optional; if it is missing, then the denominator is 1
             let mut denominator = vec![];
-            let compu_denominator = compu_rational_coeffs.get_sub_element(ElementName::CompuDenominator)?;
-            for v in compu_denominator.sub_elements() {
-                if let Some(value) = v.character_data().and_then(|cdata| cdata.parse_float()) {
-                    denominator.push(value);
+            if let Some(compu_denominator) = compu_rational_coeffs.get_sub_element(ElementName::CompuDenominator) {
+                for v in compu_denominator.sub_elements() {
+                    if let Some(value) = v.character_data().and_then(|cdata| cdata.parse_float()) {
+                        denominator.push(value);
+                    }
                 }
             }
             return Some(CompuScaleContent::RationalCoeffs { numerator, denominator });
@@ -873,6 +933,17 @@ pub enum CompuScaleContent {
 
 //#########################################################
 
+/// Default value of a `CompuMethod` conversion
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompuDefaultValue {
+    /// Text constant
+    TextConstant(String),
+    /// Numeric constant
+    NumericConstant(f64),
+}
+
+//#########################################################
+
 /// Content of a `CompuMethod`
 #[derive(Debug, Clone, PartialEq)]
 pub enum CompuMethodContent {
@@ -1315,4 +1386,77 @@ mod test {
         );
         assert_eq!(CompuMethodCategory::TabNoInterpretation.to_string(), "TAB_NOINTP");
     }
+
+    #[test]
+    fn compu_method_default_value() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/Package").unwrap();
+
+        let content = CompuMethodContent::TextTable(vec![CompuMethodTextTableContent {
+            text: "OFF".to_string(),
+            value: 0.0,
+        }]);
+        let compu_method = CompuMethod::new("compu_method", &package, content).unwrap();
+        assert_eq!(compu_method.int_to_phys_default_value(), None);
+
+        compu_method
+            .set_int_to_phys_default_value(Some(CompuDefaultValue::TextConstant("UNDEFINED".to_string())))
+            .unwrap();
+        assert_eq!(
+            compu_method.int_to_phys_default_value(),
+            Some(CompuDefaultValue::TextConstant("UNDEFINED".to_string()))
+        );
+        compu_method
+            .set_int_to_phys_default_value(Some(CompuDefaultValue::NumericConstant(-1.0)))
+            .unwrap();
+        assert_eq!(
+            compu_method.int_to_phys_default_value(),
+            Some(CompuDefaultValue::NumericConstant(-1.0))
+        );
+        // the default value does not interfere with the content
+        assert_eq!(compu_method.int_to_phys_compu_scales().count(), 1);
+        assert!(matches!(compu_method.content(), Some(CompuMethodContent::TextTable(_))));
+
+        compu_method.set_int_to_phys_default_value(None).unwrap();
+        assert_eq!(compu_method.int_to_phys_default_value(), None);
+    }
+
+    #[test]
+    fn compu_method_incomplete_coefficients() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/Package").unwrap();
+
+        // a linear conversion without denominator coefficients uses a divisor of 1
+        let content = CompuMethodContent::Linear(CompuMethodLinearContent {
+            direction: CompuScaleDirection::IntToPhys,
+            offset: 0.0,
+            factor: 1.0,
+            divisor: 1.0,
+            lower_limit: None,
+            upper_limit: None,
+        });
+        let compu_method = CompuMethod::new("compu_method", &package, content).unwrap();
+        let compu_scale = compu_method.int_to_phys_compu_scales().next().unwrap();
+        compu_scale
+            .set_content(CompuScaleContent::RationalCoeffs {
+                numerator: vec![5.0, 2.0],
+                denominator: vec![],
+            })
+            .unwrap();
+        let Some(CompuMethodContent::Linear(linear)) = compu_method.content() else {
+            panic!("expected linear content");
+        };
+        assert_eq!(linear.offset, 5.0);
+        assert_eq!(linear.factor, 2.0);
+        assert_eq!(linear.divisor, 1.0);
+
+        // a linear conversion with a missing factor cannot be decoded
+        compu_scale
+            .set_content(CompuScaleContent::RationalCoeffs {
+                numerator: vec![5.0],
+                denominator: vec![1.0],
+            })
+            .unwrap();
+        assert_eq!(compu_method.content(), None);
+    }
 }
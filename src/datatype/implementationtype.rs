@@ -6,7 +6,7 @@ use crate::{
     software_component::{ArgumentDataPrototype, ParameterDataPrototype, VariableDataPrototype},
 };
 use autosar_data::ElementName;
-use datatype::{AbstractAutosarDataType, CompuMethod, DataConstr, SwBaseType};
+use datatype::{AbstractAutosarDataType, AbstractSwDataDefProps, CompuMethod, DataConstr, SwBaseType};
 use std::fmt::Display;
 
 /// Interface for implementation data types, which provides default implementations for common operations
@@ -109,6 +109,22 @@ pub trait AbstractImplementationDataType: IdentifiableAbstractionElement {
             .parse_integer()
     }
 
+    /// get the array size semantics of this implementation data type [category: ARRAY]
+    fn array_size_semantics(&self) -> Option<ArraySizeSemantics> {
+        let category = self.category()?;
+        if category != ImplementationDataCategory::Array {
+            return None;
+        }
+        self.sub_elements()
+            .next()?
+            .element()
+            .get_sub_element(ElementName::ArraySizeSemantics)?
+            .character_data()?
+            .enum_value()?
+            .try_into()
+            .ok()
+    }
+
     /// get the data pointer target of this implementation data type [[category: `DATA_REFERENCE`]]
     fn data_pointer_target(&self) -> Option<DataPointerTarget> {
         let category = self.category()?;
@@ -162,11 +178,19 @@ pub trait AbstractImplementationDataType: IdentifiableAbstractionElement {
             }),
             ImplementationDataCategory::Array => {
                 let element_settings = self.sub_elements().next()?.settings()?;
-                Some(ImplementationDataTypeSettings::Array {
-                    name: self.name()?,
-                    length: self.array_size()?,
-                    element_type: Box::new(element_settings),
-                })
+                if self.array_size_semantics() == Some(ArraySizeSemantics::VariableSize) {
+                    Some(ImplementationDataTypeSettings::VariableArray {
+                        name: self.name()?,
+                        max_length: self.array_size()?,
+                        element_type: Box::new(element_settings),
+                    })
+                } else {
+                    Some(ImplementationDataTypeSettings::Array {
+                        name: self.name()?,
+                        length: self.array_size()?,
+                        element_type: Box::new(element_settings),
+                    })
+                }
             }
             ImplementationDataCategory::Structure => {
                 let elements = self
@@ -239,6 +263,7 @@ pub struct ImplementationDataType(Element);
 abstraction_element!(ImplementationDataType, ImplementationDataType);
 impl IdentifiableAbstractionElement for ImplementationDataType {}
 impl AbstractAutosarDataType for ImplementationDataType {}
+impl AbstractSwDataDefProps for ImplementationDataType {}
 impl AbstractImplementationDataType for ImplementationDataType {}
 
 impl ImplementationDataType {
@@ -405,7 +430,6 @@ fn apply_impl_data_settings(
     let _ = element.remove_sub_element_kind(ElementName::Category);
     let _ = element.remove_sub_element_kind(ElementName::SubElements);
     let _ = element.remove_sub_element_kind(ElementName::SwDataDefProps);
-    // DynamicArraySizeProfile is part of the definition of variable-sized arrays, which are not supported (yet?)
     let _ = element.remove_sub_element_kind(ElementName::DynamicArraySizeProfile);
 
     match settings {
@@ -453,6 +477,32 @@ fn apply_impl_data_settings(
                 .create_sub_element(ElementName::ArraySizeSemantics)?
                 .set_character_data(EnumItem::FixedSize)?;
         }
+        ImplementationDataTypeSettings::VariableArray {
+            max_length,
+            element_type,
+            ..
+        } => {
+            element
+                .create_sub_element(ElementName::Category)?
+                .set_character_data("ARRAY")?;
+            element
+                .create_sub_element(ElementName::DynamicArraySizeProfile)?
+                .set_character_data("VSA_LINEAR")?;
+            let sub_elements = element.get_or_create_sub_element(ElementName::SubElements)?;
+            let array_element = ImplementationDataTypeElement::new(&sub_elements, element_type)?;
+            array_element
+                .element()
+                .create_sub_element(ElementName::ArraySize)?
+                .set_character_data(u64::from(*max_length))?;
+            array_element
+                .element()
+                .create_sub_element(ElementName::ArraySizeHandling)?
+                .set_character_data(EnumItem::AllIndicesSameArraySize)?;
+            array_element
+                .element()
+                .create_sub_element(ElementName::ArraySizeSemantics)?
+                .set_character_data(EnumItem::VariableSize)?;
+        }
         ImplementationDataTypeSettings::Structure { elements, .. } => {
             element
                 .create_sub_element(ElementName::Category)?
@@ -627,6 +677,15 @@ pub enum ImplementationDataTypeSettings {
         /// settings to construct the element type of the array
         element_type: Box<ImplementationDataTypeSettings>,
     },
+    /// An array of elements with a variable number of elements, up to a maximum length
+    VariableArray {
+        /// the name of the data type
+        name: String,
+        /// the maximum length of the array
+        max_length: u32,
+        /// settings to construct the element type of the array
+        element_type: Box<ImplementationDataTypeSettings>,
+    },
     /// A structure of elements
     Structure {
         /// the name of the structure
@@ -674,6 +733,7 @@ impl ImplementationDataTypeSettings {
         match self {
             ImplementationDataTypeSettings::Value { name, .. } => name,
             ImplementationDataTypeSettings::Array { name, .. } => name,
+            ImplementationDataTypeSettings::VariableArray { name, .. } => name,
             ImplementationDataTypeSettings::Structure { name, .. } => name,
             ImplementationDataTypeSettings::Union { name, .. } => name,
             ImplementationDataTypeSettings::DataReference { name, .. } => name,
@@ -685,6 +745,41 @@ impl ImplementationDataTypeSettings {
 
 //#########################################################
 
+/// The size semantics of an array implementation data type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArraySizeSemantics {
+    /// The array always has the same number of elements
+    FixedSize,
+    /// The number of elements of the array can vary at runtime, up to the maximum size
+    VariableSize,
+}
+
+impl TryFrom<EnumItem> for ArraySizeSemantics {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::FixedSize => Ok(ArraySizeSemantics::FixedSize),
+            EnumItem::VariableSize => Ok(ArraySizeSemantics::VariableSize),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "ArraySizeSemantics".to_string(),
+            }),
+        }
+    }
+}
+
+impl From<ArraySizeSemantics> for EnumItem {
+    fn from(value: ArraySizeSemantics) -> Self {
+        match value {
+            ArraySizeSemantics::FixedSize => EnumItem::FixedSize,
+            ArraySizeSemantics::VariableSize => EnumItem::VariableSize,
+        }
+    }
+}
+
+//#########################################################
+
 /// The target of an `ImplementationDataType` with category `DataReference`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DataPointerTarget {
@@ -779,6 +874,16 @@ mod tests {
                     name: "DataReferenceToImpl".to_string(),
                     target: DataPointerTarget::ImplementationDataType(other_impl_data_type.clone()),
                 },
+                ImplementationDataTypeSettings::VariableArray {
+                    name: "MyVariableArray".to_string(),
+                    max_length: 20,
+                    element_type: Box::new(ImplementationDataTypeSettings::TypeReference {
+                        name: "VariableArrayElement".to_string(),
+                        reftype: other_impl_data_type.clone(),
+                        compu_method: None,
+                        data_constraint: None,
+                    }),
+                },
             ],
         };
         let impl_data_type = ImplementationDataType::new(&package, &settings).unwrap();
@@ -786,7 +891,7 @@ mod tests {
         assert_eq!(impl_data_type.category(), Some(ImplementationDataCategory::Structure));
 
         let sub_elements = impl_data_type.sub_elements().collect::<Vec<_>>();
-        assert_eq!(sub_elements.len(), 6);
+        assert_eq!(sub_elements.len(), 7);
         assert_eq!(sub_elements[0].category(), Some(ImplementationDataCategory::Union));
         assert_eq!(sub_elements[1].category(), Some(ImplementationDataCategory::Value));
         assert_eq!(sub_elements[2].category(), Some(ImplementationDataCategory::Array));
@@ -810,6 +915,16 @@ mod tests {
             sub_elements[5].data_pointer_target(),
             Some(DataPointerTarget::ImplementationDataType(other_impl_data_type.clone()))
         );
+        assert_eq!(
+            sub_elements[2].array_size_semantics(),
+            Some(ArraySizeSemantics::FixedSize)
+        );
+        assert_eq!(sub_elements[6].category(), Some(ImplementationDataCategory::Array));
+        assert_eq!(sub_elements[6].array_size(), Some(20));
+        assert_eq!(
+            sub_elements[6].array_size_semantics(),
+            Some(ArraySizeSemantics::VariableSize)
+        );
 
         let settings_read = impl_data_type.settings().unwrap();
         assert_eq!(settings, settings_read);
@@ -2,8 +2,11 @@ use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, Element, IdentifiableAbstractionElement,
     abstraction_element, datatype,
 };
-use autosar_data::ElementName;
-use datatype::{ApplicationDataType, ImplementationDataType};
+use autosar_data::{ElementName, EnumItem};
+use datatype::{
+    AbstractImplementationDataType, ApplicationArraySize, ApplicationDataType, ApplicationPrimitiveCategory,
+    ImplementationDataType, ImplementationDataTypeSettings,
+};
 
 /// A [`DataTypeMappingSet`] contains `DataTypeMap`s
 ///
@@ -41,6 +44,16 @@ impl DataTypeMappingSet {
             .flat_map(|maps| maps.sub_elements())
             .filter_map(|elem| DataTypeMap::try_from(elem).ok())
     }
+
+    /// Check that all `DataTypeMap`s in the `DataTypeMappingSet` map structurally compatible data types
+    ///
+    /// See [`DataTypeMap::check_compatibility`] for details
+    pub fn check_compatibility(&self) -> Result<(), AutosarAbstractionError> {
+        for data_type_map in self.data_type_maps() {
+            data_type_map.check_compatibility()?;
+        }
+        Ok(())
+    }
 }
 
 //#########################################################
@@ -91,6 +104,267 @@ impl DataTypeMap {
             .try_into()
             .ok()
     }
+
+    /// Check that the `ApplicationDataType` and the `ImplementationDataType` of the `DataTypeMap` are structurally compatible
+    ///
+    /// Type references in the implementation data type are resolved before the comparison. The rules are:
+    /// - primitive application data types with the category VALUE or BOOLEAN require an implementation data type with category VALUE
+    /// - other primitive application data types (e.g. STRING, CURVE) may also be mapped to arrays
+    /// - application arrays require implementation arrays with the same size and size semantics and compatible elements
+    /// - application records require implementation structures with elements of the same names and compatible types
+    pub fn check_compatibility(&self) -> Result<(), AutosarAbstractionError> {
+        let application_data_type = self
+            .application_data_type()
+            .ok_or(AutosarAbstractionError::InvalidParameter(
+                "The DataTypeMap does not reference an ApplicationDataType".to_string(),
+            ))?;
+        let implementation_settings = self
+            .implementation_data_type()
+            .and_then(|impl_data_type| impl_data_type.settings())
+            .ok_or(AutosarAbstractionError::InvalidParameter(
+                "The DataTypeMap does not reference a valid ImplementationDataType".to_string(),
+            ))?;
+
+        check_type_compatibility(&application_data_type, &implementation_settings)
+    }
+}
+
+//#########################################################
+
+/// A `TextTableMapping` maps the values of two text table (enumeration) data types
+///
+/// It is used when the enumeration values of an application data type differ from the values that
+/// are used for the same texts by the implementation data type or by the signal on the bus.
+/// The first value of each pair belongs to the data element, the second value belongs to the signal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextTableMapping {
+    /// if true, each value is mapped to the same value and the value pairs are not needed
+    pub identical_mapping: Option<bool>,
+    /// the direction in which the mapping is applied
+    pub mapping_direction: Option<MappingDirection>,
+    /// the mapped pairs of values as (first value, second value)
+    pub value_pairs: Vec<(f64, f64)>,
+}
+
+impl TextTableMapping {
+    pub(crate) fn set(
+        parent: &Element,
+        element_name: ElementName,
+        mapping: Option<&TextTableMapping>,
+    ) -> Result<(), AutosarAbstractionError> {
+        let _ = parent.remove_sub_element_kind(element_name);
+        let Some(mapping) = mapping else {
+            return Ok(());
+        };
+
+        let mapping_elem = parent.create_sub_element(element_name)?;
+        if let Some(identical_mapping) = mapping.identical_mapping {
+            mapping_elem
+                .create_sub_element(ElementName::IdenticalMapping)?
+                .set_character_data(identical_mapping)?;
+        }
+        if let Some(mapping_direction) = mapping.mapping_direction {
+            mapping_elem
+                .create_sub_element(ElementName::MappingDirection)?
+                .set_character_data::<EnumItem>(mapping_direction.into())?;
+        }
+        if !mapping.value_pairs.is_empty() {
+            let value_pairs = mapping_elem.create_sub_element(ElementName::ValuePairs)?;
+            for (first_value, second_value) in &mapping.value_pairs {
+                let value_pair = value_pairs.create_sub_element(ElementName::TextTableValuePair)?;
+                value_pair
+                    .create_sub_element(ElementName::FirstValue)?
+                    .set_character_data(*first_value)?;
+                value_pair
+                    .create_sub_element(ElementName::SecondValue)?
+                    .set_character_data(*second_value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn get(parent: &Element, element_name: ElementName) -> Option<Self> {
+        let mapping_elem = parent.get_sub_element(element_name)?;
+        let identical_mapping = mapping_elem
+            .get_sub_element(ElementName::IdenticalMapping)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_bool());
+        let mapping_direction = mapping_elem
+            .get_sub_element(ElementName::MappingDirection)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.enum_value())
+            .and_then(|enumitem| MappingDirection::try_from(enumitem).ok());
+        let value_pairs = mapping_elem
+            .get_sub_element(ElementName::ValuePairs)
+            .into_iter()
+            .flat_map(|value_pairs| value_pairs.sub_elements())
+            .filter_map(|value_pair| {
+                let first_value = value_pair
+                    .get_sub_element(ElementName::FirstValue)?
+                    .character_data()?
+                    .parse_float()?;
+                let second_value = value_pair
+                    .get_sub_element(ElementName::SecondValue)?
+                    .character_data()?
+                    .parse_float()?;
+                Some((first_value, second_value))
+            })
+            .collect();
+
+        Some(Self {
+            identical_mapping,
+            mapping_direction,
+            value_pairs,
+        })
+    }
+}
+
+//#########################################################
+
+/// The direction in which a [`TextTableMapping`] is applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MappingDirection {
+    /// the mapping is applied in both directions
+    Bidirectional,
+    /// the mapping is only applied from the first to the second value
+    FirstToSecond,
+    /// the mapping is only applied from the second to the first value
+    SecondToFirst,
+}
+
+impl From<MappingDirection> for EnumItem {
+    fn from(value: MappingDirection) -> Self {
+        match value {
+            MappingDirection::Bidirectional => EnumItem::Bidirectional,
+            MappingDirection::FirstToSecond => EnumItem::FirstToSecond,
+            MappingDirection::SecondToFirst => EnumItem::SecondToFirst,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for MappingDirection {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::Bidirectional => Ok(MappingDirection::Bidirectional),
+            EnumItem::FirstToSecond => Ok(MappingDirection::FirstToSecond),
+            EnumItem::SecondToFirst => Ok(MappingDirection::SecondToFirst),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "MappingDirection".to_string(),
+            }),
+        }
+    }
+}
+
+//#########################################################
+
+// maximum depth of nested type references; this guards against reference loops in invalid models
+const MAX_TYPE_REFERENCE_DEPTH: usize = 32;
+
+// follow type references until a type with a different category is found
+fn resolve_type_references(
+    settings: &ImplementationDataTypeSettings,
+) -> Result<ImplementationDataTypeSettings, AutosarAbstractionError> {
+    let mut settings = settings.clone();
+    for _ in 0..MAX_TYPE_REFERENCE_DEPTH {
+        let ImplementationDataTypeSettings::TypeReference { reftype, .. } = &settings else {
+            return Ok(settings);
+        };
+        settings = reftype
+            .settings()
+            .ok_or(AutosarAbstractionError::InvalidParameter(format!(
+                "The referenced ImplementationDataType {} is invalid",
+                reftype.name().as_deref().unwrap_or("(invalid)")
+            )))?;
+    }
+
+    Err(AutosarAbstractionError::InvalidParameter(format!(
+        "Too many nested type references in ImplementationDataType {}",
+        settings.name()
+    )))
+}
+
+fn check_type_compatibility(
+    application_data_type: &ApplicationDataType,
+    implementation_settings: &ImplementationDataTypeSettings,
+) -> Result<(), AutosarAbstractionError> {
+    let implementation_settings = resolve_type_references(implementation_settings)?;
+    let incompatible = || {
+        AutosarAbstractionError::InvalidParameter(format!(
+            "ApplicationDataType {} is not compatible with ImplementationDataType {}",
+            application_data_type.name().as_deref().unwrap_or("(invalid)"),
+            implementation_settings.name()
+        ))
+    };
+
+    match (application_data_type, &implementation_settings) {
+        (ApplicationDataType::Primitive(_), ImplementationDataTypeSettings::Value { .. }) => Ok(()),
+        (
+            ApplicationDataType::Primitive(primitive),
+            ImplementationDataTypeSettings::Array { .. } | ImplementationDataTypeSettings::VariableArray { .. },
+        ) => {
+            if matches!(
+                primitive.category(),
+                Some(ApplicationPrimitiveCategory::Value | ApplicationPrimitiveCategory::Boolean)
+            ) {
+                Err(incompatible())
+            } else {
+                Ok(())
+            }
+        }
+        (
+            ApplicationDataType::Array(array),
+            ImplementationDataTypeSettings::Array {
+                length, element_type, ..
+            },
+        ) => {
+            if array.size() != Some(ApplicationArraySize::Fixed(u64::from(*length))) {
+                return Err(incompatible());
+            }
+            let element_data_type = array
+                .array_element()
+                .and_then(|array_element| array_element.data_type())
+                .ok_or_else(incompatible)?;
+            check_type_compatibility(&element_data_type, element_type)
+        }
+        (
+            ApplicationDataType::Array(array),
+            ImplementationDataTypeSettings::VariableArray {
+                max_length,
+                element_type,
+                ..
+            },
+        ) => {
+            if array.size() != Some(ApplicationArraySize::VariableLinear(u64::from(*max_length))) {
+                return Err(incompatible());
+            }
+            let element_data_type = array
+                .array_element()
+                .and_then(|array_element| array_element.data_type())
+                .ok_or_else(incompatible)?;
+            check_type_compatibility(&element_data_type, element_type)
+        }
+        (ApplicationDataType::Record(record), ImplementationDataTypeSettings::Structure { elements, .. }) => {
+            let record_elements: Vec<_> = record.record_elements().collect();
+            if record_elements.len() != elements.len() {
+                return Err(incompatible());
+            }
+            for record_element in record_elements {
+                let record_element_name = record_element.name().ok_or_else(incompatible)?;
+                let impl_element = elements
+                    .iter()
+                    .find(|element| element.name() == record_element_name)
+                    .ok_or_else(incompatible)?;
+                let record_element_type = record_element.data_type().ok_or_else(incompatible)?;
+                check_type_compatibility(&record_element_type, impl_element)?;
+            }
+            Ok(())
+        }
+        _ => Err(incompatible()),
+    }
 }
 
 //#########################################################
@@ -101,8 +375,7 @@ mod tests {
     use crate::AutosarModelAbstraction;
     use autosar_data::AutosarVersion;
     use datatype::{
-        ApplicationPrimitiveCategory, ApplicationPrimitiveDataType, BaseTypeEncoding, ImplementationDataTypeSettings,
-        SwBaseType,
+        ApplicationArrayDataType, ApplicationPrimitiveDataType, ApplicationRecordDataType, BaseTypeEncoding, SwBaseType,
     };
 
     #[test]
@@ -145,4 +418,110 @@ mod tests {
 
         assert_eq!(mapping_set.data_type_maps().count(), 1);
     }
+
+    #[test]
+    fn test_check_compatibility() {
+        let model = AutosarModelAbstraction::create("test.arxml", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/DataTypeMappingSets").unwrap();
+        let mapping_set = DataTypeMappingSet::new("MappingSet", &package).unwrap();
+
+        let base_type =
+            SwBaseType::new("uint8", &package, 8, BaseTypeEncoding::None, None, None, Some("uint8")).unwrap();
+        let value_settings = ImplementationDataTypeSettings::Value {
+            name: "Element".to_string(),
+            base_type: base_type.clone(),
+            compu_method: None,
+            data_constraint: None,
+        };
+        let impl_value = ImplementationDataType::new(&package, &value_settings).unwrap();
+        let impl_array = ImplementationDataType::new(
+            &package,
+            &ImplementationDataTypeSettings::Array {
+                name: "ImplArray".to_string(),
+                length: 4,
+                element_type: Box::new(value_settings.clone()),
+            },
+        )
+        .unwrap();
+        let impl_variable_array = ImplementationDataType::new(
+            &package,
+            &ImplementationDataTypeSettings::VariableArray {
+                name: "ImplVariableArray".to_string(),
+                max_length: 10,
+                element_type: Box::new(value_settings.clone()),
+            },
+        )
+        .unwrap();
+        let impl_struct = ImplementationDataType::new(
+            &package,
+            &ImplementationDataTypeSettings::Structure {
+                name: "ImplStruct".to_string(),
+                elements: vec![
+                    value_settings.clone(),
+                    ImplementationDataTypeSettings::TypeReference {
+                        name: "Ref".to_string(),
+                        reftype: impl_value.clone(),
+                        compu_method: None,
+                        data_constraint: None,
+                    },
+                ],
+            },
+        )
+        .unwrap();
+
+        let app_value: ApplicationDataType = ApplicationPrimitiveDataType::new(
+            "AppValue",
+            &package,
+            ApplicationPrimitiveCategory::Value,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .into();
+        let app_array: ApplicationDataType =
+            ApplicationArrayDataType::new("AppArray", &package, &app_value, ApplicationArraySize::Fixed(4))
+                .unwrap()
+                .into();
+        let app_variable_array: ApplicationDataType = ApplicationArrayDataType::new(
+            "AppVariableArray",
+            &package,
+            &app_value,
+            ApplicationArraySize::VariableLinear(10),
+        )
+        .unwrap()
+        .into();
+        let app_record = ApplicationRecordDataType::new("AppRecord", &package).unwrap();
+        app_record.create_record_element("Element", &app_value).unwrap();
+        app_record.create_record_element("Ref", &app_value).unwrap();
+        let app_record: ApplicationDataType = app_record.into();
+
+        // compatible mappings
+        let map = mapping_set.create_data_type_map(&impl_value, &app_value).unwrap();
+        assert!(map.check_compatibility().is_ok());
+        let map = mapping_set.create_data_type_map(&impl_array, &app_array).unwrap();
+        assert!(map.check_compatibility().is_ok());
+        let map = mapping_set
+            .create_data_type_map(&impl_variable_array, &app_variable_array)
+            .unwrap();
+        assert!(map.check_compatibility().is_ok());
+        let map = mapping_set.create_data_type_map(&impl_struct, &app_record).unwrap();
+        assert!(map.check_compatibility().is_ok());
+        assert!(mapping_set.check_compatibility().is_ok());
+
+        // incompatible mappings
+        let map = mapping_set.create_data_type_map(&impl_array, &app_value).unwrap();
+        assert!(map.check_compatibility().is_err());
+        let map = mapping_set
+            .create_data_type_map(&impl_array, &app_variable_array)
+            .unwrap();
+        assert!(map.check_compatibility().is_err());
+        let map = mapping_set
+            .create_data_type_map(&impl_variable_array, &app_array)
+            .unwrap();
+        assert!(map.check_compatibility().is_err());
+        let map = mapping_set.create_data_type_map(&impl_value, &app_record).unwrap();
+        assert!(map.check_compatibility().is_err());
+        assert!(mapping_set.check_compatibility().is_err());
+    }
 }
@@ -12,6 +12,7 @@ mod basetype;
 mod compu_method;
 mod implementationtype;
 mod mapping;
+mod sw_data_def_props;
 mod values;
 
 pub use applicationtype::*;
@@ -19,6 +20,7 @@ pub use basetype::*;
 pub use compu_method::*;
 pub use implementationtype::*;
 pub use mapping::*;
+pub use sw_data_def_props::*;
 pub use values::*;
 
 //#########################################################
@@ -81,6 +83,7 @@ impl TryFrom<Element> for AutosarDataType {
 
 impl IdentifiableAbstractionElement for AutosarDataType {}
 impl AbstractAutosarDataType for AutosarDataType {}
+impl AbstractSwDataDefProps for AutosarDataType {}
 
 //#########################################################
 
@@ -129,11 +132,177 @@ impl Unit {
             .character_data()?
             .string_value()
     }
+
+    /// Set the factor and offset of the conversion between this unit and SI units
+    ///
+    /// Despite the names of the AUTOSAR elements, the factor and offset convert a value in this unit to SI units:
+    /// `value_si = value_unit * factor + offset`.
+    /// For example, km/h has a factor of 1/3.6 (≈ 0.27778) and an offset of 0 relative to m/s,
+    /// and °C has a factor of 1 and an offset of 273.15 relative to K.
+    pub fn set_si_conversion(&self, factor: f64, offset: f64) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::FactorSiToUnit)?
+            .set_character_data(factor)?;
+        self.element()
+            .get_or_create_sub_element(ElementName::OffsetSiToUnit)?
+            .set_character_data(offset)?;
+
+        Ok(())
+    }
+
+    /// Get the factor of the conversion from this unit to SI units
+    #[must_use]
+    pub fn factor_si_to_unit(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::FactorSiToUnit)?
+            .character_data()?
+            .parse_float()
+    }
+
+    /// Get the offset of the conversion from this unit to SI units
+    #[must_use]
+    pub fn offset_si_to_unit(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::OffsetSiToUnit)?
+            .character_data()?
+            .parse_float()
+    }
+
+    /// Set the physical dimension of the unit
+    pub fn set_physical_dimension(
+        &self,
+        physical_dimension: Option<&PhysicalDimension>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(physical_dimension) = physical_dimension {
+            self.element()
+                .get_or_create_sub_element(ElementName::PhysicalDimensionRef)?
+                .set_reference_target(physical_dimension.element())?;
+        } else {
+            let _ = self
+                .element()
+                .remove_sub_element_kind(ElementName::PhysicalDimensionRef);
+        }
+
+        Ok(())
+    }
+
+    /// Get the physical dimension of the unit
+    #[must_use]
+    pub fn physical_dimension(&self) -> Option<PhysicalDimension> {
+        self.element()
+            .get_sub_element(ElementName::PhysicalDimensionRef)?
+            .get_reference_target()
+            .ok()?
+            .try_into()
+            .ok()
+    }
+}
+
+//#########################################################
+
+/// `PhysicalDimension` describes the dimension of a physical quantity as exponents of the SI base units
+///
+/// Use [`ArPackage::create_physical_dimension`] to create a new physical dimension.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhysicalDimension(Element);
+abstraction_element!(PhysicalDimension, PhysicalDimension);
+impl IdentifiableAbstractionElement for PhysicalDimension {}
+
+impl PhysicalDimension {
+    /// Create a new physical dimension
+    pub(crate) fn new(
+        name: &str,
+        package: &ArPackage,
+        exponents: &PhysicalDimensionExponents,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let physical_dimension_elem = elements.create_named_sub_element(ElementName::PhysicalDimension, name)?;
+        let physical_dimension = Self(physical_dimension_elem);
+
+        physical_dimension.set_exponents(exponents)?;
+
+        Ok(physical_dimension)
+    }
+
+    /// Set the exponents of the SI base units
+    ///
+    /// Exponents with the value 0 are not stored in the model
+    pub fn set_exponents(&self, exponents: &PhysicalDimensionExponents) -> Result<(), AutosarAbstractionError> {
+        for (element_name, value) in [
+            (ElementName::LengthExp, exponents.length),
+            (ElementName::MassExp, exponents.mass),
+            (ElementName::TimeExp, exponents.time),
+            (ElementName::CurrentExp, exponents.current),
+            (ElementName::TemperatureExp, exponents.temperature),
+            (ElementName::MolarAmountExp, exponents.molar_amount),
+            (ElementName::LuminousIntensityExp, exponents.luminous_intensity),
+        ] {
+            if value == 0 {
+                let _ = self.element().remove_sub_element_kind(element_name);
+            } else {
+                self.element()
+                    .get_or_create_sub_element(element_name)?
+                    .set_character_data(value.to_string())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the exponents of the SI base units
+    #[must_use]
+    pub fn exponents(&self) -> PhysicalDimensionExponents {
+        let get_exp = |element_name| {
+            self.element()
+                .get_sub_element(element_name)
+                .and_then(|elem| elem.character_data())
+                .and_then(|cdata| cdata.parse_integer())
+                .unwrap_or(0)
+        };
+
+        PhysicalDimensionExponents {
+            length: get_exp(ElementName::LengthExp),
+            mass: get_exp(ElementName::MassExp),
+            time: get_exp(ElementName::TimeExp),
+            current: get_exp(ElementName::CurrentExp),
+            temperature: get_exp(ElementName::TemperatureExp),
+            molar_amount: get_exp(ElementName::MolarAmountExp),
+            luminous_intensity: get_exp(ElementName::LuminousIntensityExp),
+        }
+    }
+}
+
+//#########################################################
+
+/// The exponents of the SI base units in a `PhysicalDimension`
+///
+/// For example, a velocity has the exponents `length: 1, time: -1`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PhysicalDimensionExponents {
+    /// exponent of the length (meter)
+    pub length: i32,
+    /// exponent of the mass (kilogram)
+    pub mass: i32,
+    /// exponent of the time (second)
+    pub time: i32,
+    /// exponent of the electric current (ampere)
+    pub current: i32,
+    /// exponent of the temperature (kelvin)
+    pub temperature: i32,
+    /// exponent of the amount of substance (mole)
+    pub molar_amount: i32,
+    /// exponent of the luminous intensity (candela)
+    pub luminous_intensity: i32,
 }
 
 //#########################################################
 
 /// `DataConstr` represents a data constraint.
+///
+/// A data constraint contains rules which limit the internal or physical values of a data type or signal.
+/// It can be attached to an `ApplicationPrimitiveDataType`, an `ImplementationDataType` or an `ISignal`.
+///
+/// Use [`ArPackage::create_data_constr`] to create a new `DataConstr`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DataConstr(Element);
 abstraction_element!(DataConstr, DataConstr);
@@ -215,6 +384,11 @@ impl DataConstrRule {
         }
     }
 
+    /// set or remove the lower limit
+    pub fn set_lower_limit(&self, lower_limit: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        self.set_limit(ElementName::LowerLimit, lower_limit)
+    }
+
     /// get the lower limit
     #[must_use]
     pub fn lower_limit(&self) -> Option<f64> {
@@ -226,6 +400,11 @@ impl DataConstrRule {
             .parse_float()
     }
 
+    /// set or remove the upper limit
+    pub fn set_upper_limit(&self, upper_limit: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        self.set_limit(ElementName::UpperLimit, upper_limit)
+    }
+
     /// get the upper limit
     #[must_use]
     pub fn upper_limit(&self) -> Option<f64> {
@@ -236,6 +415,45 @@ impl DataConstrRule {
             .character_data()?
             .parse_float()
     }
+
+    /// set or remove the constraint level of the rule
+    ///
+    /// The constraint level allows several rules with different severities, e.g. a warning limit and an error limit.
+    pub fn set_constr_level(&self, constr_level: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(constr_level) = constr_level {
+            self.element()
+                .get_or_create_sub_element(ElementName::ConstrLevel)?
+                .set_character_data(u64::from(constr_level))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::ConstrLevel);
+        }
+        Ok(())
+    }
+
+    /// get the constraint level of the rule
+    #[must_use]
+    pub fn constr_level(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::ConstrLevel)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    fn set_limit(&self, limit_name: ElementName, limit: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        let constrs_name = match self.rule_type() {
+            DataConstrType::Internal => ElementName::InternalConstrs,
+            DataConstrType::Physical => ElementName::PhysConstrs,
+        };
+        if let Some(limit) = limit {
+            self.element()
+                .get_or_create_sub_element(constrs_name)?
+                .get_or_create_sub_element(limit_name)?
+                .set_character_data(limit)?;
+        } else if let Some(constrs) = self.element().get_sub_element(constrs_name) {
+            let _ = constrs.remove_sub_element_kind(limit_name);
+        }
+        Ok(())
+    }
 }
 
 //#########################################################
@@ -264,6 +482,33 @@ mod test {
 
         let unit = Unit::new("Unit", &package, Some("Unit Display")).unwrap();
         assert_eq!(unit.display_name(), Some("Unit Display".to_string()));
+
+        let velocity = PhysicalDimensionExponents {
+            length: 1,
+            time: -1,
+            ..Default::default()
+        };
+        let physical_dimension = package.create_physical_dimension("Velocity", &velocity).unwrap();
+        assert_eq!(physical_dimension.exponents(), velocity);
+        unit.set_physical_dimension(Some(&physical_dimension)).unwrap();
+        assert_eq!(unit.physical_dimension(), Some(physical_dimension));
+        unit.set_physical_dimension(None).unwrap();
+        assert_eq!(unit.physical_dimension(), None);
+
+        // 1 km/h = 1/3.6 m/s
+        unit.set_si_conversion(1.0 / 3.6, 0.0).unwrap();
+        assert_eq!(unit.factor_si_to_unit(), Some(1.0 / 3.6));
+        assert_eq!(unit.offset_si_to_unit(), Some(0.0));
+        let value_si = 36.0 * unit.factor_si_to_unit().unwrap() + unit.offset_si_to_unit().unwrap();
+        assert!((value_si - 10.0).abs() < 1e-9);
+
+        // find_or_create_unit returns the existing unit
+        let unit2 = package.find_or_create_unit("Unit", Some("other")).unwrap();
+        assert_eq!(unit2, unit);
+        assert_eq!(unit2.display_name(), Some("Unit Display".to_string()));
+        let unit3 = package.find_or_create_unit("KmPerHour", Some("km/h")).unwrap();
+        assert_ne!(unit3, unit);
+        assert_eq!(unit3.display_name(), Some("km/h".to_string()));
     }
 
     #[test]
@@ -289,6 +534,23 @@ mod test {
 
         let rules = data_constr.data_constr_rules().collect::<Vec<_>>();
         assert_eq!(rules.len(), 2);
+
+        rule1.set_lower_limit(Some(-10.0)).unwrap();
+        rule1.set_upper_limit(None).unwrap();
+        assert_eq!(rule1.lower_limit(), Some(-10.0));
+        assert_eq!(rule1.upper_limit(), None);
+        assert_eq!(rule1.rule_type(), DataConstrType::Internal);
+
+        let rule3 = data_constr
+            .create_data_constr_rule(DataConstrType::Physical, None, None)
+            .unwrap();
+        rule3.set_upper_limit(Some(50.0)).unwrap();
+        assert_eq!(rule3.rule_type(), DataConstrType::Physical);
+        assert_eq!(rule3.upper_limit(), Some(50.0));
+        rule3.set_constr_level(Some(1)).unwrap();
+        assert_eq!(rule3.constr_level(), Some(1));
+        rule3.set_constr_level(None).unwrap();
+        assert_eq!(rule3.constr_level(), None);
     }
 
     #[test]
@@ -0,0 +1,335 @@
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
+};
+use autosar_data::{Element, ElementName, EnumItem};
+
+//#########################################################
+
+/// `AbstractSwDataDefProps` provides access to the calibration related attributes in the `SwDataDefProps`
+///
+/// It is implemented by all data types and data prototypes
+pub trait AbstractSwDataDefProps: AbstractionElement {
+    /// set the calibration access of the element
+    fn set_sw_calibration_access(
+        &self,
+        sw_calibration_access: Option<SwCalibrationAccess>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(sw_calibration_access) = sw_calibration_access {
+            get_or_create_sw_data_def_props_conditional(self.element())?
+                .get_or_create_sub_element(ElementName::SwCalibrationAccess)?
+                .set_character_data::<EnumItem>(sw_calibration_access.into())?;
+        } else if let Some(sddpc) = get_sw_data_def_props_conditional(self.element()) {
+            let _ = sddpc.remove_sub_element_kind(ElementName::SwCalibrationAccess);
+        }
+
+        Ok(())
+    }
+
+    /// get the calibration access of the element
+    #[must_use]
+    fn sw_calibration_access(&self) -> Option<SwCalibrationAccess> {
+        get_sw_data_def_props_conditional(self.element())?
+            .get_sub_element(ElementName::SwCalibrationAccess)?
+            .character_data()?
+            .enum_value()?
+            .try_into()
+            .ok()
+    }
+
+    /// set the display format of the element, e.g. "%6.2f"
+    fn set_display_format(&self, display_format: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        if let Some(display_format) = display_format {
+            get_or_create_sw_data_def_props_conditional(self.element())?
+                .get_or_create_sub_element(ElementName::DisplayFormat)?
+                .set_character_data(display_format)?;
+        } else if let Some(sddpc) = get_sw_data_def_props_conditional(self.element()) {
+            let _ = sddpc.remove_sub_element_kind(ElementName::DisplayFormat);
+        }
+
+        Ok(())
+    }
+
+    /// get the display format of the element
+    #[must_use]
+    fn display_format(&self) -> Option<String> {
+        get_sw_data_def_props_conditional(self.element())?
+            .get_sub_element(ElementName::DisplayFormat)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// set the `SwAddrMethod` of the element
+    fn set_sw_addr_method(&self, sw_addr_method: Option<&SwAddrMethod>) -> Result<(), AutosarAbstractionError> {
+        if let Some(sw_addr_method) = sw_addr_method {
+            get_or_create_sw_data_def_props_conditional(self.element())?
+                .get_or_create_sub_element(ElementName::SwAddrMethodRef)?
+                .set_reference_target(sw_addr_method.element())?;
+        } else if let Some(sddpc) = get_sw_data_def_props_conditional(self.element()) {
+            let _ = sddpc.remove_sub_element_kind(ElementName::SwAddrMethodRef);
+        }
+
+        Ok(())
+    }
+
+    /// get the `SwAddrMethod` of the element
+    #[must_use]
+    fn sw_addr_method(&self) -> Option<SwAddrMethod> {
+        get_sw_data_def_props_conditional(self.element())?
+            .get_sub_element(ElementName::SwAddrMethodRef)?
+            .get_reference_target()
+            .ok()?
+            .try_into()
+            .ok()
+    }
+}
+
+fn get_sw_data_def_props_conditional(element: &Element) -> Option<Element> {
+    element
+        .get_sub_element(ElementName::SwDataDefProps)?
+        .get_sub_element(ElementName::SwDataDefPropsVariants)?
+        .get_sub_element(ElementName::SwDataDefPropsConditional)
+}
+
+fn get_or_create_sw_data_def_props_conditional(element: &Element) -> Result<Element, AutosarAbstractionError> {
+    Ok(element
+        .get_or_create_sub_element(ElementName::SwDataDefProps)?
+        .get_or_create_sub_element(ElementName::SwDataDefPropsVariants)?
+        .get_or_create_sub_element(ElementName::SwDataDefPropsConditional)?)
+}
+
+//#########################################################
+
+/// A `SwAddrMethod` describes the memory section in which data is placed, e.g. calibration data
+///
+/// Use [`ArPackage::create_sw_addr_method`] to create a new `SwAddrMethod`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SwAddrMethod(Element);
+abstraction_element!(SwAddrMethod, SwAddrMethod);
+impl IdentifiableAbstractionElement for SwAddrMethod {}
+
+impl SwAddrMethod {
+    /// create a new `SwAddrMethod`
+    pub(crate) fn new(
+        name: &str,
+        package: &ArPackage,
+        section_type: Option<SectionType>,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let sw_addr_method_elem = elements.create_named_sub_element(ElementName::SwAddrMethod, name)?;
+        let sw_addr_method = Self(sw_addr_method_elem);
+
+        sw_addr_method.set_section_type(section_type)?;
+
+        Ok(sw_addr_method)
+    }
+
+    /// set the section type of the `SwAddrMethod`
+    pub fn set_section_type(&self, section_type: Option<SectionType>) -> Result<(), AutosarAbstractionError> {
+        if let Some(section_type) = section_type {
+            self.element()
+                .get_or_create_sub_element(ElementName::SectionType)?
+                .set_character_data::<EnumItem>(section_type.into())?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::SectionType);
+        }
+
+        Ok(())
+    }
+
+    /// get the section type of the `SwAddrMethod`
+    #[must_use]
+    pub fn section_type(&self) -> Option<SectionType> {
+        self.element()
+            .get_sub_element(ElementName::SectionType)?
+            .character_data()?
+            .enum_value()?
+            .try_into()
+            .ok()
+    }
+}
+
+//#########################################################
+
+/// The type of memory section that is described by a `SwAddrMethod`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SectionType {
+    /// calibration data which is only accessible offline
+    CalibrationOffline,
+    /// calibration data which can be modified online
+    CalibrationVariables,
+    /// calibration parameters
+    Calprm,
+    /// executable code
+    Code,
+    /// post-build configuration data
+    ConfigData,
+    /// constant data
+    Const,
+    /// data which is not stored in flash memory
+    ExcludeFromFlash,
+    /// user defined section
+    UserDefined,
+    /// variable data
+    Var,
+}
+
+impl TryFrom<EnumItem> for SectionType {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::CalibrationOffline => Ok(SectionType::CalibrationOffline),
+            EnumItem::CalibrationVariables => Ok(SectionType::CalibrationVariables),
+            EnumItem::Calprm => Ok(SectionType::Calprm),
+            EnumItem::Code => Ok(SectionType::Code),
+            EnumItem::ConfigData => Ok(SectionType::ConfigData),
+            EnumItem::Const => Ok(SectionType::Const),
+            EnumItem::ExcludeFromFlash => Ok(SectionType::ExcludeFromFlash),
+            EnumItem::UserDefined => Ok(SectionType::UserDefined),
+            EnumItem::Var => Ok(SectionType::Var),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "SectionType".to_string(),
+            }),
+        }
+    }
+}
+
+impl From<SectionType> for EnumItem {
+    fn from(value: SectionType) -> Self {
+        match value {
+            SectionType::CalibrationOffline => EnumItem::CalibrationOffline,
+            SectionType::CalibrationVariables => EnumItem::CalibrationVariables,
+            SectionType::Calprm => EnumItem::Calprm,
+            SectionType::Code => EnumItem::Code,
+            SectionType::ConfigData => EnumItem::ConfigData,
+            SectionType::Const => EnumItem::Const,
+            SectionType::ExcludeFromFlash => EnumItem::ExcludeFromFlash,
+            SectionType::UserDefined => EnumItem::UserDefined,
+            SectionType::Var => EnumItem::Var,
+        }
+    }
+}
+
+//#########################################################
+
+/// The access of a measurement and calibration system to a data element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwCalibrationAccess {
+    /// the element is not accessible
+    NotAccessible,
+    /// the element can only be read
+    ReadOnly,
+    /// the element can be read and modified
+    ReadWrite,
+}
+
+impl TryFrom<EnumItem> for SwCalibrationAccess {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::NotAccessible => Ok(SwCalibrationAccess::NotAccessible),
+            EnumItem::ReadOnly => Ok(SwCalibrationAccess::ReadOnly),
+            EnumItem::ReadWrite => Ok(SwCalibrationAccess::ReadWrite),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "SwCalibrationAccess".to_string(),
+            }),
+        }
+    }
+}
+
+impl From<SwCalibrationAccess> for EnumItem {
+    fn from(value: SwCalibrationAccess) -> Self {
+        match value {
+            SwCalibrationAccess::NotAccessible => EnumItem::NotAccessible,
+            SwCalibrationAccess::ReadOnly => EnumItem::ReadOnly,
+            SwCalibrationAccess::ReadWrite => EnumItem::ReadWrite,
+        }
+    }
+}
+
+//#########################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AutosarModelAbstraction,
+        datatype::{ApplicationPrimitiveCategory, ApplicationPrimitiveDataType},
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn sw_addr_method() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/SwAddrMethods").unwrap();
+
+        let sw_addr_method = package
+            .create_sw_addr_method("CALIB", Some(SectionType::CalibrationVariables))
+            .unwrap();
+        assert_eq!(sw_addr_method.section_type(), Some(SectionType::CalibrationVariables));
+        sw_addr_method.set_section_type(Some(SectionType::Calprm)).unwrap();
+        assert_eq!(sw_addr_method.section_type(), Some(SectionType::Calprm));
+        sw_addr_method.set_section_type(None).unwrap();
+        assert_eq!(sw_addr_method.section_type(), None);
+    }
+
+    #[test]
+    fn sw_data_def_props() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/DataTypes").unwrap();
+        let sw_addr_method = package.create_sw_addr_method("CALIB", None).unwrap();
+        let data_type = package
+            .create_application_primitive_data_type("Primitive", ApplicationPrimitiveCategory::Value, None, None, None)
+            .unwrap();
+
+        assert_eq!(data_type.sw_calibration_access(), None);
+        data_type
+            .set_sw_calibration_access(Some(SwCalibrationAccess::ReadWrite))
+            .unwrap();
+        assert_eq!(data_type.sw_calibration_access(), Some(SwCalibrationAccess::ReadWrite));
+        data_type.set_sw_calibration_access(None).unwrap();
+        assert_eq!(data_type.sw_calibration_access(), None);
+
+        data_type.set_display_format(Some("%6.2f")).unwrap();
+        assert_eq!(data_type.display_format(), Some("%6.2f".to_string()));
+        data_type.set_display_format(None).unwrap();
+        assert_eq!(data_type.display_format(), None);
+
+        data_type.set_sw_addr_method(Some(&sw_addr_method)).unwrap();
+        assert_eq!(data_type.sw_addr_method(), Some(sw_addr_method));
+        data_type.set_sw_addr_method(None).unwrap();
+        assert_eq!(data_type.sw_addr_method(), None);
+    }
+
+    #[test]
+    fn enum_conversion() {
+        for value in [
+            SwCalibrationAccess::NotAccessible,
+            SwCalibrationAccess::ReadOnly,
+            SwCalibrationAccess::ReadWrite,
+        ] {
+            let enum_val: EnumItem = value.into();
+            assert_eq!(SwCalibrationAccess::try_from(enum_val).unwrap(), value);
+        }
+        assert!(SwCalibrationAccess::try_from(EnumItem::Aa).is_err());
+
+        for value in [
+            SectionType::CalibrationOffline,
+            SectionType::CalibrationVariables,
+            SectionType::Calprm,
+            SectionType::Code,
+            SectionType::ConfigData,
+            SectionType::Const,
+            SectionType::ExcludeFromFlash,
+            SectionType::UserDefined,
+            SectionType::Var,
+        ] {
+            let enum_val: EnumItem = value.into();
+            assert_eq!(SectionType::try_from(enum_val).unwrap(), value);
+        }
+        assert!(SectionType::try_from(EnumItem::Aa).is_err());
+    }
+}
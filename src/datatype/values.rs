@@ -57,6 +57,20 @@ impl ConstantSpecification {
             .and_then(|vs_elem| vs_elem.get_sub_element_at(0))?;
         ValueSpecification::load(&spec_elem)
     }
+
+    /// get the value of the constant, with all constant references resolved
+    ///
+    /// If the value of the constant is itself a reference to another constant, then the reference is followed.
+    #[must_use]
+    pub fn resolved_value(&self) -> Option<ValueSpecification> {
+        self.value_specification()?.resolve()
+    }
+}
+
+impl From<&ConstantSpecification> for ValueSpecification {
+    fn from(constant: &ConstantSpecification) -> Self {
+        ValueSpecification::constant(constant)
+    }
 }
 
 //#########################################################
@@ -245,6 +259,14 @@ impl ConstantReference {
 
         Some(Self { label, constant })
     }
+
+    /// get the value of the referenced constant
+    ///
+    /// References to further constants are resolved, so that the returned value is never a `ConstantReference`.
+    #[must_use]
+    pub fn value(&self) -> Option<ValueSpecification> {
+        ValueSpecification::ConstantReference(self.clone()).resolve()
+    }
 }
 
 impl From<ConstantReference> for ValueSpecification {
@@ -644,6 +666,166 @@ impl ValueSpecification {
             _ => None,
         }
     }
+
+    /// create a numerical value specification without a label
+    #[must_use]
+    pub fn numerical(value: f64) -> Self {
+        Self::Numerical(NumericalValueSpecification { label: None, value })
+    }
+
+    /// create a text value specification without a label
+    #[must_use]
+    pub fn text(value: impl Into<String>) -> Self {
+        Self::Text(TextValueSpecification {
+            label: None,
+            value: value.into(),
+        })
+    }
+
+    /// create an array value specification without a label
+    #[must_use]
+    pub fn array(values: Vec<ValueSpecification>) -> Self {
+        Self::Array(ArrayValueSpecification { label: None, values })
+    }
+
+    /// create a record value specification without a label
+    #[must_use]
+    pub fn record(values: Vec<ValueSpecification>) -> Self {
+        Self::Record(RecordValueSpecification { label: None, values })
+    }
+
+    /// create a reference to a constant specification without a label
+    #[must_use]
+    pub fn constant(constant: &ConstantSpecification) -> Self {
+        Self::ConstantReference(ConstantReference {
+            label: None,
+            constant: constant.clone(),
+        })
+    }
+
+    /// set the label of the value specification
+    ///
+    /// Labels are used to identify the values inside of a record.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data_abstraction::datatype::*;
+    /// let value = ValueSpecification::record(vec![
+    ///     ValueSpecification::numerical(1.0).with_label("x"),
+    ///     ValueSpecification::numerical(2.0).with_label("y"),
+    /// ]);
+    /// ```
+    #[must_use]
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        *self.label_mut() = Some(label.into());
+        self
+    }
+
+    /// get the label of the value specification
+    #[must_use]
+    pub fn label(&self) -> Option<&str> {
+        match self {
+            Self::Array(spec) => spec.label.as_deref(),
+            Self::Record(spec) => spec.label.as_deref(),
+            Self::Text(spec) => spec.label.as_deref(),
+            Self::Numerical(spec) => spec.label.as_deref(),
+            Self::ConstantReference(spec) => spec.label.as_deref(),
+            Self::Application(spec) => spec.label.as_deref(),
+            Self::NotAvailable(spec) => spec.label.as_deref(),
+            Self::Reference(spec) => spec.label.as_deref(),
+            Self::ApplicationRuleBased(spec) => spec.label.as_deref(),
+            Self::CompositeRuleBased(spec) => spec.label.as_deref(),
+            Self::NumericalRuleBased(spec) => spec.label.as_deref(),
+        }
+    }
+
+    fn label_mut(&mut self) -> &mut Option<String> {
+        match self {
+            Self::Array(spec) => &mut spec.label,
+            Self::Record(spec) => &mut spec.label,
+            Self::Text(spec) => &mut spec.label,
+            Self::Numerical(spec) => &mut spec.label,
+            Self::ConstantReference(spec) => &mut spec.label,
+            Self::Application(spec) => &mut spec.label,
+            Self::NotAvailable(spec) => &mut spec.label,
+            Self::Reference(spec) => &mut spec.label,
+            Self::ApplicationRuleBased(spec) => &mut spec.label,
+            Self::CompositeRuleBased(spec) => &mut spec.label,
+            Self::NumericalRuleBased(spec) => &mut spec.label,
+        }
+    }
+
+    /// resolve constant references
+    ///
+    /// If the value specification is a reference to a constant, then the value of the constant is returned.
+    /// Chains of constant references are followed until a value is found. All other value specifications are returned unchanged.
+    /// Returns None if a referenced constant has no value or if the references form a loop.
+    #[must_use]
+    pub fn resolve(&self) -> Option<ValueSpecification> {
+        let mut visited = Vec::new();
+        let mut current = self.clone();
+        while let Self::ConstantReference(constant_ref) = &current {
+            if visited.contains(&constant_ref.constant) {
+                return None;
+            }
+            visited.push(constant_ref.constant.clone());
+            current = constant_ref.constant.value_specification()?;
+        }
+        Some(current)
+    }
+
+    /// get the numerical value of the value specification
+    ///
+    /// Constant references are resolved. Returns None if the value is not numerical.
+    #[must_use]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.resolve()? {
+            Self::Numerical(spec) => Some(spec.value),
+            _ => None,
+        }
+    }
+
+    /// get the text value of the value specification
+    ///
+    /// Constant references are resolved. Returns None if the value is not a text.
+    #[must_use]
+    pub fn as_text(&self) -> Option<String> {
+        match self.resolve()? {
+            Self::Text(spec) => Some(spec.value),
+            _ => None,
+        }
+    }
+
+    /// get the contained values of an array or record value specification
+    ///
+    /// Constant references are resolved. Returns None if the value is neither an array nor a record.
+    #[must_use]
+    pub fn as_values(&self) -> Option<Vec<ValueSpecification>> {
+        match self.resolve()? {
+            Self::Array(spec) => Some(spec.values),
+            Self::Record(spec) => Some(spec.values),
+            _ => None,
+        }
+    }
+}
+
+impl From<f64> for ValueSpecification {
+    fn from(value: f64) -> Self {
+        Self::numerical(value)
+    }
+}
+
+impl From<&str> for ValueSpecification {
+    fn from(value: &str) -> Self {
+        Self::text(value)
+    }
+}
+
+impl From<String> for ValueSpecification {
+    fn from(value: String) -> Self {
+        Self::text(value)
+    }
 }
 
 fn store_label(parent: &Element, label: &Option<String>) -> Result<(), AutosarAbstractionError> {
@@ -1860,4 +2042,56 @@ mod test {
         // invalid conversion
         assert!(SwAxisContCategory::try_from(EnumItem::Aa).is_err());
     }
+
+    #[test]
+    fn value_specification_builders() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/Pkg").unwrap();
+
+        let value = ValueSpecification::numerical(3.0).with_label("x");
+        assert_eq!(
+            value,
+            ValueSpecification::Numerical(NumericalValueSpecification {
+                label: Some("x".to_string()),
+                value: 3.0,
+            })
+        );
+        assert_eq!(value.label(), Some("x"));
+        assert_eq!(value.as_f64(), Some(3.0));
+        assert_eq!(value.as_text(), None);
+
+        let value: ValueSpecification = "text".into();
+        assert_eq!(value.as_text(), Some("text".to_string()));
+        assert_eq!(value.label(), None);
+
+        let record = ValueSpecification::record(vec![1.0.into(), ValueSpecification::text("abc")]);
+        assert_eq!(record.as_values().unwrap().len(), 2);
+        let array = ValueSpecification::array(vec![record.clone(), record.clone()]);
+        assert_eq!(array.as_values(), Some(vec![record.clone(), record]));
+
+        // constant references are resolved
+        let constant_1 = package
+            .create_constant_specification("Constant1", ValueSpecification::numerical(7.0))
+            .unwrap();
+        let constant_2 = package
+            .create_constant_specification("Constant2", ValueSpecification::constant(&constant_1))
+            .unwrap();
+        let value = ValueSpecification::constant(&constant_2);
+        assert_eq!(value.resolve(), Some(ValueSpecification::numerical(7.0)));
+        assert_eq!(value.as_f64(), Some(7.0));
+        assert_eq!(constant_2.resolved_value(), Some(ValueSpecification::numerical(7.0)));
+        let constant_ref = ConstantReference {
+            label: None,
+            constant: constant_2.clone(),
+        };
+        assert_eq!(constant_ref.value(), Some(ValueSpecification::numerical(7.0)));
+        let value_from_constant: ValueSpecification = (&constant_1).into();
+        assert_eq!(value_from_constant.as_f64(), Some(7.0));
+
+        // a loop of constant references can't be resolved
+        constant_1
+            .set_value_specification(ValueSpecification::constant(&constant_2))
+            .unwrap();
+        assert_eq!(value.resolve(), None);
+    }
 }
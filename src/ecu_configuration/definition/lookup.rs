@@ -0,0 +1,360 @@
+use crate::{
+    AbstractionElement, AutosarAbstractionError, AutosarModelAbstraction,
+    ecu_configuration::{
+        EcucAnyReferenceDef, EcucContainerDef, EcucDefinitionCollection, EcucDefinitionElement, EcucModuleDef,
+        EcucParamConfContainerDef, EcucParameterDef,
+    },
+};
+use autosar_data::Element;
+use std::collections::HashMap;
+
+//#########################################################
+
+/// An element of an ECU configuration definition, as stored in the [`EcucDefinitionLookup`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EcucDefinition {
+    /// a module definition
+    Module(EcucModuleDef),
+    /// a container definition
+    Container(EcucContainerDef),
+    /// a parameter definition
+    Parameter(EcucParameterDef),
+    /// a reference definition
+    Reference(EcucAnyReferenceDef),
+}
+
+impl AbstractionElement for EcucDefinition {
+    fn element(&self) -> &Element {
+        match self {
+            EcucDefinition::Module(elem) => elem.element(),
+            EcucDefinition::Container(elem) => elem.element(),
+            EcucDefinition::Parameter(elem) => elem.element(),
+            EcucDefinition::Reference(elem) => elem.element(),
+        }
+    }
+}
+
+/// Information about a single definition in the [`EcucDefinitionLookup`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EcucDefinitionInfo {
+    /// the definition element
+    pub definition: EcucDefinition,
+    /// the lower multiplicity of the definition. The AUTOSAR default is 1 if it is not set
+    pub lower_multiplicity: u32,
+    /// the upper multiplicity of the definition; `None` if the upper multiplicity is infinite.
+    /// The AUTOSAR default is 1 if it is not set
+    pub upper_multiplicity: Option<u32>,
+}
+
+impl EcucDefinitionInfo {
+    fn new<T: EcucDefinitionElement>(definition_element: &T, definition: EcucDefinition) -> Self {
+        let upper_multiplicity = if definition_element.upper_multiplicity_infinite() == Some(true) {
+            None
+        } else {
+            Some(definition_element.upper_multiplicity().unwrap_or(1))
+        };
+        Self {
+            definition,
+            lower_multiplicity: definition_element.lower_multiplicity().unwrap_or(1),
+            upper_multiplicity,
+        }
+    }
+}
+
+//#########################################################
+
+/// The `EcucDefinitionLookup` indexes all elements of one or more ECU configuration definitions by their path
+///
+/// It is typically built from the parameter definition (BSWMD) files of a BSW vendor, after they have been loaded
+/// into the model. The lookup is then used to create configuration values by definition path, e.g. with
+/// [`EcucModuleConfigurationValues::create_container_by_def_path`](crate::ecu_configuration::EcucModuleConfigurationValues::create_container_by_def_path),
+/// which sets the DEFINITION-REF automatically and checks the multiplicity of the definition.
+///
+/// The lookup is a snapshot: definitions that are added to the model later are not included.
+///
+/// # Example
+///
+/// ```
+/// # use autosar_data::*;
+/// # use autosar_data_abstraction::*;
+/// # use autosar_data_abstraction::ecu_configuration::*;
+/// # fn main() -> Result<(), AutosarAbstractionError> {
+/// let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+/// // model.load_file("vendor_bswmd.arxml", false)?;
+/// # let package = model.get_or_create_package("/Vendor")?;
+/// # let module_def = package.create_ecuc_module_def("Com")?;
+/// # module_def.create_param_conf_container_def("ComGeneral")?;
+/// let lookup = EcucDefinitionLookup::from_model(&model);
+/// let info = lookup.get("/Vendor/Com/ComGeneral").unwrap();
+/// assert_eq!(info.upper_multiplicity, Some(1));
+/// # Ok(())}
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EcucDefinitionLookup {
+    definitions: HashMap<String, EcucDefinitionInfo>,
+}
+
+impl EcucDefinitionLookup {
+    /// create a lookup for all module definitions in an [`EcucDefinitionCollection`]
+    #[must_use]
+    pub fn new(collection: &EcucDefinitionCollection) -> Self {
+        let mut lookup = Self::default();
+        for module_def in collection.module_defs() {
+            lookup.add_module_def(&module_def);
+        }
+        lookup
+    }
+
+    /// create a lookup for all module definitions in the model
+    #[must_use]
+    pub fn from_model(model: &AutosarModelAbstraction) -> Self {
+        let mut lookup = Self::default();
+        for module_def in model
+            .model()
+            .identifiable_elements()
+            .filter_map(|(_, weak)| weak.upgrade())
+            .filter_map(|elem| EcucModuleDef::try_from(elem).ok())
+        {
+            lookup.add_module_def(&module_def);
+        }
+        lookup
+    }
+
+    /// add a module definition and all of its containers, parameters and references to the lookup
+    pub fn add_module_def(&mut self, module_def: &EcucModuleDef) {
+        self.insert(module_def, EcucDefinition::Module(module_def.clone()));
+        for container_def in module_def.containers() {
+            self.add_container_def(&container_def);
+        }
+    }
+
+    fn add_container_def(&mut self, container_def: &EcucContainerDef) {
+        self.insert(container_def, EcucDefinition::Container(container_def.clone()));
+        match container_def {
+            EcucContainerDef::Choice(choice_container_def) => {
+                for choice in choice_container_def.choices() {
+                    self.add_container_def(&EcucContainerDef::ParamConf(choice));
+                }
+            }
+            EcucContainerDef::ParamConf(param_conf_container_def) => {
+                self.add_param_conf_contents(param_conf_container_def);
+            }
+        }
+    }
+
+    fn add_param_conf_contents(&mut self, container_def: &EcucParamConfContainerDef) {
+        for sub_container_def in container_def.sub_containers() {
+            self.add_container_def(&sub_container_def);
+        }
+        for parameter_def in container_def.parameters() {
+            self.insert(&parameter_def, EcucDefinition::Parameter(parameter_def.clone()));
+        }
+        for reference_def in container_def.references() {
+            self.insert(&reference_def, EcucDefinition::Reference(reference_def.clone()));
+        }
+    }
+
+    fn insert<T: EcucDefinitionElement>(&mut self, definition_element: &T, definition: EcucDefinition) {
+        if let Ok(path) = definition_element.element().path() {
+            self.definitions
+                .insert(path, EcucDefinitionInfo::new(definition_element, definition));
+        }
+    }
+
+    /// get the information about the definition with the given path
+    #[must_use]
+    pub fn get(&self, def_path: &str) -> Option<&EcucDefinitionInfo> {
+        self.definitions.get(def_path)
+    }
+
+    /// get the module definition with the given path
+    #[must_use]
+    pub fn module_def(&self, def_path: &str) -> Option<EcucModuleDef> {
+        match &self.get(def_path)?.definition {
+            EcucDefinition::Module(module_def) => Some(module_def.clone()),
+            _ => None,
+        }
+    }
+
+    /// get the container definition with the given path
+    #[must_use]
+    pub fn container_def(&self, def_path: &str) -> Option<EcucContainerDef> {
+        match &self.get(def_path)?.definition {
+            EcucDefinition::Container(container_def) => Some(container_def.clone()),
+            _ => None,
+        }
+    }
+
+    /// get the parameter definition with the given path
+    #[must_use]
+    pub fn parameter_def(&self, def_path: &str) -> Option<EcucParameterDef> {
+        match &self.get(def_path)?.definition {
+            EcucDefinition::Parameter(parameter_def) => Some(parameter_def.clone()),
+            _ => None,
+        }
+    }
+
+    /// get the reference definition with the given path
+    #[must_use]
+    pub fn reference_def(&self, def_path: &str) -> Option<EcucAnyReferenceDef> {
+        match &self.get(def_path)?.definition {
+            EcucDefinition::Reference(reference_def) => Some(reference_def.clone()),
+            _ => None,
+        }
+    }
+
+    /// iterate over all definition paths in the lookup, together with their information
+    pub fn definitions(&self) -> impl Iterator<Item = (&str, &EcucDefinitionInfo)> {
+        self.definitions.iter().map(|(path, info)| (path.as_str(), info))
+    }
+
+    // look up a definition that will be used for a new element inside the parent
+    //
+    // The definition must be a direct child of the parent definition, and the parent may not already contain
+    // as many elements using the definition as allowed by the upper multiplicity.
+    pub(crate) fn checked_child_definition<'a>(
+        &'a self,
+        parent_definition_ref: Option<&str>,
+        def_path: &str,
+        existing_definition_refs: impl Iterator<Item = Option<String>>,
+    ) -> Result<&'a EcucDefinitionInfo, AutosarAbstractionError> {
+        let info = self
+            .get(def_path)
+            .ok_or_else(|| AutosarAbstractionError::InvalidParameter(format!("definition {def_path} is not known")))?;
+        let def_parent_path = def_path.rsplit_once('/').map(|(parent_path, _)| parent_path);
+        if parent_definition_ref.is_none() || def_parent_path != parent_definition_ref {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "definition {def_path} can't be used inside an element with the definition {}",
+                parent_definition_ref.unwrap_or("<none>")
+            )));
+        }
+        if let Some(upper_multiplicity) = info.upper_multiplicity {
+            let count = existing_definition_refs
+                .filter(|def_ref| def_ref.as_deref() == Some(def_path))
+                .count();
+            if count >= upper_multiplicity as usize {
+                return Err(AutosarAbstractionError::ItemAlreadyExists);
+            }
+        }
+        Ok(info)
+    }
+}
+
+//#########################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ecu_configuration::{EcucContainerValue, EcucParameterValue};
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn definition_lookup() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/Vendor").unwrap();
+        let module_def = package.create_ecuc_module_def("Com").unwrap();
+        let collection = package.create_ecuc_definition_collection("Collection").unwrap();
+        collection.add_module_def(&module_def).unwrap();
+        let general_def = module_def.create_param_conf_container_def("ComGeneral").unwrap();
+        let pdu_def = module_def.create_param_conf_container_def("ComIPdu").unwrap();
+        pdu_def.set_lower_multiplicity(Some(0)).unwrap();
+        pdu_def.set_upper_multiplicity_infinite(Some(true)).unwrap();
+        let choice_def = pdu_def.create_choice_container_def("ComIPduChoice").unwrap();
+        let choice_a = choice_def.create_param_conf_container_def("ChoiceA").unwrap();
+        let handle_id_def = pdu_def.create_integer_param_def("ComIPduHandleId", "Vendor").unwrap();
+        let direction_def = pdu_def
+            .create_enumeration_param_def("ComIPduDirection", "Vendor")
+            .unwrap();
+        direction_def.set_upper_multiplicity(Some(1)).unwrap();
+        pdu_def.create_add_info_param_def("ComIPduInfo", "Vendor").unwrap();
+        let ref_def = pdu_def.create_reference_def("ComPduIdRef", "Vendor").unwrap();
+
+        let lookup = EcucDefinitionLookup::new(&collection);
+        assert_eq!(lookup.definitions().count(), 9);
+        assert_eq!(lookup.module_def("/Vendor/Com"), Some(module_def.clone()));
+        assert_eq!(
+            lookup.container_def("/Vendor/Com/ComGeneral"),
+            Some(EcucContainerDef::ParamConf(general_def.clone()))
+        );
+        assert_eq!(
+            lookup.container_def("/Vendor/Com/ComIPdu/ComIPduChoice/ChoiceA"),
+            Some(EcucContainerDef::ParamConf(choice_a.clone()))
+        );
+        assert!(lookup.parameter_def("/Vendor/Com/ComIPdu/ComIPduHandleId").is_some());
+        assert_eq!(
+            lookup
+                .reference_def("/Vendor/Com/ComIPdu/ComPduIdRef")
+                .map(|def| def.element().clone()),
+            Some(ref_def.element().clone())
+        );
+        assert!(lookup.container_def("/Vendor/Com/ComIPdu/ComIPduHandleId").is_none());
+        let info = lookup.get("/Vendor/Com/ComIPdu").unwrap();
+        assert_eq!(info.lower_multiplicity, 0);
+        assert_eq!(info.upper_multiplicity, None);
+        let info = lookup.get("/Vendor/Com/ComGeneral").unwrap();
+        assert_eq!(info.lower_multiplicity, 1);
+        assert_eq!(info.upper_multiplicity, Some(1));
+        // the lookup built from the model finds the same definitions
+        assert_eq!(EcucDefinitionLookup::from_model(&model).definitions().count(), 9);
+
+        // create values by definition path
+        let values_package = model.get_or_create_package("/Config").unwrap();
+        let module_config = values_package
+            .create_ecuc_module_configuration_values("Com", &module_def)
+            .unwrap();
+        let general = module_config
+            .create_container_by_def_path("ComGeneral", "/Vendor/Com/ComGeneral", &lookup)
+            .unwrap();
+        assert_eq!(general.definition(), Some(EcucContainerDef::ParamConf(general_def)));
+        // ComGeneral may only exist once
+        let result = module_config.create_container_by_def_path("ComGeneral2", "/Vendor/Com/ComGeneral", &lookup);
+        assert!(matches!(result, Err(AutosarAbstractionError::ItemAlreadyExists)));
+        // unknown definitions and definitions of other containers are rejected
+        assert!(
+            module_config
+                .create_container_by_def_path("Unknown", "/Vendor/Com/Unknown", &lookup)
+                .is_err()
+        );
+        assert!(
+            module_config
+                .create_container_by_def_path("Choice", "/Vendor/Com/ComIPdu/ComIPduChoice", &lookup)
+                .is_err()
+        );
+        assert!(
+            module_config
+                .create_container_by_def_path("Param", "/Vendor/Com/ComIPdu/ComIPduHandleId", &lookup)
+                .is_err()
+        );
+
+        // ComIPdu has an infinite upper multiplicity
+        let pdu1 = module_config
+            .create_container_by_def_path("Pdu1", "/Vendor/Com/ComIPdu", &lookup)
+            .unwrap();
+        module_config
+            .create_container_by_def_path("Pdu2", "/Vendor/Com/ComIPdu", &lookup)
+            .unwrap();
+        let choice: EcucContainerValue = pdu1
+            .create_container_by_def_path("Choice", "/Vendor/Com/ComIPdu/ComIPduChoice", &lookup)
+            .unwrap();
+        choice
+            .create_container_by_def_path("A", "/Vendor/Com/ComIPdu/ComIPduChoice/ChoiceA", &lookup)
+            .unwrap();
+
+        // parameter values
+        let handle_id = pdu1
+            .create_param_value_by_def_path("/Vendor/Com/ComIPdu/ComIPduHandleId", "7", &lookup)
+            .unwrap();
+        assert!(matches!(handle_id, EcucParameterValue::Numerical(_)));
+        let direction = pdu1
+            .create_param_value_by_def_path("/Vendor/Com/ComIPdu/ComIPduDirection", "SEND", &lookup)
+            .unwrap();
+        assert!(matches!(direction, EcucParameterValue::Textual(_)));
+        let result = pdu1.create_param_value_by_def_path("/Vendor/Com/ComIPdu/ComIPduDirection", "RECEIVE", &lookup);
+        assert!(matches!(result, Err(AutosarAbstractionError::ItemAlreadyExists)));
+        assert!(
+            pdu1.create_param_value_by_def_path("/Vendor/Com/ComIPdu/ComIPduInfo", "", &lookup)
+                .is_err()
+        );
+        assert_eq!(pdu1.parameter_values().count(), 2);
+    }
+}
@@ -4,10 +4,12 @@ use crate::{
 use autosar_data::{Element, ElementName, EnumItem};
 
 mod container;
+mod lookup;
 mod parameter;
 mod reference;
 
 pub use container::*;
+pub use lookup::*;
 pub use parameter::*;
 pub use reference::*;
 
@@ -0,0 +1,466 @@
+use super::{
+    create_unique_sub_container, find_module_container_def, find_sub_container_def, set_param_value,
+    set_reference_value,
+};
+use crate::{
+    ArPackage, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
+    communication::{
+        AbstractFrame, AbstractFrameTriggering, CanAddressingMode, CanCommunicationController, CanFrameTriggering,
+        CanFrameType, CommunicationController, CommunicationDirection,
+    },
+    ecu_configuration::{EcucContainerValue, EcucModuleConfigurationValues, EcucModuleDef, EcucParamConfContainerDef},
+};
+use autosar_data::AutosarDataError;
+
+//#########################################################
+
+/// Generate configuration skeletons of the Can driver and of CanIf for an `EcuInstance`
+///
+/// For each `CanCommunicationController` of the ECU which is connected to a `CanPhysicalChannel`, a
+/// `CanController` container is created in the Can driver configuration, together with its baudrate configuration.
+/// Each `CanFrameTriggering` that is sent or received by the ECU gets its own full-CAN `CanHardwareObject`.
+/// As required by the Can driver, all receive objects are numbered before the transmit objects.
+///
+/// The CanIf configuration references the Can driver configuration: it contains a `CanIfCtrlCfg` for each controller,
+/// a `CanIfHrhCfg` or `CanIfHthCfg` for each hardware object, and a `CanIfRxPduCfg` or `CanIfTxPduCfg` for each frame.
+///
+/// The container, parameter and reference definitions are looked up by name in `can_definition` and `canif_definition`.
+/// The Can definition must contain the container `CanConfigSet` with the sub-containers `CanController`
+/// and `CanHardwareObject`; the CanIf definition must contain the container `CanIfInitCfg`.
+/// Parameters that are not defined are skipped.
+///
+/// Returns the Can driver configuration and the CanIf configuration.
+///
+/// # Example
+///
+/// ```
+/// # use autosar_data::*;
+/// # use autosar_data_abstraction::*;
+/// # use autosar_data_abstraction::ecu_configuration::*;
+/// # fn main() -> Result<(), AutosarAbstractionError> {
+/// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+/// # let package = model.get_or_create_package("/pkg")?;
+/// # let system = package.create_system("System", SystemCategory::EcuExtract)?;
+/// # let ecu_instance = system.create_ecu_instance("Ecu", &package)?;
+/// # let can_definition = package.create_ecuc_module_def("Can")?;
+/// # let config_set_def = can_definition.create_param_conf_container_def("CanConfigSet")?;
+/// # config_set_def.create_param_conf_container_def("CanController")?;
+/// # config_set_def.create_param_conf_container_def("CanHardwareObject")?;
+/// # let canif_definition = package.create_ecuc_module_def("CanIf")?;
+/// # canif_definition.create_param_conf_container_def("CanIfInitCfg")?;
+/// let (can_config, canif_config) = generate_can_configuration(
+///     "Can",
+///     "CanIf",
+///     &package,
+///     &ecu_instance,
+///     &can_definition,
+///     &canif_definition,
+/// )?;
+/// # Ok(())}
+/// ```
+///
+/// # Errors
+///
+/// - [`AutosarAbstractionError::InvalidParameter`] one of the definitions does not contain the required containers
+/// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+pub fn generate_can_configuration(
+    can_name: &str,
+    canif_name: &str,
+    package: &ArPackage,
+    ecu_instance: &EcuInstance,
+    can_definition: &EcucModuleDef,
+    canif_definition: &EcucModuleDef,
+) -> Result<(EcucModuleConfigurationValues, EcucModuleConfigurationValues), AutosarAbstractionError> {
+    let config_set_def = find_module_container_def(can_definition, "CanConfigSet").ok_or(
+        AutosarAbstractionError::InvalidParameter("The Can definition has no container CanConfigSet".to_string()),
+    )?;
+    let controller_def = find_sub_container_def(&config_set_def, "CanController").ok_or(
+        AutosarAbstractionError::InvalidParameter("The Can definition has no container CanController".to_string()),
+    )?;
+    let hoh_def = find_sub_container_def(&config_set_def, "CanHardwareObject").ok_or(
+        AutosarAbstractionError::InvalidParameter("The Can definition has no container CanHardwareObject".to_string()),
+    )?;
+    let init_cfg_def = find_module_container_def(canif_definition, "CanIfInitCfg").ok_or(
+        AutosarAbstractionError::InvalidParameter("The CanIf definition has no container CanIfInitCfg".to_string()),
+    )?;
+
+    let controllers: Vec<CanCommunicationController> = ecu_instance
+        .communication_controllers()
+        .filter_map(|controller| match controller {
+            CommunicationController::Can(can_controller) => Some(can_controller),
+            _ => None,
+        })
+        .filter(|can_controller| can_controller.connected_channels().next().is_some())
+        .collect();
+
+    // collect the frames of all controllers; received frames are listed first
+    let mut frames = Vec::new();
+    for wanted_direction in [CommunicationDirection::In, CommunicationDirection::Out] {
+        for (controller_idx, controller) in controllers.iter().enumerate() {
+            for frame_triggering in controller
+                .connected_channels()
+                .flat_map(|channel| channel.frame_triggerings())
+            {
+                let is_connected = frame_triggering.frame_ports().any(|frame_port| {
+                    frame_port.ecu().ok().as_ref() == Some(ecu_instance)
+                        && frame_port.communication_direction() == Some(wanted_direction)
+                });
+                if is_connected {
+                    frames.push((controller_idx, frame_triggering, wanted_direction));
+                }
+            }
+        }
+    }
+
+    // Can driver configuration
+    let can_config = package.create_ecuc_module_configuration_values(can_name, can_definition)?;
+    let config_set = can_config.create_container_value("CanConfigSet", &config_set_def)?;
+    let mut controller_values = Vec::with_capacity(controllers.len());
+    for (controller_idx, controller) in controllers.iter().enumerate() {
+        let controller_value = create_can_controller(&config_set, &controller_def, controller, controller_idx)?;
+        controller_values.push(controller_value);
+    }
+    let mut hoh_values = Vec::with_capacity(frames.len());
+    for (object_id, (controller_idx, frame_triggering, direction)) in frames.iter().enumerate() {
+        let hoh_value = create_unique_sub_container(
+            &config_set,
+            &frame_triggering.name().ok_or(AutosarDataError::ItemDeleted)?,
+            &hoh_def,
+        )?;
+        set_param_value(&hoh_value, &hoh_def, "CanHandleType", "FULL")?;
+        let id_type = match frame_triggering.addressing_mode() {
+            Some(CanAddressingMode::Extended) => "EXTENDED",
+            _ => "STANDARD",
+        };
+        set_param_value(&hoh_value, &hoh_def, "CanIdType", id_type)?;
+        set_param_value(&hoh_value, &hoh_def, "CanObjectId", &object_id.to_string())?;
+        let object_type = match direction {
+            CommunicationDirection::In => "RECEIVE",
+            CommunicationDirection::Out => "TRANSMIT",
+        };
+        set_param_value(&hoh_value, &hoh_def, "CanObjectType", object_type)?;
+        set_reference_value(
+            &hoh_value,
+            &hoh_def,
+            "CanControllerRef",
+            &controller_values[*controller_idx],
+        )?;
+
+        if let (Some(filter_def), Some(identifier)) = (
+            find_sub_container_def(&hoh_def, "CanHwFilter"),
+            frame_triggering.identifier(),
+        ) {
+            let filter_value = hoh_value.create_sub_container("CanHwFilter", &filter_def)?;
+            let mask: u32 = match frame_triggering.addressing_mode() {
+                Some(CanAddressingMode::Extended) => 0x1FFF_FFFF,
+                _ => 0x7FF,
+            };
+            set_param_value(&filter_value, &filter_def, "CanHwFilterCode", &identifier.to_string())?;
+            set_param_value(&filter_value, &filter_def, "CanHwFilterMask", &mask.to_string())?;
+        }
+        hoh_values.push(hoh_value);
+    }
+
+    // CanIf configuration
+    let canif_config = package.create_ecuc_module_configuration_values(canif_name, canif_definition)?;
+    let mut ctrl_cfg_values = Vec::with_capacity(controllers.len());
+    if let Some(ctrl_drv_def) = find_module_container_def(canif_definition, "CanIfCtrlDrvCfg")
+        && let Some(ctrl_cfg_def) = find_sub_container_def(&ctrl_drv_def, "CanIfCtrlCfg")
+    {
+        let ctrl_drv_value = canif_config.create_container_value("CanIfCtrlDrvCfg", &ctrl_drv_def)?;
+        for (controller_idx, controller_value) in controller_values.iter().enumerate() {
+            let ctrl_cfg_value = create_unique_sub_container(
+                &ctrl_drv_value,
+                &controller_value.name().ok_or(AutosarDataError::ItemDeleted)?,
+                &ctrl_cfg_def,
+            )?;
+            set_param_value(
+                &ctrl_cfg_value,
+                &ctrl_cfg_def,
+                "CanIfCtrlId",
+                &controller_idx.to_string(),
+            )?;
+            set_reference_value(&ctrl_cfg_value, &ctrl_cfg_def, "CanIfCtrlCanCtrlRef", controller_value)?;
+            ctrl_cfg_values.push(ctrl_cfg_value);
+        }
+    }
+
+    let init_cfg = canif_config.create_container_value("CanIfInitCfg", &init_cfg_def)?;
+    let hoh_cfg_def = find_sub_container_def(&init_cfg_def, "CanIfInitHohCfg");
+    let hoh_cfg = hoh_cfg_def
+        .as_ref()
+        .map(|def| init_cfg.create_sub_container("CanIfInitHohCfg", def))
+        .transpose()?;
+    let hrh_def = hoh_cfg_def
+        .as_ref()
+        .and_then(|def| find_sub_container_def(def, "CanIfHrhCfg"));
+    let hth_def = hoh_cfg_def
+        .as_ref()
+        .and_then(|def| find_sub_container_def(def, "CanIfHthCfg"));
+    let rx_pdu_def = find_sub_container_def(&init_cfg_def, "CanIfRxPduCfg");
+    let tx_pdu_def = find_sub_container_def(&init_cfg_def, "CanIfTxPduCfg");
+    let buffer_def = find_sub_container_def(&init_cfg_def, "CanIfBufferCfg");
+
+    let mut rx_pdu_id = 0;
+    let mut tx_pdu_id = 0;
+    for ((controller_idx, frame_triggering, direction), hoh_value) in frames.iter().zip(hoh_values.iter()) {
+        let name = hoh_value.name().ok_or(AutosarDataError::ItemDeleted)?;
+        let can_id = frame_triggering.identifier().map(|id| id.to_string());
+        let can_id_type = canif_id_type(frame_triggering);
+        match direction {
+            CommunicationDirection::In => {
+                let hrh_value = if let (Some(hoh_cfg), Some(hrh_def)) = (&hoh_cfg, &hrh_def) {
+                    let hrh_value = create_unique_sub_container(hoh_cfg, &name, hrh_def)?;
+                    if let Some(ctrl_cfg_value) = ctrl_cfg_values.get(*controller_idx) {
+                        set_reference_value(&hrh_value, hrh_def, "CanIfHrhCanCtrlIdRef", ctrl_cfg_value)?;
+                    }
+                    set_reference_value(&hrh_value, hrh_def, "CanIfHrhIdSymRef", hoh_value)?;
+                    Some(hrh_value)
+                } else {
+                    None
+                };
+
+                if let Some(rx_pdu_def) = &rx_pdu_def {
+                    let rx_pdu_value = create_unique_sub_container(&init_cfg, &name, rx_pdu_def)?;
+                    if let Some(can_id) = &can_id {
+                        set_param_value(&rx_pdu_value, rx_pdu_def, "CanIfRxPduCanId", can_id)?;
+                    }
+                    set_param_value(&rx_pdu_value, rx_pdu_def, "CanIfRxPduCanIdType", can_id_type)?;
+                    if let Some(length) = frame_triggering.frame().and_then(|frame| frame.length()) {
+                        set_param_value(&rx_pdu_value, rx_pdu_def, "CanIfRxPduDataLength", &length.to_string())?;
+                    }
+                    set_param_value(&rx_pdu_value, rx_pdu_def, "CanIfRxPduId", &rx_pdu_id.to_string())?;
+                    if let Some(hrh_value) = &hrh_value {
+                        set_reference_value(&rx_pdu_value, rx_pdu_def, "CanIfRxPduHrhIdRef", hrh_value)?;
+                    }
+                    rx_pdu_id += 1;
+                }
+            }
+            CommunicationDirection::Out => {
+                let hth_value = if let (Some(hoh_cfg), Some(hth_def)) = (&hoh_cfg, &hth_def) {
+                    let hth_value = create_unique_sub_container(hoh_cfg, &name, hth_def)?;
+                    if let Some(ctrl_cfg_value) = ctrl_cfg_values.get(*controller_idx) {
+                        set_reference_value(&hth_value, hth_def, "CanIfHthCanCtrlIdRef", ctrl_cfg_value)?;
+                    }
+                    set_reference_value(&hth_value, hth_def, "CanIfHthIdSymRef", hoh_value)?;
+                    Some(hth_value)
+                } else {
+                    None
+                };
+
+                // CanIfTxPduCfg references the HTH indirectly through a CanIfBufferCfg
+                let buffer_value = if let (Some(buffer_def), Some(hth_value)) = (&buffer_def, &hth_value) {
+                    let buffer_value = create_unique_sub_container(&init_cfg, &name, buffer_def)?;
+                    set_param_value(&buffer_value, buffer_def, "CanIfBufferSize", "0")?;
+                    set_reference_value(&buffer_value, buffer_def, "CanIfBufferHthRef", hth_value)?;
+                    Some(buffer_value)
+                } else {
+                    None
+                };
+
+                if let Some(tx_pdu_def) = &tx_pdu_def {
+                    let tx_pdu_value = create_unique_sub_container(&init_cfg, &name, tx_pdu_def)?;
+                    if let Some(can_id) = &can_id {
+                        set_param_value(&tx_pdu_value, tx_pdu_def, "CanIfTxPduCanId", can_id)?;
+                    }
+                    set_param_value(&tx_pdu_value, tx_pdu_def, "CanIfTxPduCanIdType", can_id_type)?;
+                    set_param_value(&tx_pdu_value, tx_pdu_def, "CanIfTxPduId", &tx_pdu_id.to_string())?;
+                    if let Some(buffer_value) = &buffer_value {
+                        set_reference_value(&tx_pdu_value, tx_pdu_def, "CanIfTxPduBufferRef", buffer_value)?;
+                    }
+                    tx_pdu_id += 1;
+                }
+            }
+        }
+    }
+
+    Ok((can_config, canif_config))
+}
+
+//#########################################################
+
+// create the CanController container and its baudrate configuration
+fn create_can_controller(
+    config_set: &EcucContainerValue,
+    controller_def: &EcucParamConfContainerDef,
+    controller: &CanCommunicationController,
+    controller_idx: usize,
+) -> Result<EcucContainerValue, AutosarAbstractionError> {
+    let name = controller.name().ok_or(AutosarDataError::ItemDeleted)?;
+    let controller_value = create_unique_sub_container(config_set, &name, controller_def)?;
+    set_param_value(
+        &controller_value,
+        controller_def,
+        "CanControllerId",
+        &controller_idx.to_string(),
+    )?;
+
+    let cluster = controller
+        .connected_channels()
+        .next()
+        .and_then(|channel| channel.cluster().ok());
+    if let (Some(baudrate_def), Some(cluster)) = (
+        find_sub_container_def(controller_def, "CanControllerBaudrateConfig"),
+        cluster,
+    ) {
+        let baudrate_value = controller_value.create_sub_container("CanControllerBaudrateConfig", &baudrate_def)?;
+        // the Can driver expects the baudrate in kbps
+        if let Some(baudrate) = cluster.baudrate() {
+            set_param_value(
+                &baudrate_value,
+                &baudrate_def,
+                "CanControllerBaudRate",
+                &(f64::from(baudrate) / 1000.0).to_string(),
+            )?;
+        }
+        set_param_value(&baudrate_value, &baudrate_def, "CanControllerBaudRateConfigID", "0")?;
+        if let (Some(fd_baudrate_def), Some(fd_baudrate)) = (
+            find_sub_container_def(&baudrate_def, "CanControllerFdBaudrateConfig"),
+            cluster.can_fd_baudrate(),
+        ) {
+            let fd_baudrate_value =
+                baudrate_value.create_sub_container("CanControllerFdBaudrateConfig", &fd_baudrate_def)?;
+            set_param_value(
+                &fd_baudrate_value,
+                &fd_baudrate_def,
+                "CanControllerFdBaudRate",
+                &(f64::from(fd_baudrate) / 1000.0).to_string(),
+            )?;
+        }
+        set_reference_value(
+            &controller_value,
+            controller_def,
+            "CanControllerDefaultBaudrate",
+            &baudrate_value,
+        )?;
+    }
+
+    Ok(controller_value)
+}
+
+// get the CanIf id type of a frame triggering, which combines the addressing mode and the frame type
+fn canif_id_type(frame_triggering: &CanFrameTriggering) -> &'static str {
+    let extended = frame_triggering.addressing_mode() == Some(CanAddressingMode::Extended);
+    match (extended, frame_triggering.frame_type()) {
+        (false, Some(CanFrameType::CanFd)) => "STANDARD_FD_CAN",
+        (false, Some(CanFrameType::Any)) => "STANDARD_NO_FD_CAN",
+        (false, _) => "STANDARD_CAN",
+        (true, Some(CanFrameType::CanFd)) => "EXTENDED_FD_CAN",
+        (true, Some(CanFrameType::Any)) => "EXTENDED_NO_FD_CAN",
+        (true, _) => "EXTENDED_CAN",
+    }
+}
+
+//#########################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutosarModelAbstraction, SystemCategory, ecu_configuration::EcucParameterValue};
+    use autosar_data::AutosarVersion;
+
+    fn create_definitions(package: &ArPackage) -> (EcucModuleDef, EcucModuleDef) {
+        let can_def = package.create_ecuc_module_def("Can").unwrap();
+        let config_set_def = can_def.create_param_conf_container_def("CanConfigSet").unwrap();
+        let controller_def = config_set_def.create_param_conf_container_def("CanController").unwrap();
+        controller_def
+            .create_integer_param_def("CanControllerId", "AUTOSAR_ECUC")
+            .unwrap();
+        let baudrate_def = controller_def
+            .create_param_conf_container_def("CanControllerBaudrateConfig")
+            .unwrap();
+        baudrate_def
+            .create_float_param_def("CanControllerBaudRate", "AUTOSAR_ECUC")
+            .unwrap();
+        controller_def
+            .create_reference_def("CanControllerDefaultBaudrate", "AUTOSAR_ECUC")
+            .unwrap();
+        let hoh_def = config_set_def
+            .create_param_conf_container_def("CanHardwareObject")
+            .unwrap();
+        hoh_def.create_integer_param_def("CanObjectId", "AUTOSAR_ECUC").unwrap();
+        hoh_def
+            .create_enumeration_param_def("CanObjectType", "AUTOSAR_ECUC")
+            .unwrap();
+        hoh_def
+            .create_reference_def("CanControllerRef", "AUTOSAR_ECUC")
+            .unwrap();
+
+        let canif_def = package.create_ecuc_module_def("CanIf").unwrap();
+        let ctrl_drv_def = canif_def.create_param_conf_container_def("CanIfCtrlDrvCfg").unwrap();
+        let ctrl_cfg_def = ctrl_drv_def.create_param_conf_container_def("CanIfCtrlCfg").unwrap();
+        ctrl_cfg_def
+            .create_reference_def("CanIfCtrlCanCtrlRef", "AUTOSAR_ECUC")
+            .unwrap();
+        let init_cfg_def = canif_def.create_param_conf_container_def("CanIfInitCfg").unwrap();
+        let hoh_cfg_def = init_cfg_def.create_param_conf_container_def("CanIfInitHohCfg").unwrap();
+        let hrh_def = hoh_cfg_def.create_param_conf_container_def("CanIfHrhCfg").unwrap();
+        hrh_def
+            .create_reference_def("CanIfHrhIdSymRef", "AUTOSAR_ECUC")
+            .unwrap();
+        let hth_def = hoh_cfg_def.create_param_conf_container_def("CanIfHthCfg").unwrap();
+        hth_def
+            .create_reference_def("CanIfHthIdSymRef", "AUTOSAR_ECUC")
+            .unwrap();
+        let rx_pdu_def = init_cfg_def.create_param_conf_container_def("CanIfRxPduCfg").unwrap();
+        rx_pdu_def
+            .create_integer_param_def("CanIfRxPduCanId", "AUTOSAR_ECUC")
+            .unwrap();
+        init_cfg_def.create_param_conf_container_def("CanIfTxPduCfg").unwrap();
+
+        (can_def, canif_def)
+    }
+
+    #[test]
+    fn generate_can() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+        let (can_def, canif_def) = create_definitions(&package);
+
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let cluster = system.create_can_cluster("Cluster", &package, Some(500_000)).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+        let ctrl = ecu.create_can_communication_controller("CanCtrl").unwrap();
+        ctrl.connect_physical_channel("Connector", &channel).unwrap();
+        // a controller that is not connected to any channel is ignored
+        ecu.create_can_communication_controller("UnusedCtrl").unwrap();
+
+        let tx_frame = system.create_can_frame("TxFrame", &package, 8).unwrap();
+        let tx_ft = channel
+            .trigger_frame(&tx_frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        tx_ft.connect_to_ecu(&ecu, CommunicationDirection::Out).unwrap();
+        let rx_frame = system.create_can_frame("RxFrame", &package, 8).unwrap();
+        let rx_ft = channel
+            .trigger_frame(&rx_frame, 0x200, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        rx_ft.connect_to_ecu(&ecu, CommunicationDirection::In).unwrap();
+
+        let (can_config, canif_config) =
+            generate_can_configuration("CanValues", "CanIfValues", &package, &ecu, &can_def, &canif_def).unwrap();
+
+        let config_set = can_config.container_values().next().unwrap();
+        let sub_containers: Vec<_> = config_set.sub_containers().collect();
+        // one controller and two hardware objects
+        assert_eq!(sub_containers.len(), 3);
+        assert_eq!(sub_containers[0].name().unwrap(), "CanCtrl");
+        // the receive object comes first
+        assert_eq!(sub_containers[1].name().unwrap(), rx_ft.name().unwrap());
+        assert_eq!(sub_containers[2].name().unwrap(), tx_ft.name().unwrap());
+        let baudrate_config = sub_containers[0].sub_containers().next().unwrap();
+        let Some(EcucParameterValue::Numerical(baudrate)) = baudrate_config.parameter_values().next() else {
+            panic!("missing CanControllerBaudRate");
+        };
+        assert_eq!(baudrate.value_float(), Some(500.0));
+
+        let mut canif_containers = canif_config.container_values();
+        let ctrl_drv_cfg = canif_containers.next().unwrap();
+        assert_eq!(ctrl_drv_cfg.sub_containers().count(), 1);
+        let init_cfg = canif_containers.next().unwrap();
+        // CanIfInitHohCfg, CanIfRxPduCfg, CanIfTxPduCfg
+        assert_eq!(init_cfg.sub_containers().count(), 3);
+        let hoh_cfg = init_cfg.sub_containers().next().unwrap();
+        assert_eq!(hoh_cfg.sub_containers().count(), 2);
+    }
+}
@@ -0,0 +1,534 @@
+use super::{
+    create_unique_sub_container, ecu_pdu_triggerings, find_module_container_def, find_sub_container_def,
+    set_param_value, set_reference_value,
+};
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, ByteOrder, EcuInstance, IdentifiableAbstractionElement,
+    communication::{
+        CommunicationDirection, ISignal, ISignalIPdu, ISignalToIPduMapping, Pdu, SignalPdu, TransferProperty,
+    },
+    ecu_configuration::{EcucContainerValue, EcucModuleConfigurationValues, EcucModuleDef, EcucParamConfContainerDef},
+};
+use autosar_data::AutosarDataError;
+
+//#########################################################
+
+/// Generate the configuration of the Com module for an `EcuInstance` from the system description
+///
+/// All `ISignalIPdus` that are sent or received by the ECU, as defined by the `IPduPorts` of the ECU,
+/// are collected and a `ComIPdu` container is created for each of them.
+/// Each signal that is mapped into one of these PDUs is configured as a `ComSignal`.
+/// Signal groups are configured as `ComSignalGroup` containers, which contain a `ComGroupSignal`
+/// for each of their signals.
+///
+/// The container, parameter and reference definitions are looked up by name in `com_definition`.
+/// The definition must at least contain the container `ComConfig` with the sub-containers `ComIPdu` and `ComSignal`.
+/// Parameters that are not defined in `com_definition` are skipped.
+///
+/// # Example
+///
+/// ```
+/// # use autosar_data::*;
+/// # use autosar_data_abstraction::*;
+/// # use autosar_data_abstraction::ecu_configuration::*;
+/// # fn main() -> Result<(), AutosarAbstractionError> {
+/// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+/// # let package = model.get_or_create_package("/pkg")?;
+/// # let system = package.create_system("System", SystemCategory::EcuExtract)?;
+/// # let ecu_instance = system.create_ecu_instance("Ecu", &package)?;
+/// # let com_definition = package.create_ecuc_module_def("Com")?;
+/// # let com_config_def = com_definition.create_param_conf_container_def("ComConfig")?;
+/// # com_config_def.create_param_conf_container_def("ComIPdu")?;
+/// # com_config_def.create_param_conf_container_def("ComSignal")?;
+/// let com_config = generate_com_configuration("Com", &package, &ecu_instance, &com_definition)?;
+/// # assert_eq!(com_config.container_values().count(), 1);
+/// # Ok(())}
+/// ```
+///
+/// # Errors
+///
+/// - [`AutosarAbstractionError::InvalidParameter`] the Com definition does not contain the required containers
+/// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model.
+///   The new module configuration is removed again in this case.
+pub fn generate_com_configuration(
+    name: &str,
+    package: &ArPackage,
+    ecu_instance: &EcuInstance,
+    com_definition: &EcucModuleDef,
+) -> Result<EcucModuleConfigurationValues, AutosarAbstractionError> {
+    let com_config_def = find_module_container_def(com_definition, "ComConfig").ok_or(
+        AutosarAbstractionError::InvalidParameter("The Com definition has no container ComConfig".to_string()),
+    )?;
+    let defs = ComDefinitions {
+        ipdu: find_sub_container_def(&com_config_def, "ComIPdu").ok_or(AutosarAbstractionError::InvalidParameter(
+            "The Com definition has no container ComIPdu".to_string(),
+        ))?,
+        signal: find_sub_container_def(&com_config_def, "ComSignal").ok_or(
+            AutosarAbstractionError::InvalidParameter("The Com definition has no container ComSignal".to_string()),
+        )?,
+        signal_group: find_sub_container_def(&com_config_def, "ComSignalGroup"),
+    };
+
+    // each PDU is only configured once per direction, even if it is triggered on multiple channels
+    let mut ipdus: Vec<(ISignalIPdu, CommunicationDirection)> = Vec::new();
+    for (pdu_triggering, direction) in ecu_pdu_triggerings(ecu_instance)? {
+        if let Some(Pdu::ISignalIPdu(ipdu)) = pdu_triggering.pdu()
+            && !ipdus
+                .iter()
+                .any(|(existing, dir)| *existing == ipdu && *dir == direction)
+        {
+            ipdus.push((ipdu, direction));
+        }
+    }
+
+    let module_config = package.create_ecuc_module_configuration_values(name, com_definition)?;
+    let result = fill_com_configuration(&module_config, &com_config_def, defs, &ipdus);
+    if let Err(err) = result {
+        // don't leave a partially filled module configuration behind
+        module_config.remove(false)?;
+        return Err(err);
+    }
+
+    Ok(module_config)
+}
+
+// create the ComConfig container and the configuration of all PDUs inside the module configuration
+fn fill_com_configuration(
+    module_config: &EcucModuleConfigurationValues,
+    com_config_def: &EcucParamConfContainerDef,
+    defs: ComDefinitions,
+    ipdus: &[(ISignalIPdu, CommunicationDirection)],
+) -> Result<(), AutosarAbstractionError> {
+    let com_config = module_config.create_container_value("ComConfig", com_config_def)?;
+    let mut generator = ComGenerator {
+        com_config,
+        defs,
+        signal_handle_id: 0,
+        signal_group_handle_id: 0,
+    };
+
+    for (ipdu_handle_id, (ipdu, direction)) in ipdus.iter().enumerate() {
+        generator.create_ipdu(ipdu, *direction, ipdu_handle_id)?;
+    }
+
+    Ok(())
+}
+
+//#########################################################
+
+struct ComDefinitions {
+    ipdu: EcucParamConfContainerDef,
+    signal: EcucParamConfContainerDef,
+    signal_group: Option<EcucParamConfContainerDef>,
+}
+
+struct ComGenerator {
+    com_config: EcucContainerValue,
+    defs: ComDefinitions,
+    signal_handle_id: usize,
+    signal_group_handle_id: usize,
+}
+
+impl ComGenerator {
+    fn create_ipdu(
+        &mut self,
+        ipdu: &ISignalIPdu,
+        direction: CommunicationDirection,
+        handle_id: usize,
+    ) -> Result<(), AutosarAbstractionError> {
+        let ipdu_name = ipdu.name().ok_or(AutosarDataError::ItemDeleted)?;
+        let ipdu_value = create_unique_sub_container(&self.com_config, &ipdu_name, &self.defs.ipdu)?;
+        let direction_str = match direction {
+            CommunicationDirection::In => "RECEIVE",
+            CommunicationDirection::Out => "SEND",
+        };
+        set_param_value(&ipdu_value, &self.defs.ipdu, "ComIPduDirection", direction_str)?;
+        set_param_value(&ipdu_value, &self.defs.ipdu, "ComIPduHandleId", &handle_id.to_string())?;
+
+        for mapping in ipdu.mapped_signals() {
+            if let Some(signal) = mapping.signal() {
+                // signals that are part of a signal group are configured as ComGroupSignals
+                if signal.signal_group().is_some() {
+                    continue;
+                }
+                let signal_value = create_unique_sub_container(
+                    &self.com_config,
+                    &signal.name().unwrap_or_default(),
+                    &self.defs.signal,
+                )?;
+                configure_signal(
+                    &signal_value,
+                    &self.defs.signal,
+                    &mapping,
+                    &signal,
+                    direction,
+                    self.signal_handle_id,
+                )?;
+                self.signal_handle_id += 1;
+                set_reference_value(&ipdu_value, &self.defs.ipdu, "ComIPduSignalRef", &signal_value)?;
+            } else if let Some(signal_group) = mapping.signal_group()
+                && let Some(signal_group_def) = &self.defs.signal_group
+            {
+                let group_name = signal_group.name().ok_or(AutosarDataError::ItemDeleted)?;
+                let group_value = create_unique_sub_container(&self.com_config, &group_name, signal_group_def)?;
+                set_param_value(
+                    &group_value,
+                    signal_group_def,
+                    "ComHandleId",
+                    &self.signal_group_handle_id.to_string(),
+                )?;
+                self.signal_group_handle_id += 1;
+                if direction == CommunicationDirection::Out
+                    && let Some(transfer_property) = mapping.transfer_property()
+                {
+                    set_param_value(
+                        &group_value,
+                        signal_group_def,
+                        "ComTransferProperty",
+                        transfer_property_str(transfer_property),
+                    )?;
+                }
+                set_reference_value(
+                    &group_value,
+                    signal_group_def,
+                    "ComSystemTemplateSignalGroupRef",
+                    &mapping,
+                )?;
+
+                // the group signals are mapped individually in the PDU
+                if let Some(group_signal_def) = find_sub_container_def(signal_group_def, "ComGroupSignal") {
+                    for group_signal_mapping in ipdu.mapped_signals() {
+                        let Some(group_signal) = group_signal_mapping.signal() else {
+                            continue;
+                        };
+                        if group_signal.signal_group().as_ref() != Some(&signal_group) {
+                            continue;
+                        }
+                        let group_signal_value = create_unique_sub_container(
+                            &group_value,
+                            &group_signal.name().unwrap_or_default(),
+                            &group_signal_def,
+                        )?;
+                        configure_signal(
+                            &group_signal_value,
+                            &group_signal_def,
+                            &group_signal_mapping,
+                            &group_signal,
+                            direction,
+                            self.signal_handle_id,
+                        )?;
+                        self.signal_handle_id += 1;
+                    }
+                }
+                set_reference_value(&ipdu_value, &self.defs.ipdu, "ComIPduSignalGroupRef", &group_value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// set the parameters of a ComSignal or ComGroupSignal based on the signal mapping
+fn configure_signal(
+    signal_value: &EcucContainerValue,
+    signal_def: &EcucParamConfContainerDef,
+    mapping: &ISignalToIPduMapping,
+    signal: &ISignal,
+    direction: CommunicationDirection,
+    handle_id: usize,
+) -> Result<(), AutosarAbstractionError> {
+    set_param_value(signal_value, signal_def, "ComHandleId", &handle_id.to_string())?;
+    if let Some(start_position) = mapping.start_position() {
+        set_param_value(signal_value, signal_def, "ComBitPosition", &start_position.to_string())?;
+    }
+    if let Some(length) = signal.length() {
+        set_param_value(signal_value, signal_def, "ComBitSize", &length.to_string())?;
+    }
+    if let Some(byte_order) = mapping.byte_order() {
+        let endianness = match byte_order {
+            ByteOrder::MostSignificantByteFirst => "BIG_ENDIAN",
+            ByteOrder::MostSignificantByteLast => "LITTLE_ENDIAN",
+            ByteOrder::Opaque => "OPAQUE",
+        };
+        set_param_value(signal_value, signal_def, "ComSignalEndianness", endianness)?;
+    }
+    if direction == CommunicationDirection::Out
+        && let Some(transfer_property) = mapping.transfer_property()
+    {
+        set_param_value(
+            signal_value,
+            signal_def,
+            "ComTransferProperty",
+            transfer_property_str(transfer_property),
+        )?;
+    }
+    if let Some(update_bit) = mapping.update_bit() {
+        set_param_value(
+            signal_value,
+            signal_def,
+            "ComUpdateBitPosition",
+            &update_bit.to_string(),
+        )?;
+    }
+    set_reference_value(signal_value, signal_def, "ComSystemTemplateSystemSignalRef", mapping)?;
+
+    Ok(())
+}
+
+fn transfer_property_str(transfer_property: TransferProperty) -> &'static str {
+    match transfer_property {
+        TransferProperty::Pending => "PENDING",
+        TransferProperty::Triggered => "TRIGGERED",
+        TransferProperty::TriggeredOnChange => "TRIGGERED_ON_CHANGE",
+        TransferProperty::TriggeredOnChangeWithoutRepetition => "TRIGGERED_ON_CHANGE_WITHOUT_REPETITION",
+        TransferProperty::TriggeredWithoutRepetition => "TRIGGERED_WITHOUT_REPETITION",
+    }
+}
+
+//#########################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AutosarModelAbstraction, NameConflictPolicy, SystemCategory,
+        communication::{AbstractFrame, CanAddressingMode, CanFrameType},
+        ecu_configuration::{EcucAnyReferenceValue, EcucParameterValue},
+    };
+    use autosar_data::AutosarVersion;
+
+    fn create_com_definition(package: &ArPackage) -> EcucModuleDef {
+        let com_def = package.create_ecuc_module_def("Com").unwrap();
+        let com_config_def = com_def.create_param_conf_container_def("ComConfig").unwrap();
+
+        let signal_def = com_config_def.create_param_conf_container_def("ComSignal").unwrap();
+        signal_def
+            .create_integer_param_def("ComHandleId", "AUTOSAR_ECUC")
+            .unwrap();
+        signal_def
+            .create_integer_param_def("ComBitPosition", "AUTOSAR_ECUC")
+            .unwrap();
+        signal_def
+            .create_integer_param_def("ComBitSize", "AUTOSAR_ECUC")
+            .unwrap();
+        signal_def
+            .create_enumeration_param_def("ComSignalEndianness", "AUTOSAR_ECUC")
+            .unwrap();
+        signal_def
+            .create_enumeration_param_def("ComTransferProperty", "AUTOSAR_ECUC")
+            .unwrap();
+        signal_def
+            .create_foreign_reference_def("ComSystemTemplateSystemSignalRef", "AUTOSAR_ECUC")
+            .unwrap();
+
+        let ipdu_def = com_config_def.create_param_conf_container_def("ComIPdu").unwrap();
+        ipdu_def
+            .create_enumeration_param_def("ComIPduDirection", "AUTOSAR_ECUC")
+            .unwrap();
+        ipdu_def
+            .create_integer_param_def("ComIPduHandleId", "AUTOSAR_ECUC")
+            .unwrap();
+        ipdu_def
+            .create_reference_def("ComIPduSignalRef", "AUTOSAR_ECUC")
+            .unwrap();
+
+        com_def
+    }
+
+    #[test]
+    fn generate_com() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+        let com_def = create_com_definition(&package);
+
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let other_ecu = system.create_ecu_instance("OtherEcu", &package).unwrap();
+        let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+        let ctrl = ecu.create_can_communication_controller("CanCtrl").unwrap();
+        ctrl.connect_physical_channel("Connector", &channel).unwrap();
+        let other_ctrl = other_ecu.create_can_communication_controller("CanCtrl").unwrap();
+        other_ctrl.connect_physical_channel("Connector", &channel).unwrap();
+
+        let syssignal = package.create_system_signal("SysSignal").unwrap();
+        let signal = system.create_isignal("Signal", &package, 8, &syssignal, None).unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        let mapping = pdu
+            .map_signal(
+                &signal,
+                8,
+                ByteOrder::MostSignificantByteLast,
+                None,
+                TransferProperty::Triggered,
+            )
+            .unwrap();
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        frame_triggering
+            .connect_to_ecu(&ecu, CommunicationDirection::Out)
+            .unwrap();
+        frame_triggering
+            .connect_to_ecu(&other_ecu, CommunicationDirection::In)
+            .unwrap();
+
+        let com_config = generate_com_configuration("ComValues", &package, &ecu, &com_def).unwrap();
+        let com_config_value = com_config.container_values().next().unwrap();
+        let sub_containers: Vec<_> = com_config_value.sub_containers().collect();
+        assert_eq!(sub_containers.len(), 2);
+        let ipdu_value = sub_containers
+            .iter()
+            .find(|container| container.name().as_deref() == Some("Pdu"))
+            .unwrap();
+        let signal_value = sub_containers
+            .iter()
+            .find(|container| container.name().as_deref() == Some("Signal"))
+            .unwrap();
+
+        let direction = ipdu_value
+            .parameter_values()
+            .find_map(|param| match param {
+                EcucParameterValue::Textual(textual) => textual.value(),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(direction, "SEND");
+        let Some(EcucAnyReferenceValue::Reference(signal_ref)) = ipdu_value.reference_values().next() else {
+            panic!("missing ComIPduSignalRef");
+        };
+        assert_eq!(signal_ref.target().as_ref(), Some(signal_value.element()));
+
+        let int_values: Vec<_> = signal_value
+            .parameter_values()
+            .filter_map(|param| match param {
+                EcucParameterValue::Numerical(numerical) => numerical.value_int(),
+                _ => None,
+            })
+            .collect();
+        // handle id, bit position, bit size
+        assert_eq!(int_values, vec![0, 8, 8]);
+        let Some(EcucAnyReferenceValue::Reference(mapping_ref)) = signal_value.reference_values().next() else {
+            panic!("missing ComSystemTemplateSystemSignalRef");
+        };
+        assert_eq!(mapping_ref.target().as_ref(), Some(mapping.element()));
+
+        // the other ECU receives the PDU
+        let com_config = generate_com_configuration("OtherComValues", &package, &other_ecu, &com_def).unwrap();
+        let com_config_value = com_config.container_values().next().unwrap();
+        assert_eq!(com_config_value.sub_containers().count(), 2);
+
+        // an incomplete definition is rejected
+        let bad_def = package.create_ecuc_module_def("BadCom").unwrap();
+        let result = generate_com_configuration("BadValues", &package, &ecu, &bad_def);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn generate_com_name_conflict() {
+        // the names of generated containers always get a suffix, regardless of the name conflict policy
+        for policy in [
+            NameConflictPolicy::Suffix,
+            NameConflictPolicy::Error,
+            NameConflictPolicy::Overwrite,
+        ] {
+            let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+            model.set_name_conflict_policy(policy);
+            let package = model.get_or_create_package("/pkg").unwrap();
+            let pdu_package = model.get_or_create_package("/pdus").unwrap();
+            let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+            let com_def = create_com_definition(&package);
+
+            let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+            let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+            let channel = cluster.create_physical_channel("Channel").unwrap();
+            let ctrl = ecu.create_can_communication_controller("CanCtrl").unwrap();
+            ctrl.connect_physical_channel("Connector", &channel).unwrap();
+
+            // the signal and the PDU have the same name
+            let syssignal = package.create_system_signal("SysSignal").unwrap();
+            let signal = system.create_isignal("Data", &package, 8, &syssignal, None).unwrap();
+            let pdu = system.create_isignal_ipdu("Data", &pdu_package, 8).unwrap();
+            pdu.map_signal(
+                &signal,
+                0,
+                ByteOrder::MostSignificantByteLast,
+                None,
+                TransferProperty::Triggered,
+            )
+            .unwrap();
+            let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+            frame
+                .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+                .unwrap();
+            let frame_triggering = channel
+                .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+                .unwrap();
+            frame_triggering
+                .connect_to_ecu(&ecu, CommunicationDirection::Out)
+                .unwrap();
+
+            let com_config = generate_com_configuration("ComValues", &package, &ecu, &com_def).unwrap();
+            let com_config_value = com_config.container_values().next().unwrap();
+            let mut names: Vec<_> = com_config_value
+                .sub_containers()
+                .filter_map(|container| container.name())
+                .collect();
+            names.sort();
+            assert_eq!(names, vec!["Data", "Data_1"], "policy {policy:?}");
+        }
+    }
+
+    #[test]
+    fn generate_com_failure() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let pdu_package = model.get_or_create_package("/pdus").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+        let com_def = create_com_definition(&package);
+
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+        let ctrl = ecu.create_can_communication_controller("CanCtrl").unwrap();
+        ctrl.connect_physical_channel("Connector", &channel).unwrap();
+
+        let syssignal = package.create_system_signal("SysSignal").unwrap();
+        let signal = system.create_isignal("Signal", &package, 8, &syssignal, None).unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &pdu_package, 8).unwrap();
+        pdu.map_signal(
+            &signal,
+            0,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        frame_triggering
+            .connect_to_ecu(&ecu, CommunicationDirection::Out)
+            .unwrap();
+
+        // the signal and the PDU get the same name with the maximum length of 128 characters.
+        // The ComSignal container needs a suffix, which makes its name invalid, so the generation fails
+        // after the ComIPdu container was created
+        let long_name = format!("Data{}", "x".repeat(124));
+        signal.set_name(&long_name).unwrap();
+        pdu.set_name(&long_name).unwrap();
+
+        let result = generate_com_configuration("ComValues", &package, &ecu, &com_def);
+        assert!(result.is_err());
+        // no partially filled module configuration remains
+        assert!(model.get_element_by_path("/pkg/ComValues").is_none());
+    }
+}
@@ -0,0 +1,138 @@
+//! Generators which derive ECU configuration values from the system description
+//!
+//! The generators look up the required container, parameter and reference definitions
+//! by their short names in a supplied module definition, so they can work with both the
+//! standardized definitions and vendor specific refinements of them.
+//! Parameters and references that are not present in the definition are silently skipped.
+
+use crate::{
+    AbstractionElement, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
+    communication::{CommunicationDirection, IPduPort, PduTriggering},
+    ecu_configuration::{
+        EcucContainerDef, EcucContainerValue, EcucModuleDef, EcucParamConfContainerDef, EcucParameterDef,
+    },
+    make_unique_generated_name,
+};
+use autosar_data::{ElementName, WeakElement};
+
+mod can;
+mod com;
+mod rte;
+
+pub use can::*;
+pub use com::*;
+pub use rte::*;
+
+//#########################################################
+
+// find a top-level container definition of a module definition by name
+fn find_module_container_def(module_def: &EcucModuleDef, name: &str) -> Option<EcucParamConfContainerDef> {
+    module_def.containers().find_map(|container| match container {
+        EcucContainerDef::ParamConf(param_conf) if param_conf.name().as_deref() == Some(name) => Some(param_conf),
+        _ => None,
+    })
+}
+
+// find a sub-container definition of a container definition by name
+fn find_sub_container_def(container_def: &EcucParamConfContainerDef, name: &str) -> Option<EcucParamConfContainerDef> {
+    container_def.sub_containers().find_map(|container| match container {
+        EcucContainerDef::ParamConf(param_conf) if param_conf.name().as_deref() == Some(name) => Some(param_conf),
+        _ => None,
+    })
+}
+
+// create a sub-container whose name is unique within the parent container
+fn create_unique_sub_container(
+    parent: &EcucContainerValue,
+    name: &str,
+    definition: &EcucParamConfContainerDef,
+) -> Result<EcucContainerValue, AutosarAbstractionError> {
+    let model = parent.element().model()?;
+    let base_path = parent.element().path()?;
+    let name = make_unique_generated_name(&model, &base_path, name);
+    parent.create_sub_container(&name, definition)
+}
+
+// create a parameter value in the container, if the container definition contains a parameter with the given name
+//
+// Numerical parameters (boolean, integer, float) are created as `EcucNumericalParamValue`,
+// all other parameter kinds are created as `EcucTextualParamValue`.
+fn set_param_value(
+    container_value: &EcucContainerValue,
+    container_def: &EcucParamConfContainerDef,
+    param_name: &str,
+    value: &str,
+) -> Result<(), AutosarAbstractionError> {
+    let Some(param_def) = container_def
+        .parameters()
+        .find(|param| param.name().as_deref() == Some(param_name))
+    else {
+        return Ok(());
+    };
+
+    match param_def {
+        EcucParameterDef::AddInfo(_) => {}
+        EcucParameterDef::Boolean(_) | EcucParameterDef::Integer(_) | EcucParameterDef::Float(_) => {
+            container_value.create_numerical_param_value(&param_def, value)?;
+        }
+        _ => {
+            container_value.create_textual_param_value(&param_def, value)?;
+        }
+    }
+
+    Ok(())
+}
+
+// create a reference value in the container, if the container definition contains a reference with the given name
+fn set_reference_value<T: AbstractionElement>(
+    container_value: &EcucContainerValue,
+    container_def: &EcucParamConfContainerDef,
+    reference_name: &str,
+    target: &T,
+) -> Result<(), AutosarAbstractionError> {
+    if let Some(reference_def) = container_def
+        .references()
+        .find(|reference| reference.name().as_deref() == Some(reference_name))
+    {
+        container_value.create_reference_value(&reference_def, target.element())?;
+    }
+
+    Ok(())
+}
+
+// collect all PduTriggerings which are connected to the ECU through an IPduPort
+//
+// The PduTriggerings are returned together with the direction of the IPduPort.
+fn ecu_pdu_triggerings(
+    ecu_instance: &EcuInstance,
+) -> Result<Vec<(PduTriggering, CommunicationDirection)>, AutosarAbstractionError> {
+    let model = ecu_instance.element().model()?;
+    let mut result = Vec::new();
+
+    let pdu_ports = ecu_instance
+        .element()
+        .get_sub_element(ElementName::Connectors)
+        .into_iter()
+        .flat_map(|connectors| connectors.sub_elements())
+        .filter_map(|connector| connector.get_sub_element(ElementName::EcuCommPortInstances))
+        .flat_map(|port_instances| port_instances.sub_elements())
+        .filter_map(|port| IPduPort::try_from(port).ok());
+
+    for pdu_port in pdu_ports {
+        let Some(direction) = pdu_port.communication_direction() else {
+            continue;
+        };
+        let path = pdu_port.element().path()?;
+        for pdu_triggering in model
+            .get_references_to(&path)
+            .iter()
+            .filter_map(WeakElement::upgrade)
+            .filter_map(|ref_elem| ref_elem.named_parent().ok().flatten())
+            .filter_map(|named_parent| PduTriggering::try_from(named_parent).ok())
+        {
+            result.push((pdu_triggering, direction));
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,364 @@
+use super::{create_unique_sub_container, find_module_container_def, find_sub_container_def, set_param_value};
+use crate::{
+    AbstractionElement, AutosarAbstractionError, IdentifiableAbstractionElement,
+    ecu_configuration::{
+        EcucAnyReferenceDef, EcucAnyReferenceValue, EcucContainerValue, EcucModuleConfigurationValues, EcucModuleDef,
+        EcucParamConfContainerDef, EcucParameterValue,
+    },
+    make_unique_generated_name,
+    software_component::{AbstractRTEEvent, RTEEvent, SwComponentPrototype},
+};
+use autosar_data::{AutosarDataError, Element};
+
+//#########################################################
+
+/// Map an RTE event of a component instance to an OS task in the configuration of the Rte
+///
+/// The mapping is stored in an `RteEventToTaskMapping` container, which is created inside the
+/// `RteSwComponentInstance` container of the component instance. If the Rte configuration does not contain
+/// an `RteSwComponentInstance` for the component instance yet, then it is created as well.
+/// The `os_task` is the `OsTask` container in the configuration of the Os, and the runnables of all
+/// events that are mapped to the same task are executed in the order given by `position_in_task`.
+///
+/// If the event is already mapped to the same task, the existing mapping is reused and only its position is updated.
+///
+/// The container, parameter and reference definitions are looked up by name in `rte_definition`.
+/// The definition must contain the container `RteSwComponentInstance` with the reference `RteSoftwareComponentInstanceRef`
+/// and the sub-container `RteEventToTaskMapping` with the references `RteEventRef` and `RteMappedToTaskRef`.
+///
+/// # Example
+///
+/// ```
+/// # use autosar_data::*;
+/// # use autosar_data_abstraction::*;
+/// # use autosar_data_abstraction::ecu_configuration::*;
+/// # use autosar_data_abstraction::software_component::*;
+/// # fn main() -> Result<(), AutosarAbstractionError> {
+/// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+/// # let package = model.get_or_create_package("/pkg")?;
+/// # let swc = package.create_application_sw_component_type("Swc")?;
+/// # let behavior = swc.create_swc_internal_behavior("Behavior")?;
+/// # let runnable = behavior.create_runnable_entity("Runnable")?;
+/// # let event = behavior.create_timing_event("Event", &runnable, 0.01)?;
+/// # let composition = package.create_composition_sw_component_type("Composition")?;
+/// # let component = composition.create_component("SwcPrototype", &swc)?;
+/// # let os_definition = package.create_ecuc_module_def("Os")?;
+/// # let os_task_def = os_definition.create_param_conf_container_def("OsTask")?;
+/// # let os_config = package.create_ecuc_module_configuration_values("OsValues", &os_definition)?;
+/// # let os_task = os_config.create_container_value("Task_10ms", &os_task_def)?;
+/// # let rte_definition = package.create_ecuc_module_def("Rte")?;
+/// # let instance_def = rte_definition.create_param_conf_container_def("RteSwComponentInstance")?;
+/// # instance_def.create_foreign_reference_def("RteSoftwareComponentInstanceRef", "AUTOSAR_ECUC")?;
+/// # let mapping_def = instance_def.create_param_conf_container_def("RteEventToTaskMapping")?;
+/// # mapping_def.create_foreign_reference_def("RteEventRef", "AUTOSAR_ECUC")?;
+/// # mapping_def.create_reference_def("RteMappedToTaskRef", "AUTOSAR_ECUC")?;
+/// let rte_config = package.create_ecuc_module_configuration_values("RteValues", &rte_definition)?;
+/// map_rte_event_to_task(&rte_config, &rte_definition, &component, &event, &os_task, 0)?;
+/// assert_eq!(rte_event_to_task_mappings(&rte_config).len(), 1);
+/// # Ok(())}
+/// ```
+///
+/// # Errors
+///
+/// - [`AutosarAbstractionError::InvalidParameter`] the Rte definition does not contain the required containers
+///   and references, or the event does not belong to the component type of the component instance
+/// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+pub fn map_rte_event_to_task<T: AbstractRTEEvent + IdentifiableAbstractionElement>(
+    rte_config: &EcucModuleConfigurationValues,
+    rte_definition: &EcucModuleDef,
+    component: &SwComponentPrototype,
+    event: &T,
+    os_task: &EcucContainerValue,
+    position_in_task: u32,
+) -> Result<EcucContainerValue, AutosarAbstractionError> {
+    let instance_def = find_module_container_def(rte_definition, "RteSwComponentInstance").ok_or(
+        AutosarAbstractionError::InvalidParameter(
+            "The Rte definition has no container RteSwComponentInstance".to_string(),
+        ),
+    )?;
+    let mapping_def = find_sub_container_def(&instance_def, "RteEventToTaskMapping").ok_or(
+        AutosarAbstractionError::InvalidParameter(
+            "The Rte definition has no container RteEventToTaskMapping".to_string(),
+        ),
+    )?;
+
+    let instance_ref_def = find_reference_def(&instance_def, "RteSoftwareComponentInstanceRef")?;
+    let event_ref_def = find_reference_def(&mapping_def, "RteEventRef")?;
+    let task_ref_def = find_reference_def(&mapping_def, "RteMappedToTaskRef")?;
+
+    let event_component_type = event
+        .swc_internal_behavior()
+        .and_then(|behavior| behavior.sw_component_type());
+    if event_component_type.is_none() || event_component_type != component.component_type() {
+        return Err(AutosarAbstractionError::InvalidParameter(format!(
+            "The RTE event {} does not belong to the component type of {}",
+            event.name().as_deref().unwrap_or("(invalid)"),
+            component.name().as_deref().unwrap_or("(invalid)")
+        )));
+    }
+
+    // reuse the RteSwComponentInstance of the component instance, if it already exists
+    let instance_value = if let Some(instance_value) = rte_config
+        .container_values()
+        .find(|container| references_target(container, "RteSoftwareComponentInstanceRef", component.element()))
+    {
+        instance_value
+    } else {
+        let model = rte_config.element().model()?;
+        let base_path = rte_config.element().path()?;
+        let name = component.name().ok_or(AutosarDataError::ItemDeleted)?;
+        let name = make_unique_generated_name(&model, &base_path, &name);
+        let instance_value = rte_config.create_container_value(&name, &instance_def)?;
+        instance_value.create_reference_value(&instance_ref_def, component.element())?;
+        instance_value
+    };
+
+    // reuse an existing mapping of the event to the same task
+    if let Some(mapping_value) = instance_value.sub_containers().find(|container| {
+        references_target(container, "RteEventRef", event.element())
+            && references_target(container, "RteMappedToTaskRef", os_task.element())
+    }) {
+        if let Some(EcucParameterValue::Numerical(position)) =
+            mapping_value.parameter_value_by_definition("RtePositionInTask")
+        {
+            position.set_value(&position_in_task.to_string())?;
+        } else {
+            set_param_value(
+                &mapping_value,
+                &mapping_def,
+                "RtePositionInTask",
+                &position_in_task.to_string(),
+            )?;
+        }
+        return Ok(mapping_value);
+    }
+
+    let mapping_value = create_unique_sub_container(
+        &instance_value,
+        &event.name().ok_or(AutosarDataError::ItemDeleted)?,
+        &mapping_def,
+    )?;
+    mapping_value.create_reference_value(&event_ref_def, event.element())?;
+    mapping_value.create_reference_value(&task_ref_def, os_task.element())?;
+    set_param_value(
+        &mapping_value,
+        &mapping_def,
+        "RtePositionInTask",
+        &position_in_task.to_string(),
+    )?;
+
+    Ok(mapping_value)
+}
+
+// find a reference definition in the container definition by its short name
+fn find_reference_def(
+    container_def: &EcucParamConfContainerDef,
+    name: &str,
+) -> Result<EcucAnyReferenceDef, AutosarAbstractionError> {
+    container_def
+        .references()
+        .find(|reference| reference.name().as_deref() == Some(name))
+        .ok_or(AutosarAbstractionError::InvalidParameter(format!(
+            "The Rte definition has no reference {name}"
+        )))
+}
+
+/// An `RteEventToTaskMapping` in the configuration of the Rte, as returned by [`rte_event_to_task_mappings`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RteEventToTaskMapping {
+    /// the component instance that contains the event
+    pub component: Option<SwComponentPrototype>,
+    /// the mapped event
+    pub event: RTEEvent,
+    /// the `OsTask` container to which the event is mapped
+    pub os_task: EcucContainerValue,
+    /// the position of the runnable of the event in the task
+    pub position_in_task: Option<u64>,
+}
+
+/// Get all mappings of `RTEEvent`s to OS tasks in the configuration of the Rte
+///
+/// The containers, parameters and references are identified by the short names of their definitions,
+/// so the module definition of the Rte does not need to be loaded.
+/// Incomplete mappings, which do not refer to both an event and a task, are skipped.
+#[must_use]
+pub fn rte_event_to_task_mappings(rte_config: &EcucModuleConfigurationValues) -> Vec<RteEventToTaskMapping> {
+    rte_config
+        .container_values()
+        .filter(|container| definition_name_is(container.definition_ref(), "RteSwComponentInstance"))
+        .flat_map(|instance_value| {
+            let component = reference_target(&instance_value, "RteSoftwareComponentInstanceRef")
+                .and_then(|elem| SwComponentPrototype::try_from(elem).ok());
+            instance_value
+                .sub_containers()
+                .filter(|container| definition_name_is(container.definition_ref(), "RteEventToTaskMapping"))
+                .filter_map(move |mapping_value| {
+                    let event = reference_target(&mapping_value, "RteEventRef")
+                        .and_then(|elem| RTEEvent::try_from(elem).ok())?;
+                    let os_task = reference_target(&mapping_value, "RteMappedToTaskRef")
+                        .and_then(|elem| EcucContainerValue::try_from(elem).ok())?;
+                    let position_in_task = mapping_value.parameter_values().find_map(|param| match param {
+                        EcucParameterValue::Numerical(numerical)
+                            if definition_name_is(numerical.definition_ref(), "RtePositionInTask") =>
+                        {
+                            numerical.value_int().and_then(|value| u64::try_from(value).ok())
+                        }
+                        _ => None,
+                    });
+                    Some(RteEventToTaskMapping {
+                        component: component.clone(),
+                        event,
+                        os_task,
+                        position_in_task,
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+// check if the last part of a definition path is the given short name
+fn definition_name_is(definition_ref: Option<String>, name: &str) -> bool {
+    definition_ref.is_some_and(|def_ref| def_ref.rsplit('/').next() == Some(name))
+}
+
+// get the target of a reference value in the container, identified by the short name of its definition
+fn reference_target(container: &EcucContainerValue, reference_name: &str) -> Option<Element> {
+    container.reference_values().find_map(|reference| match reference {
+        EcucAnyReferenceValue::Reference(reference)
+            if definition_name_is(reference.definition_ref(), reference_name) =>
+        {
+            reference.target()
+        }
+        _ => None,
+    })
+}
+
+fn references_target(container: &EcucContainerValue, reference_name: &str, target: &Element) -> bool {
+    reference_target(container, reference_name).as_ref() == Some(target)
+}
+
+//#########################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutosarModelAbstraction, software_component::AtomicSwComponentType};
+    use autosar_data::AutosarVersion;
+
+    fn create_rte_definition(package: &crate::ArPackage) -> EcucModuleDef {
+        let rte_def = package.create_ecuc_module_def("Rte").unwrap();
+        let instance_def = rte_def
+            .create_param_conf_container_def("RteSwComponentInstance")
+            .unwrap();
+        instance_def
+            .create_foreign_reference_def("RteSoftwareComponentInstanceRef", "AUTOSAR_ECUC")
+            .unwrap();
+        let mapping_def = instance_def
+            .create_param_conf_container_def("RteEventToTaskMapping")
+            .unwrap();
+        mapping_def
+            .create_foreign_reference_def("RteEventRef", "AUTOSAR_ECUC")
+            .unwrap();
+        mapping_def
+            .create_reference_def("RteMappedToTaskRef", "AUTOSAR_ECUC")
+            .unwrap();
+        mapping_def
+            .create_integer_param_def("RtePositionInTask", "AUTOSAR_ECUC")
+            .unwrap();
+
+        rte_def
+    }
+
+    #[test]
+    fn rte_event_to_task_mapping() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let rte_def = create_rte_definition(&package);
+        let os_def = package.create_ecuc_module_def("Os").unwrap();
+        let os_task_def = os_def.create_param_conf_container_def("OsTask").unwrap();
+        let os_config = package
+            .create_ecuc_module_configuration_values("OsValues", &os_def)
+            .unwrap();
+        let task_10ms = os_config.create_container_value("Task_10ms", &os_task_def).unwrap();
+        let task_100ms = os_config.create_container_value("Task_100ms", &os_task_def).unwrap();
+
+        let swc = package.create_application_sw_component_type("Swc").unwrap();
+        let behavior = swc.create_swc_internal_behavior("Behavior").unwrap();
+        let runnable = behavior.create_runnable_entity("Runnable").unwrap();
+        let event_10ms = behavior.create_timing_event("Event_10ms", &runnable, 0.01).unwrap();
+        let event_100ms = behavior.create_timing_event("Event_100ms", &runnable, 0.1).unwrap();
+        let other_swc = package.create_application_sw_component_type("OtherSwc").unwrap();
+        let composition = package.create_composition_sw_component_type("Composition").unwrap();
+        let component = composition.create_component("SwcPrototype", &swc).unwrap();
+        let other_component = composition.create_component("OtherPrototype", &other_swc).unwrap();
+
+        let rte_config = package
+            .create_ecuc_module_configuration_values("RteValues", &rte_def)
+            .unwrap();
+        map_rte_event_to_task(&rte_config, &rte_def, &component, &event_10ms, &task_10ms, 0).unwrap();
+        map_rte_event_to_task(&rte_config, &rte_def, &component, &event_100ms, &task_100ms, 1).unwrap();
+        // the event does not belong to the other component
+        let result = map_rte_event_to_task(&rte_config, &rte_def, &other_component, &event_10ms, &task_10ms, 2);
+        assert!(result.is_err());
+
+        // both mappings are stored in the same RteSwComponentInstance
+        assert_eq!(rte_config.container_values().count(), 1);
+        let mappings = rte_event_to_task_mappings(&rte_config);
+        assert_eq!(
+            mappings,
+            vec![
+                RteEventToTaskMapping {
+                    component: Some(component.clone()),
+                    event: RTEEvent::TimingEvent(event_10ms.clone()),
+                    os_task: task_10ms.clone(),
+                    position_in_task: Some(0),
+                },
+                RteEventToTaskMapping {
+                    component: Some(component.clone()),
+                    event: RTEEvent::TimingEvent(event_100ms),
+                    os_task: task_100ms,
+                    position_in_task: Some(1),
+                },
+            ]
+        );
+
+        // mapping the event to the same task again reuses the existing mapping
+        let mapping = map_rte_event_to_task(&rte_config, &rte_def, &component, &event_10ms, &task_10ms, 3).unwrap();
+        let mappings = rte_event_to_task_mappings(&rte_config);
+        assert_eq!(mappings.len(), 2);
+        assert_eq!(mappings[0].position_in_task, Some(3));
+        assert_eq!(mapping.parameter_values().count(), 1);
+
+        // an incomplete definition is rejected
+        let bad_def = package.create_ecuc_module_def("BadRte").unwrap();
+        let result = map_rte_event_to_task(&rte_config, &bad_def, &component, &event_10ms, &task_10ms, 2);
+        assert!(result.is_err());
+        assert_eq!(rte_event_to_task_mappings(&rte_config).len(), 2);
+
+        // a definition without the reference definitions is rejected before anything is created
+        let incomplete_def = package.create_ecuc_module_def("IncompleteRte").unwrap();
+        let instance_def = incomplete_def
+            .create_param_conf_container_def("RteSwComponentInstance")
+            .unwrap();
+        instance_def
+            .create_foreign_reference_def("RteSoftwareComponentInstanceRef", "AUTOSAR_ECUC")
+            .unwrap();
+        instance_def
+            .create_param_conf_container_def("RteEventToTaskMapping")
+            .unwrap();
+        let incomplete_config = package
+            .create_ecuc_module_configuration_values("IncompleteValues", &incomplete_def)
+            .unwrap();
+        let result = map_rte_event_to_task(
+            &incomplete_config,
+            &incomplete_def,
+            &component,
+            &event_10ms,
+            &task_10ms,
+            0,
+        );
+        assert!(result.is_err());
+        assert_eq!(incomplete_config.container_values().count(), 0);
+    }
+}
@@ -3,9 +3,15 @@
 //! This module provides the structures representing the ECU configuration in the AUTOSAR model.
 //! A complete Autosar system has multiple [`EcucModuleConfigurationValues`]
 //! containers: one per used base software module
+//!
+//! Some of the module configurations can be derived from the system description.
+//! For example, [`generate_com_configuration`] creates the Com configuration of an ECU, and
+//! [`generate_can_configuration`] creates the Can driver and CanIf configurations.
 
 mod definition;
+mod generator;
 mod values;
 
 pub use definition::*;
+pub use generator::*;
 pub use values::*;
@@ -0,0 +1,738 @@
+use crate::{
+    AbstractionElement, AutosarAbstractionError, IdentifiableAbstractionElement,
+    ecu_configuration::{EcucAnyReferenceValue, EcucContainerValue, EcucModuleConfigurationValues, EcucParameterValue},
+};
+use autosar_data::{Element, ElementName};
+use std::collections::HashMap;
+
+//#########################################################
+
+/// A single difference between two `EcucModuleConfigurationValues`
+///
+/// Containers are identified by their path relative to the module configuration, e.g. `"General/SubContainer"`.
+/// Parameter and reference values are not identifiable; they are identified by their definition ref and their
+/// position among the values with the same definition inside the container.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EcucDiffItem {
+    /// The container only exists in the modified configuration
+    ContainerAdded {
+        /// path of the container relative to the module configuration
+        path: String,
+        /// the container in the modified configuration
+        container: EcucContainerValue,
+    },
+    /// The container only exists in the base configuration
+    ContainerRemoved {
+        /// path of the container relative to the module configuration
+        path: String,
+        /// the container in the base configuration
+        container: EcucContainerValue,
+    },
+    /// The container exists in both configurations, but its definition was changed
+    ///
+    /// The container is replaced as a whole, including its values and sub-containers.
+    ContainerReplaced {
+        /// path of the container relative to the module configuration
+        path: String,
+        /// the container in the base configuration
+        old: EcucContainerValue,
+        /// the container in the modified configuration
+        new: EcucContainerValue,
+    },
+    /// A parameter value was added, removed or changed
+    ///
+    /// `old` is `None` if the value was added, and `new` is `None` if it was removed.
+    ParameterChanged {
+        /// path of the containing container relative to the module configuration
+        container_path: String,
+        /// definition ref of the parameter value
+        definition_ref: String,
+        /// position among the parameter values with the same definition
+        position: usize,
+        /// the parameter value in the base configuration
+        old: Option<EcucParameterValue>,
+        /// the parameter value in the modified configuration
+        new: Option<EcucParameterValue>,
+    },
+    /// A reference value was added, removed or changed
+    ///
+    /// `old` is `None` if the value was added, and `new` is `None` if it was removed.
+    ReferenceChanged {
+        /// path of the containing container relative to the module configuration
+        container_path: String,
+        /// definition ref of the reference value
+        definition_ref: String,
+        /// position among the reference values with the same definition
+        position: usize,
+        /// the reference value in the base configuration
+        old: Option<EcucAnyReferenceValue>,
+        /// the reference value in the modified configuration
+        new: Option<EcucAnyReferenceValue>,
+    },
+}
+
+//#########################################################
+
+/// The result of [`EcucModuleConfigurationValues::merge`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EcucMergeReport {
+    /// the changes that were applied
+    pub applied: Vec<EcucDiffItem>,
+    /// the changes that were not applied, because the target configuration was modified in a different way
+    pub conflicts: Vec<EcucDiffItem>,
+}
+
+//#########################################################
+
+impl EcucModuleConfigurationValues {
+    /// compare this module configuration with a modified version of it
+    ///
+    /// The result lists all changes that are needed to turn `self` into `modified`.
+    /// Both configurations may be located in different models, e.g. a vendor delivery and its update.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::ecu_configuration::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg")?;
+    /// # let module_def = package.create_ecuc_module_def("ModuleDef")?;
+    /// # let container_def = module_def.create_param_conf_container_def("ContainerDef")?;
+    /// let base = package.create_ecuc_module_configuration_values("Base", &module_def)?;
+    /// let modified = package.create_ecuc_module_configuration_values("Modified", &module_def)?;
+    /// modified.create_container_value("Container", &container_def)?;
+    /// let diff = base.diff(&modified);
+    /// assert_eq!(diff.len(), 1);
+    /// # Ok(())}
+    /// ```
+    #[must_use]
+    pub fn diff(&self, modified: &EcucModuleConfigurationValues) -> Vec<EcucDiffItem> {
+        let mut diff = Vec::new();
+        diff_container_lists(self.container_values(), modified.container_values(), "", &mut diff);
+        diff
+    }
+
+    /// apply the changes of a diff to this module configuration
+    ///
+    /// A change is applied if the affected value in `self` still has the old value recorded in the diff.
+    /// If it already has the new value, the change is skipped. Otherwise it was modified independently,
+    /// and the change is reported as a conflict without modifying `self`.
+    ///
+    /// A typical use is to carry local edits forward to a new delivery: compute the diff between the old and the
+    /// new delivery, and merge it into the locally edited configuration.
+    ///
+    /// The merge is not atomic: if an error occurs while the changes are applied, the changes that were applied
+    /// before the error are kept. Run the merge inside [`crate::AutosarModelAbstraction::transaction`] if the
+    /// configuration must remain unchanged in this case.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while applying the changes
+    pub fn merge(&self, diff: &[EcucDiffItem]) -> Result<EcucMergeReport, AutosarAbstractionError> {
+        let mut report = EcucMergeReport::default();
+        let mut actions = Vec::new();
+
+        // decide about all changes before modifying anything, since removals shift the positions of values
+        for item in diff {
+            match self.merge_action(item) {
+                MergeDecision::Apply(action) => {
+                    actions.push(action);
+                    report.applied.push(item.clone());
+                }
+                MergeDecision::Skip => {}
+                MergeDecision::Conflict => report.conflicts.push(item.clone()),
+            }
+        }
+
+        for action in actions {
+            match action {
+                MergeAction::Copy { parent, list, source } => {
+                    parent
+                        .get_or_create_sub_element(list)?
+                        .create_copied_sub_element(&source)?;
+                }
+                MergeAction::Remove(element) => {
+                    if let Some(parent) = element.parent()? {
+                        parent.remove_sub_element(element)?;
+                    }
+                }
+                MergeAction::Replace { target, source } => {
+                    if let Some(parent) = target.parent()? {
+                        let position = parent
+                            .sub_elements()
+                            .position(|elem| elem == target)
+                            .unwrap_or_default();
+                        parent.remove_sub_element(target)?;
+                        parent.create_copied_sub_element_at(&source, position)?;
+                    }
+                }
+                MergeAction::ReplaceContent { target, source } => {
+                    for source_sub in source.sub_elements() {
+                        let name = source_sub.element_name();
+                        if matches!(
+                            name,
+                            ElementName::Value | ElementName::ValueRef | ElementName::ValueIref
+                        ) {
+                            let _ = target.remove_sub_element_kind(name);
+                            target.create_copied_sub_element(&source_sub)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn merge_action(&self, item: &EcucDiffItem) -> MergeDecision {
+        match item {
+            EcucDiffItem::ContainerAdded { path, container } => {
+                let parent_path = path.rsplit_once('/').map_or("", |(parent_path, _)| parent_path);
+                let (parent, list) = if parent_path.is_empty() {
+                    (self.element().clone(), ElementName::Containers)
+                } else if let Some(parent) = self.container_by_relative_path(parent_path) {
+                    (parent.element().clone(), ElementName::SubContainers)
+                } else {
+                    return MergeDecision::Conflict;
+                };
+                match self.container_by_relative_path(path) {
+                    None => MergeDecision::Apply(MergeAction::Copy {
+                        parent,
+                        list,
+                        source: container.element().clone(),
+                    }),
+                    Some(existing) if containers_equal(&existing, container) => MergeDecision::Skip,
+                    Some(_) => MergeDecision::Conflict,
+                }
+            }
+            EcucDiffItem::ContainerRemoved { path, container } => match self.container_by_relative_path(path) {
+                None => MergeDecision::Skip,
+                Some(existing) if containers_equal(&existing, container) => {
+                    MergeDecision::Apply(MergeAction::Remove(existing.element().clone()))
+                }
+                Some(_) => MergeDecision::Conflict,
+            },
+            EcucDiffItem::ContainerReplaced { path, old, new } => match self.container_by_relative_path(path) {
+                Some(existing) if containers_equal(&existing, new) => MergeDecision::Skip,
+                Some(existing) if containers_equal(&existing, old) => MergeDecision::Apply(MergeAction::Replace {
+                    target: existing.element().clone(),
+                    source: new.element().clone(),
+                }),
+                _ => MergeDecision::Conflict,
+            },
+            EcucDiffItem::ParameterChanged {
+                container_path,
+                definition_ref,
+                position,
+                old,
+                new,
+            } => {
+                let Some(container) = self.container_by_relative_path(container_path) else {
+                    return if new.is_none() {
+                        MergeDecision::Skip
+                    } else {
+                        MergeDecision::Conflict
+                    };
+                };
+                let current = container
+                    .parameter_values()
+                    .filter(|param| param.definition_ref().as_deref() == Some(definition_ref))
+                    .nth(*position);
+                value_merge_decision(
+                    container.element(),
+                    ElementName::ParameterValues,
+                    current.as_ref(),
+                    old.as_ref(),
+                    new.as_ref(),
+                    parameters_equal,
+                )
+            }
+            EcucDiffItem::ReferenceChanged {
+                container_path,
+                definition_ref,
+                position,
+                old,
+                new,
+            } => {
+                let Some(container) = self.container_by_relative_path(container_path) else {
+                    return if new.is_none() {
+                        MergeDecision::Skip
+                    } else {
+                        MergeDecision::Conflict
+                    };
+                };
+                let current = container
+                    .reference_values()
+                    .filter(|reference| reference.definition_ref().as_deref() == Some(definition_ref))
+                    .nth(*position);
+                value_merge_decision(
+                    container.element(),
+                    ElementName::ReferenceValues,
+                    current.as_ref(),
+                    old.as_ref(),
+                    new.as_ref(),
+                    references_equal,
+                )
+            }
+        }
+    }
+
+    fn container_by_relative_path(&self, path: &str) -> Option<EcucContainerValue> {
+        let mut names = path.split('/');
+        let first = names.next()?;
+        let mut container = self
+            .container_values()
+            .find(|container| container.name().as_deref() == Some(first))?;
+        for name in names {
+            container = container
+                .sub_containers()
+                .find(|sub_container| sub_container.name().as_deref() == Some(name))?;
+        }
+        Some(container)
+    }
+}
+
+//#########################################################
+
+enum MergeDecision {
+    Apply(MergeAction),
+    Skip,
+    Conflict,
+}
+
+enum MergeAction {
+    Copy {
+        parent: Element,
+        list: ElementName,
+        source: Element,
+    },
+    Remove(Element),
+    Replace {
+        target: Element,
+        source: Element,
+    },
+    ReplaceContent {
+        target: Element,
+        source: Element,
+    },
+}
+
+fn value_merge_decision<T: AbstractionElement>(
+    container_elem: &Element,
+    list: ElementName,
+    current: Option<&T>,
+    old: Option<&T>,
+    new: Option<&T>,
+    equal: fn(&T, &T) -> bool,
+) -> MergeDecision {
+    let same = |a: Option<&T>, b: Option<&T>| match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => equal(a, b),
+        _ => false,
+    };
+
+    if same(current, new) {
+        MergeDecision::Skip
+    } else if !same(current, old) {
+        MergeDecision::Conflict
+    } else {
+        match (current, new) {
+            (None, Some(new)) => MergeDecision::Apply(MergeAction::Copy {
+                parent: container_elem.clone(),
+                list,
+                source: new.element().clone(),
+            }),
+            (Some(current), None) => MergeDecision::Apply(MergeAction::Remove(current.element().clone())),
+            (Some(current), Some(new)) => MergeDecision::Apply(MergeAction::ReplaceContent {
+                target: current.element().clone(),
+                source: new.element().clone(),
+            }),
+            (None, None) => MergeDecision::Skip,
+        }
+    }
+}
+
+fn join_container_path(parent_path: &str, name: &str) -> String {
+    if parent_path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{parent_path}/{name}")
+    }
+}
+
+fn containers_equal(a: &EcucContainerValue, b: &EcucContainerValue) -> bool {
+    let mut diff = Vec::new();
+    diff_container_content(a, b, "", &mut diff);
+    diff.is_empty() && a.definition_ref() == b.definition_ref()
+}
+
+fn parameters_equal(a: &EcucParameterValue, b: &EcucParameterValue) -> bool {
+    // AddInfo values have no simple value, and are considered equal if they have the same definition
+    a.definition_ref() == b.definition_ref() && a.value() == b.value()
+}
+
+fn references_equal(a: &EcucAnyReferenceValue, b: &EcucAnyReferenceValue) -> bool {
+    a.definition_ref() == b.definition_ref() && reference_target_paths(a) == reference_target_paths(b)
+}
+
+// the target of a reference is compared by its path, since the target may not be loaded
+fn reference_target_paths(reference: &EcucAnyReferenceValue) -> Vec<String> {
+    match reference {
+        EcucAnyReferenceValue::Reference(reference) => reference
+            .element()
+            .get_sub_element(ElementName::ValueRef)
+            .and_then(|value_ref| value_ref.character_data())
+            .and_then(|cdata| cdata.string_value())
+            .into_iter()
+            .collect(),
+        EcucAnyReferenceValue::Instance(instance) => instance
+            .element()
+            .get_sub_element(ElementName::ValueIref)
+            .into_iter()
+            .flat_map(|value_iref| value_iref.sub_elements())
+            .filter_map(|ref_elem| ref_elem.character_data()?.string_value())
+            .collect(),
+    }
+}
+
+fn diff_container_lists(
+    base: impl Iterator<Item = EcucContainerValue>,
+    modified: impl Iterator<Item = EcucContainerValue>,
+    parent_path: &str,
+    diff: &mut Vec<EcucDiffItem>,
+) {
+    let base: Vec<_> = base.collect();
+    let modified: Vec<_> = modified.collect();
+    let base_by_name: HashMap<String, &EcucContainerValue> = base
+        .iter()
+        .filter_map(|container| Some((container.name()?, container)))
+        .collect();
+    let modified_by_name: HashMap<String, &EcucContainerValue> = modified
+        .iter()
+        .filter_map(|container| Some((container.name()?, container)))
+        .collect();
+
+    for base_container in &base {
+        let Some(name) = base_container.name() else { continue };
+        let path = join_container_path(parent_path, &name);
+        match modified_by_name.get(&name) {
+            Some(modified_container) if modified_container.definition_ref() == base_container.definition_ref() => {
+                diff_container_content(base_container, modified_container, &path, diff);
+            }
+            // a container with a different definition can't be merged value by value
+            Some(modified_container) => diff.push(EcucDiffItem::ContainerReplaced {
+                path,
+                old: base_container.clone(),
+                new: (*modified_container).clone(),
+            }),
+            None => diff.push(EcucDiffItem::ContainerRemoved {
+                path,
+                container: base_container.clone(),
+            }),
+        }
+    }
+
+    for modified_container in &modified {
+        let Some(name) = modified_container.name() else {
+            continue;
+        };
+        if !base_by_name.contains_key(&name) {
+            diff.push(EcucDiffItem::ContainerAdded {
+                path: join_container_path(parent_path, &name),
+                container: modified_container.clone(),
+            });
+        }
+    }
+}
+
+fn diff_container_content(
+    base: &EcucContainerValue,
+    modified: &EcucContainerValue,
+    path: &str,
+    diff: &mut Vec<EcucDiffItem>,
+) {
+    for (definition_ref, position, old, new) in pair_by_definition(
+        base.parameter_values(),
+        modified.parameter_values(),
+        EcucParameterValue::definition_ref,
+    ) {
+        let unchanged = match (&old, &new) {
+            (Some(old), Some(new)) => parameters_equal(old, new),
+            _ => false,
+        };
+        if !unchanged {
+            diff.push(EcucDiffItem::ParameterChanged {
+                container_path: path.to_string(),
+                definition_ref,
+                position,
+                old,
+                new,
+            });
+        }
+    }
+
+    for (definition_ref, position, old, new) in pair_by_definition(
+        base.reference_values(),
+        modified.reference_values(),
+        EcucAnyReferenceValue::definition_ref,
+    ) {
+        let unchanged = match (&old, &new) {
+            (Some(old), Some(new)) => references_equal(old, new),
+            _ => false,
+        };
+        if !unchanged {
+            diff.push(EcucDiffItem::ReferenceChanged {
+                container_path: path.to_string(),
+                definition_ref,
+                position,
+                old,
+                new,
+            });
+        }
+    }
+
+    diff_container_lists(base.sub_containers(), modified.sub_containers(), path, diff);
+}
+
+// group the values of both sides by their definition ref, and pair them up by their position within each group
+fn pair_by_definition<T>(
+    base: impl Iterator<Item = T>,
+    modified: impl Iterator<Item = T>,
+    definition_ref: fn(&T) -> Option<String>,
+) -> Vec<(String, usize, Option<T>, Option<T>)> {
+    let mut definitions: Vec<String> = Vec::new();
+    let mut base_groups: HashMap<String, Vec<T>> = HashMap::new();
+    let mut modified_groups: HashMap<String, Vec<T>> = HashMap::new();
+    for (values, groups) in [
+        (base.collect::<Vec<_>>(), &mut base_groups),
+        (modified.collect(), &mut modified_groups),
+    ] {
+        for value in values {
+            let Some(def_ref) = definition_ref(&value) else {
+                continue;
+            };
+            if !definitions.contains(&def_ref) {
+                definitions.push(def_ref.clone());
+            }
+            groups.entry(def_ref).or_default().push(value);
+        }
+    }
+
+    let mut pairs = Vec::new();
+    for def_ref in definitions {
+        let mut base_values = base_groups.remove(&def_ref).unwrap_or_default().into_iter();
+        let mut modified_values = modified_groups.remove(&def_ref).unwrap_or_default().into_iter();
+        let mut position = 0;
+        loop {
+            let (old, new) = (base_values.next(), modified_values.next());
+            if old.is_none() && new.is_none() {
+                break;
+            }
+            pairs.push((def_ref.clone(), position, old, new));
+            position += 1;
+        }
+    }
+    pairs
+}
+
+//#########################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::AutosarModelAbstraction;
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn diff_and_merge() {
+        let def_model = AutosarModelAbstraction::create("definition.arxml", AutosarVersion::LATEST);
+        let def_package = def_model.get_or_create_package("/def").unwrap();
+        let module_def = def_package.create_ecuc_module_def("ModuleDef").unwrap();
+        let general_def = module_def.create_param_conf_container_def("General").unwrap();
+        let channel_def = module_def.create_param_conf_container_def("Channel").unwrap();
+        let int_def = general_def.create_integer_param_def("IntParam", "origin").unwrap();
+        let string_def = general_def.create_string_param_def("StringParam", "origin").unwrap();
+        let ref_def = general_def.create_foreign_reference_def("Ref", "origin").unwrap();
+
+        // the vendor delivery
+        let model = AutosarModelAbstraction::create("values.arxml", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/values").unwrap();
+        let target_a = package.create_ecuc_value_collection("TargetA").unwrap();
+        let target_b = package.create_ecuc_value_collection("TargetB").unwrap();
+
+        let create_delivery = |name: &str| {
+            let module = package
+                .create_ecuc_module_configuration_values(name, &module_def)
+                .unwrap();
+            let general = module.create_container_value("General", &general_def).unwrap();
+            general.create_numerical_param_value(&int_def, "1").unwrap();
+            general.create_numerical_param_value(&int_def, "2").unwrap();
+            general.create_textual_param_value(&string_def, "text").unwrap();
+            general.create_reference_value(&ref_def, target_a.element()).unwrap();
+            module.create_container_value("Channel0", &channel_def).unwrap();
+            module
+        };
+
+        let base = create_delivery("Base");
+        assert!(base.diff(&base).is_empty());
+        let update = create_delivery("Update");
+        let local = create_delivery("Local");
+        assert!(base.diff(&update).is_empty());
+
+        // vendor changes
+        let update_general = update.container_values_by_definition("General").next().unwrap();
+        let update_int = update_general.parameter_values().nth(1).unwrap();
+        let EcucParameterValue::Numerical(update_int) = update_int else {
+            panic!("expected a numerical value");
+        };
+        update_int.set_value_int(20).unwrap();
+        let update_string = update_general.parameter_value_by_definition("StringParam").unwrap();
+        let EcucParameterValue::Textual(update_string) = update_string else {
+            panic!("expected a textual value");
+        };
+        update_string.set_value("vendor").unwrap();
+        let update_ref = update_general.reference_values().next().unwrap();
+        let EcucAnyReferenceValue::Reference(update_ref) = update_ref else {
+            panic!("expected a reference value");
+        };
+        update_ref.set_target(target_b.element()).unwrap();
+        update.create_container_value("Channel1", &channel_def).unwrap();
+        let update_channel0 = update
+            .container_values()
+            .find(|c| c.name().as_deref() == Some("Channel0"));
+        update_channel0
+            .unwrap()
+            .create_sub_container("Sub", &channel_def)
+            .unwrap();
+
+        let diff = base.diff(&update);
+        assert_eq!(diff.len(), 5);
+        assert!(diff.contains(&EcucDiffItem::ContainerAdded {
+            path: "Channel1".to_string(),
+            container: update.container_values().nth(2).unwrap(),
+        }));
+        assert!(diff.iter().any(|item| matches!(item,
+            EcucDiffItem::ContainerAdded { path, .. } if path == "Channel0/Sub")));
+        assert!(diff.iter().any(|item| matches!(
+            item,
+            EcucDiffItem::ParameterChanged {
+                position: 1,
+                old: Some(_),
+                new: Some(_),
+                ..
+            }
+        )));
+        assert!(
+            diff.iter()
+                .any(|item| matches!(item, EcucDiffItem::ReferenceChanged { .. }))
+        );
+
+        // local edit which conflicts with the vendor change of the string parameter
+        let local_general = local.container_values_by_definition("General").next().unwrap();
+        let local_string = local_general.parameter_value_by_definition("StringParam").unwrap();
+        let EcucParameterValue::Textual(local_string) = local_string else {
+            panic!("expected a textual value");
+        };
+        local_string.set_value("local").unwrap();
+
+        let report = local.merge(&diff).unwrap();
+        assert_eq!(report.applied.len(), 4);
+        assert_eq!(report.conflicts.len(), 1);
+        assert!(matches!(
+            &report.conflicts[0],
+            EcucDiffItem::ParameterChanged { definition_ref, .. } if *definition_ref == string_def.element().path().unwrap()
+        ));
+
+        // the non-conflicting vendor changes were applied, the local edit was kept
+        let local_general = local.container_values_by_definition("General").next().unwrap();
+        let int_values: Vec<_> = local_general
+            .parameter_values()
+            .filter_map(|param| param.value_int())
+            .collect();
+        assert_eq!(int_values, vec![1, 20]);
+        assert_eq!(
+            local_general
+                .parameter_value_by_definition("StringParam")
+                .unwrap()
+                .value(),
+            Some("local".to_string())
+        );
+        let local_ref = local_general.reference_values().next().unwrap();
+        assert_eq!(local_ref.target_as::<Element>(), Some(target_b.element().clone()));
+        assert_eq!(local.container_values_by_definition("Channel").count(), 2);
+
+        // merging again doesn't change anything, only the conflict remains
+        let report = local.merge(&diff).unwrap();
+        assert!(report.applied.is_empty());
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(local.diff(&update).len(), 1);
+
+        // removals are applied if the removed element was not modified locally
+        let diff = update.diff(&base);
+        let report = local.merge(&diff).unwrap();
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(local.container_values_by_definition("Channel").count(), 1);
+        assert_eq!(
+            local_general
+                .parameter_values()
+                .filter_map(|p| p.value_int())
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn merge_changed_definition() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let module_def = package.create_ecuc_module_def("ModuleDef").unwrap();
+        let old_def = module_def.create_param_conf_container_def("OldDef").unwrap();
+        let new_def = module_def.create_param_conf_container_def("NewDef").unwrap();
+        let int_def = new_def.create_integer_param_def("IntParam", "origin").unwrap();
+
+        let create_delivery = |name: &str| {
+            let module = package
+                .create_ecuc_module_configuration_values(name, &module_def)
+                .unwrap();
+            module.create_container_value("Container", &old_def).unwrap();
+            module
+        };
+        let base = create_delivery("Base");
+        let local = create_delivery("Local");
+        let update = package
+            .create_ecuc_module_configuration_values("Update", &module_def)
+            .unwrap();
+        let update_container = update.create_container_value("Container", &new_def).unwrap();
+        update_container.create_numerical_param_value(&int_def, "5").unwrap();
+
+        // the changed definition is reported as a single replacement
+        let diff = base.diff(&update);
+        assert_eq!(diff.len(), 1);
+        assert!(matches!(&diff[0], EcucDiffItem::ContainerReplaced { path, .. } if path == "Container"));
+
+        // the container is replaced instead of being removed without a replacement
+        let report = local.merge(&diff).unwrap();
+        assert_eq!(report.applied.len(), 1);
+        assert!(report.conflicts.is_empty());
+        assert_eq!(local.container_values().count(), 1);
+        let container = local.container_values().next().unwrap();
+        assert_eq!(container.name().as_deref(), Some("Container"));
+        assert_eq!(container.definition_ref(), update_container.definition_ref());
+        assert_eq!(
+            container
+                .parameter_values()
+                .filter_map(|param| param.value_int())
+                .next(),
+            Some(5)
+        );
+        assert!(local.diff(&update).is_empty());
+
+        // merging again doesn't change anything
+        let report = local.merge(&diff).unwrap();
+        assert!(report.applied.is_empty());
+        assert!(report.conflicts.is_empty());
+    }
+}
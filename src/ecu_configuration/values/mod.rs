@@ -2,15 +2,17 @@ use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, IdentifiableAbstractionElement, System,
     abstraction_element,
     ecu_configuration::{
-        AbstractEcucContainerDef, AbstractEcucReferenceDef, EcucAddInfoParamDef, EcucContainerDef,
-        EcucInstanceReferenceDef, EcucModuleDef,
+        AbstractEcucContainerDef, AbstractEcucReferenceDef, EcucAddInfoParamDef, EcucContainerDef, EcucDefinition,
+        EcucDefinitionLookup, EcucInstanceReferenceDef, EcucModuleDef, EcucParameterDef,
     },
 };
 use autosar_data::{Element, ElementName};
 
+mod diff;
 mod parameter;
 mod reference;
 
+pub use diff::*;
 pub use parameter::*;
 pub use reference::*;
 
@@ -149,6 +151,51 @@ impl EcucModuleConfigurationValues {
         EcucContainerValue::new(name, &containers_elem, definition)
     }
 
+    /// Create a new `EcucContainerValue` in the module configuration, using the container definition with the given path
+    ///
+    /// The definition is taken from the [`EcucDefinitionLookup`], and the DEFINITION-REF of the new container is set to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::ecu_configuration::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/Vendor")?;
+    /// # let module_def = package.create_ecuc_module_def("Com")?;
+    /// # module_def.create_param_conf_container_def("ComGeneral")?;
+    /// let lookup = EcucDefinitionLookup::from_model(&model);
+    /// let module_config = package.create_ecuc_module_configuration_values("ComConfig", &module_def)?;
+    /// let general = module_config.create_container_by_def_path("ComGeneral", "/Vendor/Com/ComGeneral", &lookup)?;
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the definition is not known, is not a container definition,
+    ///   or is not a top-level container of the module definition of this configuration
+    /// - [`AutosarAbstractionError::ItemAlreadyExists`] the configuration already contains as many containers
+    ///   with this definition as allowed by its upper multiplicity
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the container
+    pub fn create_container_by_def_path(
+        &self,
+        name: &str,
+        def_path: &str,
+        lookup: &EcucDefinitionLookup,
+    ) -> Result<EcucContainerValue, AutosarAbstractionError> {
+        let existing_definition_refs = self.container_values().map(|container| container.definition_ref());
+        let info =
+            lookup.checked_child_definition(self.definition_ref().as_deref(), def_path, existing_definition_refs)?;
+        let EcucDefinition::Container(container_def) = &info.definition else {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "{def_path} is not a container definition"
+            )));
+        };
+        self.create_container_value(name, container_def)
+    }
+
     /// create an iterator over the container values in the module configuration
     pub fn container_values(&self) -> impl Iterator<Item = EcucContainerValue> + Send + use<> {
         self.element()
@@ -157,6 +204,18 @@ impl EcucModuleConfigurationValues {
             .flat_map(|containers_elem| containers_elem.sub_elements())
             .filter_map(|container_elem| EcucContainerValue::try_from(container_elem).ok())
     }
+
+    /// iterate over the container values whose definition has the given short name
+    ///
+    /// Only the last element of the `DEFINITION-REF` is compared, so this works even if the definition is not loaded.
+    pub fn container_values_by_definition(
+        &self,
+        definition_name: &str,
+    ) -> impl Iterator<Item = EcucContainerValue> + Send + use<> {
+        let definition_name = definition_name.to_string();
+        self.container_values()
+            .filter(move |container| definition_name_matches(container.definition_ref(), &definition_name))
+    }
 }
 
 //#########################################################
@@ -226,6 +285,34 @@ impl EcucContainerValue {
         EcucContainerValue::new(name, &sub_containers_elem, definition)
     }
 
+    /// create a sub-container, using the container definition with the given path
+    ///
+    /// The definition is taken from the [`EcucDefinitionLookup`], and the DEFINITION-REF of the new container is set to it.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the definition is not known, is not a container definition,
+    ///   or is not a direct sub-container (or choice) of the definition of this container
+    /// - [`AutosarAbstractionError::ItemAlreadyExists`] the container already contains as many sub-containers
+    ///   with this definition as allowed by its upper multiplicity
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the container
+    pub fn create_container_by_def_path(
+        &self,
+        name: &str,
+        def_path: &str,
+        lookup: &EcucDefinitionLookup,
+    ) -> Result<EcucContainerValue, AutosarAbstractionError> {
+        let existing_definition_refs = self.sub_containers().map(|container| container.definition_ref());
+        let info =
+            lookup.checked_child_definition(self.definition_ref().as_deref(), def_path, existing_definition_refs)?;
+        let EcucDefinition::Container(container_def) = &info.definition else {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "{def_path} is not a container definition"
+            )));
+        };
+        self.create_sub_container(name, container_def)
+    }
+
     /// iterate over the sub-containers in this container
     pub fn sub_containers(&self) -> impl Iterator<Item = EcucContainerValue> + Send + use<> {
         self.element()
@@ -235,6 +322,18 @@ impl EcucContainerValue {
             .filter_map(|elem| EcucContainerValue::try_from(elem).ok())
     }
 
+    /// iterate over the sub-containers whose definition has the given short name
+    ///
+    /// Only the last element of the `DEFINITION-REF` is compared, so this works even if the definition is not loaded.
+    pub fn sub_containers_by_definition(
+        &self,
+        definition_name: &str,
+    ) -> impl Iterator<Item = EcucContainerValue> + Send + use<> {
+        let definition_name = definition_name.to_string();
+        self.sub_containers()
+            .filter(move |container| definition_name_matches(container.definition_ref(), &definition_name))
+    }
+
     /// set the index of the container
     ///
     /// If the container definition has `requiresIndex` set to `true`, then the container
@@ -291,6 +390,51 @@ impl EcucContainerValue {
         EcucAddInfoParamValue::new(&parameter_values_elem, definition)
     }
 
+    /// create a parameter value, using the parameter definition with the given path
+    ///
+    /// The definition is taken from the [`EcucDefinitionLookup`]. Boolean, integer and float parameters are created
+    /// as `EcucNumericalParamValue`, all other parameters are created as `EcucTextualParamValue`.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the definition is not known, is not a parameter of the definition
+    ///   of this container, or is an add-info parameter. Use [`Self::create_add_info_param_value`] for these.
+    /// - [`AutosarAbstractionError::ItemAlreadyExists`] the container already contains as many values for
+    ///   this parameter as allowed by its upper multiplicity
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the value
+    pub fn create_param_value_by_def_path(
+        &self,
+        def_path: &str,
+        value: &str,
+        lookup: &EcucDefinitionLookup,
+    ) -> Result<EcucParameterValue, AutosarAbstractionError> {
+        let existing_definition_refs = self.parameter_values().map(|param| {
+            param
+                .element()
+                .get_sub_element(ElementName::DefinitionRef)
+                .and_then(|def_ref| def_ref.character_data())
+                .and_then(|cdata| cdata.string_value())
+        });
+        let info =
+            lookup.checked_child_definition(self.definition_ref().as_deref(), def_path, existing_definition_refs)?;
+        match &info.definition {
+            EcucDefinition::Parameter(EcucParameterDef::AddInfo(_)) => Err(AutosarAbstractionError::InvalidParameter(
+                format!("{def_path} is an add-info parameter, which does not have a value"),
+            )),
+            EcucDefinition::Parameter(
+                param_def @ (EcucParameterDef::Boolean(_) | EcucParameterDef::Integer(_) | EcucParameterDef::Float(_)),
+            ) => Ok(EcucParameterValue::Numerical(
+                self.create_numerical_param_value(param_def, value)?,
+            )),
+            EcucDefinition::Parameter(param_def) => Ok(EcucParameterValue::Textual(
+                self.create_textual_param_value(param_def, value)?,
+            )),
+            _ => Err(AutosarAbstractionError::InvalidParameter(format!(
+                "{def_path} is not a parameter definition"
+            ))),
+        }
+    }
+
     /// iterate over the parameter values in the container
     pub fn parameter_values(&self) -> impl Iterator<Item = EcucParameterValue> + Send + use<> {
         self.element()
@@ -300,6 +444,15 @@ impl EcucContainerValue {
             .filter_map(|param_elem| EcucParameterValue::try_from(param_elem).ok())
     }
 
+    /// get the first parameter value whose definition has the given short name
+    ///
+    /// Only the last element of the `DEFINITION-REF` is compared, so this works even if the definition is not loaded.
+    #[must_use]
+    pub fn parameter_value_by_definition(&self, definition_name: &str) -> Option<EcucParameterValue> {
+        self.parameter_values()
+            .find(|param| definition_name_matches(param.definition_ref(), definition_name))
+    }
+
     /// create a new instance reference value in the container
     pub fn create_instance_reference(
         &self,
@@ -329,13 +482,33 @@ impl EcucContainerValue {
             .flat_map(|reference_values_elem| reference_values_elem.sub_elements())
             .filter_map(|reference_elem| EcucAnyReferenceValue::try_from(reference_elem).ok())
     }
+
+    /// iterate over the reference values whose definition has the given short name
+    ///
+    /// Only the last element of the `DEFINITION-REF` is compared, so this works even if the definition is not loaded.
+    pub fn reference_values_by_definition(
+        &self,
+        definition_name: &str,
+    ) -> impl Iterator<Item = EcucAnyReferenceValue> + Send + use<> {
+        let definition_name = definition_name.to_string();
+        self.reference_values()
+            .filter(move |reference| definition_name_matches(reference.definition_ref(), &definition_name))
+    }
+}
+
+//#########################################################
+
+// compare the last element of a definition ref with the given short name
+fn definition_name_matches(definition_ref: Option<String>, definition_name: &str) -> bool {
+    definition_ref.is_some_and(|def_ref| def_ref.rsplit('/').next() == Some(definition_name))
 }
 
 //#########################################################
 
 #[cfg(test)]
 mod test {
-    use crate::{AbstractionElement, AutosarModelAbstraction, system};
+    use super::EcucContainerValue;
+    use crate::{AbstractionElement, AutosarModelAbstraction, System, system};
     use autosar_data::{AutosarVersion, ElementName};
 
     #[test]
@@ -400,4 +573,77 @@ mod test {
             .unwrap();
         // get the definitions from the value model
     }
+
+    #[test]
+    fn typed_value_access() {
+        let model = AutosarModelAbstraction::create("values.arxml", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+
+        let module_def = package.create_ecuc_module_def("ModuleDef").unwrap();
+        let container_def = module_def.create_param_conf_container_def("ContainerDef").unwrap();
+        let other_container_def = module_def.create_param_conf_container_def("OtherDef").unwrap();
+        let int_def = container_def.create_integer_param_def("IntParam", "origin").unwrap();
+        let float_def = container_def.create_float_param_def("FloatParam", "origin").unwrap();
+        let bool_def = container_def.create_boolean_param_def("BoolParam", "origin").unwrap();
+        let string_def = container_def.create_string_param_def("StringParam", "origin").unwrap();
+        let reference_def = container_def
+            .create_foreign_reference_def("SystemRef", "origin")
+            .unwrap();
+
+        let module_values = package
+            .create_ecuc_module_configuration_values("Module", &module_def)
+            .unwrap();
+        let container = module_values
+            .create_container_value("Container", &container_def)
+            .unwrap();
+        module_values
+            .create_container_value("Other", &other_container_def)
+            .unwrap();
+        let sub_container = container.create_sub_container("Sub1", &container_def).unwrap();
+        container.create_sub_container("Sub2", &other_container_def).unwrap();
+
+        // filter containers by definition name
+        let containers: Vec<_> = module_values.container_values_by_definition("ContainerDef").collect();
+        assert_eq!(containers, vec![container.clone()]);
+        assert_eq!(module_values.container_values_by_definition("Missing").count(), 0);
+        let sub_containers: Vec<_> = container.sub_containers_by_definition("ContainerDef").collect();
+        assert_eq!(sub_containers, vec![sub_container]);
+
+        // typed setters and getters
+        let int_value = container.create_numerical_param_value(&int_def, "0").unwrap();
+        int_value.set_value_int(42).unwrap();
+        let float_value = container.create_numerical_param_value(&float_def, "0").unwrap();
+        float_value.set_value_float(1.5).unwrap();
+        let bool_value = container.create_numerical_param_value(&bool_def, "0").unwrap();
+        bool_value.set_value_bool(true).unwrap();
+        container.create_textual_param_value(&string_def, "text").unwrap();
+
+        let param = container.parameter_value_by_definition("IntParam").unwrap();
+        assert_eq!(param.value_int(), Some(42));
+        assert_eq!(param.value_text(), None);
+        let param = container.parameter_value_by_definition("FloatParam").unwrap();
+        assert_eq!(param.value_float(), Some(1.5));
+        let param = container.parameter_value_by_definition("BoolParam").unwrap();
+        assert_eq!(param.value_bool(), Some(true));
+        let param = container.parameter_value_by_definition("StringParam").unwrap();
+        assert_eq!(param.value_text(), Some("text".to_string()));
+        assert_eq!(param.value(), Some("text".to_string()));
+        assert_eq!(param.value_int(), None);
+        assert_eq!(param.definition_ref(), string_def.element().path().ok());
+        assert!(container.parameter_value_by_definition("Missing").is_none());
+
+        // resolve reference targets to abstraction types
+        let system = package
+            .create_system("System", system::SystemCategory::EcuExtract)
+            .unwrap();
+        let reference = container
+            .create_reference_value(&reference_def, system.element())
+            .unwrap();
+        assert_eq!(reference.target_as::<System>(), Some(system.clone()));
+        assert_eq!(reference.target_as::<EcucContainerValue>(), None);
+        let any_reference = container.reference_values_by_definition("SystemRef").next().unwrap();
+        assert_eq!(any_reference.target_as::<System>(), Some(system));
+        assert_eq!(any_reference.definition_ref(), reference_def.element().path().ok());
+        assert_eq!(container.reference_values_by_definition("IntParam").count(), 0);
+    }
 }
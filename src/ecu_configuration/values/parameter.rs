@@ -102,6 +102,21 @@ impl EcucNumericalParamValue {
             .string_value()
     }
 
+    /// set the numerical value from a boolean
+    pub fn set_value_bool(&self, value: bool) -> Result<(), AutosarAbstractionError> {
+        self.set_value(&value.to_string())
+    }
+
+    /// set the numerical value from an integer
+    pub fn set_value_int(&self, value: i64) -> Result<(), AutosarAbstractionError> {
+        self.set_value(&value.to_string())
+    }
+
+    /// set the numerical value from a float
+    pub fn set_value_float(&self, value: f64) -> Result<(), AutosarAbstractionError> {
+        self.set_value(&value.to_string())
+    }
+
     /// get the numerical value as a boolean
     #[must_use]
     pub fn value_bool(&self) -> Option<bool> {
@@ -357,6 +372,69 @@ impl TryFrom<Element> for EcucParameterValue {
 
 impl IdentifiableAbstractionElement for EcucParameterValue {}
 
+impl EcucParameterValue {
+    /// get the parameter definition reference as a string
+    #[must_use]
+    pub fn definition_ref(&self) -> Option<String> {
+        let definition_ref_elem = self.element().get_sub_element(ElementName::DefinitionRef)?;
+        definition_ref_elem.character_data()?.string_value()
+    }
+
+    /// get the value of a numerical or textual parameter as a string
+    ///
+    /// `EcucAddInfoParamValue` has no simple value, so `None` is returned for it.
+    #[must_use]
+    pub fn value(&self) -> Option<String> {
+        match self {
+            EcucParameterValue::AddInfo(_) => None,
+            EcucParameterValue::Numerical(numerical) => numerical.value(),
+            EcucParameterValue::Textual(textual) => textual.value(),
+        }
+    }
+
+    /// get the value of a numerical parameter as a boolean
+    ///
+    /// Returns `None` if the parameter is not numerical or the value can't be parsed as a boolean.
+    #[must_use]
+    pub fn value_bool(&self) -> Option<bool> {
+        self.numerical()?.value_bool()
+    }
+
+    /// get the value of a numerical parameter as an integer
+    ///
+    /// Returns `None` if the parameter is not numerical or the value can't be parsed as an integer.
+    #[must_use]
+    pub fn value_int(&self) -> Option<i64> {
+        self.numerical()?.value_int()
+    }
+
+    /// get the value of a numerical parameter as a float
+    ///
+    /// Returns `None` if the parameter is not numerical or the value can't be parsed as a float.
+    #[must_use]
+    pub fn value_float(&self) -> Option<f64> {
+        self.numerical()?.value_float()
+    }
+
+    /// get the value of a textual parameter
+    ///
+    /// Returns `None` if the parameter is not textual.
+    #[must_use]
+    pub fn value_text(&self) -> Option<String> {
+        match self {
+            EcucParameterValue::Textual(textual) => textual.value(),
+            _ => None,
+        }
+    }
+
+    fn numerical(&self) -> Option<&EcucNumericalParamValue> {
+        match self {
+            EcucParameterValue::Numerical(numerical) => Some(numerical),
+            _ => None,
+        }
+    }
+}
+
 //#########################################################
 
 #[cfg(test)]
@@ -235,6 +235,14 @@ impl EcucReferenceValue {
             .ok()
     }
 
+    /// Get the target of the reference, converted to an abstraction type
+    ///
+    /// Returns `None` if the target can't be resolved or has a different type.
+    #[must_use]
+    pub fn target_as<T: TryFrom<Element>>(&self) -> Option<T> {
+        T::try_from(self.target()?).ok()
+    }
+
     /// set the index of the reference
     ///
     /// If the reference definition has `requiresIndex` set to `true`, then the reference
@@ -328,6 +336,29 @@ impl AbstractionElement for EcucAnyReferenceValue {
 
 impl IdentifiableAbstractionElement for EcucAnyReferenceValue {}
 
+impl EcucAnyReferenceValue {
+    /// get the reference definition ref as a string
+    #[must_use]
+    pub fn definition_ref(&self) -> Option<String> {
+        match self {
+            EcucAnyReferenceValue::Instance(instance) => instance.definition_ref(),
+            EcucAnyReferenceValue::Reference(reference) => reference.definition_ref(),
+        }
+    }
+
+    /// get the target of the reference, converted to an abstraction type
+    ///
+    /// For instance references only the final target is considered; the context is ignored.
+    #[must_use]
+    pub fn target_as<T: TryFrom<Element>>(&self) -> Option<T> {
+        let target = match self {
+            EcucAnyReferenceValue::Instance(instance) => instance.target()?.1,
+            EcucAnyReferenceValue::Reference(reference) => reference.target()?,
+        };
+        T::try_from(target).ok()
+    }
+}
+
 //#########################################################
 
 #[cfg(test)]
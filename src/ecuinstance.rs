@@ -1,6 +1,7 @@
 use crate::communication::{
     CanCommunicationController, CanTpEcu, CommunicationController, EthernetCommunicationController,
-    FlexrayCommunicationController, FlexrayTpEcu, ISignalIPduGroup, LinMaster, LinSlave, NmEcu,
+    FlexrayCommunicationController, FlexrayTpEcu, FramePort, IPduPort, ISignalIPduGroup, ISignalPort, LinMaster,
+    LinSlave, NmEcu, PhysicalChannel,
 };
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
@@ -293,12 +294,219 @@ impl EcuInstance {
                     .and_then(|elem| ISignalIPduGroup::try_from(elem).ok())
             })
     }
+
+    /// set or remove the information whether the ECU supports a sleep mode
+    pub fn set_sleep_mode_supported(&self, supported: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        if let Some(supported) = supported {
+            self.0
+                .get_or_create_sub_element(ElementName::SleepModeSupported)?
+                .set_character_data(supported)?;
+        } else {
+            let _ = self.0.remove_sub_element_kind(ElementName::SleepModeSupported);
+        }
+        Ok(())
+    }
+
+    /// get the information whether the ECU supports a sleep mode
+    #[must_use]
+    pub fn sleep_mode_supported(&self) -> Option<bool> {
+        self.0
+            .get_sub_element(ElementName::SleepModeSupported)?
+            .character_data()?
+            .parse_bool()
+    }
+
+    /// set or remove the information whether the ECU can be woken up over the bus
+    pub fn set_wake_up_over_bus_supported(&self, supported: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        if let Some(supported) = supported {
+            self.0
+                .get_or_create_sub_element(ElementName::WakeUpOverBusSupported)?
+                .set_character_data(supported)?;
+        } else {
+            let _ = self.0.remove_sub_element_kind(ElementName::WakeUpOverBusSupported);
+        }
+        Ok(())
+    }
+
+    /// get the information whether the ECU can be woken up over the bus
+    #[must_use]
+    pub fn wake_up_over_bus_supported(&self) -> Option<bool> {
+        self.0
+            .get_sub_element(ElementName::WakeUpOverBusSupported)?
+            .character_data()?
+            .parse_bool()
+    }
+
+    /// create a new `EcuPartition` in the ECU
+    ///
+    /// Partitions are used to describe multi-core and safety-partitioned ECUs.
+    /// Software components can be mapped to a partition with [`SwcToEcuMapping::set_partition`](crate::SwcToEcuMapping::set_partition)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let ecu_instance = system.create_ecu_instance("ecu_name", &package)?;
+    /// let partition = ecu_instance.create_partition("Partition")?;
+    /// # assert_eq!(ecu_instance.partitions().count(), 1);
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the ECU-PARTITION
+    pub fn create_partition(&self, name: &str) -> Result<EcuPartition, AutosarAbstractionError> {
+        let partitions = self.0.get_or_create_sub_element(ElementName::Partitions)?;
+        EcuPartition::new(name, &partitions)
+    }
+
+    /// Return an iterator over all partitions of the ECU
+    pub fn partitions(&self) -> impl Iterator<Item = EcuPartition> + Send + use<> {
+        self.0
+            .get_sub_element(ElementName::Partitions)
+            .into_iter()
+            .flat_map(|partitions| partitions.sub_elements())
+            .filter_map(|elem| EcuPartition::try_from(elem).ok())
+    }
+
+    /// get a summary of the communication of the ECU
+    ///
+    /// The summary contains all physical channels that the ECU is connected to, as well as
+    /// all frame ports, PDU ports and signal ports of the ECU.
+    /// The communication direction of each port is available via its `communication_direction()` method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, communication::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # let cluster = system.create_can_cluster("Cluster", &package, None)?;
+    /// # let channel = cluster.create_physical_channel("Channel")?;
+    /// let ecu_instance = system.create_ecu_instance("ecu_name", &package)?;
+    /// let controller = ecu_instance.create_can_communication_controller("CanCtrl")?;
+    /// controller.connect_physical_channel("connection", &channel)?;
+    /// let summary = ecu_instance.communication_summary();
+    /// assert_eq!(summary.channels, vec![PhysicalChannel::Can(channel)]);
+    /// # Ok(())}
+    /// ```
+    #[must_use]
+    pub fn communication_summary(&self) -> EcuCommunicationSummary {
+        let mut summary = EcuCommunicationSummary::default();
+
+        let connectors = self
+            .0
+            .get_sub_element(ElementName::Connectors)
+            .into_iter()
+            .flat_map(|connectors| connectors.sub_elements());
+        for connector in connectors {
+            // the physical channels reference the connectors of the ECU
+            for (named_parent, _) in get_reference_parents(&connector).unwrap_or_default() {
+                if let Ok(channel) = PhysicalChannel::try_from(named_parent)
+                    && !summary.channels.contains(&channel)
+                {
+                    summary.channels.push(channel);
+                }
+            }
+
+            let port_elements = connector
+                .get_sub_element(ElementName::EcuCommPortInstances)
+                .into_iter()
+                .flat_map(|port_instances| port_instances.sub_elements());
+            for port_elem in port_elements {
+                match port_elem.element_name() {
+                    ElementName::FramePort => summary.frame_ports.extend(FramePort::try_from(port_elem).ok()),
+                    ElementName::IPduPort => summary.pdu_ports.extend(IPduPort::try_from(port_elem).ok()),
+                    ElementName::ISignalPort => summary.signal_ports.extend(ISignalPort::try_from(port_elem).ok()),
+                    _ => {}
+                }
+            }
+        }
+
+        summary
+    }
+}
+
+//##################################################################
+
+/// The result of [`EcuInstance::communication_summary`]
+///
+/// It contains everything that one ECU sends or receives
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EcuCommunicationSummary {
+    /// The physical channels that the ECU is connected to
+    pub channels: Vec<PhysicalChannel>,
+    /// The frame ports of the ECU
+    pub frame_ports: Vec<FramePort>,
+    /// The PDU ports of the ECU
+    pub pdu_ports: Vec<IPduPort>,
+    /// The signal ports of the ECU
+    pub signal_ports: Vec<ISignalPort>,
+}
+
+//##################################################################
+
+/// An `EcuPartition` is a partition of an `EcuInstance`
+///
+/// The system description does not assign partitions to cores. This is done in the ECU configuration,
+/// where the `EcucPartition` is referenced by an `OsApplication`, which in turn references its core.
+///
+/// Use [`EcuInstance::create_partition`] to create a new `EcuPartition`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EcuPartition(Element);
+abstraction_element!(EcuPartition, EcuPartition);
+impl IdentifiableAbstractionElement for EcuPartition {}
+
+impl EcuPartition {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let partition = parent.create_named_sub_element(ElementName::EcuPartition, name)?;
+
+        Ok(Self(partition))
+    }
+
+    /// get the `EcuInstance` that contains this partition
+    pub fn ecu_instance(&self) -> Result<EcuInstance, AutosarAbstractionError> {
+        let ecu_elem = self.element().named_parent()?.unwrap();
+        EcuInstance::try_from(ecu_elem)
+    }
+
+    /// set or remove the flag that indicates that the partition is executed in user mode
+    pub fn set_exec_in_user_mode(&self, exec_in_user_mode: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        if let Some(exec_in_user_mode) = exec_in_user_mode {
+            self.element()
+                .get_or_create_sub_element(ElementName::ExecInUserMode)?
+                .set_character_data(exec_in_user_mode)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::ExecInUserMode);
+        }
+        Ok(())
+    }
+
+    /// check if the partition is executed in user mode
+    #[must_use]
+    pub fn exec_in_user_mode(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::ExecInUserMode)?
+            .character_data()?
+            .parse_bool()
+    }
 }
 
 //##################################################################
 
 #[cfg(test)]
 mod test {
+    use crate::communication::{
+        AbstractFrame, CanAddressingMode, CanFrameType, CommunicationDirection, PhysicalChannel, TransferProperty,
+    };
     use crate::*;
     use autosar_data::AutosarVersion;
 
@@ -316,5 +524,74 @@ mod test {
             .create_ethernet_communication_controller("EthCtrl", None)
             .unwrap();
         assert_eq!(ecu_instance.communication_controllers().count(), 3);
+
+        let partition = ecu_instance.create_partition("Partition").unwrap();
+        assert_eq!(ecu_instance.partitions().count(), 1);
+        assert_eq!(partition.ecu_instance().unwrap(), ecu_instance);
+        assert_eq!(partition.exec_in_user_mode(), None);
+        partition.set_exec_in_user_mode(Some(true)).unwrap();
+        assert_eq!(partition.exec_in_user_mode(), Some(true));
+        partition.set_exec_in_user_mode(None).unwrap();
+        assert_eq!(partition.exec_in_user_mode(), None);
+
+        assert_eq!(ecu_instance.sleep_mode_supported(), None);
+        ecu_instance.set_sleep_mode_supported(Some(true)).unwrap();
+        assert_eq!(ecu_instance.sleep_mode_supported(), Some(true));
+        ecu_instance.set_sleep_mode_supported(None).unwrap();
+        assert_eq!(ecu_instance.sleep_mode_supported(), None);
+        ecu_instance.set_wake_up_over_bus_supported(Some(false)).unwrap();
+        assert_eq!(ecu_instance.wake_up_over_bus_supported(), Some(false));
+        ecu_instance.set_wake_up_over_bus_supported(None).unwrap();
+        assert_eq!(ecu_instance.wake_up_over_bus_supported(), None);
+    }
+
+    #[test]
+    fn communication_summary() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+        let package = model.get_or_create_package("/pkg1").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let ecu_instance = system.create_ecu_instance("ecu_name", &package).unwrap();
+        assert_eq!(ecu_instance.communication_summary(), EcuCommunicationSummary::default());
+
+        let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+        let controller = ecu_instance.create_can_communication_controller("CanCtrl").unwrap();
+        controller.connect_physical_channel("connection", &channel).unwrap();
+
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let sys_signal = package.create_system_signal("SysSignal").unwrap();
+        let signal = system.create_isignal("Signal", &package, 8, &sys_signal, None).unwrap();
+        pdu.map_signal(
+            &signal,
+            0,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+        frame_triggering
+            .connect_to_ecu(&ecu_instance, CommunicationDirection::Out)
+            .unwrap();
+
+        let summary = ecu_instance.communication_summary();
+        assert_eq!(summary.channels, vec![PhysicalChannel::Can(channel)]);
+        assert_eq!(summary.frame_ports.len(), 1);
+        assert_eq!(
+            summary.frame_ports[0].communication_direction(),
+            Some(CommunicationDirection::Out)
+        );
+        assert_eq!(summary.pdu_ports.len(), 1);
+        assert_eq!(summary.signal_ports.len(), 1);
+        assert_eq!(
+            summary.signal_ports[0].communication_direction(),
+            Some(CommunicationDirection::Out)
+        );
     }
 }
@@ -38,6 +38,7 @@
 //!   - Ports
 //!   - Internal behavior: Runnables, Events, etc.
 //! - ECU Configuration
+//! - Analysis: export of the network topology as GraphViz DOT or Mermaid graph
 //!
 //! # Example
 //!
@@ -75,31 +76,78 @@
 //! // ...
 //! # Ok(())}
 //! ```
+//!
+//! # Thread safety
+//!
+//! All abstraction elements are `Send` and `Sync`, and all iterators returned by the crate are `Send`.
+//! The only exception is the [`BulkEditSession`], which caches names in thread local storage and is therefore
+//! neither `Send` nor `Sync`: it must be dropped on the thread that created it.
+//! Reading the model from several threads at the same time is safe, because `autosar-data` protects
+//! each element with a lock.
+//!
+//! Iterators that are created by the abstraction layer are lazy, so it is usually best to collect them
+//! into a `Vec` first, and then distribute the elements to the worker threads.
+//! Such a `Vec` can also be processed with rayon, e.g. using `into_par_iter()`.
+//!
+//! ```rust
+//! # use autosar_data::*;
+//! # use autosar_data_abstraction::*;
+//! # use autosar_data_abstraction::communication::*;
+//! # fn main() -> Result<(), AutosarAbstractionError> {
+//! # let model = AutosarModelAbstraction::create("file.arxml", AutosarVersion::Autosar_00049);
+//! # let package = model.get_or_create_package("/System")?;
+//! # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+//! # for idx in 0..10 {
+//! #     system.create_isignal_ipdu(&format!("Pdu{idx}"), &package, 8)?;
+//! # }
+//! let pdus: Vec<_> = system.pdus().collect();
+//! let total_length: u32 = std::thread::scope(|scope| {
+//!     let workers: Vec<_> = pdus
+//!         .chunks(4)
+//!         .map(|chunk| scope.spawn(move || chunk.iter().filter_map(|pdu| pdu.length()).sum::<u32>()))
+//!         .collect();
+//!     workers.into_iter().map(|worker| worker.join().unwrap()).sum()
+//! });
+//! # assert_eq!(total_length, 80);
+//! # Ok(())}
+//! ```
 
 #![warn(missing_docs)]
 
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use autosar_data::{
-    ArxmlFile, AutosarDataError, AutosarModel, AutosarVersion, Element, ElementName, EnumItem, WeakElement,
+    ArxmlFile, AttributeName, AutosarDataError, AutosarModel, AutosarVersion, ContentType, Element, ElementName,
+    EnumItem, WeakArxmlFile, WeakElement,
 };
 use thiserror::Error;
 
 // modules that are visible in the API
+pub mod analysis;
+pub mod bsw;
 pub mod communication;
 pub mod datatype;
 pub mod ecu_configuration;
 pub mod software_component;
+pub mod timing;
 
 // internal modules that only serve to split up the code
+mod admin_data;
 mod arpackage;
+mod bulk_edit;
 mod ecuinstance;
 mod system;
+mod variant;
 
 // export the content of the internal modules
+pub use admin_data::*;
 pub use arpackage::{ArPackage, ReferenceBase};
+pub use bulk_edit::BulkEditSession;
 pub use ecuinstance::*;
 pub use system::*;
+pub use variant::*;
 
 /// The error type `AutosarAbstractionError` wraps all errors from the crate
 #[derive(Error, Debug)]
@@ -139,6 +187,15 @@ pub enum AutosarAbstractionError {
     /// the function parameter has an invalid value
     #[error("invalid parameter: {}", .0)]
     InvalidParameter(String),
+
+    /// the operations of a transaction failed, and the model could not be restored to its previous state
+    #[error("transaction failed: {}; the rollback failed too: {}", .error, .rollback_error)]
+    RollbackFailed {
+        /// the error that was returned by the operations of the transaction
+        error: Box<AutosarAbstractionError>,
+        /// the error that occurred during the rollback
+        rollback_error: Box<AutosarAbstractionError>,
+    },
 }
 
 impl From<AutosarDataError> for AutosarAbstractionError {
@@ -192,6 +249,8 @@ pub trait AbstractionElement: Clone + PartialEq + TryFrom<autosar_data::Element>
             }
         }
 
+        // paths and references change, so the reverse reference index of an active BulkEditSession is outdated
+        bulk_edit::invalidate_reference_index(&element.model()?);
         parent.remove_sub_element(element.clone())?;
         Ok(())
     }
@@ -207,9 +266,183 @@ pub trait IdentifiableAbstractionElement: AbstractionElement {
 
     /// Set the item name of the element
     fn set_name(&self, name: &str) -> Result<(), AutosarAbstractionError> {
+        bulk_edit::invalidate_reference_index(&self.element().model()?);
         self.element().set_item_name(name)?;
         Ok(())
     }
+
+    /// Set the long name of the element in the given language
+    ///
+    /// The language is given as a two-letter code, e.g. "EN" or "DE".
+    /// An existing long name in the same language is replaced, long names in other languages are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// system.set_long_name("EN", "The system")?;
+    /// system.set_long_name("DE", "Das System")?;
+    /// assert_eq!(system.long_name("EN").as_deref(), Some("The system"));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] the language is not valid, or an error occurred in the Autosar model
+    fn set_long_name(&self, language: &str, text: &str) -> Result<(), AutosarAbstractionError> {
+        set_multilanguage_text(self.element(), ElementName::LongName, ElementName::L4, language, text)
+    }
+
+    /// Get the long name of the element in the given language
+    #[must_use]
+    fn long_name(&self, language: &str) -> Option<String> {
+        get_multilanguage_text(self.element(), ElementName::LongName, language)
+    }
+
+    /// Iterate over the long names of the element in all languages as pairs of (language, text)
+    fn long_names(&self) -> impl Iterator<Item = (String, String)> + Send + use<Self> {
+        multilanguage_texts(self.element(), ElementName::LongName)
+    }
+
+    /// Set the description of the element in the given language
+    ///
+    /// The language is given as a two-letter code, e.g. "EN" or "DE".
+    /// An existing description in the same language is replaced, descriptions in other languages are kept.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] the language is not valid, or an error occurred in the Autosar model
+    fn set_desc(&self, language: &str, text: &str) -> Result<(), AutosarAbstractionError> {
+        set_multilanguage_text(self.element(), ElementName::Desc, ElementName::L2, language, text)
+    }
+
+    /// Get the description of the element in the given language
+    #[must_use]
+    fn desc(&self, language: &str) -> Option<String> {
+        get_multilanguage_text(self.element(), ElementName::Desc, language)
+    }
+
+    /// Iterate over the descriptions of the element in all languages as pairs of (language, text)
+    fn descs(&self) -> impl Iterator<Item = (String, String)> + Send + use<Self> {
+        multilanguage_texts(self.element(), ElementName::Desc)
+    }
+
+    /// Get the `AdminData` of the element, if it has any
+    #[must_use]
+    fn admin_data(&self) -> Option<AdminData> {
+        AdminData::try_from(self.element().get_sub_element(ElementName::AdminData)?).ok()
+    }
+
+    /// Get the `AdminData` of the element, or create it if it does not exist yet
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let admin_data = system.get_or_create_admin_data()?;
+    /// let revision = admin_data.create_doc_revision("1.0.0")?;
+    /// revision.set_date(Some("2024-01-31"))?;
+    /// assert_eq!(system.admin_data(), Some(admin_data));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the ADMIN-DATA element
+    fn get_or_create_admin_data(&self) -> Result<AdminData, AutosarAbstractionError> {
+        AdminData::get_or_create(self.element())
+    }
+
+    /// Remove the `AdminData` of the element, if it has any
+    fn remove_admin_data(&self) {
+        let _ = self.element().remove_sub_element_kind(ElementName::AdminData);
+    }
+
+    /// Set the item name of the element and update the names of dependent elements
+    ///
+    /// Some elements are created by this crate with names that are derived from the name of another element,
+    /// e.g. `FT_<frame>`, `PT_<pdu>` and `ST_<signal>` for triggerings, `<pdu>` for a `PduToFrameMapping`,
+    /// or `<triggering>_Rx` / `<triggering>_Tx` for ports.
+    /// These derived names are updated to match the new name.
+    /// Dependent elements whose names do not follow the naming conventions are left unchanged.
+    fn rename_with_dependents(&self, new_name: &str) -> Result<(), AutosarAbstractionError> {
+        let old_name = self.name().ok_or(AutosarDataError::ItemDeleted)?;
+        self.set_name(new_name)?;
+        if old_name != new_name {
+            rename_dependents(self.element(), &old_name, new_name)?;
+        }
+        Ok(())
+    }
+
+    /// Create a deep copy of the element with a new name in the given package
+    ///
+    /// All sub elements are copied, e.g. the signal mappings of an `ISignalIPdu`, or the ports and the
+    /// internal behavior of a software component type.
+    /// References inside the copied element that point to other parts of the original element are
+    /// remapped to the corresponding parts of the copy. All other references still point to the original targets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, communication::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let pdu = system.create_isignal_ipdu("Pdu", &package, 8)?;
+    /// let variant_package = model.get_or_create_package("/variant")?;
+    /// let pdu_copy = pdu.duplicate("PduVariant", &variant_package)?;
+    /// assert_eq!(pdu_copy.length(), Some(8));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ItemAlreadyExists`] The new name is already in use in the package
+    /// - [`AutosarAbstractionError::ModelError`] The element could not be copied into the package
+    fn duplicate(&self, new_name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        // check the name first: the copy would otherwise be renamed automatically and left behind on failure
+        let model = package.element().model()?;
+        if model
+            .get_element_by_path(&format!("{}/{new_name}", package.element().path()?))
+            .is_some()
+        {
+            return Err(AutosarAbstractionError::ItemAlreadyExists);
+        }
+
+        let original_path = self.element().path()?;
+        let copy = package
+            .element()
+            .get_or_create_sub_element(ElementName::Elements)?
+            .create_copied_sub_element(self.element())?;
+        let result = if copy.item_name().as_deref() == Some(new_name) {
+            Ok(())
+        } else {
+            copy.set_item_name(new_name).map_err(AutosarAbstractionError::from)
+        };
+        if let Err(err) = result.and_then(|()| remap_internal_references(&copy, &original_path)) {
+            if let Ok(Some(parent)) = copy.parent() {
+                let _ = parent.remove_sub_element(copy);
+            }
+            return Err(err);
+        }
+
+        Self::try_from(copy.clone()).map_err(|_| AutosarAbstractionError::ConversionError {
+            element: copy,
+            dest: "the type of the original element".to_string(),
+        })
+    }
 }
 
 macro_rules! abstraction_element {
@@ -247,6 +480,17 @@ pub(crate) use abstraction_element;
 
 //#########################################################
 
+/// The result of [`AutosarModelAbstraction::remove_with_report`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RemovalReport {
+    /// The paths of all identifiable elements that were removed, sorted by path
+    pub removed_elements: Vec<String>,
+    /// All references that were removed, as pairs of (path of the referring element, path of the reference target)
+    pub removed_references: Vec<(String, String)>,
+}
+
+//#########################################################
+
 /// The `AutosarModelAbstraction` wraps an `AutosarModel` and provides additional functionality
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AutosarModelAbstraction(AutosarModel);
@@ -297,6 +541,64 @@ impl AutosarModelAbstraction {
         &self.0
     }
 
+    /// start a [`BulkEditSession`] for this model
+    ///
+    /// While the session is active, the generation of unique names is much faster when a large number
+    /// of elements is created. The session ends when the returned value is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let session = model.bulk_edit_session();
+    /// for idx in 0..1000 {
+    ///     system.create_isignal_ipdu(&format!("Pdu{idx}"), &package, 8)?;
+    /// }
+    /// drop(session);
+    /// # Ok(())}
+    /// ```
+    #[must_use]
+    pub fn bulk_edit_session(&self) -> BulkEditSession {
+        BulkEditSession::new(&self.0)
+    }
+
+    /// set the policy for name conflicts when the crate generates names for new elements
+    ///
+    /// The policy applies to the model and all its clones, regardless of which `AutosarModelAbstraction`
+    /// was used to set it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// model.set_name_conflict_policy(NameConflictPolicy::Error);
+    /// assert_eq!(model.name_conflict_policy(), NameConflictPolicy::Error);
+    /// ```
+    pub fn set_name_conflict_policy(&self, policy: NameConflictPolicy) {
+        let root = self.0.root_element();
+        let mut policies = NAME_CONFLICT_POLICIES
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        // drop the entries of models that no longer exist
+        policies.retain(|(weak_root, _)| weak_root.upgrade().is_some_and(|elem| elem != root));
+        if policy != NameConflictPolicy::Suffix {
+            policies.push((root.downgrade(), policy));
+        }
+    }
+
+    /// get the policy for name conflicts when the crate generates names for new elements
+    #[must_use]
+    pub fn name_conflict_policy(&self) -> NameConflictPolicy {
+        name_conflict_policy(&self.0)
+    }
+
     /// Get the root element of the model
     #[must_use]
     pub fn root_element(&self) -> Element {
@@ -313,6 +615,88 @@ impl AutosarModelAbstraction {
             .filter_map(|elem| ArPackage::try_from(elem).ok())
     }
 
+    /// iterate over all elements of type `T` in all packages of the model
+    ///
+    /// All packages are searched recursively, so the elements are found regardless of which package
+    /// they are located in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg1/sub")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # system.create_isignal_ipdu("Pdu", &package, 8)?;
+    /// for pdu in model.find_elements_by_type::<ISignalIPdu>() {
+    ///    println!("{:?}", pdu.name());
+    /// }
+    /// # assert_eq!(model.find_elements_by_type::<ISignalIPdu>().count(), 1);
+    /// # Ok(())}
+    /// ```
+    pub fn find_elements_by_type<T: AbstractionElement>(&self) -> impl Iterator<Item = T> + Send + use<T> {
+        self.packages()
+            .flat_map(|package| std::iter::once(package.clone()).chain(package.sub_packages_recursive()))
+            .flat_map(|package| package.elements_of_type::<T>())
+    }
+
+    /// find all identifiable elements of type `T` whose name matches a glob pattern
+    ///
+    /// In the pattern, `*` matches any sequence of characters and `?` matches exactly one character.
+    /// All identifiable elements in the model are searched, not only those directly inside a package.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+    /// # let package = model.get_or_create_package("/pkg")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// # system.create_isignal_ipdu("EngineSpeed", &package, 8)?;
+    /// # system.create_isignal_ipdu("BrakeStatus", &package, 8)?;
+    /// let engine_pdus: Vec<ISignalIPdu> = model.find_by_name("Engine*").collect();
+    /// assert_eq!(engine_pdus.len(), 1);
+    /// # Ok(())}
+    /// ```
+    pub fn find_by_name<T: IdentifiableAbstractionElement>(
+        &self,
+        pattern: &str,
+    ) -> impl Iterator<Item = T> + Send + use<T> {
+        let pattern: Vec<char> = pattern.chars().collect();
+        self.identifiable_elements_matching(|name| glob_match(&pattern, name))
+            .into_iter()
+            .filter_map(|elem| T::try_from(elem).ok())
+    }
+
+    /// find all identifiable elements of type `T` whose name is accepted by the `matches` function
+    ///
+    /// This is the general form of [`AutosarModelAbstraction::find_by_name`]; it can be used with any kind
+    /// of name matching, e.g. a regular expression from the `regex` crate.
+    pub fn find_by_name_matching<T, F>(&self, matches: F) -> impl Iterator<Item = T> + Send + use<T, F>
+    where
+        T: IdentifiableAbstractionElement,
+        F: Fn(&str) -> bool,
+    {
+        self.identifiable_elements_matching(matches)
+            .into_iter()
+            .filter_map(|elem| T::try_from(elem).ok())
+    }
+
+    // collect all identifiable elements whose name is accepted by the `matches` function
+    fn identifiable_elements_matching(&self, matches: impl Fn(&str) -> bool) -> Vec<Element> {
+        self.0
+            .identifiable_elements()
+            .filter_map(|(_, weak)| weak.upgrade())
+            .filter(|elem| elem.item_name().is_some_and(|name| matches(&name)))
+            .collect()
+    }
+
     /// Get a package by its path or create it if it does not exist
     pub fn get_or_create_package(&self, path: &str) -> Result<ArPackage, AutosarAbstractionError> {
         ArPackage::get_or_create(&self.0, path)
@@ -345,6 +729,45 @@ impl AutosarModelAbstraction {
         Ok(value)
     }
 
+    /// move an element into a file
+    ///
+    /// After the move, the element is only serialized into the given file. The parent packages of the element
+    /// are added to the file if necessary, but they remain in their other files too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("file1.arxml", AutosarVersion::Autosar_00048);
+    /// let file_2 = model.create_file("file2.arxml", AutosarVersion::Autosar_00048)?;
+    /// let package = model.get_or_create_package("/pkg")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// model.move_element_to_file(&system, &file_2)?;
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] the file membership of the element could not be changed,
+    ///   e.g. because the file belongs to a different model
+    pub fn move_element_to_file<T: AbstractionElement>(
+        &self,
+        element: &T,
+        file: &ArxmlFile,
+    ) -> Result<(), AutosarAbstractionError> {
+        let element = element.element();
+        let (_, old_files) = element.file_membership()?;
+        element.add_to_file(file)?;
+        for old_file in old_files.iter().filter_map(WeakArxmlFile::upgrade) {
+            if old_file != *file {
+                element.remove_from_file(&old_file)?;
+            }
+        }
+        Ok(())
+    }
+
     /// iterate over all files in the model
     pub fn files(&self) -> impl Iterator<Item = ArxmlFile> + Send + use<> {
         self.0.files()
@@ -356,6 +779,182 @@ impl AutosarModelAbstraction {
         Ok(())
     }
 
+    /// run a sequence of operations on the model as a transaction
+    ///
+    /// If the closure returns an error, all changes that were made to the model inside the closure are rolled back
+    /// and the error is returned.
+    /// A snapshot of the model is taken before the closure runs. The rollback compares the model with the snapshot
+    /// and only undoes the differences: elements that were created inside the closure are removed, removed elements
+    /// are re-created, and modified values are restored.
+    /// Files that were created inside the closure are removed, removed files are re-created, and the file
+    /// membership of all elements is restored.
+    /// Handles to elements that existed before the transaction and were not removed by the closure remain valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// let result = model.transaction(|tx| {
+    ///     let package = tx.get_or_create_package("/pkg1")?;
+    ///     package.create_system("System", SystemCategory::SystemExtract)?;
+    ///     // the second system causes an error, so the package is removed again
+    ///     package.create_system("System", SystemCategory::SystemExtract)
+    /// });
+    /// assert!(result.is_err());
+    /// assert!(model.get_element_by_path("/pkg1").is_none());
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - The error returned by the closure
+    /// - [`AutosarAbstractionError::ModelError`] The snapshot of the model could not be created
+    /// - [`AutosarAbstractionError::RollbackFailed`] The closure returned an error, and the model could not be
+    ///   restored. It contains both the error of the closure and the error of the rollback.
+    pub fn transaction<T, F>(&self, operations: F) -> Result<T, AutosarAbstractionError>
+    where
+        F: FnOnce(&Self) -> Result<T, AutosarAbstractionError>,
+    {
+        let snapshot = self
+            .files()
+            .map(|file| Ok((file.filename(), file.serialize()?)))
+            .collect::<Result<Vec<_>, AutosarAbstractionError>>()?;
+
+        match operations(self) {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                if let Err(rollback_error) = self.rollback(&snapshot) {
+                    return Err(AutosarAbstractionError::RollbackFailed {
+                        error: Box::new(error),
+                        rollback_error: Box::new(rollback_error),
+                    });
+                }
+                Err(error)
+            }
+        }
+    }
+
+    // restore the content of the model from a snapshot that was created by `transaction`
+    fn rollback(&self, snapshot: &[(PathBuf, String)]) -> Result<(), AutosarAbstractionError> {
+        // load the snapshot into a separate model first, so that nothing is changed if it can't be loaded
+        let saved_model = AutosarModel::new();
+        for (file_name, buffer) in snapshot {
+            saved_model.load_buffer(buffer.as_bytes(), file_name, true)?;
+        }
+
+        // re-create the files that were removed, and restore the versions of the others
+        let mut live_files: HashMap<PathBuf, ArxmlFile> = self.0.files().map(|file| (file.filename(), file)).collect();
+        for saved_file in saved_model.files() {
+            if let Some(live_file) = live_files.get(&saved_file.filename()) {
+                if live_file.version() != saved_file.version() {
+                    live_file.set_version(saved_file.version())?;
+                }
+            } else {
+                let file_name = saved_file.filename();
+                let live_file = self.0.create_file(&file_name, saved_file.version())?;
+                live_files.insert(file_name, live_file);
+            }
+        }
+
+        restore_element(&self.0.root_element(), &saved_model.root_element(), &live_files)?;
+
+        // remove the files that were created after the snapshot. Their elements were already removed or
+        // moved back into the original files by restore_element
+        let saved_file_names: HashSet<PathBuf> = saved_model.files().map(|file| file.filename()).collect();
+        for (file_name, live_file) in live_files {
+            if !saved_file_names.contains(&file_name) {
+                self.0.remove_file(&live_file);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// run a removal operation and report everything that it deleted
+    ///
+    /// Deep removals (`remove(true)`) can delete many more elements than the one on which `remove` was called,
+    /// e.g. mappings, triggerings or unused data types.
+    /// This function compares the model before and after the removal and reports all identifiable elements that
+    /// were deleted, as well as all references that were removed.
+    ///
+    /// Note that the whole model is scanned twice, so this is significantly slower than calling `remove` directly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let system_signal = package.create_system_signal("SystemSignal")?;
+    /// let signal = system.create_isignal("Signal", &package, 8, &system_signal, None)?;
+    /// let report = model.remove_with_report(|| signal.remove(true))?;
+    /// assert_eq!(report.removed_elements, vec!["/pkg1/Signal", "/pkg1/SystemSignal"]);
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - The error returned by the removal operation
+    pub fn remove_with_report<F>(&self, removal: F) -> Result<RemovalReport, AutosarAbstractionError>
+    where
+        F: FnOnce() -> Result<(), AutosarAbstractionError>,
+    {
+        let paths_before: Vec<String> = self.0.identifiable_elements().map(|(path, _)| path).collect();
+        let references_before = self.collect_references(&paths_before);
+
+        removal()?;
+
+        let remaining_paths: HashSet<String> = self.0.identifiable_elements().map(|(path, _)| path).collect();
+        let mut removed_elements: Vec<String> = paths_before
+            .iter()
+            .filter(|path| !remaining_paths.contains(*path))
+            .cloned()
+            .collect();
+        removed_elements.sort();
+
+        // compare as multisets: an element may contain several references to the same target
+        let mut remaining_references: HashMap<(String, String), usize> = HashMap::new();
+        for reference in self.collect_references(&paths_before) {
+            *remaining_references.entry(reference).or_default() += 1;
+        }
+        let mut removed_references = Vec::new();
+        for reference in references_before {
+            match remaining_references.get_mut(&reference) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => removed_references.push(reference),
+            }
+        }
+        removed_references.sort();
+
+        Ok(RemovalReport {
+            removed_elements,
+            removed_references,
+        })
+    }
+
+    // collect all references to the given paths as pairs of (path of the referring element, target path)
+    fn collect_references(&self, target_paths: &[String]) -> Vec<(String, String)> {
+        target_paths
+            .iter()
+            .flat_map(|target_path| {
+                self.0
+                    .get_references_to(target_path)
+                    .into_iter()
+                    .filter_map(|weak| weak.upgrade())
+                    .filter_map(|ref_elem| ref_elem.named_parent().ok().flatten())
+                    .filter_map(|referrer| referrer.path().ok())
+                    .map(|referrer_path| (referrer_path, target_path.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
     /// Get an element by its path
     #[must_use]
     pub fn get_element_by_path(&self, path: &str) -> Option<Element> {
@@ -386,6 +985,41 @@ impl AutosarModelAbstraction {
 
 //#########################################################
 
+/// The `NameConflictPolicy` defines how name conflicts are handled when the crate generates a name for a new element
+///
+/// Many elements, e.g. `FrameTriggering`s, `PduTriggering`s or mappings, are created with names that are derived
+/// from the names of the elements that are passed in. The policy decides what happens if the derived name is already
+/// in use.
+///
+/// The policy does not apply to the names of helper elements that are generated as part of a larger operation,
+/// e.g. by the ECU configuration generators, by migrations or while renaming dependent elements.
+/// These names always get a counter appended, so that the operation is not aborted halfway and no unrelated
+/// elements are removed.
+///
+/// Use [`AutosarModelAbstraction::set_name_conflict_policy`] to set the policy of a model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NameConflictPolicy {
+    /// append a counter `_1`, `_2`, ... to the name until it is unique. This is the default.
+    #[default]
+    Suffix,
+    /// return an [`AutosarAbstractionError::ItemAlreadyExists`] error
+    Error,
+    /// remove the existing element, so that it is replaced by the new element.
+    ///
+    /// Triggerings and mappings are removed like with their `remove(false)` function, so that the elements
+    /// which depend on them, e.g. ports and references, are removed too.
+    /// Other elements can only be overwritten if they are not referenced; otherwise an
+    /// [`AutosarAbstractionError::InvalidParameter`] error is returned.
+    Overwrite,
+}
+
+// the name conflict policies of all models that don't use the default policy.
+// The models are identified by their root elements. Entries of models that no longer exist are pruned
+// whenever the list is accessed.
+static NAME_CONFLICT_POLICIES: Mutex<Vec<(WeakElement, NameConflictPolicy)>> = Mutex::new(Vec::new());
+
+//#########################################################
+
 /// The `ByteOrder` is used to define the order of bytes in a multi-byte value
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ByteOrder {
@@ -425,21 +1059,390 @@ impl From<ByteOrder> for EnumItem {
 
 //##################################################################
 
-pub(crate) fn make_unique_name(model: &AutosarModel, base_path: &str, initial_name: &str) -> String {
-    let mut full_path = format!("{base_path}/{initial_name}");
+fn name_conflict_policy(model: &AutosarModel) -> NameConflictPolicy {
+    let root = model.root_element();
+    let mut policies = NAME_CONFLICT_POLICIES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    // drop the entries of models that no longer exist
+    policies.retain(|(weak_root, _)| weak_root.upgrade().is_some());
+    policies
+        .iter()
+        .find(|(weak_root, _)| weak_root.upgrade().is_some_and(|elem| elem == root))
+        .map(|(_, policy)| *policy)
+        .unwrap_or_default()
+}
+
+// restore the content of an element from a saved copy in another model
+//
+// Sub elements that exist in both versions are kept and restored recursively, so that handles to them stay valid.
+// Sub elements are matched by element name and item name; unnamed elements are matched in order of appearance.
+// The file membership is restored too; the files of the saved model are mapped to the live files by their names.
+fn restore_element(
+    live: &Element,
+    saved: &Element,
+    live_files: &HashMap<PathBuf, ArxmlFile>,
+) -> Result<(), AutosarAbstractionError> {
+    restore_file_membership(live, saved, live_files)?;
+
+    for attribute in live.attributes() {
+        if saved.attribute_value(attribute.attrname).is_none() {
+            live.remove_attribute(attribute.attrname);
+        }
+    }
+    for attribute in saved.attributes() {
+        if live.attribute_value(attribute.attrname).as_ref() != Some(&attribute.content) {
+            live.set_attribute(attribute.attrname, attribute.content)?;
+        }
+    }
+
+    if saved.content_type() == ContentType::CharacterData {
+        let saved_cdata = saved.character_data();
+        if live.character_data() != saved_cdata {
+            if let Some(cdata) = saved_cdata {
+                live.set_character_data(cdata)?;
+            } else {
+                live.remove_character_data()?;
+            }
+        }
+        return Ok(());
+    }
+
+    let saved_children: Vec<Element> = saved.sub_elements().collect();
+    let mut unmatched: Vec<Option<Element>> = live.sub_elements().map(Some).collect();
+    let matched: Vec<Option<Element>> = saved_children
+        .iter()
+        .map(|saved_child| {
+            let key = (saved_child.element_name(), saved_child.item_name());
+            unmatched
+                .iter_mut()
+                .find(|candidate| {
+                    candidate
+                        .as_ref()
+                        .is_some_and(|live_child| (live_child.element_name(), live_child.item_name()) == key)
+                })
+                .and_then(Option::take)
+        })
+        .collect();
+
+    // elements that were created after the snapshot
+    for live_child in unmatched.into_iter().flatten() {
+        live.remove_sub_element(live_child)?;
+    }
+
+    for (position, (saved_child, live_child)) in saved_children.iter().zip(matched).enumerate() {
+        match live_child {
+            // mixed content (e.g. formatted text) is compared as a whole and replaced if it differs
+            Some(live_child)
+                if saved_child.content_type() == ContentType::Mixed
+                    && live_child.serialize() != saved_child.serialize() =>
+            {
+                live.remove_sub_element(live_child)?;
+                let copied_child = live.create_copied_sub_element_at(saved_child, position)?;
+                restore_element(&copied_child, saved_child, live_files)?;
+            }
+            Some(live_child) => restore_element(&live_child, saved_child, live_files)?,
+            None => {
+                // the copy only inherits the file membership of its parent, so it is restored as well
+                let copied_child = live.create_copied_sub_element_at(saved_child, position)?;
+                restore_element(&copied_child, saved_child, live_files)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// restore the file membership of an element from a saved copy in another model
+fn restore_file_membership(
+    live: &Element,
+    saved: &Element,
+    live_files: &HashMap<PathBuf, ArxmlFile>,
+) -> Result<(), AutosarAbstractionError> {
+    let (saved_local, saved_membership) = saved.file_membership()?;
+    let (live_local, live_membership) = live.file_membership()?;
+    // both elements inherit the membership of their parents, which has already been restored
+    if !saved_local && !live_local {
+        return Ok(());
+    }
+
+    let saved_names: HashSet<PathBuf> = saved_membership
+        .iter()
+        .filter_map(WeakArxmlFile::upgrade)
+        .map(|file| file.filename())
+        .collect();
+    let live_names: HashSet<PathBuf> = live_membership
+        .iter()
+        .filter_map(WeakArxmlFile::upgrade)
+        .map(|file| file.filename())
+        .collect();
+    for file_name in saved_names.difference(&live_names) {
+        if let Some(file) = live_files.get(file_name) {
+            live.add_to_file(file)?;
+        }
+    }
+    for file_name in live_names.difference(&saved_names) {
+        if let Some(file) = live_files.get(file_name) {
+            live.remove_from_file(file)?;
+        }
+    }
+
+    Ok(())
+}
+
+// get a name for a new element that does not conflict with existing elements, according to the NameConflictPolicy
+//
+// The policy only applies to names that are derived directly from the elements passed in by the user, e.g. the name
+// of a frame triggering that is created for a frame. Names of helper elements that are generated as part of a larger
+// operation must use make_unique_generated_name instead.
+pub(crate) fn make_unique_name(
+    model: &AutosarModel,
+    base_path: &str,
+    initial_name: &str,
+) -> Result<String, AutosarAbstractionError> {
+    let initial_path = format!("{base_path}/{initial_name}");
+    match name_conflict_policy(model) {
+        NameConflictPolicy::Suffix => Ok(make_unique_generated_name(model, base_path, initial_name)),
+        NameConflictPolicy::Error => {
+            if model.get_element_by_path(&initial_path).is_some() {
+                return Err(AutosarAbstractionError::ItemAlreadyExists);
+            }
+            Ok(initial_name.to_string())
+        }
+        NameConflictPolicy::Overwrite => {
+            if let Some(existing) = model.get_element_by_path(&initial_path) {
+                remove_overwritten_element(existing)?;
+            }
+            Ok(initial_name.to_string())
+        }
+    }
+}
+
+// remove an element that is replaced by a new element with the same name because of NameConflictPolicy::Overwrite
+//
+// The typed remove functions are used, so that no dangling references or orphaned ports are left behind.
+fn remove_overwritten_element(existing: Element) -> Result<(), AutosarAbstractionError> {
+    match existing.element_name() {
+        ElementName::PduTriggering => communication::PduTriggering::try_from(existing)?.remove(false),
+        ElementName::ISignalTriggering => communication::ISignalTriggering::try_from(existing)?.remove(false),
+        ElementName::CanFrameTriggering | ElementName::FlexrayFrameTriggering | ElementName::LinFrameTriggering => {
+            communication::FrameTriggering::try_from(existing)?.remove(false)
+        }
+        ElementName::PduToFrameMapping => communication::PduToFrameMapping::try_from(existing)?.remove(false),
+        ElementName::ISignalToIPduMapping => communication::ISignalToIPduMapping::try_from(existing)?.remove(false),
+        _ => {
+            if !get_reference_parents(&existing)?.is_empty() {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "the existing element {} is referenced and can't be overwritten",
+                    existing.path()?
+                )));
+            }
+            if let Some(parent) = existing.parent()? {
+                parent.remove_sub_element(existing)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+// get a name for a new element that does not conflict with existing elements, by appending a counter to the name.
+// The NameConflictPolicy is ignored: operations that generate the names of helper elements, e.g. generators or
+// migrations, must not fail or remove existing elements because of a name conflict in the middle of the operation.
+pub(crate) fn make_unique_generated_name(model: &AutosarModel, base_path: &str, initial_name: &str) -> String {
+    let initial_path = format!("{base_path}/{initial_name}");
+    // while a BulkEditSession is active, the search continues after the last counter that was used
+    let mut counter = bulk_edit::cached_name_counter(model, &initial_path);
     let mut name = initial_name.to_string();
-    let mut counter = 0;
+    let mut full_path = initial_path.clone();
+    if counter > 0 {
+        name = format!("{initial_name}_{counter}");
+        full_path = format!("{base_path}/{name}");
+    }
     while model.get_element_by_path(&full_path).is_some() {
         counter += 1;
         name = format!("{initial_name}_{counter}");
         full_path = format!("{base_path}/{name}");
     }
+    bulk_edit::update_name_counter(model, initial_path, counter);
 
     name
 }
 
 //##################################################################
 
+// rename all elements whose names were derived from the old name of the element
+fn rename_dependents(element: &Element, old_name: &str, new_name: &str) -> Result<(), AutosarAbstractionError> {
+    for (dependent, _) in get_reference_parents(element)? {
+        let prefix = match dependent.element_name() {
+            ElementName::CanFrameTriggering | ElementName::FlexrayFrameTriggering | ElementName::LinFrameTriggering => {
+                "FT_"
+            }
+            ElementName::PduTriggering => "PT_",
+            ElementName::ISignalTriggering => "ST_",
+            ElementName::PduToFrameMapping | ElementName::ISignalToIPduMapping => "",
+            _ => continue,
+        };
+        let Some(dependent_name) = dependent.item_name() else {
+            continue;
+        };
+        if !is_derived_name(&dependent_name, &format!("{prefix}{old_name}")) {
+            continue;
+        }
+        let new_dependent_name = set_unique_item_name(&dependent, &format!("{prefix}{new_name}"))?;
+
+        // the ports of a triggering are named after the triggering
+        let port_refs = dependent
+            .sub_elements()
+            .filter(|elem| {
+                matches!(
+                    elem.element_name(),
+                    ElementName::FramePortRefs | ElementName::IPduPortRefs | ElementName::ISignalPortRefs
+                )
+            })
+            .flat_map(|port_refs| port_refs.sub_elements());
+        for port_ref in port_refs {
+            let Ok(port) = port_ref.get_reference_target() else {
+                continue;
+            };
+            for suffix in ["_Rx", "_Tx"] {
+                if port.item_name() == Some(format!("{dependent_name}{suffix}")) {
+                    set_unique_item_name(&port, &format!("{new_dependent_name}{suffix}"))?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// update references inside of a copied element that still point into the original element
+fn remap_internal_references(copy: &Element, original_path: &str) -> Result<(), AutosarAbstractionError> {
+    let model = copy.model()?;
+    let copy_path = copy.path()?;
+    let original_prefix = format!("{original_path}/");
+
+    for (_, elem) in copy.elements_dfs() {
+        // the references in the copy still resolve to the targets in the original element
+        let Ok(target_path) = elem.get_reference_target().and_then(|target| target.path()) else {
+            continue;
+        };
+        if let Some(relative_path) = target_path.strip_prefix(&original_prefix)
+            && let Some(new_target) = model.get_element_by_path(&format!("{copy_path}/{relative_path}"))
+        {
+            elem.set_reference_target(&new_target)?;
+        }
+    }
+    Ok(())
+}
+
+// set the text for one language in a multi-language element like LONG-NAME (L-4) or DESC (L-2)
+fn set_multilanguage_text(
+    element: &Element,
+    container_name: ElementName,
+    item_name: ElementName,
+    language: &str,
+    text: &str,
+) -> Result<(), AutosarAbstractionError> {
+    let container = element.get_or_create_sub_element(container_name)?;
+    let item = if let Some(item) = container.sub_elements().find(|item| has_language(item, language)) {
+        item
+    } else {
+        let item = container.create_sub_element(item_name)?;
+        if let Err(error) = item.set_attribute_string(AttributeName::L, language) {
+            // don't leave an item without a language behind
+            let _ = container.remove_sub_element(item);
+            if container.sub_elements().next().is_none() {
+                let _ = element.remove_sub_element(container);
+            }
+            return Err(error.into());
+        }
+        item
+    };
+    item.set_character_data(text)?;
+    Ok(())
+}
+
+// get the text for one language from a multi-language element like LONG-NAME or DESC
+fn get_multilanguage_text(element: &Element, container_name: ElementName, language: &str) -> Option<String> {
+    element
+        .get_sub_element(container_name)?
+        .sub_elements()
+        .find(|item| has_language(item, language))?
+        .character_data()?
+        .string_value()
+}
+
+// iterate over the (language, text) pairs of a multi-language element like LONG-NAME or DESC
+fn multilanguage_texts(
+    element: &Element,
+    container_name: ElementName,
+) -> impl Iterator<Item = (String, String)> + Send + use<> {
+    element
+        .get_sub_element(container_name)
+        .into_iter()
+        .flat_map(|container| container.sub_elements())
+        .filter_map(|item| {
+            let language = item.attribute_value(AttributeName::L)?.to_string();
+            let text = item.character_data()?.string_value()?;
+            Some((language, text))
+        })
+}
+
+fn has_language(item: &Element, language: &str) -> bool {
+    item.attribute_value(AttributeName::L)
+        .is_some_and(|lang| lang.to_string().eq_ignore_ascii_case(language))
+}
+
+// check if the name is equal to the base name, or if it is the base name with a suffix added by make_unique_name
+fn is_derived_name(name: &str, base_name: &str) -> bool {
+    name.strip_prefix(base_name).is_some_and(|rest| {
+        rest.is_empty()
+            || rest
+                .strip_prefix('_')
+                .is_some_and(|counter| !counter.is_empty() && counter.chars().all(|c| c.is_ascii_digit()))
+    })
+}
+
+// match a name against a glob pattern, where '*' matches any sequence of characters and '?' matches one character
+fn glob_match(pattern: &[char], name: &str) -> bool {
+    let name: Vec<char> = name.chars().collect();
+    let (mut p_idx, mut n_idx) = (0, 0);
+    // position of the last '*' in the pattern, and the position in the name where it started matching
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n_idx < name.len() {
+        match pattern.get(p_idx) {
+            Some('*') => {
+                backtrack = Some((p_idx, n_idx));
+                p_idx += 1;
+            }
+            Some(&c) if c == '?' || c == name[n_idx] => {
+                p_idx += 1;
+                n_idx += 1;
+            }
+            _ => {
+                // mismatch: let the last '*' consume one more character
+                let Some((star_idx, star_n_idx)) = backtrack else {
+                    return false;
+                };
+                backtrack = Some((star_idx, star_n_idx + 1));
+                p_idx = star_idx + 1;
+                n_idx = star_n_idx + 1;
+            }
+        }
+    }
+    pattern[p_idx..].iter().all(|&c| c == '*')
+}
+
+// set the item name of an element; a counter is appended to the name if it is already in use
+fn set_unique_item_name(element: &Element, name: &str) -> Result<String, AutosarAbstractionError> {
+    let parent = element.named_parent()?.ok_or(AutosarDataError::ItemDeleted)?;
+    let name = make_unique_generated_name(&element.model()?, &parent.path()?, name);
+    element.set_item_name(&name)?;
+    Ok(name)
+}
+
+//##################################################################
+
 /// check if the element is used anywhere
 pub(crate) fn is_used(element: &Element) -> bool {
     let Ok(model) = element.model() else {
@@ -460,14 +1463,23 @@ pub(crate) fn is_used(element: &Element) -> bool {
 //##################################################################
 
 // returns the named parent and the parent of each element that references the given element
+//
+// While a BulkEditSession is active, the result is stored in its reverse reference index and reused
+// as long as the references to the element don't change.
 pub(crate) fn get_reference_parents(element: &Element) -> Result<Vec<(Element, Element)>, AutosarAbstractionError> {
     let model = element.model()?;
     let path = element.path()?;
-    let references = model.get_references_to(&path);
-
-    let parents = references
+    let referrers: Vec<Element> = model
+        .get_references_to(&path)
         .iter()
         .filter_map(WeakElement::upgrade)
+        .collect();
+    if let Some(parents) = bulk_edit::cached_reference_parents(&model, &path, &referrers) {
+        return Ok(parents);
+    }
+
+    let parents: Vec<(Element, Element)> = referrers
+        .iter()
         .filter_map(|ref_elem| {
             Some((
                 ref_elem.named_parent().ok().flatten()?,
@@ -475,6 +1487,7 @@ pub(crate) fn get_reference_parents(element: &Element) -> Result<Vec<(Element, E
             ))
         })
         .collect();
+    bulk_edit::update_reference_parents(&model, path, referrers, parents.clone());
 
     Ok(parents)
 }
@@ -485,6 +1498,11 @@ pub(crate) fn get_reference_parents(element: &Element) -> Result<Vec<(Element, E
 mod test {
     use super::*;
     use autosar_data::AutosarModel;
+    use communication::{
+        AbstractFrame, AbstractPdu, CanAddressingMode, CanFrameType, CanPhysicalChannel, CommunicationDirection,
+        ISignalIPdu, SignalPdu, SystemSignal, TransferProperty,
+    };
+    use software_component::AbstractSwComponentType;
 
     #[test]
     fn create_model() {
@@ -605,4 +1623,509 @@ mod test {
         assert!(errors.is_empty());
         assert_eq!(model.files().count(), 2);
     }
+
+    #[test]
+    fn transaction() {
+        let model = AutosarModelAbstraction::create("file1.arxml", AutosarVersion::LATEST);
+        model.create_file("file2.arxml", AutosarVersion::LATEST).unwrap();
+        let package = model.get_or_create_package("/package").unwrap();
+        package.create_system("System", SystemCategory::SystemExtract).unwrap();
+
+        // a successful transaction keeps all changes
+        let value = model
+            .transaction(|tx| {
+                tx.get_or_create_package("/ok_package")?;
+                Ok(42)
+            })
+            .unwrap();
+        assert_eq!(value, 42);
+        assert!(model.get_element_by_path("/ok_package").is_some());
+
+        // a failed transaction rolls back all changes
+        let result = model.transaction(|tx| {
+            let package = tx.get_or_create_package("/package")?;
+            let system = package.create_system("Other", SystemCategory::SystemExtract)?;
+            system.create_ecu_instance("Ecu", &package)?;
+            // the name "System" is already in use
+            package.create_system("System", SystemCategory::SystemExtract)
+        });
+        assert!(result.is_err());
+        assert!(model.get_element_by_path("/package/System").is_some());
+        assert!(model.get_element_by_path("/package/Other").is_none());
+        assert!(model.get_element_by_path("/package/Ecu").is_none());
+        assert!(model.get_element_by_path("/ok_package").is_some());
+        assert_eq!(model.files().count(), 2);
+
+        // handles that were obtained before a failed transaction remain valid
+        let system = model.find_system().unwrap();
+        let result: Result<(), AutosarAbstractionError> = model.transaction(|_| {
+            system.set_category(SystemCategory::EcuExtract)?;
+            system.set_name("Renamed")?;
+            package.create_system("Other", SystemCategory::SystemExtract)?;
+            Err(AutosarAbstractionError::InvalidParameter("abort".to_string()))
+        });
+        assert!(matches!(result, Err(AutosarAbstractionError::InvalidParameter(_))));
+        // the renamed system was restored from the snapshot, the package handle is still valid
+        assert!(model.get_element_by_path("/package/System").is_some());
+        assert!(model.get_element_by_path("/package/Renamed").is_none());
+        assert!(model.get_element_by_path("/package/Other").is_none());
+        assert_eq!(package.element().path().unwrap(), "/package");
+        let system = model.find_system().unwrap();
+        assert_eq!(system.category(), Some(SystemCategory::SystemExtract));
+
+        // a value change on a handle is undone without invalidating the handle
+        let result: Result<(), AutosarAbstractionError> = model.transaction(|_| {
+            system.set_category(SystemCategory::EcuExtract)?;
+            Err(AutosarAbstractionError::InvalidParameter("abort".to_string()))
+        });
+        assert!(result.is_err());
+        assert_eq!(system.category(), Some(SystemCategory::SystemExtract));
+        assert_eq!(system.element().path().unwrap(), "/package/System");
+    }
+
+    #[test]
+    fn transaction_files() {
+        let model = AutosarModelAbstraction::create("file1.arxml", AutosarVersion::LATEST);
+        let file_2 = model.create_file("file2.arxml", AutosarVersion::LATEST).unwrap();
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let serialize_files = || {
+            let mut files: Vec<_> = model
+                .files()
+                .map(|file| (file.filename(), file.serialize().unwrap()))
+                .collect();
+            files.sort();
+            files
+        };
+        let files_before = serialize_files();
+        let (_, membership_before) = system.element().file_membership().unwrap();
+
+        // create a file, a cross-package reference and change the file membership, then fail
+        let result: Result<(), AutosarAbstractionError> = model.transaction(|tx| {
+            let file_3 = tx.create_file("file3.arxml", AutosarVersion::LATEST)?;
+            let other_package = tx.get_or_create_package("/other")?;
+            let syssignal = other_package.create_system_signal("SysSignal")?;
+            system.create_isignal("Signal", &package, 8, &syssignal, None)?;
+            tx.move_element_to_file(&other_package, &file_3)?;
+            tx.move_element_to_file(&system, &file_2)?;
+            Err(AutosarAbstractionError::InvalidParameter("abort".to_string()))
+        });
+        assert!(matches!(result, Err(AutosarAbstractionError::InvalidParameter(_))));
+
+        // the model is identical to the state before the transaction
+        assert_eq!(model.files().count(), 2);
+        assert_eq!(serialize_files(), files_before);
+        let (_, membership_after) = system.element().file_membership().unwrap();
+        assert_eq!(membership_after, membership_before);
+        assert!(model.get_element_by_path("/other").is_none());
+        assert!(model.get_element_by_path("/package/Signal").is_none());
+        assert_eq!(system.element().path().unwrap(), "/package/System");
+    }
+
+    #[test]
+    fn remove_with_report() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let syssignal = package.create_system_signal("SysSignal").unwrap();
+        let signal = system.create_isignal("Signal", &package, 8, &syssignal, None).unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        let mapping = pdu
+            .map_signal(
+                &signal,
+                0,
+                ByteOrder::MostSignificantByteLast,
+                None,
+                TransferProperty::Triggered,
+            )
+            .unwrap();
+        let mapping_path = mapping.element().path().unwrap();
+
+        // an operation that does not remove anything results in an empty report
+        let report = model.remove_with_report(|| Ok(())).unwrap();
+        assert_eq!(report, RemovalReport::default());
+
+        let report = model.remove_with_report(|| signal.remove(true)).unwrap();
+        let mut expected_elements = vec![
+            "/package/Signal".to_string(),
+            "/package/SysSignal".to_string(),
+            mapping_path.clone(),
+        ];
+        expected_elements.sort();
+        assert_eq!(report.removed_elements, expected_elements);
+        assert!(
+            report
+                .removed_references
+                .contains(&(mapping_path, "/package/Signal".to_string()))
+        );
+        assert!(
+            report
+                .removed_references
+                .contains(&("/package/Signal".to_string(), "/package/SysSignal".to_string()))
+        );
+        assert!(model.get_element_by_path("/package/Pdu").is_some());
+    }
+
+    #[test]
+    fn long_name_and_desc() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        assert_eq!(system.long_name("EN"), None);
+
+        system.set_long_name("EN", "The system").unwrap();
+        system.set_long_name("DE", "Das System").unwrap();
+        system.set_long_name("EN", "The updated system").unwrap();
+        assert_eq!(system.long_name("EN").as_deref(), Some("The updated system"));
+        assert_eq!(system.long_name("de").as_deref(), Some("Das System"));
+        assert_eq!(system.long_names().count(), 2);
+        let long_name = system.element().get_sub_element(ElementName::LongName).unwrap();
+        assert!(
+            long_name
+                .sub_elements()
+                .all(|item| item.element_name() == ElementName::L4)
+        );
+
+        system.set_desc("EN", "A description").unwrap();
+        assert_eq!(system.desc("EN").as_deref(), Some("A description"));
+        assert_eq!(system.desc("DE"), None);
+        assert_eq!(
+            system.descs().collect::<Vec<_>>(),
+            vec![("EN".to_string(), "A description".to_string())]
+        );
+        let desc = system.element().get_sub_element(ElementName::Desc).unwrap();
+        assert_eq!(desc.sub_elements().next().unwrap().element_name(), ElementName::L2);
+
+        // invalid languages are rejected without leaving incomplete elements behind
+        assert!(system.set_desc("not a language", "text").is_err());
+        assert_eq!(system.descs().count(), 1);
+    }
+
+    #[test]
+    fn rename_with_dependents() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let controller = ecu.create_can_communication_controller("Controller").unwrap();
+        controller.connect_physical_channel("connection", &channel).unwrap();
+
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        let pdu_mapping = frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let frame_port = frame_triggering
+            .connect_to_ecu(&ecu, CommunicationDirection::Out)
+            .unwrap();
+        let pdu_triggering = pdu.pdu_triggerings()[0].clone();
+        let pdu_port = pdu_triggering.pdu_ports().next().unwrap();
+        assert_eq!(pdu_triggering.name().unwrap(), "PT_Pdu");
+        assert_eq!(pdu_port.name().unwrap(), "PT_Pdu_Tx");
+
+        frame.rename_with_dependents("NewFrame").unwrap();
+        assert_eq!(frame.name().unwrap(), "NewFrame");
+        assert_eq!(frame_triggering.name().unwrap(), "FT_NewFrame");
+        assert_eq!(frame_port.name().unwrap(), "FT_NewFrame_Tx");
+
+        pdu.rename_with_dependents("NewPdu").unwrap();
+        assert_eq!(pdu.name().unwrap(), "NewPdu");
+        assert_eq!(pdu_mapping.name().unwrap(), "NewPdu");
+        assert_eq!(pdu_triggering.name().unwrap(), "PT_NewPdu");
+        assert_eq!(pdu_port.name().unwrap(), "PT_NewPdu_Tx");
+
+        // names that do not follow the naming conventions are not changed
+        pdu_triggering.set_name("CustomName").unwrap();
+        pdu.rename_with_dependents("Pdu").unwrap();
+        assert_eq!(pdu_mapping.name().unwrap(), "Pdu");
+        assert_eq!(pdu_triggering.name().unwrap(), "CustomName");
+        assert_eq!(pdu_port.name().unwrap(), "PT_NewPdu_Tx");
+    }
+
+    #[test]
+    fn duplicate() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let variant_package = model.get_or_create_package("/variant").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+
+        // copy a PDU together with its signal mappings
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        let syssignal = package.create_system_signal("SysSignal").unwrap();
+        let signal = system.create_isignal("Signal", &package, 8, &syssignal, None).unwrap();
+        pdu.map_signal(
+            &signal,
+            0,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+        let pdu_copy = pdu.duplicate("PduCopy", &package).unwrap();
+        assert_eq!(pdu_copy.name().unwrap(), "PduCopy");
+        assert_eq!(pdu_copy.length(), Some(8));
+        assert_eq!(pdu_copy.mapped_signals().count(), 1);
+        assert_eq!(pdu_copy.mapped_signals().next().unwrap().signal().unwrap(), signal);
+        assert_eq!(signal.mappings().len(), 2);
+
+        // copy a software component type; the timing event must trigger the runnable of the copy
+        let swc = package.create_application_sw_component_type("Swc").unwrap();
+        let behavior = swc.create_swc_internal_behavior("Behavior").unwrap();
+        let runnable = behavior.create_runnable_entity("Runnable").unwrap();
+        behavior.create_timing_event("TimingEvent", &runnable, 0.1).unwrap();
+        let swc_copy = swc.duplicate("Swc", &variant_package).unwrap();
+        assert_eq!(swc_copy.element().path().unwrap(), "/variant/Swc");
+        let runnable_copy = swc_copy
+            .swc_internal_behaviors()
+            .next()
+            .unwrap()
+            .runnable_entities()
+            .next()
+            .unwrap();
+        assert_ne!(runnable_copy, runnable);
+        assert_eq!(runnable.events().len(), 1);
+        assert_eq!(runnable_copy.events().len(), 1);
+
+        // the name is already in use; no renamed copy is left behind
+        let element_count = variant_package.elements().count();
+        let result = swc.duplicate("Swc", &variant_package);
+        assert!(matches!(result, Err(AutosarAbstractionError::ItemAlreadyExists)));
+        assert_eq!(variant_package.elements().count(), element_count);
+        assert!(model.get_element_by_path("/variant/Swc_1").is_none());
+    }
+
+    #[test]
+    fn name_conflict_policy() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system_signal = package.create_system_signal("Sig").unwrap();
+        assert_eq!(model.name_conflict_policy(), NameConflictPolicy::Suffix);
+        assert_eq!(make_unique_name(model.model(), "/pkg", "Sig").unwrap(), "Sig_1");
+
+        // the policy is shared by all abstractions of the same model
+        let model_2 = AutosarModelAbstraction::new(model.model().clone());
+        model_2.set_name_conflict_policy(NameConflictPolicy::Error);
+        assert_eq!(model.name_conflict_policy(), NameConflictPolicy::Error);
+        let result = make_unique_name(model.model(), "/pkg", "Sig");
+        assert!(matches!(result, Err(AutosarAbstractionError::ItemAlreadyExists)));
+        assert_eq!(make_unique_name(model.model(), "/pkg", "Other").unwrap(), "Other");
+
+        // other models are not affected
+        let other_model = AutosarModelAbstraction::create("other", AutosarVersion::LATEST);
+        assert_eq!(other_model.name_conflict_policy(), NameConflictPolicy::Suffix);
+
+        model.set_name_conflict_policy(NameConflictPolicy::Overwrite);
+        assert_eq!(make_unique_name(model.model(), "/pkg", "Sig").unwrap(), "Sig");
+        assert!(system_signal.element().path().is_err());
+
+        model.set_name_conflict_policy(NameConflictPolicy::Suffix);
+        assert_eq!(model.name_conflict_policy(), NameConflictPolicy::Suffix);
+    }
+
+    #[test]
+    fn name_conflict_policy_overwrite_referenced() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let controller = ecu.create_can_communication_controller("Controller").unwrap();
+        controller.connect_physical_channel("connection", &channel).unwrap();
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        frame_triggering
+            .connect_to_ecu(&ecu, CommunicationDirection::Out)
+            .unwrap();
+        let pdu_triggering = pdu.pdu_triggerings()[0].clone();
+        let pdu_port = pdu_triggering.pdu_ports().next().unwrap();
+        let pt_path = pdu_triggering.element().path().unwrap();
+        let port_path = pdu_port.element().path().unwrap();
+        assert!(!model.model().get_references_to(&pt_path).is_empty());
+        assert!(!model.model().get_references_to(&port_path).is_empty());
+
+        // the referenced pdu triggering is removed together with its port, and no references to either remain
+        model.set_name_conflict_policy(NameConflictPolicy::Overwrite);
+        let channel_path = channel.element().path().unwrap();
+        assert_eq!(
+            make_unique_name(model.model(), &channel_path, "PT_Pdu").unwrap(),
+            "PT_Pdu"
+        );
+        assert!(pdu_triggering.element().path().is_err());
+        assert!(pdu_port.element().path().is_err());
+        assert!(model.model().get_references_to(&pt_path).is_empty());
+        assert!(model.model().get_references_to(&port_path).is_empty());
+        assert!(pdu.pdu_triggerings().is_empty());
+
+        // other elements can't be overwritten while they are referenced
+        let syssignal = package.create_system_signal("SysSignal").unwrap();
+        system.create_isignal("Signal", &package, 8, &syssignal, None).unwrap();
+        let result = make_unique_name(model.model(), "/package", "SysSignal");
+        assert!(matches!(result, Err(AutosarAbstractionError::InvalidParameter(_))));
+        assert_eq!(syssignal.element().path().unwrap(), "/package/SysSignal");
+
+        model.set_name_conflict_policy(NameConflictPolicy::Suffix);
+    }
+
+    #[test]
+    fn name_conflict_policy_pruning() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        model.set_name_conflict_policy(NameConflictPolicy::Error);
+        let weak_root = model.root_element().downgrade();
+        drop(model);
+        assert!(weak_root.upgrade().is_none());
+
+        // the lookup for another model prunes the entry of the dropped model
+        let other_model = AutosarModelAbstraction::create("other", AutosarVersion::LATEST);
+        assert_eq!(other_model.name_conflict_policy(), NameConflictPolicy::Suffix);
+        let policies = NAME_CONFLICT_POLICIES
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        assert!(policies.iter().all(|(weak_root, _)| weak_root.upgrade().is_some()));
+    }
+
+    #[test]
+    fn generated_names_ignore_policy() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system_signal = package.create_system_signal("Sig").unwrap();
+
+        for policy in [
+            NameConflictPolicy::Suffix,
+            NameConflictPolicy::Error,
+            NameConflictPolicy::Overwrite,
+        ] {
+            model.set_name_conflict_policy(policy);
+            assert_eq!(make_unique_generated_name(model.model(), "/pkg", "Sig"), "Sig_1");
+            assert_eq!(make_unique_generated_name(model.model(), "/pkg", "Other"), "Other");
+            // the existing element is never removed
+            assert_eq!(system_signal.element().path().unwrap(), "/pkg/Sig");
+        }
+        model.set_name_conflict_policy(NameConflictPolicy::Suffix);
+    }
+
+    #[test]
+    fn find_by_name() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let sub_package = model.get_or_create_package("/pkg/sub").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let pdu_1 = system.create_isignal_ipdu("EngineSpeed", &package, 8).unwrap();
+        let pdu_2 = system.create_isignal_ipdu("EngineTemp", &sub_package, 8).unwrap();
+        system.create_isignal_ipdu("BrakeStatus", &package, 8).unwrap();
+        package.create_system_signal("EngineSpeed").unwrap();
+        let cluster = system.create_can_cluster("EngineCan", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("EngineChannel").unwrap();
+
+        let mut pdus: Vec<ISignalIPdu> = model.find_by_name("Engine*").collect();
+        pdus.sort_by_key(|pdu| pdu.name());
+        assert_eq!(pdus, vec![pdu_1.clone(), pdu_2]);
+        let pdus: Vec<ISignalIPdu> = model.find_by_name("Engine?peed").collect();
+        assert_eq!(pdus, vec![pdu_1]);
+        assert_eq!(model.find_by_name::<ISignalIPdu>("*Status").count(), 1);
+        assert_eq!(model.find_by_name::<ISignalIPdu>("*").count(), 3);
+        assert_eq!(model.find_by_name::<ISignalIPdu>("Engine").count(), 0);
+        assert_eq!(model.find_by_name::<SystemSignal>("EngineSpeed").count(), 1);
+
+        // elements nested inside other elements are found as well
+        let channels: Vec<CanPhysicalChannel> = model.find_by_name("*Channel").collect();
+        assert_eq!(channels, vec![channel]);
+
+        let pdus: Vec<ISignalIPdu> = model
+            .find_by_name_matching(|name| name.len() == 10 && name.starts_with("Engine"))
+            .collect();
+        assert_eq!(pdus.len(), 1);
+    }
+
+    #[test]
+    fn glob_matching() {
+        let glob = |pattern: &str, name: &str| glob_match(&pattern.chars().collect::<Vec<_>>(), name);
+        assert!(glob("", ""));
+        assert!(!glob("", "a"));
+        assert!(glob("*", ""));
+        assert!(glob("a*b*c", "aXbYbZc"));
+        assert!(glob("*ab", "aab"));
+        assert!(!glob("a*b", "aXbY"));
+        assert!(glob("a?c", "abc"));
+        assert!(!glob("a?c", "ac"));
+        assert!(glob("**x", "yyx"));
+    }
+
+    #[test]
+    fn thread_safety() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        fn assert_send<T: Send>(_: &T) {}
+
+        assert_send_sync::<AutosarModelAbstraction>();
+        assert_send_sync::<ArPackage>();
+        assert_send_sync::<System>();
+        assert_send_sync::<EcuInstance>();
+        assert_send_sync::<communication::Cluster>();
+        assert_send_sync::<communication::PhysicalChannel>();
+        assert_send_sync::<communication::Frame>();
+        assert_send_sync::<communication::FrameTriggering>();
+        assert_send_sync::<communication::Pdu>();
+        assert_send_sync::<communication::PduTriggering>();
+        assert_send_sync::<communication::ISignal>();
+        assert_send_sync::<communication::ISignalTriggering>();
+        assert_send_sync::<software_component::SwComponentType>();
+        assert_send_sync::<datatype::AutosarDataType>();
+        assert_send_sync::<AutosarAbstractionError>();
+
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        for idx in 0..100 {
+            let pdu = system.create_isignal_ipdu(&format!("Pdu{idx}"), &package, 8).unwrap();
+            let syssignal = package.create_system_signal(&format!("Sig{idx}")).unwrap();
+            let signal = system
+                .create_isignal(&format!("Sig{idx}"), &package, 8, &syssignal, None)
+                .unwrap();
+            pdu.map_signal(
+                &signal,
+                0,
+                ByteOrder::MostSignificantByteLast,
+                None,
+                TransferProperty::Triggered,
+            )
+            .unwrap();
+        }
+        assert_send(&system.pdus());
+        assert_send(&model.packages());
+
+        // concurrent read access from several threads
+        let pdus: Vec<_> = system.pdus().collect();
+        let results: Vec<(usize, usize)> = std::thread::scope(|scope| {
+            let workers: Vec<_> = pdus
+                .chunks(10)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        let names = chunk.iter().filter_map(|pdu| pdu.name()).count();
+                        let signals = chunk
+                            .iter()
+                            .filter_map(|pdu| match pdu {
+                                communication::Pdu::ISignalIPdu(ipdu) => Some(ipdu.mapped_signals().count()),
+                                _ => None,
+                            })
+                            .sum();
+                        (names, signals)
+                    })
+                })
+                .collect();
+            workers.into_iter().map(|worker| worker.join().unwrap()).collect()
+        });
+        assert_eq!(results.len(), 10);
+        assert!(results.iter().all(|(names, signals)| *names == 10 && *signals == 10));
+    }
 }
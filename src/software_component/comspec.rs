@@ -0,0 +1,681 @@
+use crate::{
+    AbstractionElement, AutosarAbstractionError, abstraction_element,
+    datatype::ValueSpecification,
+    software_component::{ClientServerOperation, ParameterDataPrototype, VariableDataPrototype},
+};
+use autosar_data::{Element, ElementName};
+
+//##################################################################
+
+/// A `NonqueuedSenderComSpec` contains the communication attributes of a data element on a provided port
+/// with "last-is-best" semantics
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonqueuedSenderComSpec(Element);
+abstraction_element!(NonqueuedSenderComSpec, NonqueuedSenderComSpec);
+
+impl NonqueuedSenderComSpec {
+    pub(crate) fn new(
+        parent_element: &Element,
+        data_element: &VariableDataPrototype,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let com_spec = parent_element.create_sub_element(ElementName::NonqueuedSenderComSpec)?;
+        com_spec
+            .create_sub_element(ElementName::DataElementRef)?
+            .set_reference_target(data_element.element())?;
+
+        Ok(Self(com_spec))
+    }
+
+    /// get the data element that is described by this com spec
+    #[must_use]
+    pub fn data_element(&self) -> Option<VariableDataPrototype> {
+        data_element(self.element())
+    }
+
+    /// set the init value of the data element on this port
+    pub fn set_init_value<T: Into<ValueSpecification>>(
+        &self,
+        value_spec: Option<T>,
+    ) -> Result<(), AutosarAbstractionError> {
+        set_init_value(self.element(), value_spec)
+    }
+
+    /// get the init value of the data element on this port
+    #[must_use]
+    pub fn init_value(&self) -> Option<ValueSpecification> {
+        init_value(self.element())
+    }
+}
+
+//##################################################################
+
+/// A `NonqueuedReceiverComSpec` contains the communication attributes of a data element on a required port
+/// with "last-is-best" semantics
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonqueuedReceiverComSpec(Element);
+abstraction_element!(NonqueuedReceiverComSpec, NonqueuedReceiverComSpec);
+
+impl NonqueuedReceiverComSpec {
+    pub(crate) fn new(
+        parent_element: &Element,
+        data_element: &VariableDataPrototype,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let com_spec = parent_element.create_sub_element(ElementName::NonqueuedReceiverComSpec)?;
+        com_spec
+            .create_sub_element(ElementName::DataElementRef)?
+            .set_reference_target(data_element.element())?;
+
+        Ok(Self(com_spec))
+    }
+
+    /// get the data element that is described by this com spec
+    #[must_use]
+    pub fn data_element(&self) -> Option<VariableDataPrototype> {
+        data_element(self.element())
+    }
+
+    /// set the init value of the data element on this port
+    pub fn set_init_value<T: Into<ValueSpecification>>(
+        &self,
+        value_spec: Option<T>,
+    ) -> Result<(), AutosarAbstractionError> {
+        set_init_value(self.element(), value_spec)
+    }
+
+    /// get the init value of the data element on this port
+    #[must_use]
+    pub fn init_value(&self) -> Option<ValueSpecification> {
+        init_value(self.element())
+    }
+
+    /// set the timeout in seconds after which the data is considered to be outdated
+    ///
+    /// A timeout of 0 disables the timeout monitoring.
+    pub fn set_alive_timeout(&self, alive_timeout: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        if let Some(alive_timeout) = alive_timeout {
+            self.element()
+                .get_or_create_sub_element(ElementName::AliveTimeout)?
+                .set_character_data(alive_timeout)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::AliveTimeout);
+        }
+        Ok(())
+    }
+
+    /// get the timeout in seconds after which the data is considered to be outdated
+    #[must_use]
+    pub fn alive_timeout(&self) -> Option<f64> {
+        self.element()
+            .get_sub_element(ElementName::AliveTimeout)?
+            .character_data()?
+            .parse_float()
+    }
+
+    /// set whether the receiver is notified if the data has never been received
+    pub fn set_handle_never_received(
+        &self,
+        handle_never_received: Option<bool>,
+    ) -> Result<(), AutosarAbstractionError> {
+        if let Some(handle_never_received) = handle_never_received {
+            self.element()
+                .get_or_create_sub_element(ElementName::HandleNeverReceived)?
+                .set_character_data(handle_never_received)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::HandleNeverReceived);
+        }
+        Ok(())
+    }
+
+    /// get whether the receiver is notified if the data has never been received
+    #[must_use]
+    pub fn handle_never_received(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::HandleNeverReceived)?
+            .character_data()?
+            .parse_bool()
+    }
+}
+
+//##################################################################
+
+/// A `QueuedReceiverComSpec` contains the communication attributes of a data element on a required port
+/// with queued semantics
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueuedReceiverComSpec(Element);
+abstraction_element!(QueuedReceiverComSpec, QueuedReceiverComSpec);
+
+impl QueuedReceiverComSpec {
+    pub(crate) fn new(
+        parent_element: &Element,
+        data_element: &VariableDataPrototype,
+        queue_length: u32,
+    ) -> Result<Self, AutosarAbstractionError> {
+        check_queue_length(queue_length)?;
+        let com_spec = parent_element.create_sub_element(ElementName::QueuedReceiverComSpec)?;
+        com_spec
+            .create_sub_element(ElementName::DataElementRef)?
+            .set_reference_target(data_element.element())?;
+        let com_spec = Self(com_spec);
+        com_spec.set_queue_length(queue_length)?;
+
+        Ok(com_spec)
+    }
+
+    /// get the data element that is described by this com spec
+    #[must_use]
+    pub fn data_element(&self) -> Option<VariableDataPrototype> {
+        data_element(self.element())
+    }
+
+    /// set the length of the receive queue
+    pub fn set_queue_length(&self, queue_length: u32) -> Result<(), AutosarAbstractionError> {
+        set_queue_length(self.element(), queue_length)
+    }
+
+    /// get the length of the receive queue
+    #[must_use]
+    pub fn queue_length(&self) -> Option<u32> {
+        queue_length(self.element())
+    }
+}
+
+//##################################################################
+
+/// A `ClientComSpec` contains the communication attributes of an operation on a required client/server port
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClientComSpec(Element);
+abstraction_element!(ClientComSpec, ClientComSpec);
+
+impl ClientComSpec {
+    pub(crate) fn new(
+        parent_element: &Element,
+        operation: &ClientServerOperation,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let com_spec = parent_element.create_sub_element(ElementName::ClientComSpec)?;
+        com_spec
+            .create_sub_element(ElementName::OperationRef)?
+            .set_reference_target(operation.element())?;
+
+        Ok(Self(com_spec))
+    }
+
+    /// get the operation that is described by this com spec
+    #[must_use]
+    pub fn operation(&self) -> Option<ClientServerOperation> {
+        operation(self.element())
+    }
+}
+
+//##################################################################
+
+/// A `ServerComSpec` contains the communication attributes of an operation on a provided client/server port
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServerComSpec(Element);
+abstraction_element!(ServerComSpec, ServerComSpec);
+
+impl ServerComSpec {
+    pub(crate) fn new(
+        parent_element: &Element,
+        operation: &ClientServerOperation,
+        queue_length: u32,
+    ) -> Result<Self, AutosarAbstractionError> {
+        check_queue_length(queue_length)?;
+        let com_spec = parent_element.create_sub_element(ElementName::ServerComSpec)?;
+        com_spec
+            .create_sub_element(ElementName::OperationRef)?
+            .set_reference_target(operation.element())?;
+        let com_spec = Self(com_spec);
+        com_spec.set_queue_length(queue_length)?;
+
+        Ok(com_spec)
+    }
+
+    /// get the operation that is described by this com spec
+    #[must_use]
+    pub fn operation(&self) -> Option<ClientServerOperation> {
+        operation(self.element())
+    }
+
+    /// set the length of the queue for incoming requests
+    pub fn set_queue_length(&self, queue_length: u32) -> Result<(), AutosarAbstractionError> {
+        set_queue_length(self.element(), queue_length)
+    }
+
+    /// get the length of the queue for incoming requests
+    #[must_use]
+    pub fn queue_length(&self) -> Option<u32> {
+        queue_length(self.element())
+    }
+}
+
+//##################################################################
+
+/// A `ParameterProvideComSpec` contains the communication attributes of a parameter on a provided port
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParameterProvideComSpec(Element);
+abstraction_element!(ParameterProvideComSpec, ParameterProvideComSpec);
+
+impl ParameterProvideComSpec {
+    pub(crate) fn new(
+        parent_element: &Element,
+        parameter: &ParameterDataPrototype,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let com_spec = parent_element.create_sub_element(ElementName::ParameterProvideComSpec)?;
+        com_spec
+            .create_sub_element(ElementName::ParameterRef)?
+            .set_reference_target(parameter.element())?;
+
+        Ok(Self(com_spec))
+    }
+
+    /// get the parameter that is described by this com spec
+    #[must_use]
+    pub fn parameter(&self) -> Option<ParameterDataPrototype> {
+        parameter(self.element())
+    }
+
+    /// set the init value of the parameter on this port
+    pub fn set_init_value<T: Into<ValueSpecification>>(
+        &self,
+        value_spec: Option<T>,
+    ) -> Result<(), AutosarAbstractionError> {
+        set_init_value(self.element(), value_spec)
+    }
+
+    /// get the init value of the parameter on this port
+    #[must_use]
+    pub fn init_value(&self) -> Option<ValueSpecification> {
+        init_value(self.element())
+    }
+}
+
+//##################################################################
+
+/// A `ParameterRequireComSpec` contains the communication attributes of a parameter on a required port
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParameterRequireComSpec(Element);
+abstraction_element!(ParameterRequireComSpec, ParameterRequireComSpec);
+
+impl ParameterRequireComSpec {
+    pub(crate) fn new(
+        parent_element: &Element,
+        parameter: &ParameterDataPrototype,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let com_spec = parent_element.create_sub_element(ElementName::ParameterRequireComSpec)?;
+        com_spec
+            .create_sub_element(ElementName::ParameterRef)?
+            .set_reference_target(parameter.element())?;
+
+        Ok(Self(com_spec))
+    }
+
+    /// get the parameter that is described by this com spec
+    #[must_use]
+    pub fn parameter(&self) -> Option<ParameterDataPrototype> {
+        parameter(self.element())
+    }
+
+    /// set the init value of the parameter on this port
+    ///
+    /// The init value is used if the port is not connected to a provider of the parameter.
+    pub fn set_init_value<T: Into<ValueSpecification>>(
+        &self,
+        value_spec: Option<T>,
+    ) -> Result<(), AutosarAbstractionError> {
+        set_init_value(self.element(), value_spec)
+    }
+
+    /// get the init value of the parameter on this port
+    #[must_use]
+    pub fn init_value(&self) -> Option<ValueSpecification> {
+        init_value(self.element())
+    }
+}
+
+//##################################################################
+
+/// The `PortComSpec` enum represents all kinds of com specs that are supported on port prototypes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PortComSpec {
+    /// com spec of a data element on a provided sender/receiver port
+    NonqueuedSender(NonqueuedSenderComSpec),
+    /// com spec of a data element on a required sender/receiver port
+    NonqueuedReceiver(NonqueuedReceiverComSpec),
+    /// com spec of a queued data element on a required sender/receiver port
+    QueuedReceiver(QueuedReceiverComSpec),
+    /// com spec of an operation on a required client/server port
+    Client(ClientComSpec),
+    /// com spec of an operation on a provided client/server port
+    Server(ServerComSpec),
+    /// com spec of a parameter on a provided parameter port
+    ParameterProvide(ParameterProvideComSpec),
+    /// com spec of a parameter on a required parameter port
+    ParameterRequire(ParameterRequireComSpec),
+}
+
+impl AbstractionElement for PortComSpec {
+    fn element(&self) -> &Element {
+        match self {
+            PortComSpec::NonqueuedSender(com_spec) => com_spec.element(),
+            PortComSpec::NonqueuedReceiver(com_spec) => com_spec.element(),
+            PortComSpec::QueuedReceiver(com_spec) => com_spec.element(),
+            PortComSpec::Client(com_spec) => com_spec.element(),
+            PortComSpec::Server(com_spec) => com_spec.element(),
+            PortComSpec::ParameterProvide(com_spec) => com_spec.element(),
+            PortComSpec::ParameterRequire(com_spec) => com_spec.element(),
+        }
+    }
+}
+
+impl TryFrom<Element> for PortComSpec {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        match element.element_name() {
+            ElementName::NonqueuedSenderComSpec => Ok(PortComSpec::NonqueuedSender(NonqueuedSenderComSpec(element))),
+            ElementName::NonqueuedReceiverComSpec => {
+                Ok(PortComSpec::NonqueuedReceiver(NonqueuedReceiverComSpec(element)))
+            }
+            ElementName::QueuedReceiverComSpec => Ok(PortComSpec::QueuedReceiver(QueuedReceiverComSpec(element))),
+            ElementName::ClientComSpec => Ok(PortComSpec::Client(ClientComSpec(element))),
+            ElementName::ServerComSpec => Ok(PortComSpec::Server(ServerComSpec(element))),
+            ElementName::ParameterProvideComSpec => Ok(PortComSpec::ParameterProvide(ParameterProvideComSpec(element))),
+            ElementName::ParameterRequireComSpec => Ok(PortComSpec::ParameterRequire(ParameterRequireComSpec(element))),
+            _ => Err(AutosarAbstractionError::ConversionError {
+                element,
+                dest: "PortComSpec".to_string(),
+            }),
+        }
+    }
+}
+
+impl From<NonqueuedSenderComSpec> for PortComSpec {
+    fn from(com_spec: NonqueuedSenderComSpec) -> Self {
+        PortComSpec::NonqueuedSender(com_spec)
+    }
+}
+
+impl From<NonqueuedReceiverComSpec> for PortComSpec {
+    fn from(com_spec: NonqueuedReceiverComSpec) -> Self {
+        PortComSpec::NonqueuedReceiver(com_spec)
+    }
+}
+
+impl From<QueuedReceiverComSpec> for PortComSpec {
+    fn from(com_spec: QueuedReceiverComSpec) -> Self {
+        PortComSpec::QueuedReceiver(com_spec)
+    }
+}
+
+impl From<ClientComSpec> for PortComSpec {
+    fn from(com_spec: ClientComSpec) -> Self {
+        PortComSpec::Client(com_spec)
+    }
+}
+
+impl From<ServerComSpec> for PortComSpec {
+    fn from(com_spec: ServerComSpec) -> Self {
+        PortComSpec::Server(com_spec)
+    }
+}
+
+impl From<ParameterProvideComSpec> for PortComSpec {
+    fn from(com_spec: ParameterProvideComSpec) -> Self {
+        PortComSpec::ParameterProvide(com_spec)
+    }
+}
+
+impl From<ParameterRequireComSpec> for PortComSpec {
+    fn from(com_spec: ParameterRequireComSpec) -> Self {
+        PortComSpec::ParameterRequire(com_spec)
+    }
+}
+
+//##################################################################
+
+fn data_element(com_spec: &Element) -> Option<VariableDataPrototype> {
+    com_spec
+        .get_sub_element(ElementName::DataElementRef)?
+        .get_reference_target()
+        .ok()?
+        .try_into()
+        .ok()
+}
+
+fn operation(com_spec: &Element) -> Option<ClientServerOperation> {
+    com_spec
+        .get_sub_element(ElementName::OperationRef)?
+        .get_reference_target()
+        .ok()?
+        .try_into()
+        .ok()
+}
+
+fn parameter(com_spec: &Element) -> Option<ParameterDataPrototype> {
+    com_spec
+        .get_sub_element(ElementName::ParameterRef)?
+        .get_reference_target()
+        .ok()?
+        .try_into()
+        .ok()
+}
+
+fn set_init_value<T: Into<ValueSpecification>>(
+    com_spec: &Element,
+    value_spec: Option<T>,
+) -> Result<(), AutosarAbstractionError> {
+    let _ = com_spec.remove_sub_element_kind(ElementName::InitValue);
+    if let Some(value_spec) = value_spec {
+        let value_spec: ValueSpecification = value_spec.into();
+        let init_value_elem = com_spec.create_sub_element(ElementName::InitValue)?;
+        value_spec.store(&init_value_elem)?;
+    }
+    Ok(())
+}
+
+fn init_value(com_spec: &Element) -> Option<ValueSpecification> {
+    let init_value_elem = com_spec
+        .get_sub_element(ElementName::InitValue)?
+        .get_sub_element_at(0)?;
+    ValueSpecification::load(&init_value_elem)
+}
+
+fn check_queue_length(queue_length: u32) -> Result<(), AutosarAbstractionError> {
+    if queue_length == 0 {
+        return Err(AutosarAbstractionError::InvalidParameter(
+            "The queue length must be at least 1".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn set_queue_length(com_spec: &Element, queue_length: u32) -> Result<(), AutosarAbstractionError> {
+    check_queue_length(queue_length)?;
+    com_spec
+        .get_or_create_sub_element(ElementName::QueueLength)?
+        .set_character_data(u64::from(queue_length))?;
+    Ok(())
+}
+
+fn queue_length(com_spec: &Element) -> Option<u32> {
+    com_spec
+        .get_sub_element(ElementName::QueueLength)?
+        .character_data()?
+        .parse_integer()
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AutosarModelAbstraction,
+        datatype::{BaseTypeEncoding, ImplementationDataTypeSettings},
+        software_component::{AbstractSwComponentType, PortInterface},
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn sender_receiver_com_specs() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let base_type = package
+            .create_sw_base_type("uint8", 8, BaseTypeEncoding::None, None, None, None)
+            .unwrap();
+        let data_type = package
+            .create_implementation_data_type(&ImplementationDataTypeSettings::Value {
+                name: "uint8_t".to_string(),
+                base_type,
+                compu_method: None,
+                data_constraint: None,
+            })
+            .unwrap();
+        let interface = package.create_sender_receiver_interface("sr_interface").unwrap();
+        let data_element = interface.create_data_element("data", &data_type).unwrap();
+        let queued_element = interface.create_data_element("queued", &data_type).unwrap();
+
+        let comp = package.create_application_sw_component_type("comp").unwrap();
+        let p_port = comp.create_p_port("p_port", &interface).unwrap();
+        let r_port = comp.create_r_port("r_port", &interface).unwrap();
+
+        let sender_com_spec = p_port.create_nonqueued_sender_com_spec(&data_element).unwrap();
+        assert_eq!(sender_com_spec.data_element(), Some(data_element.clone()));
+        sender_com_spec
+            .set_init_value(Some(ValueSpecification::numerical(1.0)))
+            .unwrap();
+        assert_eq!(sender_com_spec.init_value(), Some(ValueSpecification::numerical(1.0)));
+        sender_com_spec.set_init_value::<ValueSpecification>(None).unwrap();
+        assert_eq!(sender_com_spec.init_value(), None);
+
+        let receiver_com_spec = r_port.create_nonqueued_receiver_com_spec(&data_element).unwrap();
+        assert_eq!(receiver_com_spec.data_element(), Some(data_element.clone()));
+        receiver_com_spec.set_alive_timeout(Some(0.5)).unwrap();
+        assert_eq!(receiver_com_spec.alive_timeout(), Some(0.5));
+        receiver_com_spec.set_handle_never_received(Some(true)).unwrap();
+        assert_eq!(receiver_com_spec.handle_never_received(), Some(true));
+        receiver_com_spec.set_alive_timeout(None).unwrap();
+        receiver_com_spec.set_handle_never_received(None).unwrap();
+        assert_eq!(receiver_com_spec.alive_timeout(), None);
+        assert_eq!(receiver_com_spec.handle_never_received(), None);
+
+        let queued_com_spec = r_port.create_queued_receiver_com_spec(&queued_element, 4).unwrap();
+        assert_eq!(queued_com_spec.data_element(), Some(queued_element.clone()));
+        assert_eq!(queued_com_spec.queue_length(), Some(4));
+        assert!(queued_com_spec.set_queue_length(0).is_err());
+        // a com spec with an empty queue is rejected without leaving a partial com spec behind
+        assert!(r_port.create_queued_receiver_com_spec(&queued_element, 0).is_err());
+        assert_eq!(r_port.com_specs().count(), 2);
+
+        assert_eq!(p_port.com_specs().count(), 1);
+        let r_com_specs: Vec<PortComSpec> = r_port.com_specs().collect();
+        assert_eq!(
+            r_com_specs,
+            vec![receiver_com_spec.into(), queued_com_spec.clone().into()]
+        );
+
+        // data elements of other interfaces are rejected
+        let other_interface = package.create_sender_receiver_interface("other_interface").unwrap();
+        let other_element = other_interface.create_data_element("other", &data_type).unwrap();
+        assert!(r_port.create_nonqueued_receiver_com_spec(&other_element).is_err());
+
+        // removing the data element also removes its com specs
+        queued_element.remove(false).unwrap();
+        assert_eq!(r_port.com_specs().count(), 1);
+    }
+
+    #[test]
+    fn client_server_com_specs() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let interface = package.create_client_server_interface("cs_interface").unwrap();
+        let operation = interface.create_operation("op").unwrap();
+
+        let comp = package.create_application_sw_component_type("comp").unwrap();
+        let p_port = comp.create_p_port("p_port", &interface).unwrap();
+        let r_port = comp.create_r_port("r_port", &interface).unwrap();
+        let pr_port = comp.create_pr_port("pr_port", &interface).unwrap();
+
+        let server_com_spec = p_port.create_server_com_spec(&operation, 2).unwrap();
+        assert_eq!(server_com_spec.operation(), Some(operation.clone()));
+        assert_eq!(server_com_spec.queue_length(), Some(2));
+        assert!(p_port.create_server_com_spec(&operation, 0).is_err());
+        assert_eq!(p_port.com_specs().count(), 1);
+
+        let client_com_spec = r_port.create_client_com_spec(&operation).unwrap();
+        assert_eq!(client_com_spec.operation(), Some(operation.clone()));
+
+        // a PR port has both provided and required com specs
+        pr_port.create_server_com_spec(&operation, 1).unwrap();
+        pr_port.create_client_com_spec(&operation).unwrap();
+        assert_eq!(pr_port.com_specs().count(), 2);
+
+        // the operation of a sender/receiver port can't be used
+        let sr_interface = package.create_sender_receiver_interface("sr_interface").unwrap();
+        let sr_port = comp.create_r_port("sr_port", &sr_interface).unwrap();
+        assert!(sr_port.create_client_com_spec(&operation).is_err());
+
+        operation.remove(false).unwrap();
+        assert_eq!(p_port.com_specs().count(), 0);
+        assert_eq!(r_port.com_specs().count(), 0);
+    }
+
+    #[test]
+    fn parameter_and_trigger_ports() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let base_type = package
+            .create_sw_base_type("uint8", 8, BaseTypeEncoding::None, None, None, None)
+            .unwrap();
+        let data_type = package
+            .create_implementation_data_type(&ImplementationDataTypeSettings::Value {
+                name: "uint8_t".to_string(),
+                base_type,
+                compu_method: None,
+                data_constraint: None,
+            })
+            .unwrap();
+        let parameter_interface = package.create_parameter_interface("param_interface").unwrap();
+        let parameter = parameter_interface.create_parameter("param", &data_type).unwrap();
+        let trigger_interface = package.create_trigger_interface("trigger_interface").unwrap();
+        trigger_interface.create_trigger("trigger").unwrap();
+
+        let comp = package.create_application_sw_component_type("comp").unwrap();
+        let p_port = comp.create_p_port("param_p_port", &parameter_interface).unwrap();
+        let r_port = comp.create_r_port("param_r_port", &parameter_interface).unwrap();
+        // parameter interfaces are not allowed on PR ports
+        assert!(comp.create_pr_port("param_pr_port", &parameter_interface).is_err());
+        let trigger_p_port = comp.create_p_port("trigger_p_port", &trigger_interface).unwrap();
+        let trigger_r_port = comp.create_r_port("trigger_r_port", &trigger_interface).unwrap();
+        assert_eq!(
+            trigger_p_port.port_interface(),
+            Some(PortInterface::TriggerInterface(trigger_interface.clone()))
+        );
+        assert_eq!(
+            trigger_r_port.port_interface(),
+            Some(PortInterface::TriggerInterface(trigger_interface))
+        );
+
+        let provide_com_spec = p_port.create_parameter_provide_com_spec(&parameter).unwrap();
+        provide_com_spec
+            .set_init_value(Some(ValueSpecification::numerical(5.0)))
+            .unwrap();
+        assert_eq!(provide_com_spec.parameter(), Some(parameter.clone()));
+        assert_eq!(provide_com_spec.init_value(), Some(ValueSpecification::numerical(5.0)));
+
+        let require_com_spec = r_port.create_parameter_require_com_spec(&parameter).unwrap();
+        require_com_spec
+            .set_init_value(Some(ValueSpecification::numerical(3.0)))
+            .unwrap();
+        assert_eq!(require_com_spec.parameter(), Some(parameter.clone()));
+        assert_eq!(require_com_spec.init_value(), Some(ValueSpecification::numerical(3.0)));
+        assert_eq!(r_port.com_specs().next(), Some(require_com_spec.into()));
+
+        // a parameter can only be used on a port with the matching interface
+        assert!(trigger_r_port.create_parameter_require_com_spec(&parameter).is_err());
+
+        parameter.remove(false).unwrap();
+        assert_eq!(p_port.com_specs().count(), 0);
+        assert_eq!(r_port.com_specs().count(), 0);
+    }
+}
@@ -0,0 +1,490 @@
+use crate::{
+    AbstractionElement, AutosarAbstractionError, Element, IdentifiableAbstractionElement,
+    software_component::{
+        CompositionSwComponentType, PortPrototype, RootSwCompositionPrototype, SwComponentPrototype, SwComponentType,
+    },
+};
+use autosar_data::ElementName;
+
+//##################################################################
+
+/// An `InstanceRef` identifies a component prototype or a port inside of a composition hierarchy
+///
+/// Instance references are built starting from the root composition, followed by the path of component
+/// prototypes that leads to the target. Each step is validated, so that only paths which exist in the model
+/// can be built. The finished reference is written into the CONTEXT and TARGET references of an instance
+/// ref element with [`InstanceRef::write_component_iref`] or [`InstanceRef::write_port_element_iref`].
+///
+/// # Example
+///
+/// ```
+/// # use autosar_data::*;
+/// # use autosar_data_abstraction::*;
+/// # use autosar_data_abstraction::software_component::*;
+/// # fn main() -> Result<(), AutosarAbstractionError> {
+/// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+/// # let package = model.get_or_create_package("/some/package")?;
+/// let root_composition = package.create_composition_sw_component_type("root")?;
+/// let sub_composition = package.create_composition_sw_component_type("sub")?;
+/// let swc_type = package.create_application_sw_component_type("swc")?;
+/// let interface = package.create_sender_receiver_interface("interface")?;
+/// let port = swc_type.create_p_port("port", &interface)?;
+///
+/// let sub_prototype = root_composition.create_component("sub_prototype", &sub_composition)?;
+/// let swc_prototype = sub_composition.create_component("swc_prototype", &swc_type)?;
+///
+/// let iref = InstanceRef::new(&root_composition)
+///     .component(&sub_prototype)?
+///     .component(&swc_prototype)?
+///     .port(&port)?;
+/// assert_eq!(iref.target_component(), Some(swc_prototype));
+/// # Ok(())}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InstanceRef {
+    root_composition: CompositionSwComponentType,
+    root_composition_prototype: Option<RootSwCompositionPrototype>,
+    components: Vec<SwComponentPrototype>,
+    port: Option<PortPrototype>,
+}
+
+impl InstanceRef {
+    /// start a new instance reference in the given root composition
+    #[must_use]
+    pub fn new(root_composition: &CompositionSwComponentType) -> Self {
+        Self {
+            root_composition: root_composition.clone(),
+            root_composition_prototype: None,
+            components: Vec::new(),
+            port: None,
+        }
+    }
+
+    /// start a new instance reference in the composition of a root composition prototype
+    ///
+    /// The root composition prototype is written as CONTEXT-COMPOSITION-REF
+    pub fn from_root_composition_prototype(
+        root_composition_prototype: &RootSwCompositionPrototype,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let root_composition =
+            root_composition_prototype
+                .composition()
+                .ok_or(AutosarAbstractionError::InvalidParameter(
+                    "Incomplete root composition prototype".to_string(),
+                ))?;
+        Ok(Self {
+            root_composition,
+            root_composition_prototype: Some(root_composition_prototype.clone()),
+            components: Vec::new(),
+            port: None,
+        })
+    }
+
+    /// descend into a component prototype
+    ///
+    /// The component prototype must be part of the composition reached by the previous step,
+    /// and no port may have been selected yet.
+    pub fn component(mut self, component: &SwComponentPrototype) -> Result<Self, AutosarAbstractionError> {
+        if self.port.is_some() {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The instance reference already ends with a port".to_string(),
+            ));
+        }
+        let current_composition = self.current_composition()?;
+        if component.parent_composition()? != current_composition {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "The component prototype {} is not part of the composition {}",
+                component.name().unwrap_or_default(),
+                current_composition.name().unwrap_or_default()
+            )));
+        }
+        self.components.push(component.clone());
+        Ok(self)
+    }
+
+    /// select a port as the target of the instance reference
+    ///
+    /// The port must belong to the component type of the last component prototype in the path,
+    /// or to the root composition if the path is empty.
+    pub fn port<T: Into<PortPrototype> + Clone>(mut self, port: &T) -> Result<Self, AutosarAbstractionError> {
+        let port = port.clone().into();
+        let expected_type = match self.components.last() {
+            Some(component) => component
+                .component_type()
+                .ok_or(AutosarAbstractionError::InvalidParameter(
+                    "invalid SWC prototype: component type ref is missing".to_string(),
+                ))?,
+            None => SwComponentType::Composition(self.root_composition.clone()),
+        };
+        if port.component_type()? != expected_type {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "The port {} is not part of the component type {}",
+                port.name().unwrap_or_default(),
+                expected_type.name().unwrap_or_default()
+            )));
+        }
+        self.port = Some(port);
+        Ok(self)
+    }
+
+    /// get the root composition of the instance reference
+    #[must_use]
+    pub fn root_composition(&self) -> &CompositionSwComponentType {
+        &self.root_composition
+    }
+
+    /// get the root composition prototype, if the instance reference was started from one
+    #[must_use]
+    pub fn root_composition_prototype(&self) -> Option<&RootSwCompositionPrototype> {
+        self.root_composition_prototype.as_ref()
+    }
+
+    /// get the path of component prototypes, starting at the root composition
+    #[must_use]
+    pub fn components(&self) -> &[SwComponentPrototype] {
+        &self.components
+    }
+
+    /// get the last component prototype in the path
+    #[must_use]
+    pub fn target_component(&self) -> Option<SwComponentPrototype> {
+        self.components.last().cloned()
+    }
+
+    /// get the port at the end of the path
+    #[must_use]
+    pub fn port_prototype(&self) -> Option<&PortPrototype> {
+        self.port.as_ref()
+    }
+
+    /// write the instance reference as a component instance ref, e.g. COMPONENT-IREF
+    ///
+    /// The last component prototype of the path becomes the TARGET-COMPONENT-REF, and all others become
+    /// CONTEXT-COMPONENT-REFs. Any existing content of the instance ref element is replaced.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the instance reference does not end with a component prototype,
+    ///   or the element contains sub elements which do not belong to a component instance ref
+    pub fn write_component_iref(&self, iref: &Element) -> Result<(), AutosarAbstractionError> {
+        if self.port.is_some() {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "A component instance reference can not end with a port".to_string(),
+            ));
+        }
+        let Some((target, context)) = self.components.split_last() else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "A component instance reference requires at least one component prototype".to_string(),
+            ));
+        };
+
+        check_iref(
+            iref,
+            &[
+                ElementName::ContextCompositionRef,
+                ElementName::ContextComponentRef,
+                ElementName::TargetComponentRef,
+            ],
+        )?;
+        clear_iref(iref)?;
+        self.write_context(iref, context)?;
+        iref.create_sub_element(ElementName::TargetComponentRef)?
+            .set_reference_target(target.element())?;
+
+        Ok(())
+    }
+
+    /// write the instance reference as the instance ref of an element of the port interface
+    ///
+    /// This is used e.g. for the DATA-ELEMENT-IREF of a `SenderReceiverToSignalMapping`.
+    /// All component prototypes of the path become CONTEXT-COMPONENT-REFs, the port becomes the CONTEXT-PORT-REF,
+    /// and the target element is referenced by a sub element named `target_ref_name`.
+    /// The target must be an element of the interface of the port. Any existing content of the instance ref element is replaced.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the instance reference does not end with a port,
+    ///   the target is not part of the port interface, or the element contains sub elements
+    ///   which do not belong to a port element instance ref
+    pub fn write_port_element_iref<T: AbstractionElement>(
+        &self,
+        iref: &Element,
+        target_ref_name: ElementName,
+        target: &T,
+    ) -> Result<(), AutosarAbstractionError> {
+        let Some(port) = &self.port else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The instance reference does not end with a port".to_string(),
+            ));
+        };
+        let interface = port.port_interface().ok_or(AutosarAbstractionError::InvalidParameter(
+            "Invalid port lacks a port interface".to_string(),
+        ))?;
+        if target.element().named_parent()?.as_ref() != Some(interface.element()) {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "The target element is not part of the port interface {}",
+                interface.name().unwrap_or_default()
+            )));
+        }
+
+        check_iref(
+            iref,
+            &[
+                ElementName::ContextCompositionRef,
+                ElementName::ContextComponentRef,
+                ElementName::ContextPortRef,
+                target_ref_name,
+            ],
+        )?;
+        clear_iref(iref)?;
+        self.write_context(iref, &self.components)?;
+        iref.create_sub_element(ElementName::ContextPortRef)?
+            .set_reference_target(port.element())?;
+        iref.create_sub_element(target_ref_name)?
+            .set_reference_target(target.element())?;
+
+        Ok(())
+    }
+
+    // the composition that contains the next component prototype of the path
+    fn current_composition(&self) -> Result<CompositionSwComponentType, AutosarAbstractionError> {
+        match self.components.last() {
+            Some(component) => match component.component_type() {
+                Some(SwComponentType::Composition(composition)) => Ok(composition),
+                _ => Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "The component prototype {} is not a composition",
+                    component.name().unwrap_or_default()
+                ))),
+            },
+            None => Ok(self.root_composition.clone()),
+        }
+    }
+
+    // write the CONTEXT-COMPOSITION-REF and the CONTEXT-COMPONENT-REFs
+    fn write_context(&self, iref: &Element, context: &[SwComponentPrototype]) -> Result<(), AutosarAbstractionError> {
+        if let Some(root_composition_prototype) = &self.root_composition_prototype {
+            iref.create_sub_element(ElementName::ContextCompositionRef)?
+                .set_reference_target(root_composition_prototype.element())?;
+        }
+        // the context components are ordered, starting at the root composition
+        for component in context {
+            iref.create_sub_element(ElementName::ContextComponentRef)?
+                .set_reference_target(component.element())?;
+        }
+        Ok(())
+    }
+}
+
+// make sure that the element only contains the references of an instance ref, so that clearing it can't destroy anything else
+fn check_iref(iref: &Element, allowed: &[ElementName]) -> Result<(), AutosarAbstractionError> {
+    if let Some(sub_element) = iref
+        .sub_elements()
+        .find(|sub_element| !allowed.contains(&sub_element.element_name()))
+    {
+        return Err(AutosarAbstractionError::InvalidParameter(format!(
+            "The element {} is not an instance ref: it contains {}",
+            iref.element_name(),
+            sub_element.element_name()
+        )));
+    }
+    Ok(())
+}
+
+// remove all existing references from an instance ref element
+fn clear_iref(iref: &Element) -> Result<(), AutosarAbstractionError> {
+    let existing: Vec<_> = iref.sub_elements().collect();
+    for sub_element in existing {
+        iref.remove_sub_element(sub_element)?;
+    }
+    Ok(())
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AutosarModelAbstraction, SystemCategory,
+        datatype::{BaseTypeEncoding, ImplementationDataTypeSettings},
+        software_component::AbstractSwComponentType,
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn instance_ref() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("system", SystemCategory::EcuExtract).unwrap();
+
+        let root_composition = package.create_composition_sw_component_type("root").unwrap();
+        let sub_composition = package.create_composition_sw_component_type("sub").unwrap();
+        let swc_type = package.create_application_sw_component_type("swc").unwrap();
+        let interface = package.create_sender_receiver_interface("interface").unwrap();
+        let base_type = package
+            .create_sw_base_type("base", 8, BaseTypeEncoding::None, None, None, None)
+            .unwrap();
+        let datatype = package
+            .create_implementation_data_type(&ImplementationDataTypeSettings::Value {
+                name: "ImplementationValue".to_string(),
+                base_type,
+                compu_method: None,
+                data_constraint: None,
+            })
+            .unwrap();
+        let data_element = interface.create_data_element("data", &datatype).unwrap();
+        let other_interface = package.create_sender_receiver_interface("other_interface").unwrap();
+        let other_data_element = other_interface.create_data_element("data", &datatype).unwrap();
+        let port = swc_type.create_p_port("port", &interface).unwrap();
+        let root_port = root_composition.create_p_port("root_port", &interface).unwrap();
+
+        let sub_prototype = root_composition
+            .create_component("sub_prototype", &sub_composition)
+            .unwrap();
+        let swc_prototype = sub_composition.create_component("swc_prototype", &swc_type).unwrap();
+        let root_composition_prototype = system
+            .set_root_sw_composition("root_prototype", &root_composition)
+            .unwrap();
+
+        // invalid paths are rejected
+        assert!(InstanceRef::new(&root_composition).component(&swc_prototype).is_err());
+        assert!(InstanceRef::new(&root_composition).port(&port).is_err());
+        assert!(
+            InstanceRef::new(&root_composition)
+                .component(&sub_prototype)
+                .unwrap()
+                .component(&swc_prototype)
+                .unwrap()
+                .component(&swc_prototype)
+                .is_err()
+        );
+
+        // component instance ref
+        let component_iref = InstanceRef::from_root_composition_prototype(&root_composition_prototype)
+            .unwrap()
+            .component(&sub_prototype)
+            .unwrap()
+            .component(&swc_prototype)
+            .unwrap();
+        assert_eq!(
+            component_iref.components(),
+            &[sub_prototype.clone(), swc_prototype.clone()]
+        );
+        assert_eq!(component_iref.root_composition(), &root_composition);
+        assert_eq!(
+            component_iref.root_composition_prototype(),
+            Some(&root_composition_prototype)
+        );
+        let mapping = system.get_or_create_mapping("mapping").unwrap();
+        let ecu = system.create_ecu_instance("ecu", &package).unwrap();
+        let swc_to_ecu_mapping = mapping.map_swc_to_ecu("swc_mapping", &swc_prototype, &ecu).unwrap();
+        let component_iref_elem = swc_to_ecu_mapping
+            .element()
+            .get_sub_element(ElementName::ComponentIrefs)
+            .unwrap()
+            .get_sub_element(ElementName::ComponentIref)
+            .unwrap();
+        // an element that is not an instance ref is rejected without modifying it
+        assert!(
+            component_iref
+                .write_component_iref(swc_to_ecu_mapping.element())
+                .is_err()
+        );
+        assert!(
+            swc_to_ecu_mapping
+                .element()
+                .get_sub_element(ElementName::EcuInstanceRef)
+                .is_some()
+        );
+        component_iref.write_component_iref(&component_iref_elem).unwrap();
+        assert_eq!(
+            component_iref_elem
+                .get_sub_element(ElementName::TargetComponentRef)
+                .unwrap()
+                .get_reference_target()
+                .unwrap(),
+            *swc_prototype.element()
+        );
+        assert_eq!(
+            component_iref_elem
+                .get_sub_element(ElementName::ContextComponentRef)
+                .unwrap()
+                .get_reference_target()
+                .unwrap(),
+            *sub_prototype.element()
+        );
+        assert!(
+            component_iref_elem
+                .get_sub_element(ElementName::ContextCompositionRef)
+                .is_some()
+        );
+
+        // port element instance ref
+        let port_iref = component_iref.clone().port(&port).unwrap();
+        assert!(port_iref.clone().component(&swc_prototype).is_err());
+        assert!(port_iref.write_component_iref(&component_iref_elem).is_err());
+        let system_signal = package.create_system_signal("system_signal").unwrap();
+        let data_mapping = mapping
+            .map_sender_receiver_to_signal(
+                &system_signal,
+                &data_element,
+                &port,
+                &[&sub_prototype, &swc_prototype],
+                Some(&root_composition_prototype),
+            )
+            .unwrap();
+        let data_iref_elem = data_mapping
+            .element()
+            .get_sub_element(ElementName::DataElementIref)
+            .unwrap();
+        assert!(
+            port_iref
+                .write_port_element_iref(
+                    data_mapping.element(),
+                    ElementName::TargetDataPrototypeRef,
+                    &data_element
+                )
+                .is_err()
+        );
+        assert!(
+            port_iref
+                .write_port_element_iref(
+                    &data_iref_elem,
+                    ElementName::TargetDataPrototypeRef,
+                    &other_data_element
+                )
+                .is_err()
+        );
+        port_iref
+            .write_port_element_iref(&data_iref_elem, ElementName::TargetDataPrototypeRef, &data_element)
+            .unwrap();
+        assert_eq!(
+            data_iref_elem
+                .get_sub_element(ElementName::ContextPortRef)
+                .unwrap()
+                .get_reference_target()
+                .unwrap(),
+            *port.element()
+        );
+        assert_eq!(
+            data_iref_elem
+                .get_sub_element(ElementName::TargetDataPrototypeRef)
+                .unwrap()
+                .get_reference_target()
+                .unwrap(),
+            *data_element.element()
+        );
+        assert_eq!(
+            data_iref_elem
+                .sub_elements()
+                .filter(|elem| elem.element_name() == ElementName::ContextComponentRef)
+                .count(),
+            2
+        );
+
+        // a port of the root composition doesn't need any context components
+        let root_port_iref = InstanceRef::new(&root_composition).port(&root_port).unwrap();
+        assert_eq!(root_port_iref.target_component(), None);
+        assert_eq!(root_port_iref.port_prototype(), Some(&root_port.clone().into()));
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, Element, EnumItem, IdentifiableAbstractionElement,
     abstraction_element,
-    datatype::{self, AbstractAutosarDataType},
+    datatype::{self, AbstractAutosarDataType, AbstractSwDataDefProps},
     get_reference_parents,
-    software_component::{AbstractPortInterface, OperationInvokedEvent, PortPrototype},
+    software_component::{AbstractPortInterface, OperationInvokedEvent, PortComSpec, PortPrototype},
 };
 use autosar_data::ElementName;
 use datatype::AutosarDataType;
@@ -143,17 +143,25 @@ impl ClientServerOperation {
         let ref_parents = get_reference_parents(self.element())?;
         AbstractionElement::remove(self, deep)?;
 
-        for (named_parent, _parent) in ref_parents {
+        for (named_parent, parent) in ref_parents {
             if named_parent.element_name() == ElementName::OperationInvokedEvent
                 && let Ok(event) = OperationInvokedEvent::try_from(named_parent)
             {
                 event.remove(deep)?;
+            } else if let Ok(com_spec) = PortComSpec::try_from(parent) {
+                com_spec.remove(deep)?;
             }
         }
 
         Ok(())
     }
 
+    /// Get the `ClientServerInterface` that contains this operation
+    pub fn interface(&self) -> Result<ClientServerInterface, AutosarAbstractionError> {
+        let named_parent = self.element().named_parent()?.unwrap();
+        ClientServerInterface::try_from(named_parent)
+    }
+
     /// Add an argument to the operation
     pub fn create_argument<T: AbstractAutosarDataType>(
         &self,
@@ -256,6 +264,7 @@ impl From<ArgumentDirection> for EnumItem {
 pub struct ArgumentDataPrototype(Element);
 abstraction_element!(ArgumentDataPrototype, ArgumentDataPrototype);
 impl IdentifiableAbstractionElement for ArgumentDataPrototype {}
+impl AbstractSwDataDefProps for ArgumentDataPrototype {}
 
 impl ArgumentDataPrototype {
     /// Create a new `ArgumentDataPrototype`
@@ -338,6 +347,7 @@ mod test {
         assert_eq!(error.error_code().unwrap(), 42);
 
         let operation = client_server_interface.create_operation("TestOperation").unwrap();
+        assert_eq!(operation.interface().unwrap(), client_server_interface);
         assert_eq!(client_server_interface.operations().count(), 1);
         assert_eq!(operation.name().unwrap(), "TestOperation");
         assert_eq!(operation.arguments().count(), 0);
@@ -1,11 +1,11 @@
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, Element, IdentifiableAbstractionElement,
     abstraction_element,
-    datatype::{AbstractAutosarDataType, AutosarDataType, ValueSpecification},
+    datatype::{AbstractAutosarDataType, AbstractSwDataDefProps, AutosarDataType, ValueSpecification},
     get_reference_parents,
-    software_component::{ModeDeclarationGroup, PortPrototype},
+    software_component::{ModeDeclarationGroup, PortComSpec, PortPrototype},
 };
-use autosar_data::ElementName;
+use autosar_data::{ElementName, EnumItem};
 
 mod clientserver;
 mod senderreceiver;
@@ -121,6 +121,12 @@ impl ModeGroup {
             .ok()?;
         ModeDeclarationGroup::try_from(mode_declaration_group_elem).ok()
     }
+
+    /// Get the `ModeSwitchInterface` that contains this `ModeGroup`
+    pub fn interface(&self) -> Result<ModeSwitchInterface, AutosarAbstractionError> {
+        let named_parent = self.element().named_parent()?.unwrap();
+        ModeSwitchInterface::try_from(named_parent)
+    }
 }
 
 //##################################################################
@@ -196,10 +202,11 @@ impl ParameterInterface {
 pub struct ParameterDataPrototype(Element);
 abstraction_element!(ParameterDataPrototype, ParameterDataPrototype);
 impl IdentifiableAbstractionElement for ParameterDataPrototype {}
+impl AbstractSwDataDefProps for ParameterDataPrototype {}
 
 impl ParameterDataPrototype {
     /// Create a new `ParameterDataPrototype`
-    fn new<T: AbstractAutosarDataType>(
+    pub(crate) fn new<T: AbstractAutosarDataType>(
         name: &str,
         parent_element: &Element,
         data_type: &T,
@@ -212,6 +219,20 @@ impl ParameterDataPrototype {
         Ok(pdp)
     }
 
+    /// remove this `ParameterDataPrototype` from the model
+    pub fn remove(self, deep: bool) -> Result<(), AutosarAbstractionError> {
+        let ref_parents = get_reference_parents(self.element())?;
+        AbstractionElement::remove(self, deep)?;
+
+        for (_named_parent, parent) in ref_parents {
+            if let Ok(com_spec) = PortComSpec::try_from(parent) {
+                com_spec.remove(deep)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// set the init value for this signal
     pub fn set_init_value<T: Into<ValueSpecification>>(
         &self,
@@ -279,8 +300,31 @@ impl NvDataInterface {
         Ok(Self(nv_data_interface))
     }
 
+    /// Add a new nv data element to the interface
+    pub fn create_nv_data<T: AbstractAutosarDataType>(
+        &self,
+        name: &str,
+        data_type: &T,
+    ) -> Result<VariableDataPrototype, AutosarAbstractionError> {
+        let nv_datas = self.element().get_or_create_sub_element(ElementName::NvDatas)?;
+        VariableDataPrototype::new(name, &nv_datas, data_type.element())
+    }
+
+    /// iterate over all nv data elements
+    pub fn nv_datas(&self) -> impl Iterator<Item = VariableDataPrototype> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::NvDatas)
+            .into_iter()
+            .flat_map(|nv_datas| nv_datas.sub_elements())
+            .filter_map(|elem| VariableDataPrototype::try_from(elem).ok())
+    }
+
     /// remove this `NvDataInterface` from the model
     pub fn remove(self, deep: bool) -> Result<(), AutosarAbstractionError> {
+        for nv_data in self.nv_datas() {
+            nv_data.remove(true)?;
+        }
+
         let ref_parents = get_reference_parents(self.element())?;
 
         AbstractionElement::remove(self, deep)?;
@@ -339,6 +383,44 @@ impl TriggerInterface {
 
         Ok(())
     }
+
+    /// Create a new `Trigger` in the trigger interface
+    pub fn create_trigger(&self, name: &str) -> Result<Trigger, AutosarAbstractionError> {
+        let triggers = self.element().get_or_create_sub_element(ElementName::Triggers)?;
+        Trigger::new(name, &triggers)
+    }
+
+    /// iterate over all triggers in the trigger interface
+    pub fn triggers(&self) -> impl Iterator<Item = Trigger> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::Triggers)
+            .into_iter()
+            .flat_map(|triggers| triggers.sub_elements())
+            .filter_map(|elem| Trigger::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// A `Trigger` is raised by a trigger source and activates the runnables that are connected to it
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Trigger(Element);
+abstraction_element!(Trigger, Trigger);
+impl IdentifiableAbstractionElement for Trigger {}
+
+impl Trigger {
+    /// Create a new `Trigger`
+    fn new(name: &str, parent_element: &Element) -> Result<Self, AutosarAbstractionError> {
+        let trigger = parent_element.create_named_sub_element(ElementName::Trigger, name)?;
+
+        Ok(Self(trigger))
+    }
+
+    /// Get the interface containing the trigger
+    pub fn interface(&self) -> Result<TriggerInterface, AutosarAbstractionError> {
+        let named_parent = self.element().named_parent()?.unwrap();
+        TriggerInterface::try_from(named_parent)
+    }
 }
 
 //##################################################################
@@ -437,6 +519,184 @@ impl PortInterface {
             PortInterface::TriggerInterface(interface) => interface.remove(deep),
         }
     }
+
+    /// check if this port interface, used by a providing port, satisfies the interface of a requiring port
+    ///
+    /// The compatibility is directional: the provided interface may offer more than the required interface needs.
+    /// The interfaces are compatible if they are of the same kind and each required item has a matching provided item:
+    /// - sender-receiver and nv data interfaces: data elements with the same names, data types and queue semantics
+    /// - client-server interfaces: operations with the same arguments. The provided operations and the provided
+    ///   interface may only return errors that are also known to the required interface.
+    /// - mode switch interfaces: mode groups with the same mode declaration group
+    /// - parameter interfaces: parameters with the same names and data types
+    /// - trigger interfaces: triggers with the same names
+    #[must_use]
+    pub fn is_compatible_with(&self, required: &PortInterface) -> bool {
+        self.compatibility_issue(required).is_none()
+    }
+
+    /// describe why this provided port interface does not satisfy the required port interface
+    ///
+    /// Returns None if the interfaces are compatible
+    pub(crate) fn compatibility_issue(&self, required: &PortInterface) -> Option<String> {
+        if self == required {
+            return None;
+        }
+
+        match (self, required) {
+            (PortInterface::SenderReceiverInterface(provided), PortInterface::SenderReceiverInterface(required)) => {
+                check_required_items(
+                    "data element",
+                    &variable_data_items(provided.data_elements()),
+                    &variable_data_items(required.data_elements()),
+                )
+            }
+            (PortInterface::ClientServerInterface(provided), PortInterface::ClientServerInterface(required)) => {
+                // the client must know every error that the server can return
+                check_items(
+                    "possible error",
+                    &application_error_items(provided.possible_errors()),
+                    &application_error_items(required.possible_errors()),
+                    "is unknown to the required interface",
+                    |provided_code, required_code| provided_code == required_code,
+                )
+                .or_else(|| {
+                    check_items(
+                        "operation",
+                        &operation_items(required.operations()),
+                        &operation_items(provided.operations()),
+                        "is not provided",
+                        |(required_args, required_errors), (provided_args, provided_errors)| {
+                            required_args == provided_args
+                                && provided_errors.iter().all(|code| required_errors.contains(code))
+                        },
+                    )
+                })
+            }
+            (PortInterface::ModeSwitchInterface(provided), PortInterface::ModeSwitchInterface(required)) => {
+                let provided_group = provided.mode_group().and_then(|group| group.mode_declaration_group());
+                let required_group = required.mode_group().and_then(|group| group.mode_declaration_group());
+                (provided_group != required_group).then(|| "the mode declaration groups differ".to_string())
+            }
+            (PortInterface::ParameterInterface(provided), PortInterface::ParameterInterface(required)) => {
+                let provided_parameters: Vec<_> = provided
+                    .parameters()
+                    .filter_map(|param| Some((param.name()?, param.data_type())))
+                    .collect();
+                let required_parameters: Vec<_> = required
+                    .parameters()
+                    .filter_map(|param| Some((param.name()?, param.data_type())))
+                    .collect();
+                check_required_items("parameter", &provided_parameters, &required_parameters)
+            }
+            (PortInterface::NvDataInterface(provided), PortInterface::NvDataInterface(required)) => {
+                check_required_items(
+                    "nv data element",
+                    &variable_data_items(provided.nv_datas()),
+                    &variable_data_items(required.nv_datas()),
+                )
+            }
+            (PortInterface::TriggerInterface(provided), PortInterface::TriggerInterface(required)) => {
+                let provided_triggers: Vec<_> = provided
+                    .triggers()
+                    .filter_map(|trigger| Some((trigger.name()?, ())))
+                    .collect();
+                let required_triggers: Vec<_> = required
+                    .triggers()
+                    .filter_map(|trigger| Some((trigger.name()?, ())))
+                    .collect();
+                check_required_items("trigger", &provided_triggers, &required_triggers)
+            }
+            _ => Some("the interfaces are of different kinds".to_string()),
+        }
+    }
+}
+
+/// collect the name, data type and implementation policy of each data element
+///
+/// The implementation policy distinguishes queued from unqueued (last-is-best) data
+fn variable_data_items(
+    data_elements: impl Iterator<Item = VariableDataPrototype>,
+) -> Vec<(String, (Option<AutosarDataType>, Option<EnumItem>))> {
+    data_elements
+        .filter_map(|data_element| {
+            let sw_impl_policy = data_element
+                .element()
+                .get_sub_element(ElementName::SwDataDefProps)
+                .and_then(|sddp| sddp.get_sub_element(ElementName::SwDataDefPropsVariants))
+                .and_then(|variants| variants.get_sub_element(ElementName::SwDataDefPropsConditional))
+                .and_then(|sddpc| sddpc.get_sub_element(ElementName::SwImplPolicy))
+                .and_then(|policy| policy.character_data())
+                .and_then(|cdata| cdata.enum_value());
+            Some((data_element.name()?, (data_element.data_type(), sw_impl_policy)))
+        })
+        .collect()
+}
+
+/// collect the name and error code of each application error
+fn application_error_items(errors: impl Iterator<Item = ApplicationError>) -> Vec<(String, Option<u64>)> {
+    errors
+        .filter_map(|error| Some((error.name()?, error.error_code())))
+        .collect()
+}
+
+/// collect the name, arguments and possible error codes of each operation
+#[allow(clippy::type_complexity)]
+fn operation_items(
+    operations: impl Iterator<Item = ClientServerOperation>,
+) -> Vec<(
+    String,
+    (
+        Vec<(Option<String>, Option<ArgumentDirection>, Option<AutosarDataType>)>,
+        Vec<Option<u64>>,
+    ),
+)> {
+    operations
+        .filter_map(|operation| {
+            let arguments = operation
+                .arguments()
+                .map(|arg| (arg.name(), arg.direction(), arg.data_type()))
+                .collect();
+            let mut error_codes: Vec<_> = operation.possible_errors().map(|error| error.error_code()).collect();
+            error_codes.sort_unstable();
+            Some((operation.name()?, (arguments, error_codes)))
+        })
+        .collect()
+}
+
+/// check that every required item has an identical provided item, and describe the first mismatch
+fn check_required_items<T: PartialEq>(
+    kind: &str,
+    provided: &[(String, T)],
+    required: &[(String, T)],
+) -> Option<String> {
+    check_items(
+        kind,
+        required,
+        provided,
+        "is not provided",
+        |required_value, provided_value| required_value == provided_value,
+    )
+}
+
+/// check that every item of `items` has a matching item with the same name in `available`,
+/// and describe the first mismatch
+fn check_items<T>(
+    kind: &str,
+    items: &[(String, T)],
+    available: &[(String, T)],
+    missing: &str,
+    matches: impl Fn(&T, &T) -> bool,
+) -> Option<String> {
+    items.iter().find_map(
+        |(name, value)| match available.iter().find(|(available_name, _)| available_name == name) {
+            Some((_, available_value)) if !matches(value, available_value) => {
+                Some(format!("the {kind} '{name}' differs between the interfaces"))
+            }
+            Some(_) => None,
+            None => Some(format!("the {kind} '{name}' {missing}")),
+        },
+    )
 }
 
 //##################################################################
@@ -593,6 +853,7 @@ mod test {
             .unwrap();
         assert_eq!(mode_switch_interface.mode_group().unwrap(), mode_group);
         assert_eq!(mode_group.mode_declaration_group().unwrap(), mode_declaration_group);
+        assert_eq!(mode_group.interface().unwrap(), mode_switch_interface);
     }
 
     #[test]
@@ -639,6 +900,18 @@ mod test {
         assert_eq!(composition_type.ports().count(), 0);
     }
 
+    #[test]
+    fn trigger_interface() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let trigger_interface = package.create_trigger_interface("trigger_interface").unwrap();
+
+        let trigger = trigger_interface.create_trigger("trigger").unwrap();
+        assert_eq!(trigger_interface.triggers().count(), 1);
+        assert_eq!(trigger_interface.triggers().next().unwrap(), trigger);
+        assert_eq!(trigger.interface().unwrap(), trigger_interface);
+    }
+
     #[test]
     fn remove_trigger_interface() {
         let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
@@ -652,4 +925,146 @@ mod test {
         trigger_interface.remove(true).unwrap();
         assert_eq!(composition_type.ports().count(), 0);
     }
+
+    #[test]
+    fn interface_compatibility() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let base_type = package
+            .create_sw_base_type("base", 32, BaseTypeEncoding::None, None, None, None)
+            .unwrap();
+        let datatype_1 = package
+            .create_implementation_data_type(&ImplementationDataTypeSettings::Value {
+                name: "ImplementationValue1".to_string(),
+                base_type: base_type.clone(),
+                compu_method: None,
+                data_constraint: None,
+            })
+            .unwrap();
+        let datatype_2 = package
+            .create_implementation_data_type(&ImplementationDataTypeSettings::Value {
+                name: "ImplementationValue2".to_string(),
+                base_type: base_type.clone(),
+                compu_method: None,
+                data_constraint: None,
+            })
+            .unwrap();
+
+        // sender-receiver interfaces
+        let sr_interface_1 = package.create_sender_receiver_interface("sr_interface_1").unwrap();
+        let sr_interface_2 = package.create_sender_receiver_interface("sr_interface_2").unwrap();
+        let data_element_1 = sr_interface_1.create_data_element("data", &datatype_1).unwrap();
+        let data_element_2 = sr_interface_2.create_data_element("data", &datatype_1).unwrap();
+        let sr_1 = PortInterface::SenderReceiverInterface(sr_interface_1.clone());
+        let sr_2 = PortInterface::SenderReceiverInterface(sr_interface_2.clone());
+        assert!(sr_1.is_compatible_with(&sr_1));
+        assert!(sr_1.is_compatible_with(&sr_2));
+        assert!(sr_2.is_compatible_with(&sr_1));
+
+        // different data types
+        data_element_2.set_data_type(&datatype_2).unwrap();
+        assert!(!sr_1.is_compatible_with(&sr_2));
+        data_element_2.set_data_type(&datatype_1).unwrap();
+
+        // different queue semantics
+        data_element_1
+            .element()
+            .get_or_create_sub_element(ElementName::SwDataDefProps)
+            .and_then(|sddp| sddp.get_or_create_sub_element(ElementName::SwDataDefPropsVariants))
+            .and_then(|variants| variants.get_or_create_sub_element(ElementName::SwDataDefPropsConditional))
+            .and_then(|sddpc| sddpc.get_or_create_sub_element(ElementName::SwImplPolicy))
+            .and_then(|policy| policy.set_character_data(EnumItem::Queued))
+            .unwrap();
+        assert!(!sr_1.is_compatible_with(&sr_2));
+        assert!(sr_1.compatibility_issue(&sr_2).unwrap().contains("'data'"));
+        data_element_1
+            .element()
+            .remove_sub_element_kind(ElementName::SwDataDefProps)
+            .unwrap();
+        assert!(sr_1.is_compatible_with(&sr_2));
+
+        // additional data element: the provider may offer more than is required, but not less
+        sr_interface_2.create_data_element("extra", &datatype_1).unwrap();
+        assert!(!sr_1.is_compatible_with(&sr_2));
+        assert!(
+            sr_1.compatibility_issue(&sr_2)
+                .unwrap()
+                .contains("'extra' is not provided")
+        );
+        assert!(sr_2.is_compatible_with(&sr_1));
+
+        // client-server interfaces
+        let cs_interface_1 = package.create_client_server_interface("cs_interface_1").unwrap();
+        let cs_interface_2 = package.create_client_server_interface("cs_interface_2").unwrap();
+        let operation_1 = cs_interface_1.create_operation("op").unwrap();
+        let operation_2 = cs_interface_2.create_operation("op").unwrap();
+        operation_1
+            .create_argument("arg", &datatype_1, ArgumentDirection::In)
+            .unwrap();
+        let argument_2 = operation_2
+            .create_argument("arg", &datatype_1, ArgumentDirection::In)
+            .unwrap();
+        let cs_1 = PortInterface::ClientServerInterface(cs_interface_1.clone());
+        let cs_2 = PortInterface::ClientServerInterface(cs_interface_2.clone());
+        assert!(cs_1.is_compatible_with(&cs_2));
+        argument_2.set_direction(ArgumentDirection::Out).unwrap();
+        assert!(!cs_1.is_compatible_with(&cs_2));
+        argument_2.set_direction(ArgumentDirection::In).unwrap();
+        // the provider may not return errors that are unknown to the requirer
+        cs_interface_1.create_possible_error("error", 1).unwrap();
+        assert!(!cs_1.is_compatible_with(&cs_2));
+        assert!(cs_2.is_compatible_with(&cs_1));
+        cs_interface_2.create_possible_error("error", 1).unwrap();
+        assert!(cs_1.is_compatible_with(&cs_2));
+        // an additional operation of the provider is not used by the requirer
+        cs_interface_1.create_operation("extra_op").unwrap();
+        assert!(cs_1.is_compatible_with(&cs_2));
+        assert!(!cs_2.is_compatible_with(&cs_1));
+
+        // trigger interfaces
+        let trigger_interface_1 = package.create_trigger_interface("trigger_interface_1").unwrap();
+        let trigger_interface_2 = package.create_trigger_interface("trigger_interface_2").unwrap();
+        trigger_interface_1.create_trigger("trigger").unwrap();
+        let trigger_1 = PortInterface::TriggerInterface(trigger_interface_1);
+        let trigger_2 = PortInterface::TriggerInterface(trigger_interface_2.clone());
+        assert!(trigger_1.is_compatible_with(&trigger_2));
+        assert!(!trigger_2.is_compatible_with(&trigger_1));
+        trigger_interface_2.create_trigger("trigger").unwrap();
+        assert!(trigger_2.is_compatible_with(&trigger_1));
+
+        // different kinds of interfaces are never compatible
+        assert!(!sr_1.is_compatible_with(&cs_1));
+        assert!(!cs_1.is_compatible_with(&trigger_1));
+
+        // connectors between ports with incompatible interfaces are rejected
+        let composition = package.create_composition_sw_component_type("composition").unwrap();
+        let swc_type = package.create_application_sw_component_type("swc_type").unwrap();
+        let swc_prototype = composition.create_component("swc_prototype", &swc_type).unwrap();
+        let inner_port = swc_type.create_p_port("inner_port", &sr_interface_1).unwrap();
+        let outer_port = composition.create_p_port("outer_port", &sr_interface_2).unwrap();
+        let result = composition.create_delegation_connector("connector", &inner_port, &swc_prototype, &outer_port);
+        assert!(result.is_err());
+        let outer_port_ok = composition.create_p_port("outer_port_ok", &sr_interface_1).unwrap();
+        let result = composition.create_delegation_connector("connector", &inner_port, &swc_prototype, &outer_port_ok);
+        assert!(result.is_ok());
+
+        // an assembly connector accepts a provider whose interface is a superset of the required interface
+        let provider_type = package.create_application_sw_component_type("provider_type").unwrap();
+        let requirer_type = package.create_application_sw_component_type("requirer_type").unwrap();
+        let provider = composition.create_component("provider", &provider_type).unwrap();
+        let requirer = composition.create_component("requirer", &requirer_type).unwrap();
+        let p_port = provider_type.create_p_port("p_port", &sr_interface_2).unwrap();
+        let r_port = requirer_type.create_r_port("r_port", &sr_interface_1).unwrap();
+        let result = composition.create_assembly_connector("assembly_1", &p_port, &provider, &r_port, &requirer);
+        assert!(result.is_ok());
+        // the order of the ports doesn't matter
+        let result = composition.create_assembly_connector("assembly_2", &r_port, &requirer, &p_port, &provider);
+        assert!(result.is_ok());
+        // the provider must offer everything that is required
+        let small_p_port = provider_type.create_p_port("small_p_port", &sr_interface_1).unwrap();
+        let large_r_port = requirer_type.create_r_port("large_r_port", &sr_interface_2).unwrap();
+        let result =
+            composition.create_assembly_connector("assembly_3", &small_p_port, &provider, &large_r_port, &requirer);
+        assert!(result.is_err());
+    }
 }
@@ -1,9 +1,9 @@
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, Element, IdentifiableAbstractionElement,
     SenderReceiverToSignalMapping, abstraction_element,
-    datatype::{AbstractAutosarDataType, AutosarDataType, ValueSpecification},
+    datatype::{AbstractAutosarDataType, AbstractSwDataDefProps, AutosarDataType, ValueSpecification},
     get_reference_parents,
-    software_component::{AbstractPortInterface, DataReceivedEvent, PortPrototype},
+    software_component::{AbstractPortInterface, DataReceivedEvent, NvBlockDataMapping, PortComSpec, PortPrototype},
 };
 use autosar_data::ElementName;
 
@@ -75,14 +75,21 @@ impl SenderReceiverInterface {
 //##################################################################
 
 /// A `VariableDataPrototype` represents a data element in a `SenderReceiverInterface`
+///
+/// It is also used for the inter-runnable variables of a `SwcInternalBehavior`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct VariableDataPrototype(Element);
 abstraction_element!(VariableDataPrototype, VariableDataPrototype);
 impl IdentifiableAbstractionElement for VariableDataPrototype {}
+impl AbstractSwDataDefProps for VariableDataPrototype {}
 
 impl VariableDataPrototype {
     /// Create a new `VariableDataPrototype`
-    fn new(name: &str, parent_element: &Element, data_type: &Element) -> Result<Self, AutosarAbstractionError> {
+    pub(crate) fn new(
+        name: &str,
+        parent_element: &Element,
+        data_type: &Element,
+    ) -> Result<Self, AutosarAbstractionError> {
         let vdp = parent_element.create_named_sub_element(ElementName::VariableDataPrototype, name)?;
         vdp.create_sub_element(ElementName::TypeTref)?
             .set_reference_target(data_type)?;
@@ -106,6 +113,15 @@ impl VariableDataPrototype {
                 && let Ok(mapping) = SenderReceiverToSignalMapping::try_from(parent_parent)
             {
                 mapping.remove(deep)?;
+            } else if named_parent.element_name() == ElementName::NvBlockDescriptor
+                && parent.element_name() == ElementName::AutosarVariableIref
+                && let Ok(Some(access)) = parent.parent()
+                && let Ok(Some(mapping_elem)) = access.parent()
+                && let Ok(mapping) = NvBlockDataMapping::try_from(mapping_elem)
+            {
+                mapping.remove(deep)?;
+            } else if let Ok(com_spec) = PortComSpec::try_from(parent) {
+                com_spec.remove(deep)?;
             }
         }
 
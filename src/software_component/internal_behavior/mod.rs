@@ -1,16 +1,18 @@
 use crate::{
     AbstractionElement, AutosarAbstractionError, Element, IdentifiableAbstractionElement, abstraction_element,
-    datatype::DataTypeMappingSet,
+    datatype::{AbstractAutosarDataType, DataTypeMappingSet},
     software_component::{
-        ClientServerOperation, ModeDeclaration, ModeGroup, PPortPrototype, PortPrototype, RPortPrototype,
-        SwComponentType, VariableDataPrototype,
+        ClientServerOperation, ModeDeclaration, ModeGroup, PPortPrototype, ParameterDataPrototype, PortInterface,
+        PortPrototype, RPortPrototype, SwComponentType, Trigger, VariableDataPrototype,
     },
 };
 use autosar_data::ElementName;
 
 mod rte_event;
+mod service_dependency;
 
 pub use rte_event::*;
+pub use service_dependency::*;
 
 //##################################################################
 
@@ -89,6 +91,184 @@ impl SwcInternalBehavior {
             })
     }
 
+    /// Create a new `ExclusiveArea` in the `SwcInternalBehavior`
+    ///
+    /// Runnables that can enter the exclusive area are protected against concurrent execution.
+    pub fn create_exclusive_area(&self, name: &str) -> Result<ExclusiveArea, AutosarAbstractionError> {
+        let exclusive_areas = self.element().get_or_create_sub_element(ElementName::ExclusiveAreas)?;
+        ExclusiveArea::new(name, &exclusive_areas)
+    }
+
+    /// Get an iterator over all `ExclusiveAreas` in the `SwcInternalBehavior`
+    pub fn exclusive_areas(&self) -> impl Iterator<Item = ExclusiveArea> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::ExclusiveAreas)
+            .into_iter()
+            .flat_map(|exclusive_areas| exclusive_areas.sub_elements())
+            .filter_map(|elem| ExclusiveArea::try_from(elem).ok())
+    }
+
+    /// Create a new explicit inter-runnable variable in the `SwcInternalBehavior`
+    ///
+    /// Explicit inter-runnable variables are accessed directly with `Rte_IrvRead` and `Rte_IrvWrite`.
+    pub fn create_explicit_inter_runnable_variable<T: AbstractAutosarDataType>(
+        &self,
+        name: &str,
+        data_type: &T,
+    ) -> Result<VariableDataPrototype, AutosarAbstractionError> {
+        let variables = self
+            .element()
+            .get_or_create_sub_element(ElementName::ExplicitInterRunnableVariables)?;
+        VariableDataPrototype::new(name, &variables, data_type.element())
+    }
+
+    /// Get an iterator over all explicit inter-runnable variables in the `SwcInternalBehavior`
+    pub fn explicit_inter_runnable_variables(&self) -> impl Iterator<Item = VariableDataPrototype> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::ExplicitInterRunnableVariables)
+            .into_iter()
+            .flat_map(|variables| variables.sub_elements())
+            .filter_map(|elem| VariableDataPrototype::try_from(elem).ok())
+    }
+
+    /// Create a new implicit inter-runnable variable in the `SwcInternalBehavior`
+    ///
+    /// Implicit inter-runnable variables are accessed with `Rte_IrvIRead` and `Rte_IrvIWrite`;
+    /// the RTE guarantees that the value does not change while a runnable is executing.
+    pub fn create_implicit_inter_runnable_variable<T: AbstractAutosarDataType>(
+        &self,
+        name: &str,
+        data_type: &T,
+    ) -> Result<VariableDataPrototype, AutosarAbstractionError> {
+        let variables = self
+            .element()
+            .get_or_create_sub_element(ElementName::ImplicitInterRunnableVariables)?;
+        VariableDataPrototype::new(name, &variables, data_type.element())
+    }
+
+    /// Get an iterator over all implicit inter-runnable variables in the `SwcInternalBehavior`
+    pub fn implicit_inter_runnable_variables(&self) -> impl Iterator<Item = VariableDataPrototype> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::ImplicitInterRunnableVariables)
+            .into_iter()
+            .flat_map(|variables| variables.sub_elements())
+            .filter_map(|elem| VariableDataPrototype::try_from(elem).ok())
+    }
+
+    /// Create a new `PerInstanceMemory` in the `SwcInternalBehavior`
+    ///
+    /// The memory is described by the name of its C type and the C type definition,
+    /// e.g. `create_per_instance_memory("Pim", "MyStruct", "struct { uint8 a; uint16 b; }")`
+    pub fn create_per_instance_memory(
+        &self,
+        name: &str,
+        type_name: &str,
+        type_definition: &str,
+    ) -> Result<PerInstanceMemory, AutosarAbstractionError> {
+        let memories = self
+            .element()
+            .get_or_create_sub_element(ElementName::PerInstanceMemorys)?;
+        PerInstanceMemory::new(name, &memories, type_name, type_definition)
+    }
+
+    /// Get an iterator over all `PerInstanceMemories` in the `SwcInternalBehavior`
+    pub fn per_instance_memories(&self) -> impl Iterator<Item = PerInstanceMemory> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::PerInstanceMemorys)
+            .into_iter()
+            .flat_map(|memories| memories.sub_elements())
+            .filter_map(|elem| PerInstanceMemory::try_from(elem).ok())
+    }
+
+    /// Create a new per-instance memory in the `SwcInternalBehavior` which is typed by an autosar data type
+    ///
+    /// Ar-typed per-instance memories are typically used as RAM mirrors of NV blocks.
+    pub fn create_ar_typed_per_instance_memory<T: AbstractAutosarDataType>(
+        &self,
+        name: &str,
+        data_type: &T,
+    ) -> Result<VariableDataPrototype, AutosarAbstractionError> {
+        let memories = self
+            .element()
+            .get_or_create_sub_element(ElementName::ArTypedPerInstanceMemorys)?;
+        VariableDataPrototype::new(name, &memories, data_type.element())
+    }
+
+    /// Get an iterator over all ar-typed per-instance memories in the `SwcInternalBehavior`
+    pub fn ar_typed_per_instance_memories(&self) -> impl Iterator<Item = VariableDataPrototype> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::ArTypedPerInstanceMemorys)
+            .into_iter()
+            .flat_map(|memories| memories.sub_elements())
+            .filter_map(|elem| VariableDataPrototype::try_from(elem).ok())
+    }
+
+    /// Create a new per-instance parameter in the `SwcInternalBehavior`
+    ///
+    /// Each instance of the software component has its own value of the parameter.
+    pub fn create_per_instance_parameter<T: AbstractAutosarDataType>(
+        &self,
+        name: &str,
+        data_type: &T,
+    ) -> Result<ParameterDataPrototype, AutosarAbstractionError> {
+        let parameters = self
+            .element()
+            .get_or_create_sub_element(ElementName::PerInstanceParameters)?;
+        ParameterDataPrototype::new(name, &parameters, data_type)
+    }
+
+    /// Get an iterator over all per-instance parameters in the `SwcInternalBehavior`
+    pub fn per_instance_parameters(&self) -> impl Iterator<Item = ParameterDataPrototype> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::PerInstanceParameters)
+            .into_iter()
+            .flat_map(|parameters| parameters.sub_elements())
+            .filter_map(|elem| ParameterDataPrototype::try_from(elem).ok())
+    }
+
+    /// Create a new shared parameter in the `SwcInternalBehavior`
+    ///
+    /// All instances of the software component share the same value of the parameter.
+    pub fn create_shared_parameter<T: AbstractAutosarDataType>(
+        &self,
+        name: &str,
+        data_type: &T,
+    ) -> Result<ParameterDataPrototype, AutosarAbstractionError> {
+        let parameters = self
+            .element()
+            .get_or_create_sub_element(ElementName::SharedParameters)?;
+        ParameterDataPrototype::new(name, &parameters, data_type)
+    }
+
+    /// Get an iterator over all shared parameters in the `SwcInternalBehavior`
+    pub fn shared_parameters(&self) -> impl Iterator<Item = ParameterDataPrototype> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::SharedParameters)
+            .into_iter()
+            .flat_map(|parameters| parameters.sub_elements())
+            .filter_map(|elem| ParameterDataPrototype::try_from(elem).ok())
+    }
+
+    /// Create a new `SwcServiceDependency` in the `SwcInternalBehavior`
+    ///
+    /// The service dependency describes the needs of the component for a service of the basic software,
+    /// e.g. the Nv blocks of the NvM or the diagnostic events of the Dem.
+    pub fn create_service_dependency(&self, name: &str) -> Result<SwcServiceDependency, AutosarAbstractionError> {
+        let service_dependencies = self
+            .element()
+            .get_or_create_sub_element(ElementName::ServiceDependencys)?;
+        SwcServiceDependency::new(name, &service_dependencies)
+    }
+
+    /// Get an iterator over all `SwcServiceDependencies` in the `SwcInternalBehavior`
+    pub fn service_dependencies(&self) -> impl Iterator<Item = SwcServiceDependency> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::ServiceDependencys)
+            .into_iter()
+            .flat_map(|service_dependencies| service_dependencies.sub_elements())
+            .filter_map(|elem| SwcServiceDependency::try_from(elem).ok())
+    }
+
     /// Create a new `InitEvent` in the `SwcInternalBehavior`
     pub fn create_init_event(
         &self,
@@ -176,6 +356,55 @@ impl SwcInternalBehavior {
         )
     }
 
+    /// create an external trigger occurred event that triggers a runnable in the `SwcInternalBehavior`
+    /// when the trigger is raised
+    pub fn create_external_trigger_occurred_event<T: Into<PortPrototype> + Clone>(
+        &self,
+        name: &str,
+        runnable: &RunnableEntity,
+        trigger: &Trigger,
+        context_port: &T,
+    ) -> Result<ExternalTriggerOccurredEvent, AutosarAbstractionError> {
+        let events = self.element().get_or_create_sub_element(ElementName::Events)?;
+        ExternalTriggerOccurredEvent::new(name, &events, runnable, trigger, context_port)
+    }
+
+    /// create a data send completed event that triggers a runnable in the `SwcInternalBehavior`
+    /// when the data of a data send point has been transmitted
+    pub fn create_data_send_completed_event(
+        &self,
+        name: &str,
+        runnable: &RunnableEntity,
+        data_send_point: &VariableAccess,
+    ) -> Result<DataSendCompletedEvent, AutosarAbstractionError> {
+        let events = self.element().get_or_create_sub_element(ElementName::Events)?;
+        DataSendCompletedEvent::new(name, &events, runnable, data_send_point)
+    }
+
+    /// create a data write completed event that triggers a runnable in the `SwcInternalBehavior`
+    /// when the data of an implicit write access has been written
+    pub fn create_data_write_completed_event(
+        &self,
+        name: &str,
+        runnable: &RunnableEntity,
+        data_write_access: &VariableAccess,
+    ) -> Result<DataWriteCompletedEvent, AutosarAbstractionError> {
+        let events = self.element().get_or_create_sub_element(ElementName::Events)?;
+        DataWriteCompletedEvent::new(name, &events, runnable, data_write_access)
+    }
+
+    /// create a mode switched ack event that triggers a runnable in the `SwcInternalBehavior`
+    /// when the mode switch of a mode switch point has been acknowledged
+    pub fn create_mode_switched_ack_event(
+        &self,
+        name: &str,
+        runnable: &RunnableEntity,
+        mode_switch_point: &ModeSwitchPoint,
+    ) -> Result<ModeSwitchedAckEvent, AutosarAbstractionError> {
+        let events = self.element().get_or_create_sub_element(ElementName::Events)?;
+        ModeSwitchedAckEvent::new(name, &events, runnable, mode_switch_point)
+    }
+
     /// create an iterator over all events in the `SwcInternalBehavior`
     pub fn events(&self) -> impl Iterator<Item = RTEEvent> + Send + use<> {
         self.element()
@@ -241,6 +470,94 @@ impl RunnableEntity {
         }
     }
 
+    /// allow the runnable to enter an `ExclusiveArea`
+    pub fn add_can_enter_exclusive_area(&self, exclusive_area: &ExclusiveArea) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::CanEnterExclusiveAreaRefs)?
+            .create_sub_element(ElementName::CanEnterExclusiveAreaRef)?
+            .set_reference_target(exclusive_area.element())?;
+        Ok(())
+    }
+
+    /// iterate over all `ExclusiveAreas` that the runnable can enter
+    pub fn can_enter_exclusive_areas(&self) -> impl Iterator<Item = ExclusiveArea> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::CanEnterExclusiveAreaRefs)
+            .into_iter()
+            .flat_map(|refs| {
+                refs.sub_elements()
+                    .filter_map(|elem| elem.get_reference_target().ok())
+                    .filter_map(|elem| ExclusiveArea::try_from(elem).ok())
+            })
+    }
+
+    /// add read access to an inter-runnable variable or an ar-typed per-instance memory of the `SwcInternalBehavior`
+    pub fn create_read_local_variable(
+        &self,
+        name: &str,
+        inter_runnable_variable: &VariableDataPrototype,
+    ) -> Result<VariableAccess, AutosarAbstractionError> {
+        let variable_accesses = self
+            .element()
+            .get_or_create_sub_element(ElementName::ReadLocalVariables)?;
+        VariableAccess::new_local(name, &variable_accesses, inter_runnable_variable)
+    }
+
+    /// iterate over all read accesses to inter-runnable variables
+    pub fn read_local_variables(&self) -> impl Iterator<Item = VariableAccess> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::ReadLocalVariables)
+            .into_iter()
+            .flat_map(|variable_accesses| variable_accesses.sub_elements())
+            .filter_map(|elem| VariableAccess::try_from(elem).ok())
+    }
+
+    /// add write access to an inter-runnable variable or an ar-typed per-instance memory of the `SwcInternalBehavior`
+    pub fn create_written_local_variable(
+        &self,
+        name: &str,
+        inter_runnable_variable: &VariableDataPrototype,
+    ) -> Result<VariableAccess, AutosarAbstractionError> {
+        let variable_accesses = self
+            .element()
+            .get_or_create_sub_element(ElementName::WrittenLocalVariables)?;
+        VariableAccess::new_local(name, &variable_accesses, inter_runnable_variable)
+    }
+
+    /// iterate over all write accesses to inter-runnable variables
+    pub fn written_local_variables(&self) -> impl Iterator<Item = VariableAccess> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::WrittenLocalVariables)
+            .into_iter()
+            .flat_map(|variable_accesses| variable_accesses.sub_elements())
+            .filter_map(|elem| VariableAccess::try_from(elem).ok())
+    }
+
+    /// add read access to a parameter
+    ///
+    /// If `context_port` is `None`, then the parameter must be a per-instance parameter or a shared parameter
+    /// of the `SwcInternalBehavior`. Otherwise the parameter is a data element of the parameter interface of the port.
+    pub fn create_parameter_access(
+        &self,
+        name: &str,
+        parameter: &ParameterDataPrototype,
+        context_port: Option<&PortPrototype>,
+    ) -> Result<ParameterAccess, AutosarAbstractionError> {
+        let parameter_accesses = self
+            .element()
+            .get_or_create_sub_element(ElementName::ParameterAccesss)?;
+        ParameterAccess::new(name, &parameter_accesses, parameter, context_port)
+    }
+
+    /// iterate over all parameter accesses
+    pub fn parameter_accesses(&self) -> impl Iterator<Item = ParameterAccess> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::ParameterAccesss)
+            .into_iter()
+            .flat_map(|parameter_accesses| parameter_accesses.sub_elements())
+            .filter_map(|elem| ParameterAccess::try_from(elem).ok())
+    }
+
     /// add implicit read access to a data element of a sender-receiver `PortPrototype`
     ///
     /// this results in `Rte_IRead_<port>_<data_element>` being generated
@@ -442,6 +759,18 @@ impl VariableAccess {
         Ok(variable_access)
     }
 
+    pub(crate) fn new_local(
+        name: &str,
+        parent: &Element,
+        inter_runnable_variable: &VariableDataPrototype,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let variable_access = parent.create_named_sub_element(ElementName::VariableAccess, name)?;
+        let variable_access = Self(variable_access);
+        variable_access.set_accessed_local_variable(inter_runnable_variable)?;
+
+        Ok(variable_access)
+    }
+
     /// Set the accessed variable
     pub fn set_accessed_variable(
         &self,
@@ -476,6 +805,31 @@ impl VariableAccess {
         Some((data_prototype, port_prototype))
     }
 
+    /// Set the accessed inter-runnable variable
+    ///
+    /// This replaces any previously accessed variable, including variables in the context of a port.
+    pub fn set_accessed_local_variable(
+        &self,
+        inter_runnable_variable: &VariableDataPrototype,
+    ) -> Result<(), AutosarAbstractionError> {
+        let _ = self.element().remove_sub_element_kind(ElementName::AccessedVariable);
+        self.element()
+            .create_sub_element(ElementName::AccessedVariable)?
+            .create_sub_element(ElementName::LocalVariableRef)?
+            .set_reference_target(inter_runnable_variable.element())?;
+        Ok(())
+    }
+
+    /// Get the accessed inter-runnable variable
+    #[must_use]
+    pub fn accessed_local_variable(&self) -> Option<VariableDataPrototype> {
+        let local_variable_ref = self
+            .element()
+            .get_sub_element(ElementName::AccessedVariable)?
+            .get_sub_element(ElementName::LocalVariableRef)?;
+        VariableDataPrototype::try_from(local_variable_ref.get_reference_target().ok()?).ok()
+    }
+
     /// Get the `RunnableEntity` that contains the `VariableAccess`
     #[must_use]
     pub fn runnable_entity(&self) -> Option<RunnableEntity> {
@@ -486,6 +840,196 @@ impl VariableAccess {
 
 //##################################################################
 
+/// A `ParameterAccess` allows a `RunnableEntity` to read a parameter
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParameterAccess(Element);
+abstraction_element!(ParameterAccess, ParameterAccess);
+impl IdentifiableAbstractionElement for ParameterAccess {}
+
+impl ParameterAccess {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        parameter: &ParameterDataPrototype,
+        context_port: Option<&PortPrototype>,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let parameter_access = parent.create_named_sub_element(ElementName::ParameterAccess, name)?;
+        let parameter_access = Self(parameter_access);
+        parameter_access.set_accessed_parameter(parameter, context_port)?;
+
+        Ok(parameter_access)
+    }
+
+    /// Set the accessed parameter
+    ///
+    /// If `context_port` is `None`, then the parameter is a parameter of the `SwcInternalBehavior`
+    pub fn set_accessed_parameter(
+        &self,
+        parameter: &ParameterDataPrototype,
+        context_port: Option<&PortPrototype>,
+    ) -> Result<(), AutosarAbstractionError> {
+        // remove the old accessed parameter
+        let _ = self.element().remove_sub_element_kind(ElementName::AccessedParameter);
+        let accessed_parameter = self.element().create_sub_element(ElementName::AccessedParameter)?;
+
+        if let Some(context_port) = context_port {
+            let autosar_parameter_iref = accessed_parameter.create_sub_element(ElementName::AutosarParameterIref)?;
+            autosar_parameter_iref
+                .create_sub_element(ElementName::PortPrototypeRef)?
+                .set_reference_target(context_port.element())?;
+            autosar_parameter_iref
+                .create_sub_element(ElementName::TargetDataPrototypeRef)?
+                .set_reference_target(parameter.element())?;
+        } else {
+            accessed_parameter
+                .create_sub_element(ElementName::LocalParameterRef)?
+                .set_reference_target(parameter.element())?;
+        }
+        Ok(())
+    }
+
+    /// Get the accessed parameter, and the context port if the parameter is accessed through a port
+    #[must_use]
+    pub fn accessed_parameter(&self) -> Option<(ParameterDataPrototype, Option<PortPrototype>)> {
+        let accessed_parameter = self.element().get_sub_element(ElementName::AccessedParameter)?;
+        if let Some(local_parameter_ref) = accessed_parameter.get_sub_element(ElementName::LocalParameterRef) {
+            let parameter = ParameterDataPrototype::try_from(local_parameter_ref.get_reference_target().ok()?).ok()?;
+            Some((parameter, None))
+        } else {
+            let autosar_parameter_iref = accessed_parameter.get_sub_element(ElementName::AutosarParameterIref)?;
+            let port_prototype_ref = autosar_parameter_iref.get_sub_element(ElementName::PortPrototypeRef)?;
+            let data_prototype_ref = autosar_parameter_iref.get_sub_element(ElementName::TargetDataPrototypeRef)?;
+
+            let port_prototype = PortPrototype::try_from(port_prototype_ref.get_reference_target().ok()?).ok()?;
+            let parameter = ParameterDataPrototype::try_from(data_prototype_ref.get_reference_target().ok()?).ok()?;
+            Some((parameter, Some(port_prototype)))
+        }
+    }
+
+    /// Get the `RunnableEntity` that contains the `ParameterAccess`
+    #[must_use]
+    pub fn runnable_entity(&self) -> Option<RunnableEntity> {
+        let parent = self.element().named_parent().ok()??;
+        RunnableEntity::try_from(parent).ok()
+    }
+}
+
+//##################################################################
+
+/// A `PerInstanceMemory` is a block of memory which is allocated separately for each instance of a software component
+///
+/// The type of the memory is given as C code. Use [`SwcInternalBehavior::create_ar_typed_per_instance_memory`]
+/// if the memory should be typed by an autosar data type instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PerInstanceMemory(Element);
+abstraction_element!(PerInstanceMemory, PerInstanceMemory);
+impl IdentifiableAbstractionElement for PerInstanceMemory {}
+
+impl PerInstanceMemory {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        type_name: &str,
+        type_definition: &str,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let per_instance_memory = parent.create_named_sub_element(ElementName::PerInstanceMemory, name)?;
+        let per_instance_memory = Self(per_instance_memory);
+        per_instance_memory.set_type_name(type_name)?;
+        per_instance_memory.set_type_definition(type_definition)?;
+
+        Ok(per_instance_memory)
+    }
+
+    /// set the name of the C type of the memory
+    pub fn set_type_name(&self, type_name: &str) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::Type)?
+            .set_character_data(type_name)?;
+        Ok(())
+    }
+
+    /// get the name of the C type of the memory
+    #[must_use]
+    pub fn type_name(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::Type)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// set the C type definition of the memory
+    pub fn set_type_definition(&self, type_definition: &str) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::TypeDefinition)?
+            .set_character_data(type_definition)?;
+        Ok(())
+    }
+
+    /// get the C type definition of the memory
+    #[must_use]
+    pub fn type_definition(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::TypeDefinition)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// set the initial value of the memory, given as C code
+    pub fn set_init_value(&self, init_value: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        if let Some(init_value) = init_value {
+            self.element()
+                .get_or_create_sub_element(ElementName::InitValue)?
+                .set_character_data(init_value)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::InitValue);
+        }
+        Ok(())
+    }
+
+    /// get the initial value of the memory
+    #[must_use]
+    pub fn init_value(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::InitValue)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// Get the `SwcInternalBehavior` that contains the `PerInstanceMemory`
+    #[must_use]
+    pub fn swc_internal_behavior(&self) -> Option<SwcInternalBehavior> {
+        let parent = self.element().named_parent().ok()??;
+        SwcInternalBehavior::try_from(parent).ok()
+    }
+}
+
+//##################################################################
+
+/// An `ExclusiveArea` protects the runnables that can enter it against concurrent execution
+///
+/// Use [`SwcInternalBehavior::create_exclusive_area`] to create a new exclusive area.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExclusiveArea(Element);
+abstraction_element!(ExclusiveArea, ExclusiveArea);
+impl IdentifiableAbstractionElement for ExclusiveArea {}
+
+impl ExclusiveArea {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let exclusive_area = parent.create_named_sub_element(ElementName::ExclusiveArea, name)?;
+
+        Ok(Self(exclusive_area))
+    }
+
+    /// Get the `SwcInternalBehavior` that contains the `ExclusiveArea`
+    #[must_use]
+    pub fn swc_internal_behavior(&self) -> Option<SwcInternalBehavior> {
+        let parent = self.element().named_parent().ok()??;
+        SwcInternalBehavior::try_from(parent).ok()
+    }
+}
+
+//##################################################################
+
 /// A `SynchronousServerCallPoint` allows a `RunnableEntity` to call a server operation synchronously
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SynchronousServerCallPoint(Element);
@@ -584,8 +1128,15 @@ impl ModeAccessPoint {
     ) -> Result<Self, AutosarAbstractionError> {
         let mode_access_point = parent.create_sub_element(ElementName::ModeAccessPoint)?;
         let mode_access_point = Self(mode_access_point);
-        mode_access_point.set_name(name)?;
-        mode_access_point.set_mode_group(mode_group, context_port)?;
+
+        let result = mode_access_point
+            .set_name(name)
+            .and_then(|()| mode_access_point.set_mode_group(mode_group, context_port));
+        if let Err(err) = result {
+            // this operation could fail if bad parameters are provided; in this case we remove the access point
+            parent.remove_sub_element(mode_access_point.0)?;
+            return Err(err);
+        }
 
         Ok(mode_access_point)
     }
@@ -597,6 +1148,8 @@ impl ModeAccessPoint {
         context_port: &T,
     ) -> Result<(), AutosarAbstractionError> {
         let context_port = context_port.clone().into();
+        check_mode_group_port(mode_group, &context_port)?;
+
         // remove the old mode group iref
         let _ = self.element().remove_sub_element_kind(ElementName::ModeGroupIref);
         let mode_group_iref = self.element().create_sub_element(ElementName::ModeGroupIref)?;
@@ -692,7 +1245,13 @@ impl ModeSwitchPoint {
     ) -> Result<Self, AutosarAbstractionError> {
         let mode_switch_point = parent.create_named_sub_element(ElementName::ModeSwitchPoint, name)?;
         let mode_switch_point = Self(mode_switch_point);
-        mode_switch_point.set_mode_group(mode_group, context_port)?;
+
+        let result = mode_switch_point.set_mode_group(mode_group, context_port);
+        if let Err(err) = result {
+            // this operation could fail if bad parameters are provided; in this case we remove the switch point
+            parent.remove_sub_element(mode_switch_point.0)?;
+            return Err(err);
+        }
 
         Ok(mode_switch_point)
     }
@@ -709,6 +1268,7 @@ impl ModeSwitchPoint {
                 "ModeSwitchPoint context_port cannot be an R port".to_string(),
             ));
         }
+        check_mode_group_port(mode_group, &context_port)?;
 
         // remove the old mode group iref
         let _ = self.element().remove_sub_element_kind(ElementName::ModeGroupIref);
@@ -753,12 +1313,32 @@ impl ModeSwitchPoint {
 
 //##################################################################
 
+// verify that the mode group is part of the ModeSwitchInterface of the context port
+fn check_mode_group_port(mode_group: &ModeGroup, context_port: &PortPrototype) -> Result<(), AutosarAbstractionError> {
+    let Some(PortInterface::ModeSwitchInterface(mode_switch_interface)) = context_port.port_interface() else {
+        return Err(AutosarAbstractionError::InvalidParameter(
+            "The context port must use a ModeSwitchInterface".to_string(),
+        ));
+    };
+    if mode_switch_interface != mode_group.interface()? {
+        return Err(AutosarAbstractionError::InvalidParameter(format!(
+            "ModeGroup {} is not part of ModeSwitchInterface {}",
+            mode_group.name().as_deref().unwrap_or("(invalid)"),
+            mode_switch_interface.name().as_deref().unwrap_or("(invalid)")
+        )));
+    }
+
+    Ok(())
+}
+
+//##################################################################
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::{
         AbstractionElement, AutosarModelAbstraction,
-        datatype::ApplicationPrimitiveCategory,
+        datatype::{ApplicationPrimitiveCategory, AutosarDataType},
         software_component::{AbstractRTEEvent, AbstractSwComponentType, AtomicSwComponentType},
     };
     use autosar_data::{AutosarVersion, EnumItem};
@@ -847,6 +1427,151 @@ mod test {
         assert_eq!(swc_internal_behavior.data_type_mapping_sets().count(), 1);
     }
 
+    #[test]
+    fn exclusive_areas_and_inter_runnable_variables() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+
+        let app_swc = package
+            .create_application_sw_component_type("AppSwComponentType")
+            .unwrap();
+        let swc_internal_behavior = app_swc
+            .create_swc_internal_behavior("AppSwComponentType_InternalBehavior")
+            .unwrap();
+        let runnable1 = swc_internal_behavior.create_runnable_entity("Runnable1").unwrap();
+        let runnable2 = swc_internal_behavior.create_runnable_entity("Runnable2").unwrap();
+
+        // exclusive areas
+        let exclusive_area = swc_internal_behavior.create_exclusive_area("ExclusiveArea").unwrap();
+        assert_eq!(exclusive_area.swc_internal_behavior().unwrap(), swc_internal_behavior);
+        assert_eq!(swc_internal_behavior.exclusive_areas().count(), 1);
+        runnable1.add_can_enter_exclusive_area(&exclusive_area).unwrap();
+        runnable2.add_can_enter_exclusive_area(&exclusive_area).unwrap();
+        assert_eq!(runnable1.can_enter_exclusive_areas().next().unwrap(), exclusive_area);
+        assert_eq!(runnable2.can_enter_exclusive_areas().count(), 1);
+
+        // inter-runnable variables
+        let data_type = package
+            .create_application_primitive_data_type("uint32", ApplicationPrimitiveCategory::Value, None, None, None)
+            .unwrap();
+        let explicit_irv = swc_internal_behavior
+            .create_explicit_inter_runnable_variable("ExplicitIrv", &data_type)
+            .unwrap();
+        let implicit_irv = swc_internal_behavior
+            .create_implicit_inter_runnable_variable("ImplicitIrv", &data_type)
+            .unwrap();
+        assert_eq!(
+            explicit_irv.data_type().unwrap(),
+            AutosarDataType::ApplicationPrimitiveDataType(data_type.clone())
+        );
+        assert_eq!(
+            implicit_irv.data_type().unwrap(),
+            AutosarDataType::ApplicationPrimitiveDataType(data_type)
+        );
+        assert_eq!(swc_internal_behavior.explicit_inter_runnable_variables().count(), 1);
+        assert_eq!(swc_internal_behavior.implicit_inter_runnable_variables().count(), 1);
+
+        // runnable1 writes the variables, runnable2 reads them
+        let write_access = runnable1
+            .create_written_local_variable("WriteExplicitIrv", &explicit_irv)
+            .unwrap();
+        runnable1
+            .create_written_local_variable("WriteImplicitIrv", &implicit_irv)
+            .unwrap();
+        let read_access = runnable2
+            .create_read_local_variable("ReadExplicitIrv", &explicit_irv)
+            .unwrap();
+        assert_eq!(runnable1.written_local_variables().count(), 2);
+        assert_eq!(runnable2.read_local_variables().count(), 1);
+        assert_eq!(write_access.accessed_local_variable().unwrap(), explicit_irv);
+        assert_eq!(read_access.accessed_local_variable().unwrap(), explicit_irv);
+        assert_eq!(read_access.runnable_entity().unwrap(), runnable2);
+        assert!(read_access.accessed_variable().is_none());
+
+        read_access.set_accessed_local_variable(&implicit_irv).unwrap();
+        assert_eq!(read_access.accessed_local_variable().unwrap(), implicit_irv);
+    }
+
+    #[test]
+    fn per_instance_memory_and_parameters() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+
+        let data_type = package
+            .create_application_primitive_data_type("uint32", ApplicationPrimitiveCategory::Value, None, None, None)
+            .unwrap();
+        let parameter_interface = package.create_parameter_interface("ParameterInterface").unwrap();
+        let interface_parameter = parameter_interface.create_parameter("Param", &data_type).unwrap();
+
+        let app_swc = package
+            .create_application_sw_component_type("AppSwComponentType")
+            .unwrap();
+        let r_port = app_swc.create_r_port("r_port", &parameter_interface).unwrap();
+        let swc_internal_behavior = app_swc
+            .create_swc_internal_behavior("AppSwComponentType_InternalBehavior")
+            .unwrap();
+        let runnable = swc_internal_behavior.create_runnable_entity("Runnable").unwrap();
+
+        // per-instance memory with a C type
+        let pim = swc_internal_behavior
+            .create_per_instance_memory("Pim", "MyType", "uint8[4]")
+            .unwrap();
+        assert_eq!(pim.type_name().unwrap(), "MyType");
+        assert_eq!(pim.type_definition().unwrap(), "uint8[4]");
+        assert_eq!(pim.init_value(), None);
+        pim.set_init_value(Some("{0, 0, 0, 0}")).unwrap();
+        assert_eq!(pim.init_value().unwrap(), "{0, 0, 0, 0}");
+        pim.set_init_value(None).unwrap();
+        assert_eq!(pim.init_value(), None);
+        assert_eq!(pim.swc_internal_behavior().unwrap(), swc_internal_behavior);
+        assert_eq!(swc_internal_behavior.per_instance_memories().count(), 1);
+
+        // per-instance memory with an autosar data type
+        let ar_typed_pim = swc_internal_behavior
+            .create_ar_typed_per_instance_memory("ArTypedPim", &data_type)
+            .unwrap();
+        assert_eq!(swc_internal_behavior.ar_typed_per_instance_memories().count(), 1);
+        let access = runnable
+            .create_written_local_variable("WritePim", &ar_typed_pim)
+            .unwrap();
+        assert_eq!(access.accessed_local_variable().unwrap(), ar_typed_pim);
+
+        // parameters
+        let per_instance_parameter = swc_internal_behavior
+            .create_per_instance_parameter("PerInstanceParameter", &data_type)
+            .unwrap();
+        let shared_parameter = swc_internal_behavior
+            .create_shared_parameter("SharedParameter", &data_type)
+            .unwrap();
+        assert_eq!(swc_internal_behavior.per_instance_parameters().count(), 1);
+        assert_eq!(swc_internal_behavior.shared_parameters().count(), 1);
+        assert_eq!(
+            shared_parameter.data_type().unwrap(),
+            AutosarDataType::ApplicationPrimitiveDataType(data_type)
+        );
+
+        // parameter accesses
+        let local_access = runnable
+            .create_parameter_access("LocalAccess", &per_instance_parameter, None)
+            .unwrap();
+        let port_access = runnable
+            .create_parameter_access("PortAccess", &interface_parameter, Some(&r_port.clone().into()))
+            .unwrap();
+        assert_eq!(runnable.parameter_accesses().count(), 2);
+        assert_eq!(
+            local_access.accessed_parameter().unwrap(),
+            (per_instance_parameter, None)
+        );
+        assert_eq!(
+            port_access.accessed_parameter().unwrap(),
+            (interface_parameter, Some(r_port.into()))
+        );
+        assert_eq!(port_access.runnable_entity().unwrap(), runnable);
+
+        local_access.set_accessed_parameter(&shared_parameter, None).unwrap();
+        assert_eq!(local_access.accessed_parameter().unwrap(), (shared_parameter, None));
+    }
+
     #[test]
     fn mode_switch_event() {
         let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
@@ -1051,6 +1776,104 @@ mod test {
         assert_eq!(data_received_event.runnable_entity().unwrap(), runnable);
     }
 
+    #[test]
+    fn trigger_and_completion_events() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+
+        let trigger_interface = package.create_trigger_interface("TriggerInterface").unwrap();
+        let trigger = trigger_interface.create_trigger("Trigger").unwrap();
+        let other_trigger_interface = package.create_trigger_interface("OtherTriggerInterface").unwrap();
+        let other_trigger = other_trigger_interface.create_trigger("OtherTrigger").unwrap();
+        let sender_receiver_interface = package
+            .create_sender_receiver_interface("SenderReceiverInterface")
+            .unwrap();
+        let app_data_type = package
+            .create_application_primitive_data_type("uint32", ApplicationPrimitiveCategory::Value, None, None, None)
+            .unwrap();
+        let data_element = sender_receiver_interface
+            .create_data_element("data", &app_data_type)
+            .unwrap();
+        let mode_declaration_group = package
+            .create_mode_declaration_group("ModeDeclarationGroup", None)
+            .unwrap();
+        let mode_switch_interface = package.create_mode_switch_interface("ModeSwitchInterface").unwrap();
+        let mode_group = mode_switch_interface
+            .create_mode_group("mode_group", &mode_declaration_group)
+            .unwrap();
+
+        let app_swc = package
+            .create_application_sw_component_type("AppSwComponentType")
+            .unwrap();
+        let trigger_port = app_swc.create_r_port("trigger_port", &trigger_interface).unwrap();
+        let trigger_p_port = app_swc.create_p_port("trigger_p_port", &trigger_interface).unwrap();
+        let sr_port = app_swc.create_p_port("sr_port", &sender_receiver_interface).unwrap();
+        let mode_port = app_swc.create_p_port("mode_port", &mode_switch_interface).unwrap();
+        let swc_internal_behavior = app_swc
+            .create_swc_internal_behavior("AppSwComponentType_InternalBehavior")
+            .unwrap();
+        let runnable = swc_internal_behavior.create_runnable_entity("Runnable").unwrap();
+
+        // external trigger occurred event
+        let result = swc_internal_behavior.create_external_trigger_occurred_event(
+            "TriggerEvent",
+            &runnable,
+            &trigger,
+            &trigger_p_port,
+        );
+        assert!(result.is_err());
+        let result = swc_internal_behavior.create_external_trigger_occurred_event(
+            "TriggerEvent",
+            &runnable,
+            &other_trigger,
+            &trigger_port,
+        );
+        assert!(result.is_err());
+        let result =
+            swc_internal_behavior.create_external_trigger_occurred_event("TriggerEvent", &runnable, &trigger, &sr_port);
+        assert!(result.is_err());
+        assert_eq!(swc_internal_behavior.events().count(), 0);
+        let trigger_event = swc_internal_behavior
+            .create_external_trigger_occurred_event("TriggerEvent", &runnable, &trigger, &trigger_port)
+            .unwrap();
+        assert_eq!(trigger_event.runnable_entity().unwrap(), runnable);
+        assert_eq!(trigger_event.trigger().unwrap(), (trigger, trigger_port.into()));
+
+        // data send completed event
+        let data_send_point = runnable
+            .create_data_send_point("DataSendPoint", &data_element, &sr_port)
+            .unwrap();
+        let send_completed_event = swc_internal_behavior
+            .create_data_send_completed_event("SendCompletedEvent", &runnable, &data_send_point)
+            .unwrap();
+        assert_eq!(send_completed_event.event_source().unwrap(), data_send_point);
+
+        // data write completed event
+        let data_write_access = runnable
+            .create_data_write_access("DataWriteAccess", &data_element, &sr_port)
+            .unwrap();
+        let write_completed_event = swc_internal_behavior
+            .create_data_write_completed_event("WriteCompletedEvent", &runnable, &data_write_access)
+            .unwrap();
+        assert_eq!(write_completed_event.event_source().unwrap(), data_write_access);
+
+        // mode switched ack event
+        let mode_switch_point = runnable
+            .create_mode_switch_point("ModeSwitchPoint", &mode_group, &mode_port)
+            .unwrap();
+        let ack_event = swc_internal_behavior
+            .create_mode_switched_ack_event("AckEvent", &runnable, &mode_switch_point)
+            .unwrap();
+        assert_eq!(ack_event.event_source().unwrap(), mode_switch_point);
+
+        assert_eq!(swc_internal_behavior.events().count(), 4);
+        assert_eq!(runnable.events().len(), 4);
+        assert!(matches!(
+            swc_internal_behavior.events().last().unwrap(),
+            RTEEvent::ModeSwitchedAckEvent(_)
+        ));
+    }
+
     #[test]
     fn variable_access() {
         let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
@@ -1261,6 +2084,23 @@ mod test {
         assert_eq!(mode_switch_point.mode_group().unwrap().0, mode_group);
         assert_eq!(mode_switch_point.mode_group().unwrap().1, p_port.into());
         assert_eq!(runnable.mode_switch_points().count(), 1);
+
+        // the mode group must be part of the interface of the context port
+        let other_interface = package.create_mode_switch_interface("other_interface").unwrap();
+        let other_port = app_swc.create_p_port("other_port", &other_interface).unwrap();
+        let result = runnable.create_mode_switch_point("ModeSwitchPoint2", &mode_group, &other_port);
+        assert!(result.is_err());
+        let result = runnable.create_mode_access_point("ModeAccessPoint2", &mode_group, &other_port);
+        assert!(result.is_err());
+        // the rejected points are not left behind in the model
+        assert_eq!(runnable.mode_switch_points().count(), 1);
+        assert_eq!(runnable.mode_access_points().count(), 0);
+
+        // a mode switch point can't use an R port
+        let r_port = app_swc.create_r_port("r_port", &mode_switch_interface).unwrap();
+        let result = runnable.create_mode_switch_point("ModeSwitchPoint3", &mode_group, &r_port);
+        assert!(result.is_err());
+        assert_eq!(runnable.mode_switch_points().count(), 1);
     }
 
     #[test]
@@ -1,8 +1,8 @@
 use crate::{
     AbstractionElement, AutosarAbstractionError, Element, IdentifiableAbstractionElement, abstraction_element,
     software_component::{
-        ClientServerOperation, ModeDeclaration, PPortPrototype, PortInterface, PortPrototype, RunnableEntity,
-        SwcInternalBehavior, VariableDataPrototype,
+        ClientServerOperation, ModeDeclaration, ModeSwitchPoint, PPortPrototype, PortInterface, PortPrototype,
+        RunnableEntity, SwcInternalBehavior, Trigger, VariableAccess, VariableDataPrototype,
     },
 };
 use autosar_data::{ElementName, EnumItem};
@@ -219,6 +219,41 @@ abstraction_element!(DataSendCompletedEvent, DataSendCompletedEvent);
 impl IdentifiableAbstractionElement for DataSendCompletedEvent {}
 impl AbstractRTEEvent for DataSendCompletedEvent {}
 
+impl DataSendCompletedEvent {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        runnable: &RunnableEntity,
+        data_send_point: &VariableAccess,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let data_send_completed_event = parent.create_named_sub_element(ElementName::DataSendCompletedEvent, name)?;
+        let data_send_completed_event = Self(data_send_completed_event);
+        data_send_completed_event.set_runnable_entity(runnable)?;
+        data_send_completed_event.set_event_source(data_send_point)?;
+
+        Ok(data_send_completed_event)
+    }
+
+    /// Set the data send point whose transmission raises the `DataSendCompletedEvent`
+    pub fn set_event_source(&self, data_send_point: &VariableAccess) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::EventSourceRef)?
+            .set_reference_target(data_send_point.element())?;
+        Ok(())
+    }
+
+    /// Get the data send point whose transmission raises the `DataSendCompletedEvent`
+    #[must_use]
+    pub fn event_source(&self) -> Option<VariableAccess> {
+        let event_source_elem = self
+            .element()
+            .get_sub_element(ElementName::EventSourceRef)?
+            .get_reference_target()
+            .ok()?;
+        VariableAccess::try_from(event_source_elem).ok()
+    }
+}
+
 //##################################################################
 
 /// raised when an implicit write access was successful or an error occurred
@@ -228,6 +263,41 @@ abstraction_element!(DataWriteCompletedEvent, DataWriteCompletedEvent);
 impl IdentifiableAbstractionElement for DataWriteCompletedEvent {}
 impl AbstractRTEEvent for DataWriteCompletedEvent {}
 
+impl DataWriteCompletedEvent {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        runnable: &RunnableEntity,
+        data_write_access: &VariableAccess,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let data_write_completed_event = parent.create_named_sub_element(ElementName::DataWriteCompletedEvent, name)?;
+        let data_write_completed_event = Self(data_write_completed_event);
+        data_write_completed_event.set_runnable_entity(runnable)?;
+        data_write_completed_event.set_event_source(data_write_access)?;
+
+        Ok(data_write_completed_event)
+    }
+
+    /// Set the implicit data write access that raises the `DataWriteCompletedEvent`
+    pub fn set_event_source(&self, data_write_access: &VariableAccess) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::EventSourceRef)?
+            .set_reference_target(data_write_access.element())?;
+        Ok(())
+    }
+
+    /// Get the implicit data write access that raises the `DataWriteCompletedEvent`
+    #[must_use]
+    pub fn event_source(&self) -> Option<VariableAccess> {
+        let event_source_elem = self
+            .element()
+            .get_sub_element(ElementName::EventSourceRef)?
+            .get_reference_target()
+            .ok()?;
+        VariableAccess::try_from(event_source_elem).ok()
+    }
+}
+
 //##################################################################
 
 /// raised when the referenced trigger occurred
@@ -237,6 +307,84 @@ abstraction_element!(ExternalTriggerOccurredEvent, ExternalTriggerOccurredEvent)
 impl IdentifiableAbstractionElement for ExternalTriggerOccurredEvent {}
 impl AbstractRTEEvent for ExternalTriggerOccurredEvent {}
 
+impl ExternalTriggerOccurredEvent {
+    pub(crate) fn new<T: Into<PortPrototype> + Clone>(
+        name: &str,
+        parent: &Element,
+        runnable: &RunnableEntity,
+        trigger: &Trigger,
+        context_port: &T,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let trigger_event = parent.create_named_sub_element(ElementName::ExternalTriggerOccurredEvent, name)?;
+        let trigger_event = Self(trigger_event);
+        trigger_event.set_runnable_entity(runnable)?;
+
+        let result = trigger_event.set_trigger(trigger, context_port);
+        if let Err(err) = result {
+            // this operation could fail if bad parameters are provided; in this case we remove the event
+            parent.remove_sub_element(trigger_event.0)?;
+            return Err(err);
+        }
+
+        Ok(trigger_event)
+    }
+
+    /// Set the `Trigger` that raises the `ExternalTriggerOccurredEvent`
+    ///
+    /// The context port must be a required port with a `TriggerInterface` that contains the trigger.
+    pub fn set_trigger<T: Into<PortPrototype> + Clone>(
+        &self,
+        trigger: &Trigger,
+        context_port: &T,
+    ) -> Result<(), AutosarAbstractionError> {
+        let context_port = context_port.clone().into();
+        if matches!(context_port, PortPrototype::P(_)) {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "An ExternalTriggerOccurredEvent must refer to a port using an RPortPrototype".to_string(),
+            ));
+        }
+        let Some(PortInterface::TriggerInterface(trigger_interface)) = context_port.port_interface() else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "An ExternalTriggerOccurredEvent must refer to a port using a TriggerInterface".to_string(),
+            ));
+        };
+        if trigger_interface != trigger.interface()? {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "Trigger {} is not part of TriggerInterface {}",
+                trigger.name().as_deref().unwrap_or("(invalid)"),
+                trigger_interface.name().as_deref().unwrap_or("(invalid)")
+            )));
+        }
+
+        let trigger_iref = self.element().get_or_create_sub_element(ElementName::TriggerIref)?;
+        trigger_iref
+            .get_or_create_sub_element(ElementName::ContextRPortRef)?
+            .set_reference_target(context_port.element())?;
+        trigger_iref
+            .get_or_create_sub_element(ElementName::TargetTriggerRef)?
+            .set_reference_target(trigger.element())?;
+
+        Ok(())
+    }
+
+    /// Get the `Trigger` that raises the `ExternalTriggerOccurredEvent`, and the context port
+    #[must_use]
+    pub fn trigger(&self) -> Option<(Trigger, PortPrototype)> {
+        let trigger_iref = self.element().get_sub_element(ElementName::TriggerIref)?;
+        let trigger_elem = trigger_iref
+            .get_sub_element(ElementName::TargetTriggerRef)?
+            .get_reference_target()
+            .ok()?;
+        let context_port_elem = trigger_iref
+            .get_sub_element(ElementName::ContextRPortRef)?
+            .get_reference_target()
+            .ok()?;
+        let trigger = Trigger::try_from(trigger_elem).ok()?;
+        let context_port = PortPrototype::try_from(context_port_elem).ok()?;
+        Some((trigger, context_port))
+    }
+}
+
 //##################################################################
 
 /// triggered once after the RTE has been started
@@ -278,6 +426,41 @@ abstraction_element!(ModeSwitchedAckEvent, ModeSwitchedAckEvent);
 impl IdentifiableAbstractionElement for ModeSwitchedAckEvent {}
 impl AbstractRTEEvent for ModeSwitchedAckEvent {}
 
+impl ModeSwitchedAckEvent {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        runnable: &RunnableEntity,
+        mode_switch_point: &ModeSwitchPoint,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let mode_switched_ack_event = parent.create_named_sub_element(ElementName::ModeSwitchedAckEvent, name)?;
+        let mode_switched_ack_event = Self(mode_switched_ack_event);
+        mode_switched_ack_event.set_runnable_entity(runnable)?;
+        mode_switched_ack_event.set_event_source(mode_switch_point)?;
+
+        Ok(mode_switched_ack_event)
+    }
+
+    /// Set the `ModeSwitchPoint` whose acknowledgement raises the `ModeSwitchedAckEvent`
+    pub fn set_event_source(&self, mode_switch_point: &ModeSwitchPoint) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::EventSourceRef)?
+            .set_reference_target(mode_switch_point.element())?;
+        Ok(())
+    }
+
+    /// Get the `ModeSwitchPoint` whose acknowledgement raises the `ModeSwitchedAckEvent`
+    #[must_use]
+    pub fn event_source(&self) -> Option<ModeSwitchPoint> {
+        let event_source_elem = self
+            .element()
+            .get_sub_element(ElementName::EventSourceRef)?
+            .get_reference_target()
+            .ok()?;
+        ModeSwitchPoint::try_from(event_source_elem).ok()
+    }
+}
+
 //##################################################################
 
 /// raised in order to run the server runnable of a `ClientServerOperation`
@@ -0,0 +1,487 @@
+use crate::{
+    AbstractionElement, AutosarAbstractionError, Element, IdentifiableAbstractionElement, abstraction_element,
+    software_component::{
+        ParameterDataPrototype, PerInstanceMemory, PortPrototype, SwcInternalBehavior, VariableDataPrototype,
+    },
+};
+use autosar_data::ElementName;
+
+//##################################################################
+
+/// A `SwcServiceDependency` describes the needs of a software component for a service of the basic software
+///
+/// The needs are specified by one [`ServiceNeeds`] element. Data and ports of the software component
+/// can be assigned to the service dependency with a role, e.g. the RAM block of an NV block.
+///
+/// Use [`SwcInternalBehavior::create_service_dependency`] to create a new service dependency.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SwcServiceDependency(Element);
+abstraction_element!(SwcServiceDependency, SwcServiceDependency);
+impl IdentifiableAbstractionElement for SwcServiceDependency {}
+
+impl SwcServiceDependency {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let service_dependency = parent.create_named_sub_element(ElementName::SwcServiceDependency, name)?;
+
+        Ok(Self(service_dependency))
+    }
+
+    /// Get the `SwcInternalBehavior` that contains the `SwcServiceDependency`
+    #[must_use]
+    pub fn swc_internal_behavior(&self) -> Option<SwcInternalBehavior> {
+        let parent = self.element().named_parent().ok()??;
+        SwcInternalBehavior::try_from(parent).ok()
+    }
+
+    /// Create `NvBlockNeeds` in the service dependency
+    ///
+    /// A service dependency can only contain one service needs element; any existing service needs are replaced.
+    pub fn create_nv_block_needs(&self, name: &str) -> Result<NvBlockNeeds, AutosarAbstractionError> {
+        let service_needs = self.replace_service_needs()?;
+        NvBlockNeeds::new(name, &service_needs)
+    }
+
+    /// Create `DiagnosticEventNeeds` in the service dependency
+    ///
+    /// A service dependency can only contain one service needs element; any existing service needs are replaced.
+    pub fn create_diagnostic_event_needs(&self, name: &str) -> Result<DiagnosticEventNeeds, AutosarAbstractionError> {
+        let service_needs = self.replace_service_needs()?;
+        DiagnosticEventNeeds::new(name, &service_needs)
+    }
+
+    /// Create `DiagnosticCommunicationManagerNeeds` in the service dependency
+    ///
+    /// A service dependency can only contain one service needs element; any existing service needs are replaced.
+    pub fn create_diagnostic_communication_manager_needs(
+        &self,
+        name: &str,
+    ) -> Result<DiagnosticCommunicationManagerNeeds, AutosarAbstractionError> {
+        let service_needs = self.replace_service_needs()?;
+        DiagnosticCommunicationManagerNeeds::new(name, &service_needs)
+    }
+
+    // remove the existing service needs and create a new empty ServiceNeeds element
+    fn replace_service_needs(&self) -> Result<Element, AutosarAbstractionError> {
+        let _ = self.element().remove_sub_element_kind(ElementName::ServiceNeeds);
+        Ok(self.element().create_sub_element(ElementName::ServiceNeeds)?)
+    }
+
+    /// Get the service needs of the service dependency
+    #[must_use]
+    pub fn service_needs(&self) -> Option<ServiceNeeds> {
+        let service_needs = self
+            .element()
+            .get_sub_element(ElementName::ServiceNeeds)?
+            .get_sub_element_at(0)?;
+        ServiceNeeds::try_from(service_needs).ok()
+    }
+
+    /// Assign data of the software component to the service dependency
+    ///
+    /// The role describes how the data is used by the service, e.g. "ramBlock" or "defaultValue" for `NvBlockNeeds`.
+    pub fn create_assigned_data(
+        &self,
+        role: &str,
+        data: &RoleBasedData,
+    ) -> Result<RoleBasedDataAssignment, AutosarAbstractionError> {
+        let assigned_datas = self.element().get_or_create_sub_element(ElementName::AssignedDatas)?;
+        RoleBasedDataAssignment::new(&assigned_datas, role, data)
+    }
+
+    /// iterate over all data assignments of the service dependency
+    pub fn assigned_data(&self) -> impl Iterator<Item = RoleBasedDataAssignment> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::AssignedDatas)
+            .into_iter()
+            .flat_map(|assigned_datas| assigned_datas.sub_elements())
+            .filter_map(|elem| RoleBasedDataAssignment::try_from(elem).ok())
+    }
+
+    /// Assign a port of the software component to the service dependency
+    ///
+    /// The port is typically connected to the service component which provides the service.
+    pub fn add_assigned_port<T: Into<PortPrototype> + Clone>(
+        &self,
+        port: &T,
+        role: Option<&str>,
+    ) -> Result<(), AutosarAbstractionError> {
+        let port: PortPrototype = port.clone().into();
+        let port_assignment = self
+            .element()
+            .get_or_create_sub_element(ElementName::AssignedPorts)?
+            .create_sub_element(ElementName::RoleBasedPortAssignment)?;
+        port_assignment
+            .create_sub_element(ElementName::PortPrototypeRef)?
+            .set_reference_target(port.element())?;
+        if let Some(role) = role {
+            port_assignment
+                .create_sub_element(ElementName::Role)?
+                .set_character_data(role)?;
+        }
+        Ok(())
+    }
+
+    /// iterate over all ports that are assigned to the service dependency, together with their roles
+    pub fn assigned_ports(&self) -> impl Iterator<Item = (PortPrototype, Option<String>)> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::AssignedPorts)
+            .into_iter()
+            .flat_map(|assigned_ports| assigned_ports.sub_elements())
+            .filter_map(|port_assignment| {
+                let port_elem = port_assignment
+                    .get_sub_element(ElementName::PortPrototypeRef)?
+                    .get_reference_target()
+                    .ok()?;
+                let port = PortPrototype::try_from(port_elem).ok()?;
+                let role = port_assignment
+                    .get_sub_element(ElementName::Role)
+                    .and_then(|elem| elem.character_data())
+                    .and_then(|cdata| cdata.string_value());
+                Some((port, role))
+            })
+    }
+}
+
+//##################################################################
+
+/// A `RoleBasedDataAssignment` assigns data of a software component to a `SwcServiceDependency`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RoleBasedDataAssignment(Element);
+abstraction_element!(RoleBasedDataAssignment, RoleBasedDataAssignment);
+
+impl RoleBasedDataAssignment {
+    pub(crate) fn new(parent: &Element, role: &str, data: &RoleBasedData) -> Result<Self, AutosarAbstractionError> {
+        let data_assignment = parent.create_sub_element(ElementName::RoleBasedDataAssignment)?;
+        data_assignment
+            .create_sub_element(ElementName::Role)?
+            .set_character_data(role)?;
+
+        match data {
+            RoleBasedData::LocalVariable(variable) => {
+                data_assignment
+                    .create_sub_element(ElementName::UsedDataElement)?
+                    .create_sub_element(ElementName::LocalVariableRef)?
+                    .set_reference_target(variable.element())?;
+            }
+            RoleBasedData::LocalParameter(parameter) => {
+                data_assignment
+                    .create_sub_element(ElementName::UsedParameterElement)?
+                    .create_sub_element(ElementName::LocalParameterRef)?
+                    .set_reference_target(parameter.element())?;
+            }
+            RoleBasedData::PerInstanceMemory(per_instance_memory) => {
+                data_assignment
+                    .create_sub_element(ElementName::UsedPimRef)?
+                    .set_reference_target(per_instance_memory.element())?;
+            }
+        }
+
+        Ok(Self(data_assignment))
+    }
+
+    /// get the role of the assigned data
+    #[must_use]
+    pub fn role(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::Role)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// get the assigned data
+    #[must_use]
+    pub fn data(&self) -> Option<RoleBasedData> {
+        if let Some(used_data_element) = self.element().get_sub_element(ElementName::UsedDataElement) {
+            let variable_elem = used_data_element
+                .get_sub_element(ElementName::LocalVariableRef)?
+                .get_reference_target()
+                .ok()?;
+            Some(RoleBasedData::LocalVariable(
+                VariableDataPrototype::try_from(variable_elem).ok()?,
+            ))
+        } else if let Some(used_parameter_element) = self.element().get_sub_element(ElementName::UsedParameterElement) {
+            let parameter_elem = used_parameter_element
+                .get_sub_element(ElementName::LocalParameterRef)?
+                .get_reference_target()
+                .ok()?;
+            Some(RoleBasedData::LocalParameter(
+                ParameterDataPrototype::try_from(parameter_elem).ok()?,
+            ))
+        } else {
+            let pim_elem = self
+                .element()
+                .get_sub_element(ElementName::UsedPimRef)?
+                .get_reference_target()
+                .ok()?;
+            Some(RoleBasedData::PerInstanceMemory(
+                PerInstanceMemory::try_from(pim_elem).ok()?,
+            ))
+        }
+    }
+}
+
+//##################################################################
+
+/// The data of a software component that can be assigned to a `SwcServiceDependency`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RoleBasedData {
+    /// a variable of the `SwcInternalBehavior`, e.g. an ar-typed per-instance memory
+    LocalVariable(VariableDataPrototype),
+    /// a parameter of the `SwcInternalBehavior`
+    LocalParameter(ParameterDataPrototype),
+    /// a `PerInstanceMemory` with a C type
+    PerInstanceMemory(PerInstanceMemory),
+}
+
+//##################################################################
+
+/// `NvBlockNeeds` specify the requirements of a software component on a block of the NvM
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NvBlockNeeds(Element);
+abstraction_element!(NvBlockNeeds, NvBlockNeeds);
+impl IdentifiableAbstractionElement for NvBlockNeeds {}
+
+impl NvBlockNeeds {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let nv_block_needs = parent.create_named_sub_element(ElementName::NvBlockNeeds, name)?;
+
+        Ok(Self(nv_block_needs))
+    }
+
+    /// set the number of data sets of the NV block
+    pub fn set_n_data_sets(&self, n_data_sets: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(n_data_sets) = n_data_sets {
+            self.element()
+                .get_or_create_sub_element(ElementName::NDataSets)?
+                .set_character_data(u64::from(n_data_sets))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::NDataSets);
+        }
+        Ok(())
+    }
+
+    /// get the number of data sets of the NV block
+    #[must_use]
+    pub fn n_data_sets(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::NDataSets)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// set whether the NV block is restored from non-volatile memory when the ECU starts
+    pub fn set_restore_at_start(&self, restore_at_start: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        if let Some(restore_at_start) = restore_at_start {
+            self.element()
+                .get_or_create_sub_element(ElementName::RestoreAtStart)?
+                .set_character_data(restore_at_start)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::RestoreAtStart);
+        }
+        Ok(())
+    }
+
+    /// get whether the NV block is restored from non-volatile memory when the ECU starts
+    #[must_use]
+    pub fn restore_at_start(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::RestoreAtStart)?
+            .character_data()?
+            .parse_bool()
+    }
+
+    /// set whether the NV block is written to non-volatile memory when the ECU shuts down
+    pub fn set_store_at_shutdown(&self, store_at_shutdown: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        if let Some(store_at_shutdown) = store_at_shutdown {
+            self.element()
+                .get_or_create_sub_element(ElementName::StoreAtShutdown)?
+                .set_character_data(store_at_shutdown)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::StoreAtShutdown);
+        }
+        Ok(())
+    }
+
+    /// get whether the NV block is written to non-volatile memory when the ECU shuts down
+    #[must_use]
+    pub fn store_at_shutdown(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::StoreAtShutdown)?
+            .character_data()?
+            .parse_bool()
+    }
+}
+
+//##################################################################
+
+/// `DiagnosticEventNeeds` specify the requirements of a software component on a diagnostic event of the Dem
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiagnosticEventNeeds(Element);
+abstraction_element!(DiagnosticEventNeeds, DiagnosticEventNeeds);
+impl IdentifiableAbstractionElement for DiagnosticEventNeeds {}
+
+impl DiagnosticEventNeeds {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let diagnostic_event_needs = parent.create_named_sub_element(ElementName::DiagnosticEventNeeds, name)?;
+
+        Ok(Self(diagnostic_event_needs))
+    }
+}
+
+//##################################################################
+
+/// `DiagnosticCommunicationManagerNeeds` specify the requirements of a software component on the Dcm
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiagnosticCommunicationManagerNeeds(Element);
+abstraction_element!(DiagnosticCommunicationManagerNeeds, DiagnosticCommunicationManagerNeeds);
+impl IdentifiableAbstractionElement for DiagnosticCommunicationManagerNeeds {}
+
+impl DiagnosticCommunicationManagerNeeds {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let dcm_needs = parent.create_named_sub_element(ElementName::DiagnosticCommunicationManagerNeeds, name)?;
+
+        Ok(Self(dcm_needs))
+    }
+}
+
+//##################################################################
+
+/// The `ServiceNeeds` enum represents the supported kinds of service needs of a `SwcServiceDependency`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceNeeds {
+    /// the service needs are `NvBlockNeeds`
+    NvBlock(NvBlockNeeds),
+    /// the service needs are `DiagnosticEventNeeds`
+    DiagnosticEvent(DiagnosticEventNeeds),
+    /// the service needs are `DiagnosticCommunicationManagerNeeds`
+    DiagnosticCommunicationManager(DiagnosticCommunicationManagerNeeds),
+}
+
+impl AbstractionElement for ServiceNeeds {
+    fn element(&self) -> &Element {
+        match self {
+            ServiceNeeds::NvBlock(needs) => needs.element(),
+            ServiceNeeds::DiagnosticEvent(needs) => needs.element(),
+            ServiceNeeds::DiagnosticCommunicationManager(needs) => needs.element(),
+        }
+    }
+}
+
+impl IdentifiableAbstractionElement for ServiceNeeds {}
+
+impl TryFrom<Element> for ServiceNeeds {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        match element.element_name() {
+            ElementName::NvBlockNeeds => Ok(ServiceNeeds::NvBlock(NvBlockNeeds(element))),
+            ElementName::DiagnosticEventNeeds => Ok(ServiceNeeds::DiagnosticEvent(DiagnosticEventNeeds(element))),
+            ElementName::DiagnosticCommunicationManagerNeeds => Ok(ServiceNeeds::DiagnosticCommunicationManager(
+                DiagnosticCommunicationManagerNeeds(element),
+            )),
+            _ => Err(AutosarAbstractionError::ConversionError {
+                element,
+                dest: "ServiceNeeds".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AutosarModelAbstraction,
+        datatype::ApplicationPrimitiveCategory,
+        software_component::{AbstractSwComponentType, AtomicSwComponentType},
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn service_dependency() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+
+        let data_type = package
+            .create_application_primitive_data_type("uint32", ApplicationPrimitiveCategory::Value, None, None, None)
+            .unwrap();
+        let client_server_interface = package.create_client_server_interface("NvMService").unwrap();
+        let app_swc = package
+            .create_application_sw_component_type("AppSwComponentType")
+            .unwrap();
+        let r_port = app_swc.create_r_port("NvM_Block", &client_server_interface).unwrap();
+        let swc_internal_behavior = app_swc
+            .create_swc_internal_behavior("AppSwComponentType_InternalBehavior")
+            .unwrap();
+        let ram_block = swc_internal_behavior
+            .create_ar_typed_per_instance_memory("RamBlock", &data_type)
+            .unwrap();
+        let rom_block = swc_internal_behavior
+            .create_shared_parameter("RomBlock", &data_type)
+            .unwrap();
+        let pim = swc_internal_behavior
+            .create_per_instance_memory("Pim", "uint32", "uint32")
+            .unwrap();
+
+        let service_dependency = swc_internal_behavior
+            .create_service_dependency("NvBlockDependency")
+            .unwrap();
+        assert_eq!(swc_internal_behavior.service_dependencies().count(), 1);
+        assert_eq!(
+            service_dependency.swc_internal_behavior().unwrap(),
+            swc_internal_behavior
+        );
+        assert!(service_dependency.service_needs().is_none());
+
+        // NvBlockNeeds
+        let nv_block_needs = service_dependency.create_nv_block_needs("NvBlockNeeds").unwrap();
+        nv_block_needs.set_n_data_sets(Some(1)).unwrap();
+        nv_block_needs.set_restore_at_start(Some(true)).unwrap();
+        nv_block_needs.set_store_at_shutdown(Some(false)).unwrap();
+        assert_eq!(nv_block_needs.n_data_sets(), Some(1));
+        assert_eq!(nv_block_needs.restore_at_start(), Some(true));
+        assert_eq!(nv_block_needs.store_at_shutdown(), Some(false));
+        nv_block_needs.set_n_data_sets(None).unwrap();
+        assert_eq!(nv_block_needs.n_data_sets(), None);
+        assert_eq!(
+            service_dependency.service_needs().unwrap(),
+            ServiceNeeds::NvBlock(nv_block_needs)
+        );
+
+        // role-based assignments
+        let ram_assignment = service_dependency
+            .create_assigned_data("ramBlock", &RoleBasedData::LocalVariable(ram_block.clone()))
+            .unwrap();
+        let rom_assignment = service_dependency
+            .create_assigned_data("defaultValue", &RoleBasedData::LocalParameter(rom_block.clone()))
+            .unwrap();
+        let pim_assignment = service_dependency
+            .create_assigned_data("pim", &RoleBasedData::PerInstanceMemory(pim.clone()))
+            .unwrap();
+        assert_eq!(service_dependency.assigned_data().count(), 3);
+        assert_eq!(ram_assignment.role().unwrap(), "ramBlock");
+        assert_eq!(ram_assignment.data().unwrap(), RoleBasedData::LocalVariable(ram_block));
+        assert_eq!(rom_assignment.data().unwrap(), RoleBasedData::LocalParameter(rom_block));
+        assert_eq!(pim_assignment.data().unwrap(), RoleBasedData::PerInstanceMemory(pim));
+
+        service_dependency.add_assigned_port(&r_port, Some("NvM")).unwrap();
+        let assigned_ports: Vec<_> = service_dependency.assigned_ports().collect();
+        assert_eq!(assigned_ports.len(), 1);
+        assert_eq!(assigned_ports[0], (r_port.into(), Some("NvM".to_string())));
+
+        // replace the service needs
+        let event_needs = service_dependency.create_diagnostic_event_needs("EventNeeds").unwrap();
+        assert_eq!(
+            service_dependency.service_needs().unwrap(),
+            ServiceNeeds::DiagnosticEvent(event_needs)
+        );
+        let dcm_needs = service_dependency
+            .create_diagnostic_communication_manager_needs("DcmNeeds")
+            .unwrap();
+        let service_needs = service_dependency.service_needs().unwrap();
+        assert_eq!(service_needs.name().unwrap(), "DcmNeeds");
+        assert_eq!(service_needs, ServiceNeeds::DiagnosticCommunicationManager(dcm_needs));
+    }
+}
@@ -4,21 +4,28 @@
 //! It also contains the definition of the composition hierarchy, and the connectors between components.
 
 use crate::{
-    AbstractionElement, ArPackage, AutosarAbstractionError, Element, IdentifiableAbstractionElement, SwcToEcuMapping,
-    abstraction_element, get_reference_parents,
+    AbstractionElement, ArPackage, AutosarAbstractionError, Element, FlatMap, IdentifiableAbstractionElement,
+    SwcToEcuMapping, abstraction_element, get_reference_parents, make_unique_generated_name,
 };
-use autosar_data::ElementName;
+use autosar_data::{AutosarDataError, ElementName};
+use std::collections::HashSet;
 
+mod comspec;
 mod connector;
+mod instance_ref;
 mod interface;
 mod internal_behavior;
 mod mode;
+mod nv_block;
 mod port;
 
+pub use comspec::*;
 pub use connector::*;
+pub use instance_ref::*;
 pub use interface::*;
 pub use internal_behavior::*;
 pub use mode::*;
+pub use nv_block::*;
 pub use port::*;
 
 //##################################################################
@@ -97,6 +104,15 @@ pub trait AbstractSwComponentType: IdentifiableAbstractionElement {
         let port_groups = self.element().get_or_create_sub_element(ElementName::PortGroups)?;
         PortGroup::new(name, &port_groups)
     }
+
+    /// iterate over all port groups of the component
+    fn port_groups(&self) -> impl Iterator<Item = PortGroup> + Send + use<Self> {
+        self.element()
+            .get_sub_element(ElementName::PortGroups)
+            .into_iter()
+            .flat_map(|port_groups| port_groups.sub_elements())
+            .filter_map(|elem| PortGroup::try_from(elem).ok())
+    }
 }
 
 //##################################################################
@@ -229,7 +245,8 @@ impl CompositionSwComponentType {
 
     /// create a new delegation connector between an inner port and an outer port
     ///
-    /// The two ports must be compatible.
+    /// The interfaces of the two ports must be compatible, see [`PortInterface::is_compatible_with`].
+    /// For P ports the inner port is the provider, for R ports the outer port is the provider.
     pub fn create_delegation_connector<T1: Into<PortPrototype> + Clone, T2: Into<PortPrototype> + Clone>(
         &self,
         name: &str,
@@ -254,21 +271,11 @@ impl CompositionSwComponentType {
         inner_sw_prototype: &SwComponentPrototype,
         outer_port: &PortPrototype,
     ) -> Result<DelegationSwConnector, AutosarAbstractionError> {
-        // check the compatibility of the interfaces
-        let interface_1 = inner_port
-            .port_interface()
-            .ok_or(AutosarAbstractionError::InvalidParameter(
-                "Invalid port lacks a port interface".to_string(),
-            ))?;
-        let interface_2 = outer_port
-            .port_interface()
-            .ok_or(AutosarAbstractionError::InvalidParameter(
-                "Invalid port lacks a port interface".to_string(),
-            ))?;
-        if std::mem::discriminant(&interface_1) != std::mem::discriminant(&interface_2) {
-            return Err(AutosarAbstractionError::InvalidParameter(
-                "The interfaces of the two ports are not compatible".to_string(),
-            ));
+        // check the compatibility of the interfaces; a delegated R port receives its data from the outer port
+        if matches!(inner_port, PortPrototype::R(_)) {
+            check_interface_compatibility(outer_port, inner_port)?;
+        } else {
+            check_interface_compatibility(inner_port, outer_port)?;
         }
 
         // check that the inner port is part of the inner component
@@ -305,9 +312,208 @@ impl CompositionSwComponentType {
         )
     }
 
+    /// create all delegation connectors that are needed to connect a port of the composition to a port of a nested component
+    ///
+    /// The component containing the `inner_port` may be nested arbitrarily deep inside of the composition.
+    /// Each intermediate composition on the way to the inner component gets a port with the same kind and
+    /// port interface as the inner port, unless a matching port with the same name already exists.
+    /// If the inner component is instantiated more than once, the first instance that is found is connected.
+    ///
+    /// The delegation connectors are returned in order, starting with the connector in this composition.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the outer port is not part of the composition,
+    ///   or the component of the inner port is not contained in the composition
+    /// - [`AutosarAbstractionError::InvalidParameter`] the ports are not of the same kind,
+    ///   or their port interfaces are not compatible
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    ///
+    /// All checks are performed before the model is modified. If an error occurs while the path is created,
+    /// then all ports and connectors that were already created are removed again.
+    pub fn create_connection_path<T1: Into<PortPrototype> + Clone, T2: Into<PortPrototype> + Clone>(
+        &self,
+        outer_port: &T1,
+        inner_port: &T2,
+    ) -> Result<Vec<DelegationSwConnector>, AutosarAbstractionError> {
+        self.create_connection_path_internal(&outer_port.clone().into(), &inner_port.clone().into())
+    }
+
+    fn create_connection_path_internal(
+        &self,
+        outer_port: &PortPrototype,
+        inner_port: &PortPrototype,
+    ) -> Result<Vec<DelegationSwConnector>, AutosarAbstractionError> {
+        // resolve and check the complete path before the model is modified
+        let swc_self: SwComponentType = self.clone().into();
+        if outer_port.component_type()? != swc_self {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The outer port must be part of the composition".to_string(),
+            ));
+        }
+        if std::mem::discriminant(outer_port) != std::mem::discriminant(inner_port) {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The outer port and the inner port must be of the same kind (P, R or PR)".to_string(),
+            ));
+        }
+        let port_interface = inner_port
+            .port_interface()
+            .ok_or(AutosarAbstractionError::InvalidParameter(
+                "Invalid port lacks a port interface".to_string(),
+            ))?;
+        // all intermediate ports use the interface of the inner port, so only the outer port needs to be checked
+        if matches!(inner_port, PortPrototype::R(_)) {
+            check_interface_compatibility(outer_port, inner_port)?;
+        } else {
+            check_interface_compatibility(inner_port, outer_port)?;
+        }
+        let prototype_path = self.find_component_path(&inner_port.component_type()?).ok_or(
+            AutosarAbstractionError::InvalidParameter(
+                "The component of the inner port is not contained in the composition".to_string(),
+            ),
+        )?;
+        // all prototypes except for the last one are instances of intermediate compositions
+        let (_, intermediate_prototypes) =
+            prototype_path
+                .split_last()
+                .ok_or(AutosarAbstractionError::InvalidParameter(
+                    "Invalid composition hierarchy".to_string(),
+                ))?;
+        let nested_compositions = intermediate_prototypes
+            .iter()
+            .map(|sw_prototype| match sw_prototype.component_type() {
+                Some(SwComponentType::Composition(nested_composition)) => Ok(nested_composition),
+                _ => Err(AutosarAbstractionError::InvalidParameter(
+                    "Invalid composition hierarchy".to_string(),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut connectors = Vec::with_capacity(prototype_path.len());
+        let mut created_ports = Vec::new();
+        let result = self.create_connection_path_elements(
+            outer_port,
+            inner_port,
+            &port_interface,
+            &prototype_path,
+            &nested_compositions,
+            &mut connectors,
+            &mut created_ports,
+        );
+        if let Err(err) = result {
+            // remove everything that was created before the error occurred
+            for connector in connectors {
+                connector.remove(false)?;
+            }
+            for port in created_ports {
+                port.remove(false)?;
+            }
+            return Err(err);
+        }
+
+        Ok(connectors)
+    }
+
+    // create the ports and delegation connectors of a connection path that has already been checked
+    #[allow(clippy::too_many_arguments)]
+    fn create_connection_path_elements(
+        &self,
+        outer_port: &PortPrototype,
+        inner_port: &PortPrototype,
+        port_interface: &PortInterface,
+        prototype_path: &[SwComponentPrototype],
+        nested_compositions: &[CompositionSwComponentType],
+        connectors: &mut Vec<DelegationSwConnector>,
+        created_ports: &mut Vec<PortPrototype>,
+    ) -> Result<(), AutosarAbstractionError> {
+        let model = self.element().model()?;
+        let connector_name = format!("{}_delegation", inner_port.name().ok_or(AutosarDataError::ItemDeleted)?);
+        let mut composition = self.clone();
+        let mut current_outer_port = outer_port.clone();
+        for (idx, sw_prototype) in prototype_path.iter().enumerate() {
+            let current_inner_port = if let Some(nested_composition) = nested_compositions.get(idx) {
+                let (port, created) = nested_composition.get_or_create_matching_port(inner_port, port_interface)?;
+                if created {
+                    created_ports.push(port.clone());
+                }
+                port
+            } else {
+                inner_port.clone()
+            };
+
+            let name = make_unique_generated_name(&model, &composition.element().path()?, &connector_name);
+            let connector = composition.create_delegation_connector_internal(
+                &name,
+                &current_inner_port,
+                sw_prototype,
+                &current_outer_port,
+            )?;
+            connectors.push(connector);
+
+            if let Some(nested_composition) = nested_compositions.get(idx) {
+                composition = nested_composition.clone();
+                current_outer_port = current_inner_port;
+            }
+        }
+
+        Ok(())
+    }
+
+    // find the chain of component prototypes leading from this composition to an instance of the component type
+    fn find_component_path(&self, component_type: &SwComponentType) -> Option<Vec<SwComponentPrototype>> {
+        let mut work_items = vec![(self.clone(), Vec::new())];
+        // each composition is only searched once, which also prevents infinite loops if the hierarchy contains cycles
+        let mut visited = HashSet::from([self.clone()]);
+        while let Some((composition, path)) = work_items.pop() {
+            for sw_prototype in composition.components() {
+                let Some(prototype_type) = sw_prototype.component_type() else {
+                    continue;
+                };
+                let mut prototype_path: Vec<SwComponentPrototype> = path.clone();
+                prototype_path.push(sw_prototype);
+                if &prototype_type == component_type {
+                    return Some(prototype_path);
+                }
+                if let SwComponentType::Composition(nested_composition) = prototype_type
+                    && visited.insert(nested_composition.clone())
+                {
+                    work_items.push((nested_composition, prototype_path));
+                }
+            }
+        }
+
+        None
+    }
+
+    // get the port of the composition that has the same name, kind and port interface as the template port
+    // if there is no such port, then a new one is created; the returned flag is true if the port was created
+    fn get_or_create_matching_port(
+        &self,
+        template_port: &PortPrototype,
+        port_interface: &PortInterface,
+    ) -> Result<(PortPrototype, bool), AutosarAbstractionError> {
+        let port_name = template_port.name().ok_or(AutosarDataError::ItemDeleted)?;
+        if let Some(port) = self.ports().find(|port| port.name().as_deref() == Some(&port_name))
+            && std::mem::discriminant(&port) == std::mem::discriminant(template_port)
+            && port.port_interface().as_ref() == Some(port_interface)
+        {
+            return Ok((port, false));
+        }
+
+        let model = self.element().model()?;
+        let port_name = make_unique_generated_name(&model, &self.element().path()?, &port_name);
+        let port = match template_port {
+            PortPrototype::R(_) => self.create_r_port(&port_name, port_interface)?.into(),
+            PortPrototype::P(_) => self.create_p_port(&port_name, port_interface)?.into(),
+            PortPrototype::PR(_) => self.create_pr_port(&port_name, port_interface)?.into(),
+        };
+        Ok((port, true))
+    }
+
     /// create a new assembly connector between two ports of contained software components
     ///
-    /// The two ports must be compatible.
+    /// The interfaces of the two ports must be compatible, see [`PortInterface::is_compatible_with`].
+    /// The interface of the P port may provide more than the R port requires.
     pub fn create_assembly_connector<T1: Into<PortPrototype> + Clone, T2: Into<PortPrototype> + Clone>(
         &self,
         name: &str,
@@ -333,21 +539,11 @@ impl CompositionSwComponentType {
         port_2: &PortPrototype,
         sw_prototype_2: &SwComponentPrototype,
     ) -> Result<AssemblySwConnector, AutosarAbstractionError> {
-        // check the compatibility of the interfaces
-        let interface_1 = port_1
-            .port_interface()
-            .ok_or(AutosarAbstractionError::InvalidParameter(
-                "Invalid port lacks a port interface".to_string(),
-            ))?;
-        let interface_2 = port_2
-            .port_interface()
-            .ok_or(AutosarAbstractionError::InvalidParameter(
-                "Invalid port lacks a port interface".to_string(),
-            ))?;
-        if std::mem::discriminant(&interface_1) != std::mem::discriminant(&interface_2) {
-            return Err(AutosarAbstractionError::InvalidParameter(
-                "The interfaces of the two ports are not compatible".to_string(),
-            ));
+        // check the compatibility of the interfaces; the data flows from the P port to the R port
+        if matches!(port_1, PortPrototype::R(_)) {
+            check_interface_compatibility(port_2, port_1)?;
+        } else {
+            check_interface_compatibility(port_1, port_2)?;
         }
 
         // check that the ports are part of the correct components
@@ -394,7 +590,8 @@ impl CompositionSwComponentType {
 
     /// create a new passthrough connector between two outer ports of the composition
     ///
-    /// The two ports must be compatible.
+    /// The interfaces of the two ports must be compatible, see [`PortInterface::is_compatible_with`].
+    /// The R port receives the data from outside the composition, so it is the provider of the P port.
     pub fn create_pass_through_connector<T1: Into<PortPrototype> + Clone, T2: Into<PortPrototype> + Clone>(
         &self,
         name: &str,
@@ -410,21 +607,11 @@ impl CompositionSwComponentType {
         port_1: &PortPrototype,
         port_2: &PortPrototype,
     ) -> Result<PassThroughSwConnector, AutosarAbstractionError> {
-        // check the compatibility of the interfaces
-        let interface_1 = port_1
-            .port_interface()
-            .ok_or(AutosarAbstractionError::InvalidParameter(
-                "Invalid port lacks a port interface".to_string(),
-            ))?;
-        let interface_2 = port_2
-            .port_interface()
-            .ok_or(AutosarAbstractionError::InvalidParameter(
-                "Invalid port lacks a port interface".to_string(),
-            ))?;
-        if std::mem::discriminant(&interface_1) != std::mem::discriminant(&interface_2) {
-            return Err(AutosarAbstractionError::InvalidParameter(
-                "The interfaces of the two ports are not compatible".to_string(),
-            ));
+        // check the compatibility of the interfaces; the data enters the composition through the R port
+        if matches!(port_2, PortPrototype::R(_)) {
+            check_interface_compatibility(port_2, port_1)?;
+        } else {
+            check_interface_compatibility(port_1, port_2)?;
         }
 
         // decide what kind of connector to create
@@ -451,12 +638,181 @@ impl CompositionSwComponentType {
             .flat_map(|connectors| connectors.sub_elements())
             .filter_map(|elem| SwConnector::try_from(elem).ok())
     }
+
+    /// create assembly connectors between all matching ports of the contained components
+    ///
+    /// A required port (R or PR) is connected to a provided port (P or PR) of a different component in the composition
+    /// if both ports use the same port interface and if their names match.
+    /// When the names are compared, the case is ignored and the common prefixes and suffixes for
+    /// port directions (`P_`, `R_`, `PP_`, `RP_`, `_P`, `_R`, `_PP`, `_RP`) are removed.
+    /// Two PR ports are never connected to each other, since the direction of the connection would be ambiguous.
+    ///
+    /// A required port is only connected if there is exactly one matching provided port.
+    /// Ports that are already connected by an existing connector are skipped.
+    ///
+    /// The returned [`PortConnectionReport`] contains the newly created connectors, as well as all ports
+    /// of the contained components that are still not connected.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn connect_matching_ports(&self) -> Result<PortConnectionReport, AutosarAbstractionError> {
+        let model = self.element().model()?;
+        let base_path = self.element().path()?;
+
+        let all_ports: Vec<(SwComponentPrototype, PortPrototype)> = self
+            .components()
+            .filter_map(|sw_prototype| sw_prototype.component_type().map(|swc| (sw_prototype, swc)))
+            .flat_map(|(sw_prototype, swc)| swc.ports().map(move |port| (sw_prototype.clone(), port)))
+            .collect();
+        let mut connected_ports = self.connected_ports();
+
+        let mut connectors = Vec::new();
+        for (r_prototype, r_port) in &all_ports {
+            if matches!(r_port, PortPrototype::P(_)) || connected_ports.contains(&(r_prototype.clone(), r_port.clone()))
+            {
+                continue;
+            }
+            let Some(port_interface) = r_port.port_interface() else {
+                continue;
+            };
+            let r_port_name = r_port.name().unwrap_or_default();
+            let mut candidates = all_ports.iter().filter(|(p_prototype, p_port)| {
+                p_prototype != r_prototype
+                    && (matches!(p_port, PortPrototype::P(_))
+                        || (matches!(p_port, PortPrototype::PR(_)) && matches!(r_port, PortPrototype::R(_))))
+                    && p_port.port_interface().as_ref() == Some(&port_interface)
+                    && ports_names_match(&p_port.name().unwrap_or_default(), &r_port_name)
+            });
+            let (Some((p_prototype, p_port)), None) = (candidates.next(), candidates.next()) else {
+                // no matching provided port, or the match is ambiguous
+                continue;
+            };
+
+            let name = format!(
+                "{}_{}_{}_{}",
+                p_prototype.name().unwrap_or_default(),
+                p_port.name().unwrap_or_default(),
+                r_prototype.name().unwrap_or_default(),
+                r_port_name
+            );
+            let name = make_unique_generated_name(&model, &base_path, &name);
+            // the connector might be rejected, e.g. if a client-server PR port would be connected
+            if let Ok(connector) =
+                self.create_assembly_connector_internal(&name, p_port, p_prototype, r_port, r_prototype)
+            {
+                connected_ports.insert((p_prototype.clone(), p_port.clone()));
+                connected_ports.insert((r_prototype.clone(), r_port.clone()));
+                connectors.push(connector);
+            }
+        }
+
+        let unconnected_ports = all_ports
+            .into_iter()
+            .filter(|item| !connected_ports.contains(item))
+            .collect();
+
+        Ok(PortConnectionReport {
+            connectors,
+            unconnected_ports,
+        })
+    }
+
+    // collect the ports of the contained components which are used by any connector of the composition
+    fn connected_ports(&self) -> HashSet<(SwComponentPrototype, PortPrototype)> {
+        let mut connected_ports = HashSet::new();
+        for connector in self.connectors() {
+            match connector {
+                SwConnector::Assembly(assembly) => {
+                    if let (Some(p_prototype), Some(p_port)) = (assembly.p_sw_component(), assembly.p_port()) {
+                        connected_ports.insert((p_prototype, p_port));
+                    }
+                    if let (Some(r_prototype), Some(r_port)) = (assembly.r_sw_component(), assembly.r_port()) {
+                        connected_ports.insert((r_prototype, r_port));
+                    }
+                }
+                SwConnector::Delegation(delegation) => {
+                    if let (Some(inner_prototype), Some(inner_port)) =
+                        (delegation.inner_sw_component(), delegation.inner_port())
+                    {
+                        connected_ports.insert((inner_prototype, inner_port));
+                    }
+                }
+                SwConnector::PassThrough(_) => {}
+            }
+        }
+        connected_ports
+    }
 }
 
 impl AbstractSwComponentType for CompositionSwComponentType {}
 
 //##################################################################
 
+/// The result of [`CompositionSwComponentType::connect_matching_ports`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortConnectionReport {
+    /// The assembly connectors that were created
+    pub connectors: Vec<AssemblySwConnector>,
+    /// The ports of the contained components that are not connected to anything
+    pub unconnected_ports: Vec<(SwComponentPrototype, PortPrototype)>,
+}
+
+// compare two port names, ignoring the case and the common prefixes and suffixes for port directions
+fn ports_names_match(name_1: &str, name_2: &str) -> bool {
+    fn base_name(name: &str) -> String {
+        let mut name = name.to_ascii_lowercase();
+        for prefix in ["pp_", "rp_", "p_", "r_"] {
+            if let Some(stripped) = name.strip_prefix(prefix) {
+                name = stripped.to_string();
+                break;
+            }
+        }
+        for suffix in ["_pp", "_rp", "_p", "_r"] {
+            if let Some(stripped) = name.strip_suffix(suffix) {
+                name = stripped.to_string();
+                break;
+            }
+        }
+        name
+    }
+
+    base_name(name_1) == base_name(name_2)
+}
+
+// check that the interface of the source port satisfies the interface of the sink port, and describe the mismatch
+// if it does not. Data flows in both directions between two PR ports, so their interfaces must satisfy each other.
+fn check_interface_compatibility(
+    source_port: &PortPrototype,
+    sink_port: &PortPrototype,
+) -> Result<(), AutosarAbstractionError> {
+    let source_interface = source_port
+        .port_interface()
+        .ok_or(AutosarAbstractionError::InvalidParameter(
+            "Invalid port lacks a port interface".to_string(),
+        ))?;
+    let sink_interface = sink_port
+        .port_interface()
+        .ok_or(AutosarAbstractionError::InvalidParameter(
+            "Invalid port lacks a port interface".to_string(),
+        ))?;
+    let mut issue = source_interface.compatibility_issue(&sink_interface);
+    if issue.is_none() && matches!((source_port, sink_port), (PortPrototype::PR(_), PortPrototype::PR(_))) {
+        issue = sink_interface.compatibility_issue(&source_interface);
+    }
+    if let Some(issue) = issue {
+        return Err(AutosarAbstractionError::InvalidParameter(format!(
+            "The interfaces of the two ports are not compatible: {} and {}: {issue}",
+            source_interface.element().path().unwrap_or_default(),
+            sink_interface.element().path().unwrap_or_default(),
+        )));
+    }
+
+    Ok(())
+}
+
+//##################################################################
+
 /// An `ApplicationSwComponentType` is a software component that provides application functionality
 ///
 /// Use [`ArPackage::create_application_sw_component_type`] to create a new application sw component type.
@@ -589,6 +945,68 @@ impl AtomicSwComponentType for EcuAbstractionSwComponentType {}
 
 //##################################################################
 
+/// An `NvBlockSwComponentType` is a software component that provides access to blocks of non-volatile memory
+///
+/// The non-volatile data is described by `NvBlockDescriptor`s, and other components access it through ports
+/// typed by an `NvDataInterface`.
+///
+/// Use [`ArPackage::create_nv_block_sw_component_type`] to create a new nv block sw component type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NvBlockSwComponentType(Element);
+abstraction_element!(NvBlockSwComponentType, NvBlockSwComponentType);
+impl IdentifiableAbstractionElement for NvBlockSwComponentType {}
+
+impl NvBlockSwComponentType {
+    /// create a new nv block component with the given name
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let nv_block = elements.create_named_sub_element(ElementName::NvBlockSwComponentType, name)?;
+        Ok(Self(nv_block))
+    }
+
+    /// remove this `NvBlockSwComponentType` from the model
+    pub fn remove(self, deep: bool) -> Result<(), AutosarAbstractionError> {
+        for swc_internal_behavior in self.swc_internal_behaviors() {
+            swc_internal_behavior.remove(deep)?;
+        }
+        let ref_parents = get_reference_parents(self.element())?;
+
+        AbstractionElement::remove(self, deep)?;
+
+        for (named_parent, _parent) in ref_parents {
+            if named_parent.element_name() == ElementName::SwComponentPrototype
+                && let Ok(component) = SwComponentPrototype::try_from(named_parent)
+            {
+                component.remove(deep)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// create a new `NvBlockDescriptor` in the component
+    pub fn create_nv_block_descriptor(&self, name: &str) -> Result<NvBlockDescriptor, AutosarAbstractionError> {
+        let descriptors = self
+            .element()
+            .get_or_create_sub_element(ElementName::NvBlockDescriptors)?;
+        NvBlockDescriptor::new(name, &descriptors)
+    }
+
+    /// iterate over all `NvBlockDescriptor`s of the component
+    pub fn nv_block_descriptors(&self) -> impl Iterator<Item = NvBlockDescriptor> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::NvBlockDescriptors)
+            .into_iter()
+            .flat_map(|descriptors| descriptors.sub_elements())
+            .filter_map(|elem| NvBlockDescriptor::try_from(elem).ok())
+    }
+}
+
+impl AbstractSwComponentType for NvBlockSwComponentType {}
+impl AtomicSwComponentType for NvBlockSwComponentType {}
+
+//##################################################################
+
 /// The `SwComponentType` enum represents all possible types of software components
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SwComponentType {
@@ -604,6 +1022,8 @@ pub enum SwComponentType {
     SensorActuator(SensorActuatorSwComponentType),
     /// the component is `EcuAbstractionSwComponentType`
     EcuAbstraction(EcuAbstractionSwComponentType),
+    /// the component is `NvBlockSwComponentType`
+    NvBlock(NvBlockSwComponentType),
 }
 
 impl AbstractionElement for SwComponentType {
@@ -615,6 +1035,7 @@ impl AbstractionElement for SwComponentType {
             SwComponentType::Service(service) => service.element(),
             SwComponentType::SensorActuator(sensor_actuator) => sensor_actuator.element(),
             SwComponentType::EcuAbstraction(ecu_abstraction) => ecu_abstraction.element(),
+            SwComponentType::NvBlock(nv_block) => nv_block.element(),
         }
     }
 }
@@ -642,6 +1063,7 @@ impl TryFrom<Element> for SwComponentType {
             ElementName::EcuAbstractionSwComponentType => {
                 Ok(SwComponentType::EcuAbstraction(EcuAbstractionSwComponentType(element)))
             }
+            ElementName::NvBlockSwComponentType => Ok(SwComponentType::NvBlock(NvBlockSwComponentType(element))),
             _ => Err(AutosarAbstractionError::ConversionError {
                 element,
                 dest: "SwComponentType".to_string(),
@@ -686,6 +1108,12 @@ impl From<EcuAbstractionSwComponentType> for SwComponentType {
     }
 }
 
+impl From<NvBlockSwComponentType> for SwComponentType {
+    fn from(nv_block: NvBlockSwComponentType) -> Self {
+        SwComponentType::NvBlock(nv_block)
+    }
+}
+
 impl AbstractSwComponentType for SwComponentType {}
 
 impl SwComponentType {
@@ -698,6 +1126,7 @@ impl SwComponentType {
             SwComponentType::Service(service) => service.remove(deep),
             SwComponentType::SensorActuator(sensor_actuator) => sensor_actuator.remove(deep),
             SwComponentType::EcuAbstraction(ecu_abstraction) => ecu_abstraction.remove(deep),
+            SwComponentType::NvBlock(nv_block) => nv_block.remove(deep),
         }
     }
 }
@@ -757,6 +1186,118 @@ impl SwComponentPrototype {
         let parent = self.element().named_parent()?.unwrap();
         CompositionSwComponentType::try_from(parent)
     }
+
+    /// change the component type of this prototype
+    ///
+    /// The connectors of the parent composition that reference ports of this prototype are updated to use
+    /// the port with the same name in the new component type. A connector can only be updated if the new port
+    /// has the same kind (P, R or PR) and a compatible port interface, see [`PortInterface::is_compatible_with`].
+    /// Connectors that can't be updated are left unchanged and are listed in the returned
+    /// [`ComponentTypeChangeReport`], so that they can be fixed or removed.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the new type would create a cycle in the composition hierarchy
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn change_type<T: Into<SwComponentType> + Clone>(
+        &self,
+        component_type: &T,
+    ) -> Result<ComponentTypeChangeReport, AutosarAbstractionError> {
+        let component_type = component_type.clone().into();
+        let parent_composition = self.parent_composition()?;
+        if let SwComponentType::Composition(composition) = &component_type
+            && (composition == &parent_composition || composition.is_parent_of(&parent_composition))
+        {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "Creating a cycle in the composition hierarchy".to_string(),
+            ));
+        }
+
+        self.element()
+            .get_or_create_sub_element(ElementName::TypeTref)?
+            .set_reference_target(component_type.element())?;
+
+        let mut report = ComponentTypeChangeReport::default();
+        for connector in parent_composition.connectors() {
+            // collect the port references in the connector whose context is this prototype
+            let port_refs: Vec<Element> = connector
+                .element()
+                .elements_dfs()
+                .filter(|(_, elem)| {
+                    matches!(
+                        elem.element_name(),
+                        ElementName::TargetPPortRef | ElementName::TargetRPortRef
+                    )
+                })
+                .map(|(_, elem)| elem)
+                .filter(|port_ref| {
+                    port_ref
+                        .parent()
+                        .ok()
+                        .flatten()
+                        .and_then(|iref| iref.get_sub_element(ElementName::ContextComponentRef))
+                        .and_then(|context_ref| context_ref.get_reference_target().ok())
+                        .as_ref()
+                        == Some(self.element())
+                })
+                .collect();
+            if port_refs.is_empty() {
+                continue;
+            }
+
+            // find replacements for all ports before modifying anything
+            let replacements: Option<Vec<(Element, PortPrototype)>> = port_refs
+                .into_iter()
+                .map(|port_ref| {
+                    let old_port = PortPrototype::try_from(port_ref.get_reference_target().ok()?).ok()?;
+                    let new_port = matching_port(&component_type, &old_port)?;
+                    Some((port_ref, new_port))
+                })
+                .collect();
+
+            if let Some(replacements) = replacements {
+                for (port_ref, new_port) in replacements {
+                    port_ref.set_reference_target(new_port.element())?;
+                }
+                report.updated_connectors.push(connector);
+            } else {
+                report.invalid_connectors.push(connector);
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+// find the port of the component type that can replace the old port in a connector
+fn matching_port(component_type: &SwComponentType, old_port: &PortPrototype) -> Option<PortPrototype> {
+    let old_name = old_port.name()?;
+    let new_port = component_type
+        .ports()
+        .find(|port| port.name().as_deref() == Some(&*old_name))?;
+    let old_interface = old_port.port_interface()?;
+    let new_interface = new_port.port_interface()?;
+    // a new P port must provide everything that the old port provided, a new R port must not require more
+    let compatible = match (&new_port, old_port) {
+        (PortPrototype::P(_), PortPrototype::P(_)) => new_interface.is_compatible_with(&old_interface),
+        (PortPrototype::R(_), PortPrototype::R(_)) => old_interface.is_compatible_with(&new_interface),
+        (PortPrototype::PR(_), PortPrototype::PR(_)) => {
+            new_interface.is_compatible_with(&old_interface) && old_interface.is_compatible_with(&new_interface)
+        }
+        _ => false,
+    };
+    compatible.then_some(new_port)
+}
+
+//##################################################################
+
+/// The result of [`SwComponentPrototype::change_type`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentTypeChangeReport {
+    /// The connectors that now reference the ports of the new component type
+    pub updated_connectors: Vec<SwConnector>,
+    /// The connectors that could not be updated, because the new component type has no matching port
+    pub invalid_connectors: Vec<SwConnector>,
 }
 
 //##################################################################
@@ -792,6 +1333,29 @@ impl RootSwCompositionPrototype {
             .ok()?;
         CompositionSwComponentType::try_from(composition_elem).ok()
     }
+
+    /// set or remove the `FlatMap` of the root composition
+    pub fn set_flat_map(&self, flat_map: Option<&FlatMap>) -> Result<(), AutosarAbstractionError> {
+        if let Some(flat_map) = flat_map {
+            self.element()
+                .get_or_create_sub_element(ElementName::FlatMapRef)?
+                .set_reference_target(flat_map.element())?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::FlatMapRef);
+        }
+        Ok(())
+    }
+
+    /// get the `FlatMap` of the root composition
+    #[must_use]
+    pub fn flat_map(&self) -> Option<FlatMap> {
+        let flat_map_elem = self
+            .element()
+            .get_sub_element(ElementName::FlatMapRef)?
+            .get_reference_target()
+            .ok()?;
+        FlatMap::try_from(flat_map_elem).ok()
+    }
 }
 
 //##################################################################
@@ -859,7 +1423,7 @@ impl ComponentPrototype {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{AutosarModelAbstraction, SystemCategory};
+    use crate::{AutosarModelAbstraction, SystemCategory, datatype::BaseTypeEncoding};
     use autosar_data::AutosarVersion;
 
     #[test]
@@ -1057,6 +1621,192 @@ mod test {
         comp_parent_type.create_port_group("group").unwrap();
     }
 
+    #[test]
+    fn connect_matching_ports() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+
+        let composition = package.create_composition_sw_component_type("composition").unwrap();
+        let provider_type = package.create_application_sw_component_type("provider").unwrap();
+        let consumer_type = package.create_application_sw_component_type("consumer").unwrap();
+        let provider = composition.create_component("provider", &provider_type).unwrap();
+        let consumer_1 = composition.create_component("consumer_1", &consumer_type).unwrap();
+        let consumer_2 = composition.create_component("consumer_2", &consumer_type).unwrap();
+
+        let sr_interface = package.create_sender_receiver_interface("sr").unwrap();
+        let other_interface = package.create_sender_receiver_interface("other").unwrap();
+        let cs_interface = package.create_client_server_interface("cs").unwrap();
+
+        // matching names after removing the direction prefixes and suffixes
+        provider_type.create_p_port("P_Speed", &sr_interface).unwrap();
+        consumer_type.create_r_port("speed_r", &sr_interface).unwrap();
+        // the names match, but the interfaces are different
+        provider_type.create_p_port("Temperature", &sr_interface).unwrap();
+        consumer_type.create_r_port("Temperature", &other_interface).unwrap();
+        // client-server ports with identical names
+        provider_type.create_p_port("Service", &cs_interface).unwrap();
+        consumer_type.create_r_port("Service", &cs_interface).unwrap();
+
+        let report = composition.connect_matching_ports().unwrap();
+        // speed and service are connected for both consumers
+        assert_eq!(report.connectors.len(), 4);
+        assert_eq!(composition.connectors().count(), 4);
+        // temperature remains unconnected in the provider and in both consumers
+        assert_eq!(report.unconnected_ports.len(), 3);
+        assert!(report.unconnected_ports.iter().any(|(proto, _)| *proto == provider));
+        assert!(report.unconnected_ports.iter().any(|(proto, _)| *proto == consumer_1));
+        assert!(report.unconnected_ports.iter().any(|(proto, _)| *proto == consumer_2));
+        assert!(
+            report
+                .unconnected_ports
+                .iter()
+                .all(|(_, port)| port.name().unwrap() == "Temperature")
+        );
+
+        // running the connection again does not create duplicate connectors
+        let report = composition.connect_matching_ports().unwrap();
+        assert!(report.connectors.is_empty());
+        assert_eq!(report.unconnected_ports.len(), 3);
+        assert_eq!(composition.connectors().count(), 4);
+    }
+
+    #[test]
+    fn change_component_type() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+
+        let composition = package.create_composition_sw_component_type("composition").unwrap();
+        let provider_type = package.create_application_sw_component_type("provider").unwrap();
+        let consumer_type = package.create_application_sw_component_type("consumer").unwrap();
+        let provider = composition.create_component("provider", &provider_type).unwrap();
+        let consumer = composition.create_component("consumer", &consumer_type).unwrap();
+
+        let sr_interface = package.create_sender_receiver_interface("sr").unwrap();
+        let cs_interface = package.create_client_server_interface("cs").unwrap();
+        let speed_p = provider_type.create_p_port("speed", &sr_interface).unwrap();
+        let speed_r = consumer_type.create_r_port("speed", &sr_interface).unwrap();
+        let service_p = provider_type.create_p_port("service", &cs_interface).unwrap();
+        let service_r = consumer_type.create_r_port("service", &cs_interface).unwrap();
+        let outer_port = composition.create_p_port("outer_speed", &sr_interface).unwrap();
+        let speed_connector = composition
+            .create_assembly_connector("speed_connector", &speed_p, &provider, &speed_r, &consumer)
+            .unwrap();
+        let service_connector = composition
+            .create_assembly_connector("service_connector", &service_p, &provider, &service_r, &consumer)
+            .unwrap();
+        let delegation = composition
+            .create_delegation_connector("delegation", &speed_p, &provider, &outer_port)
+            .unwrap();
+
+        // the new provider type has a matching speed port, but the service port uses a different kind of interface
+        let new_provider_type = package.create_application_sw_component_type("new_provider").unwrap();
+        let new_speed_p = new_provider_type.create_p_port("speed", &sr_interface).unwrap();
+        new_provider_type.create_p_port("service", &sr_interface).unwrap();
+
+        let report = provider.change_type(&new_provider_type).unwrap();
+        assert_eq!(
+            provider.component_type().unwrap(),
+            SwComponentType::Application(new_provider_type.clone())
+        );
+        assert_eq!(report.updated_connectors.len(), 2);
+        assert!(
+            report
+                .updated_connectors
+                .contains(&SwConnector::Assembly(speed_connector.clone()))
+        );
+        assert!(
+            report
+                .updated_connectors
+                .contains(&SwConnector::Delegation(delegation.clone()))
+        );
+        assert_eq!(
+            report.invalid_connectors,
+            vec![SwConnector::Assembly(service_connector.clone())]
+        );
+        assert_eq!(speed_connector.p_port().unwrap(), new_speed_p.clone().into());
+        assert_eq!(speed_connector.r_port().unwrap(), speed_r.clone().into());
+        assert_eq!(delegation.inner_port().unwrap(), new_speed_p.clone().into());
+        // the invalid connector is not modified
+        assert_eq!(service_connector.p_port().unwrap(), service_p.clone().into());
+
+        // a composition can't be used as the type of a prototype inside of itself
+        let result = provider.change_type(&composition);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn connection_path() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+
+        // comp_outer contains comp_mid, which contains comp_inner, which contains swc
+        let comp_outer_type = package.create_composition_sw_component_type("comp_outer").unwrap();
+        let comp_mid_type = package.create_composition_sw_component_type("comp_mid").unwrap();
+        let comp_inner_type = package.create_composition_sw_component_type("comp_inner").unwrap();
+        let swc_type = package.create_application_sw_component_type("swc_type").unwrap();
+        comp_outer_type.create_component("comp_mid", &comp_mid_type).unwrap();
+        comp_mid_type.create_component("comp_inner", &comp_inner_type).unwrap();
+        comp_inner_type.create_component("swc", &swc_type).unwrap();
+
+        let port_interface = package.create_sender_receiver_interface("sr").unwrap();
+        let outer_port = comp_outer_type.create_r_port("outer_port", &port_interface).unwrap();
+        let swc_port = swc_type.create_r_port("swc_port", &port_interface).unwrap();
+        // a matching port already exists in comp_mid and is reused
+        let mid_port = comp_mid_type.create_r_port("swc_port", &port_interface).unwrap();
+
+        let connectors = comp_outer_type.create_connection_path(&outer_port, &swc_port).unwrap();
+        assert_eq!(connectors.len(), 3);
+        assert_eq!(comp_outer_type.connectors().count(), 1);
+        assert_eq!(comp_mid_type.connectors().count(), 1);
+        assert_eq!(comp_inner_type.connectors().count(), 1);
+        assert_eq!(comp_mid_type.ports().count(), 1);
+        assert_eq!(comp_mid_type.ports().next().unwrap(), mid_port.into());
+        // a new port was created in comp_inner
+        let mut inner_ports = comp_inner_type.ports();
+        let inner_port = inner_ports.next().unwrap();
+        assert!(inner_ports.next().is_none());
+        assert_eq!(inner_port.name().unwrap(), "swc_port");
+        assert!(matches!(inner_port, PortPrototype::R(_)));
+        assert_eq!(
+            inner_port.port_interface().unwrap(),
+            PortInterface::SenderReceiverInterface(port_interface.clone())
+        );
+
+        // the connection path can only lead to components inside the composition
+        let other_swc_type = package.create_application_sw_component_type("other_swc_type").unwrap();
+        let other_port = other_swc_type.create_r_port("other_port", &port_interface).unwrap();
+        let result = comp_outer_type.create_connection_path(&outer_port, &other_port);
+        assert!(result.is_err());
+        // the outer port must be a port of the composition
+        let result = comp_outer_type.create_connection_path(&swc_port, &swc_port);
+        assert!(result.is_err());
+
+        // a second component in comp_inner, whose ports don't match the outer port
+        let base_type = package
+            .create_sw_base_type("uint8", 8, BaseTypeEncoding::None, None, None, None)
+            .unwrap();
+        let other_interface = package.create_sender_receiver_interface("sr_other").unwrap();
+        other_interface.create_data_element("data", &base_type).unwrap();
+        let swc2_type = package.create_application_sw_component_type("swc2_type").unwrap();
+        comp_inner_type.create_component("swc2", &swc2_type).unwrap();
+        let incompatible_port = swc2_type.create_r_port("incompatible_port", &other_interface).unwrap();
+        let p_port = swc2_type.create_p_port("p_port", &port_interface).unwrap();
+
+        // the outer port does not provide the data element required by the inner port
+        let result = comp_outer_type.create_connection_path(&outer_port, &incompatible_port);
+        assert!(result.is_err());
+        // a P port can't be connected to an R port
+        let result = comp_outer_type.create_connection_path(&outer_port, &p_port);
+        assert!(result.is_err());
+        // nothing was left behind by the failed attempts
+        assert_eq!(comp_outer_type.ports().count(), 1);
+        assert_eq!(comp_mid_type.ports().count(), 1);
+        assert_eq!(comp_inner_type.ports().count(), 1);
+        assert_eq!(comp_outer_type.connectors().count(), 1);
+        assert_eq!(comp_mid_type.connectors().count(), 1);
+        assert_eq!(comp_inner_type.connectors().count(), 1);
+    }
+
     #[test]
     fn remove_swc_type() {
         let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
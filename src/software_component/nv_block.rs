@@ -0,0 +1,344 @@
+use crate::{
+    AbstractionElement, AutosarAbstractionError, Element, IdentifiableAbstractionElement, abstraction_element,
+    datatype::{AbstractAutosarDataType, AutosarDataType, ValueSpecification},
+    software_component::{NvBlockNeeds, PortPrototype, VariableDataPrototype},
+};
+use autosar_data::ElementName;
+
+//##################################################################
+
+/// An `NvBlockDescriptor` describes one block of non-volatile data inside an `NvBlockSwComponentType`
+///
+/// It contains the RAM block and ROM block of the nv data, as well as the mappings
+/// between the RAM block and the nv data elements of the component ports.
+///
+/// Use [`NvBlockSwComponentType::create_nv_block_descriptor`](crate::software_component::NvBlockSwComponentType::create_nv_block_descriptor)
+/// to create a new `NvBlockDescriptor`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NvBlockDescriptor(Element);
+abstraction_element!(NvBlockDescriptor, NvBlockDescriptor);
+impl IdentifiableAbstractionElement for NvBlockDescriptor {}
+
+impl NvBlockDescriptor {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let nv_block_descriptor = parent.create_named_sub_element(ElementName::NvBlockDescriptor, name)?;
+
+        Ok(Self(nv_block_descriptor))
+    }
+
+    /// set the RAM block of the `NvBlockDescriptor`
+    ///
+    /// If a RAM block already exists, it is renamed and its data type is updated,
+    /// so that existing data mappings remain valid.
+    pub fn set_ram_block<T: AbstractAutosarDataType>(
+        &self,
+        name: &str,
+        data_type: &T,
+    ) -> Result<(), AutosarAbstractionError> {
+        let ram_block = if let Some(ram_block) = self.element().get_sub_element(ElementName::RamBlock) {
+            ram_block.set_item_name(name)?;
+            ram_block
+        } else {
+            self.element().create_named_sub_element(ElementName::RamBlock, name)?
+        };
+        ram_block
+            .get_or_create_sub_element(ElementName::TypeTref)?
+            .set_reference_target(data_type.element())?;
+        Ok(())
+    }
+
+    /// get the data type of the RAM block
+    #[must_use]
+    pub fn ram_block_data_type(&self) -> Option<AutosarDataType> {
+        let type_tref = self
+            .element()
+            .get_sub_element(ElementName::RamBlock)?
+            .get_sub_element(ElementName::TypeTref)?;
+        AutosarDataType::try_from(type_tref.get_reference_target().ok()?).ok()
+    }
+
+    /// set the ROM block of the `NvBlockDescriptor`
+    ///
+    /// The ROM block contains the default values of the nv data.
+    /// If a ROM block already exists, it is renamed and its data type is updated.
+    pub fn set_rom_block<T: AbstractAutosarDataType>(
+        &self,
+        name: &str,
+        data_type: &T,
+    ) -> Result<(), AutosarAbstractionError> {
+        let rom_block = if let Some(rom_block) = self.element().get_sub_element(ElementName::RomBlock) {
+            rom_block.set_item_name(name)?;
+            rom_block
+        } else {
+            self.element().create_named_sub_element(ElementName::RomBlock, name)?
+        };
+        rom_block
+            .get_or_create_sub_element(ElementName::TypeTref)?
+            .set_reference_target(data_type.element())?;
+        Ok(())
+    }
+
+    /// get the data type of the ROM block
+    #[must_use]
+    pub fn rom_block_data_type(&self) -> Option<AutosarDataType> {
+        let type_tref = self
+            .element()
+            .get_sub_element(ElementName::RomBlock)?
+            .get_sub_element(ElementName::TypeTref)?;
+        AutosarDataType::try_from(type_tref.get_reference_target().ok()?).ok()
+    }
+
+    /// set the init value of the ROM block
+    ///
+    /// The ROM block must be created with [`NvBlockDescriptor::set_rom_block`] first.
+    pub fn set_rom_block_init_value<T: Into<ValueSpecification>>(
+        &self,
+        value_spec: Option<T>,
+    ) -> Result<(), AutosarAbstractionError> {
+        let Some(rom_block) = self.element().get_sub_element(ElementName::RomBlock) else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The NvBlockDescriptor does not have a ROM block".to_string(),
+            ));
+        };
+        if let Some(value_spec) = value_spec {
+            let value_spec: ValueSpecification = value_spec.into();
+            let init_value_elem = rom_block.get_or_create_sub_element(ElementName::InitValue)?;
+            value_spec.store(&init_value_elem)?;
+        } else {
+            let _ = rom_block.remove_sub_element_kind(ElementName::InitValue);
+        }
+        Ok(())
+    }
+
+    /// get the init value of the ROM block
+    #[must_use]
+    pub fn rom_block_init_value(&self) -> Option<ValueSpecification> {
+        let init_value_elem = self
+            .element()
+            .get_sub_element(ElementName::RomBlock)?
+            .get_sub_element(ElementName::InitValue)?
+            .get_sub_element_at(0)?;
+        ValueSpecification::load(&init_value_elem)
+    }
+
+    /// create the `NvBlockNeeds` of the `NvBlockDescriptor`
+    ///
+    /// An `NvBlockDescriptor` has at most one `NvBlockNeeds`; an existing one is replaced.
+    pub fn create_nv_block_needs(&self, name: &str) -> Result<NvBlockNeeds, AutosarAbstractionError> {
+        let _ = self.element().remove_sub_element_kind(ElementName::NvBlockNeeds);
+        NvBlockNeeds::new(name, self.element())
+    }
+
+    /// get the `NvBlockNeeds` of the `NvBlockDescriptor`
+    #[must_use]
+    pub fn nv_block_needs(&self) -> Option<NvBlockNeeds> {
+        let nv_block_needs = self.element().get_sub_element(ElementName::NvBlockNeeds)?;
+        NvBlockNeeds::try_from(nv_block_needs).ok()
+    }
+
+    /// map the RAM block to an nv data element in the context of a port of the `NvBlockSwComponentType`
+    ///
+    /// The kind of access is derived from the port: nv data in a P-port is read by other components,
+    /// nv data in an R-port is written by other components, and nv data in a PR-port is both written and read.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the descriptor has no RAM block, the port does not
+    ///   belong to the component containing the descriptor, or the nv data is not part of the port interface
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the mapping
+    pub fn create_nv_block_data_mapping(
+        &self,
+        nv_data: &VariableDataPrototype,
+        port: &PortPrototype,
+    ) -> Result<NvBlockDataMapping, AutosarAbstractionError> {
+        let Some(ram_block) = self.element().get_sub_element(ElementName::RamBlock) else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The NvBlockDescriptor does not have a RAM block".to_string(),
+            ));
+        };
+        if self.element().named_parent()?.as_ref() != Some(port.component_type()?.element()) {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The port does not belong to the component containing the NvBlockDescriptor".to_string(),
+            ));
+        }
+        let nv_data_interface = nv_data.element().named_parent()?;
+        if port
+            .port_interface()
+            .is_none_or(|interface| Some(interface.element()) != nv_data_interface.as_ref())
+        {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The nv data is not part of the port interface".to_string(),
+            ));
+        }
+
+        let mappings = self
+            .element()
+            .get_or_create_sub_element(ElementName::NvBlockDataMappings)?;
+        NvBlockDataMapping::new(&mappings, &ram_block, nv_data, port)
+    }
+
+    /// iterate over all `NvBlockDataMapping`s of the `NvBlockDescriptor`
+    pub fn nv_block_data_mappings(&self) -> impl Iterator<Item = NvBlockDataMapping> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::NvBlockDataMappings)
+            .into_iter()
+            .flat_map(|mappings| mappings.sub_elements())
+            .filter_map(|elem| NvBlockDataMapping::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// An `NvBlockDataMapping` connects the RAM block of an `NvBlockDescriptor` with an nv data element of a port
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NvBlockDataMapping(Element);
+abstraction_element!(NvBlockDataMapping, NvBlockDataMapping);
+
+impl NvBlockDataMapping {
+    fn new(
+        parent: &Element,
+        ram_block: &Element,
+        nv_data: &VariableDataPrototype,
+        port: &PortPrototype,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let mapping = parent.create_sub_element(ElementName::NvBlockDataMapping)?;
+        mapping
+            .create_sub_element(ElementName::NvRamBlockElement)?
+            .create_sub_element(ElementName::LocalVariableRef)?
+            .set_reference_target(ram_block)?;
+
+        let access_name = match port {
+            PortPrototype::P(_) => ElementName::ReadNvData,
+            PortPrototype::R(_) => ElementName::WrittenNvData,
+            PortPrototype::PR(_) => ElementName::WrittenReadNvData,
+        };
+        let autosar_variable_iref = mapping
+            .create_sub_element(access_name)?
+            .create_sub_element(ElementName::AutosarVariableIref)?;
+        autosar_variable_iref
+            .create_sub_element(ElementName::PortPrototypeRef)?
+            .set_reference_target(port.element())?;
+        autosar_variable_iref
+            .create_sub_element(ElementName::TargetDataPrototypeRef)?
+            .set_reference_target(nv_data.element())?;
+
+        Ok(Self(mapping))
+    }
+
+    /// get the nv data element and the port of the mapping
+    #[must_use]
+    pub fn nv_data(&self) -> Option<(VariableDataPrototype, PortPrototype)> {
+        let access = [
+            ElementName::ReadNvData,
+            ElementName::WrittenNvData,
+            ElementName::WrittenReadNvData,
+        ]
+        .into_iter()
+        .find_map(|name| self.element().get_sub_element(name))?;
+        let autosar_variable_iref = access.get_sub_element(ElementName::AutosarVariableIref)?;
+        let data_prototype_ref = autosar_variable_iref.get_sub_element(ElementName::TargetDataPrototypeRef)?;
+        let port_prototype_ref = autosar_variable_iref.get_sub_element(ElementName::PortPrototypeRef)?;
+
+        let data_prototype = VariableDataPrototype::try_from(data_prototype_ref.get_reference_target().ok()?).ok()?;
+        let port_prototype = PortPrototype::try_from(port_prototype_ref.get_reference_target().ok()?).ok()?;
+
+        Some((data_prototype, port_prototype))
+    }
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AutosarModelAbstraction,
+        datatype::{BaseTypeEncoding, ImplementationDataTypeSettings},
+        software_component::{AbstractSwComponentType, SwComponentType},
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn nv_block_sw_component() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+
+        let base_type = package
+            .create_sw_base_type("base", 32, BaseTypeEncoding::None, None, None, None)
+            .unwrap();
+        let data_type = package
+            .create_implementation_data_type(&ImplementationDataTypeSettings::Value {
+                name: "ImplementationValue".to_string(),
+                base_type: base_type.clone(),
+                compu_method: None,
+                data_constraint: None,
+            })
+            .unwrap();
+
+        let nv_data_interface = package.create_nv_data_interface("NvDataInterface").unwrap();
+        let nv_data = nv_data_interface.create_nv_data("NvData", &data_type).unwrap();
+        assert_eq!(nv_data_interface.nv_datas().next(), Some(nv_data.clone()));
+
+        let nv_block_swc = package.create_nv_block_sw_component_type("NvBlockSwc").unwrap();
+        let p_port = nv_block_swc.create_p_port("p_port", &nv_data_interface).unwrap();
+        let pr_port = nv_block_swc.create_pr_port("pr_port", &nv_data_interface).unwrap();
+        let swc_type = SwComponentType::try_from(nv_block_swc.element().clone()).unwrap();
+        assert_eq!(swc_type, SwComponentType::NvBlock(nv_block_swc.clone()));
+
+        let descriptor = nv_block_swc.create_nv_block_descriptor("Descriptor").unwrap();
+        assert_eq!(nv_block_swc.nv_block_descriptors().count(), 1);
+
+        // a mapping requires a RAM block
+        let result = descriptor.create_nv_block_data_mapping(&nv_data, &p_port.clone().into());
+        assert!(result.is_err());
+
+        descriptor.set_ram_block("RamBlock", &data_type).unwrap();
+        assert_eq!(
+            descriptor.ram_block_data_type(),
+            Some(AutosarDataType::ImplementationDataType(data_type.clone()))
+        );
+        // the init value can only be set once the ROM block exists
+        assert!(descriptor.set_rom_block_init_value(Some(5.0)).is_err());
+        descriptor.set_rom_block("RomBlock", &data_type).unwrap();
+        assert_eq!(
+            descriptor.rom_block_data_type(),
+            Some(AutosarDataType::ImplementationDataType(data_type.clone()))
+        );
+        descriptor.set_rom_block_init_value(Some(5.0)).unwrap();
+        assert_eq!(descriptor.rom_block_init_value(), Some(ValueSpecification::from(5.0)));
+
+        let needs = descriptor.create_nv_block_needs("Needs").unwrap();
+        assert_eq!(descriptor.nv_block_needs(), Some(needs));
+
+        let mapping = descriptor
+            .create_nv_block_data_mapping(&nv_data, &p_port.clone().into())
+            .unwrap();
+        assert_eq!(mapping.nv_data(), Some((nv_data.clone(), p_port.into())));
+        descriptor
+            .create_nv_block_data_mapping(&nv_data, &pr_port.clone().into())
+            .unwrap();
+        assert_eq!(descriptor.nv_block_data_mappings().count(), 2);
+
+        // ports of other components can't be mapped
+        let app = package.create_application_sw_component_type("App").unwrap();
+        let r_port = app.create_r_port("r_port", &nv_data_interface).unwrap();
+        let result = descriptor.create_nv_block_data_mapping(&nv_data, &r_port.into());
+        assert!(result.is_err());
+
+        // renaming the RAM block keeps the mappings intact
+        descriptor.set_ram_block("RamBlock2", &data_type).unwrap();
+        let local_variable_ref = mapping
+            .element()
+            .get_sub_element(ElementName::NvRamBlockElement)
+            .and_then(|elem| elem.get_sub_element(ElementName::LocalVariableRef))
+            .unwrap();
+        assert!(local_variable_ref.get_reference_target().is_ok());
+
+        // removing the nv data also removes the mappings that use it
+        nv_data.remove(true).unwrap();
+        assert_eq!(descriptor.nv_block_data_mappings().count(), 0);
+
+        nv_block_swc.remove(true).unwrap();
+        assert!(model.get_element_by_path("/package/NvBlockSwc").is_none());
+    }
+}
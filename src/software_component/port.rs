@@ -3,7 +3,11 @@ use crate::{
     get_reference_parents, is_used, software_component,
 };
 use autosar_data::{Element, ElementName};
-use software_component::{AbstractPortInterface, PortInterface, SwComponentType};
+use software_component::{
+    AbstractPortInterface, ClientComSpec, ClientServerOperation, NonqueuedReceiverComSpec, NonqueuedSenderComSpec,
+    ParameterDataPrototype, ParameterProvideComSpec, ParameterRequireComSpec, PortComSpec, PortInterface,
+    QueuedReceiverComSpec, ServerComSpec, SwComponentPrototype, SwComponentType, VariableDataPrototype,
+};
 
 //#########################################################
 
@@ -42,7 +46,7 @@ impl RPortPrototype {
             port_interface.remove(true)?;
         }
 
-        for (named_parent, _parent) in ref_parents {
+        for (named_parent, parent) in ref_parents {
             match named_parent.element_name() {
                 ElementName::DelegationSwConnector
                 | ElementName::AssemblySwConnector
@@ -51,6 +55,14 @@ impl RPortPrototype {
                         connector.remove(deep)?;
                     };
                 }
+                ElementName::PortGroup => {
+                    // drop the empty entry that remains in the outer ports of the port group
+                    if parent.element_name() == ElementName::PortPrototypeRefConditional
+                        && let Ok(Some(outer_ports)) = parent.parent()
+                    {
+                        outer_ports.remove_sub_element(parent)?;
+                    }
+                }
                 _ => {}
             }
         }
@@ -72,6 +84,80 @@ impl RPortPrototype {
         let component_type_elem = self.element().named_parent()?.unwrap();
         SwComponentType::try_from(component_type_elem)
     }
+
+    /// create a `NonqueuedReceiverComSpec` for a data element of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The data element is not part of the port interface
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_nonqueued_receiver_com_spec(
+        &self,
+        data_element: &VariableDataPrototype,
+    ) -> Result<NonqueuedReceiverComSpec, AutosarAbstractionError> {
+        check_data_element(self.port_interface(), data_element)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::RequiredComSpecs)?;
+        NonqueuedReceiverComSpec::new(&com_specs, data_element)
+    }
+
+    /// create a `QueuedReceiverComSpec` for a data element of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The data element is not part of the port interface, or the queue length is 0
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_queued_receiver_com_spec(
+        &self,
+        data_element: &VariableDataPrototype,
+        queue_length: u32,
+    ) -> Result<QueuedReceiverComSpec, AutosarAbstractionError> {
+        check_data_element(self.port_interface(), data_element)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::RequiredComSpecs)?;
+        QueuedReceiverComSpec::new(&com_specs, data_element, queue_length)
+    }
+
+    /// create a `ClientComSpec` for an operation of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The operation is not part of the port interface
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_client_com_spec(
+        &self,
+        operation: &ClientServerOperation,
+    ) -> Result<ClientComSpec, AutosarAbstractionError> {
+        check_operation(self.port_interface(), operation)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::RequiredComSpecs)?;
+        ClientComSpec::new(&com_specs, operation)
+    }
+
+    /// create a `ParameterRequireComSpec` for a parameter of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The parameter is not part of the port interface
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_parameter_require_com_spec(
+        &self,
+        parameter: &ParameterDataPrototype,
+    ) -> Result<ParameterRequireComSpec, AutosarAbstractionError> {
+        check_parameter(self.port_interface(), parameter)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::RequiredComSpecs)?;
+        ParameterRequireComSpec::new(&com_specs, parameter)
+    }
+
+    /// iterate over all com specs of the port
+    pub fn com_specs(&self) -> impl Iterator<Item = PortComSpec> + Send + use<> {
+        com_specs(self.element(), ElementName::RequiredComSpecs)
+    }
 }
 
 //##################################################################
@@ -111,7 +197,7 @@ impl PPortPrototype {
             port_interface.remove(true)?;
         }
 
-        for (named_parent, _parent) in ref_parents {
+        for (named_parent, parent) in ref_parents {
             match named_parent.element_name() {
                 ElementName::DelegationSwConnector
                 | ElementName::AssemblySwConnector
@@ -120,6 +206,14 @@ impl PPortPrototype {
                         connector.remove(deep)?;
                     };
                 }
+                ElementName::PortGroup => {
+                    // drop the empty entry that remains in the outer ports of the port group
+                    if parent.element_name() == ElementName::PortPrototypeRefConditional
+                        && let Ok(Some(outer_ports)) = parent.parent()
+                    {
+                        outer_ports.remove_sub_element(parent)?;
+                    }
+                }
                 _ => {}
             }
         }
@@ -141,6 +235,63 @@ impl PPortPrototype {
         let component_type_elem = self.element().named_parent()?.unwrap();
         SwComponentType::try_from(component_type_elem)
     }
+
+    /// create a `NonqueuedSenderComSpec` for a data element of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The data element is not part of the port interface
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_nonqueued_sender_com_spec(
+        &self,
+        data_element: &VariableDataPrototype,
+    ) -> Result<NonqueuedSenderComSpec, AutosarAbstractionError> {
+        check_data_element(self.port_interface(), data_element)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::ProvidedComSpecs)?;
+        NonqueuedSenderComSpec::new(&com_specs, data_element)
+    }
+
+    /// create a `ServerComSpec` for an operation of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The operation is not part of the port interface, or the queue length is 0
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_server_com_spec(
+        &self,
+        operation: &ClientServerOperation,
+        queue_length: u32,
+    ) -> Result<ServerComSpec, AutosarAbstractionError> {
+        check_operation(self.port_interface(), operation)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::ProvidedComSpecs)?;
+        ServerComSpec::new(&com_specs, operation, queue_length)
+    }
+
+    /// create a `ParameterProvideComSpec` for a parameter of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The parameter is not part of the port interface
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_parameter_provide_com_spec(
+        &self,
+        parameter: &ParameterDataPrototype,
+    ) -> Result<ParameterProvideComSpec, AutosarAbstractionError> {
+        check_parameter(self.port_interface(), parameter)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::ProvidedComSpecs)?;
+        ParameterProvideComSpec::new(&com_specs, parameter)
+    }
+
+    /// iterate over all com specs of the port
+    pub fn com_specs(&self) -> impl Iterator<Item = PortComSpec> + Send + use<> {
+        com_specs(self.element(), ElementName::ProvidedComSpecs)
+    }
 }
 
 //##################################################################
@@ -186,7 +337,7 @@ impl PRPortPrototype {
             port_interface.remove(true)?;
         }
 
-        for (named_parent, _parent) in ref_parents {
+        for (named_parent, parent) in ref_parents {
             match named_parent.element_name() {
                 ElementName::DelegationSwConnector
                 | ElementName::AssemblySwConnector
@@ -195,6 +346,14 @@ impl PRPortPrototype {
                         connector.remove(deep)?;
                     };
                 }
+                ElementName::PortGroup => {
+                    // drop the empty entry that remains in the outer ports of the port group
+                    if parent.element_name() == ElementName::PortPrototypeRefConditional
+                        && let Ok(Some(outer_ports)) = parent.parent()
+                    {
+                        outer_ports.remove_sub_element(parent)?;
+                    }
+                }
                 _ => {}
             }
         }
@@ -216,6 +375,99 @@ impl PRPortPrototype {
         let component_type_elem = self.element().named_parent()?.unwrap();
         SwComponentType::try_from(component_type_elem)
     }
+
+    /// create a `NonqueuedSenderComSpec` for a data element of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The data element is not part of the port interface
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_nonqueued_sender_com_spec(
+        &self,
+        data_element: &VariableDataPrototype,
+    ) -> Result<NonqueuedSenderComSpec, AutosarAbstractionError> {
+        check_data_element(self.port_interface(), data_element)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::ProvidedComSpecs)?;
+        NonqueuedSenderComSpec::new(&com_specs, data_element)
+    }
+
+    /// create a `ServerComSpec` for an operation of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The operation is not part of the port interface, or the queue length is 0
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_server_com_spec(
+        &self,
+        operation: &ClientServerOperation,
+        queue_length: u32,
+    ) -> Result<ServerComSpec, AutosarAbstractionError> {
+        check_operation(self.port_interface(), operation)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::ProvidedComSpecs)?;
+        ServerComSpec::new(&com_specs, operation, queue_length)
+    }
+
+    /// create a `NonqueuedReceiverComSpec` for a data element of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The data element is not part of the port interface
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_nonqueued_receiver_com_spec(
+        &self,
+        data_element: &VariableDataPrototype,
+    ) -> Result<NonqueuedReceiverComSpec, AutosarAbstractionError> {
+        check_data_element(self.port_interface(), data_element)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::RequiredComSpecs)?;
+        NonqueuedReceiverComSpec::new(&com_specs, data_element)
+    }
+
+    /// create a `QueuedReceiverComSpec` for a data element of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The data element is not part of the port interface, or the queue length is 0
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_queued_receiver_com_spec(
+        &self,
+        data_element: &VariableDataPrototype,
+        queue_length: u32,
+    ) -> Result<QueuedReceiverComSpec, AutosarAbstractionError> {
+        check_data_element(self.port_interface(), data_element)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::RequiredComSpecs)?;
+        QueuedReceiverComSpec::new(&com_specs, data_element, queue_length)
+    }
+
+    /// create a `ClientComSpec` for an operation of the port interface
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The operation is not part of the port interface
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the com spec
+    pub fn create_client_com_spec(
+        &self,
+        operation: &ClientServerOperation,
+    ) -> Result<ClientComSpec, AutosarAbstractionError> {
+        check_operation(self.port_interface(), operation)?;
+        let com_specs = self
+            .element()
+            .get_or_create_sub_element(ElementName::RequiredComSpecs)?;
+        ClientComSpec::new(&com_specs, operation)
+    }
+
+    /// iterate over all com specs of the port, both provided and required
+    pub fn com_specs(&self) -> impl Iterator<Item = PortComSpec> + Send + use<> {
+        com_specs(self.element(), ElementName::ProvidedComSpecs)
+            .chain(com_specs(self.element(), ElementName::RequiredComSpecs))
+    }
 }
 
 //##################################################################
@@ -318,6 +570,157 @@ impl PortGroup {
 
         Ok(Self(port_group))
     }
+
+    /// get the component type containing this port group
+    pub fn component_type(&self) -> Result<SwComponentType, AutosarAbstractionError> {
+        let component_type_elem = self.element().named_parent()?.unwrap();
+        SwComponentType::try_from(component_type_elem)
+    }
+
+    /// add a port of the containing component to the port group
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the port does not belong to the component containing the port group
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to add the port
+    pub fn add_outer_port(&self, port: &PortPrototype) -> Result<(), AutosarAbstractionError> {
+        if self.element().named_parent()? != port.element().named_parent()? {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The port does not belong to the component containing the port group".to_string(),
+            ));
+        }
+        self.element()
+            .get_or_create_sub_element(ElementName::OuterPorts)?
+            .create_sub_element(ElementName::PortPrototypeRefConditional)?
+            .create_sub_element(ElementName::PortPrototypeRef)?
+            .set_reference_target(port.element())?;
+        Ok(())
+    }
+
+    /// iterate over all ports in the port group
+    pub fn outer_ports(&self) -> impl Iterator<Item = PortPrototype> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::OuterPorts)
+            .into_iter()
+            .flat_map(|outer_ports| outer_ports.sub_elements())
+            .filter_map(|conditional| {
+                conditional
+                    .get_sub_element(ElementName::PortPrototypeRef)
+                    .and_then(|port_ref| port_ref.get_reference_target().ok())
+                    .and_then(|port| PortPrototype::try_from(port).ok())
+            })
+    }
+
+    /// add a port group of an inner component to this port group
+    ///
+    /// This is only possible if the port group belongs to a composition. The inner port group is identified
+    /// by the component prototype inside the composition and by the port group of the component type.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the component prototype is not part of the composition
+    ///   containing this port group, or the inner port group does not belong to the type of the component prototype
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to add the inner group
+    pub fn add_inner_group(
+        &self,
+        context_component: &SwComponentPrototype,
+        inner_group: &PortGroup,
+    ) -> Result<(), AutosarAbstractionError> {
+        if context_component.parent_composition()?.element() != self.component_type()?.element() {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The component prototype is not part of the composition containing the port group".to_string(),
+            ));
+        }
+        if context_component.component_type() != Some(inner_group.component_type()?) {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The inner port group does not belong to the type of the component prototype".to_string(),
+            ));
+        }
+
+        let inner_group_iref = self
+            .element()
+            .get_or_create_sub_element(ElementName::InnerGroupIrefs)?
+            .create_sub_element(ElementName::InnerGroupIref)?;
+        inner_group_iref
+            .create_sub_element(ElementName::ContextRef)?
+            .set_reference_target(context_component.element())?;
+        inner_group_iref
+            .create_sub_element(ElementName::TargetRef)?
+            .set_reference_target(inner_group.element())?;
+        Ok(())
+    }
+
+    /// iterate over all inner port groups of this port group
+    ///
+    /// Each item contains the component prototype and the port group of the component type
+    pub fn inner_groups(&self) -> impl Iterator<Item = (SwComponentPrototype, PortGroup)> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::InnerGroupIrefs)
+            .into_iter()
+            .flat_map(|inner_group_irefs| inner_group_irefs.sub_elements())
+            .filter_map(|inner_group_iref| {
+                let context_ref = inner_group_iref.get_sub_element(ElementName::ContextRef)?;
+                let target_ref = inner_group_iref.get_sub_element(ElementName::TargetRef)?;
+                let context = SwComponentPrototype::try_from(context_ref.get_reference_target().ok()?).ok()?;
+                let target = PortGroup::try_from(target_ref.get_reference_target().ok()?).ok()?;
+                Some((context, target))
+            })
+    }
+}
+
+//##################################################################
+
+// check that the data element is part of the port interface
+fn check_data_element(
+    port_interface: Option<PortInterface>,
+    data_element: &VariableDataPrototype,
+) -> Result<(), AutosarAbstractionError> {
+    let data_element_interface = data_element.interface()?;
+    if port_interface.is_some_and(|interface| interface.element() == data_element_interface.element()) {
+        Ok(())
+    } else {
+        Err(AutosarAbstractionError::InvalidParameter(
+            "The data element is not part of the port interface".to_string(),
+        ))
+    }
+}
+
+// check that the operation is part of the port interface
+fn check_operation(
+    port_interface: Option<PortInterface>,
+    operation: &ClientServerOperation,
+) -> Result<(), AutosarAbstractionError> {
+    let operation_interface = operation.interface()?;
+    if port_interface.is_some_and(|interface| interface.element() == operation_interface.element()) {
+        Ok(())
+    } else {
+        Err(AutosarAbstractionError::InvalidParameter(
+            "The operation is not part of the port interface".to_string(),
+        ))
+    }
+}
+
+// check that the parameter is part of the port interface
+fn check_parameter(
+    port_interface: Option<PortInterface>,
+    parameter: &ParameterDataPrototype,
+) -> Result<(), AutosarAbstractionError> {
+    let parameter_interface = parameter.interface()?;
+    if port_interface.is_some_and(|interface| interface.element() == parameter_interface.element()) {
+        Ok(())
+    } else {
+        Err(AutosarAbstractionError::InvalidParameter(
+            "The parameter is not part of the port interface".to_string(),
+        ))
+    }
+}
+
+// iterate over the com specs in the given container of a port
+fn com_specs(port: &Element, container: ElementName) -> impl Iterator<Item = PortComSpec> + Send + use<> {
+    port.get_sub_element(container)
+        .into_iter()
+        .flat_map(|com_specs| com_specs.sub_elements())
+        .filter_map(|elem| PortComSpec::try_from(elem).ok())
 }
 
 //##################################################################
@@ -445,4 +848,54 @@ mod test {
         pr_port.remove(true).unwrap();
         assert_eq!(composition_type.ports().count(), 0);
     }
+
+    #[test]
+    fn port_groups() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let interface = package.create_sender_receiver_interface("TestInterface").unwrap();
+
+        let app_swc_type = package.create_application_sw_component_type("app_swc").unwrap();
+        let app_port = app_swc_type.create_p_port("app_port", &interface).unwrap();
+        let app_port_group = app_swc_type.create_port_group("app_port_group").unwrap();
+        assert_eq!(app_swc_type.port_groups().next(), Some(app_port_group.clone()));
+        assert_eq!(app_port_group.component_type().unwrap(), app_swc_type.clone().into());
+        app_port_group.add_outer_port(&app_port.clone().into()).unwrap();
+
+        let composition_type = package.create_composition_sw_component_type("comp").unwrap();
+        let comp_port = composition_type.create_p_port("comp_port", &interface).unwrap();
+        let comp_port_group = composition_type.create_port_group("comp_port_group").unwrap();
+        comp_port_group.add_outer_port(&comp_port.clone().into()).unwrap();
+        // ports of other components can't be added
+        assert!(comp_port_group.add_outer_port(&app_port.clone().into()).is_err());
+        assert_eq!(comp_port_group.outer_ports().next(), Some(comp_port.clone().into()));
+
+        let app_prototype = composition_type.create_component("app_proto", &app_swc_type).unwrap();
+        comp_port_group
+            .add_inner_group(&app_prototype, &app_port_group)
+            .unwrap();
+        assert_eq!(
+            comp_port_group.inner_groups().next(),
+            Some((app_prototype.clone(), app_port_group.clone()))
+        );
+        // the inner group must belong to the type of the component prototype
+        assert!(
+            comp_port_group
+                .add_inner_group(&app_prototype, &comp_port_group)
+                .is_err()
+        );
+
+        // removing a port also removes it from the port group
+        comp_port.remove(true).unwrap();
+        assert_eq!(comp_port_group.outer_ports().count(), 0);
+        assert!(
+            comp_port_group
+                .element()
+                .get_sub_element(ElementName::OuterPorts)
+                .unwrap()
+                .sub_elements()
+                .next()
+                .is_none()
+        );
+    }
 }
@@ -0,0 +1,317 @@
+use crate::communication::{
+    AbstractFrame, AbstractFrameTriggering, AbstractPdu, CommunicationDirection, Frame, FrameTriggering, Pdu,
+    PhysicalChannel, SignalPdu,
+};
+use crate::{AbstractionElement, ByteOrder, IdentifiableAbstractionElement, System};
+use std::collections::BTreeMap;
+
+//##################################################################
+
+/// A snapshot of the communication of a `System`: clusters, channels, frames, pdus and signals
+///
+/// All names are stored as plain strings and all lists are sorted, so that two snapshots of the
+/// same system are always identical. This makes the matrix suitable for exporting and diffing.
+///
+/// Use [`System::communication_matrix`] to create a `CommunicationMatrix`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CommunicationMatrix {
+    /// the clusters of the system, sorted by name
+    pub clusters: Vec<ClusterMatrix>,
+}
+
+/// The frames of all physical channels of a cluster in the [`CommunicationMatrix`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterMatrix {
+    /// name of the cluster
+    pub name: String,
+    /// the physical channels of the cluster, sorted by name
+    pub channels: Vec<ChannelMatrix>,
+}
+
+/// The frames that are triggered on a physical channel in the [`CommunicationMatrix`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelMatrix {
+    /// name of the physical channel
+    pub name: String,
+    /// the frames triggered on the channel, sorted by identifier and name
+    pub frames: Vec<FrameMatrixEntry>,
+}
+
+/// A frame in the [`CommunicationMatrix`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameMatrixEntry {
+    /// name of the frame
+    pub name: String,
+    /// CAN or LIN identifier, or the `FlexRay` slot of the frame
+    pub identifier: Option<u32>,
+    /// length of the frame in bytes
+    pub length: Option<u32>,
+    /// names of the ECUs that send the frame, sorted
+    pub senders: Vec<String>,
+    /// names of the ECUs that receive the frame, sorted
+    pub receivers: Vec<String>,
+    /// the pdus in the frame, sorted by start position
+    pub pdus: Vec<PduMatrixEntry>,
+}
+
+/// A pdu inside a frame in the [`CommunicationMatrix`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PduMatrixEntry {
+    /// name of the pdu
+    pub name: String,
+    /// start position of the pdu in the frame, in bits
+    pub start_position: Option<u32>,
+    /// byte order of the pdu in the frame
+    pub byte_order: Option<ByteOrder>,
+    /// length of the pdu in bytes
+    pub length: Option<u32>,
+    /// the signals in the pdu, sorted by start position
+    pub signals: Vec<SignalMatrixEntry>,
+}
+
+/// A signal inside a pdu in the [`CommunicationMatrix`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalMatrixEntry {
+    /// name of the signal
+    pub name: String,
+    /// start position of the signal in the pdu, in bits
+    pub start_position: Option<u32>,
+    /// length of the signal in bits
+    pub bit_length: Option<u64>,
+    /// byte order of the signal
+    pub byte_order: Option<ByteOrder>,
+}
+
+//##################################################################
+
+impl CommunicationMatrix {
+    pub(crate) fn new(system: &System) -> Self {
+        // cluster name -> channel name -> frames
+        let mut clusters: BTreeMap<String, BTreeMap<String, Vec<FrameMatrixEntry>>> = BTreeMap::new();
+
+        for frame in system.frames() {
+            for frame_triggering in frame.frame_triggerings() {
+                let Ok(channel) = frame_triggering.physical_channel() else {
+                    continue;
+                };
+                let Some(cluster_name) = channel_cluster_name(&channel) else {
+                    continue;
+                };
+                let channel_name = channel.name().unwrap_or_default();
+                clusters
+                    .entry(cluster_name)
+                    .or_default()
+                    .entry(channel_name)
+                    .or_default()
+                    .push(frame_entry(&frame, &frame_triggering));
+            }
+        }
+
+        let clusters = clusters
+            .into_iter()
+            .map(|(name, channels)| ClusterMatrix {
+                name,
+                channels: channels
+                    .into_iter()
+                    .map(|(name, mut frames)| {
+                        frames.sort_by(|a, b| (a.identifier, &a.name).cmp(&(b.identifier, &b.name)));
+                        ChannelMatrix { name, frames }
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self { clusters }
+    }
+}
+
+// get the name of the cluster that contains the channel
+fn channel_cluster_name(channel: &PhysicalChannel) -> Option<String> {
+    channel.element().named_parent().ok()??.item_name()
+}
+
+fn frame_entry(frame: &Frame, frame_triggering: &FrameTriggering) -> FrameMatrixEntry {
+    let identifier = match frame_triggering {
+        FrameTriggering::Can(can_ft) => can_ft.identifier(),
+        FrameTriggering::Flexray(flexray_ft) => flexray_ft.slot().map(u32::from),
+        FrameTriggering::Lin(lin_ft) => lin_ft.identifier(),
+    };
+
+    let mut senders = Vec::new();
+    let mut receivers = Vec::new();
+    for frame_port in frame_triggering.frame_ports() {
+        let Some(ecu_name) = frame_port.ecu().ok().and_then(|ecu| ecu.name()) else {
+            continue;
+        };
+        match frame_port.communication_direction() {
+            Some(CommunicationDirection::Out) => senders.push(ecu_name),
+            Some(CommunicationDirection::In) => receivers.push(ecu_name),
+            None => {}
+        }
+    }
+    senders.sort();
+    receivers.sort();
+
+    let mut pdus: Vec<PduMatrixEntry> = frame
+        .mapped_pdus()
+        .filter_map(|mapping| {
+            let pdu = mapping.pdu()?;
+            Some(PduMatrixEntry {
+                name: pdu.name().unwrap_or_default(),
+                start_position: mapping.start_position(),
+                byte_order: mapping.byte_order(),
+                length: pdu.length(),
+                signals: signal_entries(&pdu),
+            })
+        })
+        .collect();
+    pdus.sort_by(|a, b| (a.start_position, &a.name).cmp(&(b.start_position, &b.name)));
+
+    FrameMatrixEntry {
+        name: frame.name().unwrap_or_default(),
+        identifier,
+        length: frame.length(),
+        senders,
+        receivers,
+        pdus,
+    }
+}
+
+fn signal_entries(pdu: &Pdu) -> Vec<SignalMatrixEntry> {
+    let mappings: Vec<_> = match pdu {
+        Pdu::ISignalIPdu(isignal_ipdu) => isignal_ipdu.mapped_signals().collect(),
+        Pdu::NmPdu(nm_pdu) => nm_pdu.mapped_signals().collect(),
+        _ => Vec::new(),
+    };
+
+    // signal groups don't have a position of their own, so only the signal mappings are listed
+    let mut signals: Vec<SignalMatrixEntry> = mappings
+        .into_iter()
+        .filter_map(|mapping| {
+            let signal = mapping.signal()?;
+            Some(SignalMatrixEntry {
+                name: signal.name().unwrap_or_default(),
+                start_position: mapping.start_position(),
+                bit_length: signal.length(),
+                byte_order: mapping.byte_order(),
+            })
+        })
+        .collect();
+    signals.sort_by(|a, b| (a.start_position, &a.name).cmp(&(b.start_position, &b.name)));
+    signals
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AutosarModelAbstraction, SystemCategory,
+        communication::{CanAddressingMode, CanFrameType, TransferProperty},
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn communication_matrix() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_can_cluster("CanCluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("CanChannel").unwrap();
+
+        let ecu_a = system.create_ecu_instance("EcuA", &package).unwrap();
+        let ecu_b = system.create_ecu_instance("EcuB", &package).unwrap();
+        for ecu in [&ecu_a, &ecu_b] {
+            ecu.create_can_communication_controller("CanCtrl")
+                .unwrap()
+                .connect_physical_channel("Connector", &channel)
+                .unwrap();
+        }
+
+        let frame_2 = system.create_can_frame("Frame2", &package, 8).unwrap();
+        let frame_1 = system.create_can_frame("Frame1", &package, 8).unwrap();
+        let ft_2 = channel
+            .trigger_frame(&frame_2, 0x200, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let ft_1 = channel
+            .trigger_frame(&frame_1, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        ft_1.connect_to_ecu(&ecu_a, CommunicationDirection::Out).unwrap();
+        ft_1.connect_to_ecu(&ecu_b, CommunicationDirection::In).unwrap();
+        ft_2.connect_to_ecu(&ecu_b, CommunicationDirection::Out).unwrap();
+
+        let pdu = system.create_isignal_ipdu("Pdu1", &package, 8).unwrap();
+        frame_1
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let syssignal_b = package.create_system_signal("SigB").unwrap();
+        let signal_b = system.create_isignal("SigB", &package, 16, &syssignal_b, None).unwrap();
+        let syssignal_a = package.create_system_signal("SigA").unwrap();
+        let signal_a = system.create_isignal("SigA", &package, 8, &syssignal_a, None).unwrap();
+        pdu.map_signal(
+            &signal_b,
+            8,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+        pdu.map_signal(
+            &signal_a,
+            0,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+
+        let matrix = system.communication_matrix();
+        let expected = CommunicationMatrix {
+            clusters: vec![ClusterMatrix {
+                name: "CanCluster".to_string(),
+                channels: vec![ChannelMatrix {
+                    name: "CanChannel".to_string(),
+                    frames: vec![
+                        FrameMatrixEntry {
+                            name: "Frame1".to_string(),
+                            identifier: Some(0x100),
+                            length: Some(8),
+                            senders: vec!["EcuA".to_string()],
+                            receivers: vec!["EcuB".to_string()],
+                            pdus: vec![PduMatrixEntry {
+                                name: "Pdu1".to_string(),
+                                start_position: Some(0),
+                                byte_order: Some(ByteOrder::MostSignificantByteLast),
+                                length: Some(8),
+                                signals: vec![
+                                    SignalMatrixEntry {
+                                        name: "SigA".to_string(),
+                                        start_position: Some(0),
+                                        bit_length: Some(8),
+                                        byte_order: Some(ByteOrder::MostSignificantByteLast),
+                                    },
+                                    SignalMatrixEntry {
+                                        name: "SigB".to_string(),
+                                        start_position: Some(8),
+                                        bit_length: Some(16),
+                                        byte_order: Some(ByteOrder::MostSignificantByteLast),
+                                    },
+                                ],
+                            }],
+                        },
+                        FrameMatrixEntry {
+                            name: "Frame2".to_string(),
+                            identifier: Some(0x200),
+                            length: Some(8),
+                            senders: vec!["EcuB".to_string()],
+                            receivers: vec![],
+                            pdus: vec![],
+                        },
+                    ],
+                }],
+            }],
+        };
+        assert_eq!(matrix, expected);
+    }
+}
@@ -0,0 +1,355 @@
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, Element, IdentifiableAbstractionElement, System,
+    abstraction_element, datatype::DataPrototype, make_unique_generated_name, software_component,
+};
+use autosar_data::ElementName;
+use software_component::{
+    AbstractSwComponentType, CompositionSwComponentType, InstanceRef, PortInterface, PortPrototype,
+    RootSwCompositionPrototype, SwComponentPrototype, SwComponentType,
+};
+use std::collections::HashSet;
+
+//##################################################################
+
+/// A `FlatMap` contains a flat list of all instances in the system, identified by their instance paths
+///
+/// Flat maps are typically used as input for the generation of measurement and calibration descriptions (A2L)
+///
+/// Use [`ArPackage::create_flat_map`] or [`System::generate_flat_map`] to create a new `FlatMap`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FlatMap(Element);
+abstraction_element!(FlatMap, FlatMap);
+impl IdentifiableAbstractionElement for FlatMap {}
+
+impl FlatMap {
+    /// create a new `FlatMap` in the given package
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let pkg_elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let flat_map = pkg_elements.create_named_sub_element(ElementName::FlatMap, name)?;
+
+        Ok(Self(flat_map))
+    }
+
+    /// create a new `FlatInstanceDescriptor` in the `FlatMap`
+    ///
+    /// The instance is identified by the path of the `instance` (the root composition prototype,
+    /// the component prototypes and the port prototype) and the `target` data prototype (e.g. a data element)
+    pub fn create_flat_instance_descriptor(
+        &self,
+        name: &str,
+        instance: &InstanceRef,
+        target: &DataPrototype,
+    ) -> Result<FlatInstanceDescriptor, AutosarAbstractionError> {
+        let instances = self.element().get_or_create_sub_element(ElementName::Instances)?;
+        FlatInstanceDescriptor::new(name, &instances, instance, target)
+    }
+
+    /// iterate over all `FlatInstanceDescriptor`s in the `FlatMap`
+    pub fn flat_instance_descriptors(&self) -> impl Iterator<Item = FlatInstanceDescriptor> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::Instances)
+            .into_iter()
+            .flat_map(|instances| instances.sub_elements())
+            .filter_map(|elem| FlatInstanceDescriptor::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// A `FlatInstanceDescriptor` describes a single instance in a `FlatMap`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FlatInstanceDescriptor(Element);
+abstraction_element!(FlatInstanceDescriptor, FlatInstanceDescriptor);
+impl IdentifiableAbstractionElement for FlatInstanceDescriptor {}
+
+impl FlatInstanceDescriptor {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        instance: &InstanceRef,
+        target: &DataPrototype,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let flat_instance_descriptor = parent.create_named_sub_element(ElementName::FlatInstanceDescriptor, name)?;
+
+        let result = write_ecu_extract_iref(&flat_instance_descriptor, instance, target);
+        if let Err(err) = result {
+            parent.remove_sub_element(flat_instance_descriptor)?;
+            return Err(err);
+        }
+
+        Ok(Self(flat_instance_descriptor))
+    }
+
+    /// get the instance path of the descriptor
+    ///
+    /// The path is rebuilt from the context references; `None` is returned if they don't form a valid path.
+    #[must_use]
+    pub fn instance(&self) -> Option<InstanceRef> {
+        let mut context = self
+            .element()
+            .get_sub_element(ElementName::EcuExtractReferenceIref)?
+            .sub_elements()
+            .filter(|elem| elem.element_name() == ElementName::ContextElementRef)
+            .filter_map(|elem| elem.get_reference_target().ok())
+            .peekable();
+
+        // the path starts either with the root composition prototype, or directly inside a composition
+        let first = context.peek()?.clone();
+        let mut instance = if let Ok(root_composition_prototype) = RootSwCompositionPrototype::try_from(first.clone()) {
+            context.next();
+            InstanceRef::from_root_composition_prototype(&root_composition_prototype).ok()?
+        } else if let Ok(component) = SwComponentPrototype::try_from(first.clone()) {
+            InstanceRef::new(&component.parent_composition().ok()?)
+        } else {
+            let SwComponentType::Composition(composition) =
+                PortPrototype::try_from(first).ok()?.component_type().ok()?
+            else {
+                return None;
+            };
+            InstanceRef::new(&composition)
+        };
+
+        for elem in context {
+            instance = if let Ok(component) = SwComponentPrototype::try_from(elem.clone()) {
+                instance.component(&component).ok()?
+            } else {
+                instance.port(&PortPrototype::try_from(elem).ok()?).ok()?
+            };
+        }
+        Some(instance)
+    }
+
+    /// get the target data prototype of the instance reference
+    #[must_use]
+    pub fn target(&self) -> Option<DataPrototype> {
+        let target_elem = self
+            .element()
+            .get_sub_element(ElementName::EcuExtractReferenceIref)?
+            .get_sub_element(ElementName::TargetRef)?
+            .get_reference_target()
+            .ok()?;
+        DataPrototype::try_from(target_elem).ok()
+    }
+}
+
+// write the context path and the target of a flat instance descriptor
+fn write_ecu_extract_iref(
+    flat_instance_descriptor: &Element,
+    instance: &InstanceRef,
+    target: &DataPrototype,
+) -> Result<(), AutosarAbstractionError> {
+    let iref = flat_instance_descriptor.create_sub_element(ElementName::EcuExtractReferenceIref)?;
+    let context = instance
+        .root_composition_prototype()
+        .map(AbstractionElement::element)
+        .into_iter()
+        .chain(instance.components().iter().map(AbstractionElement::element))
+        .chain(instance.port_prototype().map(AbstractionElement::element));
+    for context_elem in context {
+        iref.create_sub_element(ElementName::ContextElementRef)?
+            .set_reference_target(context_elem)?;
+    }
+    iref.create_sub_element(ElementName::TargetRef)?
+        .set_reference_target(target.element())?;
+
+    Ok(())
+}
+
+//##################################################################
+
+impl System {
+    /// generate a `FlatMap` from the root composition of the system
+    ///
+    /// A `FlatInstanceDescriptor` is created for each data element of a sender-receiver port and for each parameter of
+    /// a parameter port of every atomic component instance in the composition hierarchy.
+    /// The new `FlatMap` is referenced by the root composition prototype.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The system has no root composition,
+    ///   or a composition contains itself. The hierarchy is checked before the `FlatMap` is created.
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while creating the `FlatMap`
+    pub fn generate_flat_map(&self, name: &str, package: &ArPackage) -> Result<FlatMap, AutosarAbstractionError> {
+        let root_composition_prototype =
+            self.root_sw_composition()
+                .ok_or(AutosarAbstractionError::InvalidParameter(
+                    "The root composition of the system is not set".to_string(),
+                ))?;
+        let root_composition_type =
+            root_composition_prototype
+                .composition()
+                .ok_or(AutosarAbstractionError::InvalidParameter(
+                    "Incomplete root composition prototype".to_string(),
+                ))?;
+
+        // collect all instances first, so that an invalid hierarchy does not leave a partial FlatMap behind
+        let mut instances = Vec::new();
+        let mut visited = HashSet::from([root_composition_type.clone()]);
+        collect_composition_instances(
+            &root_composition_type,
+            &InstanceRef::from_root_composition_prototype(&root_composition_prototype)?,
+            &mut visited,
+            &mut Vec::new(),
+            &mut instances,
+        )?;
+
+        let flat_map = FlatMap::new(name, package)?;
+        let model = flat_map.element().model()?;
+        let base_path = flat_map.element().path()?;
+        for (initial_name, instance, target) in instances {
+            let descriptor_name = make_unique_generated_name(&model, &base_path, &initial_name);
+            flat_map.create_flat_instance_descriptor(&descriptor_name, &instance, &target)?;
+        }
+        root_composition_prototype.set_flat_map(Some(&flat_map))?;
+
+        Ok(flat_map)
+    }
+}
+
+// recursively collect the instances of all components inside the composition
+//
+// `visited` contains the compositions on the current path, so that a composition which (indirectly)
+// contains itself is detected instead of recursing endlessly
+fn collect_composition_instances(
+    composition: &CompositionSwComponentType,
+    instance: &InstanceRef,
+    visited: &mut HashSet<CompositionSwComponentType>,
+    name_parts: &mut Vec<String>,
+    instances: &mut Vec<(String, InstanceRef, DataPrototype)>,
+) -> Result<(), AutosarAbstractionError> {
+    for component_prototype in composition.components() {
+        let Some(component_type) = component_prototype.component_type() else {
+            continue;
+        };
+        let Some(component_name) = component_prototype.name() else {
+            continue;
+        };
+        let component_instance = instance.clone().component(&component_prototype)?;
+        name_parts.push(component_name);
+
+        if let SwComponentType::Composition(inner_composition) = &component_type {
+            if !visited.insert(inner_composition.clone()) {
+                return Err(AutosarAbstractionError::InvalidParameter(format!(
+                    "The composition {} contains itself",
+                    inner_composition.name().unwrap_or_default()
+                )));
+            }
+            collect_composition_instances(inner_composition, &component_instance, visited, name_parts, instances)?;
+            visited.remove(inner_composition);
+        } else {
+            for port in component_type.ports() {
+                let (Some(port_name), Some(port_interface)) = (port.name(), port.port_interface()) else {
+                    continue;
+                };
+                let targets: Vec<(String, DataPrototype)> = match port_interface {
+                    PortInterface::SenderReceiverInterface(sr_interface) => sr_interface
+                        .data_elements()
+                        .filter_map(|data_element| {
+                            Some((data_element.name()?, DataPrototype::VariableDataPrototype(data_element)))
+                        })
+                        .collect(),
+                    PortInterface::ParameterInterface(parameter_interface) => parameter_interface
+                        .parameters()
+                        .filter_map(|parameter| {
+                            Some((parameter.name()?, DataPrototype::ParameterDataPrototype(parameter)))
+                        })
+                        .collect(),
+                    _ => continue,
+                };
+
+                let port_instance = component_instance.clone().port(&port)?;
+                for (target_name, target) in targets {
+                    let initial_name = format!("{}_{port_name}_{target_name}", name_parts.join("_"));
+                    instances.push((initial_name, port_instance.clone(), target));
+                }
+            }
+        }
+
+        name_parts.pop();
+    }
+
+    Ok(())
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutosarModelAbstraction, SystemCategory, datatype::BaseTypeEncoding};
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn flat_map() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+
+        // no root composition yet
+        let result = system.generate_flat_map("FlatMap", &package);
+        assert!(result.is_err());
+
+        let base_type = package
+            .create_sw_base_type("uint8", 8, BaseTypeEncoding::None, None, None, None)
+            .unwrap();
+        let sr_interface = package.create_sender_receiver_interface("SrInterface").unwrap();
+        let data_element = sr_interface.create_data_element("Data", &base_type).unwrap();
+        let param_interface = package.create_parameter_interface("ParamInterface").unwrap();
+        let parameter = param_interface.create_parameter("Param", &base_type).unwrap();
+
+        let swc = package.create_application_sw_component_type("Swc").unwrap();
+        let p_port = swc.create_p_port("PPort", &sr_interface).unwrap();
+        let param_port = swc.create_r_port("ParamPort", &param_interface).unwrap();
+
+        let root_composition = package.create_composition_sw_component_type("Root").unwrap();
+        let inner_composition = package.create_composition_sw_component_type("Inner").unwrap();
+        let inner_prototype = root_composition
+            .create_component("InnerProto", &inner_composition)
+            .unwrap();
+        let swc_prototype = inner_composition.create_component("SwcProto", &swc).unwrap();
+        let root_prototype = system.set_root_sw_composition("RootProto", &root_composition).unwrap();
+
+        let flat_map = system.generate_flat_map("FlatMap", &package).unwrap();
+        assert_eq!(root_prototype.flat_map().unwrap(), flat_map);
+        let descriptors: Vec<_> = flat_map.flat_instance_descriptors().collect();
+        assert_eq!(descriptors.len(), 2);
+
+        let data_descriptor = descriptors
+            .iter()
+            .find(|descriptor| descriptor.target() == Some(DataPrototype::VariableDataPrototype(data_element.clone())))
+            .unwrap();
+        assert_eq!(
+            data_descriptor.name().unwrap(),
+            "InnerProto_SwcProto_PPort_Data".to_string()
+        );
+        let instance = data_descriptor.instance().unwrap();
+        assert_eq!(instance.root_composition_prototype(), Some(&root_prototype));
+        assert_eq!(instance.components(), &[inner_prototype, swc_prototype]);
+        assert_eq!(instance.port_prototype(), Some(&p_port.into()));
+
+        let param_descriptor = descriptors
+            .iter()
+            .find(|descriptor| descriptor.target() == Some(DataPrototype::ParameterDataPrototype(parameter.clone())))
+            .unwrap();
+        assert_eq!(
+            param_descriptor.instance().unwrap().port_prototype(),
+            Some(&param_port.into())
+        );
+
+        // a composition that contains itself is rejected before the FlatMap is created
+        // create_component prevents cycles, so the reference is changed directly, as it could be in a loaded file
+        let other_composition = package.create_composition_sw_component_type("Other").unwrap();
+        let recursive_prototype = inner_composition
+            .create_component("Recursive", &other_composition)
+            .unwrap();
+        recursive_prototype
+            .element()
+            .get_sub_element(ElementName::TypeTref)
+            .unwrap()
+            .set_reference_target(inner_composition.element())
+            .unwrap();
+        let result = system.generate_flat_map("FlatMap2", &package);
+        assert!(result.is_err());
+        assert!(model.get_element_by_path("/package/FlatMap2").is_none());
+    }
+}
@@ -1,12 +1,16 @@
 use crate::{
-    AbstractionElement, AutosarAbstractionError, EcuInstance, Element, IdentifiableAbstractionElement, System,
-    abstraction_element, communication, software_component,
+    AbstractionElement, AutosarAbstractionError, EcuInstance, EcuPartition, Element, IdentifiableAbstractionElement,
+    System, abstraction_element, communication, datatype, get_reference_parents, software_component,
 };
 use autosar_data::ElementName;
-use communication::SystemSignal;
+use communication::{
+    AbstractFrameTriggering, CommunicationDirection, Frame, FramePort, FrameTriggering, ISignal, ISignalPort,
+    ISignalTriggering, SystemSignal, SystemSignalGroup,
+};
+use datatype::{ApplicationRecordElement, ImplementationDataTypeElement, TextTableMapping};
 use software_component::{
-    AbstractSwComponentType, ComponentPrototype, PortInterface, PortPrototype, RootSwCompositionPrototype,
-    SwComponentPrototype, VariableDataPrototype,
+    AbstractSwComponentType, ClientServerOperation, ComponentPrototype, InstanceRef, PortInterface, PortPrototype,
+    RootSwCompositionPrototype, SwComponentPrototype, VariableDataPrototype,
 };
 
 //##################################################################
@@ -94,6 +98,76 @@ impl SystemMapping {
         )
     }
 
+    /// iterate over all mappings between SWCs and ECUs
+    pub fn swc_to_ecu_mappings(&self) -> impl Iterator<Item = SwcToEcuMapping> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::SwMappings)
+            .into_iter()
+            .flat_map(|sw_mappings| sw_mappings.sub_elements())
+            .filter_map(|elem| SwcToEcuMapping::try_from(elem).ok())
+    }
+
+    /// collect the SWC prototypes, signals and frames of an ECU
+    ///
+    /// The SWC prototypes are taken from the `SwcToEcuMapping`s of this `SystemMapping`, while the signals and frames
+    /// are found through the signal ports and frame ports of the ECU's communication connectors.
+    /// The report can be used as input for ECU resource and bus load estimations.
+    pub fn ecu_report(&self, ecu: &EcuInstance) -> Result<EcuMappingReport, AutosarAbstractionError> {
+        let mut report = EcuMappingReport::default();
+
+        for swc_to_ecu_mapping in self.swc_to_ecu_mappings() {
+            if swc_to_ecu_mapping.ecu_instance().as_ref() == Some(ecu)
+                && let Some(swc_prototype) = swc_to_ecu_mapping.target_component()
+                && !report.swc_prototypes.contains(&swc_prototype)
+            {
+                report.swc_prototypes.push(swc_prototype);
+            }
+        }
+
+        let comm_ports: Vec<_> = ecu
+            .element()
+            .get_sub_element(ElementName::Connectors)
+            .into_iter()
+            .flat_map(|connectors| connectors.sub_elements())
+            .filter_map(|connector| connector.get_sub_element(ElementName::EcuCommPortInstances))
+            .flat_map(|port_instances| port_instances.sub_elements())
+            .collect();
+
+        for port_elem in comm_ports {
+            if let Ok(signal_port) = ISignalPort::try_from(port_elem.clone()) {
+                let signals = match signal_port.communication_direction() {
+                    Some(CommunicationDirection::Out) => &mut report.sent_signals,
+                    Some(CommunicationDirection::In) => &mut report.received_signals,
+                    None => continue,
+                };
+                for (named_parent, _) in get_reference_parents(signal_port.element())? {
+                    if let Ok(signal_triggering) = ISignalTriggering::try_from(named_parent)
+                        && let Some(signal) = signal_triggering.signal()
+                        && !signals.contains(&signal)
+                    {
+                        signals.push(signal);
+                    }
+                }
+            } else if let Ok(frame_port) = FramePort::try_from(port_elem) {
+                let frames = match frame_port.communication_direction() {
+                    Some(CommunicationDirection::Out) => &mut report.sent_frames,
+                    Some(CommunicationDirection::In) => &mut report.received_frames,
+                    None => continue,
+                };
+                for (named_parent, _) in get_reference_parents(frame_port.element())? {
+                    if let Ok(frame_triggering) = FrameTriggering::try_from(named_parent)
+                        && let Some(frame) = frame_triggering.frame()
+                        && !frames.contains(&frame)
+                    {
+                        frames.push(frame);
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     /// create a new mapping between a sender/receiver port and a signal
     ///
     /// `signal`: the system signal that the port is mapped to
@@ -147,21 +221,7 @@ impl SystemMapping {
             ));
         }
 
-        // the last context component in the list contains the port prototype
-        if let Some(swc_prototype) = context_components.last() {
-            let swc_type = port_prototype.component_type()?;
-            let swc_prototype_type =
-                swc_prototype
-                    .component_type()
-                    .ok_or(AutosarAbstractionError::InvalidParameter(
-                        "invalid SWC prototype: component type ref is missing".to_string(),
-                    ))?;
-            if swc_type != swc_prototype_type {
-                return Err(AutosarAbstractionError::InvalidParameter(
-                    "The port must be part of the component prototype".to_string(),
-                ));
-            }
-        }
+        check_port_context(port_prototype, context_components)?;
 
         // create the mapping
         let data_mappings = self.element().get_or_create_sub_element(ElementName::DataMappings)?;
@@ -175,6 +235,163 @@ impl SystemMapping {
             root_composition_prototype,
         )
     }
+
+    /// create a new mapping between a sender/receiver port and a signal group
+    ///
+    /// The data element must have a record data type. The individual record elements are mapped to the
+    /// signals of the signal group with [`SenderReceiverToSignalGroupMapping::map_application_record_element`]
+    /// or [`SenderReceiverToSignalGroupMapping::map_implementation_record_element`].
+    ///
+    /// The parameters `port_prototype`, `context_components` and `root_composition_prototype` have the same
+    /// meaning as in [`SystemMapping::map_sender_receiver_to_signal`].
+    pub fn map_sender_receiver_to_signal_group<T: Into<PortPrototype> + Clone>(
+        &self,
+        signal_group: &SystemSignalGroup,
+        data_element: &VariableDataPrototype,
+        port_prototype: &T,
+        context_components: &[&SwComponentPrototype],
+        root_composition_prototype: Option<&RootSwCompositionPrototype>,
+    ) -> Result<SenderReceiverToSignalGroupMapping, AutosarAbstractionError> {
+        let port_prototype = port_prototype.clone().into();
+
+        // the port must be a sender/receiver port, and the data element must be part of its interface
+        let Some(PortInterface::SenderReceiverInterface(interface)) = port_prototype.port_interface() else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The port prototype must be a sender/receiver port".to_string(),
+            ));
+        };
+        if data_element.interface()? != interface {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The data element must be part of the sender/receiver interface".to_string(),
+            ));
+        }
+        check_port_context(&port_prototype, context_components)?;
+
+        let data_mappings = self.element().get_or_create_sub_element(ElementName::DataMappings)?;
+
+        SenderReceiverToSignalGroupMapping::new(
+            &data_mappings,
+            signal_group,
+            data_element,
+            &port_prototype,
+            context_components,
+            root_composition_prototype,
+        )
+    }
+
+    /// create a new mapping between an operation of a client/server port and the signals that transport it
+    ///
+    /// This is used when client/server communication is transported over the network, e.g. with SOME/IP methods.
+    ///
+    /// `call_signal`: the system signal that transports the call (request) of the operation
+    ///
+    /// `return_signal`: the system signal that transports the response. It may be omitted for fire-and-forget operations.
+    ///
+    /// The parameters `port_prototype`, `context_components` and `root_composition_prototype` have the same
+    /// meaning as in [`SystemMapping::map_sender_receiver_to_signal`].
+    pub fn map_client_server_to_signal<T: Into<PortPrototype> + Clone>(
+        &self,
+        call_signal: &SystemSignal,
+        return_signal: Option<&SystemSignal>,
+        operation: &ClientServerOperation,
+        port_prototype: &T,
+        context_components: &[&SwComponentPrototype],
+        root_composition_prototype: Option<&RootSwCompositionPrototype>,
+    ) -> Result<ClientServerToSignalMapping, AutosarAbstractionError> {
+        let port_prototype = port_prototype.clone().into();
+
+        // the port must be a client/server port, and the operation must be part of its interface
+        let Some(PortInterface::ClientServerInterface(interface)) = port_prototype.port_interface() else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The port prototype must be a client/server port".to_string(),
+            ));
+        };
+        if operation.interface()? != interface {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The operation must be part of the client/server interface".to_string(),
+            ));
+        }
+        check_port_context(&port_prototype, context_components)?;
+
+        let data_mappings = self.element().get_or_create_sub_element(ElementName::DataMappings)?;
+
+        ClientServerToSignalMapping::new(
+            &data_mappings,
+            call_signal,
+            return_signal,
+            operation,
+            &port_prototype,
+            context_components,
+            root_composition_prototype,
+        )
+    }
+}
+
+// the last context component in the list must contain the port prototype
+fn check_port_context(
+    port_prototype: &PortPrototype,
+    context_components: &[&SwComponentPrototype],
+) -> Result<(), AutosarAbstractionError> {
+    if let Some(swc_prototype) = context_components.last() {
+        let swc_type = port_prototype.component_type()?;
+        let swc_prototype_type = swc_prototype
+            .component_type()
+            .ok_or(AutosarAbstractionError::InvalidParameter(
+                "invalid SWC prototype: component type ref is missing".to_string(),
+            ))?;
+        if swc_type != swc_prototype_type {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The port must be part of the component prototype".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+// fill an instance ref which identifies an element of a port interface in the composition hierarchy
+fn fill_port_element_iref(
+    iref: &Element,
+    target_ref_name: ElementName,
+    target: &Element,
+    port_prototype: &PortPrototype,
+    context_components: &[&SwComponentPrototype],
+    root_composition_prototype: Option<&RootSwCompositionPrototype>,
+) -> Result<(), AutosarAbstractionError> {
+    iref.create_sub_element(ElementName::ContextPortRef)?
+        .set_reference_target(port_prototype.element())?;
+    iref.create_sub_element(target_ref_name)?.set_reference_target(target)?;
+
+    // the list of context components is ordered, with the root composition prototype at the beginning
+    for comp_proto in context_components {
+        iref.create_sub_element(ElementName::ContextComponentRef)?
+            .set_reference_target(comp_proto.element())?;
+    }
+
+    if let Some(root_composition_prototype) = root_composition_prototype {
+        iref.create_sub_element(ElementName::ContextCompositionRef)?
+            .set_reference_target(root_composition_prototype.element())?;
+    }
+
+    Ok(())
+}
+
+//#########################################################
+
+/// The result of [`SystemMapping::ecu_report`]
+///
+/// It contains everything that is mapped to a single ECU
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EcuMappingReport {
+    /// The SWC prototypes that are mapped to the ECU
+    pub swc_prototypes: Vec<SwComponentPrototype>,
+    /// The signals that are sent by the ECU
+    pub sent_signals: Vec<ISignal>,
+    /// The signals that are received by the ECU
+    pub received_signals: Vec<ISignal>,
+    /// The frames that are sent by the ECU
+    pub sent_frames: Vec<Frame>,
+    /// The frames that are received by the ECU
+    pub received_frames: Vec<Frame>,
 }
 
 //#########################################################
@@ -194,27 +411,36 @@ impl SwcToEcuMapping {
         ecu: &EcuInstance,
         mapping: &SystemMapping,
     ) -> Result<Self, AutosarAbstractionError> {
+        // build the instance reference first, so that an invalid path is rejected before the model is modified
+        let mut instance = InstanceRef::from_root_composition_prototype(root_composition_prototype)?;
+        for context_comp in context_composition_prototypes {
+            let ComponentPrototype::SwComponent(context_comp) = context_comp else {
+                return Err(AutosarAbstractionError::InvalidParameter(
+                    "A root composition can not be a context component".to_string(),
+                ));
+            };
+            instance = instance.component(context_comp)?;
+        }
+        let instance = instance.component(component_prototype)?;
+
         let sw_mappings_elem = mapping.element().get_or_create_sub_element(ElementName::SwMappings)?;
         let swc_to_ecu_mapping = sw_mappings_elem.create_named_sub_element(ElementName::SwcToEcuMapping, name)?;
 
-        let iref = swc_to_ecu_mapping
-            .create_sub_element(ElementName::ComponentIrefs)?
-            .create_sub_element(ElementName::ComponentIref)?;
-
-        // create the references to root composition and context compositions
-        iref.create_sub_element(ElementName::ContextCompositionRef)?
-            .set_reference_target(root_composition_prototype.element())?;
-        for context_comp in context_composition_prototypes {
-            iref.create_sub_element(ElementName::ContextComponentRef)?
-                .set_reference_target(context_comp.element())?;
+        let result = swc_to_ecu_mapping
+            .create_sub_element(ElementName::ComponentIrefs)
+            .and_then(|irefs| irefs.create_sub_element(ElementName::ComponentIref))
+            .map_err(AutosarAbstractionError::from)
+            .and_then(|iref| instance.write_component_iref(&iref))
+            .and_then(|()| {
+                swc_to_ecu_mapping
+                    .create_sub_element(ElementName::EcuInstanceRef)?
+                    .set_reference_target(ecu.element())?;
+                Ok(())
+            });
+        if let Err(err) = result {
+            sw_mappings_elem.remove_sub_element(swc_to_ecu_mapping)?;
+            return Err(err);
         }
-        // create the reference to the target component prototype
-        iref.create_sub_element(ElementName::TargetComponentRef)?
-            .set_reference_target(component_prototype.element())?;
-
-        swc_to_ecu_mapping
-            .create_sub_element(ElementName::EcuInstanceRef)?
-            .set_reference_target(ecu.element())?;
 
         Ok(Self(swc_to_ecu_mapping))
     }
@@ -238,6 +464,35 @@ impl SwcToEcuMapping {
             .and_then(|r| r.get_reference_target().ok())
             .and_then(|target| EcuInstance::try_from(target).ok())
     }
+
+    /// set or remove the `EcuPartition` that the component is mapped to
+    ///
+    /// The partition must belong to the ECU instance of the mapping.
+    /// The core that executes the component follows from the core assignment of the partition in the ECU configuration.
+    pub fn set_partition(&self, partition: Option<&EcuPartition>) -> Result<(), AutosarAbstractionError> {
+        if let Some(partition) = partition {
+            if self.ecu_instance() != Some(partition.ecu_instance()?) {
+                return Err(AutosarAbstractionError::InvalidParameter(
+                    "The partition does not belong to the ECU instance of the mapping".to_string(),
+                ));
+            }
+            self.element()
+                .get_or_create_sub_element(ElementName::PartitionRef)?
+                .set_reference_target(partition.element())?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::PartitionRef);
+        }
+        Ok(())
+    }
+
+    /// get the `EcuPartition` that the component is mapped to
+    #[must_use]
+    pub fn partition(&self) -> Option<EcuPartition> {
+        self.element()
+            .get_sub_element(ElementName::PartitionRef)
+            .and_then(|r| r.get_reference_target().ok())
+            .and_then(|target| EcuPartition::try_from(target).ok())
+    }
 }
 
 //#########################################################
@@ -258,21 +513,14 @@ impl SenderReceiverToSignalMapping {
     ) -> Result<Self, AutosarAbstractionError> {
         let sr_mapping = parent.create_sub_element(ElementName::SenderReceiverToSignalMapping)?;
         let iref = sr_mapping.create_sub_element(ElementName::DataElementIref)?;
-        iref.create_sub_element(ElementName::ContextPortRef)?
-            .set_reference_target(port_prototype.element())?;
-        iref.create_sub_element(ElementName::TargetDataPrototypeRef)?
-            .set_reference_target(data_element.element())?;
-
-        // the list of context components is ordered, with the root composition prototype at the beginning
-        for comp_proto in context_components {
-            iref.create_sub_element(ElementName::ContextComponentRef)?
-                .set_reference_target(comp_proto.element())?;
-        }
-
-        if let Some(root_composition_prototype) = root_composition_prototype {
-            iref.create_sub_element(ElementName::ContextCompositionRef)?
-                .set_reference_target(root_composition_prototype.element())?;
-        }
+        fill_port_element_iref(
+            &iref,
+            ElementName::TargetDataPrototypeRef,
+            data_element.element(),
+            port_prototype,
+            context_components,
+            root_composition_prototype,
+        )?;
 
         sr_mapping
             .create_sub_element(ElementName::SystemSignalRef)?
@@ -301,6 +549,276 @@ impl SenderReceiverToSignalMapping {
             .and_then(|r| r.get_reference_target().ok())?;
         VariableDataPrototype::try_from(element).ok()
     }
+
+    /// set or remove the `TextTableMapping` that converts the values of the data element into the values of the signal
+    ///
+    /// This is used on the sender side, if the data element and the signal use different values for the same texts.
+    pub fn set_sender_to_signal_text_table_mapping(
+        &self,
+        mapping: Option<&TextTableMapping>,
+    ) -> Result<(), AutosarAbstractionError> {
+        TextTableMapping::set(self.element(), ElementName::SenderToSignalTextTableMapping, mapping)
+    }
+
+    /// get the `TextTableMapping` that converts the values of the data element into the values of the signal
+    #[must_use]
+    pub fn sender_to_signal_text_table_mapping(&self) -> Option<TextTableMapping> {
+        TextTableMapping::get(self.element(), ElementName::SenderToSignalTextTableMapping)
+    }
+
+    /// set or remove the `TextTableMapping` that converts the values of the signal into the values of the data element
+    ///
+    /// This is used on the receiver side, if the data element and the signal use different values for the same texts.
+    pub fn set_signal_to_receiver_text_table_mapping(
+        &self,
+        mapping: Option<&TextTableMapping>,
+    ) -> Result<(), AutosarAbstractionError> {
+        TextTableMapping::set(self.element(), ElementName::SignalToReceiverTextTableMapping, mapping)
+    }
+
+    /// get the `TextTableMapping` that converts the values of the signal into the values of the data element
+    #[must_use]
+    pub fn signal_to_receiver_text_table_mapping(&self) -> Option<TextTableMapping> {
+        TextTableMapping::get(self.element(), ElementName::SignalToReceiverTextTableMapping)
+    }
+}
+
+//#########################################################
+
+/// A `SenderReceiverToSignalGroupMapping` contains a mapping between a sender/receiver port with a record data type
+/// and a system signal group
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SenderReceiverToSignalGroupMapping(Element);
+abstraction_element!(SenderReceiverToSignalGroupMapping, SenderReceiverToSignalGroupMapping);
+
+impl SenderReceiverToSignalGroupMapping {
+    pub(crate) fn new(
+        parent: &Element,
+        signal_group: &SystemSignalGroup,
+        data_element: &VariableDataPrototype,
+        port_prototype: &PortPrototype,
+        context_components: &[&SwComponentPrototype],
+        root_composition_prototype: Option<&RootSwCompositionPrototype>,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let sr_group_mapping = parent.create_sub_element(ElementName::SenderReceiverToSignalGroupMapping)?;
+        let iref = sr_group_mapping.create_sub_element(ElementName::DataElementIref)?;
+        fill_port_element_iref(
+            &iref,
+            ElementName::TargetDataPrototypeRef,
+            data_element.element(),
+            port_prototype,
+            context_components,
+            root_composition_prototype,
+        )?;
+
+        sr_group_mapping
+            .create_sub_element(ElementName::SignalGroupRef)?
+            .set_reference_target(signal_group.element())?;
+
+        Ok(Self(sr_group_mapping))
+    }
+
+    /// Get the system signal group that is the target of this mapping
+    #[must_use]
+    pub fn signal_group(&self) -> Option<SystemSignalGroup> {
+        let element = self
+            .element()
+            .get_sub_element(ElementName::SignalGroupRef)
+            .and_then(|r| r.get_reference_target().ok())?;
+        SystemSignalGroup::try_from(element).ok()
+    }
+
+    /// Get the data element that is mapped to the signal group
+    #[must_use]
+    pub fn data_element(&self) -> Option<VariableDataPrototype> {
+        let element = self
+            .element()
+            .get_sub_element(ElementName::DataElementIref)
+            .and_then(|iref| iref.get_sub_element(ElementName::TargetDataPrototypeRef))
+            .and_then(|r| r.get_reference_target().ok())?;
+        VariableDataPrototype::try_from(element).ok()
+    }
+
+    /// map an element of an application record data type to a signal of the signal group
+    pub fn map_application_record_element(
+        &self,
+        record_element: &ApplicationRecordElement,
+        signal: &SystemSignal,
+    ) -> Result<SenderRecRecordElementMapping, AutosarAbstractionError> {
+        self.check_group_signal(signal)?;
+        let element_mapping = self.create_record_element_mapping()?;
+        element_mapping
+            .create_sub_element(ElementName::ApplicationRecordElementRef)?
+            .set_reference_target(record_element.element())?;
+        element_mapping
+            .create_sub_element(ElementName::SystemSignalRef)?
+            .set_reference_target(signal.element())?;
+
+        Ok(SenderRecRecordElementMapping(element_mapping))
+    }
+
+    /// map an element of an implementation structure data type to a signal of the signal group
+    pub fn map_implementation_record_element(
+        &self,
+        record_element: &ImplementationDataTypeElement,
+        signal: &SystemSignal,
+    ) -> Result<SenderRecRecordElementMapping, AutosarAbstractionError> {
+        self.check_group_signal(signal)?;
+        let element_mapping = self.create_record_element_mapping()?;
+        element_mapping
+            .create_sub_element(ElementName::ImplementationRecordElementRef)?
+            .set_reference_target(record_element.element())?;
+        element_mapping
+            .create_sub_element(ElementName::SystemSignalRef)?
+            .set_reference_target(signal.element())?;
+
+        Ok(SenderRecRecordElementMapping(element_mapping))
+    }
+
+    /// iterate over the mappings of record elements to signals
+    pub fn record_element_mappings(&self) -> impl Iterator<Item = SenderRecRecordElementMapping> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::TypeMapping)
+            .and_then(|type_mapping| type_mapping.get_sub_element(ElementName::SenderRecRecordTypeMapping))
+            .and_then(|record_type_mapping| record_type_mapping.get_sub_element(ElementName::RecordElementMappings))
+            .into_iter()
+            .flat_map(|mappings| mappings.sub_elements())
+            .filter_map(|elem| SenderRecRecordElementMapping::try_from(elem).ok())
+    }
+
+    // the signal must be part of the signal group
+    fn check_group_signal(&self, signal: &SystemSignal) -> Result<(), AutosarAbstractionError> {
+        let signal_group = self.signal_group().ok_or(AutosarAbstractionError::InvalidParameter(
+            "The mapping does not reference a signal group".to_string(),
+        ))?;
+        if !signal_group.signals().any(|group_signal| group_signal == *signal) {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The signal must be part of the signal group".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn create_record_element_mapping(&self) -> Result<Element, AutosarAbstractionError> {
+        let element_mapping = self
+            .element()
+            .get_or_create_sub_element(ElementName::TypeMapping)?
+            .get_or_create_sub_element(ElementName::SenderRecRecordTypeMapping)?
+            .get_or_create_sub_element(ElementName::RecordElementMappings)?
+            .create_sub_element(ElementName::SenderRecRecordElementMapping)?;
+        Ok(element_mapping)
+    }
+}
+
+//#########################################################
+
+/// A `SenderRecRecordElementMapping` maps one element of a record data type to a system signal
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SenderRecRecordElementMapping(Element);
+abstraction_element!(SenderRecRecordElementMapping, SenderRecRecordElementMapping);
+
+impl SenderRecRecordElementMapping {
+    /// Get the application record element that is mapped to the signal
+    #[must_use]
+    pub fn application_record_element(&self) -> Option<ApplicationRecordElement> {
+        let element = self
+            .element()
+            .get_sub_element(ElementName::ApplicationRecordElementRef)
+            .and_then(|r| r.get_reference_target().ok())?;
+        ApplicationRecordElement::try_from(element).ok()
+    }
+
+    /// Get the implementation record element that is mapped to the signal
+    #[must_use]
+    pub fn implementation_record_element(&self) -> Option<ImplementationDataTypeElement> {
+        let element = self
+            .element()
+            .get_sub_element(ElementName::ImplementationRecordElementRef)
+            .and_then(|r| r.get_reference_target().ok())?;
+        ImplementationDataTypeElement::try_from(element).ok()
+    }
+
+    /// Get the system signal that the record element is mapped to
+    #[must_use]
+    pub fn system_signal(&self) -> Option<SystemSignal> {
+        let element = self
+            .element()
+            .get_sub_element(ElementName::SystemSignalRef)
+            .and_then(|r| r.get_reference_target().ok())?;
+        SystemSignal::try_from(element).ok()
+    }
+}
+
+//#########################################################
+
+/// A `ClientServerToSignalMapping` maps an operation of a client/server port to the signals that transport
+/// the call and the return of the operation
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClientServerToSignalMapping(Element);
+abstraction_element!(ClientServerToSignalMapping, ClientServerToSignalMapping);
+
+impl ClientServerToSignalMapping {
+    pub(crate) fn new(
+        parent: &Element,
+        call_signal: &SystemSignal,
+        return_signal: Option<&SystemSignal>,
+        operation: &ClientServerOperation,
+        port_prototype: &PortPrototype,
+        context_components: &[&SwComponentPrototype],
+        root_composition_prototype: Option<&RootSwCompositionPrototype>,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let cs_mapping = parent.create_sub_element(ElementName::ClientServerToSignalMapping)?;
+        cs_mapping
+            .create_sub_element(ElementName::CallSignalRef)?
+            .set_reference_target(call_signal.element())?;
+        let iref = cs_mapping.create_sub_element(ElementName::ClientServerOperationIref)?;
+        fill_port_element_iref(
+            &iref,
+            ElementName::TargetOperationRef,
+            operation.element(),
+            port_prototype,
+            context_components,
+            root_composition_prototype,
+        )?;
+        if let Some(return_signal) = return_signal {
+            cs_mapping
+                .create_sub_element(ElementName::ReturnSignalRef)?
+                .set_reference_target(return_signal.element())?;
+        }
+
+        Ok(Self(cs_mapping))
+    }
+
+    /// Get the system signal that transports the call of the operation
+    #[must_use]
+    pub fn call_signal(&self) -> Option<SystemSignal> {
+        let element = self
+            .element()
+            .get_sub_element(ElementName::CallSignalRef)
+            .and_then(|r| r.get_reference_target().ok())?;
+        SystemSignal::try_from(element).ok()
+    }
+
+    /// Get the system signal that transports the return of the operation
+    #[must_use]
+    pub fn return_signal(&self) -> Option<SystemSignal> {
+        let element = self
+            .element()
+            .get_sub_element(ElementName::ReturnSignalRef)
+            .and_then(|r| r.get_reference_target().ok())?;
+        SystemSignal::try_from(element).ok()
+    }
+
+    /// Get the operation that is mapped to the signals
+    #[must_use]
+    pub fn operation(&self) -> Option<ClientServerOperation> {
+        let element = self
+            .element()
+            .get_sub_element(ElementName::ClientServerOperationIref)
+            .and_then(|iref| iref.get_sub_element(ElementName::TargetOperationRef))
+            .and_then(|r| r.get_reference_target().ok())?;
+        ClientServerOperation::try_from(element).ok()
+    }
 }
 
 //#########################################################
@@ -309,8 +827,9 @@ impl SenderReceiverToSignalMapping {
 mod test {
     use super::*;
     use crate::{
-        AutosarModelAbstraction, SystemCategory,
-        datatype::{ApplicationPrimitiveCategory, ApplicationPrimitiveDataType},
+        AutosarModelAbstraction, ByteOrder, SystemCategory,
+        communication::{CanAddressingMode, CanFrameType, TransferProperty},
+        datatype::{ApplicationPrimitiveCategory, ApplicationPrimitiveDataType, MappingDirection},
     };
 
     #[test]
@@ -343,6 +862,16 @@ mod test {
         assert_eq!(swc_to_ecu.target_component().unwrap(), ecu_composition_prototype);
         assert_eq!(swc_to_ecu.ecu_instance().unwrap(), ecu);
 
+        // map the component to a partition of the ecu
+        let partition = ecu.create_partition("test_partition").unwrap();
+        swc_to_ecu.set_partition(Some(&partition)).unwrap();
+        assert_eq!(swc_to_ecu.partition().unwrap(), partition);
+        let other_ecu = system.create_ecu_instance("other_ecu", &package).unwrap();
+        let other_partition = other_ecu.create_partition("other_partition").unwrap();
+        assert!(swc_to_ecu.set_partition(Some(&other_partition)).is_err());
+        swc_to_ecu.set_partition(None).unwrap();
+        assert!(swc_to_ecu.partition().is_none());
+
         // map a signal to a port
         let sys_signal = package.create_system_signal("test_signal").unwrap();
 
@@ -365,8 +894,134 @@ mod test {
             .create_r_port("test_port", &sender_receiver_interface)
             .unwrap();
 
-        mapping
+        let sr_mapping = mapping
             .map_sender_receiver_to_signal(&sys_signal, &data_element, &sr_port, &[], None)
             .unwrap();
+        assert_eq!(sr_mapping.system_signal().unwrap(), sys_signal);
+        assert_eq!(sr_mapping.data_element().unwrap(), data_element);
+
+        // map the values of an enumeration
+        let text_table_mapping = TextTableMapping {
+            identical_mapping: Some(false),
+            mapping_direction: Some(MappingDirection::Bidirectional),
+            value_pairs: vec![(0.0, 1.0), (1.0, 2.0)],
+        };
+        sr_mapping
+            .set_sender_to_signal_text_table_mapping(Some(&text_table_mapping))
+            .unwrap();
+        assert_eq!(
+            sr_mapping.sender_to_signal_text_table_mapping(),
+            Some(text_table_mapping.clone())
+        );
+        assert!(sr_mapping.signal_to_receiver_text_table_mapping().is_none());
+        sr_mapping.set_sender_to_signal_text_table_mapping(None).unwrap();
+        assert!(sr_mapping.sender_to_signal_text_table_mapping().is_none());
+
+        // map a record data element to a signal group
+        let record_type = package.create_application_record_data_type("Record").unwrap();
+        let record_element = record_type.create_record_element("record_element", &data_type).unwrap();
+        let record_data_element = sender_receiver_interface
+            .create_data_element("record", &record_type)
+            .unwrap();
+        let signal_group = package.create_system_signal_group("test_signal_group").unwrap();
+        let group_signal = package.create_system_signal("test_group_signal").unwrap();
+        signal_group.add_signal(&group_signal).unwrap();
+
+        let sr_group_mapping = mapping
+            .map_sender_receiver_to_signal_group(&signal_group, &record_data_element, &sr_port, &[], None)
+            .unwrap();
+        assert_eq!(sr_group_mapping.signal_group().unwrap(), signal_group);
+        assert_eq!(sr_group_mapping.data_element().unwrap(), record_data_element);
+        // the signal is not part of the signal group
+        let result = sr_group_mapping.map_application_record_element(&record_element, &sys_signal);
+        assert!(result.is_err());
+        let element_mapping = sr_group_mapping
+            .map_application_record_element(&record_element, &group_signal)
+            .unwrap();
+        assert_eq!(element_mapping.application_record_element().unwrap(), record_element);
+        assert_eq!(element_mapping.system_signal().unwrap(), group_signal);
+        assert!(element_mapping.implementation_record_element().is_none());
+        assert_eq!(sr_group_mapping.record_element_mappings().count(), 1);
+
+        // map a client/server operation to signals
+        let cs_interface = package.create_client_server_interface("ClientServerInterface").unwrap();
+        let operation = cs_interface.create_operation("operation").unwrap();
+        let cs_port = ecu_composition_type.create_p_port("cs_port", &cs_interface).unwrap();
+        let call_signal = package.create_system_signal("call_signal").unwrap();
+        let return_signal = package.create_system_signal("return_signal").unwrap();
+
+        // the sender/receiver port can't be used for client/server mappings
+        let result =
+            mapping.map_client_server_to_signal(&call_signal, Some(&return_signal), &operation, &sr_port, &[], None);
+        assert!(result.is_err());
+        let cs_mapping = mapping
+            .map_client_server_to_signal(&call_signal, Some(&return_signal), &operation, &cs_port, &[], None)
+            .unwrap();
+        assert_eq!(cs_mapping.call_signal().unwrap(), call_signal);
+        assert_eq!(cs_mapping.return_signal().unwrap(), return_signal);
+        assert_eq!(cs_mapping.operation().unwrap(), operation);
+    }
+
+    #[test]
+    fn ecu_report() {
+        let model = AutosarModelAbstraction::create("filename", autosar_data::AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+        let mapping = system.get_or_create_mapping("Mapping").unwrap();
+
+        let ecu = system.create_ecu_instance("Ecu", &package).unwrap();
+        let other_ecu = system.create_ecu_instance("OtherEcu", &package).unwrap();
+        let root_composition_type = package.create_composition_sw_component_type("Root").unwrap();
+        system
+            .set_root_sw_composition("RootProto", &root_composition_type)
+            .unwrap();
+        let swc_type = package.create_application_sw_component_type("Swc").unwrap();
+        let swc_prototype = root_composition_type.create_component("SwcProto", &swc_type).unwrap();
+        let other_swc_prototype = root_composition_type
+            .create_component("OtherSwcProto", &swc_type)
+            .unwrap();
+        mapping.map_swc_to_ecu("SwcToEcu", &swc_prototype, &ecu).unwrap();
+        mapping
+            .map_swc_to_ecu("OtherSwcToEcu", &other_swc_prototype, &other_ecu)
+            .unwrap();
+        assert_eq!(mapping.swc_to_ecu_mappings().count(), 2);
+
+        let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+        let ctrl = ecu.create_can_communication_controller("CanCtrl").unwrap();
+        ctrl.connect_physical_channel("Connector", &channel).unwrap();
+
+        let syssignal = package.create_system_signal("SysSignal").unwrap();
+        let signal = system.create_isignal("Signal", &package, 8, &syssignal, None).unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        pdu.map_signal(
+            &signal,
+            0,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap()
+            .connect_to_ecu(&ecu, CommunicationDirection::Out)
+            .unwrap();
+
+        let report = mapping.ecu_report(&ecu).unwrap();
+        assert_eq!(report.swc_prototypes, vec![swc_prototype]);
+        assert_eq!(report.sent_signals, vec![signal]);
+        assert!(report.received_signals.is_empty());
+        assert_eq!(report.sent_frames, vec![Frame::Can(frame)]);
+        assert!(report.received_frames.is_empty());
+
+        let other_report = mapping.ecu_report(&other_ecu).unwrap();
+        assert_eq!(other_report.swc_prototypes, vec![other_swc_prototype]);
+        assert!(other_report.sent_signals.is_empty());
+        assert!(other_report.sent_frames.is_empty());
     }
 }
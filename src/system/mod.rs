@@ -1,23 +1,33 @@
 use crate::communication::{
     CanCluster, CanFrame, CanTpConfig, Cluster, CommunicationDirection, ContainerIPdu, ContainerIPduHeaderType,
-    DcmIPdu, DiagPduType, DoIpTpConfig, EthernetCluster, EventGroupControlType, FlexrayArTpConfig, FlexrayCluster,
-    FlexrayClusterSettings, FlexrayFrame, FlexrayTpConfig, Frame, GeneralPurposeIPdu, GeneralPurposeIPduCategory,
-    GeneralPurposePdu, GeneralPurposePduCategory, ISignal, ISignalGroup, ISignalIPdu, ISignalIPduGroup, LinCluster,
-    LinEventTriggeredFrame, LinSporadicFrame, LinUnconditionalFrame, MultiplexedIPdu, NPdu, NmConfig, NmPdu, Pdu,
-    RxAcceptContainedIPdu, SecureCommunicationProps, SecuredIPdu, ServiceInstanceCollectionSet, SoAdRoutingGroup,
+    CouplingElement, DcmIPdu, DiagPduType, DoIpTpConfig, EthernetCluster, EventGroupControlType, FlexrayArTpConfig,
+    FlexrayCluster, FlexrayClusterSettings, FlexrayFrame, FlexrayTpConfig, Frame, Gateway, GeneralPurposeIPdu,
+    GeneralPurposeIPduCategory, GeneralPurposePdu, GeneralPurposePduCategory, ISignal, ISignalGroup, ISignalIPdu,
+    ISignalIPduGroup, LinCluster, LinEventTriggeredFrame, LinSporadicFrame, LinUnconditionalFrame, MultiplexedIPdu,
+    NPdu, NetworkEndpoint, NmConfig, NmPdu, Pdu, RxAcceptContainedIPdu, SecureCommunicationProps, SecuredIPdu,
+    ServiceInstanceCollectionSet, SignalConsistencyIssue, SignalCounterpartReport, SoAdRoutingGroup,
     SocketConnectionIpduIdentifierSet, SomeipTpConfig, SystemSignal, SystemSignalGroup, UserDefinedPdu,
 };
 use crate::datatype::SwBaseType;
 use crate::software_component::{CompositionSwComponentType, RootSwCompositionPrototype};
 use crate::{
     AbstractionElement, ArPackage, AutosarAbstractionError, EcuInstance, IdentifiableAbstractionElement,
-    abstraction_element,
+    abstraction_element, is_used, make_unique_generated_name,
 };
-use autosar_data::{AutosarModel, Element, ElementName, WeakElement};
+use autosar_data::{AutosarDataError, AutosarModel, Element, ElementName, WeakElement};
 
+mod communication_matrix;
+mod flat_map;
 mod mapping;
+mod pnc_mapping;
+mod rapid_prototyping;
+mod restbus;
 
+pub use communication_matrix::*;
+pub use flat_map::*;
 pub use mapping::*;
+pub use pnc_mapping::*;
+pub use rapid_prototyping::*;
 
 /// The System is the top level of a system template
 ///
@@ -192,6 +202,67 @@ impl System {
             .parse_integer()
     }
 
+    /// create a `PncMapping` for the partial network cluster with the given identifier
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let pnc_mapping = system.create_pnc_mapping(5)?;
+    /// assert_eq!(pnc_mapping.pnc_identifier(), Some(5));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the PNC-MAPPING element
+    pub fn create_pnc_mapping(&self, pnc_identifier: u32) -> Result<PncMapping, AutosarAbstractionError> {
+        let pnc_mappings = self.0.get_or_create_sub_element(ElementName::PncMappings)?;
+        PncMapping::new(&pnc_mappings, pnc_identifier)
+    }
+
+    /// iterate over all `PncMapping`s of the system
+    pub fn pnc_mappings(&self) -> impl Iterator<Item = PncMapping> + Send + use<> {
+        self.0
+            .get_sub_element(ElementName::PncMappings)
+            .into_iter()
+            .flat_map(|pnc_mappings| pnc_mappings.sub_elements())
+            .filter_map(|elem| PncMapping::try_from(elem).ok())
+    }
+
+    /// create a snapshot of the communication in the system
+    ///
+    /// The [`CommunicationMatrix`] lists the frames of each cluster and channel, together with their senders
+    /// and receivers, the pdus they contain, and the signals in each pdu.
+    /// Only frames with a frame triggering are included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let matrix = system.communication_matrix();
+    /// for cluster in &matrix.clusters {
+    ///     for channel in &cluster.channels {
+    ///         println!("{}/{}: {} frames", cluster.name, channel.name, channel.frames.len());
+    ///     }
+    /// }
+    /// # Ok(())}
+    /// ```
+    #[must_use]
+    pub fn communication_matrix(&self) -> CommunicationMatrix {
+        CommunicationMatrix::new(self)
+    }
+
     /// create an `EcuInstance` that is connected to this System
     ///
     /// # Example
@@ -249,6 +320,155 @@ impl System {
             })
     }
 
+    /// create a new `Gateway` for the given `EcuInstance`
+    ///
+    /// A gateway ECU forwards frames, PDUs or signals between physical channels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let ecu_instance = system.create_ecu_instance("ecu_name", &package)?;
+    /// let gateway = system.create_gateway("Gateway", &package, &ecu_instance)?;
+    /// assert_eq!(gateway.ecu(), Some(ecu_instance));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the GATEWAY
+    pub fn create_gateway(
+        &self,
+        name: &str,
+        package: &ArPackage,
+        ecu: &EcuInstance,
+    ) -> Result<Gateway, AutosarAbstractionError> {
+        let gateway = Gateway::new(name, package, ecu)?;
+        self.create_fibex_element_ref_unchecked(gateway.element())?;
+
+        Ok(gateway)
+    }
+
+    /// get an iterator over all GATEWAYs in this SYSTEM
+    pub fn gateways(&self) -> impl Iterator<Item = Gateway> + Send + use<> {
+        self.0
+            .get_sub_element(ElementName::FibexElements)
+            .into_iter()
+            .flat_map(|fibexelems| fibexelems.sub_elements())
+            .filter_map(|ferc| {
+                ferc.get_sub_element(ElementName::FibexElementRef)
+                    .and_then(|fer| fer.get_reference_target().ok())
+                    .and_then(|elem| Gateway::try_from(elem).ok())
+            })
+    }
+
+    /// create copies of a configured `EcuInstance`
+    ///
+    /// Each replica contains copies of the controllers, connectors and ports of the template.
+    /// The replicas are connected to the same physical channels as the template, and their ports
+    /// are connected to the same frame, PDU and signal triggerings.
+    ///
+    /// The names of the replicas are created from the `name_pattern` by replacing `{}` with the
+    /// number of the replica, starting at 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let template = system.create_ecu_instance("Sensor", &package)?;
+    /// template.create_can_communication_controller("CanCtrl")?;
+    /// let replicas = system.replicate_ecu_instance(&template, "Sensor_{}", 3, &package)?;
+    /// assert_eq!(replicas[0].name().unwrap(), "Sensor_1");
+    /// assert_eq!(system.ecu_instances().count(), 4);
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The name pattern does not contain `{}`
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the replicas
+    pub fn replicate_ecu_instance(
+        &self,
+        template: &EcuInstance,
+        name_pattern: &str,
+        count: usize,
+        package: &ArPackage,
+    ) -> Result<Vec<EcuInstance>, AutosarAbstractionError> {
+        if !name_pattern.contains("{}") {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The name pattern must contain {}".to_string(),
+            ));
+        }
+        let model = self.0.model()?;
+        let template_path = template.element().path()?;
+
+        // collect all identifiable elements inside the template that are referenced from outside of the template,
+        // e.g. connectors that are referenced by channels or ports that are referenced by triggerings
+        let template_prefix = format!("{template_path}/");
+        let mut external_refs = Vec::new();
+        for (_, elem) in template.element().elements_dfs().skip(1) {
+            if !elem.is_identifiable() {
+                continue;
+            }
+            let elem_path = elem.path()?;
+            let relative_path = elem_path.strip_prefix(&template_prefix).unwrap_or_default().to_string();
+            for ref_elem in model
+                .get_references_to(&elem_path)
+                .iter()
+                .filter_map(WeakElement::upgrade)
+            {
+                if matches!(
+                    ref_elem.element_name(),
+                    ElementName::CommunicationConnectorRef
+                        | ElementName::FramePortRef
+                        | ElementName::IPduPortRef
+                        | ElementName::ISignalPortRef
+                ) {
+                    external_refs.push((relative_path.clone(), ref_elem));
+                }
+            }
+        }
+
+        let mut replicas = Vec::with_capacity(count);
+        for index in 1..=count {
+            let name = name_pattern.replace("{}", &index.to_string());
+            let replica = template.duplicate(&name, package)?;
+            self.create_fibex_element_ref_unchecked(replica.element())?;
+
+            let replica_path = replica.element().path()?;
+            for (relative_path, ref_elem) in &external_refs {
+                let Some(target) = model.get_element_by_path(&format!("{replica_path}/{relative_path}")) else {
+                    continue;
+                };
+                let parent = ref_elem.parent()?.ok_or(AutosarDataError::ItemDeleted)?;
+                let new_ref = if ref_elem.element_name() == ElementName::CommunicationConnectorRef {
+                    // CommConnectors -> CommunicationConnectorRefConditional -> CommunicationConnectorRef
+                    let container = parent.parent()?.ok_or(AutosarDataError::ItemDeleted)?;
+                    container
+                        .create_sub_element(parent.element_name())?
+                        .create_sub_element(ref_elem.element_name())?
+                } else {
+                    parent.create_sub_element(ref_elem.element_name())?
+                };
+                new_ref.set_reference_target(&target)?;
+            }
+
+            replicas.push(replica);
+        }
+
+        Ok(replicas)
+    }
+
     /// create a new CAN-CLUSTER
     ///
     /// The cluster must have a channel to be valid, but this channel is not created automatically.
@@ -319,6 +539,37 @@ impl System {
         Ok(cluster)
     }
 
+    /// create a new COUPLING-ELEMENT (e.g. an ethernet switch) in an [`EthernetCluster`] and connect it to the SYSTEM
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let cluster = system.create_ethernet_cluster("ethernet_cluster", &package)?;
+    /// let switch = system.create_coupling_element("switch", &package, &cluster)?;
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the coupling element
+    pub fn create_coupling_element(
+        &self,
+        name: &str,
+        package: &ArPackage,
+        cluster: &EthernetCluster,
+    ) -> Result<CouplingElement, AutosarAbstractionError> {
+        let coupling_element = CouplingElement::new(name, package, cluster)?;
+        self.create_fibex_element_ref_unchecked(coupling_element.element())?;
+
+        Ok(coupling_element)
+    }
+
     /// create a new FLEXRAY-CLUSTER and connect it to the SYSTEM
     ///
     /// A `FlexrayClusterSettings` structure containing the timings and parameters for the Flexray cluster must be provided.
@@ -565,6 +816,161 @@ impl System {
             })
     }
 
+    /// check that the `ISignal`s and the `SystemSignal`s of the system correspond to each other
+    ///
+    /// The `SystemSignal`s of the system are those referenced by its `ISignal`s, by the `SystemSignalGroup`s of its
+    /// `ISignalGroup`s, and by its `SystemMapping`s. `SystemSignal`s of other systems in the same model are not checked.
+    ///
+    /// The following problems are reported:
+    /// - an `ISignal` of the system does not refer to a `SystemSignal`
+    /// - a `SystemSignal` of the system is not referenced by any `ISignal`
+    /// - an `ISignal` is part of an `ISignalGroup`, but its `SystemSignal` is not part of the corresponding `SystemSignalGroup`
+    ///
+    /// Missing counterparts can be created with [`System::create_missing_signal_counterparts`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # use autosar_data_abstraction::communication::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// # let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// let sig_package = model.get_or_create_package("/ISignals")?;
+    /// let sys_package = model.get_or_create_package("/SystemSignals")?;
+    /// let system_signal_group = sys_package.create_system_signal_group("group")?;
+    /// system.create_isignal_group("group", &sig_package, &system_signal_group)?;
+    /// let system_signal = sys_package.create_system_signal("signal1")?;
+    /// system_signal_group.add_signal(&system_signal)?;
+    /// let issues = system.check_signal_consistency();
+    /// assert_eq!(issues, vec![SignalConsistencyIssue::MissingISignal(system_signal)]);
+    /// # Ok(())}
+    /// ```
+    #[must_use]
+    pub fn check_signal_consistency(&self) -> Vec<SignalConsistencyIssue> {
+        let mut issues = Vec::new();
+
+        for isignal in self.isignals() {
+            let Some(system_signal) = isignal.system_signal() else {
+                issues.push(SignalConsistencyIssue::MissingSystemSignal(isignal));
+                continue;
+            };
+            if let Some(isignal_group) = isignal.signal_group()
+                && isignal_group.system_signal_group() != system_signal.signal_group()
+            {
+                issues.push(SignalConsistencyIssue::GroupMismatch(isignal, isignal_group));
+            }
+        }
+
+        for system_signal in self.system_signals() {
+            if system_signal.isignals().next().is_none() {
+                issues.push(SignalConsistencyIssue::MissingISignal(system_signal));
+            }
+        }
+
+        issues
+    }
+
+    /// create the missing counterparts of `ISignal`s and `SystemSignal`s
+    ///
+    /// For each `ISignal` of the system that does not refer to a `SystemSignal`, a `SystemSignal` with the same name is
+    /// created in `sys_package`. For each `SystemSignal` of the system that is not referenced by any `ISignal`,
+    /// an `ISignal` with the same name is created in `sig_package`.
+    /// See [`System::check_signal_consistency`] for the `SystemSignal`s that belong to the system.
+    ///
+    /// The length of a created `ISignal` can not be derived from the `SystemSignal`, so `bit_length` is called
+    /// for each `SystemSignal` to provide it.
+    ///
+    /// `sig_package` and `sys_package` may not be identical, because the signals are created using the same name.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] `sig_package` and `sys_package` are identical
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create elements
+    pub fn create_missing_signal_counterparts(
+        &self,
+        sig_package: &ArPackage,
+        sys_package: &ArPackage,
+        bit_length: impl Fn(&SystemSignal) -> u64,
+    ) -> Result<SignalCounterpartReport, AutosarAbstractionError> {
+        if sig_package == sys_package {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "sig_package and sys_package may not be identical".to_string(),
+            ));
+        }
+        let model = self.element().model()?;
+        let sig_package_path = sig_package.element().path()?;
+        let sys_package_path = sys_package.element().path()?;
+        let mut report = SignalCounterpartReport::default();
+
+        // collect the system signals before new ones are created, so that they are not checked
+        let orphan_system_signals: Vec<SystemSignal> = self
+            .system_signals()
+            .into_iter()
+            .filter(|system_signal| system_signal.isignals().next().is_none())
+            .collect();
+
+        for isignal in self.isignals() {
+            if isignal.system_signal().is_none() {
+                let name = isignal.name().unwrap_or("unnamed".to_string());
+                let system_signal =
+                    sys_package.create_system_signal(&make_unique_generated_name(&model, &sys_package_path, &name))?;
+                isignal.set_system_signal(&system_signal)?;
+                report.created_system_signals.push(system_signal);
+            }
+        }
+
+        for system_signal in orphan_system_signals {
+            let name = system_signal.name().unwrap_or("unnamed".to_string());
+            let isignal = self.create_isignal(
+                &make_unique_generated_name(&model, &sig_package_path, &name),
+                sig_package,
+                bit_length(&system_signal),
+                &system_signal,
+                None,
+            )?;
+            report.created_isignals.push(isignal);
+        }
+
+        Ok(report)
+    }
+
+    // collect the system signals that are reachable from this system: the signals of its isignals,
+    // the members of the system signal groups of its isignal groups, and the signals referenced by its mappings
+    fn system_signals(&self) -> Vec<SystemSignal> {
+        let mut system_signals: Vec<SystemSignal> =
+            self.isignals().filter_map(|isignal| isignal.system_signal()).collect();
+        system_signals.extend(
+            self.isignal_groups()
+                .filter_map(|isignal_group| isignal_group.system_signal_group())
+                .flat_map(|system_signal_group| system_signal_group.signals()),
+        );
+        if let Some(mappings) = self.element().get_sub_element(ElementName::Mappings) {
+            system_signals.extend(
+                mappings
+                    .elements_dfs()
+                    .filter(|(_, elem)| {
+                        matches!(
+                            elem.element_name(),
+                            ElementName::SystemSignalRef | ElementName::CallSignalRef | ElementName::ReturnSignalRef
+                        )
+                    })
+                    .filter_map(|(_, elem)| elem.get_reference_target().ok())
+                    .filter_map(|target| SystemSignal::try_from(target).ok()),
+            );
+        }
+
+        let mut unique_signals = Vec::new();
+        for system_signal in system_signals {
+            if !unique_signals.contains(&system_signal) {
+                unique_signals.push(system_signal);
+            }
+        }
+        unique_signals
+    }
+
     /// create a new signal group in the [`System`]
     ///
     /// `I-SIGNAL-GROUP` and `SYSTEM-SIGNAL-GROUP` are created using the same name; therefore they must be placed in
@@ -1165,6 +1571,128 @@ impl System {
             })
     }
 
+    /// find and remove communication elements that are no longer used
+    ///
+    /// Frames, PDUs and signals are unused if they are not referenced by anything except the SYSTEM, i.e. they
+    /// are neither triggered nor mapped. Network endpoints are unused if they are not referenced at all.
+    /// System signals are only considered if they are referenced by a signal of this system, and they are unused
+    /// if they are not referenced at all after the unused signals were removed.
+    ///
+    /// Since removing an element can cause other elements to become unused (e.g. the PDUs of a removed frame),
+    /// the search is repeated until no more unused elements are found.
+    /// If `dry_run` is true, then nothing is removed: the removal is simulated on a copy of the model, so that the
+    /// result is the same as the result of a real cleanup.
+    ///
+    /// Returns the paths of all unused elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let system = package.create_system("System", SystemCategory::SystemExtract)?;
+    /// system.create_can_frame("Frame", &package, 8)?;
+    /// let unused = system.cleanup_unused(true)?;
+    /// assert_eq!(unused, vec!["/pkg1/Frame"]);
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while removing an element
+    pub fn cleanup_unused(&self, dry_run: bool) -> Result<Vec<String>, AutosarAbstractionError> {
+        if dry_run {
+            let model = self.element().model()?;
+            let model_copy = AutosarModel::new();
+            for file in model.files() {
+                model_copy.load_buffer(file.serialize()?.as_bytes(), file.filename(), true)?;
+            }
+            let system_copy = model_copy
+                .get_element_by_path(&self.element().path()?)
+                .ok_or(AutosarDataError::ItemDeleted)?;
+            return System::try_from(system_copy)?.cleanup_unused(false);
+        }
+
+        let mut unused_paths = Vec::new();
+        // only the system signals of this system are considered
+        let mut system_signal_candidates: Vec<SystemSignal> = Vec::new();
+
+        loop {
+            let mut found_unused = false;
+
+            let frames: Vec<Frame> = self
+                .frames()
+                .filter(|frame| !is_used_system_element(frame.element()))
+                .collect();
+            for frame in frames {
+                unused_paths.push(frame.element().path()?);
+                found_unused = true;
+                frame.remove(false)?;
+            }
+
+            let pdus: Vec<Pdu> = self
+                .pdus()
+                .filter(|pdu| !is_used_system_element(pdu.element()))
+                .collect();
+            for pdu in pdus {
+                unused_paths.push(pdu.element().path()?);
+                found_unused = true;
+                pdu.remove(false)?;
+            }
+
+            let signals: Vec<ISignal> = self
+                .isignals()
+                .filter(|signal| !is_used_system_element(signal.element()))
+                .collect();
+            for signal in signals {
+                unused_paths.push(signal.element().path()?);
+                found_unused = true;
+                if let Some(system_signal) = signal.system_signal()
+                    && !system_signal_candidates.contains(&system_signal)
+                {
+                    system_signal_candidates.push(system_signal);
+                }
+                signal.remove(false)?;
+            }
+
+            let system_signals: Vec<SystemSignal> = system_signal_candidates
+                .iter()
+                .filter(|system_signal| system_signal.element().path().is_ok() && !is_used(system_signal.element()))
+                .cloned()
+                .collect();
+            for system_signal in system_signals {
+                unused_paths.push(system_signal.element().path()?);
+                found_unused = true;
+                system_signal.remove(false)?;
+            }
+
+            let network_endpoints: Vec<NetworkEndpoint> = self
+                .clusters()
+                .filter_map(|cluster| match cluster {
+                    Cluster::Ethernet(eth_cluster) => Some(eth_cluster),
+                    _ => None,
+                })
+                .flat_map(|eth_cluster| eth_cluster.physical_channels())
+                .flat_map(|channel| channel.network_endpoints())
+                .filter(|network_endpoint| !is_used(network_endpoint.element()))
+                .collect();
+            for network_endpoint in network_endpoints {
+                unused_paths.push(network_endpoint.element().path()?);
+                found_unused = true;
+                network_endpoint.remove(false)?;
+            }
+
+            if !found_unused {
+                break;
+            }
+        }
+
+        Ok(unused_paths)
+    }
+
     /// connect an element to the SYSTEM by creating a FIBEX-ELEMENT-REF
     ///
     /// If there is already a FIBEX-ELEMENT-REF, this function does nothing, successfully.
@@ -1338,10 +1866,13 @@ pub(crate) fn is_used_system_element(element: &Element) -> bool {
 #[cfg(test)]
 mod test {
     use crate::{
-        AbstractionElement, AutosarModelAbstraction, IdentifiableAbstractionElement, System,
+        AbstractionElement, AutosarModelAbstraction, ByteOrder, IdentifiableAbstractionElement, System,
         communication::{
+            AbstractCommunicationController, AbstractFrame, AbstractFrameTriggering, AbstractPdu,
+            AbstractPhysicalChannel, CanAddressingMode, CanFrameType, CommunicationController, CommunicationDirection,
             ContainerIPduHeaderType, DiagPduType, FlexrayClusterSettings, GeneralPurposeIPduCategory,
-            GeneralPurposePduCategory, RxAcceptContainedIPdu, SecureCommunicationProps,
+            GeneralPurposePduCategory, RxAcceptContainedIPdu, SecureCommunicationProps, SignalConsistencyIssue,
+            TransferProperty,
         },
         software_component::CompositionSwComponentType,
         system::SystemCategory,
@@ -1557,6 +2088,76 @@ mod test {
         assert_eq!(system.isignals().count(), 2);
     }
 
+    #[test]
+    fn signal_consistency() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package_1 = model.get_or_create_package("/SYSTEM").unwrap();
+        let system = package_1
+            .create_system("System", SystemCategory::SystemExtract)
+            .unwrap();
+        let sig_package = model.get_or_create_package("/Signals").unwrap();
+        let sys_package = model.get_or_create_package("/SystemSignals").unwrap();
+
+        // a consistent pair
+        let syssig1 = sys_package.create_system_signal("Sig1").unwrap();
+        system.create_isignal("Sig1", &sig_package, 8, &syssig1, None).unwrap();
+        // an isignal whose system signal was lost
+        let syssig2 = sys_package.create_system_signal("Sig2").unwrap();
+        let isig2 = system.create_isignal("Sig2", &sig_package, 8, &syssig2, None).unwrap();
+        isig2
+            .element()
+            .remove_sub_element_kind(ElementName::SystemSignalRef)
+            .unwrap();
+        // a system signal of the system without isignal
+        let sys_group = sys_package.create_system_signal_group("Group").unwrap();
+        let isig_group = system.create_isignal_group("Group", &sig_package, &sys_group).unwrap();
+        let syssig3 = sys_package.create_system_signal("Sig3").unwrap();
+        sys_group.add_signal(&syssig3).unwrap();
+        // a system signal that does not belong to the system is not checked
+        let other_syssig = sys_package.create_system_signal("Other").unwrap();
+        // an isignal in a group, whose system signal is not in the corresponding system signal group
+        let syssig4 = sys_package.create_system_signal("Sig4").unwrap();
+        sys_group.add_signal(&syssig4).unwrap();
+        let isig4 = system.create_isignal("Sig4", &sig_package, 8, &syssig4, None).unwrap();
+        isig_group.add_signal(&isig4).unwrap();
+        let syssig5 = sys_package.create_system_signal("Sig5").unwrap();
+        isig4.set_system_signal(&syssig5).unwrap();
+
+        let issues = system.check_signal_consistency();
+        assert_eq!(issues.len(), 4);
+        assert!(issues.contains(&SignalConsistencyIssue::MissingSystemSignal(isig2.clone())));
+        assert!(!issues.contains(&SignalConsistencyIssue::MissingISignal(syssig2.clone())));
+        assert!(!issues.contains(&SignalConsistencyIssue::MissingISignal(other_syssig.clone())));
+        assert!(issues.contains(&SignalConsistencyIssue::MissingISignal(syssig3.clone())));
+        assert!(issues.contains(&SignalConsistencyIssue::MissingISignal(syssig4.clone())));
+        assert!(issues.contains(&SignalConsistencyIssue::GroupMismatch(isig4, isig_group)));
+
+        // the packages must be different
+        assert!(
+            system
+                .create_missing_signal_counterparts(&sig_package, &sig_package, |_| 8)
+                .is_err()
+        );
+        let report = system
+            .create_missing_signal_counterparts(&sig_package, &sys_package, |system_signal| {
+                if system_signal == &syssig3 { 16 } else { 8 }
+            })
+            .unwrap();
+        assert_eq!(report.created_system_signals.len(), 1);
+        assert_eq!(isig2.system_signal(), Some(report.created_system_signals[0].clone()));
+        // the name Sig2 is already used by the orphaned system signal
+        assert_eq!(report.created_system_signals[0].name().unwrap(), "Sig2_1");
+        assert_eq!(report.created_isignals.len(), 2);
+        assert_eq!(syssig3.isignals().count(), 1);
+        assert_eq!(syssig3.isignals().next().unwrap().length(), Some(16));
+        assert_eq!(other_syssig.isignals().count(), 0);
+        // the name Sig4 is already used by an isignal in the package
+        assert_eq!(syssig4.isignals().next().unwrap().name().unwrap(), "Sig4_1");
+
+        // only the group mismatch remains
+        assert_eq!(system.check_signal_consistency().len(), 1);
+    }
+
     #[test]
     fn isignal_groups_iterator() {
         let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
@@ -1675,4 +2276,130 @@ mod test {
 
         // println!("{}", _file.serialize().unwrap());
     }
+
+    #[test]
+    fn cleanup_unused() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+
+        // used: the frame is triggered, and the pdu and signal are mapped into it
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let syssignal = package.create_system_signal("SysSignal").unwrap();
+        let signal = system.create_isignal("Signal", &package, 8, &syssignal, None).unwrap();
+        pdu.map_signal(
+            &signal,
+            0,
+            ByteOrder::MostSignificantByteLast,
+            None,
+            TransferProperty::Triggered,
+        )
+        .unwrap();
+        assert!(system.cleanup_unused(true).unwrap().is_empty());
+
+        // unused: the frame is not triggered; the pdu and signal only become unused once the frame is removed
+        let unused_frame = system.create_can_frame("UnusedFrame", &package, 8).unwrap();
+        let unused_pdu = system.create_isignal_ipdu("UnusedPdu", &package, 8).unwrap();
+        unused_frame
+            .map_pdu(&unused_pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let unused_syssignal = package.create_system_signal("UnusedSysSignal").unwrap();
+        let unused_signal = system
+            .create_isignal("UnusedSignal", &package, 8, &unused_syssignal, None)
+            .unwrap();
+        unused_pdu
+            .map_signal(
+                &unused_signal,
+                0,
+                ByteOrder::MostSignificantByteLast,
+                None,
+                TransferProperty::Triggered,
+            )
+            .unwrap();
+
+        // system signals of other systems, or without any signal, are not affected
+        let other_syssignal = package.create_system_signal("OtherSysSignal").unwrap();
+
+        // the dry run reports the same elements as the real cleanup
+        let mut candidates = system.cleanup_unused(true).unwrap();
+        candidates.sort();
+        assert!(model.get_element_by_path("/package/UnusedFrame").is_some());
+        assert!(model.get_element_by_path("/package/UnusedSysSignal").is_some());
+
+        let mut removed = system.cleanup_unused(false).unwrap();
+        removed.sort();
+        assert_eq!(candidates, removed);
+        assert_eq!(
+            removed,
+            vec![
+                "/package/UnusedFrame".to_string(),
+                "/package/UnusedPdu".to_string(),
+                "/package/UnusedSignal".to_string(),
+                "/package/UnusedSysSignal".to_string(),
+            ]
+        );
+        assert!(model.get_element_by_path("/package/UnusedSysSignal").is_none());
+        assert_eq!(other_syssignal.element().path().unwrap(), "/package/OtherSysSignal");
+        assert!(model.get_element_by_path("/package/Frame").is_some());
+        assert!(model.get_element_by_path("/package/SysSignal").is_some());
+    }
+
+    #[test]
+    fn replicate_ecu_instance() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+
+        let template = system.create_ecu_instance("Sensor", &package).unwrap();
+        let controller = template.create_can_communication_controller("CanCtrl").unwrap();
+        controller.connect_physical_channel("connection", &channel).unwrap();
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        frame_triggering
+            .connect_to_ecu(&template, CommunicationDirection::Out)
+            .unwrap();
+
+        // the name pattern must contain {}
+        let result = system.replicate_ecu_instance(&template, "Sensor", 2, &package);
+        assert!(result.is_err());
+
+        let replicas = system
+            .replicate_ecu_instance(&template, "Sensor_{}", 2, &package)
+            .unwrap();
+        assert_eq!(replicas.len(), 2);
+        assert_eq!(replicas[0].name().unwrap(), "Sensor_1");
+        assert_eq!(replicas[1].name().unwrap(), "Sensor_2");
+        assert_eq!(system.ecu_instances().count(), 3);
+
+        // each replica is connected to the channel and to the frame triggering
+        assert_eq!(channel.connectors().count(), 3);
+        assert_eq!(frame_triggering.frame_ports().count(), 3);
+        assert_eq!(pdu.pdu_triggerings()[0].pdu_ports().count(), 3);
+        for replica in &replicas {
+            assert!(channel.ecu_connector(replica).is_some());
+            let CommunicationController::Can(replica_controller) = replica.communication_controllers().next().unwrap()
+            else {
+                panic!("expected a CAN controller");
+            };
+            assert_eq!(replica_controller.ecu_instance().unwrap(), *replica);
+            assert_eq!(replica_controller.connected_channels().next().unwrap(), channel);
+        }
+    }
 }
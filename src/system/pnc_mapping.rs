@@ -0,0 +1,214 @@
+use crate::{
+    AbstractionElement, AutosarAbstractionError, System, abstraction_element,
+    communication::{AbstractPhysicalChannel, ISignalIPduGroup, PhysicalChannel},
+    software_component::{AbstractSwComponentType, PortGroup},
+};
+use autosar_data::{Element, ElementName};
+
+//##################################################################
+
+/// A `PncMapping` describes the mapping of a partial network cluster (PNC) to the parts of the system it controls
+///
+/// The functions that belong to the PNC are identified by the port groups of the root composition (also known as
+/// virtual function clusters), while the `ISignalIPduGroup`s are enabled and disabled together with the PNC.
+///
+/// The PNC identifier selects the bit of the PNC in the PNC vector of the NM PDUs. The position of the vector
+/// inside the NM PDUs is configured for the whole system with [`System::set_pnc_vector_offset`] and
+/// [`System::set_pnc_vector_length`].
+///
+/// Use [`System::create_pnc_mapping`] to create a new `PncMapping`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PncMapping(Element);
+abstraction_element!(PncMapping, PncMapping);
+
+impl PncMapping {
+    pub(crate) fn new(parent: &Element, pnc_identifier: u32) -> Result<Self, AutosarAbstractionError> {
+        let pnc_mapping = Self(parent.create_sub_element(ElementName::PncMapping)?);
+        pnc_mapping.set_pnc_identifier(pnc_identifier)?;
+
+        Ok(pnc_mapping)
+    }
+
+    /// set the identifier of the PNC
+    pub fn set_pnc_identifier(&self, pnc_identifier: u32) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::PncIdentifier)?
+            .set_character_data(u64::from(pnc_identifier))?;
+        Ok(())
+    }
+
+    /// get the identifier of the PNC
+    #[must_use]
+    pub fn pnc_identifier(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::PncIdentifier)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// set whether the PNC wakes up the ECU when it is requested
+    pub fn set_pnc_wakeup_enable(&self, wakeup_enable: Option<bool>) -> Result<(), AutosarAbstractionError> {
+        if let Some(wakeup_enable) = wakeup_enable {
+            self.element()
+                .get_or_create_sub_element(ElementName::PncWakeupEnable)?
+                .set_character_data(wakeup_enable)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::PncWakeupEnable);
+        }
+        Ok(())
+    }
+
+    /// get whether the PNC wakes up the ECU when it is requested
+    #[must_use]
+    pub fn pnc_wakeup_enable(&self) -> Option<bool> {
+        self.element()
+            .get_sub_element(ElementName::PncWakeupEnable)?
+            .character_data()?
+            .parse_bool()
+    }
+
+    /// add a port group of the root composition to the PNC
+    ///
+    /// The communication of the ports in the port group is enabled or disabled together with the PNC.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the system has no root composition, or the port group
+    ///   does not belong to the root composition
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to add the port group
+    pub fn add_port_group(&self, port_group: &PortGroup) -> Result<(), AutosarAbstractionError> {
+        let system = System::try_from(self.element().named_parent()?.unwrap())?;
+        let Some(root_composition) = system.root_sw_composition() else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The system does not have a root composition".to_string(),
+            ));
+        };
+        let is_root_port_group = root_composition
+            .composition()
+            .is_some_and(|composition| composition.port_groups().any(|group| &group == port_group));
+        if !is_root_port_group {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The port group does not belong to the root composition".to_string(),
+            ));
+        }
+
+        let vfc_iref = self
+            .element()
+            .get_or_create_sub_element(ElementName::VfcIrefs)?
+            .create_sub_element(ElementName::VfcIref)?;
+        vfc_iref
+            .create_sub_element(ElementName::ContextRef)?
+            .set_reference_target(root_composition.element())?;
+        vfc_iref
+            .create_sub_element(ElementName::TargetRef)?
+            .set_reference_target(port_group.element())?;
+        Ok(())
+    }
+
+    /// iterate over all port groups of the PNC
+    pub fn port_groups(&self) -> impl Iterator<Item = PortGroup> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::VfcIrefs)
+            .into_iter()
+            .flat_map(|vfc_irefs| vfc_irefs.sub_elements())
+            .filter_map(|vfc_iref| {
+                let target_ref = vfc_iref.get_sub_element(ElementName::TargetRef)?;
+                PortGroup::try_from(target_ref.get_reference_target().ok()?).ok()
+            })
+    }
+
+    /// add an `ISignalIPduGroup` to the PNC
+    ///
+    /// The pdus in the group are enabled or disabled together with the PNC.
+    pub fn add_pnc_group(&self, ipdu_group: &ISignalIPduGroup) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::PncGroupRefs)?
+            .create_sub_element(ElementName::PncGroupRef)?
+            .set_reference_target(ipdu_group.element())?;
+        Ok(())
+    }
+
+    /// iterate over all `ISignalIPduGroup`s of the PNC
+    pub fn pnc_groups(&self) -> impl Iterator<Item = ISignalIPduGroup> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::PncGroupRefs)
+            .into_iter()
+            .flat_map(|pnc_group_refs| pnc_group_refs.sub_elements())
+            .filter_map(|pnc_group_ref| ISignalIPduGroup::try_from(pnc_group_ref.get_reference_target().ok()?).ok())
+    }
+
+    /// assign the PNC to a physical channel
+    ///
+    /// The PNC is only handled on the channels that it has been assigned to.
+    pub fn add_physical_channel<T: AbstractPhysicalChannel>(&self, channel: &T) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::PhysicalChannelRefs)?
+            .create_sub_element(ElementName::PhysicalChannelRef)?
+            .set_reference_target(channel.element())?;
+        Ok(())
+    }
+
+    /// iterate over all physical channels that the PNC is assigned to
+    pub fn physical_channels(&self) -> impl Iterator<Item = PhysicalChannel> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::PhysicalChannelRefs)
+            .into_iter()
+            .flat_map(|channel_refs| channel_refs.sub_elements())
+            .filter_map(|channel_ref| PhysicalChannel::try_from(channel_ref.get_reference_target().ok()?).ok())
+    }
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutosarModelAbstraction, SystemCategory, communication::CommunicationDirection};
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn pnc_mapping() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/package").unwrap();
+        let system = package.create_system("System", SystemCategory::EcuExtract).unwrap();
+
+        let composition = package.create_composition_sw_component_type("Composition").unwrap();
+        let port_group = composition.create_port_group("PortGroup").unwrap();
+        let other_composition = package.create_composition_sw_component_type("Other").unwrap();
+        let other_port_group = other_composition.create_port_group("OtherPortGroup").unwrap();
+
+        let pnc_mapping = system.create_pnc_mapping(7).unwrap();
+        assert_eq!(pnc_mapping.pnc_identifier(), Some(7));
+        assert_eq!(system.pnc_mappings().count(), 1);
+
+        // no root composition yet
+        assert!(pnc_mapping.add_port_group(&port_group).is_err());
+        system.set_root_sw_composition("Root", &composition).unwrap();
+        pnc_mapping.add_port_group(&port_group).unwrap();
+        // only port groups of the root composition can be added
+        assert!(pnc_mapping.add_port_group(&other_port_group).is_err());
+        assert_eq!(pnc_mapping.port_groups().next(), Some(port_group));
+
+        let ipdu_group = system
+            .create_isignal_ipdu_group("IpduGroup", &package, CommunicationDirection::In)
+            .unwrap();
+        pnc_mapping.add_pnc_group(&ipdu_group).unwrap();
+        assert_eq!(pnc_mapping.pnc_groups().next(), Some(ipdu_group));
+
+        pnc_mapping.set_pnc_wakeup_enable(Some(true)).unwrap();
+        assert_eq!(pnc_mapping.pnc_wakeup_enable(), Some(true));
+        pnc_mapping.set_pnc_wakeup_enable(None).unwrap();
+        assert_eq!(pnc_mapping.pnc_wakeup_enable(), None);
+
+        let cluster = system.create_can_cluster("CanCluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("CanChannel").unwrap();
+        pnc_mapping.add_physical_channel(&channel).unwrap();
+        assert_eq!(
+            pnc_mapping.physical_channels().next(),
+            Some(PhysicalChannel::Can(channel))
+        );
+
+        pnc_mapping.set_pnc_identifier(8).unwrap();
+        assert_eq!(pnc_mapping.pnc_identifier(), Some(8));
+    }
+}
@@ -0,0 +1,886 @@
+use crate::software_component::{
+    InstanceRef, RootSwCompositionPrototype, RunnableEntity, SwComponentPrototype, VariableAccess,
+};
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, Element, IdentifiableAbstractionElement, System,
+    SystemCategory, abstraction_element,
+};
+use autosar_data::{ElementName, EnumItem};
+
+//##################################################################
+
+/// A `RapidPrototypingScenario` describes how the software of a host system is bypassed for rapid prototyping (RPT)
+///
+/// The scenario refers to the host system and to an RPT system, and it contains the `RptContainer`s that
+/// configure the bypass points and the `RptProfile`s that describe the service functions of the RPT tool.
+///
+/// Use [`ArPackage::create_rapid_prototyping_scenario`] to create a new `RapidPrototypingScenario`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RapidPrototypingScenario(Element);
+abstraction_element!(RapidPrototypingScenario, RapidPrototypingScenario);
+impl IdentifiableAbstractionElement for RapidPrototypingScenario {}
+
+impl RapidPrototypingScenario {
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let pkg_elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let scenario = pkg_elements.create_named_sub_element(ElementName::RapidPrototypingScenario, name)?;
+
+        Ok(Self(scenario))
+    }
+
+    /// set or remove the host system, whose software is bypassed
+    pub fn set_host_system(&self, system: Option<&System>) -> Result<(), AutosarAbstractionError> {
+        set_optional_reference(self.element(), ElementName::HostSystemRef, system.map(System::element))
+    }
+
+    /// get the host system
+    #[must_use]
+    pub fn host_system(&self) -> Option<System> {
+        get_reference(self.element(), ElementName::HostSystemRef)
+    }
+
+    /// set or remove the RPT system, which describes the system with the rapid prototyping tool
+    ///
+    /// The category of the system must be [`SystemCategory::RptSystem`].
+    pub fn set_rpt_system(&self, system: Option<&System>) -> Result<(), AutosarAbstractionError> {
+        if let Some(system) = system
+            && system.category() != Some(SystemCategory::RptSystem)
+        {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "System {} does not have the category RPT_SYSTEM",
+                system.name().as_deref().unwrap_or("(invalid)")
+            )));
+        }
+        set_optional_reference(self.element(), ElementName::RptSystemRef, system.map(System::element))
+    }
+
+    /// get the RPT system
+    #[must_use]
+    pub fn rpt_system(&self) -> Option<System> {
+        get_reference(self.element(), ElementName::RptSystemRef)
+    }
+
+    /// create a new `RptContainer` in the scenario
+    pub fn create_rpt_container(&self, name: &str) -> Result<RptContainer, AutosarAbstractionError> {
+        let containers = self.element().get_or_create_sub_element(ElementName::RptContainers)?;
+        RptContainer::new(name, &containers)
+    }
+
+    /// iterate over all `RptContainer`s of the scenario
+    pub fn rpt_containers(&self) -> impl Iterator<Item = RptContainer> + Send + use<> {
+        rpt_containers(self.element())
+    }
+
+    /// create a new `RptProfile` in the scenario
+    pub fn create_rpt_profile(&self, name: &str) -> Result<RptProfile, AutosarAbstractionError> {
+        let profiles = self.element().get_or_create_sub_element(ElementName::RptProfiles)?;
+        RptProfile::new(name, &profiles)
+    }
+
+    /// iterate over all `RptProfile`s of the scenario
+    pub fn rpt_profiles(&self) -> impl Iterator<Item = RptProfile> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::RptProfiles)
+            .into_iter()
+            .flat_map(|profiles| profiles.sub_elements())
+            .filter_map(|elem| RptProfile::try_from(elem).ok())
+    }
+}
+
+fn rpt_containers(element: &Element) -> impl Iterator<Item = RptContainer> + Send + use<> {
+    element
+        .get_sub_element(ElementName::RptContainers)
+        .into_iter()
+        .flat_map(|containers| containers.sub_elements())
+        .filter_map(|elem| RptContainer::try_from(elem).ok())
+}
+
+fn set_optional_reference(
+    element: &Element,
+    ref_name: ElementName,
+    target: Option<&Element>,
+) -> Result<(), AutosarAbstractionError> {
+    if let Some(target) = target {
+        element
+            .get_or_create_sub_element(ref_name)?
+            .set_reference_target(target)?;
+    } else {
+        let _ = element.remove_sub_element_kind(ref_name);
+    }
+    Ok(())
+}
+
+fn get_reference<T: TryFrom<Element>>(element: &Element, ref_name: ElementName) -> Option<T> {
+    let target = element.get_sub_element(ref_name)?.get_reference_target().ok()?;
+    T::try_from(target).ok()
+}
+
+// write the context and target references of a BY-PASS-POINT-IREF
+fn write_by_pass_point_iref(
+    iref: &Element,
+    instance: &InstanceRef,
+    target: &ByPassPointTarget,
+) -> Result<(), AutosarAbstractionError> {
+    if let Some(root_composition_prototype) = instance.root_composition_prototype() {
+        iref.create_sub_element(ElementName::ContextElementRef)?
+            .set_reference_target(root_composition_prototype.element())?;
+    }
+    for component in instance.components() {
+        iref.create_sub_element(ElementName::ContextElementRef)?
+            .set_reference_target(component.element())?;
+    }
+    iref.create_sub_element(ElementName::TargetRef)?
+        .set_reference_target(target.element())?;
+    Ok(())
+}
+
+// rebuild the instance reference from the context elements of a BY-PASS-POINT-IREF
+fn read_by_pass_point_context(context: &[Element]) -> Option<InstanceRef> {
+    let (first, _) = context.split_first()?;
+    let (mut instance, components) =
+        if let Ok(root_composition_prototype) = RootSwCompositionPrototype::try_from(first.clone()) {
+            (
+                InstanceRef::from_root_composition_prototype(&root_composition_prototype).ok()?,
+                &context[1..],
+            )
+        } else {
+            let first_component = SwComponentPrototype::try_from(first.clone()).ok()?;
+            (InstanceRef::new(&first_component.parent_composition().ok()?), context)
+        };
+    for elem in components {
+        let component = SwComponentPrototype::try_from(elem.clone()).ok()?;
+        instance = instance.component(&component).ok()?;
+    }
+    Some(instance)
+}
+
+//##################################################################
+
+/// The target of a bypass point in an [`RptContainer`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ByPassPointTarget {
+    /// the runnable entity is bypassed
+    RunnableEntity(RunnableEntity),
+    /// the variable access of a runnable entity is bypassed
+    VariableAccess(VariableAccess),
+}
+
+impl ByPassPointTarget {
+    /// get the runnable entity that is bypassed or that contains the bypassed variable access
+    #[must_use]
+    pub fn runnable_entity(&self) -> Option<RunnableEntity> {
+        match self {
+            ByPassPointTarget::RunnableEntity(runnable) => Some(runnable.clone()),
+            ByPassPointTarget::VariableAccess(variable_access) => variable_access.runnable_entity(),
+        }
+    }
+}
+
+impl AbstractionElement for ByPassPointTarget {
+    fn element(&self) -> &Element {
+        match self {
+            ByPassPointTarget::RunnableEntity(runnable) => runnable.element(),
+            ByPassPointTarget::VariableAccess(variable_access) => variable_access.element(),
+        }
+    }
+}
+
+impl TryFrom<Element> for ByPassPointTarget {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        match element.element_name() {
+            ElementName::RunnableEntity => Ok(ByPassPointTarget::RunnableEntity(RunnableEntity::try_from(element)?)),
+            ElementName::VariableAccess => Ok(ByPassPointTarget::VariableAccess(VariableAccess::try_from(element)?)),
+            _ => Err(AutosarAbstractionError::ConversionError {
+                element,
+                dest: "ByPassPointTarget".to_string(),
+            }),
+        }
+    }
+}
+
+impl From<RunnableEntity> for ByPassPointTarget {
+    fn from(runnable: RunnableEntity) -> Self {
+        ByPassPointTarget::RunnableEntity(runnable)
+    }
+}
+
+impl From<VariableAccess> for ByPassPointTarget {
+    fn from(variable_access: VariableAccess) -> Self {
+        ByPassPointTarget::VariableAccess(variable_access)
+    }
+}
+
+//##################################################################
+
+/// An `RptContainer` configures the rapid prototyping properties of one or more bypass points
+///
+/// The bypass points are runnables or data access points of component instances. Containers can be nested
+/// in order to structure the configuration, e.g. one container per component with one inner container per runnable.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RptContainer(Element);
+abstraction_element!(RptContainer, RptContainer);
+impl IdentifiableAbstractionElement for RptContainer {}
+
+impl RptContainer {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let container = parent.create_named_sub_element(ElementName::RptContainer, name)?;
+
+        Ok(Self(container))
+    }
+
+    /// create a nested `RptContainer`
+    pub fn create_rpt_container(&self, name: &str) -> Result<RptContainer, AutosarAbstractionError> {
+        let containers = self.element().get_or_create_sub_element(ElementName::RptContainers)?;
+        RptContainer::new(name, &containers)
+    }
+
+    /// iterate over all nested `RptContainer`s
+    pub fn rpt_containers(&self) -> impl Iterator<Item = RptContainer> + Send + use<> {
+        rpt_containers(self.element())
+    }
+
+    /// add a bypass point to the container
+    ///
+    /// The bypass point is the `target` runnable entity or variable access inside the component instance
+    /// identified by `instance`. The instance reference must end with a component prototype whose component type
+    /// contains the target.
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] the instance reference does not end with a component prototype,
+    ///   or the target does not belong to its component type
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model
+    pub fn add_by_pass_point<T: Into<ByPassPointTarget> + Clone>(
+        &self,
+        instance: &InstanceRef,
+        target: &T,
+    ) -> Result<(), AutosarAbstractionError> {
+        let target = target.clone().into();
+        let Some(component) = instance.target_component() else {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The instance reference of a bypass point must end with a component prototype".to_string(),
+            ));
+        };
+        if instance.port_prototype().is_some() {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The instance reference of a bypass point can not end with a port".to_string(),
+            ));
+        }
+        let target_component_type = target
+            .runnable_entity()
+            .and_then(|runnable| runnable.swc_internal_behavior())
+            .and_then(|behavior| behavior.sw_component_type());
+        if target_component_type.is_none() || target_component_type != component.component_type() {
+            return Err(AutosarAbstractionError::InvalidParameter(format!(
+                "The bypass point target {} does not belong to the component type of {}",
+                target.element().item_name().unwrap_or_default(),
+                component.name().unwrap_or_default()
+            )));
+        }
+
+        let irefs = self
+            .element()
+            .get_or_create_sub_element(ElementName::ByPassPointIrefs)?;
+        let iref = irefs.create_sub_element(ElementName::ByPassPointIref)?;
+        let result = write_by_pass_point_iref(&iref, instance, &target);
+        if let Err(err) = result {
+            irefs.remove_sub_element(iref)?;
+            if irefs.sub_elements().next().is_none() {
+                self.element().remove_sub_element(irefs)?;
+            }
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// get all bypass points of the container as pairs of (instance reference, target)
+    ///
+    /// Bypass points whose instance reference does not describe a valid path through the composition hierarchy are skipped.
+    #[must_use]
+    pub fn by_pass_points(&self) -> Vec<(InstanceRef, ByPassPointTarget)> {
+        self.element()
+            .get_sub_element(ElementName::ByPassPointIrefs)
+            .into_iter()
+            .flat_map(|irefs| irefs.sub_elements())
+            .filter_map(|iref| {
+                let context: Vec<Element> = iref
+                    .sub_elements()
+                    .filter(|elem| elem.element_name() == ElementName::ContextElementRef)
+                    .filter_map(|elem| elem.get_reference_target().ok())
+                    .collect();
+                let target = iref
+                    .get_sub_element(ElementName::TargetRef)?
+                    .get_reference_target()
+                    .ok()
+                    .and_then(|elem| ByPassPointTarget::try_from(elem).ok())?;
+                Some((read_by_pass_point_context(&context)?, target))
+            })
+            .collect()
+    }
+
+    /// select an `RptProfile` for the bypass points of the container
+    pub fn add_rpt_profile(&self, profile: &RptProfile) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::ExplicitRptProfileSelectionRefs)?
+            .create_sub_element(ElementName::ExplicitRptProfileSelectionRef)?
+            .set_reference_target(profile.element())?;
+        Ok(())
+    }
+
+    /// iterate over all `RptProfile`s that are selected for the bypass points of the container
+    pub fn rpt_profiles(&self) -> impl Iterator<Item = RptProfile> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::ExplicitRptProfileSelectionRefs)
+            .into_iter()
+            .flat_map(|refs| refs.sub_elements())
+            .filter_map(|profile_ref| profile_ref.get_reference_target().ok())
+            .filter_map(|elem| RptProfile::try_from(elem).ok())
+    }
+
+    /// set or remove the hook, which identifies the bypass point in the code and in the measurement description
+    pub fn set_rpt_hook(&self, hook: Option<&RptHook>) -> Result<(), AutosarAbstractionError> {
+        let _ = self.element().remove_sub_element_kind(ElementName::RptHook);
+        let Some(hook) = hook else {
+            return Ok(());
+        };
+
+        let hook_elem = self.element().create_sub_element(ElementName::RptHook)?;
+        if let Some(code_label) = &hook.code_label {
+            hook_elem
+                .create_sub_element(ElementName::CodeLabel)?
+                .set_character_data(code_label.as_str())?;
+        }
+        if let Some(mcd_identifier) = &hook.mcd_identifier {
+            hook_elem
+                .create_sub_element(ElementName::McdIdentifier)?
+                .set_character_data(mcd_identifier.as_str())?;
+        }
+        Ok(())
+    }
+
+    /// get the hook of the container
+    #[must_use]
+    pub fn rpt_hook(&self) -> Option<RptHook> {
+        let hook_elem = self.element().get_sub_element(ElementName::RptHook)?;
+        let code_label = hook_elem
+            .get_sub_element(ElementName::CodeLabel)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.string_value());
+        let mcd_identifier = hook_elem
+            .get_sub_element(ElementName::McdIdentifier)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.string_value());
+        Some(RptHook {
+            code_label,
+            mcd_identifier,
+        })
+    }
+
+    /// set or remove the implementation policy, which controls how the bypass points are prepared in the ECU code
+    pub fn set_rpt_impl_policy(&self, policy: Option<&RptImplPolicy>) -> Result<(), AutosarAbstractionError> {
+        let _ = self.element().remove_sub_element_kind(ElementName::RptImplPolicy);
+        let Some(policy) = policy else {
+            return Ok(());
+        };
+
+        let policy_elem = self.element().create_sub_element(ElementName::RptImplPolicy)?;
+        if let Some(enabler_impl_type) = policy.enabler_impl_type {
+            policy_elem
+                .create_sub_element(ElementName::RptEnablerImplType)?
+                .set_character_data::<EnumItem>(enabler_impl_type.into())?;
+        }
+        if let Some(preparation_level) = policy.preparation_level {
+            policy_elem
+                .create_sub_element(ElementName::RptPreparationLevel)?
+                .set_character_data::<EnumItem>(preparation_level.into())?;
+        }
+        Ok(())
+    }
+
+    /// get the implementation policy of the container
+    #[must_use]
+    pub fn rpt_impl_policy(&self) -> Option<RptImplPolicy> {
+        let policy_elem = self.element().get_sub_element(ElementName::RptImplPolicy)?;
+        let enabler_impl_type = policy_elem
+            .get_sub_element(ElementName::RptEnablerImplType)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.enum_value())
+            .and_then(|enumitem| RptEnablerImplType::try_from(enumitem).ok());
+        let preparation_level = policy_elem
+            .get_sub_element(ElementName::RptPreparationLevel)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.enum_value())
+            .and_then(|enumitem| RptPreparationLevel::try_from(enumitem).ok());
+        Some(RptImplPolicy {
+            enabler_impl_type,
+            preparation_level,
+        })
+    }
+
+    /// set or remove the properties of bypassed runnables
+    pub fn set_rpt_executable_entity_properties(
+        &self,
+        properties: Option<&RptExecutableEntityProperties>,
+    ) -> Result<(), AutosarAbstractionError> {
+        let _ = self
+            .element()
+            .remove_sub_element_kind(ElementName::RptExecutableEntityProperties);
+        let Some(properties) = properties else {
+            return Ok(());
+        };
+
+        let properties_elem = self
+            .element()
+            .create_sub_element(ElementName::RptExecutableEntityProperties)?;
+        if let Some(max_rpt_event_id) = properties.max_rpt_event_id {
+            properties_elem
+                .create_sub_element(ElementName::MaxRptEventId)?
+                .set_character_data(u64::from(max_rpt_event_id))?;
+        }
+        if let Some(min_rpt_event_id) = properties.min_rpt_event_id {
+            properties_elem
+                .create_sub_element(ElementName::MinRptEventId)?
+                .set_character_data(u64::from(min_rpt_event_id))?;
+        }
+        if let Some(execution_control) = properties.execution_control {
+            properties_elem
+                .create_sub_element(ElementName::RptExecutionControl)?
+                .set_character_data::<EnumItem>(execution_control.into())?;
+        }
+        if let Some(service_point) = properties.service_point {
+            properties_elem
+                .create_sub_element(ElementName::RptServicePoint)?
+                .set_character_data::<EnumItem>(service_point.into())?;
+        }
+        Ok(())
+    }
+
+    /// get the properties of bypassed runnables
+    #[must_use]
+    pub fn rpt_executable_entity_properties(&self) -> Option<RptExecutableEntityProperties> {
+        let properties_elem = self
+            .element()
+            .get_sub_element(ElementName::RptExecutableEntityProperties)?;
+        let max_rpt_event_id = properties_elem
+            .get_sub_element(ElementName::MaxRptEventId)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_integer());
+        let min_rpt_event_id = properties_elem
+            .get_sub_element(ElementName::MinRptEventId)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.parse_integer());
+        let execution_control = properties_elem
+            .get_sub_element(ElementName::RptExecutionControl)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.enum_value())
+            .and_then(|enumitem| RptExecutionControl::try_from(enumitem).ok());
+        let service_point = properties_elem
+            .get_sub_element(ElementName::RptServicePoint)
+            .and_then(|elem| elem.character_data())
+            .and_then(|cdata| cdata.enum_value())
+            .and_then(|enumitem| RptServicePoint::try_from(enumitem).ok());
+        Some(RptExecutableEntityProperties {
+            max_rpt_event_id,
+            min_rpt_event_id,
+            execution_control,
+            service_point,
+        })
+    }
+}
+
+//##################################################################
+
+/// An `RptProfile` describes the service functions that are provided by the rapid prototyping tool
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RptProfile(Element);
+abstraction_element!(RptProfile, RptProfile);
+impl IdentifiableAbstractionElement for RptProfile {}
+
+impl RptProfile {
+    pub(crate) fn new(name: &str, parent: &Element) -> Result<Self, AutosarAbstractionError> {
+        let profile = parent.create_named_sub_element(ElementName::RptProfile, name)?;
+
+        Ok(Self(profile))
+    }
+
+    /// set or remove the smallest id that may be used for a service point
+    pub fn set_min_service_point_id(&self, id: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        self.set_integer(ElementName::MinServicePointId, id)
+    }
+
+    /// get the smallest id that may be used for a service point
+    #[must_use]
+    pub fn min_service_point_id(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::MinServicePointId)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// set or remove the largest id that may be used for a service point
+    pub fn set_max_service_point_id(&self, id: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        self.set_integer(ElementName::MaxServicePointId, id)
+    }
+
+    /// get the largest id that may be used for a service point
+    #[must_use]
+    pub fn max_service_point_id(&self) -> Option<u32> {
+        self.element()
+            .get_sub_element(ElementName::MaxServicePointId)?
+            .character_data()?
+            .parse_integer()
+    }
+
+    /// set or remove the symbol of the service function that is called before a bypassed runnable
+    pub fn set_service_point_symbol_pre(&self, symbol: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        self.set_text(ElementName::ServicePointSymbolPre, symbol)
+    }
+
+    /// get the symbol of the service function that is called before a bypassed runnable
+    #[must_use]
+    pub fn service_point_symbol_pre(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::ServicePointSymbolPre)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// set or remove the symbol of the service function that is called after a bypassed runnable
+    pub fn set_service_point_symbol_post(&self, symbol: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        self.set_text(ElementName::ServicePointSymbolPost, symbol)
+    }
+
+    /// get the symbol of the service function that is called after a bypassed runnable
+    #[must_use]
+    pub fn service_point_symbol_post(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::ServicePointSymbolPost)?
+            .character_data()?
+            .string_value()
+    }
+
+    fn set_integer(&self, element_name: ElementName, value: Option<u32>) -> Result<(), AutosarAbstractionError> {
+        if let Some(value) = value {
+            self.element()
+                .get_or_create_sub_element(element_name)?
+                .set_character_data(u64::from(value))?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(element_name);
+        }
+        Ok(())
+    }
+
+    fn set_text(&self, element_name: ElementName, value: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        if let Some(value) = value {
+            self.element()
+                .get_or_create_sub_element(element_name)?
+                .set_character_data(value)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(element_name);
+        }
+        Ok(())
+    }
+}
+
+//##################################################################
+
+/// The hook of an [`RptContainer`], which identifies the bypass point in the code and in the measurement description
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RptHook {
+    /// the label of the bypass point in the code
+    pub code_label: Option<String>,
+    /// the identifier of the bypass point in the measurement and calibration description
+    pub mcd_identifier: Option<String>,
+}
+
+//##################################################################
+
+/// The implementation policy of an [`RptContainer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RptImplPolicy {
+    /// the kind of memory that is used for the enablers of the bypass points
+    pub enabler_impl_type: Option<RptEnablerImplType>,
+    /// the level of preparation of the bypass points in the ECU code
+    pub preparation_level: Option<RptPreparationLevel>,
+}
+
+//##################################################################
+
+/// The properties of the bypassed runnables of an [`RptContainer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RptExecutableEntityProperties {
+    /// the largest id that may be used for an RPT event
+    pub max_rpt_event_id: Option<u32>,
+    /// the smallest id that may be used for an RPT event
+    pub min_rpt_event_id: Option<u32>,
+    /// controls whether the execution of the runnable can be disabled by the RPT tool
+    pub execution_control: Option<RptExecutionControl>,
+    /// controls whether service points are generated around the runnable
+    pub service_point: Option<RptServicePoint>,
+}
+
+//##################################################################
+
+/// The kind of memory that is used for the enablers of the bypass points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RptEnablerImplType {
+    /// no enablers are implemented
+    None,
+    /// the enablers are located in RAM
+    RptEnablerRam,
+    /// the enablers are located in RAM and initialized from ROM
+    RptEnablerRamAndRom,
+    /// the enablers are located in ROM
+    RptEnablerRom,
+}
+
+impl From<RptEnablerImplType> for EnumItem {
+    fn from(value: RptEnablerImplType) -> Self {
+        match value {
+            RptEnablerImplType::None => EnumItem::None,
+            RptEnablerImplType::RptEnablerRam => EnumItem::RptEnablerRam,
+            RptEnablerImplType::RptEnablerRamAndRom => EnumItem::RptEnablerRamAndRom,
+            RptEnablerImplType::RptEnablerRom => EnumItem::RptEnablerRom,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for RptEnablerImplType {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::None => Ok(RptEnablerImplType::None),
+            EnumItem::RptEnablerRam => Ok(RptEnablerImplType::RptEnablerRam),
+            EnumItem::RptEnablerRamAndRom => Ok(RptEnablerImplType::RptEnablerRamAndRom),
+            EnumItem::RptEnablerRom => Ok(RptEnablerImplType::RptEnablerRom),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "RptEnablerImplType".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
+/// The level of preparation of the bypass points in the ECU code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RptPreparationLevel {
+    /// the bypass points are not prepared
+    None,
+    /// level 1: the data accesses are prepared for bypassing
+    RptLevel1,
+    /// level 2: the data accesses and the service points are prepared
+    RptLevel2,
+    /// level 3: the data accesses, the service points and the execution control are prepared
+    RptLevel3,
+}
+
+impl From<RptPreparationLevel> for EnumItem {
+    fn from(value: RptPreparationLevel) -> Self {
+        match value {
+            RptPreparationLevel::None => EnumItem::None,
+            RptPreparationLevel::RptLevel1 => EnumItem::RptLevel1,
+            RptPreparationLevel::RptLevel2 => EnumItem::RptLevel2,
+            RptPreparationLevel::RptLevel3 => EnumItem::RptLevel3,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for RptPreparationLevel {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::None => Ok(RptPreparationLevel::None),
+            EnumItem::RptLevel1 => Ok(RptPreparationLevel::RptLevel1),
+            EnumItem::RptLevel2 => Ok(RptPreparationLevel::RptLevel2),
+            EnumItem::RptLevel3 => Ok(RptPreparationLevel::RptLevel3),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "RptPreparationLevel".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
+/// Controls whether the execution of a bypassed runnable can be disabled by the RPT tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RptExecutionControl {
+    /// the runnable is only executed if it is enabled by the RPT tool
+    Conditional,
+    /// the runnable is always executed
+    None,
+}
+
+impl From<RptExecutionControl> for EnumItem {
+    fn from(value: RptExecutionControl) -> Self {
+        match value {
+            RptExecutionControl::Conditional => EnumItem::Conditional,
+            RptExecutionControl::None => EnumItem::None,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for RptExecutionControl {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::Conditional => Ok(RptExecutionControl::Conditional),
+            EnumItem::None => Ok(RptExecutionControl::None),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "RptExecutionControl".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
+/// Controls whether service points are generated around a bypassed runnable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RptServicePoint {
+    /// service points are generated
+    Enabled,
+    /// no service points are generated
+    None,
+}
+
+impl From<RptServicePoint> for EnumItem {
+    fn from(value: RptServicePoint) -> Self {
+        match value {
+            RptServicePoint::Enabled => EnumItem::Enabled,
+            RptServicePoint::None => EnumItem::None,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for RptServicePoint {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::Enabled => Ok(RptServicePoint::Enabled),
+            EnumItem::None => Ok(RptServicePoint::None),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "RptServicePoint".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutosarModelAbstraction, software_component::AtomicSwComponentType};
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn rapid_prototyping_scenario() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let host_system = package.create_system("HostSystem", SystemCategory::EcuExtract).unwrap();
+        let rpt_system = package.create_system("RptSystem", SystemCategory::RptSystem).unwrap();
+
+        let scenario = package.create_rapid_prototyping_scenario("Scenario").unwrap();
+        scenario.set_host_system(Some(&host_system)).unwrap();
+        assert_eq!(scenario.host_system(), Some(host_system.clone()));
+        // the host system is not an RPT system
+        assert!(scenario.set_rpt_system(Some(&host_system)).is_err());
+        scenario.set_rpt_system(Some(&rpt_system)).unwrap();
+        assert_eq!(scenario.rpt_system(), Some(rpt_system));
+        scenario.set_rpt_system(None).unwrap();
+        assert_eq!(scenario.rpt_system(), None);
+
+        let profile = scenario.create_rpt_profile("Profile").unwrap();
+        profile.set_min_service_point_id(Some(1)).unwrap();
+        profile.set_max_service_point_id(Some(100)).unwrap();
+        profile.set_service_point_symbol_pre(Some("Rpt_Pre")).unwrap();
+        profile.set_service_point_symbol_post(Some("Rpt_Post")).unwrap();
+        assert_eq!(profile.min_service_point_id(), Some(1));
+        assert_eq!(profile.max_service_point_id(), Some(100));
+        assert_eq!(profile.service_point_symbol_pre().as_deref(), Some("Rpt_Pre"));
+        assert_eq!(profile.service_point_symbol_post().as_deref(), Some("Rpt_Post"));
+        profile.set_service_point_symbol_post(None).unwrap();
+        assert_eq!(profile.service_point_symbol_post(), None);
+        assert_eq!(scenario.rpt_profiles().collect::<Vec<_>>(), vec![profile.clone()]);
+
+        let swc = package.create_application_sw_component_type("Swc").unwrap();
+        let behavior = swc.create_swc_internal_behavior("Behavior").unwrap();
+        let runnable = behavior.create_runnable_entity("Runnable").unwrap();
+        let composition = package.create_composition_sw_component_type("Composition").unwrap();
+        let swc_prototype = composition.create_component("SwcPrototype", &swc).unwrap();
+
+        let container = scenario.create_rpt_container("Container").unwrap();
+        let inner_container = container.create_rpt_container("InnerContainer").unwrap();
+        assert_eq!(scenario.rpt_containers().collect::<Vec<_>>(), vec![container.clone()]);
+        assert_eq!(
+            container.rpt_containers().collect::<Vec<_>>(),
+            vec![inner_container.clone()]
+        );
+
+        let instance = InstanceRef::new(&composition).component(&swc_prototype).unwrap();
+        inner_container.add_by_pass_point(&instance, &runnable).unwrap();
+        assert_eq!(
+            inner_container.by_pass_points(),
+            vec![(instance.clone(), ByPassPointTarget::RunnableEntity(runnable.clone()))]
+        );
+        // the instance reference must end with a component prototype that contains the target
+        let other_swc = package.create_application_sw_component_type("OtherSwc").unwrap();
+        let other_prototype = composition.create_component("OtherPrototype", &other_swc).unwrap();
+        let other_instance = InstanceRef::new(&composition).component(&other_prototype).unwrap();
+        assert!(inner_container.add_by_pass_point(&other_instance, &runnable).is_err());
+        assert!(
+            container
+                .add_by_pass_point(&InstanceRef::new(&composition), &runnable)
+                .is_err()
+        );
+        // nothing is left behind by the failed calls
+        assert_eq!(inner_container.by_pass_points().len(), 1);
+        assert!(
+            container
+                .element()
+                .get_sub_element(ElementName::ByPassPointIrefs)
+                .is_none()
+        );
+        inner_container.add_rpt_profile(&profile).unwrap();
+        assert_eq!(inner_container.rpt_profiles().collect::<Vec<_>>(), vec![profile]);
+
+        let hook = RptHook {
+            code_label: Some("Runnable_Hook".to_string()),
+            mcd_identifier: Some("RunnableHook".to_string()),
+        };
+        inner_container.set_rpt_hook(Some(&hook)).unwrap();
+        assert_eq!(inner_container.rpt_hook(), Some(hook));
+
+        let policy = RptImplPolicy {
+            enabler_impl_type: Some(RptEnablerImplType::RptEnablerRam),
+            preparation_level: Some(RptPreparationLevel::RptLevel2),
+        };
+        inner_container.set_rpt_impl_policy(Some(&policy)).unwrap();
+        assert_eq!(inner_container.rpt_impl_policy(), Some(policy));
+        inner_container.set_rpt_impl_policy(None).unwrap();
+        assert_eq!(inner_container.rpt_impl_policy(), None);
+
+        let properties = RptExecutableEntityProperties {
+            max_rpt_event_id: Some(20),
+            min_rpt_event_id: Some(10),
+            execution_control: Some(RptExecutionControl::Conditional),
+            service_point: Some(RptServicePoint::Enabled),
+        };
+        inner_container
+            .set_rpt_executable_entity_properties(Some(&properties))
+            .unwrap();
+        assert_eq!(inner_container.rpt_executable_entity_properties(), Some(properties));
+    }
+}
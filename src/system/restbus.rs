@@ -0,0 +1,463 @@
+use crate::communication::{
+    AbstractFrameTriggering, AbstractLinCommunicationController, AbstractPhysicalChannel, Cluster,
+    CommunicationController, CommunicationDirection, FrameTriggering, PhysicalChannel, SocketAddressType,
+};
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, AutosarModelAbstraction, EcuInstance,
+    IdentifiableAbstractionElement, System, make_unique_generated_name,
+};
+use autosar_data::{AutosarDataError, Element, ElementName};
+
+//##################################################################
+
+impl System {
+    /// create a rest-bus simulation extract for an ECU in a separate model
+    ///
+    /// All packages of the model are copied into the `target_model`. In the copy of this system, all ECUs
+    /// except the `ecu_under_test` are collapsed into a single simulated ECU named `RestbusSimulation`:
+    ///
+    /// - clusters and physical channels that the `ecu_under_test` is not connected to are removed
+    /// - the simulated ECU is connected to all remaining channels
+    /// - the simulated ECU sends all frames and PDUs that the `ecu_under_test` receives, and receives all frames
+    ///   and PDUs that the `ecu_under_test` sends
+    /// - frame triggerings that do not involve the `ecu_under_test` are removed
+    /// - on Ethernet channels, the socket addresses of the other ECUs are assigned to the simulated ECU
+    ///
+    /// Finally, all frames, PDUs and signals that are no longer used are removed, see [`System::cleanup_unused`].
+    /// The original model is not modified.
+    ///
+    /// Returns the copy of the system in the `target_model`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::*;
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let system = package.create_system("System", SystemCategory::SystemDescription)?;
+    /// let ecu = system.create_ecu_instance("Ecu", &package)?;
+    /// system.create_ecu_instance("OtherEcu", &package)?;
+    /// let target_model = AutosarModelAbstraction::create("restbus.arxml", AutosarVersion::Autosar_00048);
+    /// let extract = system.create_restbus_extract(&ecu, &target_model)?;
+    /// assert_eq!(extract.ecu_instances().count(), 2);
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::InvalidParameter`] The `ecu_under_test` is not part of this system
+    /// - [`AutosarAbstractionError::ItemAlreadyExists`] A package of the model already exists in the `target_model`
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while creating the extract.
+    ///   The copied packages are removed from the `target_model` again in this case.
+    pub fn create_restbus_extract(
+        &self,
+        ecu_under_test: &EcuInstance,
+        target_model: &AutosarModelAbstraction,
+    ) -> Result<System, AutosarAbstractionError> {
+        if !self.ecu_instances().any(|ecu| ecu == *ecu_under_test) {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The ECU is not part of the system".to_string(),
+            ));
+        }
+
+        let packages: Vec<Element> = self
+            .0
+            .model()?
+            .root_element()
+            .get_sub_element(ElementName::ArPackages)
+            .into_iter()
+            .flat_map(|packages| packages.sub_elements())
+            .collect();
+        for package in &packages {
+            if target_model.get_element_by_path(&package.path()?).is_some() {
+                return Err(AutosarAbstractionError::ItemAlreadyExists);
+            }
+        }
+        let target_root = target_model.root_element();
+        let created_packages_elem = target_root.get_sub_element(ElementName::ArPackages).is_none();
+        let target_packages = target_root.get_or_create_sub_element(ElementName::ArPackages)?;
+        let mut copied_packages = Vec::with_capacity(packages.len());
+        match self.copy_and_reduce(ecu_under_test, target_model, &packages, &mut copied_packages) {
+            Ok(system) => Ok(system),
+            Err(err) => {
+                // remove the copied packages, so that no unreduced copy is left in the target model
+                for copied_package in copied_packages {
+                    target_packages.remove_sub_element(copied_package)?;
+                }
+                if created_packages_elem {
+                    target_root.remove_sub_element(target_packages)?;
+                }
+                Err(err)
+            }
+        }
+    }
+
+    // copy the packages into the target model and reduce the copy of the system to a restbus simulation
+    fn copy_and_reduce(
+        &self,
+        ecu_under_test: &EcuInstance,
+        target_model: &AutosarModelAbstraction,
+        packages: &[Element],
+        copied_packages: &mut Vec<Element>,
+    ) -> Result<System, AutosarAbstractionError> {
+        let target_packages = target_model
+            .root_element()
+            .get_or_create_sub_element(ElementName::ArPackages)?;
+        for package in packages {
+            copied_packages.push(target_packages.create_copied_sub_element(package)?);
+        }
+
+        let system_elem = target_model
+            .get_element_by_path(&self.0.path()?)
+            .ok_or(AutosarDataError::ItemDeleted)?;
+        let ecu_elem = target_model
+            .get_element_by_path(&ecu_under_test.element().path()?)
+            .ok_or(AutosarDataError::ItemDeleted)?;
+        let system = System::try_from(system_elem)?;
+        system.reduce_to_restbus(&EcuInstance::try_from(ecu_elem)?)?;
+
+        Ok(system)
+    }
+
+    // replace all ECUs except the ecu_under_test by a single simulated ECU, and remove all communication
+    // that does not involve the ecu_under_test
+    fn reduce_to_restbus(&self, ecu_under_test: &EcuInstance) -> Result<(), AutosarAbstractionError> {
+        let model = self.0.model()?;
+        let remote_ecus: Vec<EcuInstance> = self.ecu_instances().filter(|ecu| ecu != ecu_under_test).collect();
+
+        let package_elem = ecu_under_test
+            .element()
+            .named_parent()?
+            .ok_or(AutosarDataError::ItemDeleted)?;
+        let package = ArPackage::try_from(package_elem)?;
+        let sim_name = make_unique_generated_name(&model, &package.element().path()?, "RestbusSimulation");
+        let sim_ecu = self.create_ecu_instance(&sim_name, &package)?;
+        let sim_path = sim_ecu.element().path()?;
+
+        let clusters: Vec<Cluster> = self.clusters().collect();
+        for cluster in clusters {
+            let Some((_, dut_controller)) = cluster.ecus().find(|(ecu, _)| ecu == ecu_under_test) else {
+                cluster.remove(true)?;
+                continue;
+            };
+
+            let controller_name = format!("{}_Controller", cluster.name().unwrap_or_default());
+            let controller_name = make_unique_generated_name(&model, &sim_path, &controller_name);
+            let sim_controller: CommunicationController = match dut_controller {
+                CommunicationController::Can(_) => {
+                    sim_ecu.create_can_communication_controller(&controller_name)?.into()
+                }
+                CommunicationController::Ethernet(_) => sim_ecu
+                    .create_ethernet_communication_controller(&controller_name, None)?
+                    .into(),
+                CommunicationController::Flexray(_) => sim_ecu
+                    .create_flexray_communication_controller(&controller_name)?
+                    .into(),
+                // the simulated ECU takes the opposite role on a LIN cluster
+                CommunicationController::LinMaster(_) => sim_ecu
+                    .create_lin_slave_communication_controller(&controller_name)?
+                    .into(),
+                CommunicationController::LinSlave(_) => sim_ecu
+                    .create_lin_master_communication_controller(&controller_name)?
+                    .into(),
+            };
+
+            for channel in cluster_channels(&cluster) {
+                if channel.ecu_connector(ecu_under_test).is_none() {
+                    channel.remove(true)?;
+                    continue;
+                }
+                let connector_name = format!("{}_Connector", channel.name().unwrap_or_default());
+                let connector_name = make_unique_generated_name(&model, &sim_path, &connector_name);
+                connect_controller(&sim_controller, &connector_name, &channel)?;
+                redirect_channel_communication(&channel, ecu_under_test, &sim_ecu)?;
+            }
+        }
+
+        for ecu in remote_ecus {
+            ecu.remove(false)?;
+        }
+        self.cleanup_unused(false)?;
+
+        Ok(())
+    }
+}
+
+// get the physical channels of a cluster
+fn cluster_channels(cluster: &Cluster) -> Vec<PhysicalChannel> {
+    match cluster {
+        Cluster::Can(can_cluster) => can_cluster
+            .physical_channel()
+            .map(PhysicalChannel::Can)
+            .into_iter()
+            .collect(),
+        Cluster::Ethernet(eth_cluster) => eth_cluster.physical_channels().map(PhysicalChannel::Ethernet).collect(),
+        Cluster::FlexRay(flx_cluster) => {
+            let channels_info = flx_cluster.physical_channels();
+            [channels_info.channel_a, channels_info.channel_b]
+                .into_iter()
+                .flatten()
+                .map(PhysicalChannel::Flexray)
+                .collect()
+        }
+        Cluster::Lin(lin_cluster) => lin_cluster
+            .physical_channel()
+            .map(PhysicalChannel::Lin)
+            .into_iter()
+            .collect(),
+    }
+}
+
+// connect a controller of the simulated ECU to a physical channel
+fn connect_controller(
+    controller: &CommunicationController,
+    connector_name: &str,
+    channel: &PhysicalChannel,
+) -> Result<(), AutosarAbstractionError> {
+    match (controller, channel) {
+        (CommunicationController::Can(can_ctrl), PhysicalChannel::Can(can_channel)) => {
+            can_ctrl.connect_physical_channel(connector_name, can_channel)?;
+        }
+        (CommunicationController::Ethernet(eth_ctrl), PhysicalChannel::Ethernet(eth_channel)) => {
+            eth_ctrl.connect_physical_channel(connector_name, eth_channel)?;
+        }
+        (CommunicationController::Flexray(flx_ctrl), PhysicalChannel::Flexray(flx_channel)) => {
+            flx_ctrl.connect_physical_channel(connector_name, flx_channel)?;
+        }
+        (CommunicationController::LinMaster(lin_master), PhysicalChannel::Lin(lin_channel)) => {
+            lin_master.connect_physical_channel(connector_name, lin_channel)?;
+        }
+        (CommunicationController::LinSlave(lin_slave), PhysicalChannel::Lin(lin_channel)) => {
+            lin_slave.connect_physical_channel(connector_name, lin_channel)?;
+        }
+        _ => {
+            return Err(AutosarAbstractionError::InvalidParameter(
+                "The controller does not match the bus type of the channel".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+// connect the simulated ECU to all frames and PDUs of the channel that the ecu_under_test sends or receives,
+// using the opposite communication direction. Frame triggerings without the ecu_under_test are removed.
+fn redirect_channel_communication(
+    channel: &PhysicalChannel,
+    ecu_under_test: &EcuInstance,
+    sim_ecu: &EcuInstance,
+) -> Result<(), AutosarAbstractionError> {
+    let frame_triggerings: Vec<FrameTriggering> = channel.frame_triggerings().collect();
+    for frame_triggering in frame_triggerings {
+        let directions: Vec<CommunicationDirection> = frame_triggering
+            .frame_ports()
+            .filter(|port| port.ecu().is_ok_and(|ecu| ecu == *ecu_under_test))
+            .filter_map(|port| port.communication_direction())
+            .collect();
+        if directions.is_empty() {
+            frame_triggering.remove(false)?;
+            continue;
+        }
+        for direction in directions {
+            frame_triggering.connect_to_ecu(sim_ecu, opposite_direction(direction))?;
+        }
+    }
+
+    // PDUs can also be transmitted without frames, e.g. on Ethernet
+    for pdu_triggering in channel.pdu_triggerings() {
+        let directions: Vec<CommunicationDirection> = pdu_triggering
+            .pdu_ports()
+            .filter(|port| port.ecu().is_ok_and(|ecu| ecu == *ecu_under_test))
+            .filter_map(|port| port.communication_direction())
+            .collect();
+        for direction in directions {
+            pdu_triggering.create_pdu_port(sim_ecu, opposite_direction(direction))?;
+        }
+    }
+
+    if let PhysicalChannel::Ethernet(eth_channel) = channel {
+        for socket_address in eth_channel.socket_addresses() {
+            match socket_address.socket_address_type() {
+                Some(SocketAddressType::Unicast(Some(ecu))) if ecu != *ecu_under_test => {
+                    socket_address.set_unicast_ecu(sim_ecu)?;
+                }
+                Some(SocketAddressType::Multicast(ecus)) if ecus.iter().any(|ecu| ecu != ecu_under_test) => {
+                    socket_address.add_multicast_ecu(sim_ecu)?;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn opposite_direction(direction: CommunicationDirection) -> CommunicationDirection {
+    match direction {
+        CommunicationDirection::In => CommunicationDirection::Out,
+        CommunicationDirection::Out => CommunicationDirection::In,
+    }
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        AbstractionElement, AutosarAbstractionError, AutosarModelAbstraction, ByteOrder,
+        IdentifiableAbstractionElement, SystemCategory,
+        communication::{
+            AbstractFrameTriggering, AbstractPhysicalChannel, CanAddressingMode, CanFrameType, Cluster,
+            CommunicationDirection,
+        },
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn restbus_extract() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+
+        let ecu_dut = system.create_ecu_instance("Dut", &pkg).unwrap();
+        let ecu_a = system.create_ecu_instance("EcuA", &pkg).unwrap();
+        let ecu_b = system.create_ecu_instance("EcuB", &pkg).unwrap();
+
+        // the ECU under test is connected to the first cluster
+        let cluster = system.create_can_cluster("CanCluster", &pkg, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+        for ecu in [&ecu_dut, &ecu_a, &ecu_b] {
+            ecu.create_can_communication_controller("Controller")
+                .unwrap()
+                .connect_physical_channel("Connector", &channel)
+                .unwrap();
+        }
+        // the second cluster only connects the other ECUs
+        let other_cluster = system.create_can_cluster("OtherCluster", &pkg, None).unwrap();
+        let other_channel = other_cluster.create_physical_channel("OtherChannel").unwrap();
+        for ecu in [&ecu_a, &ecu_b] {
+            ecu.create_can_communication_controller("OtherController")
+                .unwrap()
+                .connect_physical_channel("OtherConnector", &other_channel)
+                .unwrap();
+        }
+
+        // received by the ECU under test
+        let frame_rx = system.create_can_frame("FrameRx", &pkg, 8).unwrap();
+        let ft_rx = channel
+            .trigger_frame(&frame_rx, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        ft_rx.connect_to_ecu(&ecu_a, CommunicationDirection::Out).unwrap();
+        ft_rx.connect_to_ecu(&ecu_dut, CommunicationDirection::In).unwrap();
+        let pdu_rx = system.create_isignal_ipdu("PduRx", &pkg, 8).unwrap();
+        frame_rx
+            .map_pdu(&pdu_rx, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        // sent by the ECU under test
+        let frame_tx = system.create_can_frame("FrameTx", &pkg, 8).unwrap();
+        let ft_tx = channel
+            .trigger_frame(&frame_tx, 0x200, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        ft_tx.connect_to_ecu(&ecu_dut, CommunicationDirection::Out).unwrap();
+        ft_tx.connect_to_ecu(&ecu_b, CommunicationDirection::In).unwrap();
+        // exchanged between the other ECUs only
+        let frame_other = system.create_can_frame("FrameOther", &pkg, 8).unwrap();
+        let ft_other = channel
+            .trigger_frame(&frame_other, 0x300, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        ft_other.connect_to_ecu(&ecu_a, CommunicationDirection::Out).unwrap();
+        ft_other.connect_to_ecu(&ecu_b, CommunicationDirection::In).unwrap();
+
+        let target_model = AutosarModelAbstraction::create("restbus.arxml", AutosarVersion::LATEST);
+        let extract = system.create_restbus_extract(&ecu_dut, &target_model).unwrap();
+        assert_eq!(extract.element().path().unwrap(), "/test/System");
+
+        let ecu_names: Vec<String> = extract.ecu_instances().filter_map(|ecu| ecu.name()).collect();
+        assert_eq!(ecu_names.len(), 2);
+        assert!(ecu_names.contains(&"Dut".to_string()));
+        assert!(ecu_names.contains(&"RestbusSimulation".to_string()));
+        let sim_ecu = extract
+            .ecu_instances()
+            .find(|ecu| ecu.name().as_deref() == Some("RestbusSimulation"))
+            .unwrap();
+
+        // only the cluster of the ECU under test remains
+        assert_eq!(extract.clusters().count(), 1);
+        assert!(target_model.get_element_by_path("/test/OtherCluster").is_none());
+
+        let Some(Cluster::Can(target_cluster)) = extract.clusters().next() else {
+            panic!("the CAN cluster is missing");
+        };
+        let target_channel = target_cluster.physical_channel().unwrap();
+        assert_eq!(target_channel.ecus().count(), 2);
+        let frame_triggerings: Vec<_> = target_channel.frame_triggerings().collect();
+        assert_eq!(frame_triggerings.len(), 2);
+        for ft in &frame_triggerings {
+            let sim_direction = ft
+                .frame_ports()
+                .find(|port| port.ecu().is_ok_and(|ecu| ecu == sim_ecu))
+                .and_then(|port| port.communication_direction());
+            match ft.identifier() {
+                Some(0x100) => {
+                    assert_eq!(sim_direction, Some(CommunicationDirection::Out));
+                    // the PDU in the frame is also sent by the simulated ECU
+                    let pt = ft.pdu_triggerings().next().unwrap();
+                    assert!(pt.pdu_ports().any(|port| port.ecu().is_ok_and(|ecu| ecu == sim_ecu)
+                        && port.communication_direction() == Some(CommunicationDirection::Out)));
+                }
+                Some(0x200) => assert_eq!(sim_direction, Some(CommunicationDirection::In)),
+                _ => panic!("unexpected frame triggering"),
+            }
+        }
+        // the frame between the other ECUs was removed
+        assert!(target_model.get_element_by_path("/test/FrameOther").is_none());
+        assert!(target_model.get_element_by_path("/test/FrameRx").is_some());
+        assert!(target_model.get_element_by_path("/test/PduRx").is_some());
+
+        // the original model is unchanged
+        assert_eq!(system.ecu_instances().count(), 3);
+        assert_eq!(system.clusters().count(), 2);
+        assert_eq!(channel.frame_triggerings().count(), 3);
+
+        // the packages already exist in the target model
+        let result = system.create_restbus_extract(&ecu_dut, &target_model);
+        assert!(matches!(result, Err(AutosarAbstractionError::ItemAlreadyExists)));
+
+        // the ECU is not part of the system
+        let system_2 = pkg.create_system("System2", SystemCategory::SystemDescription).unwrap();
+        let other_ecu = system_2.create_ecu_instance("Unrelated", &pkg).unwrap();
+        let target_model_2 = AutosarModelAbstraction::create("restbus2.arxml", AutosarVersion::LATEST);
+        let result = system.create_restbus_extract(&other_ecu, &target_model_2);
+        assert!(matches!(result, Err(AutosarAbstractionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn restbus_extract_failure() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let pkg = model.get_or_create_package("/test").unwrap();
+        let system = pkg.create_system("System", SystemCategory::SystemDescription).unwrap();
+        let ecu_dut = system.create_ecu_instance("Dut", &pkg).unwrap();
+        system.create_ecu_instance("EcuA", &pkg).unwrap();
+        let cluster = system.create_can_cluster("CanCluster", &pkg, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+        ecu_dut
+            .create_can_communication_controller("Controller")
+            .unwrap()
+            .connect_physical_channel("Connector", &channel)
+            .unwrap();
+        // the name of the controller of the simulated ECU is derived from the cluster name.
+        // It exceeds the maximum length of 128 characters, so the reduction of the copied system fails
+        cluster.set_name(&format!("Can{}", "x".repeat(117))).unwrap();
+
+        let target_model = AutosarModelAbstraction::create("restbus.arxml", AutosarVersion::LATEST);
+        let result = system.create_restbus_extract(&ecu_dut, &target_model);
+        assert!(result.is_err());
+        // the copied packages were removed again
+        assert!(target_model.get_element_by_path("/test").is_none());
+        assert_eq!(target_model.packages().count(), 0);
+        // a second attempt is not blocked by leftovers from the first one
+        cluster.set_name("CanCluster").unwrap();
+        assert!(system.create_restbus_extract(&ecu_dut, &target_model).is_ok());
+    }
+}
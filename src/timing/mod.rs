@@ -0,0 +1,932 @@
+//! Timing extensions
+//!
+//! This module contains the timing extensions of the system: timing description events that mark points in time,
+//! e.g. the transmission of a PDU or the activation of a runnable, event chains that connect these events,
+//! and timing constraints that restrict the event chains.
+//!
+//! # Example
+//!
+//! ```
+//! use autosar_data::*;
+//! use autosar_data_abstraction::*;
+//! use autosar_data_abstraction::communication::*;
+//! use autosar_data_abstraction::timing::*;
+//!
+//! # fn main() -> Result<(), AutosarAbstractionError> {
+//! let model = AutosarModelAbstraction::create("timing.arxml", AutosarVersion::LATEST);
+//! let package = model.get_or_create_package("/Timing")?;
+//! let system = package.create_system("System", SystemCategory::SystemExtract)?;
+//! let cluster = system.create_can_cluster("Cluster", &package, None)?;
+//! let channel = cluster.create_physical_channel("Channel")?;
+//! let frame = system.create_can_frame("Frame", &package, 8)?;
+//! let pdu = system.create_isignal_ipdu("Pdu", &package, 8)?;
+//! let frame_triggering = channel.trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)?;
+//! frame.map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)?;
+//! let pdu_triggering = frame_triggering.pdu_triggerings().next().unwrap();
+//!
+//! let timing = package.create_system_timing("SystemTiming", &system)?;
+//! let sent = timing.create_ipdu_event("PduSent", &pdu_triggering, TdEventIPduType::IPduSentToIf)?;
+//! let received = timing.create_ipdu_event("PduReceived", &pdu_triggering, TdEventIPduType::IPduReceivedByCom)?;
+//! let chain = timing.create_event_chain("PduChain", &sent.into(), &received.into())?;
+//! let constraint = timing.create_latency_constraint("PduLatency", &chain, LatencyConstraintType::Reaction)?;
+//! constraint.set_maximum(Some(0.005))?;
+//! assert_eq!(constraint.maximum(), Some(0.005));
+//! # Ok(())}
+//! ```
+
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, Element, IdentifiableAbstractionElement, System,
+    abstraction_element,
+    communication::{Pdu, PduTriggering, PhysicalChannel},
+    software_component::RunnableEntity,
+};
+use autosar_data::{ElementName, EnumItem};
+use std::collections::HashSet;
+
+//##################################################################
+
+/// A `SystemTiming` contains the timing descriptions and the timing constraints of a [`System`]
+///
+/// Use [`ArPackage::create_system_timing`] to create a new `SystemTiming`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SystemTiming(Element);
+abstraction_element!(SystemTiming, SystemTiming);
+impl IdentifiableAbstractionElement for SystemTiming {}
+
+impl SystemTiming {
+    pub(crate) fn new(name: &str, package: &ArPackage, system: &System) -> Result<Self, AutosarAbstractionError> {
+        let elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let timing_elem = elements.create_named_sub_element(ElementName::SystemTiming, name)?;
+        let timing = Self(timing_elem);
+        timing.set_system(system)?;
+
+        Ok(timing)
+    }
+
+    /// set the `System` that is described by the timing
+    pub fn set_system(&self, system: &System) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::SystemRef)?
+            .set_reference_target(system.element())?;
+        Ok(())
+    }
+
+    /// get the `System` that is described by the timing
+    #[must_use]
+    pub fn system(&self) -> Option<System> {
+        let system_elem = self
+            .element()
+            .get_sub_element(ElementName::SystemRef)?
+            .get_reference_target()
+            .ok()?;
+        System::try_from(system_elem).ok()
+    }
+
+    /// create a timing description event for a PDU on the channel of the given `PduTriggering`
+    pub fn create_ipdu_event(
+        &self,
+        name: &str,
+        pdu_triggering: &PduTriggering,
+        event_type: TdEventIPduType,
+    ) -> Result<TdEventIPdu, AutosarAbstractionError> {
+        let descriptions = self
+            .element()
+            .get_or_create_sub_element(ElementName::TimingDescriptions)?;
+        TdEventIPdu::new(name, &descriptions, pdu_triggering, event_type)
+    }
+
+    /// create a timing description event for a runnable, e.g. its activation
+    pub fn create_runnable_event(
+        &self,
+        name: &str,
+        runnable: &RunnableEntity,
+        event_type: TdEventSwcInternalBehaviorType,
+    ) -> Result<TdEventSwcInternalBehavior, AutosarAbstractionError> {
+        let descriptions = self
+            .element()
+            .get_or_create_sub_element(ElementName::TimingDescriptions)?;
+        TdEventSwcInternalBehavior::new(name, &descriptions, runnable, event_type)
+    }
+
+    /// create an event chain from a stimulus event to a response event
+    pub fn create_event_chain(
+        &self,
+        name: &str,
+        stimulus: &TimingDescriptionEvent,
+        response: &TimingDescriptionEvent,
+    ) -> Result<TimingDescriptionEventChain, AutosarAbstractionError> {
+        let descriptions = self
+            .element()
+            .get_or_create_sub_element(ElementName::TimingDescriptions)?;
+        TimingDescriptionEventChain::new(name, &descriptions, stimulus, response)
+    }
+
+    /// iterate over all timing description events of the timing
+    pub fn timing_description_events(&self) -> impl Iterator<Item = TimingDescriptionEvent> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::TimingDescriptions)
+            .into_iter()
+            .flat_map(|descriptions| descriptions.sub_elements())
+            .filter_map(|elem| TimingDescriptionEvent::try_from(elem).ok())
+    }
+
+    /// iterate over all event chains of the timing
+    pub fn event_chains(&self) -> impl Iterator<Item = TimingDescriptionEventChain> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::TimingDescriptions)
+            .into_iter()
+            .flat_map(|descriptions| descriptions.sub_elements())
+            .filter_map(|elem| TimingDescriptionEventChain::try_from(elem).ok())
+    }
+
+    /// create a latency constraint for an event chain
+    pub fn create_latency_constraint(
+        &self,
+        name: &str,
+        scope: &TimingDescriptionEventChain,
+        constraint_type: LatencyConstraintType,
+    ) -> Result<LatencyTimingConstraint, AutosarAbstractionError> {
+        let requirements = self
+            .element()
+            .get_or_create_sub_element(ElementName::TimingRequirements)?;
+        LatencyTimingConstraint::new(name, &requirements, scope, constraint_type)
+    }
+
+    /// create an execution order constraint, which requires that the runnables are executed in the given order
+    pub fn create_execution_order_constraint(
+        &self,
+        name: &str,
+        runnables: &[RunnableEntity],
+    ) -> Result<ExecutionOrderConstraint, AutosarAbstractionError> {
+        let requirements = self
+            .element()
+            .get_or_create_sub_element(ElementName::TimingRequirements)?;
+        ExecutionOrderConstraint::new(name, &requirements, runnables)
+    }
+
+    /// iterate over all timing constraints of the timing
+    pub fn timing_constraints(&self) -> impl Iterator<Item = TimingConstraint> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::TimingRequirements)
+            .into_iter()
+            .flat_map(|requirements| requirements.sub_elements())
+            .filter_map(|elem| TimingConstraint::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// A `TdEventIPdu` marks a point in time in the transmission or reception of a PDU
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TdEventIPdu(Element);
+abstraction_element!(TdEventIPdu, TdEventIPdu);
+impl IdentifiableAbstractionElement for TdEventIPdu {}
+
+impl TdEventIPdu {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        pdu_triggering: &PduTriggering,
+        event_type: TdEventIPduType,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let pdu = pdu_triggering.pdu().ok_or(AutosarAbstractionError::InvalidParameter(
+            "The PduTriggering does not refer to a PDU".to_string(),
+        ))?;
+        let channel = pdu_triggering.physical_channel()?;
+
+        let event_elem = parent.create_named_sub_element(ElementName::TdEventIPdu, name)?;
+        event_elem
+            .create_sub_element(ElementName::IPduRef)?
+            .set_reference_target(pdu.element())?;
+        event_elem
+            .create_sub_element(ElementName::PhysicalChannelRef)?
+            .set_reference_target(channel.element())?;
+        let event = Self(event_elem);
+        event.set_event_type(event_type)?;
+
+        Ok(event)
+    }
+
+    /// set the type of the event
+    pub fn set_event_type(&self, event_type: TdEventIPduType) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::TdEventType)?
+            .set_character_data::<EnumItem>(event_type.into())?;
+        Ok(())
+    }
+
+    /// get the type of the event
+    #[must_use]
+    pub fn event_type(&self) -> Option<TdEventIPduType> {
+        self.element()
+            .get_sub_element(ElementName::TdEventType)?
+            .character_data()?
+            .enum_value()
+            .and_then(|enumitem| TdEventIPduType::try_from(enumitem).ok())
+    }
+
+    /// get the PDU of the event
+    #[must_use]
+    pub fn pdu(&self) -> Option<Pdu> {
+        let pdu_elem = self
+            .element()
+            .get_sub_element(ElementName::IPduRef)?
+            .get_reference_target()
+            .ok()?;
+        Pdu::try_from(pdu_elem).ok()
+    }
+
+    /// get the physical channel on which the PDU is transmitted
+    #[must_use]
+    pub fn physical_channel(&self) -> Option<PhysicalChannel> {
+        let channel_elem = self
+            .element()
+            .get_sub_element(ElementName::PhysicalChannelRef)?
+            .get_reference_target()
+            .ok()?;
+        PhysicalChannel::try_from(channel_elem).ok()
+    }
+}
+
+//##################################################################
+
+/// The type of a [`TdEventIPdu`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TdEventIPduType {
+    /// the PDU has been received by the COM module
+    IPduReceivedByCom,
+    /// the PDU has been sent to the interface module
+    IPduSentToIf,
+}
+
+impl From<TdEventIPduType> for EnumItem {
+    fn from(value: TdEventIPduType) -> Self {
+        match value {
+            TdEventIPduType::IPduReceivedByCom => EnumItem::IPduReceivedByCom,
+            TdEventIPduType::IPduSentToIf => EnumItem::IPduSentToIf,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for TdEventIPduType {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::IPduReceivedByCom => Ok(TdEventIPduType::IPduReceivedByCom),
+            EnumItem::IPduSentToIf => Ok(TdEventIPduType::IPduSentToIf),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "TdEventIPduType".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
+/// A `TdEventSwcInternalBehavior` marks a point in time in the execution of a runnable
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TdEventSwcInternalBehavior(Element);
+abstraction_element!(TdEventSwcInternalBehavior, TdEventSwcInternalBehavior);
+impl IdentifiableAbstractionElement for TdEventSwcInternalBehavior {}
+
+impl TdEventSwcInternalBehavior {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        runnable: &RunnableEntity,
+        event_type: TdEventSwcInternalBehaviorType,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let event_elem = parent.create_named_sub_element(ElementName::TdEventSwcInternalBehavior, name)?;
+        event_elem
+            .create_sub_element(ElementName::RunnableRef)?
+            .set_reference_target(runnable.element())?;
+        let event = Self(event_elem);
+        event.set_event_type(event_type)?;
+
+        Ok(event)
+    }
+
+    /// set the type of the event
+    pub fn set_event_type(&self, event_type: TdEventSwcInternalBehaviorType) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::TdEventSwcInternalBehaviorType)?
+            .set_character_data::<EnumItem>(event_type.into())?;
+        Ok(())
+    }
+
+    /// get the type of the event
+    #[must_use]
+    pub fn event_type(&self) -> Option<TdEventSwcInternalBehaviorType> {
+        self.element()
+            .get_sub_element(ElementName::TdEventSwcInternalBehaviorType)?
+            .character_data()?
+            .enum_value()
+            .and_then(|enumitem| TdEventSwcInternalBehaviorType::try_from(enumitem).ok())
+    }
+
+    /// get the runnable of the event
+    #[must_use]
+    pub fn runnable(&self) -> Option<RunnableEntity> {
+        let runnable_elem = self
+            .element()
+            .get_sub_element(ElementName::RunnableRef)?
+            .get_reference_target()
+            .ok()?;
+        RunnableEntity::try_from(runnable_elem).ok()
+    }
+}
+
+//##################################################################
+
+/// The type of a [`TdEventSwcInternalBehavior`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TdEventSwcInternalBehaviorType {
+    /// the runnable has been activated, i.e. it is ready to be started
+    RunnableEntityActivated,
+    /// the runnable has been started
+    RunnableEntityStarted,
+    /// the runnable has terminated
+    RunnableEntityTerminated,
+    /// the runnable accesses a variable
+    RunnableEntityVariableAccess,
+}
+
+impl From<TdEventSwcInternalBehaviorType> for EnumItem {
+    fn from(value: TdEventSwcInternalBehaviorType) -> Self {
+        match value {
+            TdEventSwcInternalBehaviorType::RunnableEntityActivated => EnumItem::RunnableEntityActivated,
+            TdEventSwcInternalBehaviorType::RunnableEntityStarted => EnumItem::RunnableEntityStarted,
+            TdEventSwcInternalBehaviorType::RunnableEntityTerminated => EnumItem::RunnableEntityTerminated,
+            TdEventSwcInternalBehaviorType::RunnableEntityVariableAccess => EnumItem::RunnableEntityVariableAccess,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for TdEventSwcInternalBehaviorType {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::RunnableEntityActivated => Ok(TdEventSwcInternalBehaviorType::RunnableEntityActivated),
+            EnumItem::RunnableEntityStarted => Ok(TdEventSwcInternalBehaviorType::RunnableEntityStarted),
+            EnumItem::RunnableEntityTerminated => Ok(TdEventSwcInternalBehaviorType::RunnableEntityTerminated),
+            EnumItem::RunnableEntityVariableAccess => Ok(TdEventSwcInternalBehaviorType::RunnableEntityVariableAccess),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "TdEventSwcInternalBehaviorType".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
+/// All timing description events that can be used in a [`TimingDescriptionEventChain`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TimingDescriptionEvent {
+    /// an event of a PDU
+    IPdu(TdEventIPdu),
+    /// an event of a runnable
+    SwcInternalBehavior(TdEventSwcInternalBehavior),
+}
+
+impl AbstractionElement for TimingDescriptionEvent {
+    fn element(&self) -> &Element {
+        match self {
+            TimingDescriptionEvent::IPdu(event) => event.element(),
+            TimingDescriptionEvent::SwcInternalBehavior(event) => event.element(),
+        }
+    }
+}
+
+impl TryFrom<Element> for TimingDescriptionEvent {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        match element.element_name() {
+            ElementName::TdEventIPdu => Ok(TimingDescriptionEvent::IPdu(TdEventIPdu(element))),
+            ElementName::TdEventSwcInternalBehavior => Ok(TimingDescriptionEvent::SwcInternalBehavior(
+                TdEventSwcInternalBehavior(element),
+            )),
+            _ => Err(AutosarAbstractionError::ConversionError {
+                element,
+                dest: "TimingDescriptionEvent".to_string(),
+            }),
+        }
+    }
+}
+
+impl IdentifiableAbstractionElement for TimingDescriptionEvent {}
+
+impl From<TdEventIPdu> for TimingDescriptionEvent {
+    fn from(event: TdEventIPdu) -> Self {
+        TimingDescriptionEvent::IPdu(event)
+    }
+}
+
+impl From<TdEventSwcInternalBehavior> for TimingDescriptionEvent {
+    fn from(event: TdEventSwcInternalBehavior) -> Self {
+        TimingDescriptionEvent::SwcInternalBehavior(event)
+    }
+}
+
+//##################################################################
+
+/// A `TimingDescriptionEventChain` describes the causal relationship between a stimulus event and a response event
+///
+/// The chain can be divided into segments, which are event chains themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimingDescriptionEventChain(Element);
+abstraction_element!(TimingDescriptionEventChain, TimingDescriptionEventChain);
+impl IdentifiableAbstractionElement for TimingDescriptionEventChain {}
+
+impl TimingDescriptionEventChain {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        stimulus: &TimingDescriptionEvent,
+        response: &TimingDescriptionEvent,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let chain_elem = parent.create_named_sub_element(ElementName::TimingDescriptionEventChain, name)?;
+        let chain = Self(chain_elem);
+        chain.set_stimulus(stimulus)?;
+        chain.set_response(response)?;
+
+        Ok(chain)
+    }
+
+    /// set the stimulus event of the chain
+    pub fn set_stimulus(&self, stimulus: &TimingDescriptionEvent) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::StimulusRef)?
+            .set_reference_target(stimulus.element())?;
+        Ok(())
+    }
+
+    /// get the stimulus event of the chain
+    #[must_use]
+    pub fn stimulus(&self) -> Option<TimingDescriptionEvent> {
+        let event_elem = self
+            .element()
+            .get_sub_element(ElementName::StimulusRef)?
+            .get_reference_target()
+            .ok()?;
+        TimingDescriptionEvent::try_from(event_elem).ok()
+    }
+
+    /// set the response event of the chain
+    pub fn set_response(&self, response: &TimingDescriptionEvent) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::ResponseRef)?
+            .set_reference_target(response.element())?;
+        Ok(())
+    }
+
+    /// get the response event of the chain
+    #[must_use]
+    pub fn response(&self) -> Option<TimingDescriptionEvent> {
+        let event_elem = self
+            .element()
+            .get_sub_element(ElementName::ResponseRef)?
+            .get_reference_target()
+            .ok()?;
+        TimingDescriptionEvent::try_from(event_elem).ok()
+    }
+
+    /// add a segment to the chain
+    ///
+    /// The segment must not contain this chain, directly or indirectly.
+    pub fn add_segment(&self, segment: &TimingDescriptionEventChain) -> Result<(), AutosarAbstractionError> {
+        // search the segments of the new segment for this chain
+        let mut visited = HashSet::new();
+        let mut pending = vec![segment.clone()];
+        while let Some(chain) = pending.pop() {
+            if &chain == self {
+                return Err(AutosarAbstractionError::InvalidParameter(
+                    "An event chain cannot be a segment of itself".to_string(),
+                ));
+            }
+            if visited.insert(chain.clone()) {
+                pending.extend(chain.segments());
+            }
+        }
+        self.element()
+            .get_or_create_sub_element(ElementName::SegmentRefs)?
+            .create_sub_element(ElementName::SegmentRef)?
+            .set_reference_target(segment.element())?;
+        Ok(())
+    }
+
+    /// iterate over all segments of the chain
+    pub fn segments(&self) -> impl Iterator<Item = TimingDescriptionEventChain> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::SegmentRefs)
+            .into_iter()
+            .flat_map(|segment_refs| segment_refs.sub_elements())
+            .filter_map(|segment_ref| segment_ref.get_reference_target().ok())
+            .filter_map(|elem| TimingDescriptionEventChain::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// A `LatencyTimingConstraint` restricts the time between the stimulus and the response of an event chain
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LatencyTimingConstraint(Element);
+abstraction_element!(LatencyTimingConstraint, LatencyTimingConstraint);
+impl IdentifiableAbstractionElement for LatencyTimingConstraint {}
+
+impl LatencyTimingConstraint {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        scope: &TimingDescriptionEventChain,
+        constraint_type: LatencyConstraintType,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let constraint_elem = parent.create_named_sub_element(ElementName::LatencyTimingConstraint, name)?;
+        let constraint = Self(constraint_elem);
+        constraint.set_scope(scope)?;
+        constraint.set_constraint_type(constraint_type)?;
+
+        Ok(constraint)
+    }
+
+    /// set the event chain that is constrained
+    pub fn set_scope(&self, scope: &TimingDescriptionEventChain) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::ScopeRef)?
+            .set_reference_target(scope.element())?;
+        Ok(())
+    }
+
+    /// get the event chain that is constrained
+    #[must_use]
+    pub fn scope(&self) -> Option<TimingDescriptionEventChain> {
+        let chain_elem = self
+            .element()
+            .get_sub_element(ElementName::ScopeRef)?
+            .get_reference_target()
+            .ok()?;
+        TimingDescriptionEventChain::try_from(chain_elem).ok()
+    }
+
+    /// set the type of the latency constraint
+    pub fn set_constraint_type(&self, constraint_type: LatencyConstraintType) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::LatencyConstraintType)?
+            .set_character_data::<EnumItem>(constraint_type.into())?;
+        Ok(())
+    }
+
+    /// get the type of the latency constraint
+    #[must_use]
+    pub fn constraint_type(&self) -> Option<LatencyConstraintType> {
+        self.element()
+            .get_sub_element(ElementName::LatencyConstraintType)?
+            .character_data()?
+            .enum_value()
+            .and_then(|enumitem| LatencyConstraintType::try_from(enumitem).ok())
+    }
+
+    /// set or remove the maximum latency in seconds
+    pub fn set_maximum(&self, maximum: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        set_time(self.element(), ElementName::Maximum, maximum)
+    }
+
+    /// get the maximum latency in seconds
+    #[must_use]
+    pub fn maximum(&self) -> Option<f64> {
+        get_time(self.element(), ElementName::Maximum)
+    }
+
+    /// set or remove the minimum latency in seconds
+    pub fn set_minimum(&self, minimum: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        set_time(self.element(), ElementName::Minimum, minimum)
+    }
+
+    /// get the minimum latency in seconds
+    #[must_use]
+    pub fn minimum(&self) -> Option<f64> {
+        get_time(self.element(), ElementName::Minimum)
+    }
+
+    /// set or remove the nominal latency in seconds
+    pub fn set_nominal(&self, nominal: Option<f64>) -> Result<(), AutosarAbstractionError> {
+        set_time(self.element(), ElementName::Nominal, nominal)
+    }
+
+    /// get the nominal latency in seconds
+    #[must_use]
+    pub fn nominal(&self) -> Option<f64> {
+        get_time(self.element(), ElementName::Nominal)
+    }
+}
+
+// time units of the ASAM CSE codes 0 (1 microsecond) to 9 (1 hour), given as (multiplier, divisor)
+// the sub-second units are divisors, so that e.g. 10000 microseconds are exactly 0.01 seconds
+const CSE_CODE_UNITS: [(f64, f64); 10] = [
+    (1.0, 1e6),
+    (1.0, 1e5),
+    (1.0, 1e4),
+    (1.0, 1e3),
+    (1.0, 1e2),
+    (1.0, 1e1),
+    (1.0, 1.0),
+    (10.0, 1.0),
+    (60.0, 1.0),
+    (3600.0, 1.0),
+];
+
+// times are stored as a multidimensional time, i.e. as a multiple of the time unit of a CSE code.
+// The time is written in microseconds (CSE code 0), but any of the CSE codes can be read
+fn set_time(element: &Element, element_name: ElementName, seconds: Option<f64>) -> Result<(), AutosarAbstractionError> {
+    if let Some(seconds) = seconds
+        && (!seconds.is_finite() || seconds < 0.0)
+    {
+        return Err(AutosarAbstractionError::InvalidParameter(format!(
+            "Invalid time value {seconds}: the time must not be negative"
+        )));
+    }
+    let _ = element.remove_sub_element_kind(element_name);
+    let Some(seconds) = seconds else {
+        return Ok(());
+    };
+
+    let time_elem = element.create_sub_element(element_name)?;
+    time_elem
+        .create_sub_element(ElementName::CseCode)?
+        .set_character_data(0u64)?;
+    time_elem
+        .create_sub_element(ElementName::CseCodeFactor)?
+        .set_character_data((seconds * 1e6).round() as u64)?;
+    Ok(())
+}
+
+fn get_time(element: &Element, element_name: ElementName) -> Option<f64> {
+    let time_elem = element.get_sub_element(element_name)?;
+    let cse_code: u32 = time_elem
+        .get_sub_element(ElementName::CseCode)?
+        .character_data()?
+        .parse_integer()?;
+    let factor: u64 = time_elem
+        .get_sub_element(ElementName::CseCodeFactor)?
+        .character_data()?
+        .parse_integer()?;
+    let (multiplier, divisor) = CSE_CODE_UNITS.get(cse_code as usize)?;
+    Some(factor as f64 * multiplier / divisor)
+}
+
+//##################################################################
+
+/// The type of a [`LatencyTimingConstraint`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LatencyConstraintType {
+    /// the age of the data at the response, i.e. the time since the data was sampled at the stimulus
+    Age,
+    /// the reaction time, i.e. the time until a change at the stimulus has an effect at the response
+    Reaction,
+}
+
+impl From<LatencyConstraintType> for EnumItem {
+    fn from(value: LatencyConstraintType) -> Self {
+        match value {
+            LatencyConstraintType::Age => EnumItem::Age,
+            LatencyConstraintType::Reaction => EnumItem::Reaction,
+        }
+    }
+}
+
+impl TryFrom<EnumItem> for LatencyConstraintType {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(value: EnumItem) -> Result<Self, Self::Error> {
+        match value {
+            EnumItem::Age => Ok(LatencyConstraintType::Age),
+            EnumItem::Reaction => Ok(LatencyConstraintType::Reaction),
+            _ => Err(AutosarAbstractionError::ValueConversionError {
+                value: value.to_string(),
+                dest: "LatencyConstraintType".to_string(),
+            }),
+        }
+    }
+}
+
+//##################################################################
+
+/// An `ExecutionOrderConstraint` requires that a list of runnables is executed in the given order
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExecutionOrderConstraint(Element);
+abstraction_element!(ExecutionOrderConstraint, ExecutionOrderConstraint);
+impl IdentifiableAbstractionElement for ExecutionOrderConstraint {}
+
+impl ExecutionOrderConstraint {
+    pub(crate) fn new(
+        name: &str,
+        parent: &Element,
+        runnables: &[RunnableEntity],
+    ) -> Result<Self, AutosarAbstractionError> {
+        let constraint_elem = parent.create_named_sub_element(ElementName::ExecutionOrderConstraint, name)?;
+        let constraint = Self(constraint_elem);
+        constraint.set_runnables(runnables)?;
+
+        Ok(constraint)
+    }
+
+    /// set the runnables of the constraint, in the order in which they must be executed
+    ///
+    /// Each runnable is referenced by an ordered element, which refers to the ordered element of the next runnable
+    /// as its successor.
+    pub fn set_runnables(&self, runnables: &[RunnableEntity]) -> Result<(), AutosarAbstractionError> {
+        let _ = self.element().remove_sub_element_kind(ElementName::OrderedElements);
+        if runnables.is_empty() {
+            return Ok(());
+        }
+        let ordered_elements = self.element().create_sub_element(ElementName::OrderedElements)?;
+
+        let mut previous: Option<Element> = None;
+        for (idx, runnable) in runnables.iter().enumerate() {
+            let name = format!("{}_{idx}", runnable.name().as_deref().unwrap_or("Runnable"));
+            let entity_ref = ordered_elements.create_named_sub_element(ElementName::EocExecutableEntityRef, &name)?;
+            entity_ref
+                .create_sub_element(ElementName::ExecutableRef)?
+                .set_reference_target(runnable.element())?;
+            if let Some(previous) = previous {
+                previous
+                    .create_sub_element(ElementName::SuccessorRefs)?
+                    .create_sub_element(ElementName::SuccessorRef)?
+                    .set_reference_target(&entity_ref)?;
+            }
+            previous = Some(entity_ref);
+        }
+        Ok(())
+    }
+
+    /// get the runnables of the constraint, in the order in which they must be executed
+    #[must_use]
+    pub fn runnables(&self) -> Vec<RunnableEntity> {
+        self.element()
+            .get_sub_element(ElementName::OrderedElements)
+            .into_iter()
+            .flat_map(|ordered_elements| ordered_elements.sub_elements())
+            .filter_map(|entity_ref| entity_ref.get_sub_element(ElementName::ExecutableRef))
+            .filter_map(|executable_ref| executable_ref.get_reference_target().ok())
+            .filter_map(|elem| RunnableEntity::try_from(elem).ok())
+            .collect()
+    }
+}
+
+//##################################################################
+
+/// All timing constraints of a [`SystemTiming`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TimingConstraint {
+    /// an execution order constraint
+    ExecutionOrder(ExecutionOrderConstraint),
+    /// a latency constraint
+    Latency(LatencyTimingConstraint),
+}
+
+impl AbstractionElement for TimingConstraint {
+    fn element(&self) -> &Element {
+        match self {
+            TimingConstraint::ExecutionOrder(constraint) => constraint.element(),
+            TimingConstraint::Latency(constraint) => constraint.element(),
+        }
+    }
+}
+
+impl TryFrom<Element> for TimingConstraint {
+    type Error = AutosarAbstractionError;
+
+    fn try_from(element: Element) -> Result<Self, Self::Error> {
+        match element.element_name() {
+            ElementName::ExecutionOrderConstraint => {
+                Ok(TimingConstraint::ExecutionOrder(ExecutionOrderConstraint(element)))
+            }
+            ElementName::LatencyTimingConstraint => Ok(TimingConstraint::Latency(LatencyTimingConstraint(element))),
+            _ => Err(AutosarAbstractionError::ConversionError {
+                element,
+                dest: "TimingConstraint".to_string(),
+            }),
+        }
+    }
+}
+
+impl IdentifiableAbstractionElement for TimingConstraint {}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AutosarModelAbstraction, ByteOrder, SystemCategory,
+        communication::{AbstractFrame, AbstractFrameTriggering, CanAddressingMode, CanFrameType},
+        software_component::AtomicSwComponentType,
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn system_timing() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/Timing").unwrap();
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_can_cluster("Cluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("Channel").unwrap();
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        let pdu = system.create_isignal_ipdu("Pdu", &package, 8).unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        frame
+            .map_pdu(&pdu, 0, ByteOrder::MostSignificantByteLast, None)
+            .unwrap();
+        let pdu_triggering = frame_triggering.pdu_triggerings().next().unwrap();
+
+        let swc = package.create_application_sw_component_type("Swc").unwrap();
+        let behavior = swc.create_swc_internal_behavior("Behavior").unwrap();
+        let runnable1 = behavior.create_runnable_entity("Runnable1").unwrap();
+        let runnable2 = behavior.create_runnable_entity("Runnable2").unwrap();
+
+        let timing = package.create_system_timing("SystemTiming", &system).unwrap();
+        assert_eq!(timing.system(), Some(system));
+
+        let activated = timing
+            .create_runnable_event(
+                "Activated",
+                &runnable1,
+                TdEventSwcInternalBehaviorType::RunnableEntityActivated,
+            )
+            .unwrap();
+        assert_eq!(activated.runnable(), Some(runnable1.clone()));
+        assert_eq!(
+            activated.event_type(),
+            Some(TdEventSwcInternalBehaviorType::RunnableEntityActivated)
+        );
+        let sent = timing
+            .create_ipdu_event("Sent", &pdu_triggering, TdEventIPduType::IPduSentToIf)
+            .unwrap();
+        assert_eq!(sent.pdu(), Some(pdu.into()));
+        assert_eq!(sent.physical_channel(), Some(channel.into()));
+        assert_eq!(sent.event_type(), Some(TdEventIPduType::IPduSentToIf));
+        assert_eq!(timing.timing_description_events().count(), 2);
+
+        let chain = timing
+            .create_event_chain("Chain", &activated.clone().into(), &sent.clone().into())
+            .unwrap();
+        let segment = timing
+            .create_event_chain("Segment", &activated.clone().into(), &sent.clone().into())
+            .unwrap();
+        chain.add_segment(&segment).unwrap();
+        assert!(chain.add_segment(&chain).is_err());
+        // indirect cycles are rejected as well
+        assert!(segment.add_segment(&chain).is_err());
+        assert_eq!(segment.segments().count(), 0);
+        assert_eq!(chain.stimulus(), Some(activated.into()));
+        assert_eq!(chain.response(), Some(sent.into()));
+        assert_eq!(chain.segments().collect::<Vec<_>>(), vec![segment]);
+        assert_eq!(timing.event_chains().count(), 2);
+
+        let latency = timing
+            .create_latency_constraint("Latency", &chain, LatencyConstraintType::Reaction)
+            .unwrap();
+        assert_eq!(latency.scope(), Some(chain));
+        assert_eq!(latency.constraint_type(), Some(LatencyConstraintType::Reaction));
+        latency.set_maximum(Some(0.01)).unwrap();
+        latency.set_minimum(Some(0.000_5)).unwrap();
+        assert_eq!(latency.maximum(), Some(0.01));
+        assert_eq!(latency.minimum(), Some(0.000_5));
+        assert!(latency.set_nominal(Some(-1.0)).is_err());
+        // an invalid value doesn't remove the existing value
+        assert!(latency.set_maximum(Some(f64::NAN)).is_err());
+        assert_eq!(latency.maximum(), Some(0.01));
+        latency.set_minimum(None).unwrap();
+        assert_eq!(latency.minimum(), None);
+
+        let execution_order = timing
+            .create_execution_order_constraint("ExecutionOrder", &[runnable2.clone(), runnable1.clone()])
+            .unwrap();
+        assert_eq!(execution_order.runnables(), vec![runnable2.clone(), runnable1.clone()]);
+        execution_order.set_runnables(&[]).unwrap();
+        assert!(execution_order.runnables().is_empty());
+        assert!(
+            execution_order
+                .element()
+                .get_sub_element(ElementName::OrderedElements)
+                .is_none()
+        );
+        execution_order
+            .set_runnables(&[runnable2.clone(), runnable1.clone()])
+            .unwrap();
+        assert_eq!(execution_order.runnables(), vec![runnable2, runnable1]);
+        assert_eq!(
+            timing.timing_constraints().collect::<Vec<_>>(),
+            vec![
+                TimingConstraint::Latency(latency),
+                TimingConstraint::ExecutionOrder(execution_order)
+            ]
+        );
+    }
+}
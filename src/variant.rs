@@ -0,0 +1,613 @@
+use crate::communication::{
+    CanFrameTriggering, FlexrayFrameTriggering, FrameTriggering, ISignalToIPduMapping, ISignalTriggering,
+    LinFrameTriggering, PduToFrameMapping, PduTriggering,
+};
+use crate::software_component::{
+    AssemblySwConnector, DelegationSwConnector, PPortPrototype, PRPortPrototype, PassThroughSwConnector, PortPrototype,
+    RPortPrototype, SwComponentPrototype, SwConnector,
+};
+use crate::{
+    AbstractionElement, ArPackage, AutosarAbstractionError, IdentifiableAbstractionElement, abstraction_element,
+};
+use autosar_data::{Element, ElementContent, ElementName};
+use std::collections::HashMap;
+
+//##################################################################
+
+/// The `VariantElement` trait is implemented by all elements that can be made variant with a [`VariationPoint`]
+///
+/// An element with a variation point is only present in the variants for which the conditions of the
+/// variation point are fulfilled. Elements without a variation point are present in all variants.
+pub trait VariantElement: AbstractionElement {
+    /// get the variation point of the element, if it has one
+    #[must_use]
+    fn variation_point(&self) -> Option<VariationPoint> {
+        VariationPoint::try_from(self.element().get_sub_element(ElementName::VariationPoint)?).ok()
+    }
+
+    /// get the variation point of the element, or create it if the element does not have one yet
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use autosar_data::*;
+    /// # use autosar_data_abstraction::{*, software_component::*};
+    /// # fn main() -> Result<(), AutosarAbstractionError> {
+    /// # let model = AutosarModelAbstraction::create("filename", AutosarVersion::Autosar_00048);
+    /// # let package = model.get_or_create_package("/pkg1")?;
+    /// let composition = package.create_composition_sw_component_type("Composition")?;
+    /// let swc_type = package.create_application_sw_component_type("Swc")?;
+    /// let swc_prototype = composition.create_component("SwcPrototype", &swc_type)?;
+    /// let criterion = package.create_post_build_variant_criterion("Criterion")?;
+    /// let variation_point = swc_prototype.get_or_create_variation_point()?;
+    /// variation_point.add_post_build_variant_condition(&criterion, 1)?;
+    ///
+    /// let mut binding = VariantBinding::new();
+    /// binding.set_criterion_value(&criterion, 1);
+    /// assert!(binding.is_active(&swc_prototype));
+    /// binding.set_criterion_value(&criterion, 2);
+    /// assert!(!binding.is_active(&swc_prototype));
+    /// # Ok(())}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`AutosarAbstractionError::ModelError`] An error occurred in the Autosar model while trying to create the VARIATION-POINT element
+    fn get_or_create_variation_point(&self) -> Result<VariationPoint, AutosarAbstractionError> {
+        let variation_point = self.element().get_or_create_sub_element(ElementName::VariationPoint)?;
+        Ok(VariationPoint(variation_point))
+    }
+
+    /// remove the variation point of the element, so that it is present in all variants
+    fn remove_variation_point(&self) {
+        let _ = self.element().remove_sub_element_kind(ElementName::VariationPoint);
+    }
+}
+
+impl VariantElement for SwComponentPrototype {}
+impl VariantElement for RPortPrototype {}
+impl VariantElement for PPortPrototype {}
+impl VariantElement for PRPortPrototype {}
+impl VariantElement for PortPrototype {}
+impl VariantElement for DelegationSwConnector {}
+impl VariantElement for AssemblySwConnector {}
+impl VariantElement for PassThroughSwConnector {}
+impl VariantElement for SwConnector {}
+impl VariantElement for PduTriggering {}
+impl VariantElement for ISignalTriggering {}
+impl VariantElement for CanFrameTriggering {}
+impl VariantElement for FlexrayFrameTriggering {}
+impl VariantElement for LinFrameTriggering {}
+impl VariantElement for FrameTriggering {}
+impl VariantElement for ISignalToIPduMapping {}
+impl VariantElement for PduToFrameMapping {}
+
+//##################################################################
+
+/// A `VariationPoint` contains the conditions under which the element that owns it is present
+///
+/// The conditions can be given as a pre-build condition (`SwSyscond`), which is a formula based on system constants,
+/// and as a list of post-build variant conditions, which are evaluated at runtime.
+///
+/// Use [`VariantElement::get_or_create_variation_point`] to create a `VariationPoint`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VariationPoint(Element);
+abstraction_element!(VariationPoint, VariationPoint);
+
+impl VariationPoint {
+    /// set or remove the short label of the variation point
+    pub fn set_short_label(&self, short_label: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        if let Some(short_label) = short_label {
+            self.element()
+                .get_or_create_sub_element(ElementName::ShortLabel)?
+                .set_character_data(short_label)?;
+        } else {
+            let _ = self.element().remove_sub_element_kind(ElementName::ShortLabel);
+        }
+        Ok(())
+    }
+
+    /// get the short label of the variation point
+    #[must_use]
+    pub fn short_label(&self) -> Option<String> {
+        self.element()
+            .get_sub_element(ElementName::ShortLabel)?
+            .character_data()?
+            .string_value()
+    }
+
+    /// set or remove the pre-build condition of the variation point
+    ///
+    /// The condition is a formula using system constants, e.g. `"SYSC_VARIANT == 2"`.
+    pub fn set_sw_syscond(&self, formula: Option<&str>) -> Result<(), AutosarAbstractionError> {
+        let _ = self.element().remove_sub_element_kind(ElementName::SwSyscond);
+        if let Some(formula) = formula {
+            // SW-SYSCOND has mixed content, so the formula is inserted as a character content item
+            self.element()
+                .create_sub_element(ElementName::SwSyscond)?
+                .insert_character_content_item(formula, 0)?;
+        }
+        Ok(())
+    }
+
+    /// get the pre-build condition of the variation point
+    ///
+    /// Only the text of the formula is returned; references to system constants inside the formula are skipped.
+    #[must_use]
+    pub fn sw_syscond(&self) -> Option<String> {
+        let sw_syscond = self.element().get_sub_element(ElementName::SwSyscond)?;
+        let formula: String = sw_syscond
+            .content()
+            .filter_map(|content| match content {
+                ElementContent::CharacterData(cdata) => cdata.string_value(),
+                ElementContent::Element(_) => None,
+            })
+            .collect();
+        Some(formula)
+    }
+
+    /// add a post-build variant condition to the variation point
+    ///
+    /// The condition is fulfilled if the criterion has the given value.
+    /// All conditions of a variation point must be fulfilled for the element to be present.
+    pub fn add_post_build_variant_condition(
+        &self,
+        criterion: &PostBuildVariantCriterion,
+        value: i64,
+    ) -> Result<PostBuildVariantCondition, AutosarAbstractionError> {
+        let conditions = self
+            .element()
+            .get_or_create_sub_element(ElementName::PostBuildVariantConditions)?;
+        PostBuildVariantCondition::new(&conditions, criterion, value)
+    }
+
+    /// iterate over all post-build variant conditions of the variation point
+    pub fn post_build_variant_conditions(&self) -> impl Iterator<Item = PostBuildVariantCondition> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::PostBuildVariantConditions)
+            .into_iter()
+            .flat_map(|conditions| conditions.sub_elements())
+            .filter_map(|elem| PostBuildVariantCondition::try_from(elem).ok())
+    }
+}
+
+//##################################################################
+
+/// A `PostBuildVariantCondition` compares the value of a [`PostBuildVariantCriterion`] with a fixed value
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PostBuildVariantCondition(Element);
+abstraction_element!(PostBuildVariantCondition, PostBuildVariantCondition);
+
+impl PostBuildVariantCondition {
+    pub(crate) fn new(
+        parent: &Element,
+        criterion: &PostBuildVariantCriterion,
+        value: i64,
+    ) -> Result<Self, AutosarAbstractionError> {
+        let condition = Self(parent.create_sub_element(ElementName::PostBuildVariantCondition)?);
+        condition.set_criterion(criterion)?;
+        condition.set_value(value)?;
+        Ok(condition)
+    }
+
+    /// set the criterion that is checked by the condition
+    pub fn set_criterion(&self, criterion: &PostBuildVariantCriterion) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::MatchingCriterionRef)?
+            .set_reference_target(criterion.element())?;
+        Ok(())
+    }
+
+    /// get the criterion that is checked by the condition
+    #[must_use]
+    pub fn criterion(&self) -> Option<PostBuildVariantCriterion> {
+        let criterion_ref = self.element().get_sub_element(ElementName::MatchingCriterionRef)?;
+        PostBuildVariantCriterion::try_from(criterion_ref.get_reference_target().ok()?).ok()
+    }
+
+    /// set the value that the criterion must have to fulfill the condition
+    pub fn set_value(&self, value: i64) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::Value)?
+            .set_character_data(value.to_string())?;
+        Ok(())
+    }
+
+    /// get the value that the criterion must have to fulfill the condition
+    #[must_use]
+    pub fn value(&self) -> Option<i64> {
+        self.element()
+            .get_sub_element(ElementName::Value)?
+            .character_data()?
+            .parse_integer()
+    }
+}
+
+//##################################################################
+
+/// A `PostBuildVariantCriterion` is a variable whose value selects the active post-build variant
+///
+/// Use [`ArPackage::create_post_build_variant_criterion`] to create a new `PostBuildVariantCriterion`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PostBuildVariantCriterion(Element);
+abstraction_element!(PostBuildVariantCriterion, PostBuildVariantCriterion);
+impl IdentifiableAbstractionElement for PostBuildVariantCriterion {}
+
+impl PostBuildVariantCriterion {
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let pkg_elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let criterion = pkg_elements.create_named_sub_element(ElementName::PostBuildVariantCriterion, name)?;
+        Ok(Self(criterion))
+    }
+}
+
+//##################################################################
+
+/// A `PostBuildVariantCriterionValueSet` assigns values to a set of [`PostBuildVariantCriterion`]s
+///
+/// Use [`ArPackage::create_post_build_variant_criterion_value_set`] to create a new `PostBuildVariantCriterionValueSet`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PostBuildVariantCriterionValueSet(Element);
+abstraction_element!(PostBuildVariantCriterionValueSet, PostBuildVariantCriterionValueSet);
+impl IdentifiableAbstractionElement for PostBuildVariantCriterionValueSet {}
+
+impl PostBuildVariantCriterionValueSet {
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let pkg_elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let value_set = pkg_elements.create_named_sub_element(ElementName::PostBuildVariantCriterionValueSet, name)?;
+        Ok(Self(value_set))
+    }
+
+    /// add a value for a criterion to the value set
+    pub fn add_criterion_value(
+        &self,
+        criterion: &PostBuildVariantCriterion,
+        value: i64,
+    ) -> Result<(), AutosarAbstractionError> {
+        let criterion_value = self
+            .element()
+            .get_or_create_sub_element(ElementName::PostBuildVariantCriterionValues)?
+            .create_sub_element(ElementName::PostBuildVariantCriterionValue)?;
+        criterion_value
+            .create_sub_element(ElementName::VariantCriterionRef)?
+            .set_reference_target(criterion.element())?;
+        criterion_value
+            .create_sub_element(ElementName::Value)?
+            .set_character_data(value.to_string())?;
+        Ok(())
+    }
+
+    /// iterate over the criteria and their values in the value set
+    pub fn criterion_values(&self) -> impl Iterator<Item = (PostBuildVariantCriterion, i64)> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::PostBuildVariantCriterionValues)
+            .into_iter()
+            .flat_map(|values| values.sub_elements())
+            .filter_map(|criterion_value| {
+                let criterion_ref = criterion_value.get_sub_element(ElementName::VariantCriterionRef)?;
+                let criterion = PostBuildVariantCriterion::try_from(criterion_ref.get_reference_target().ok()?).ok()?;
+                let value = criterion_value
+                    .get_sub_element(ElementName::Value)?
+                    .character_data()?
+                    .parse_integer()?;
+                Some((criterion, value))
+            })
+    }
+}
+
+//##################################################################
+
+/// A `SwSystemconst` is a system constant, which can be used in the pre-build conditions of variation points
+///
+/// Use [`ArPackage::create_sw_systemconst`] to create a new `SwSystemconst`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SwSystemconst(Element);
+abstraction_element!(SwSystemconst, SwSystemconst);
+impl IdentifiableAbstractionElement for SwSystemconst {}
+
+impl SwSystemconst {
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let pkg_elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let systemconst = pkg_elements.create_named_sub_element(ElementName::SwSystemconst, name)?;
+        Ok(Self(systemconst))
+    }
+}
+
+//##################################################################
+
+/// A `SwSystemconstantValueSet` assigns values to a set of [`SwSystemconst`]s
+///
+/// Use [`ArPackage::create_sw_systemconstant_value_set`] to create a new `SwSystemconstantValueSet`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SwSystemconstantValueSet(Element);
+abstraction_element!(SwSystemconstantValueSet, SwSystemconstantValueSet);
+impl IdentifiableAbstractionElement for SwSystemconstantValueSet {}
+
+impl SwSystemconstantValueSet {
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let pkg_elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let value_set = pkg_elements.create_named_sub_element(ElementName::SwSystemconstantValueSet, name)?;
+        Ok(Self(value_set))
+    }
+
+    /// add a value for a system constant to the value set
+    pub fn add_systemconst_value(
+        &self,
+        systemconst: &SwSystemconst,
+        value: i64,
+    ) -> Result<(), AutosarAbstractionError> {
+        let systemconst_value = self
+            .element()
+            .get_or_create_sub_element(ElementName::SwSystemconstantValues)?
+            .create_sub_element(ElementName::SwSystemconstValue)?;
+        systemconst_value
+            .create_sub_element(ElementName::SwSystemconstRef)?
+            .set_reference_target(systemconst.element())?;
+        systemconst_value
+            .create_sub_element(ElementName::Value)?
+            .set_character_data(value.to_string())?;
+        Ok(())
+    }
+
+    /// iterate over the system constants and their values in the value set
+    pub fn systemconst_values(&self) -> impl Iterator<Item = (SwSystemconst, i64)> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::SwSystemconstantValues)
+            .into_iter()
+            .flat_map(|values| values.sub_elements())
+            .filter_map(|systemconst_value| {
+                let systemconst_ref = systemconst_value.get_sub_element(ElementName::SwSystemconstRef)?;
+                let systemconst = SwSystemconst::try_from(systemconst_ref.get_reference_target().ok()?).ok()?;
+                let value = systemconst_value
+                    .get_sub_element(ElementName::Value)?
+                    .character_data()?
+                    .parse_integer()?;
+                Some((systemconst, value))
+            })
+    }
+}
+
+//##################################################################
+
+/// A `PredefinedVariant` describes a complete variant of the model by referencing value sets
+/// for the post-build variant criteria and the system constants
+///
+/// Use [`ArPackage::create_predefined_variant`] to create a new `PredefinedVariant`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PredefinedVariant(Element);
+abstraction_element!(PredefinedVariant, PredefinedVariant);
+impl IdentifiableAbstractionElement for PredefinedVariant {}
+
+impl PredefinedVariant {
+    pub(crate) fn new(name: &str, package: &ArPackage) -> Result<Self, AutosarAbstractionError> {
+        let pkg_elements = package.element().get_or_create_sub_element(ElementName::Elements)?;
+        let variant = pkg_elements.create_named_sub_element(ElementName::PredefinedVariant, name)?;
+        Ok(Self(variant))
+    }
+
+    /// add a `PostBuildVariantCriterionValueSet` to the variant
+    pub fn add_post_build_variant_criterion_value_set(
+        &self,
+        value_set: &PostBuildVariantCriterionValueSet,
+    ) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::PostBuildVariantCriterionValueSetRefs)?
+            .create_sub_element(ElementName::PostBuildVariantCriterionValueSetRef)?
+            .set_reference_target(value_set.element())?;
+        Ok(())
+    }
+
+    /// iterate over all `PostBuildVariantCriterionValueSet`s of the variant
+    pub fn post_build_variant_criterion_value_sets(
+        &self,
+    ) -> impl Iterator<Item = PostBuildVariantCriterionValueSet> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::PostBuildVariantCriterionValueSetRefs)
+            .into_iter()
+            .flat_map(|refs| refs.sub_elements())
+            .filter_map(|value_set_ref| {
+                PostBuildVariantCriterionValueSet::try_from(value_set_ref.get_reference_target().ok()?).ok()
+            })
+    }
+
+    /// add a `SwSystemconstantValueSet` to the variant
+    pub fn add_sw_systemconstant_value_set(
+        &self,
+        value_set: &SwSystemconstantValueSet,
+    ) -> Result<(), AutosarAbstractionError> {
+        self.element()
+            .get_or_create_sub_element(ElementName::SwSystemconstantValueSetRefs)?
+            .create_sub_element(ElementName::SwSystemconstantValueSetRef)?
+            .set_reference_target(value_set.element())?;
+        Ok(())
+    }
+
+    /// iterate over all `SwSystemconstantValueSet`s of the variant
+    pub fn sw_systemconstant_value_sets(&self) -> impl Iterator<Item = SwSystemconstantValueSet> + Send + use<> {
+        self.element()
+            .get_sub_element(ElementName::SwSystemconstantValueSetRefs)
+            .into_iter()
+            .flat_map(|refs| refs.sub_elements())
+            .filter_map(|value_set_ref| {
+                SwSystemconstantValueSet::try_from(value_set_ref.get_reference_target().ok()?).ok()
+            })
+    }
+}
+
+//##################################################################
+
+/// A `VariantBinding` assigns values to [`PostBuildVariantCriterion`]s and thereby selects one variant of the model
+///
+/// It can be used to filter the elements returned by the iterators of this crate, e.g.
+/// `composition.components().filter(|component| binding.is_active(component))`.
+///
+/// Pre-build conditions (`SwSyscond`) are formulas over system constants, which are not evaluated by the binding.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VariantBinding {
+    criterion_values: HashMap<PostBuildVariantCriterion, i64>,
+}
+
+impl VariantBinding {
+    /// create a new empty `VariantBinding`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// create a `VariantBinding` from the criterion values of a `PredefinedVariant`
+    ///
+    /// If a criterion has values in several value sets of the variant, then the last value is used.
+    #[must_use]
+    pub fn from_predefined_variant(variant: &PredefinedVariant) -> Self {
+        let mut binding = Self::new();
+        for value_set in variant.post_build_variant_criterion_value_sets() {
+            for (criterion, value) in value_set.criterion_values() {
+                binding.set_criterion_value(&criterion, value);
+            }
+        }
+        binding
+    }
+
+    /// set the value of a criterion in the binding
+    pub fn set_criterion_value(&mut self, criterion: &PostBuildVariantCriterion, value: i64) {
+        self.criterion_values.insert(criterion.clone(), value);
+    }
+
+    /// get the value of a criterion in the binding
+    #[must_use]
+    pub fn criterion_value(&self, criterion: &PostBuildVariantCriterion) -> Option<i64> {
+        self.criterion_values.get(criterion).copied()
+    }
+
+    /// check if an element is present in the variant that is selected by the binding
+    ///
+    /// Elements without a variation point are always present. Otherwise all post-build variant conditions
+    /// of the variation point must be fulfilled. A condition whose criterion has no value in the binding is not fulfilled.
+    #[must_use]
+    pub fn is_active<T: VariantElement>(&self, element: &T) -> bool {
+        element.variation_point().is_none_or(|variation_point| {
+            variation_point.post_build_variant_conditions().all(|condition| {
+                condition
+                    .criterion()
+                    .and_then(|criterion| self.criterion_value(&criterion))
+                    .is_some_and(|value| Some(value) == condition.value())
+            })
+        })
+    }
+}
+
+//##################################################################
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        AutosarModelAbstraction, SystemCategory,
+        communication::{AbstractFrame, CanAddressingMode, CanFrameType},
+    };
+    use autosar_data::AutosarVersion;
+
+    #[test]
+    fn variation_point() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let criterion = package.create_post_build_variant_criterion("Criterion").unwrap();
+        let other_criterion = package.create_post_build_variant_criterion("OtherCriterion").unwrap();
+
+        let composition = package.create_composition_sw_component_type("Composition").unwrap();
+        let swc_type = package.create_application_sw_component_type("Swc").unwrap();
+        let swc_a = composition.create_component("SwcA", &swc_type).unwrap();
+        let swc_b = composition.create_component("SwcB", &swc_type).unwrap();
+        assert!(swc_a.variation_point().is_none());
+
+        let variation_point = swc_a.get_or_create_variation_point().unwrap();
+        assert_eq!(swc_a.variation_point(), Some(variation_point.clone()));
+        variation_point.set_short_label(Some("VariantA")).unwrap();
+        assert_eq!(variation_point.short_label().as_deref(), Some("VariantA"));
+        variation_point.set_sw_syscond(Some("SYSC_VARIANT == 1")).unwrap();
+        assert_eq!(variation_point.sw_syscond().as_deref(), Some("SYSC_VARIANT == 1"));
+        variation_point.set_sw_syscond(None).unwrap();
+        assert_eq!(variation_point.sw_syscond(), None);
+
+        let condition = variation_point.add_post_build_variant_condition(&criterion, 1).unwrap();
+        assert_eq!(condition.criterion(), Some(criterion.clone()));
+        assert_eq!(condition.value(), Some(1));
+        variation_point
+            .add_post_build_variant_condition(&other_criterion, 5)
+            .unwrap();
+        assert_eq!(variation_point.post_build_variant_conditions().count(), 2);
+
+        let mut binding = VariantBinding::new();
+        binding.set_criterion_value(&criterion, 1);
+        // the other criterion is not bound yet
+        assert!(!binding.is_active(&swc_a));
+        binding.set_criterion_value(&other_criterion, 5);
+        assert!(binding.is_active(&swc_a));
+        // elements without a variation point are always active
+        assert!(binding.is_active(&swc_b));
+
+        binding.set_criterion_value(&criterion, 2);
+        let active: Vec<_> = composition
+            .components()
+            .filter(|component| binding.is_active(component))
+            .collect();
+        assert_eq!(active, vec![swc_b]);
+
+        swc_a.remove_variation_point();
+        assert!(binding.is_active(&swc_a));
+
+        // variation points on communication elements
+        let system = package.create_system("System", SystemCategory::SystemExtract).unwrap();
+        let cluster = system.create_can_cluster("CanCluster", &package, None).unwrap();
+        let channel = cluster.create_physical_channel("CanChannel").unwrap();
+        let frame = system.create_can_frame("Frame", &package, 8).unwrap();
+        let frame_triggering = channel
+            .trigger_frame(&frame, 0x100, CanAddressingMode::Standard, CanFrameType::Can20)
+            .unwrap();
+        frame_triggering
+            .get_or_create_variation_point()
+            .unwrap()
+            .add_post_build_variant_condition(&criterion, 3)
+            .unwrap();
+        assert!(!binding.is_active(&frame_triggering));
+        assert_eq!(frame.frame_triggerings().count(), 1);
+    }
+
+    #[test]
+    fn predefined_variant() {
+        let model = AutosarModelAbstraction::create("filename", AutosarVersion::LATEST);
+        let package = model.get_or_create_package("/pkg").unwrap();
+        let criterion = package.create_post_build_variant_criterion("Criterion").unwrap();
+        let systemconst = package.create_sw_systemconst("SYSC_VARIANT").unwrap();
+
+        let criterion_values = package
+            .create_post_build_variant_criterion_value_set("CriterionValues")
+            .unwrap();
+        criterion_values.add_criterion_value(&criterion, 2).unwrap();
+        assert_eq!(
+            criterion_values.criterion_values().collect::<Vec<_>>(),
+            vec![(criterion.clone(), 2)]
+        );
+
+        let systemconst_values = package.create_sw_systemconstant_value_set("SystemconstValues").unwrap();
+        systemconst_values.add_systemconst_value(&systemconst, 1).unwrap();
+        assert_eq!(
+            systemconst_values.systemconst_values().collect::<Vec<_>>(),
+            vec![(systemconst, 1)]
+        );
+
+        let variant = package.create_predefined_variant("Variant").unwrap();
+        variant
+            .add_post_build_variant_criterion_value_set(&criterion_values)
+            .unwrap();
+        variant.add_sw_systemconstant_value_set(&systemconst_values).unwrap();
+        assert_eq!(
+            variant.post_build_variant_criterion_value_sets().next(),
+            Some(criterion_values)
+        );
+        assert_eq!(variant.sw_systemconstant_value_sets().next(), Some(systemconst_values));
+
+        let binding = VariantBinding::from_predefined_variant(&variant);
+        assert_eq!(binding.criterion_value(&criterion), Some(2));
+    }
+}
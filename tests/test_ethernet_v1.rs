@@ -51,6 +51,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: Some("192.168.0.200".to_string()),
             network_mask: Some("255.255.255.0".to_string()),
+            dns_servers: vec![],
+            ttl: None,
         };
         let network_endpoint_ecu_a =
             eth_channel.create_network_endpoint("local_endpoint", network_address_ecu_a, None)?;
@@ -78,6 +80,8 @@ mod test {
             address_source: Some(IPv4AddressSource::Fixed),
             default_gateway: Some("192.168.0.200".to_string()),
             network_mask: Some("255.255.255.0".to_string()),
+            dns_servers: vec![],
+            ttl: None,
         };
         let network_endpoint_remote =
             eth_channel.create_network_endpoint("remote_endpoint", network_address_remote, None)?;
@@ -283,6 +287,8 @@ mod test {
                 address_source: Some(IPv4AddressSource::Fixed),
                 default_gateway: None,
                 network_mask: None,
+                dns_servers: vec![],
+                ttl: None,
             },
             None,
         )?;
@@ -302,6 +308,8 @@ mod test {
                 address_source: None,
                 default_gateway: None,
                 network_mask: None,
+                dns_servers: vec![],
+                ttl: None,
             },
             None,
         )?;